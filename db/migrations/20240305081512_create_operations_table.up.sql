@@ -0,0 +1,35 @@
+CREATE TABLE IF NOT EXISTS operations
+(
+    id                            uuid PRIMARY KEY,
+    user_id                       uuid             NOT NULL,
+    kind                          VARCHAR(255)     NOT NULL,
+    category_id                   uuid             NOT NULL,
+    amount                        DOUBLE PRECISION NOT NULL,
+    currency                      VARCHAR(3)       NOT NULL,
+    currency_amount               DOUBLE PRECISION NOT NULL,
+    rate                          DOUBLE PRECISION NOT NULL,
+    label                         VARCHAR(255)     NOT NULL,
+    tag_ids                       uuid[]           NOT NULL DEFAULT '{}',
+    created_at                    TIMESTAMPTZ      NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS operations_user_id_created_at_idx ON operations (user_id, created_at, id);
+CREATE INDEX IF NOT EXISTS operations_user_id_amount_idx ON operations (user_id, amount, id);
+CREATE INDEX IF NOT EXISTS operations_tag_ids_idx ON operations USING GIN (tag_ids);
+
+-- Backfill the projection from the operations that were recorded before it existed
+INSERT INTO operations (id, user_id, kind, category_id, amount, currency, currency_amount, rate, label, tag_ids, created_at)
+SELECT (payload ->> 'id')::uuid,
+       (payload ->> 'user_id')::uuid,
+       payload ->> 'kind',
+       (payload ->> 'category_id')::uuid,
+       (payload ->> 'amount')::DOUBLE PRECISION,
+       payload ->> 'currency',
+       (payload ->> 'amount_currency')::DOUBLE PRECISION,
+       (payload ->> 'rate')::DOUBLE PRECISION,
+       payload ->> 'label',
+       ARRAY(SELECT jsonb_array_elements_text(payload -> 'tag_ids')::uuid),
+       (payload ->> 'created_at')::TIMESTAMPTZ
+FROM operation_events
+WHERE name = 'operation_created'
+ON CONFLICT (id) DO NOTHING;
@@ -0,0 +1,7 @@
+DROP INDEX IF EXISTS operation_events_aggregate_id_version_idx;
+DROP INDEX IF EXISTS category_events_aggregate_id_version_idx;
+DROP INDEX IF EXISTS tag_events_aggregate_id_version_idx;
+
+ALTER TABLE operation_events DROP COLUMN IF EXISTS aggregate_id;
+ALTER TABLE category_events DROP COLUMN IF EXISTS aggregate_id;
+ALTER TABLE tag_events DROP COLUMN IF EXISTS aggregate_id;
@@ -0,0 +1,31 @@
+ALTER TABLE operation_events ADD COLUMN IF NOT EXISTS aggregate_id uuid;
+ALTER TABLE category_events ADD COLUMN IF NOT EXISTS aggregate_id uuid;
+ALTER TABLE tag_events ADD COLUMN IF NOT EXISTS aggregate_id uuid;
+
+-- Every event recorded so far starts its own stream, the aggregate id is the `id` of its payload
+UPDATE operation_events SET aggregate_id = (payload ->> 'id')::uuid WHERE aggregate_id IS NULL;
+UPDATE category_events SET aggregate_id = (payload ->> 'id')::uuid WHERE aggregate_id IS NULL;
+UPDATE tag_events SET aggregate_id = (payload ->> 'id')::uuid WHERE aggregate_id IS NULL;
+
+UPDATE operation_events e
+SET version = v.version
+FROM (SELECT id, ROW_NUMBER() OVER (PARTITION BY aggregate_id ORDER BY created_at, id) AS version FROM operation_events) v
+WHERE e.id = v.id;
+
+UPDATE category_events e
+SET version = v.version
+FROM (SELECT id, ROW_NUMBER() OVER (PARTITION BY aggregate_id ORDER BY created_at, id) AS version FROM category_events) v
+WHERE e.id = v.id;
+
+UPDATE tag_events e
+SET version = v.version
+FROM (SELECT id, ROW_NUMBER() OVER (PARTITION BY aggregate_id ORDER BY created_at, id) AS version FROM tag_events) v
+WHERE e.id = v.id;
+
+ALTER TABLE operation_events ALTER COLUMN aggregate_id SET NOT NULL;
+ALTER TABLE category_events ALTER COLUMN aggregate_id SET NOT NULL;
+ALTER TABLE tag_events ALTER COLUMN aggregate_id SET NOT NULL;
+
+CREATE UNIQUE INDEX IF NOT EXISTS operation_events_aggregate_id_version_idx ON operation_events (aggregate_id, version);
+CREATE UNIQUE INDEX IF NOT EXISTS category_events_aggregate_id_version_idx ON category_events (aggregate_id, version);
+CREATE UNIQUE INDEX IF NOT EXISTS tag_events_aggregate_id_version_idx ON tag_events (aggregate_id, version);
@@ -0,0 +1,22 @@
+CREATE TABLE IF NOT EXISTS categories
+(
+    id                            uuid PRIMARY KEY,
+    user_id                       uuid             NOT NULL,
+    name                          VARCHAR(255)     NOT NULL,
+    icon                          VARCHAR(255)     DEFAULT NULL,
+    archived                      BOOLEAN          NOT NULL DEFAULT FALSE,
+    created_at                    TIMESTAMPTZ      NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS categories_user_id_name_idx ON categories (user_id, name);
+
+-- Backfill the projection from the categories that were recorded before it existed
+INSERT INTO categories (id, user_id, name, icon, created_at)
+SELECT (payload ->> 'id')::uuid,
+       (payload ->> 'user_id')::uuid,
+       payload ->> 'name',
+       payload ->> 'icon',
+       created_at
+FROM category_events
+WHERE name = 'category_created'
+ON CONFLICT (id) DO NOTHING;
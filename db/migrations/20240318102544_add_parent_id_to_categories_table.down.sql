@@ -0,0 +1,4 @@
+DROP INDEX IF EXISTS operations_user_id_category_id_idx;
+DROP INDEX IF EXISTS categories_user_id_parent_id_idx;
+
+ALTER TABLE categories DROP COLUMN IF EXISTS parent_id;
@@ -0,0 +1,4 @@
+ALTER TABLE categories ADD COLUMN IF NOT EXISTS parent_id uuid DEFAULT NULL;
+
+CREATE INDEX IF NOT EXISTS categories_user_id_parent_id_idx ON categories (user_id, parent_id);
+CREATE INDEX IF NOT EXISTS operations_user_id_category_id_idx ON operations (user_id, category_id);
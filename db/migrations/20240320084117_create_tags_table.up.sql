@@ -0,0 +1,19 @@
+CREATE TABLE IF NOT EXISTS tags
+(
+    id                            uuid PRIMARY KEY,
+    user_id                       uuid             NOT NULL,
+    name                          VARCHAR(255)     NOT NULL,
+    created_at                    TIMESTAMPTZ      NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS tags_user_id_name_idx ON tags (user_id, name);
+
+-- Backfill the projection from the tags that were recorded before it existed
+INSERT INTO tags (id, user_id, name, created_at)
+SELECT (payload ->> 'id')::uuid,
+       (payload ->> 'user_id')::uuid,
+       payload ->> 'name',
+       created_at
+FROM tag_events
+WHERE name = 'tag_created'
+ON CONFLICT (id) DO NOTHING;
@@ -0,0 +1,5 @@
+DROP INDEX IF EXISTS categories_user_id_normalized_name_idx;
+DROP INDEX IF EXISTS tags_user_id_normalized_name_idx;
+
+ALTER TABLE categories DROP COLUMN IF EXISTS normalized_name;
+ALTER TABLE tags DROP COLUMN IF EXISTS normalized_name;
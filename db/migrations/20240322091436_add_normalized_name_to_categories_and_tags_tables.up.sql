@@ -0,0 +1,23 @@
+ALTER TABLE categories ADD COLUMN IF NOT EXISTS normalized_name VARCHAR(255);
+ALTER TABLE tags ADD COLUMN IF NOT EXISTS normalized_name VARCHAR(255);
+
+-- Same rules as `support::name::normalize_name`: collapsed whitespace, lower case
+UPDATE categories SET normalized_name = lower(trim(regexp_replace(name, '\s+', ' ', 'g'))) WHERE normalized_name IS NULL;
+UPDATE tags SET normalized_name = lower(trim(regexp_replace(name, '\s+', ' ', 'g'))) WHERE normalized_name IS NULL;
+
+-- Duplicates created before the check keep their names, only the oldest one of them is found by name from now on
+UPDATE categories c
+SET normalized_name = left(c.normalized_name, 200) || '#' || c.id
+FROM (SELECT id, ROW_NUMBER() OVER (PARTITION BY user_id, normalized_name ORDER BY created_at, id) AS position FROM categories) d
+WHERE c.id = d.id AND d.position > 1;
+
+UPDATE tags t
+SET normalized_name = left(t.normalized_name, 200) || '#' || t.id
+FROM (SELECT id, ROW_NUMBER() OVER (PARTITION BY user_id, normalized_name ORDER BY created_at, id) AS position FROM tags) d
+WHERE t.id = d.id AND d.position > 1;
+
+ALTER TABLE categories ALTER COLUMN normalized_name SET NOT NULL;
+ALTER TABLE tags ALTER COLUMN normalized_name SET NOT NULL;
+
+CREATE UNIQUE INDEX IF NOT EXISTS categories_user_id_normalized_name_idx ON categories (user_id, normalized_name);
+CREATE UNIQUE INDEX IF NOT EXISTS tags_user_id_normalized_name_idx ON tags (user_id, normalized_name);
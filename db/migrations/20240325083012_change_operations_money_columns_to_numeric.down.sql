@@ -0,0 +1,4 @@
+ALTER TABLE operations
+    ALTER COLUMN amount TYPE DOUBLE PRECISION USING amount::DOUBLE PRECISION,
+    ALTER COLUMN currency_amount TYPE DOUBLE PRECISION USING currency_amount::DOUBLE PRECISION,
+    ALTER COLUMN rate TYPE DOUBLE PRECISION USING rate::DOUBLE PRECISION;
@@ -0,0 +1,5 @@
+-- Money is exact from now on. Amounts recorded so far are rounded to cents as the domain does now, rates are kept as is
+ALTER TABLE operations
+    ALTER COLUMN amount TYPE NUMERIC USING round(amount::NUMERIC, 2),
+    ALTER COLUMN currency_amount TYPE NUMERIC USING round(currency_amount::NUMERIC, 2),
+    ALTER COLUMN rate TYPE NUMERIC USING rate::NUMERIC;
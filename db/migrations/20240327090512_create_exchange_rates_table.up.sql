@@ -0,0 +1,9 @@
+CREATE TABLE IF NOT EXISTS exchange_rates
+(
+    base       VARCHAR(3)  NOT NULL,
+    quote      VARCHAR(3)  NOT NULL,
+    date       DATE        NOT NULL,
+    rate       NUMERIC     NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (base, quote, date)
+);
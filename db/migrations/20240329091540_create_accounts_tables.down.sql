@@ -0,0 +1,10 @@
+DROP INDEX IF EXISTS operations_account_id_idx;
+
+UPDATE operation_events
+SET payload = payload - 'account_id' - 'previous_account_id'
+WHERE name IN ('operation_created', 'operation_updated', 'operation_deleted');
+
+ALTER TABLE operations DROP COLUMN IF EXISTS account_id;
+
+DROP TABLE IF EXISTS accounts;
+DROP TABLE IF EXISTS account_events;
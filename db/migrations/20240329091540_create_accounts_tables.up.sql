@@ -0,0 +1,86 @@
+CREATE TABLE IF NOT EXISTS account_events
+(
+    id                            uuid PRIMARY KEY,
+    aggregate_id                  uuid         NOT NULL,
+    name                          VARCHAR(255) DEFAULT NULL,
+    payload                       JSONB DEFAULT NULL,
+    version                       INT NOT NULL DEFAULT 0,
+    created_at                    TIMESTAMPTZ  NOT NULL   DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS account_events_aggregate_id_version_idx ON account_events (aggregate_id, version);
+
+CREATE TABLE IF NOT EXISTS accounts
+(
+    id                            uuid PRIMARY KEY,
+    user_id                       uuid             NOT NULL,
+    name                          VARCHAR(255)     NOT NULL,
+    kind                          VARCHAR(255)     NOT NULL,
+    currency                      VARCHAR(3)       NOT NULL,
+    opening_balance               NUMERIC          NOT NULL DEFAULT 0,
+    created_at                    TIMESTAMPTZ      NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS accounts_user_id_idx ON accounts (user_id);
+
+ALTER TABLE operations ADD COLUMN IF NOT EXISTS account_id uuid;
+
+-- Operations recorded so far are moved to a main account per user and currency, so every operation keeps the currency of its account
+CREATE TEMPORARY TABLE main_accounts AS
+SELECT gen_random_uuid() AS id, user_id, currency, MIN(created_at) AS created_at
+FROM (
+    SELECT (payload ->> 'user_id')::uuid AS user_id, payload ->> 'currency' AS currency, created_at
+    FROM operation_events
+    WHERE name IN ('operation_created', 'operation_updated', 'operation_deleted')
+    UNION ALL
+    SELECT (payload ->> 'user_id')::uuid, payload ->> 'previous_currency', created_at
+    FROM operation_events
+    WHERE name = 'operation_updated'
+) c
+GROUP BY user_id, currency;
+
+INSERT INTO account_events (id, aggregate_id, name, payload, version, created_at)
+SELECT gen_random_uuid(),
+       id,
+       'account_created',
+       jsonb_build_object(
+           'id', id,
+           'user_id', user_id,
+           'name', 'Main ' || currency,
+           'kind', 'Other',
+           'currency', currency,
+           'opening_balance', '0'
+       ),
+       1,
+       created_at
+FROM main_accounts;
+
+INSERT INTO accounts (id, user_id, name, kind, currency, opening_balance, created_at)
+SELECT id, user_id, 'Main ' || currency, 'Other', currency, 0, created_at
+FROM main_accounts
+ON CONFLICT (id) DO NOTHING;
+
+UPDATE operations o
+SET account_id = a.id
+FROM main_accounts a
+WHERE o.user_id = a.user_id AND o.currency = a.currency AND o.account_id IS NULL;
+
+UPDATE operation_events e
+SET payload = jsonb_set(e.payload, '{account_id}', to_jsonb(a.id::text))
+FROM main_accounts a
+WHERE e.name IN ('operation_created', 'operation_updated', 'operation_deleted')
+  AND (e.payload ->> 'user_id')::uuid = a.user_id
+  AND e.payload ->> 'currency' = a.currency;
+
+UPDATE operation_events e
+SET payload = jsonb_set(e.payload, '{previous_account_id}', to_jsonb(a.id::text))
+FROM main_accounts a
+WHERE e.name = 'operation_updated'
+  AND (e.payload ->> 'user_id')::uuid = a.user_id
+  AND e.payload ->> 'previous_currency' = a.currency;
+
+DROP TABLE main_accounts;
+
+ALTER TABLE operations ALTER COLUMN account_id SET NOT NULL;
+
+CREATE INDEX IF NOT EXISTS operations_account_id_idx ON operations (account_id);
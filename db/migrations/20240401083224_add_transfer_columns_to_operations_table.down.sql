@@ -0,0 +1,5 @@
+DROP INDEX IF EXISTS operations_transfer_account_id_idx;
+
+ALTER TABLE operations DROP COLUMN IF EXISTS transfer_amount;
+ALTER TABLE operations DROP COLUMN IF EXISTS transfer_currency;
+ALTER TABLE operations DROP COLUMN IF EXISTS transfer_account_id;
@@ -0,0 +1,5 @@
+ALTER TABLE operations ADD COLUMN IF NOT EXISTS transfer_account_id uuid;
+ALTER TABLE operations ADD COLUMN IF NOT EXISTS transfer_currency VARCHAR(3);
+ALTER TABLE operations ADD COLUMN IF NOT EXISTS transfer_amount NUMERIC;
+
+CREATE INDEX IF NOT EXISTS operations_transfer_account_id_idx ON operations (transfer_account_id);
@@ -0,0 +1,46 @@
+CREATE TABLE IF NOT EXISTS balance_changeds
+(
+    id                            uuid PRIMARY KEY,
+    name                          VARCHAR(255) DEFAULT NULL,
+    payload                       JSONB DEFAULT NULL,
+    created_at                    TIMESTAMPTZ  NOT NULL   DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE TABLE IF NOT EXISTS balances
+(
+    account_id                    uuid PRIMARY KEY,
+    user_id                       uuid             NOT NULL,
+    currency                      VARCHAR(3)       NOT NULL,
+    amount                        NUMERIC          NOT NULL DEFAULT 0,
+    currency_amount               NUMERIC          NOT NULL DEFAULT 0,
+    updated_at                    TIMESTAMPTZ      NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS balances_user_id_idx ON balances (user_id);
+
+-- Balances start from the operations recorded so far, with the same signs as Kind::sign
+INSERT INTO balances (account_id, user_id, currency, amount, currency_amount)
+SELECT c.account_id, a.user_id, a.currency, SUM(c.amount), SUM(c.currency_amount)
+FROM (
+    SELECT account_id,
+           CASE
+               WHEN kind IN ('Income', 'Credit') THEN amount
+               WHEN kind IN ('Expense', 'Debt') THEN -amount
+               WHEN kind = 'Transfer' AND transfer_account_id IS NOT NULL THEN -amount
+               ELSE 0
+           END AS amount,
+           CASE
+               WHEN kind IN ('Income', 'Credit') THEN currency_amount
+               WHEN kind IN ('Expense', 'Debt') THEN -currency_amount
+               WHEN kind = 'Transfer' AND transfer_account_id IS NOT NULL THEN -currency_amount
+               ELSE 0
+           END AS currency_amount
+    FROM operations
+    UNION ALL
+    SELECT transfer_account_id, amount, transfer_amount
+    FROM operations
+    WHERE transfer_account_id IS NOT NULL
+) c
+JOIN accounts a ON a.id = c.account_id
+GROUP BY c.account_id, a.user_id, a.currency
+ON CONFLICT (account_id) DO NOTHING;
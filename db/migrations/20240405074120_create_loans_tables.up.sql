@@ -0,0 +1,39 @@
+CREATE TABLE IF NOT EXISTS loan_events
+(
+    id                            uuid PRIMARY KEY,
+    aggregate_id                  uuid         NOT NULL,
+    name                          VARCHAR(255) DEFAULT NULL,
+    payload                       JSONB DEFAULT NULL,
+    version                       INT NOT NULL DEFAULT 0,
+    created_at                    TIMESTAMPTZ  NOT NULL   DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS loan_events_aggregate_id_version_idx ON loan_events (aggregate_id, version);
+
+CREATE TABLE IF NOT EXISTS loans
+(
+    id                            uuid PRIMARY KEY,
+    user_id                       uuid             NOT NULL,
+    direction                     VARCHAR(255)     NOT NULL,
+    counterparty                  VARCHAR(255)     NOT NULL,
+    principal                     NUMERIC          NOT NULL,
+    currency                      VARCHAR(3)       NOT NULL,
+    interest_rate                 NUMERIC          NOT NULL DEFAULT 0,
+    issued_on                     DATE             NOT NULL,
+    schedule                      JSONB            NOT NULL DEFAULT '[]'::JSONB,
+    created_at                    TIMESTAMPTZ      NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS loans_user_id_idx ON loans (user_id);
+
+CREATE TABLE IF NOT EXISTS loan_repayments
+(
+    id                            uuid PRIMARY KEY,
+    loan_id                       uuid             NOT NULL,
+    operation_id                  uuid             NOT NULL,
+    amount                        NUMERIC          NOT NULL,
+    paid_on                       DATE             NOT NULL,
+    created_at                    TIMESTAMPTZ      NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS loan_repayments_loan_id_operation_id_idx ON loan_repayments (loan_id, operation_id);
@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS recurring_operations;
+DROP TABLE IF EXISTS recurring_operation_events;
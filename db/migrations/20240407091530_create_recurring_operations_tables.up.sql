@@ -0,0 +1,31 @@
+CREATE TABLE IF NOT EXISTS recurring_operation_events
+(
+    id                            uuid PRIMARY KEY,
+    aggregate_id                  uuid         NOT NULL,
+    name                          VARCHAR(255) DEFAULT NULL,
+    payload                       JSONB DEFAULT NULL,
+    version                       INT NOT NULL DEFAULT 0,
+    created_at                    TIMESTAMPTZ  NOT NULL   DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS recurring_operation_events_aggregate_id_version_idx ON recurring_operation_events (aggregate_id, version);
+
+CREATE TABLE IF NOT EXISTS recurring_operations
+(
+    id                            uuid PRIMARY KEY,
+    user_id                       uuid             NOT NULL,
+    frequency                     VARCHAR(255)     NOT NULL,
+    interval                      INT              NOT NULL DEFAULT 1,
+    starts_on                     DATE             NOT NULL,
+    until                         DATE             DEFAULT NULL,
+    count                         INT              DEFAULT NULL,
+    template                      JSONB            NOT NULL,
+    occurrences                   INT              NOT NULL DEFAULT 0,
+    last_operation_id             uuid             DEFAULT NULL,
+    next_due_on                   DATE             DEFAULT NULL,
+    cancelled                     BOOLEAN          NOT NULL DEFAULT FALSE,
+    created_at                    TIMESTAMPTZ      NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS recurring_operations_user_id_idx ON recurring_operations (user_id);
+CREATE INDEX IF NOT EXISTS recurring_operations_next_due_on_idx ON recurring_operations (next_due_on) WHERE NOT cancelled;
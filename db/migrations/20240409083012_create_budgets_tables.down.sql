@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS budget_spendings;
+DROP TABLE IF EXISTS budgets;
+DROP TABLE IF EXISTS budget_events;
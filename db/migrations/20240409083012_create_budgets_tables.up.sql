@@ -0,0 +1,39 @@
+CREATE TABLE IF NOT EXISTS budget_events
+(
+    id                            uuid PRIMARY KEY,
+    aggregate_id                  uuid         NOT NULL,
+    name                          VARCHAR(255) DEFAULT NULL,
+    payload                       JSONB DEFAULT NULL,
+    version                       INT NOT NULL DEFAULT 0,
+    created_at                    TIMESTAMPTZ  NOT NULL   DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS budget_events_aggregate_id_version_idx ON budget_events (aggregate_id, version);
+
+CREATE TABLE IF NOT EXISTS budgets
+(
+    id                            uuid PRIMARY KEY,
+    user_id                       uuid             NOT NULL,
+    category_id                   uuid             NOT NULL,
+    period                        VARCHAR(255)     NOT NULL,
+    currency                      VARCHAR(3)       NOT NULL,
+    amount_limit                  NUMERIC          NOT NULL,
+    rollover                      VARCHAR(255)     NOT NULL DEFAULT 'None',
+    thresholds                    JSONB            NOT NULL DEFAULT '[]'::JSONB,
+    starts_on                     DATE             NOT NULL,
+    created_at                    TIMESTAMPTZ      NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS budgets_user_id_category_id_idx ON budgets (user_id, category_id);
+
+CREATE TABLE IF NOT EXISTS budget_spendings
+(
+    id                            uuid PRIMARY KEY,
+    budget_id                     uuid             NOT NULL,
+    operation_id                  uuid             NOT NULL,
+    period_start                  DATE             NOT NULL,
+    amount                        NUMERIC          NOT NULL,
+    created_at                    TIMESTAMPTZ      NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS budget_spendings_budget_id_operation_id_idx ON budget_spendings (budget_id, operation_id);
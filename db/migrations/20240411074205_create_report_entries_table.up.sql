@@ -0,0 +1,18 @@
+CREATE TABLE IF NOT EXISTS report_entries
+(
+    operation_id                  uuid PRIMARY KEY,
+    user_id                       uuid             NOT NULL,
+    kind                          VARCHAR(255)     NOT NULL,
+    category_id                   uuid             NOT NULL,
+    tag_ids                       uuid[]           NOT NULL DEFAULT '{}',
+    amount                        NUMERIC          NOT NULL,
+    created_at                    TIMESTAMPTZ      NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS report_entries_user_id_created_at_idx ON report_entries (user_id, created_at);
+
+-- Backfill the projection from the current state of the operations, so deleted operations are left out, amounts are in the base currency
+INSERT INTO report_entries (operation_id, user_id, kind, category_id, tag_ids, amount, created_at)
+SELECT id, user_id, kind, category_id, tag_ids, amount, created_at
+FROM operations
+ON CONFLICT (operation_id) DO NOTHING;
\ No newline at end of file
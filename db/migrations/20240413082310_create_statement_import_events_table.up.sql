@@ -0,0 +1,11 @@
+CREATE TABLE IF NOT EXISTS statement_import_events
+(
+    id                            uuid PRIMARY KEY,
+    aggregate_id                  uuid         NOT NULL,
+    name                          VARCHAR(255) DEFAULT NULL,
+    payload                       JSONB DEFAULT NULL,
+    version                       INT NOT NULL DEFAULT 0,
+    created_at                    TIMESTAMPTZ  NOT NULL   DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS statement_import_events_aggregate_id_version_idx ON statement_import_events (aggregate_id, version);
@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS bank_connections;
+DROP TABLE IF EXISTS bank_connection_events;
@@ -0,0 +1,25 @@
+CREATE TABLE IF NOT EXISTS bank_connection_events
+(
+    id                            uuid PRIMARY KEY,
+    aggregate_id                  uuid         NOT NULL,
+    name                          VARCHAR(255) DEFAULT NULL,
+    payload                       JSONB DEFAULT NULL,
+    version                       INT NOT NULL DEFAULT 0,
+    created_at                    TIMESTAMPTZ  NOT NULL   DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS bank_connection_events_aggregate_id_version_idx ON bank_connection_events (aggregate_id, version);
+
+CREATE TABLE IF NOT EXISTS bank_connections
+(
+    id                            uuid PRIMARY KEY,
+    user_id                       uuid             NOT NULL,
+    account_id                    uuid             NOT NULL,
+    provider                      VARCHAR(255)     NOT NULL,
+    external_account_id           VARCHAR(255)     NOT NULL,
+    synced_transactions           INT              NOT NULL DEFAULT 0,
+    last_synced_at                TIMESTAMPTZ      DEFAULT NULL,
+    created_at                    TIMESTAMPTZ      NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS bank_connections_user_id_idx ON bank_connections (user_id);
@@ -0,0 +1,25 @@
+CREATE TABLE IF NOT EXISTS rule_events
+(
+    id                            uuid PRIMARY KEY,
+    aggregate_id                  uuid         NOT NULL,
+    name                          VARCHAR(255) DEFAULT NULL,
+    payload                       JSONB DEFAULT NULL,
+    version                       INT NOT NULL DEFAULT 0,
+    created_at                    TIMESTAMPTZ  NOT NULL   DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS rule_events_aggregate_id_version_idx ON rule_events (aggregate_id, version);
+
+CREATE TABLE IF NOT EXISTS rules
+(
+    id                            uuid PRIMARY KEY,
+    user_id                       uuid             NOT NULL,
+    name                          VARCHAR(255)     NOT NULL,
+    priority                      INT              NOT NULL DEFAULT 0,
+    conditions                    JSONB            NOT NULL,
+    category_id                   uuid             NOT NULL,
+    tag_ids                       uuid[]           NOT NULL DEFAULT '{}',
+    created_at                    TIMESTAMPTZ      NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS rules_user_id_idx ON rules (user_id);
@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS suggestion_tokens;
+DROP TABLE IF EXISTS suggestion_classes;
+DROP TABLE IF EXISTS suggestion_operations;
@@ -0,0 +1,65 @@
+CREATE TABLE IF NOT EXISTS suggestion_operations
+(
+    operation_id                  uuid PRIMARY KEY,
+    user_id                       uuid             NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS suggestion_classes
+(
+    user_id                       uuid             NOT NULL,
+    target                        VARCHAR(255)     NOT NULL,
+    target_id                     uuid             NOT NULL,
+    operations                    BIGINT           NOT NULL DEFAULT 0,
+    tokens                        BIGINT           NOT NULL DEFAULT 0,
+    PRIMARY KEY (user_id, target, target_id)
+);
+
+CREATE TABLE IF NOT EXISTS suggestion_tokens
+(
+    user_id                       uuid             NOT NULL,
+    target                        VARCHAR(255)     NOT NULL,
+    target_id                     uuid             NOT NULL,
+    token                         VARCHAR(255)     NOT NULL,
+    count                         BIGINT           NOT NULL DEFAULT 0,
+    PRIMARY KEY (user_id, target, target_id, token)
+);
+
+CREATE INDEX IF NOT EXISTS suggestion_tokens_user_id_target_token_idx ON suggestion_tokens (user_id, target, token);
+
+-- Learn from the operations that were recorded before the projection existed, tokens are split the same way as in tokenizer.rs
+CREATE TEMPORARY TABLE suggestion_backfill AS
+SELECT (payload ->> 'id')::uuid AS operation_id,
+       (payload ->> 'user_id')::uuid AS user_id,
+       (payload ->> 'category_id')::uuid AS category_id,
+       ARRAY(SELECT jsonb_array_elements_text(payload -> 'tag_ids')::uuid) AS tag_ids,
+       ARRAY(
+           SELECT DISTINCT token
+           FROM regexp_split_to_table(lower(payload ->> 'label'), '[^[:alnum:]]+') AS token
+           WHERE char_length(token) >= 2 AND token !~ '^[0-9]+$'
+       ) || ('#amount:' || char_length(trunc(abs((payload ->> 'amount_currency')::NUMERIC))::TEXT))::VARCHAR AS tokens
+FROM operation_events
+WHERE name = 'operation_created' AND payload ->> 'kind' <> 'Transfer';
+
+CREATE TEMPORARY TABLE suggestion_backfill_targets AS
+SELECT user_id, 'category' AS target, category_id AS target_id, tokens FROM suggestion_backfill
+UNION ALL
+SELECT user_id, 'tag' AS target, tag_id AS target_id, tokens FROM suggestion_backfill CROSS JOIN LATERAL unnest(tag_ids) AS tag_id;
+
+INSERT INTO suggestion_operations (operation_id, user_id)
+SELECT operation_id, user_id FROM suggestion_backfill
+ON CONFLICT (operation_id) DO NOTHING;
+
+INSERT INTO suggestion_classes (user_id, target, target_id, operations, tokens)
+SELECT user_id, target, target_id, COUNT(*), SUM(cardinality(tokens))
+FROM suggestion_backfill_targets
+GROUP BY user_id, target, target_id
+ON CONFLICT (user_id, target, target_id) DO NOTHING;
+
+INSERT INTO suggestion_tokens (user_id, target, target_id, token, count)
+SELECT user_id, target, target_id, token, COUNT(*)
+FROM suggestion_backfill_targets CROSS JOIN LATERAL unnest(tokens) AS token
+GROUP BY user_id, target, target_id, token
+ON CONFLICT (user_id, target, target_id, token) DO NOTHING;
+
+DROP TABLE suggestion_backfill_targets;
+DROP TABLE suggestion_backfill;
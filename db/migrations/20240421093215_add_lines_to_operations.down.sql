@@ -0,0 +1,6 @@
+DELETE FROM report_entries WHERE line > 0;
+ALTER TABLE report_entries DROP CONSTRAINT IF EXISTS report_entries_pkey;
+ALTER TABLE report_entries DROP COLUMN IF EXISTS line;
+ALTER TABLE report_entries ADD PRIMARY KEY (operation_id);
+
+ALTER TABLE operations DROP COLUMN IF EXISTS lines;
@@ -0,0 +1,6 @@
+ALTER TABLE operations ADD COLUMN IF NOT EXISTS lines JSONB NOT NULL DEFAULT '[]';
+
+-- A split operation is reported once per line
+ALTER TABLE report_entries ADD COLUMN IF NOT EXISTS line INT NOT NULL DEFAULT 0;
+ALTER TABLE report_entries DROP CONSTRAINT IF EXISTS report_entries_pkey;
+ALTER TABLE report_entries ADD PRIMARY KEY (operation_id, line);
@@ -0,0 +1 @@
+ALTER TABLE operations ADD COLUMN IF NOT EXISTS attachments JSONB NOT NULL DEFAULT '[]';
@@ -0,0 +1 @@
+-- The rebuilt entries are kept, they match the operations
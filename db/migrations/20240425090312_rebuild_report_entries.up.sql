@@ -0,0 +1,18 @@
+-- Entries were only recorded on creation, rebuild them from the current state of the operations
+DELETE FROM report_entries;
+
+INSERT INTO report_entries (operation_id, line, user_id, kind, category_id, tag_ids, amount, created_at)
+SELECT o.id,
+       COALESCE(l.position - 1, 0),
+       o.user_id,
+       o.kind,
+       COALESCE((l.line ->> 'category_id')::uuid, o.category_id),
+       CASE
+           WHEN l.line IS NULL THEN o.tag_ids
+           ELSE ARRAY(SELECT DISTINCT unnest(o.tag_ids || ARRAY(SELECT jsonb_array_elements_text(l.line -> 'tag_ids')::uuid)))
+       END,
+       COALESCE((l.line ->> 'amount')::NUMERIC, o.amount),
+       o.created_at
+FROM operations o
+LEFT JOIN LATERAL jsonb_array_elements(o.lines) WITH ORDINALITY AS l(line, position) ON TRUE
+ON CONFLICT DO NOTHING;
@@ -0,0 +1,5 @@
+DROP INDEX IF EXISTS balance_changeds_operation_event_id_account_id_reverted_idx;
+
+ALTER TABLE balance_changeds DROP COLUMN IF EXISTS reverted;
+ALTER TABLE balance_changeds DROP COLUMN IF EXISTS account_id;
+ALTER TABLE balance_changeds DROP COLUMN IF EXISTS operation_event_id;
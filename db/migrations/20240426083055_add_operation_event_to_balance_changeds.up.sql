@@ -0,0 +1,7 @@
+ALTER TABLE balance_changeds ADD COLUMN IF NOT EXISTS operation_event_id uuid;
+ALTER TABLE balance_changeds ADD COLUMN IF NOT EXISTS account_id uuid;
+ALTER TABLE balance_changeds ADD COLUMN IF NOT EXISTS reverted BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- Each operation event changes the balance of an account once per direction
+CREATE UNIQUE INDEX IF NOT EXISTS balance_changeds_operation_event_id_account_id_reverted_idx
+    ON balance_changeds (operation_event_id, account_id, reverted);
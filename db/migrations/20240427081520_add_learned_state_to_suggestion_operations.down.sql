@@ -0,0 +1,3 @@
+ALTER TABLE suggestion_operations DROP COLUMN IF EXISTS tokens;
+ALTER TABLE suggestion_operations DROP COLUMN IF EXISTS tag_ids;
+ALTER TABLE suggestion_operations DROP COLUMN IF EXISTS category_id;
@@ -0,0 +1,40 @@
+ALTER TABLE suggestion_operations ADD COLUMN IF NOT EXISTS category_id uuid;
+ALTER TABLE suggestion_operations ADD COLUMN IF NOT EXISTS tag_ids uuid[] NOT NULL DEFAULT '{}';
+ALTER TABLE suggestion_operations ADD COLUMN IF NOT EXISTS tokens VARCHAR(255)[] NOT NULL DEFAULT '{}';
+
+-- Operations were only learned on creation, rebuild what was learned from their current state
+DELETE FROM suggestion_tokens;
+DELETE FROM suggestion_classes;
+DELETE FROM suggestion_operations;
+
+INSERT INTO suggestion_operations (operation_id, user_id, category_id, tag_ids, tokens)
+SELECT id,
+       user_id,
+       category_id,
+       tag_ids,
+       ARRAY(
+           SELECT DISTINCT token
+           FROM regexp_split_to_table(lower(label), '[^[:alnum:]]+') AS token
+           WHERE char_length(token) >= 2 AND token !~ '^[0-9]+$'
+       ) || ('#amount:' || char_length(trunc(abs(currency_amount))::TEXT))::VARCHAR
+FROM operations
+WHERE kind <> 'Transfer';
+
+ALTER TABLE suggestion_operations ALTER COLUMN category_id SET NOT NULL;
+
+CREATE TEMPORARY TABLE suggestion_rebuild_targets AS
+SELECT user_id, 'category' AS target, category_id AS target_id, tokens FROM suggestion_operations
+UNION ALL
+SELECT user_id, 'tag' AS target, tag_id AS target_id, tokens FROM suggestion_operations CROSS JOIN LATERAL unnest(tag_ids) AS tag_id;
+
+INSERT INTO suggestion_classes (user_id, target, target_id, operations, tokens)
+SELECT user_id, target, target_id, COUNT(*), SUM(cardinality(tokens))
+FROM suggestion_rebuild_targets
+GROUP BY user_id, target, target_id;
+
+INSERT INTO suggestion_tokens (user_id, target, target_id, token, count)
+SELECT user_id, target, target_id, token, COUNT(*)
+FROM suggestion_rebuild_targets CROSS JOIN LATERAL unnest(tokens) AS token
+GROUP BY user_id, target, target_id, token;
+
+DROP TABLE suggestion_rebuild_targets;
@@ -0,0 +1 @@
+ALTER TABLE recurring_operations DROP COLUMN IF EXISTS paused_reason;
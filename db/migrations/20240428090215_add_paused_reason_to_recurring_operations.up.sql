@@ -0,0 +1 @@
+ALTER TABLE recurring_operations ADD COLUMN IF NOT EXISTS paused_reason TEXT DEFAULT NULL;
@@ -0,0 +1,13 @@
+use std::env;
+use metan::test_utils::mock_bank::MockBank;
+
+/// Serves the mock bank on `MOCK_BANK_PORT` (8090 by default) for local bank syncs.
+#[actix_web::main]
+async fn main() -> std::io::Result<()> {
+    let port = env::var("MOCK_BANK_PORT")
+        .ok()
+        .and_then(|port| port.parse().ok())
+        .unwrap_or(8090);
+
+    MockBank::new().start(port)?.await
+}
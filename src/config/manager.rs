@@ -1,13 +1,20 @@
 use config::{Config as ConfigLoader, Environment, File, FileFormat};
 use serde::Deserialize;
 use crate::config::structs::auth::AuthConfig;
+use crate::config::structs::banking::BankingConfig;
+use crate::config::structs::budgets::BudgetsConfig;
 
 use crate::config::structs::db::DbConfig;
 use crate::config::structs::general::GeneralConfig;
+use crate::config::structs::imports::ImportsConfig;
 use crate::config::structs::log::LogConfig;
 use crate::config::structs::mailer::MailerConfig;
 use crate::config::structs::mq::MqConfig;
+use crate::config::structs::rates::RatesConfig;
+use crate::config::structs::recurrences::RecurrencesConfig;
+use crate::config::structs::reports::ReportsConfig;
 use crate::config::structs::server::ServerConfig;
+use crate::config::structs::storage::StorageConfig;
 use crate::config::structs::templater::TemplaterConfig;
 
 const CONFIG_DIR: &str = "config";
@@ -16,12 +23,19 @@ const CONFIG_FORMAT: FileFormat = FileFormat::Toml;
 #[derive(Deserialize, Clone, Debug)]
 pub struct ConfigManager {
     auth: AuthConfig,
+    banking: BankingConfig,
+    budgets: BudgetsConfig,
     db: DbConfig,
     general: GeneralConfig,
+    imports: ImportsConfig,
     log: LogConfig,
     mailer: MailerConfig,
     mq: MqConfig,
+    rates: RatesConfig,
+    recurrences: RecurrencesConfig,
+    reports: ReportsConfig,
     server: ServerConfig,
+    storage: StorageConfig,
     templater: TemplaterConfig,
 }
 
@@ -35,12 +49,19 @@ impl ConfigManager {
         //TODO: Add configuration file names to here
         let files = vec![
             "auth.toml",
+            "banking.toml",
+            "budgets.toml",
             "db.toml",
             "general.toml",
+            "imports.toml",
             "log.toml",
             "mailer.toml",
             "mq.toml",
+            "rates.toml",
+            "recurrences.toml",
+            "reports.toml",
             "server.toml",
+            "storage.toml",
             "templater.toml",
         ];
 
@@ -74,6 +95,14 @@ impl ConfigManager {
         &self.auth
     }
 
+    pub fn banking(&self) -> &BankingConfig {
+        &self.banking
+    }
+
+    pub fn budgets(&self) -> &BudgetsConfig {
+        &self.budgets
+    }
+
     pub fn db(&self) -> &DbConfig {
         &self.db
     }
@@ -82,6 +111,10 @@ impl ConfigManager {
         &self.general
     }
 
+    pub fn imports(&self) -> &ImportsConfig {
+        &self.imports
+    }
+
     pub fn log(&self) -> &LogConfig {
         &self.log
     }
@@ -93,10 +126,26 @@ impl ConfigManager {
         &self.mq
     }
 
+    pub fn rates(&self) -> &RatesConfig {
+        &self.rates
+    }
+
+    pub fn recurrences(&self) -> &RecurrencesConfig {
+        &self.recurrences
+    }
+
+    pub fn reports(&self) -> &ReportsConfig {
+        &self.reports
+    }
+
     pub fn server(&self) -> &ServerConfig {
         &self.server
     }
 
+    pub fn storage(&self) -> &StorageConfig {
+        &self.storage
+    }
+
     pub fn templater(&self) -> &TemplaterConfig {
         &self.templater
     }
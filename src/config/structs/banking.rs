@@ -0,0 +1,47 @@
+use serde::Deserialize;
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct BankingConfig {
+    encryption_key: String,
+    sync_interval: u64,
+    category: String,
+    providers: Vec<BankProviderConfig>,
+}
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct BankProviderConfig {
+    name: String,
+    url: String,
+}
+
+impl BankingConfig {
+    /// Key the credentials of bank connections are encrypted with, 32 bytes in base64.
+    pub fn encryption_key(&self) -> &str {
+        &self.encryption_key
+    }
+
+    /// Seconds between two runs of the bank sync worker.
+    pub fn sync_interval(&self) -> u64 {
+        self.sync_interval
+    }
+
+    /// Category synced operations are booked to.
+    pub fn category(&self) -> &str {
+        &self.category
+    }
+
+    pub fn providers(&self) -> &[BankProviderConfig] {
+        &self.providers
+    }
+}
+
+impl BankProviderConfig {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Base url of the Open-Banking API of the provider.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+}
@@ -0,0 +1,13 @@
+use serde::Deserialize;
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct BudgetsConfig {
+    thresholds: Vec<u32>,
+}
+
+impl BudgetsConfig {
+    /// Percentages announced for budgets created without their own thresholds.
+    pub fn thresholds(&self) -> &[u32] {
+        &self.thresholds
+    }
+}
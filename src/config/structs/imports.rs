@@ -0,0 +1,19 @@
+use serde::Deserialize;
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct ImportsConfig {
+    default_category: String,
+    max_file_size: usize,
+}
+
+impl ImportsConfig {
+    /// Category imported operations are booked to when the request names none.
+    pub fn default_category(&self) -> &str {
+        &self.default_category
+    }
+
+    /// Largest statement file accepted, in bytes.
+    pub fn max_file_size(&self) -> usize {
+        self.max_file_size
+    }
+}
@@ -1,8 +1,15 @@
 pub mod auth;
+pub mod banking;
+pub mod budgets;
 pub mod db;
 pub mod general;
+pub mod imports;
 pub mod mq;
 pub mod log;
 pub mod mailer;
+pub mod rates;
+pub mod recurrences;
+pub mod reports;
 pub mod server;
+pub mod storage;
 pub mod templater;
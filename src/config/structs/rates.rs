@@ -0,0 +1,35 @@
+use serde::Deserialize;
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct RatesConfig {
+    provider: String,
+    base_currency: String,
+    url: String,
+    file: String,
+    refresh_interval: u64,
+}
+
+impl RatesConfig {
+    /// Either `http` or `file`.
+    pub fn provider(&self) -> &str {
+        &self.provider
+    }
+
+    /// Currency in which operation amounts are kept.
+    pub fn base_currency(&self) -> &str {
+        &self.base_currency
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn file(&self) -> &str {
+        &self.file
+    }
+
+    /// Seconds between two runs of the rate refresher.
+    pub fn refresh_interval(&self) -> u64 {
+        self.refresh_interval
+    }
+}
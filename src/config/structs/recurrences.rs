@@ -0,0 +1,13 @@
+use serde::Deserialize;
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct RecurrencesConfig {
+    materialize_interval: u64,
+}
+
+impl RecurrencesConfig {
+    /// Seconds between two runs of the recurrence worker.
+    pub fn materialize_interval(&self) -> u64 {
+        self.materialize_interval
+    }
+}
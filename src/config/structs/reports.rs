@@ -0,0 +1,19 @@
+use serde::Deserialize;
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct ReportsConfig {
+    forecast_lookback: u32,
+    forecast_max_days: u32,
+}
+
+impl ReportsConfig {
+    /// Days of history the trend of a forecast is computed from.
+    pub fn forecast_lookback(&self) -> u32 {
+        self.forecast_lookback
+    }
+
+    /// Longest forecast a user can request, in days.
+    pub fn forecast_max_days(&self) -> u32 {
+        self.forecast_max_days
+    }
+}
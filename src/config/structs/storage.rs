@@ -0,0 +1,31 @@
+use serde::Deserialize;
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct StorageConfig {
+    driver: String,
+    dir: String,
+    max_file_size: usize,
+    content_types: Vec<String>,
+}
+
+impl StorageConfig {
+    /// Backend files are kept in, only `local` is supported for now.
+    pub fn driver(&self) -> &str {
+        &self.driver
+    }
+
+    /// Root directory of the `local` driver.
+    pub fn dir(&self) -> &str {
+        &self.dir
+    }
+
+    /// Largest attachment accepted, in bytes.
+    pub fn max_file_size(&self) -> usize {
+        self.max_file_size
+    }
+
+    /// MIME types attachments may have.
+    pub fn content_types(&self) -> &[String] {
+        &self.content_types
+    }
+}
@@ -4,15 +4,22 @@ use crate::config::manager::ConfigManager;
 use crate::db::factory::DbFactory;
 use crate::db::manager::DbManager;
 use crate::di::error::ServiceContainerError;
+use crate::features::rates::domain::rate_provider::RateProvider;
+use crate::features::rates::error::RateError;
+use crate::features::rates::infrastructure::rate_provider_factory::RateProviderFactory;
 use crate::mq::manager::MqManager;
+use crate::services::cipher::{AesGcmCipher, Cipher};
 use crate::services::hasher::{BcryptHasher, Hasher};
 use crate::services::http_client::{HttpClient, ReqwestClient};
 use crate::services::jwt::{JsonwebtokenLibService, JwtService};
 use crate::services::mailer::{LettreMailer, Mailer};
+use crate::services::error::ServiceError;
 use crate::services::serializer::Serializer;
+use crate::services::storage::{Storage, StorageFactory};
 use crate::services::templater::{HandlebarsTemplater, Templater};
 use crate::services::tokenizer::{SymbolsTokenizer, Tokenizer};
 use crate::support::command_bus::{Command, CommandBus, CommandHandler};
+use crate::support::query_bus::{Query, QueryBus, QueryHandler};
 
 pub struct ServiceContainer {
     config: ConfigManager,
@@ -41,6 +48,10 @@ impl ServiceContainer {
         &self.config
     }
 
+    pub fn cipher(&self) -> impl Cipher {
+        AesGcmCipher::new(self.config.banking().encryption_key().to_string())
+    }
+
     pub fn command_bus<C, H>(&self) -> CommandBus<C, H>
         where
             C: 'static + Send + Sync + Command,
@@ -73,10 +84,26 @@ impl ServiceContainer {
         self.mq_manager.clone()
     }
 
+    pub fn query_bus<Q, H>(&self) -> QueryBus<Q, H>
+        where
+            Q: 'static + Send + Sync + Query,
+            H: 'static + Send + Sync + QueryHandler<Q>,
+    {
+        QueryBus::new()
+    }
+
+    pub fn rate_provider(&self) -> Result<Box<dyn RateProvider>, RateError> {
+        RateProviderFactory::create(self.config.rates())
+    }
+
     pub fn serializer(&self) -> Serializer {
         Serializer::Cbor
     }
 
+    pub fn storage(&self) -> Result<Box<dyn Storage>, ServiceError> {
+        StorageFactory::create(self.config.storage())
+    }
+
     pub fn templater(&self) -> impl Templater {
         HandlebarsTemplater::new(self.config.templater().clone())
     }
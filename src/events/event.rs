@@ -1,7 +1,14 @@
 use serde::{Deserialize, Serialize};
+use crate::features::accounts::domain::events::account_event::AccountEvent;
 use crate::features::balance::domain::events::balance_event::BalanceEvent;
+use crate::features::banking::domain::events::bank_connection_event::BankConnectionEvent;
+use crate::features::budgets::domain::events::budget_event::BudgetEvent;
 use crate::features::categories::domain::events::category_event::CategoryEvent;
+use crate::features::imports::domain::events::statement_import_event::StatementImportEvent;
+use crate::features::loans::domain::events::loan_event::LoanEvent;
 use crate::features::operations::domain::events::operation_event::OperationEvent;
+use crate::features::recurrences::domain::events::recurring_operation_event::RecurringOperationEvent;
+use crate::features::rules::domain::events::rule_event::RuleEvent;
 use crate::features::tags::domain::events::tag_event::TagEvent;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -9,7 +16,14 @@ pub enum Event {
     OperationEvent(OperationEvent),
     CategoryEvent(CategoryEvent),
     TagEvent(TagEvent),
-    BalanceEvent(BalanceEvent)
+    BalanceEvent(BalanceEvent),
+    AccountEvent(AccountEvent),
+    LoanEvent(LoanEvent),
+    RecurringOperationEvent(RecurringOperationEvent),
+    BudgetEvent(BudgetEvent),
+    StatementImportEvent(StatementImportEvent),
+    BankConnectionEvent(BankConnectionEvent),
+    RuleEvent(RuleEvent),
 }
 
 impl Event {
@@ -19,6 +33,13 @@ impl Event {
             Event::CategoryEvent(category_event) => category_event.name(),
             Event::TagEvent(tag_event) => tag_event.name(),
             Event::BalanceEvent(balance_event) => balance_event.name(),
+            Event::AccountEvent(account_event) => account_event.name(),
+            Event::LoanEvent(loan_event) => loan_event.name(),
+            Event::RecurringOperationEvent(recurring_operation_event) => recurring_operation_event.name(),
+            Event::BudgetEvent(budget_event) => budget_event.name(),
+            Event::StatementImportEvent(statement_import_event) => statement_import_event.name(),
+            Event::BankConnectionEvent(bank_connection_event) => bank_connection_event.name(),
+            Event::RuleEvent(rule_event) => rule_event.name(),
         }
     }
 }
\ No newline at end of file
@@ -3,10 +3,77 @@ use tokio::sync::Mutex;
 use crate::di::service_container::ServiceContainer;
 use crate::events::error::EventError;
 use crate::events::event_listener::EventListener;
+use crate::features::accounts::domain::events::account_created::ACCOUNT_CREATED_NAME;
+use crate::features::accounts::domain::events::account_renamed::ACCOUNT_RENAMED_NAME;
+use crate::features::accounts::infrastructure::db_account_projection_repository::DbAccountProjectionRepository;
+use crate::features::accounts::infrastructure::event_listeners::account_projection_listener::AccountProjectionListener;
+use crate::features::balance::infrastructure::db_balance_repository::DbBalanceRepository;
+use crate::features::balance::infrastructure::event_listeners::operation_balance_listener::OperationBalanceListener;
+use crate::features::banking::domain::events::bank_connection_created::BANK_CONNECTION_CREATED_NAME;
+use crate::features::banking::domain::events::bank_connection_removed::BANK_CONNECTION_REMOVED_NAME;
+use crate::features::banking::domain::events::bank_transactions_synced::BANK_TRANSACTIONS_SYNCED_NAME;
+use crate::features::banking::infrastructure::db_bank_connection_projection_repository::DbBankConnectionProjectionRepository;
+use crate::features::banking::infrastructure::event_listeners::bank_connection_projection_listener::BankConnectionProjectionListener;
+use crate::features::budgets::domain::events::budget_created::BUDGET_CREATED_NAME;
+use crate::features::budgets::domain::events::budget_spending_recorded::BUDGET_SPENDING_RECORDED_NAME;
+use crate::features::budgets::domain::events::budget_spending_reverted::BUDGET_SPENDING_REVERTED_NAME;
+use crate::features::budgets::infrastructure::db_budget_projection_repository::DbBudgetProjectionRepository;
+use crate::features::budgets::infrastructure::db_budget_repository::DbBudgetRepository;
+use crate::features::budgets::infrastructure::event_listeners::budget_projection_listener::BudgetProjectionListener;
+use crate::features::budgets::infrastructure::event_listeners::operation_spending_listener::OperationSpendingListener;
+use crate::features::categories::domain::events::category_archived::CATEGORY_ARCHIVED_NAME;
+use crate::features::categories::domain::events::category_created::CATEGORY_CREATED_NAME;
+use crate::features::categories::domain::events::category_deleted::CATEGORY_DELETED_NAME;
+use crate::features::categories::domain::events::category_icon_changed::CATEGORY_ICON_CHANGED_NAME;
+use crate::features::categories::domain::events::category_moved::CATEGORY_MOVED_NAME;
+use crate::features::categories::domain::events::category_renamed::CATEGORY_RENAMED_NAME;
+use crate::features::categories::infrastructure::db_category_projection_repository::DbCategoryProjectionRepository;
 use crate::features::categories::infrastructure::db_category_repository::DbCategoryRepository;
 use crate::features::categories::infrastructure::event_listeners::category_creation_requested_listener::CategoryCreationRequestedListener;
+use crate::features::categories::infrastructure::event_listeners::category_projection_listener::CategoryProjectionListener;
+use crate::features::loans::domain::events::loan_created::LOAN_CREATED_NAME;
+use crate::features::loans::domain::events::loan_repayment_recorded::LOAN_REPAYMENT_RECORDED_NAME;
+use crate::features::loans::infrastructure::db_loan_projection_repository::DbLoanProjectionRepository;
+use crate::features::loans::infrastructure::event_listeners::loan_projection_listener::LoanProjectionListener;
+use crate::features::operations::domain::events::operation_created::OPERATION_CREATED_NAME;
+use crate::features::operations::domain::events::operation_deleted::OPERATION_DELETED_NAME;
+use crate::features::operations::domain::events::operation_updated::OPERATION_UPDATED_NAME;
+use crate::features::operations::infrastructure::db_operation_projection_repository::DbOperationProjectionRepository;
+use crate::features::operations::infrastructure::db_operation_repository::DbOperationRepository;
+use crate::features::operations::infrastructure::event_listeners::attachment_added_listener::AttachmentAddedListener;
+use crate::features::operations::infrastructure::event_listeners::category_deleted_listener::CategoryDeletedListener;
+use crate::features::operations::infrastructure::event_listeners::category_reused_listener::CategoryReusedListener;
+use crate::features::operations::infrastructure::event_listeners::operation_created_listener::OperationCreatedListener;
+use crate::features::operations::infrastructure::event_listeners::operation_deleted_listener::OperationDeletedListener;
+use crate::features::operations::infrastructure::event_listeners::operation_updated_listener::OperationUpdatedListener;
+use crate::features::operations::infrastructure::event_listeners::tag_deleted_listener::TagDeletedListener;
+use crate::features::operations::infrastructure::event_listeners::tag_reused_listener::TagReusedListener;
+use crate::features::rates::application::queries::find_rate::handler::FindRateQueryHandler;
+use crate::features::rates::infrastructure::db_rate_repository::DbRateRepository;
+use crate::features::rates::infrastructure::query_rate_source::QueryRateSource;
+use crate::features::recurrences::domain::events::occurrence_scheduled::OCCURRENCE_SCHEDULED_NAME;
+use crate::features::recurrences::domain::events::recurring_operation_cancelled::RECURRING_OPERATION_CANCELLED_NAME;
+use crate::features::recurrences::domain::events::recurring_operation_created::RECURRING_OPERATION_CREATED_NAME;
+use crate::features::recurrences::domain::events::recurring_operation_paused::RECURRING_OPERATION_PAUSED_NAME;
+use crate::features::recurrences::domain::events::recurring_operation_resumed::RECURRING_OPERATION_RESUMED_NAME;
+use crate::features::recurrences::infrastructure::db_recurring_operation_projection_repository::DbRecurringOperationProjectionRepository;
+use crate::features::recurrences::infrastructure::event_listeners::recurring_operation_projection_listener::RecurringOperationProjectionListener;
+use crate::features::reports::infrastructure::db_report_projection_repository::DbReportProjectionRepository;
+use crate::features::reports::infrastructure::event_listeners::report_projection_listener::ReportProjectionListener;
+use crate::features::rules::domain::events::rule_created::RULE_CREATED_NAME;
+use crate::features::rules::domain::events::rule_deleted::RULE_DELETED_NAME;
+use crate::features::rules::infrastructure::db_rule_projection_repository::DbRuleProjectionRepository;
+use crate::features::rules::infrastructure::event_listeners::rule_projection_listener::RuleProjectionListener;
+use crate::features::suggestions::infrastructure::db_suggestion_projection_repository::DbSuggestionProjectionRepository;
+use crate::features::suggestions::infrastructure::event_listeners::suggestion_projection_listener::SuggestionProjectionListener;
+use crate::features::tags::domain::events::tag_created::TAG_CREATED_NAME;
+use crate::features::tags::domain::events::tag_deleted::TAG_DELETED_NAME;
+use crate::features::tags::domain::events::tag_renamed::TAG_RENAMED_NAME;
+use crate::features::tags::infrastructure::db_tag_projection_repository::DbTagProjectionRepository;
 use crate::features::tags::infrastructure::db_tag_repository::DbTagRepository;
 use crate::features::tags::infrastructure::event_listeners::tag_creation_requested_listener::TagCreationRequestedListener;
+use crate::features::tags::infrastructure::event_listeners::tag_projection_listener::TagProjectionListener;
+use crate::support::error::FeatureError;
 
 pub struct EventRouter {
     service_container: Arc<ServiceContainer>,
@@ -49,12 +116,336 @@ impl EventRouter {
             ),
         ).await;
 
+        let operation_created_listener = OperationCreatedListener::new(
+            DbOperationProjectionRepository::new(
+                self.service_container.db_manager().clone(),
+            ),
+        );
+
+        let operation_updated_listener = OperationUpdatedListener::new(
+            DbOperationProjectionRepository::new(
+                self.service_container.db_manager().clone(),
+            ),
+        );
+
+        let operation_deleted_listener = OperationDeletedListener::new(
+            DbOperationProjectionRepository::new(
+                self.service_container.db_manager().clone(),
+            ),
+        );
+
+        let attachment_added_listener = AttachmentAddedListener::new(
+            DbOperationProjectionRepository::new(
+                self.service_container.db_manager().clone(),
+            ),
+        );
+
+        let category_deleted_listener = CategoryDeletedListener::new(
+            Arc::new(Mutex::new(
+                self.service_container.command_bus()
+            )),
+            DbOperationRepository::new(
+                self.service_container.db_manager().clone(),
+                self.service_container.serializer(),
+            ),
+            DbOperationProjectionRepository::new(
+                self.service_container.db_manager().clone(),
+            ),
+        ).await;
+
+        let tag_deleted_listener = TagDeletedListener::new(
+            Arc::new(Mutex::new(
+                self.service_container.command_bus()
+            )),
+            DbOperationRepository::new(
+                self.service_container.db_manager().clone(),
+                self.service_container.serializer(),
+            ),
+            DbOperationProjectionRepository::new(
+                self.service_container.db_manager().clone(),
+            ),
+        ).await;
+
+        let category_reused_listener = CategoryReusedListener::new(
+            Arc::new(Mutex::new(
+                self.service_container.command_bus()
+            )),
+            DbOperationRepository::new(
+                self.service_container.db_manager().clone(),
+                self.service_container.serializer(),
+            ),
+        ).await;
+
+        let tag_reused_listener = TagReusedListener::new(
+            Arc::new(Mutex::new(
+                self.service_container.command_bus()
+            )),
+            DbOperationRepository::new(
+                self.service_container.db_manager().clone(),
+                self.service_container.serializer(),
+            ),
+        ).await;
+
         guard.push(
             Box::new(category_creation_requested_listener),
         );
         guard.push(
             Box::new(tag_creation_requested_listener),
         );
+        guard.push(
+            Box::new(operation_created_listener),
+        );
+        guard.push(
+            Box::new(operation_updated_listener),
+        );
+        guard.push(
+            Box::new(operation_deleted_listener),
+        );
+        guard.push(
+            Box::new(attachment_added_listener),
+        );
+        guard.push(
+            Box::new(category_deleted_listener),
+        );
+        guard.push(
+            Box::new(tag_deleted_listener),
+        );
+        guard.push(
+            Box::new(category_reused_listener),
+        );
+        guard.push(
+            Box::new(tag_reused_listener),
+        );
+
+        for event_name in [
+            CATEGORY_CREATED_NAME,
+            CATEGORY_RENAMED_NAME,
+            CATEGORY_ICON_CHANGED_NAME,
+            CATEGORY_MOVED_NAME,
+            CATEGORY_ARCHIVED_NAME,
+            CATEGORY_DELETED_NAME,
+        ] {
+            guard.push(
+                Box::new(
+                    CategoryProjectionListener::new(
+                        DbCategoryProjectionRepository::new(
+                            self.service_container.db_manager().clone(),
+                        ),
+                        event_name,
+                    )
+                ),
+            );
+        }
+
+        for event_name in [
+            TAG_CREATED_NAME,
+            TAG_RENAMED_NAME,
+            TAG_DELETED_NAME,
+        ] {
+            guard.push(
+                Box::new(
+                    TagProjectionListener::new(
+                        DbTagProjectionRepository::new(
+                            self.service_container.db_manager().clone(),
+                        ),
+                        event_name,
+                    )
+                ),
+            );
+        }
+
+        for event_name in [
+            ACCOUNT_CREATED_NAME,
+            ACCOUNT_RENAMED_NAME,
+        ] {
+            guard.push(
+                Box::new(
+                    AccountProjectionListener::new(
+                        DbAccountProjectionRepository::new(
+                            self.service_container.db_manager().clone(),
+                        ),
+                        event_name,
+                    )
+                ),
+            );
+        }
+
+        for event_name in [
+            LOAN_CREATED_NAME,
+            LOAN_REPAYMENT_RECORDED_NAME,
+        ] {
+            guard.push(
+                Box::new(
+                    LoanProjectionListener::new(
+                        DbLoanProjectionRepository::new(
+                            self.service_container.db_manager().clone(),
+                        ),
+                        event_name,
+                    )
+                ),
+            );
+        }
+
+        for event_name in [
+            RECURRING_OPERATION_CREATED_NAME,
+            OCCURRENCE_SCHEDULED_NAME,
+            RECURRING_OPERATION_CANCELLED_NAME,
+            RECURRING_OPERATION_PAUSED_NAME,
+            RECURRING_OPERATION_RESUMED_NAME,
+        ] {
+            guard.push(
+                Box::new(
+                    RecurringOperationProjectionListener::new(
+                        DbRecurringOperationProjectionRepository::new(
+                            self.service_container.db_manager().clone(),
+                        ),
+                        event_name,
+                    )
+                ),
+            );
+        }
+
+        for event_name in [
+            OPERATION_CREATED_NAME,
+            OPERATION_UPDATED_NAME,
+            OPERATION_DELETED_NAME,
+        ] {
+            guard.push(
+                Box::new(
+                    OperationBalanceListener::new(
+                        Arc::new(Mutex::new(
+                            self.service_container.command_bus()
+                        )),
+                        DbBalanceRepository::new(
+                            self.service_container.db_manager().clone(),
+                        ),
+                        event_name,
+                    ).await
+                ),
+            );
+        }
+
+        for event_name in [
+            BUDGET_CREATED_NAME,
+            BUDGET_SPENDING_RECORDED_NAME,
+            BUDGET_SPENDING_REVERTED_NAME,
+        ] {
+            guard.push(
+                Box::new(
+                    BudgetProjectionListener::new(
+                        DbBudgetProjectionRepository::new(
+                            self.service_container.db_manager().clone(),
+                        ),
+                        event_name,
+                    )
+                ),
+            );
+        }
+
+        for event_name in [
+            BANK_CONNECTION_CREATED_NAME,
+            BANK_TRANSACTIONS_SYNCED_NAME,
+            BANK_CONNECTION_REMOVED_NAME,
+        ] {
+            guard.push(
+                Box::new(
+                    BankConnectionProjectionListener::new(
+                        DbBankConnectionProjectionRepository::new(
+                            self.service_container.db_manager().clone(),
+                        ),
+                        event_name,
+                    )
+                ),
+            );
+        }
+
+        for event_name in [
+            RULE_CREATED_NAME,
+            RULE_DELETED_NAME,
+        ] {
+            guard.push(
+                Box::new(
+                    RuleProjectionListener::new(
+                        DbRuleProjectionRepository::new(
+                            self.service_container.db_manager().clone(),
+                        ),
+                        event_name,
+                    )
+                ),
+            );
+        }
+
+        for event_name in [
+            OPERATION_CREATED_NAME,
+            OPERATION_UPDATED_NAME,
+            OPERATION_DELETED_NAME,
+        ] {
+            let rate_provider = self.service_container.rate_provider()
+                .map_err(|e|
+                    EventError::Feature(FeatureError::Rate(e))
+                )?;
+
+            guard.push(
+                Box::new(
+                    OperationSpendingListener::new(
+                        Arc::new(Mutex::new(
+                            self.service_container.command_bus()
+                        )),
+                        Arc::new(Mutex::new(
+                            self.service_container.command_bus()
+                        )),
+                        DbBudgetRepository::new(
+                            self.service_container.db_manager().clone(),
+                        ),
+                        DbBudgetProjectionRepository::new(
+                            self.service_container.db_manager().clone(),
+                        ),
+                        QueryRateSource::new(
+                            FindRateQueryHandler::new(
+                                DbRateRepository::new(self.service_container.db_manager().clone()),
+                                rate_provider,
+                            ),
+                            self.service_container.config().rates().base_currency().to_string(),
+                        ),
+                        event_name,
+                    ).await
+                ),
+            );
+        }
+
+        for event_name in [
+            OPERATION_CREATED_NAME,
+            OPERATION_UPDATED_NAME,
+            OPERATION_DELETED_NAME,
+        ] {
+            guard.push(
+                Box::new(
+                    ReportProjectionListener::new(
+                        DbReportProjectionRepository::new(
+                            self.service_container.db_manager().clone(),
+                        ),
+                        event_name,
+                    )
+                ),
+            );
+        }
+
+        for event_name in [
+            OPERATION_CREATED_NAME,
+            OPERATION_UPDATED_NAME,
+            OPERATION_DELETED_NAME,
+        ] {
+            guard.push(
+                Box::new(
+                    SuggestionProjectionListener::new(
+                        DbSuggestionProjectionRepository::new(
+                            self.service_container.db_manager().clone(),
+                        ),
+                        event_name,
+                    )
+                ),
+            );
+        }
 
         Ok(())
     }
@@ -0,0 +1,53 @@
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use crate::support::command_bus::Command;
+
+const NAME : &str = "create_account";
+
+#[derive(Debug, Clone)]
+pub struct CreateAccountCommand {
+    user_id: Uuid,
+    account_name: String,
+    kind: String,
+    currency: String,
+    opening_balance: Decimal,
+}
+
+impl CreateAccountCommand {
+    pub fn new(user_id: Uuid, account_name: String, kind: String, currency: String, opening_balance: Decimal) -> Self {
+        Self {
+            user_id,
+            account_name,
+            kind,
+            currency,
+            opening_balance,
+        }
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    pub fn account_name(&self) -> &str {
+        &self.account_name
+    }
+
+    pub fn kind(&self) -> &str {
+        &self.kind
+    }
+
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    /// Money on the account before the first operation, in the account currency. May be negative, e.g. for a credit card.
+    pub fn opening_balance(&self) -> Decimal {
+        self.opening_balance
+    }
+}
+
+impl Command for CreateAccountCommand {
+    fn name() -> &'static str {
+        NAME
+    }
+}
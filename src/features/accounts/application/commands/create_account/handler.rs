@@ -0,0 +1,92 @@
+use async_trait::async_trait;
+use crate::events::event::Event;
+use crate::features::accounts::application::commands::create_account::command::CreateAccountCommand;
+use crate::features::accounts::domain::account::Account;
+use crate::features::accounts::domain::account_repository::AccountRepository;
+use crate::features::accounts::domain::events::account_event::AccountEvent;
+use crate::features::accounts::error::AccountError;
+use crate::support::command_bus::CommandHandler;
+use crate::support::error::FeatureError;
+
+pub struct CreateAccountCommandHandler<R>
+    where
+        R: AccountRepository + Send + Sync,
+{
+    account_repository: R,
+}
+
+impl<R> CreateAccountCommandHandler<R>
+    where
+        R: AccountRepository + Send + Sync,
+{
+    pub fn new(account_repository: R) -> Self {
+        Self {
+            account_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> CommandHandler<CreateAccountCommand> for CreateAccountCommandHandler<R>
+    where
+        R: AccountRepository + Send + Sync,
+{
+    async fn handle(&mut self, command: CreateAccountCommand) -> Result<Vec<Event>, FeatureError> {
+        let event = Account::handle_creation(command)
+            .map_err(|e|
+                FeatureError::Account(
+                    AccountError::Domain(e)
+                )
+            )?;
+
+        if let AccountEvent::AccountCreated(account_created) = &event {
+            self.account_repository.append(account_created.payload().id().value(), 0, std::slice::from_ref(&event))
+                .await
+                .map_err(FeatureError::Account)?;
+        }
+
+        Ok(
+            vec![Event::AccountEvent(event)]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+    use uuid::Uuid;
+    use crate::features::accounts::domain::account_repository::MockAccountRepository;
+    use crate::features::accounts::domain::error::DomainError;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle_success() {
+        let mut handler = CreateAccountCommandHandler::new(MockAccountRepository::new(false));
+
+        let events = handler.handle(command_fixture("USD")).await.unwrap();
+
+        assert!(matches!(events[0], Event::AccountEvent(AccountEvent::AccountCreated(_))));
+    }
+
+    #[tokio::test]
+    async fn test_handle_unknown_currency() {
+        let mut handler = CreateAccountCommandHandler::new(MockAccountRepository::new(false));
+
+        let result = handler.handle(command_fixture("XXX")).await;
+
+        assert!(matches!(result, Err(FeatureError::Account(AccountError::Domain(DomainError::UnknownCurrency(_))))));
+    }
+
+    #[tokio::test]
+    async fn test_handle_repository_error() {
+        let mut handler = CreateAccountCommandHandler::new(MockAccountRepository::new(true));
+
+        let result = handler.handle(command_fixture("USD")).await;
+
+        assert!(matches!(result, Err(FeatureError::Account(AccountError::Infrastructure(_)))));
+    }
+
+    fn command_fixture(currency: &str) -> CreateAccountCommand {
+        CreateAccountCommand::new(Uuid::new_v4(), "Cash".to_string(), "Cash".to_string(), currency.to_string(), Decimal::from(10))
+    }
+}
@@ -0,0 +1,2 @@
+pub mod create_account;
+pub mod rename_account;
\ No newline at end of file
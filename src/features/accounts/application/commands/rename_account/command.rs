@@ -0,0 +1,39 @@
+use uuid::Uuid;
+use crate::support::command_bus::Command;
+
+const NAME : &str = "rename_account";
+
+#[derive(Debug, Clone)]
+pub struct RenameAccountCommand {
+    account_id: Uuid,
+    user_id: Uuid,
+    account_name: String,
+}
+
+impl RenameAccountCommand {
+    pub fn new(account_id: Uuid, user_id: Uuid, account_name: String) -> Self {
+        Self {
+            account_id,
+            user_id,
+            account_name,
+        }
+    }
+
+    pub fn account_id(&self) -> &Uuid {
+        &self.account_id
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    pub fn account_name(&self) -> &str {
+        &self.account_name
+    }
+}
+
+impl Command for RenameAccountCommand {
+    fn name() -> &'static str {
+        NAME
+    }
+}
@@ -0,0 +1,97 @@
+use async_trait::async_trait;
+use crate::events::event::Event;
+use crate::features::accounts::application::commands::rename_account::command::RenameAccountCommand;
+use crate::features::accounts::domain::account_repository::AccountRepository;
+use crate::features::accounts::domain::error::DomainError;
+use crate::features::accounts::error::AccountError;
+use crate::support::command_bus::CommandHandler;
+use crate::support::error::FeatureError;
+
+pub struct RenameAccountCommandHandler<R>
+    where
+        R: AccountRepository + Send + Sync,
+{
+    account_repository: R,
+}
+
+impl<R> RenameAccountCommandHandler<R>
+    where
+        R: AccountRepository + Send + Sync,
+{
+    pub fn new(account_repository: R) -> Self {
+        Self {
+            account_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> CommandHandler<RenameAccountCommand> for RenameAccountCommandHandler<R>
+    where
+        R: AccountRepository + Send + Sync,
+{
+    async fn handle(&mut self, command: RenameAccountCommand) -> Result<Vec<Event>, FeatureError> {
+        let account = self.account_repository.load(*command.account_id())
+            .await
+            .map_err(FeatureError::Account)?
+            .ok_or(
+                FeatureError::Account(
+                    AccountError::Domain(
+                        DomainError::AccountNotFound(command.account_id().to_string())
+                    )
+                )
+            )?;
+
+        let event = account.aggregate().handle_rename(command)
+            .map_err(|e|
+                FeatureError::Account(
+                    AccountError::Domain(e)
+                )
+            )?;
+
+        self.account_repository.append(account.aggregate().id().value(), account.version(), std::slice::from_ref(&event))
+            .await
+            .map_err(FeatureError::Account)?;
+
+        Ok(
+            vec![Event::AccountEvent(event)]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+    use uuid::Uuid;
+    use crate::features::accounts::application::commands::create_account::command::CreateAccountCommand;
+    use crate::features::accounts::domain::account::Account;
+    use crate::features::accounts::domain::account_repository::MockAccountRepository;
+    use crate::features::accounts::domain::events::account_event::AccountEvent;
+    use crate::support::event_store::{Aggregate, Versioned};
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle_success() {
+        let account = account_fixture();
+        let command = RenameAccountCommand::new(account.aggregate().id().value(), account.aggregate().user_id().value(), "Wallet".to_string());
+
+        let events = RenameAccountCommandHandler::new(MockAccountRepository::with_accounts(vec![account])).handle(command).await.unwrap();
+
+        assert!(matches!(events[0], Event::AccountEvent(AccountEvent::AccountRenamed(_))));
+    }
+
+    #[tokio::test]
+    async fn test_handle_unknown_account() {
+        let command = RenameAccountCommand::new(Uuid::new_v4(), Uuid::new_v4(), "Wallet".to_string());
+
+        let result = RenameAccountCommandHandler::new(MockAccountRepository::new(false)).handle(command).await;
+
+        assert!(matches!(result, Err(FeatureError::Account(AccountError::Domain(DomainError::AccountNotFound(_))))));
+    }
+
+    fn account_fixture() -> Versioned<Account> {
+        let command = CreateAccountCommand::new(Uuid::new_v4(), "Cash".to_string(), "Cash".to_string(), "USD".to_string(), Decimal::ZERO);
+
+        Versioned::new(Account::apply(None, &Account::handle_creation(command).unwrap()).unwrap(), 1)
+    }
+}
@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use mockall::automock;
+use uuid::Uuid;
+use crate::features::accounts::application::queries::account_view::AccountView;
+use crate::features::accounts::domain::events::account_created::AccountCreated;
+use crate::features::accounts::domain::events::account_renamed::AccountRenamed;
+use crate::features::accounts::error::AccountError;
+
+#[async_trait]
+#[automock]
+pub trait AccountProjectionRepository {
+    async fn find(&self, user_id: Uuid) -> Result<Vec<AccountView>, AccountError>;
+
+    async fn find_by_id(&self, account_id: Uuid) -> Result<Option<AccountView>, AccountError>;
+
+    async fn apply_account_created(&self, event: &AccountCreated) -> Result<(), AccountError>;
+
+    async fn apply_account_renamed(&self, event: &AccountRenamed) -> Result<(), AccountError>;
+}
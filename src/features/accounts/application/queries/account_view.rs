@@ -0,0 +1,135 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Read model row of the `accounts` projection with the balance of its operations.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct AccountView {
+    id: Uuid,
+    user_id: Uuid,
+    name: String,
+    kind: String,
+    currency: String,
+    opening_balance: Decimal,
+    balance: Decimal,
+    created_at: DateTime<Utc>,
+}
+
+impl AccountView {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: Uuid,
+        user_id: Uuid,
+        name: String,
+        kind: String,
+        currency: String,
+        opening_balance: Decimal,
+        balance: Decimal,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            name,
+            kind,
+            currency,
+            opening_balance,
+            balance,
+            created_at,
+        }
+    }
+
+    pub fn id(&self) -> &Uuid {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn kind(&self) -> &str {
+        &self.kind
+    }
+
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    pub fn opening_balance(&self) -> Decimal {
+        self.opening_balance
+    }
+
+    /// Opening balance plus the effect of the account operations, in the account currency.
+    pub fn balance(&self) -> Decimal {
+        self.balance
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+}
+
+/// Sum of the balances of the user accounts kept in one currency.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CurrencyBalance {
+    currency: String,
+    balance: Decimal,
+}
+
+impl CurrencyBalance {
+    pub fn new(currency: String, balance: Decimal) -> Self {
+        Self {
+            currency,
+            balance,
+        }
+    }
+
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    pub fn balance(&self) -> Decimal {
+        self.balance
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountsOverview {
+    accounts: Vec<AccountView>,
+    totals: Vec<CurrencyBalance>,
+}
+
+impl AccountsOverview {
+    /// Sums the account balances per currency, the totals are ordered by currency code.
+    pub fn new(accounts: Vec<AccountView>) -> Self {
+        let mut totals: Vec<CurrencyBalance> = vec![];
+
+        for account in &accounts {
+            match totals.iter_mut().find(|total| total.currency == account.currency) {
+                Some(total) => total.balance += account.balance,
+                None => totals.push(CurrencyBalance::new(account.currency.clone(), account.balance)),
+            }
+        }
+
+        totals.sort_by(|a, b| a.currency.cmp(&b.currency));
+
+        Self {
+            accounts,
+            totals,
+        }
+    }
+
+    pub fn accounts(&self) -> &[AccountView] {
+        &self.accounts
+    }
+
+    pub fn totals(&self) -> &[CurrencyBalance] {
+        &self.totals
+    }
+}
@@ -0,0 +1,94 @@
+use async_trait::async_trait;
+use crate::features::accounts::application::queries::account_projection_repository::AccountProjectionRepository;
+use crate::features::accounts::application::queries::account_view::AccountsOverview;
+use crate::features::accounts::application::queries::list_accounts::query::ListAccountsQuery;
+use crate::support::error::FeatureError;
+use crate::support::query_bus::QueryHandler;
+
+pub struct ListAccountsQueryHandler<R>
+    where
+        R: AccountProjectionRepository + Send + Sync,
+{
+    rep: R,
+}
+
+impl<R> ListAccountsQueryHandler<R>
+    where
+        R: AccountProjectionRepository + Send + Sync,
+{
+    pub fn new(rep: R) -> Self {
+        Self {
+            rep,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> QueryHandler<ListAccountsQuery> for ListAccountsQueryHandler<R>
+    where
+        R: AccountProjectionRepository + Send + Sync,
+{
+    type Output = AccountsOverview;
+
+    async fn handle(&self, query: ListAccountsQuery) -> Result<AccountsOverview, FeatureError> {
+        let accounts = self.rep.find(*query.user_id())
+            .await
+            .map_err(FeatureError::Account)?;
+
+        Ok(AccountsOverview::new(accounts))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use futures_util::FutureExt;
+    use mockall::predicate::eq;
+    use rust_decimal::Decimal;
+    use uuid::Uuid;
+    use crate::features::accounts::application::queries::account_projection_repository::MockAccountProjectionRepository;
+    use crate::features::accounts::application::queries::account_view::{AccountView, CurrencyBalance};
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle_sums_balances_per_currency() {
+        let user_id = Uuid::new_v4();
+        let account = |name: &str, currency: &str, balance: Decimal| AccountView::new(
+            Uuid::new_v4(),
+            user_id,
+            name.to_string(),
+            "Cash".to_string(),
+            currency.to_string(),
+            Decimal::ZERO,
+            balance,
+            Utc::now(),
+        );
+        let accounts = vec![
+            account("Card", "USD", Decimal::new(1050, 2)),
+            account("Cash", "EUR", Decimal::from(20)),
+            account("Savings", "USD", Decimal::from(-3)),
+        ];
+
+        let mut rep = MockAccountProjectionRepository::new();
+        rep.expect_find()
+            .with(eq(user_id))
+            .returning(move |_| {
+                let accounts = accounts.clone();
+                async move { Ok(accounts) }.boxed()
+            });
+
+        let overview = ListAccountsQueryHandler::new(rep)
+            .handle(ListAccountsQuery::new(user_id))
+            .await
+            .unwrap();
+
+        assert_eq!(overview.accounts().len(), 3);
+        assert_eq!(
+            overview.totals(),
+            [
+                CurrencyBalance::new("EUR".to_string(), Decimal::from(20)),
+                CurrencyBalance::new("USD".to_string(), Decimal::new(750, 2)),
+            ]
+        );
+    }
+}
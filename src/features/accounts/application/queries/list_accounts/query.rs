@@ -0,0 +1,27 @@
+use uuid::Uuid;
+use crate::support::query_bus::Query;
+
+const NAME: &str = "list_accounts";
+
+#[derive(Debug, Clone)]
+pub struct ListAccountsQuery {
+    user_id: Uuid,
+}
+
+impl ListAccountsQuery {
+    pub fn new(user_id: Uuid) -> Self {
+        Self {
+            user_id,
+        }
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+}
+
+impl Query for ListAccountsQuery {
+    fn name() -> &'static str {
+        NAME
+    }
+}
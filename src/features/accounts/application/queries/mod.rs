@@ -0,0 +1,3 @@
+pub mod account_projection_repository;
+pub mod account_view;
+pub mod list_accounts;
\ No newline at end of file
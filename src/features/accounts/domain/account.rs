@@ -0,0 +1,200 @@
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use crate::features::accounts::application::commands::create_account::command::CreateAccountCommand;
+use crate::features::accounts::application::commands::rename_account::command::RenameAccountCommand;
+use crate::features::accounts::domain::account_kind::AccountKind;
+use crate::features::accounts::domain::error::DomainError;
+use crate::features::accounts::domain::events::account_created::AccountCreated;
+use crate::features::accounts::domain::events::account_event::AccountEvent;
+use crate::features::accounts::domain::events::account_renamed::AccountRenamed;
+use crate::support::currency::Currency;
+use crate::support::event_store::{Aggregate, EventStoreError};
+use crate::support::id::Id;
+use crate::support::money::round_money;
+
+/// Place where the user keeps money in a single currency: a wallet, a card, a savings account.
+#[derive(Debug, Clone)]
+pub struct Account {
+    id: Id,
+    user_id: Id,
+    name: String,
+    kind: AccountKind,
+    currency: Currency,
+    opening_balance: Decimal,
+}
+
+impl Account {
+    pub fn handle_creation(command: CreateAccountCommand) -> Result<AccountEvent, DomainError> {
+        let name = Self::checked_name(command.account_name())?;
+        let kind = AccountKind::new(command.kind())?;
+        let currency = Currency::find(command.currency())
+            .ok_or_else(|| DomainError::UnknownCurrency(command.currency().to_string()))?;
+
+        Ok(
+            AccountEvent::AccountCreated(
+                AccountCreated::new(
+                    Id::new(Id::generate()),
+                    Id::new(Id::generate()),
+                    Id::new(*command.user_id()),
+                    name,
+                    kind,
+                    currency,
+                    round_money(command.opening_balance(), currency.minor_units()),
+                )
+            )
+        )
+    }
+
+    pub fn handle_rename(&self, command: RenameAccountCommand) -> Result<AccountEvent, DomainError> {
+        self.check_access(command.user_id())?;
+
+        Ok(
+            AccountEvent::AccountRenamed(
+                AccountRenamed::new(
+                    Id::new(Id::generate()),
+                    self.id.clone(),
+                    self.user_id.clone(),
+                    Self::checked_name(command.account_name())?,
+                )
+            )
+        )
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Id {
+        &self.user_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn kind(&self) -> &AccountKind {
+        &self.kind
+    }
+
+    pub fn currency(&self) -> &Currency {
+        &self.currency
+    }
+
+    pub fn opening_balance(&self) -> Decimal {
+        self.opening_balance
+    }
+
+    fn checked_name(name: &str) -> Result<String, DomainError> {
+        let name = name.trim();
+
+        if name.is_empty() {
+            return Err(DomainError::EmptyName);
+        }
+
+        Ok(name.to_string())
+    }
+
+    fn check_access(&self, user_id: &Uuid) -> Result<(), DomainError> {
+        if self.user_id.value() != *user_id {
+            return Err(DomainError::AccessDenied);
+        }
+
+        Ok(())
+    }
+}
+
+impl Aggregate for Account {
+    type Event = AccountEvent;
+
+    fn apply(state: Option<Self>, event: &AccountEvent) -> Result<Self, EventStoreError> {
+        match (state, event) {
+            (None, AccountEvent::AccountCreated(account_created)) => {
+                let payload = account_created.payload();
+
+                Ok(
+                    Self {
+                        id: payload.id().clone(),
+                        user_id: payload.user_id().clone(),
+                        name: payload.name().to_string(),
+                        kind: payload.kind().clone(),
+                        currency: *payload.currency(),
+                        opening_balance: payload.opening_balance(),
+                    }
+                )
+            }
+            (Some(account), AccountEvent::AccountCreated(_)) => Err(
+                EventStoreError::Rehydration(
+                    format!("Account {} is already created", account.id().value())
+                )
+            ),
+            (Some(account), AccountEvent::AccountRenamed(account_renamed)) => Ok(
+                Self {
+                    name: account_renamed.payload().name().to_string(),
+                    ..account
+                }
+            ),
+            (None, event) => Err(
+                EventStoreError::Rehydration(
+                    format!("Account stream must start with account_created, got {}", event.name())
+                )
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_handle_creation() {
+        let command = CreateAccountCommand::new(Uuid::new_v4(), " Card ".to_string(), "Card".to_string(), "EUR".to_string(), Decimal::new(100505, 3));
+
+        let event = Account::handle_creation(command.clone()).unwrap();
+        let account = Account::apply(None, &event).unwrap();
+
+        assert_eq!(account.user_id().value(), *command.user_id());
+        assert_eq!(account.name(), "Card");
+        assert_eq!(account.kind(), &AccountKind::Card);
+        assert_eq!(account.currency().code(), "EUR");
+        assert_eq!(account.opening_balance(), Decimal::new(10051, 2));
+    }
+
+    #[test]
+    fn test_handle_creation_with_invalid_data() {
+        let user_id = Uuid::new_v4();
+
+        let empty_name = CreateAccountCommand::new(user_id, " ".to_string(), "Cash".to_string(), "USD".to_string(), Decimal::ZERO);
+        let unknown_kind = CreateAccountCommand::new(user_id, "Cash".to_string(), "Crypto".to_string(), "USD".to_string(), Decimal::ZERO);
+        let unknown_currency = CreateAccountCommand::new(user_id, "Cash".to_string(), "Cash".to_string(), "XXX".to_string(), Decimal::ZERO);
+
+        assert!(matches!(Account::handle_creation(empty_name), Err(DomainError::EmptyName)));
+        assert!(matches!(Account::handle_creation(unknown_kind), Err(DomainError::UnknownAccountKind(_))));
+        assert!(matches!(Account::handle_creation(unknown_currency), Err(DomainError::UnknownCurrency(_))));
+    }
+
+    #[test]
+    fn test_handle_rename() {
+        let account = account_fixture();
+        let command = RenameAccountCommand::new(account.id().value(), account.user_id().value(), "Wallet".to_string());
+
+        let event = account.handle_rename(command).unwrap();
+        let renamed = Account::apply(Some(account), &event).unwrap();
+
+        assert_eq!(renamed.name(), "Wallet");
+    }
+
+    #[test]
+    fn test_handle_rename_by_another_user() {
+        let account = account_fixture();
+        let command = RenameAccountCommand::new(account.id().value(), Uuid::new_v4(), "Wallet".to_string());
+
+        assert!(matches!(account.handle_rename(command), Err(DomainError::AccessDenied)));
+    }
+
+    fn account_fixture() -> Account {
+        let command = CreateAccountCommand::new(Uuid::new_v4(), "Cash".to_string(), "Cash".to_string(), "USD".to_string(), Decimal::ZERO);
+
+        Account::apply(None, &Account::handle_creation(command).unwrap()).unwrap()
+    }
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+use crate::features::accounts::domain::error::DomainError;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AccountKind {
+    Cash,
+    Card,
+    Savings,
+    Other,
+}
+
+impl AccountKind {
+    pub fn new(value: &str) -> Result<Self, DomainError> {
+        match value {
+            "Cash" => Ok(Self::Cash),
+            "Card" => Ok(Self::Card),
+            "Savings" => Ok(Self::Savings),
+            "Other" => Ok(Self::Other),
+            _ => Err(DomainError::UnknownAccountKind(value.to_string())),
+        }
+    }
+
+    pub fn to_str(&self) -> &str {
+        match self {
+            Self::Cash => "Cash",
+            Self::Card => "Card",
+            Self::Savings => "Savings",
+            Self::Other => "Other",
+        }
+    }
+}
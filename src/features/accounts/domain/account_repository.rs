@@ -0,0 +1,58 @@
+use std::collections::HashMap;
+use async_trait::async_trait;
+use uuid::Uuid;
+use crate::features::accounts::domain::account::Account;
+use crate::features::accounts::domain::events::account_event::AccountEvent;
+use crate::features::accounts::error::AccountError;
+use crate::features::accounts::infrastructure::error::InfrastructureError;
+use crate::support::event_store::Versioned;
+
+#[async_trait]
+pub trait AccountRepository {
+    async fn load(&self, account_id: Uuid) -> Result<Option<Versioned<Account>>, AccountError>;
+
+    /// Fails with `InfrastructureError::Conflict` when the stream is no longer at `expected_version`.
+    async fn append(&self, account_id: Uuid, expected_version: i32, events: &[AccountEvent]) -> Result<i32, AccountError>;
+}
+
+pub struct MockAccountRepository {
+    has_error: bool,
+    accounts: HashMap<Uuid, Versioned<Account>>,
+}
+
+impl MockAccountRepository {
+    pub fn new(has_error: bool) -> Self {
+        Self {
+            has_error,
+            accounts: HashMap::new(),
+        }
+    }
+
+    pub fn with_accounts(accounts: Vec<Versioned<Account>>) -> Self {
+        Self {
+            has_error: false,
+            accounts: accounts.into_iter()
+                .map(|account| (account.aggregate().id().value(), account))
+                .collect(),
+        }
+    }
+}
+
+#[async_trait]
+impl AccountRepository for MockAccountRepository {
+    async fn load(&self, account_id: Uuid) -> Result<Option<Versioned<Account>>, AccountError> {
+        Ok(self.accounts.get(&account_id).cloned())
+    }
+
+    async fn append(&self, _account_id: Uuid, expected_version: i32, events: &[AccountEvent]) -> Result<i32, AccountError> {
+        if self.has_error {
+            return Err(
+                AccountError::Infrastructure(
+                    InfrastructureError::Repository("Mock repository error".into())
+                )
+            );
+        }
+
+        Ok(expected_version + events.len() as i32)
+    }
+}
@@ -0,0 +1,19 @@
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone)]
+pub enum DomainError {
+    #[error("Account {0} not found")]
+    AccountNotFound(String),
+
+    #[error("Account belongs to another user")]
+    AccessDenied,
+
+    #[error("Account name cannot be empty")]
+    EmptyName,
+
+    #[error("Unknown account kind {0}")]
+    UnknownAccountKind(String),
+
+    #[error("Unknown currency {0}")]
+    UnknownCurrency(String),
+}
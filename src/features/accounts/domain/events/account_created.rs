@@ -0,0 +1,79 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use crate::features::accounts::domain::account_kind::AccountKind;
+use crate::support::currency::Currency;
+use crate::support::id::Id;
+
+pub const ACCOUNT_CREATED_NAME: &str = "account_created";
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AccountCreated {
+    id: Id,
+    name: String,
+    payload: AccountCreatedPayload,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AccountCreatedPayload {
+    id: Id,
+    user_id: Id,
+    name: String,
+    kind: AccountKind,
+    currency: Currency,
+    opening_balance: Decimal,
+}
+
+impl AccountCreated {
+    pub fn new(id: Id, account_id: Id, user_id: Id, account_name: String, kind: AccountKind, currency: Currency, opening_balance: Decimal) -> Self {
+        Self {
+            id,
+            name: ACCOUNT_CREATED_NAME.to_string(),
+            payload: AccountCreatedPayload {
+                id: account_id,
+                user_id,
+                name: account_name,
+                kind,
+                currency,
+                opening_balance,
+            }
+        }
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn payload(&self) -> &AccountCreatedPayload {
+        &self.payload
+    }
+}
+
+impl AccountCreatedPayload {
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Id {
+        &self.user_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn kind(&self) -> &AccountKind {
+        &self.kind
+    }
+
+    pub fn currency(&self) -> &Currency {
+        &self.currency
+    }
+
+    pub fn opening_balance(&self) -> Decimal {
+        self.opening_balance
+    }
+}
@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+use crate::features::accounts::domain::events::account_created::{ACCOUNT_CREATED_NAME, AccountCreated};
+use crate::features::accounts::domain::events::account_renamed::{ACCOUNT_RENAMED_NAME, AccountRenamed};
+use crate::support::event_store::{decode_event, EventStoreError, NewEvent, StorableEvent, StoredEvent};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum AccountEvent {
+    AccountCreated(AccountCreated),
+    AccountRenamed(AccountRenamed),
+}
+
+impl AccountEvent {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::AccountCreated(event) => event.name(),
+            Self::AccountRenamed(event) => event.name(),
+        }
+    }
+}
+
+impl StorableEvent for AccountEvent {
+    fn from_stored(stored: &StoredEvent) -> Result<Self, EventStoreError> {
+        match stored.name() {
+            ACCOUNT_CREATED_NAME => Ok(Self::AccountCreated(decode_event(stored)?)),
+            ACCOUNT_RENAMED_NAME => Ok(Self::AccountRenamed(decode_event(stored)?)),
+            name => Err(
+                EventStoreError::Serialization(format!("Unknown account event {}", name))
+            ),
+        }
+    }
+
+    fn to_new(&self) -> Result<NewEvent, EventStoreError> {
+        match self {
+            Self::AccountCreated(event) => NewEvent::from_event(event),
+            Self::AccountRenamed(event) => NewEvent::from_event(event),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+use crate::support::id::Id;
+
+pub const ACCOUNT_RENAMED_NAME: &str = "account_renamed";
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AccountRenamed {
+    id: Id,
+    name: String,
+    payload: AccountRenamedPayload,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AccountRenamedPayload {
+    id: Id,
+    user_id: Id,
+    name: String,
+}
+
+impl AccountRenamed {
+    pub fn new(id: Id, account_id: Id, user_id: Id, account_name: String) -> Self {
+        Self {
+            id,
+            name: ACCOUNT_RENAMED_NAME.to_string(),
+            payload: AccountRenamedPayload {
+                id: account_id,
+                user_id,
+                name: account_name,
+            }
+        }
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn payload(&self) -> &AccountRenamedPayload {
+        &self.payload
+    }
+}
+
+impl AccountRenamedPayload {
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Id {
+        &self.user_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
@@ -0,0 +1,3 @@
+pub mod account_created;
+pub mod account_event;
+pub mod account_renamed;
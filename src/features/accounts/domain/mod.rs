@@ -0,0 +1,5 @@
+pub mod account;
+pub mod account_kind;
+pub mod account_repository;
+pub mod events;
+pub mod error;
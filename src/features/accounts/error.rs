@@ -0,0 +1,12 @@
+use thiserror::Error;
+use crate::features::accounts::domain::error::DomainError;
+use crate::features::accounts::infrastructure::error::InfrastructureError;
+
+#[derive(Error, Debug, Clone)]
+pub enum AccountError {
+    #[error("Account domain error: {0}")]
+    Domain(DomainError),
+
+    #[error("Account infrastructure error: {0}")]
+    Infrastructure(InfrastructureError)
+}
@@ -0,0 +1,155 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use sqlx::{query, query_as, Pool, Postgres};
+use sqlx::postgres::PgArguments;
+use sqlx::query::Query;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+use crate::db::manager::DbManager;
+use crate::features::accounts::application::queries::account_projection_repository::AccountProjectionRepository;
+use crate::features::accounts::application::queries::account_view::AccountView;
+use crate::features::accounts::domain::events::account_created::AccountCreated;
+use crate::features::accounts::domain::events::account_renamed::AccountRenamed;
+use crate::features::accounts::error::AccountError;
+use crate::features::accounts::infrastructure::error::InfrastructureError;
+
+/// Accounts with their balances. Operations change the balance of their account in the direction of `Kind::sign`,
+/// a transfer also credits the account it is sent to.
+const SELECT_ACCOUNTS: &str = "
+    SELECT a.id,
+           a.user_id,
+           a.name,
+           a.kind,
+           a.currency,
+           a.opening_balance,
+           a.opening_balance
+               + COALESCE(
+                   (
+                       SELECT SUM(
+                           CASE
+                               WHEN o.kind IN ('Income', 'Credit') THEN o.currency_amount
+                               WHEN o.kind IN ('Expense', 'Debt') THEN -o.currency_amount
+                               -- Transfers recorded before they had a destination do not move money
+                               WHEN o.kind = 'Transfer' AND o.transfer_account_id IS NOT NULL THEN -o.currency_amount
+                               ELSE 0
+                           END
+                       )
+                       FROM operations o
+                       WHERE o.account_id = a.id
+                   ),
+                   0
+               )
+               + COALESCE(
+                   (
+                       SELECT SUM(t.transfer_amount)
+                       FROM operations t
+                       WHERE t.transfer_account_id = a.id
+                   ),
+                   0
+               ) AS balance,
+           a.created_at
+    FROM accounts a
+";
+
+#[derive(Clone)]
+pub struct DbAccountProjectionRepository {
+    db_manager: Arc<Mutex<DbManager>>,
+}
+
+impl DbAccountProjectionRepository {
+    pub fn new(db_manager: Arc<Mutex<DbManager>>) -> Self {
+        Self {
+            db_manager,
+        }
+    }
+
+    async fn pool(&self) -> Result<Pool<Postgres>, AccountError> {
+        let guard = self.db_manager.lock().await;
+
+        guard.pool()
+            .map_err(|e|
+                AccountError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to get pool: {}", e)
+                    )
+                )
+            )
+    }
+
+    async fn execute(&self, res_query: Query<'_, Postgres, PgArguments>) -> Result<(), AccountError> {
+        let pool = self.pool().await?;
+
+        res_query.execute(&pool)
+            .await
+            .map_err(|e|
+                AccountError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to project account: {}", e)
+                    )
+                )
+            )?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl AccountProjectionRepository for DbAccountProjectionRepository {
+    async fn find(&self, user_id: Uuid) -> Result<Vec<AccountView>, AccountError> {
+        let q = format!("{} WHERE a.user_id = $1 ORDER BY a.name, a.id", SELECT_ACCOUNTS);
+
+        let pool = self.pool().await?;
+
+        query_as::<_, AccountView>(&q)
+            .bind(user_id)
+            .fetch_all(&pool)
+            .await
+            .map_err(|e|
+                AccountError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to fetch accounts: {}", e)
+                    )
+                )
+            )
+    }
+
+    async fn find_by_id(&self, account_id: Uuid) -> Result<Option<AccountView>, AccountError> {
+        let q = format!("{} WHERE a.id = $1", SELECT_ACCOUNTS);
+
+        let pool = self.pool().await?;
+
+        query_as::<_, AccountView>(&q)
+            .bind(account_id)
+            .fetch_optional(&pool)
+            .await
+            .map_err(|e|
+                AccountError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to fetch account {}: {}", account_id, e)
+                    )
+                )
+            )
+    }
+
+    async fn apply_account_created(&self, event: &AccountCreated) -> Result<(), AccountError> {
+        let payload = event.payload();
+
+        self.execute(
+            query("INSERT INTO accounts (id, user_id, name, kind, currency, opening_balance) VALUES ($1, $2, $3, $4, $5, $6) ON CONFLICT DO NOTHING")
+                .bind(payload.id().value())
+                .bind(payload.user_id().value())
+                .bind(payload.name().to_string())
+                .bind(payload.kind().to_str().to_string())
+                .bind(payload.currency().code())
+                .bind(payload.opening_balance())
+        ).await
+    }
+
+    async fn apply_account_renamed(&self, event: &AccountRenamed) -> Result<(), AccountError> {
+        self.execute(
+            query("UPDATE accounts SET name = $2 WHERE id = $1")
+                .bind(event.payload().id().value())
+                .bind(event.payload().name().to_string())
+        ).await
+    }
+}
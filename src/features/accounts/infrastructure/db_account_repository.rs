@@ -0,0 +1,64 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+use crate::db::manager::DbManager;
+use crate::features::accounts::domain::account::Account;
+use crate::features::accounts::domain::account_repository::AccountRepository;
+use crate::features::accounts::domain::events::account_event::AccountEvent;
+use crate::features::accounts::error::AccountError;
+use crate::features::accounts::infrastructure::error::InfrastructureError;
+use crate::support::event_store::{Aggregate, EventStore, StorableEvent, Versioned};
+use crate::support::pg_event_store::PgEventStore;
+
+const EVENTS_TABLE: &str = "account_events";
+
+#[derive(Clone)]
+pub struct DbAccountRepository {
+    event_store: PgEventStore,
+}
+
+impl DbAccountRepository {
+    pub fn new(db_manager: Arc<Mutex<DbManager>>) -> Self {
+        Self {
+            event_store: PgEventStore::new(db_manager, EVENTS_TABLE),
+        }
+    }
+}
+
+#[async_trait]
+impl AccountRepository for DbAccountRepository {
+    async fn load(&self, account_id: Uuid) -> Result<Option<Versioned<Account>>, AccountError> {
+        let stream = self.event_store.load(account_id)
+            .await
+            .map_err(|e|
+                AccountError::Infrastructure(
+                    InfrastructureError::Repository(e.to_string())
+                )
+            )?;
+
+        Account::rehydrate(&stream)
+            .map_err(|e|
+                AccountError::Infrastructure(
+                    InfrastructureError::Repository(e.to_string())
+                )
+            )
+    }
+
+    async fn append(&self, account_id: Uuid, expected_version: i32, events: &[AccountEvent]) -> Result<i32, AccountError> {
+        let events = events.iter()
+            .map(|event| event.to_new())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e|
+                AccountError::Infrastructure(
+                    InfrastructureError::Repository(e.to_string())
+                )
+            )?;
+
+        self.event_store.append(account_id, expected_version, &events)
+            .await
+            .map_err(|e|
+                AccountError::Infrastructure(e.into())
+            )
+    }
+}
@@ -0,0 +1,65 @@
+use async_trait::async_trait;
+use crate::events::error::EventError;
+use crate::events::event::Event;
+use crate::events::event_listener::EventListener;
+use crate::features::accounts::application::queries::account_projection_repository::AccountProjectionRepository;
+use crate::features::accounts::domain::events::account_event::AccountEvent;
+use crate::support::error::FeatureError;
+
+/// Keeps the `accounts` read model in sync with the account event stream.
+/// One instance is registered per account event name.
+pub struct AccountProjectionListener<R>
+    where
+        R: AccountProjectionRepository + Send + Sync + 'static,
+{
+    rep: R,
+    event_name: &'static str,
+}
+
+#[async_trait]
+impl<R> EventListener for AccountProjectionListener<R>
+    where
+        R: AccountProjectionRepository + Send + Sync + 'static,
+{
+    async fn on_event(&mut self, event: Event) -> Result<Vec<Event>, EventError> {
+        let res = match self.parse_event(event)? {
+            AccountEvent::AccountCreated(event) => self.rep.apply_account_created(&event).await,
+            AccountEvent::AccountRenamed(event) => self.rep.apply_account_renamed(&event).await,
+        };
+
+        res.map_err(|e|
+            EventError::Feature(
+                FeatureError::Account(e)
+            )
+        )?;
+
+        Ok(vec![])
+    }
+
+    fn event_name(&self) -> &str {
+        self.event_name
+    }
+}
+
+impl<R> AccountProjectionListener<R>
+    where
+        R: AccountProjectionRepository + Send + Sync + 'static,
+{
+    pub fn new(rep: R, event_name: &'static str) -> Self {
+        Self {
+            rep,
+            event_name,
+        }
+    }
+
+    pub fn parse_event(&self, event: Event) -> Result<AccountEvent, EventError> {
+        match event {
+            Event::AccountEvent(account_event) => Ok(account_event),
+            _ => Err(
+                EventError::Parsing(
+                    format!("Invalid event type. Expected AccountEvent, got {:?}", event)
+                )
+            )
+        }
+    }
+}
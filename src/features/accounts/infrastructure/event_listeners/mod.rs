@@ -0,0 +1 @@
+pub mod account_projection_listener;
\ No newline at end of file
@@ -0,0 +1,4 @@
+pub mod db_account_projection_repository;
+pub mod db_account_repository;
+pub mod error;
+pub mod event_listeners;
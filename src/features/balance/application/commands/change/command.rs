@@ -1,3 +1,4 @@
+use rust_decimal::Decimal;
 use uuid::Uuid;
 use crate::support::command_bus::Command;
 
@@ -6,28 +7,46 @@ const NAME: &str = "change_command";
 #[derive(Clone, Debug)]
 pub struct ChangeCommand {
     user_id: Uuid,
-    amount: f64,
+    account_id: Uuid,
+    amount: Decimal,
     currency: String,
-    currency_amount: f64,
-    rate: f64,
+    currency_amount: Decimal,
+    rate: Decimal,
+    operation_event_id: Option<Uuid>,
+    reverted: bool,
 }
 
 impl ChangeCommand {
-    pub fn new(user_id: Uuid, amount: f64, currency: String, currency_amount: f64, rate: f64) -> Self {
+    pub fn new(user_id: Uuid, account_id: Uuid, amount: Decimal, currency: String, currency_amount: Decimal, rate: Decimal) -> Self {
         Self {
             user_id,
+            account_id,
             amount,
             currency,
             currency_amount,
             rate,
+            operation_event_id: None,
+            reverted: false,
         }
     }
 
+    /// Operation event the change comes from, `reverted` when it undoes the previous state of the operation.
+    /// A change is applied once per operation event, account and direction.
+    pub fn with_operation_event(mut self, operation_event_id: Uuid, reverted: bool) -> Self {
+        self.operation_event_id = Some(operation_event_id);
+        self.reverted = reverted;
+        self
+    }
+
     pub fn user_id(&self) -> Uuid {
         self.user_id
     }
 
-    pub fn amount(&self) -> f64 {
+    pub fn account_id(&self) -> Uuid {
+        self.account_id
+    }
+
+    pub fn amount(&self) -> Decimal {
         self.amount
     }
 
@@ -35,13 +54,21 @@ impl ChangeCommand {
         &self.currency
     }
 
-    pub fn currency_amount(&self) -> f64 {
+    pub fn currency_amount(&self) -> Decimal {
         self.currency_amount
     }
 
-    pub fn rate(&self) -> f64 {
+    pub fn rate(&self) -> Decimal {
         self.rate
     }
+
+    pub fn operation_event_id(&self) -> Option<Uuid> {
+        self.operation_event_id
+    }
+
+    pub fn reverted(&self) -> bool {
+        self.reverted
+    }
 }
 
 impl Command for ChangeCommand {
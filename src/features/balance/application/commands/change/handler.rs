@@ -47,6 +47,7 @@ impl<R> CommandHandler<ChangeCommand> for ChangeCommandHandler<R>
 
 #[cfg(test)]
 mod tests {
+    use rust_decimal::Decimal;
     use crate::features::balance::domain::balance_repository::MockBalanceRepository;
     use crate::support::id::Id;
     use super::*;
@@ -76,10 +77,11 @@ mod tests {
     fn create_command_fixture() -> ChangeCommand {
         ChangeCommand::new(
             Id::generate(),
-            100.0,
+            Id::generate(),
+            Decimal::from(100),
             "USD".to_string(),
-            100.0,
-            1.0,
+            Decimal::from(100),
+            Decimal::ONE,
         )
     }
 }
\ No newline at end of file
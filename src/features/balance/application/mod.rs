@@ -1 +1,2 @@
-pub mod commands;
\ No newline at end of file
+pub mod commands;
+pub mod queries;
@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+use mockall::automock;
+use uuid::Uuid;
+use crate::features::balance::application::queries::balance_view::BalanceView;
+use crate::features::balance::error::BalanceError;
+
+#[async_trait]
+#[automock]
+pub trait BalanceProjectionRepository {
+    async fn find(&self, user_id: Uuid) -> Result<Vec<BalanceView>, BalanceError>;
+}
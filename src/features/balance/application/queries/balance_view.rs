@@ -0,0 +1,105 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use crate::support::money::{round_money, DEFAULT_MINOR_UNITS};
+
+/// Balance of one account: its opening balance and the net effect of its operations from the `balances` projection.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct BalanceView {
+    account_id: Uuid,
+    user_id: Uuid,
+    currency: String,
+    amount: Decimal,
+    currency_amount: Decimal,
+    opening_balance: Decimal,
+    updated_at: DateTime<Utc>,
+}
+
+impl BalanceView {
+    pub fn new(
+        account_id: Uuid,
+        user_id: Uuid,
+        currency: String,
+        amount: Decimal,
+        currency_amount: Decimal,
+        opening_balance: Decimal,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            account_id,
+            user_id,
+            currency,
+            amount,
+            currency_amount,
+            opening_balance,
+            updated_at,
+        }
+    }
+
+    /// Adds the opening balance to `amount`, worth `rate` in the base currency per unit of the account currency.
+    pub fn with_opening_balance_at(mut self, rate: Decimal) -> Self {
+        self.amount += round_money(self.opening_balance * rate, DEFAULT_MINOR_UNITS);
+        self
+    }
+
+    pub fn account_id(&self) -> &Uuid {
+        &self.account_id
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    /// Net effect in the base currency, at the rates of the operations.
+    /// The opening balance is only included once converted with `with_opening_balance_at`.
+    pub fn amount(&self) -> Decimal {
+        self.amount
+    }
+
+    /// Balance in the account currency, opening balance included, as listed with the accounts.
+    pub fn currency_amount(&self) -> Decimal {
+        self.currency_amount
+    }
+
+    pub fn opening_balance(&self) -> Decimal {
+        self.opening_balance
+    }
+
+    pub fn updated_at(&self) -> &DateTime<Utc> {
+        &self.updated_at
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BalanceOverview {
+    balances: Vec<BalanceView>,
+    total: Decimal,
+}
+
+impl BalanceOverview {
+    /// Sums the balances in the base currency, the only one they all share.
+    pub fn new(balances: Vec<BalanceView>) -> Self {
+        let total = balances.iter()
+            .map(|balance| balance.amount)
+            .sum();
+
+        Self {
+            balances,
+            total,
+        }
+    }
+
+    pub fn balances(&self) -> &[BalanceView] {
+        &self.balances
+    }
+
+    pub fn total(&self) -> Decimal {
+        self.total
+    }
+}
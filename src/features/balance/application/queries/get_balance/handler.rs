@@ -0,0 +1,110 @@
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use crate::features::balance::application::queries::balance_projection_repository::BalanceProjectionRepository;
+use crate::features::balance::application::queries::balance_view::BalanceOverview;
+use crate::features::balance::application::queries::get_balance::query::GetBalanceQuery;
+use crate::features::rates::domain::rate_source::RateSource;
+use crate::support::error::FeatureError;
+use crate::support::query_bus::QueryHandler;
+
+pub struct GetBalanceQueryHandler<R, S>
+    where
+        R: BalanceProjectionRepository + Send + Sync,
+        S: RateSource + Send + Sync,
+{
+    rep: R,
+    rates: S,
+}
+
+impl<R, S> GetBalanceQueryHandler<R, S>
+    where
+        R: BalanceProjectionRepository + Send + Sync,
+        S: RateSource + Send + Sync,
+{
+    pub fn new(rep: R, rates: S) -> Self {
+        Self {
+            rep,
+            rates,
+        }
+    }
+}
+
+#[async_trait]
+impl<R, S> QueryHandler<GetBalanceQuery> for GetBalanceQueryHandler<R, S>
+    where
+        R: BalanceProjectionRepository + Send + Sync,
+        S: RateSource + Send + Sync,
+{
+    type Output = BalanceOverview;
+
+    async fn handle(&self, query: GetBalanceQuery) -> Result<BalanceOverview, FeatureError> {
+        let mut balances = vec![];
+
+        for balance in self.rep.find(*query.user_id()).await.map_err(FeatureError::Balance)? {
+            // Opening balances have no operation rate, they are worth what they are on the day of the query
+            let rate = if balance.opening_balance().is_zero() {
+                Decimal::ZERO
+            } else {
+                self.rates.rate(balance.currency(), *query.date()).await?
+            };
+
+            balances.push(balance.with_opening_balance_at(rate));
+        }
+
+        Ok(BalanceOverview::new(balances))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, Utc};
+    use futures_util::FutureExt;
+    use mockall::predicate::eq;
+    use uuid::Uuid;
+    use crate::features::balance::application::queries::balance_projection_repository::MockBalanceProjectionRepository;
+    use crate::features::balance::application::queries::balance_view::BalanceView;
+    use crate::features::rates::domain::rate_source::MockRateSource;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle_sums_balances_in_base_currency() {
+        let user_id = Uuid::new_v4();
+        let date = NaiveDate::from_ymd_opt(2024, 4, 26).unwrap();
+        let balance = |currency: &str, amount: Decimal, currency_amount: Decimal, opening_balance: Decimal| BalanceView::new(
+            Uuid::new_v4(),
+            user_id,
+            currency.to_string(),
+            amount,
+            currency_amount,
+            opening_balance,
+            Utc::now(),
+        );
+        let balances = vec![
+            balance("USD", Decimal::new(1050, 2), Decimal::new(1050, 2), Decimal::ZERO),
+            balance("EUR", Decimal::new(-216, 1), Decimal::from(80), Decimal::from(100)),
+        ];
+
+        let mut rep = MockBalanceProjectionRepository::new();
+        rep.expect_find()
+            .with(eq(user_id))
+            .returning(move |_| {
+                let balances = balances.clone();
+                async move { Ok(balances) }.boxed()
+            });
+
+        let mut rates = MockRateSource::new();
+        rates.expect_rate()
+            .with(eq("EUR"), eq(date))
+            .times(1)
+            .returning(|_, _| async { Ok(Decimal::new(11, 1)) }.boxed());
+
+        let overview = GetBalanceQueryHandler::new(rep, rates)
+            .handle(GetBalanceQuery::new(user_id, date))
+            .await
+            .unwrap();
+
+        assert_eq!(overview.balances().len(), 2);
+        assert_eq!(overview.balances()[1].amount(), Decimal::new(884, 1));
+        assert_eq!(overview.total(), Decimal::new(9890, 2));
+    }
+}
@@ -0,0 +1,35 @@
+use chrono::NaiveDate;
+use uuid::Uuid;
+use crate::support::query_bus::Query;
+
+const NAME: &str = "get_balance";
+
+#[derive(Debug, Clone)]
+pub struct GetBalanceQuery {
+    user_id: Uuid,
+    date: NaiveDate,
+}
+
+impl GetBalanceQuery {
+    pub fn new(user_id: Uuid, date: NaiveDate) -> Self {
+        Self {
+            user_id,
+            date,
+        }
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    /// Day of the rates opening balances are converted at.
+    pub fn date(&self) -> &NaiveDate {
+        &self.date
+    }
+}
+
+impl Query for GetBalanceQuery {
+    fn name() -> &'static str {
+        NAME
+    }
+}
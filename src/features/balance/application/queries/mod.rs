@@ -0,0 +1,3 @@
+pub mod balance_projection_repository;
+pub mod balance_view;
+pub mod get_balance;
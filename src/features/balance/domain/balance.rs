@@ -1,38 +1,55 @@
+use rust_decimal::Decimal;
 use crate::features::balance::application::commands::change::command::ChangeCommand;
-use crate::features::balance::domain::currency::Currency;
 use crate::features::balance::domain::error::DomainError;
 use crate::features::balance::domain::events::balance_changed::BalanceChanged;
 use crate::features::balance::domain::events::balance_event::BalanceEvent;
 use crate::features::balance::domain::rate::Rate;
+use crate::support::currency::Currency;
 use crate::support::id::Id;
+use crate::support::money::{is_conversion_of, round_money, DEFAULT_MINOR_UNITS};
 
+/// Change of the balance of one account of the user.
 pub struct Balance {
     user_id: Id,
-    amount: f64,
+    account_id: Id,
+    amount: Decimal,
     currency: Currency,
-    currency_amount: f64,
+    currency_amount: Decimal,
     rate: Rate,
 }
 
 impl Balance {
     pub fn handle_change(command: ChangeCommand) -> Result<BalanceEvent, DomainError> {
+        let currency = Currency::find(command.currency())
+            .ok_or_else(|| DomainError::UnknownCurrency(command.currency().to_string()))?;
         let balance = Balance {
             user_id: Id::new(command.user_id()),
-            amount: command.amount(),
-            currency: Currency::new(command.currency())?,
-            currency_amount: command.currency_amount(),
+            account_id: Id::new(command.account_id()),
+            amount: round_money(command.amount(), DEFAULT_MINOR_UNITS),
+            currency_amount: round_money(command.currency_amount(), currency.minor_units()),
+            currency,
             rate: Rate::new(command.rate())?,
         };
 
+        if !is_conversion_of(balance.amount, DEFAULT_MINOR_UNITS, balance.currency_amount, balance.currency.minor_units(), balance.rate.rate()) {
+            return Err(
+                DomainError::InvalidAmount(
+                    format!("Amount {} does not match currency amount {} at rate {}", balance.amount, balance.currency_amount, balance.rate.rate())
+                )
+            );
+        }
+
         let event = BalanceEvent::BalanceChanged(
             BalanceChanged::new(
                 Id::new(Id::generate()),
                 balance.user_id,
+                balance.account_id,
                 balance.amount,
                 balance.currency,
                 balance.currency_amount,
                 balance.rate,
             )
+                .with_operation_event(command.operation_event_id().map(Id::new), command.reverted())
         );
 
         Ok(event)
@@ -47,10 +64,11 @@ mod tests {
     fn test_handle_change_successful() {
         let command = ChangeCommand::new(
             Id::generate(),
-            1.0,
+            Id::generate(),
+            Decimal::ONE,
             "USD".to_string(),
-            1.0,
-            1.0,
+            Decimal::ONE,
+            Decimal::ONE,
         );
         let res = Balance::handle_change(command.clone());
 
@@ -62,8 +80,9 @@ mod tests {
             BalanceEvent::BalanceChanged(balance_changed) => balance_changed,
         };
 
+        assert_eq!(balance_changed.payload().account_id().value(), command.account_id());
         assert_eq!(balance_changed.payload().amount(), command.amount());
-        assert_eq!(balance_changed.payload().currency().to_str(), command.currency());
+        assert_eq!(balance_changed.payload().currency().code(), command.currency());
         assert_eq!(balance_changed.payload().currency_amount(), command.currency_amount());
         assert_eq!(balance_changed.payload().rate().rate(), command.rate());
     }
@@ -72,13 +91,47 @@ mod tests {
     fn test_handle_change_with_error() {
         let command = ChangeCommand::new(
             Id::generate(),
-            0.0,
+            Id::generate(),
+            Decimal::ZERO,
             "test".to_string(),
-            0.0,
-            -1.0,
+            Decimal::ZERO,
+            Decimal::NEGATIVE_ONE,
         );
         let res = Balance::handle_change(command);
 
         assert!(res.is_err());
     }
+
+    #[test]
+    fn test_handle_change_rounds_amounts() {
+        let command = ChangeCommand::new(
+            Id::generate(),
+            Id::generate(),
+            Decimal::new(-10005, 3),
+            "USD".to_string(),
+            Decimal::new(-10005, 3),
+            Decimal::ONE,
+        );
+
+        let balance_changed = match Balance::handle_change(command).unwrap() {
+            BalanceEvent::BalanceChanged(balance_changed) => balance_changed,
+        };
+
+        assert_eq!(balance_changed.payload().amount(), Decimal::new(-1001, 2));
+        assert_eq!(balance_changed.payload().currency_amount(), Decimal::new(-1001, 2));
+    }
+
+    #[test]
+    fn test_handle_change_with_mismatched_amounts() {
+        let command = ChangeCommand::new(
+            Id::generate(),
+            Id::generate(),
+            Decimal::from(300),
+            "USD".to_string(),
+            Decimal::from(100),
+            Decimal::from(2),
+        );
+
+        assert!(matches!(Balance::handle_change(command), Err(DomainError::InvalidAmount(_))));
+    }
 }
\ No newline at end of file
@@ -7,4 +7,7 @@ pub enum DomainError {
 
     #[error("Rate cannot be negative")]
     RateCannotBeNegative,
+
+    #[error("Invalid amount: {0}")]
+    InvalidAmount(String),
 }
\ No newline at end of file
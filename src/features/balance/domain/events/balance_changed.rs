@@ -1,6 +1,7 @@
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
-use crate::features::balance::domain::currency::Currency;
 use crate::features::balance::domain::rate::Rate;
+use crate::support::currency::Currency;
 use crate::support::id::Id;
 
 const NAME: &str = "balance_changed";
@@ -15,21 +16,32 @@ pub struct BalanceChanged {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BalanceChangedPayload {
     user_id: Id,
-    amount: f64,
+    account_id: Id,
+    amount: Decimal,
     currency: Currency,
-    currency_amount: f64,
+    currency_amount: Decimal,
     rate: Rate,
+    #[serde(default)]
+    operation_event_id: Option<Id>,
+    #[serde(default)]
+    reverted: bool,
 }
 
 impl BalanceChanged {
-    pub fn new(id: Id, user_id: Id, amount: f64, currency: Currency, currency_amount: f64, rate: Rate) -> Self {
+    pub fn new(id: Id, user_id: Id, account_id: Id, amount: Decimal, currency: Currency, currency_amount: Decimal, rate: Rate) -> Self {
         Self {
             id,
             name: NAME.to_string(),
-            payload: BalanceChangedPayload::new(user_id, amount, currency, currency_amount, rate),
+            payload: BalanceChangedPayload::new(user_id, account_id, amount, currency, currency_amount, rate),
         }
     }
 
+    pub fn with_operation_event(mut self, operation_event_id: Option<Id>, reverted: bool) -> Self {
+        self.payload.operation_event_id = operation_event_id;
+        self.payload.reverted = reverted;
+        self
+    }
+
     pub fn id(&self) -> &Id {
         &self.id
     }
@@ -44,15 +56,19 @@ impl BalanceChanged {
 }
 
 impl BalanceChangedPayload {
-    pub fn new(user_id: Id, amount: f64, currency: Currency, currency_amount: f64, rate: Rate) -> Self {
-        Self { user_id, amount, currency, currency_amount, rate }
+    pub fn new(user_id: Id, account_id: Id, amount: Decimal, currency: Currency, currency_amount: Decimal, rate: Rate) -> Self {
+        Self { user_id, account_id, amount, currency, currency_amount, rate, operation_event_id: None, reverted: false }
     }
 
     pub fn user_id(&self) -> &Id {
         &self.user_id
     }
 
-    pub fn amount(&self) -> f64 {
+    pub fn account_id(&self) -> &Id {
+        &self.account_id
+    }
+
+    pub fn amount(&self) -> Decimal {
         self.amount
     }
 
@@ -60,12 +76,21 @@ impl BalanceChangedPayload {
         &self.currency
     }
 
-    pub fn currency_amount(&self) -> f64 {
+    pub fn currency_amount(&self) -> Decimal {
         self.currency_amount
     }
 
     pub fn rate(&self) -> &Rate {
         &self.rate
     }
+
+    /// Operation event the change comes from, changes recorded before it was tracked have none.
+    pub fn operation_event_id(&self) -> &Option<Id> {
+        &self.operation_event_id
+    }
+
+    pub fn reverted(&self) -> bool {
+        self.reverted
+    }
 }
 
@@ -1,5 +1,4 @@
 pub mod balance;
-pub mod currency;
 pub mod rate;
 pub mod balance_repository;
 pub mod events;
@@ -1,14 +1,15 @@
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use crate::features::balance::domain::error::DomainError;
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Rate {
-    rate: f64,
+    rate: Decimal,
 }
 
 impl Rate {
-    pub fn new(rate: f64) -> Result<Self, DomainError> {
-        if rate <= 0.0 {
+    pub fn new(rate: Decimal) -> Result<Self, DomainError> {
+        if rate <= Decimal::ZERO {
             return Err(
                 DomainError::RateCannotBeNegative
             )
@@ -19,7 +20,7 @@ impl Rate {
         )
     }
 
-    pub fn rate(&self) -> f64 {
+    pub fn rate(&self) -> Decimal {
         self.rate
     }
 }
@@ -30,20 +31,20 @@ mod tests {
 
     #[test]
     fn test_new_rate() {
-        let rate = Rate::new(0.1);
+        let rate = Rate::new(Decimal::new(1, 1));
 
         assert!(rate.is_ok());
     }
 
     #[test]
     fn test_new_rate_negative() {
-        let rate = Rate::new(-1.0);
+        let rate = Rate::new(Decimal::NEGATIVE_ONE);
         assert!(rate.is_err());
     }
 
     #[test]
     fn test_new_rate_zero() {
-        let rate = Rate::new(0.0);
+        let rate = Rate::new(Decimal::ZERO);
         assert!(rate.is_err());
     }
 }
\ No newline at end of file
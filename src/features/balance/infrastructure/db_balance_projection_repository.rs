@@ -0,0 +1,66 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use sqlx::query_as;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+use crate::db::manager::DbManager;
+use crate::features::balance::application::queries::balance_projection_repository::BalanceProjectionRepository;
+use crate::features::balance::application::queries::balance_view::BalanceView;
+use crate::features::balance::error::BalanceError;
+use crate::features::balance::infrastructure::error::InfrastructureError;
+
+#[derive(Clone)]
+pub struct DbBalanceProjectionRepository {
+    db_manager: Arc<Mutex<DbManager>>,
+}
+
+impl DbBalanceProjectionRepository {
+    pub fn new(db_manager: Arc<Mutex<DbManager>>) -> Self {
+        Self {
+            db_manager,
+        }
+    }
+}
+
+#[async_trait]
+impl BalanceProjectionRepository for DbBalanceProjectionRepository {
+    async fn find(&self, user_id: Uuid) -> Result<Vec<BalanceView>, BalanceError> {
+        // Accounts without operations yet still have their opening balance
+        let q = "
+            SELECT COALESCE(a.id, b.account_id) AS account_id,
+                   COALESCE(a.user_id, b.user_id) AS user_id,
+                   COALESCE(a.currency, b.currency) AS currency,
+                   COALESCE(b.amount, 0) AS amount,
+                   COALESCE(a.opening_balance, 0) + COALESCE(b.currency_amount, 0) AS currency_amount,
+                   COALESCE(a.opening_balance, 0) AS opening_balance,
+                   COALESCE(b.updated_at, a.created_at) AS updated_at
+            FROM accounts a
+            FULL JOIN balances b ON b.account_id = a.id
+            WHERE COALESCE(a.user_id, b.user_id) = $1
+            ORDER BY currency, account_id
+        ";
+
+        let pool = self.db_manager.lock()
+            .await
+            .pool()
+            .map_err(|e|
+                BalanceError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to get pool: {}", e)
+                    )
+                )
+            )?;
+
+        query_as::<_, BalanceView>(q)
+            .bind(user_id)
+            .fetch_all(&pool)
+            .await
+            .map_err(|e|
+                BalanceError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to fetch balances: {}", e)
+                    )
+                )
+            )
+    }
+}
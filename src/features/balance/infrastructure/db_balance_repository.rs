@@ -40,15 +40,32 @@ impl BalanceRepository for DbBalanceRepository {
                 )
             )?;
 
-        let _ = sqlx::query(
+        let mut tx = pool.begin()
+            .await
+            .map_err(|e|
+                BalanceError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to begin transaction: {}", e)
+                    )
+                )
+            )?;
+
+        let balance = balance_changed.payload();
+
+        // A redelivered operation event finds its change already recorded and leaves the balance as is
+        let recorded = sqlx::query(
             r#"
-            INSERT INTO balance_changeds (id, name, payload)
-            VALUES ($1, $2, $3)
+            INSERT INTO balance_changeds (id, name, payload, operation_event_id, account_id, reverted)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (operation_event_id, account_id, reverted) DO NOTHING
             "#
         ).bind(balance_changed.id().value())
             .bind(balance_changed.name().to_string())
             .bind(payload)
-            .execute(&pool)
+            .bind(balance.operation_event_id().as_ref().map(|id| id.value()))
+            .bind(balance.account_id().value())
+            .bind(balance.reverted())
+            .execute(&mut *tx)
             .await
             .map_err(|e|
                 BalanceError::Infrastructure(
@@ -56,8 +73,45 @@ impl BalanceRepository for DbBalanceRepository {
                         format!("Failed to persist balance event: {}", e.to_string())
                     )
                 )
+            )?
+            .rows_affected();
+
+        if recorded == 0 {
+            return Ok(());
+        }
+
+        let _ = sqlx::query(
+            r#"
+            INSERT INTO balances (account_id, user_id, currency, amount, currency_amount)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (account_id) DO UPDATE SET
+                amount = balances.amount + EXCLUDED.amount,
+                currency_amount = balances.currency_amount + EXCLUDED.currency_amount,
+                updated_at = CURRENT_TIMESTAMP
+            "#
+        ).bind(balance.account_id().value())
+            .bind(balance.user_id().value())
+            .bind(balance.currency().code())
+            .bind(balance.amount())
+            .bind(balance.currency_amount())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e|
+                BalanceError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to update balance: {}", e)
+                    )
+                )
             )?;
 
-        Ok(())
+        tx.commit()
+            .await
+            .map_err(|e|
+                BalanceError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to commit transaction: {}", e)
+                    )
+                )
+            )
     }
 }
\ No newline at end of file
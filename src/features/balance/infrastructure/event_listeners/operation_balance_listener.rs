@@ -0,0 +1,295 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use tokio::sync::Mutex;
+use crate::events::error::EventError;
+use crate::events::event::Event;
+use crate::events::event_listener::EventListener;
+use crate::features::balance::application::commands::change::command::ChangeCommand;
+use crate::features::balance::application::commands::change::handler::ChangeCommandHandler;
+use crate::features::balance::domain::balance_repository::BalanceRepository;
+use crate::features::operations::domain::amount::Amount;
+use crate::features::operations::domain::events::operation_event::OperationEvent;
+use crate::features::operations::domain::kind::Kind;
+use crate::features::operations::domain::transfer::Transfer;
+use crate::support::command_bus::CommandBus;
+use crate::support::currency::Currency;
+use crate::support::id::Id;
+
+/// Money part of an operation state, as far as balances are concerned.
+struct OperationEffect<'a> {
+    user_id: &'a Id,
+    account_id: &'a Id,
+    kind: &'a Kind,
+    amount: &'a Amount,
+    currency: &'a Currency,
+    currency_amount: &'a Amount,
+    rate: &'a Amount,
+    transfer: &'a Option<Transfer>,
+}
+
+impl OperationEffect<'_> {
+    /// Balance changes of the operation event applying the operation, or reverting it for a negative `direction`.
+    fn changes(&self, event_id: &Id, direction: Decimal) -> Vec<ChangeCommand> {
+        // Transfers recorded before they had a destination do not move money
+        if *self.kind == Kind::Transfer && self.transfer.is_none() {
+            return vec![];
+        }
+
+        let sign = self.kind.sign() * direction;
+
+        let mut changes = vec![
+            ChangeCommand::new(
+                self.user_id.value(),
+                self.account_id.value(),
+                self.amount.value() * sign,
+                self.currency.code().to_string(),
+                self.currency_amount.value() * sign,
+                self.rate.value(),
+            )
+                .with_operation_event(event_id.value(), direction.is_sign_negative())
+        ];
+
+        if let Some(transfer) = self.transfer {
+            // The credited amount is worth the same base amount as the debited one
+            changes.push(
+                ChangeCommand::new(
+                    self.user_id.value(),
+                    transfer.account_id().value(),
+                    self.amount.value() * direction,
+                    transfer.currency().code().to_string(),
+                    transfer.amount().value() * direction,
+                    self.amount.value() / transfer.amount().value(),
+                )
+                    .with_operation_event(event_id.value(), direction.is_sign_negative())
+            );
+        }
+
+        changes
+    }
+}
+
+/// Keeps account balances in step with operations: a creation applies the operation, an update reverts
+/// the previous state and applies the new one, a deletion reverts the last state.
+/// One instance is registered per operation event name.
+pub struct OperationBalanceListener<R>
+    where
+        R: BalanceRepository + Send + Sync + 'static,
+{
+    command_bus: Arc<Mutex<CommandBus<ChangeCommand, ChangeCommandHandler<R>>>>,
+    event_name: &'static str,
+}
+
+#[async_trait]
+impl<R> EventListener for OperationBalanceListener<R>
+    where
+        R: BalanceRepository + Send + Sync + 'static,
+{
+    async fn on_event(&mut self, event: Event) -> Result<Vec<Event>, EventError> {
+        let commands = Self::changes(&self.parse_event(event)?);
+
+        let mut guard = self.command_bus.lock().await;
+        let mut events = vec![];
+
+        for command in commands {
+            events.extend(
+                guard.dispatch(command)
+                    .await
+                    .map_err(EventError::Feature)?
+            );
+        }
+
+        Ok(events)
+    }
+
+    fn event_name(&self) -> &str {
+        self.event_name
+    }
+}
+
+impl<R> OperationBalanceListener<R>
+    where
+        R: BalanceRepository + Send + Sync + 'static,
+{
+    pub async fn new(
+        command_bus: Arc<Mutex<CommandBus<ChangeCommand, ChangeCommandHandler<R>>>>,
+        rep: R,
+        event_name: &'static str,
+    ) -> Self {
+        let mut guard = command_bus.lock().await;
+        guard.register(ChangeCommandHandler::new(rep));
+
+        Self {
+            command_bus: command_bus.clone(),
+            event_name,
+        }
+    }
+
+    pub fn parse_event(&self, event: Event) -> Result<OperationEvent, EventError> {
+        match event {
+            Event::OperationEvent(operation_event) => Ok(operation_event),
+            _ => Err(
+                EventError::Parsing(
+                    format!("Invalid event type. Expected OperationEvent, got {:?}", event)
+                )
+            )
+        }
+    }
+
+    fn changes(event: &OperationEvent) -> Vec<ChangeCommand> {
+        match event {
+            OperationEvent::OperationCreated(operation_created) => {
+                let event_id = operation_created.id();
+                let payload = operation_created.payload();
+
+                OperationEffect {
+                    user_id: payload.user_id(),
+                    account_id: payload.account_id(),
+                    kind: payload.kind(),
+                    amount: payload.amount(),
+                    currency: payload.currency(),
+                    currency_amount: payload.amount_currency(),
+                    rate: payload.rate(),
+                    transfer: payload.transfer(),
+                }.changes(event_id, Decimal::ONE)
+            }
+            OperationEvent::OperationUpdated(operation_updated) => {
+                let event_id = operation_updated.id();
+                let payload = operation_updated.payload();
+
+                let mut changes = OperationEffect {
+                    user_id: payload.user_id(),
+                    account_id: payload.previous_account_id(),
+                    kind: payload.previous_kind(),
+                    amount: payload.previous_amount(),
+                    currency: payload.previous_currency(),
+                    currency_amount: payload.previous_amount_currency(),
+                    rate: payload.previous_rate(),
+                    transfer: payload.previous_transfer(),
+                }.changes(event_id, Decimal::NEGATIVE_ONE);
+
+                changes.extend(
+                    OperationEffect {
+                        user_id: payload.user_id(),
+                        account_id: payload.account_id(),
+                        kind: payload.kind(),
+                        amount: payload.amount(),
+                        currency: payload.currency(),
+                        currency_amount: payload.amount_currency(),
+                        rate: payload.rate(),
+                        transfer: payload.transfer(),
+                    }.changes(event_id, Decimal::ONE)
+                );
+
+                changes
+            }
+            OperationEvent::OperationDeleted(operation_deleted) => {
+                let event_id = operation_deleted.id();
+                let payload = operation_deleted.payload();
+
+                OperationEffect {
+                    user_id: payload.user_id(),
+                    account_id: payload.account_id(),
+                    kind: payload.kind(),
+                    amount: payload.amount(),
+                    currency: payload.currency(),
+                    currency_amount: payload.amount_currency(),
+                    rate: payload.rate(),
+                    transfer: payload.transfer(),
+                }.changes(event_id, Decimal::NEGATIVE_ONE)
+            }
+            // Requests to other bounded contexts do not move money
+            _ => vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::features::balance::domain::balance_repository::MockBalanceRepository;
+    use crate::features::operations::application::commands::create_operation::command::{CreateOperationCommand, TransferData};
+    use crate::features::operations::application::commands::delete_operation::command::DeleteOperationCommand;
+    use crate::features::operations::domain::operation::Operation;
+    use crate::support::event_store::Aggregate;
+    use super::*;
+
+    type Listener = OperationBalanceListener<MockBalanceRepository>;
+
+    #[test]
+    fn test_changes_of_created_expense() {
+        let event = created_event("Expense", None);
+
+        let changes = Listener::changes(&event);
+
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].amount(), Decimal::from(-108));
+        assert_eq!(changes[0].currency(), "EUR");
+        assert_eq!(changes[0].currency_amount(), Decimal::from(-100));
+        assert!(changes[0].operation_event_id().is_some());
+        assert!(!changes[0].reverted());
+    }
+
+    #[test]
+    fn test_changes_of_created_credit_and_debt() {
+        let credit = Listener::changes(&created_event("Credit", None));
+        let debt = Listener::changes(&created_event("Debt", None));
+
+        assert_eq!(credit[0].currency_amount(), Decimal::from(100));
+        assert_eq!(debt[0].currency_amount(), Decimal::from(-100));
+    }
+
+    #[test]
+    fn test_changes_of_created_transfer() {
+        let destination_id = Id::generate();
+        let transfer = TransferData::new(destination_id, Some(Decimal::from(108))).with_currency("USD".to_string());
+
+        let changes = Listener::changes(&created_event("Transfer", Some(transfer)));
+
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].currency_amount(), Decimal::from(-100));
+        assert_eq!(changes[1].account_id(), destination_id);
+        assert_eq!(changes[1].currency(), "USD");
+        assert_eq!(changes[1].amount(), Decimal::from(108));
+        assert_eq!(changes[1].currency_amount(), Decimal::from(108));
+        assert_eq!(changes[1].rate(), Decimal::ONE);
+    }
+
+    #[test]
+    fn test_changes_of_deleted_income() {
+        let event = created_event("Income", None);
+        let operation = Operation::apply(None, &event).unwrap();
+        let events = operation.handle_deletion(
+            DeleteOperationCommand::new(operation.id().value(), operation.user_id().value())
+        ).unwrap();
+
+        let changes = Listener::changes(&events[0]);
+
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].amount(), Decimal::from(-108));
+        assert_eq!(changes[0].currency_amount(), Decimal::from(-100));
+        assert!(changes[0].operation_event_id().is_some());
+        assert!(changes[0].reverted());
+    }
+
+    fn created_event(kind: &str, transfer: Option<TransferData>) -> OperationEvent {
+        let command = CreateOperationCommand::new(
+            kind.to_string(),
+            Id::generate(),
+            Id::generate(),
+            Some(Id::generate()),
+            String::from("Other"),
+            None,
+            String::from("EUR"),
+            Decimal::from(100),
+            Some(Decimal::new(108, 2)),
+            String::from("Label"),
+            vec![],
+        );
+        let command = match transfer {
+            Some(transfer) => command.with_transfer(transfer),
+            None => command,
+        };
+
+        Operation::handle_creation(command).unwrap().remove(0)
+    }
+}
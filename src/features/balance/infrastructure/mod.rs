@@ -1,2 +1,4 @@
+pub mod db_balance_projection_repository;
 pub mod db_balance_repository;
-pub mod error;
\ No newline at end of file
+pub mod error;
+pub mod event_listeners;
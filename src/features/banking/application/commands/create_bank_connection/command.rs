@@ -0,0 +1,54 @@
+use uuid::Uuid;
+use crate::support::command_bus::Command;
+
+const NAME : &str = "create_bank_connection";
+
+#[derive(Debug, Clone)]
+pub struct CreateBankConnectionCommand {
+    user_id: Uuid,
+    account_id: Uuid,
+    provider: String,
+    external_account_id: String,
+    credentials: String,
+}
+
+impl CreateBankConnectionCommand {
+    pub fn new(user_id: Uuid, account_id: Uuid, provider: String, external_account_id: String, credentials: String) -> Self {
+        Self {
+            user_id,
+            account_id,
+            provider,
+            external_account_id,
+            credentials,
+        }
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    /// Account the transactions of the bank account are booked to.
+    pub fn account_id(&self) -> &Uuid {
+        &self.account_id
+    }
+
+    pub fn provider(&self) -> &str {
+        &self.provider
+    }
+
+    /// Id of the account at the bank.
+    pub fn external_account_id(&self) -> &str {
+        &self.external_account_id
+    }
+
+    /// Access granted by the user to the bank API, kept encrypted.
+    pub fn credentials(&self) -> &str {
+        &self.credentials
+    }
+}
+
+impl Command for CreateBankConnectionCommand {
+    fn name() -> &'static str {
+        NAME
+    }
+}
@@ -0,0 +1,220 @@
+use async_trait::async_trait;
+use crate::events::event::Event;
+use crate::features::banking::application::commands::create_bank_connection::command::CreateBankConnectionCommand;
+use crate::features::banking::domain::bank_connection::BankConnection;
+use crate::features::banking::domain::bank_connection_repository::BankConnectionRepository;
+use crate::features::banking::domain::bank_connectors::BankConnectors;
+use crate::features::banking::domain::error::DomainError;
+use crate::features::banking::domain::events::bank_connection_event::BankConnectionEvent;
+use crate::features::banking::error::BankingError;
+use crate::features::banking::infrastructure::error::InfrastructureError;
+use crate::features::operations::domain::account_source::AccountSource;
+use crate::services::cipher::Cipher;
+use crate::support::command_bus::CommandHandler;
+use crate::support::error::FeatureError;
+
+/// Connects an account of the user to an account at a bank. The credentials are checked against the bank,
+/// which has to hold the account in the currency of the user's account, and are stored encrypted.
+pub struct CreateBankConnectionCommandHandler<R, A, K, X>
+    where
+        R: BankConnectionRepository + Send + Sync,
+        A: AccountSource + Send + Sync,
+        K: BankConnectors + Send + Sync,
+        X: Cipher + Send + Sync,
+{
+    bank_connection_repository: R,
+    accounts: A,
+    connectors: K,
+    cipher: X,
+}
+
+impl<R, A, K, X> CreateBankConnectionCommandHandler<R, A, K, X>
+    where
+        R: BankConnectionRepository + Send + Sync,
+        A: AccountSource + Send + Sync,
+        K: BankConnectors + Send + Sync,
+        X: Cipher + Send + Sync,
+{
+    pub fn new(bank_connection_repository: R, accounts: A, connectors: K, cipher: X) -> Self {
+        Self {
+            bank_connection_repository,
+            accounts,
+            connectors,
+            cipher,
+        }
+    }
+}
+
+#[async_trait]
+impl<R, A, K, X> CommandHandler<CreateBankConnectionCommand> for CreateBankConnectionCommandHandler<R, A, K, X>
+    where
+        R: BankConnectionRepository + Send + Sync,
+        A: AccountSource + Send + Sync,
+        K: BankConnectors + Send + Sync,
+        X: Cipher + Send + Sync,
+{
+    async fn handle(&mut self, command: CreateBankConnectionCommand) -> Result<Vec<Event>, FeatureError> {
+        let account = self.accounts.account(*command.account_id())
+            .await?
+            .filter(|account| account.check_owner(command.user_id()).is_ok())
+            .ok_or(FeatureError::Banking(BankingError::Domain(DomainError::AccountNotFound)))?;
+
+        let connector = self.connectors.connector(command.provider())
+            .map_err(|e| FeatureError::Banking(BankingError::Domain(e)))?;
+
+        let bank_account = connector.accounts(command.credentials())
+            .await?
+            .into_iter()
+            .find(|bank_account| bank_account.id() == command.external_account_id())
+            .ok_or(
+                FeatureError::Banking(
+                    BankingError::Domain(
+                        DomainError::BankAccountNotFound(command.external_account_id().to_string())
+                    )
+                )
+            )?;
+
+        if bank_account.currency() != account.currency() {
+            return Err(
+                FeatureError::Banking(
+                    BankingError::Domain(
+                        DomainError::CurrencyMismatch(
+                            format!("Bank account currency {} differs from account currency {}", bank_account.currency(), account.currency())
+                        )
+                    )
+                )
+            );
+        }
+
+        let credentials = self.cipher.encrypt(command.credentials())
+            .map_err(|e|
+                FeatureError::Banking(
+                    BankingError::Infrastructure(
+                        InfrastructureError::Credentials(e.to_string())
+                    )
+                )
+            )?;
+
+        let event = BankConnection::handle_creation(command, credentials);
+
+        if let BankConnectionEvent::BankConnectionCreated(created) = &event {
+            self.bank_connection_repository.append(created.payload().id().value(), 0, std::slice::from_ref(&event))
+                .await
+                .map_err(FeatureError::Banking)?;
+        }
+
+        Ok(
+            vec![Event::BankConnectionEvent(event)]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::FutureExt;
+    use uuid::Uuid;
+    use crate::features::banking::domain::bank_account::BankAccount;
+    use crate::features::banking::domain::bank_connection_repository::MockBankConnectionRepository;
+    use crate::features::banking::domain::bank_connector::{BankConnector, MockBankConnector};
+    use crate::features::banking::domain::bank_connectors::MockBankConnectors;
+    use crate::features::operations::domain::account_source::{MockAccountSource, OperationAccount};
+    use crate::services::cipher::MockCipher;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle_success() {
+        let user_id = Uuid::new_v4();
+
+        let mut cipher = MockCipher::new();
+        cipher.expect_encrypt()
+            .returning(|value| Ok(format!("encrypted {}", value)));
+
+        let mut handler = CreateBankConnectionCommandHandler::new(
+            MockBankConnectionRepository::new(false),
+            accounts(user_id, "USD"),
+            connectors(),
+            cipher,
+        );
+
+        let events = handler.handle(command_fixture(user_id, "acc-1")).await.unwrap();
+
+        let Event::BankConnectionEvent(BankConnectionEvent::BankConnectionCreated(created)) = &events[0] else {
+            panic!("Bank connection is not created");
+        };
+        assert_eq!(created.payload().credentials(), "encrypted token");
+    }
+
+    #[tokio::test]
+    async fn test_handle_unknown_bank_account() {
+        let user_id = Uuid::new_v4();
+
+        let mut handler = CreateBankConnectionCommandHandler::new(
+            MockBankConnectionRepository::new(false),
+            accounts(user_id, "USD"),
+            connectors(),
+            MockCipher::new(),
+        );
+
+        let result = handler.handle(command_fixture(user_id, "acc-9")).await;
+
+        assert!(matches!(result, Err(FeatureError::Banking(BankingError::Domain(DomainError::BankAccountNotFound(_))))));
+    }
+
+    #[tokio::test]
+    async fn test_handle_currency_mismatch() {
+        let user_id = Uuid::new_v4();
+
+        let mut handler = CreateBankConnectionCommandHandler::new(
+            MockBankConnectionRepository::new(false),
+            accounts(user_id, "EUR"),
+            connectors(),
+            MockCipher::new(),
+        );
+
+        let result = handler.handle(command_fixture(user_id, "acc-1")).await;
+
+        assert!(matches!(result, Err(FeatureError::Banking(BankingError::Domain(DomainError::CurrencyMismatch(_))))));
+    }
+
+    #[tokio::test]
+    async fn test_handle_account_of_other_user() {
+        let mut handler = CreateBankConnectionCommandHandler::new(
+            MockBankConnectionRepository::new(false),
+            accounts(Uuid::new_v4(), "USD"),
+            MockBankConnectors::new(),
+            MockCipher::new(),
+        );
+
+        let result = handler.handle(command_fixture(Uuid::new_v4(), "acc-1")).await;
+
+        assert!(matches!(result, Err(FeatureError::Banking(BankingError::Domain(DomainError::AccountNotFound)))));
+    }
+
+    fn command_fixture(user_id: Uuid, external_account_id: &str) -> CreateBankConnectionCommand {
+        CreateBankConnectionCommand::new(user_id, Uuid::new_v4(), "mock".to_string(), external_account_id.to_string(), "token".to_string())
+    }
+
+    fn accounts(user_id: Uuid, currency: &'static str) -> MockAccountSource {
+        let mut accounts = MockAccountSource::new();
+        accounts.expect_account()
+            .returning(move |_| async move { Ok(Some(OperationAccount::new(user_id, currency.to_string()))) }.boxed());
+
+        accounts
+    }
+
+    fn connectors() -> MockBankConnectors {
+        let mut connectors = MockBankConnectors::new();
+        connectors.expect_connector()
+            .returning(|_| {
+                let mut connector = MockBankConnector::new();
+                connector.expect_accounts()
+                    .returning(|_| async {
+                        Ok(vec![BankAccount::new("acc-1".to_string(), "Checking".to_string(), "USD".to_string())])
+                    }.boxed());
+
+                Ok(Box::new(connector) as Box<dyn BankConnector>)
+            });
+
+        connectors
+    }
+}
@@ -0,0 +1,3 @@
+pub mod create_bank_connection;
+pub mod remove_bank_connection;
+pub mod sync_bank_connection;
@@ -0,0 +1,33 @@
+use uuid::Uuid;
+use crate::support::command_bus::Command;
+
+const NAME : &str = "remove_bank_connection";
+
+#[derive(Debug, Clone)]
+pub struct RemoveBankConnectionCommand {
+    connection_id: Uuid,
+    user_id: Uuid,
+}
+
+impl RemoveBankConnectionCommand {
+    pub fn new(connection_id: Uuid, user_id: Uuid) -> Self {
+        Self {
+            connection_id,
+            user_id,
+        }
+    }
+
+    pub fn connection_id(&self) -> &Uuid {
+        &self.connection_id
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+}
+
+impl Command for RemoveBankConnectionCommand {
+    fn name() -> &'static str {
+        NAME
+    }
+}
@@ -0,0 +1,91 @@
+use async_trait::async_trait;
+use crate::events::event::Event;
+use crate::features::banking::application::commands::remove_bank_connection::command::RemoveBankConnectionCommand;
+use crate::features::banking::domain::bank_connection_repository::BankConnectionRepository;
+use crate::features::banking::domain::error::DomainError;
+use crate::features::banking::error::BankingError;
+use crate::support::command_bus::CommandHandler;
+use crate::support::error::FeatureError;
+
+/// Stops the sync of a bank connection, operations already synced are kept.
+pub struct RemoveBankConnectionCommandHandler<R>
+    where
+        R: BankConnectionRepository + Send + Sync,
+{
+    bank_connection_repository: R,
+}
+
+impl<R> RemoveBankConnectionCommandHandler<R>
+    where
+        R: BankConnectionRepository + Send + Sync,
+{
+    pub fn new(bank_connection_repository: R) -> Self {
+        Self {
+            bank_connection_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> CommandHandler<RemoveBankConnectionCommand> for RemoveBankConnectionCommandHandler<R>
+    where
+        R: BankConnectionRepository + Send + Sync,
+{
+    async fn handle(&mut self, command: RemoveBankConnectionCommand) -> Result<Vec<Event>, FeatureError> {
+        let bank_connection = self.bank_connection_repository.load(*command.connection_id())
+            .await
+            .map_err(FeatureError::Banking)?
+            .ok_or(
+                FeatureError::Banking(
+                    BankingError::Domain(
+                        DomainError::BankConnectionNotFound(command.connection_id().to_string())
+                    )
+                )
+            )?;
+
+        let event = bank_connection.aggregate().handle_removal(command)
+            .map_err(|e|
+                FeatureError::Banking(
+                    BankingError::Domain(e)
+                )
+            )?;
+
+        self.bank_connection_repository.append(bank_connection.aggregate().id().value(), bank_connection.version(), std::slice::from_ref(&event))
+            .await
+            .map_err(FeatureError::Banking)?;
+
+        Ok(
+            vec![Event::BankConnectionEvent(event)]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+    use crate::features::banking::domain::bank_connection::tests::bank_connection_fixture;
+    use crate::features::banking::domain::bank_connection_repository::MockBankConnectionRepository;
+    use crate::features::banking::domain::events::bank_connection_event::BankConnectionEvent;
+    use crate::support::event_store::Versioned;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle_success() {
+        let bank_connection = bank_connection_fixture();
+        let command = RemoveBankConnectionCommand::new(bank_connection.id().value(), bank_connection.user_id().value());
+
+        let rep = MockBankConnectionRepository::with_bank_connections(vec![Versioned::new(bank_connection, 1)]);
+        let events = RemoveBankConnectionCommandHandler::new(rep).handle(command).await.unwrap();
+
+        assert!(matches!(events[0], Event::BankConnectionEvent(BankConnectionEvent::BankConnectionRemoved(_))));
+    }
+
+    #[tokio::test]
+    async fn test_handle_not_found() {
+        let command = RemoveBankConnectionCommand::new(Uuid::new_v4(), Uuid::new_v4());
+
+        let result = RemoveBankConnectionCommandHandler::new(MockBankConnectionRepository::new(false)).handle(command).await;
+
+        assert!(matches!(result, Err(FeatureError::Banking(BankingError::Domain(DomainError::BankConnectionNotFound(_))))));
+    }
+}
@@ -0,0 +1,41 @@
+use uuid::Uuid;
+use crate::support::command_bus::Command;
+
+const NAME : &str = "sync_bank_connection";
+
+#[derive(Debug, Clone)]
+pub struct SyncBankConnectionCommand {
+    connection_id: Uuid,
+    user_id: Option<Uuid>,
+    category_name: String,
+}
+
+impl SyncBankConnectionCommand {
+    pub fn new(connection_id: Uuid, user_id: Option<Uuid>, category_name: String) -> Self {
+        Self {
+            connection_id,
+            user_id,
+            category_name,
+        }
+    }
+
+    pub fn connection_id(&self) -> &Uuid {
+        &self.connection_id
+    }
+
+    /// User who asked for the sync, `None` when the sync worker runs it.
+    pub fn user_id(&self) -> &Option<Uuid> {
+        &self.user_id
+    }
+
+    /// Category the synced operations are booked to.
+    pub fn category_name(&self) -> &str {
+        &self.category_name
+    }
+}
+
+impl Command for SyncBankConnectionCommand {
+    fn name() -> &'static str {
+        NAME
+    }
+}
@@ -0,0 +1,372 @@
+use async_trait::async_trait;
+use crate::events::event::Event;
+use crate::features::banking::application::commands::sync_bank_connection::command::SyncBankConnectionCommand;
+use crate::features::banking::domain::bank_connection_repository::BankConnectionRepository;
+use crate::features::banking::domain::bank_connectors::BankConnectors;
+use crate::features::banking::domain::error::DomainError;
+use crate::features::banking::error::BankingError;
+use crate::features::banking::infrastructure::error::InfrastructureError;
+use crate::features::operations::domain::account_source::AccountSource;
+use crate::features::operations::domain::operation_creator::OperationCreator;
+use crate::features::operations::error::OperationError;
+use crate::services::cipher::Cipher;
+use crate::support::command_bus::CommandHandler;
+use crate::support::error::FeatureError;
+
+/// Takes one step of a sync: creates one missing operation of the latest page, or pulls the next page from the bank.
+///
+/// The transactions are recorded with the ids of their operations before the operations are created,
+/// then each dispatch creates one of them under its id, so a restart never duplicates operations.
+/// Returning the events of a single step lets the caller publish them before anything else is created.
+/// A transaction whose operation is rejected by the operations domain is skipped, as retrying it would fail forever.
+/// No events are returned once every operation exists and the bank has nothing new.
+pub struct SyncBankConnectionCommandHandler<R, A, C, K, X>
+    where
+        R: BankConnectionRepository + Send + Sync,
+        A: AccountSource + Send + Sync,
+        C: OperationCreator + Send + Sync,
+        K: BankConnectors + Send + Sync,
+        X: Cipher + Send + Sync,
+{
+    bank_connection_repository: R,
+    accounts: A,
+    operations: C,
+    connectors: K,
+    cipher: X,
+}
+
+impl<R, A, C, K, X> SyncBankConnectionCommandHandler<R, A, C, K, X>
+    where
+        R: BankConnectionRepository + Send + Sync,
+        A: AccountSource + Send + Sync,
+        C: OperationCreator + Send + Sync,
+        K: BankConnectors + Send + Sync,
+        X: Cipher + Send + Sync,
+{
+    pub fn new(bank_connection_repository: R, accounts: A, operations: C, connectors: K, cipher: X) -> Self {
+        Self {
+            bank_connection_repository,
+            accounts,
+            operations,
+            connectors,
+            cipher,
+        }
+    }
+}
+
+#[async_trait]
+impl<R, A, C, K, X> CommandHandler<SyncBankConnectionCommand> for SyncBankConnectionCommandHandler<R, A, C, K, X>
+    where
+        R: BankConnectionRepository + Send + Sync,
+        A: AccountSource + Send + Sync,
+        C: OperationCreator + Send + Sync,
+        K: BankConnectors + Send + Sync,
+        X: Cipher + Send + Sync,
+{
+    async fn handle(&mut self, command: SyncBankConnectionCommand) -> Result<Vec<Event>, FeatureError> {
+        let bank_connection = self.bank_connection_repository.load(*command.connection_id())
+            .await
+            .map_err(FeatureError::Banking)?
+            .ok_or(
+                FeatureError::Banking(
+                    BankingError::Domain(
+                        DomainError::BankConnectionNotFound(command.connection_id().to_string())
+                    )
+                )
+            )?;
+
+        let connection = bank_connection.aggregate();
+
+        if let Some(user_id) = command.user_id() {
+            connection.check_access(user_id)
+                .map_err(|e| FeatureError::Banking(BankingError::Domain(e)))?;
+        }
+
+        if connection.is_removed() {
+            return Err(FeatureError::Banking(BankingError::Domain(DomainError::AlreadyRemoved)));
+        }
+
+        let user_id = connection.user_id().value();
+        let account_id = connection.account_id().value();
+
+        for synced in connection.last_transactions() {
+            if !self.operations.exists(synced.operation_id().value()).await? {
+                return match self.operations.create(synced.to_command(user_id, account_id, command.category_name())).await {
+                    Err(e @ FeatureError::Operation(OperationError::Domain(_))) => {
+                        let event = connection.handle_skip(synced.operation_id().clone(), e.to_string());
+
+                        self.bank_connection_repository.append(connection.id().value(), bank_connection.version(), std::slice::from_ref(&event))
+                            .await
+                            .map_err(FeatureError::Banking)?;
+
+                        Ok(vec![Event::BankConnectionEvent(event)])
+                    }
+                    res => res,
+                };
+            }
+        }
+
+        let account = self.accounts.account(account_id)
+            .await?
+            .ok_or(FeatureError::Banking(BankingError::Domain(DomainError::AccountNotFound)))?;
+
+        let credentials = self.cipher.decrypt(connection.credentials())
+            .map_err(|e|
+                FeatureError::Banking(
+                    BankingError::Infrastructure(
+                        InfrastructureError::Credentials(e.to_string())
+                    )
+                )
+            )?;
+
+        let page = self.connectors.connector(connection.provider())
+            .map_err(|e| FeatureError::Banking(BankingError::Domain(e)))?
+            .transactions(&credentials, connection.external_account_id(), connection.cursor().clone())
+            .await?;
+
+        let Some(event) = connection.handle_sync(&page, account.currency()) else {
+            return Ok(vec![]);
+        };
+
+        self.bank_connection_repository.append(connection.id().value(), bank_connection.version(), std::slice::from_ref(&event))
+            .await
+            .map_err(FeatureError::Banking)?;
+
+        Ok(vec![Event::BankConnectionEvent(event)])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use futures_util::FutureExt;
+    use rust_decimal::Decimal;
+    use uuid::Uuid;
+    use crate::features::banking::domain::bank_connection::BankConnection;
+    use crate::features::banking::domain::bank_connection::tests::{bank_connection_fixture, transaction_fixture};
+    use crate::features::banking::domain::bank_connection_repository::MockBankConnectionRepository;
+    use crate::features::banking::domain::bank_connector::{BankConnector, MockBankConnector, TransactionPage};
+    use crate::features::banking::domain::bank_connectors::MockBankConnectors;
+    use crate::features::banking::domain::bank_transaction::BankTransaction;
+    use crate::features::banking::domain::events::bank_connection_event::BankConnectionEvent;
+    use crate::features::operations::domain::account_source::{MockAccountSource, OperationAccount};
+    use crate::features::operations::domain::error::DomainError as OperationDomainError;
+    use crate::features::operations::domain::operation_creator::MockOperationCreator;
+    use crate::services::cipher::MockCipher;
+    use crate::support::event_store::{Aggregate, Versioned};
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle_records_new_transactions() {
+        let bank_connection = bank_connection_fixture();
+        let command = SyncBankConnectionCommand::new(bank_connection.id().value(), None, "Bank".to_string());
+
+        let mut operations = MockOperationCreator::new();
+        operations.expect_exists().never();
+        operations.expect_create().never();
+
+        let page = TransactionPage::new(
+            vec![transaction_fixture("t-1", "USD"), transaction_fixture("t-2", "USD")],
+            "2".to_string(),
+            false,
+        );
+
+        let mut handler = SyncBankConnectionCommandHandler::new(
+            MockBankConnectionRepository::with_bank_connections(vec![Versioned::new(bank_connection, 1)]),
+            accounts(),
+            operations,
+            connectors(page),
+            cipher(),
+        );
+
+        let events = handler.handle(command).await.unwrap();
+
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], Event::BankConnectionEvent(BankConnectionEvent::BankTransactionsSynced(_))));
+    }
+
+    #[tokio::test]
+    async fn test_handle_creates_interrupted_operations_with_same_ids() {
+        let bank_connection = synced_fixture();
+        let operation_id = bank_connection.last_transactions()[0].operation_id().value();
+        let command = SyncBankConnectionCommand::new(bank_connection.id().value(), None, "Bank".to_string());
+
+        let mut operations = MockOperationCreator::new();
+        operations.expect_exists()
+            .returning(|_| async { Ok(false) }.boxed());
+        operations.expect_create()
+            .withf(move |command| *command.operation_id() == Some(operation_id))
+            .times(1)
+            .returning(|_| async { Ok(vec![]) }.boxed());
+
+        let mut handler = SyncBankConnectionCommandHandler::new(
+            MockBankConnectionRepository::with_bank_connections(vec![Versioned::new(bank_connection, 2)]),
+            accounts(),
+            operations,
+            connectors(TransactionPage::new(vec![transaction_fixture("t-1", "USD")], "1".to_string(), false)),
+            cipher(),
+        );
+
+        let events = handler.handle(command).await.unwrap();
+
+        assert!(events.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_handle_creates_one_operation_per_dispatch() {
+        let bank_connection = bank_connection_fixture();
+        let page = TransactionPage::new(
+            vec![transaction_fixture("t-1", "USD"), transaction_fixture("t-2", "USD")],
+            "2".to_string(),
+            false,
+        );
+        let event = bank_connection.handle_sync(&page, "USD").unwrap();
+        let bank_connection = BankConnection::apply(Some(bank_connection), &event).unwrap();
+        let first_id = bank_connection.last_transactions()[0].operation_id().value();
+        let command = SyncBankConnectionCommand::new(bank_connection.id().value(), None, "Bank".to_string());
+
+        let mut operations = MockOperationCreator::new();
+        operations.expect_exists()
+            .returning(|_| async { Ok(false) }.boxed());
+        operations.expect_create()
+            .withf(move |command| *command.operation_id() == Some(first_id))
+            .times(1)
+            .returning(|_| async { Ok(vec![]) }.boxed());
+
+        let mut handler = SyncBankConnectionCommandHandler::new(
+            MockBankConnectionRepository::with_bank_connections(vec![Versioned::new(bank_connection, 2)]),
+            MockAccountSource::new(),
+            operations,
+            MockBankConnectors::new(),
+            MockCipher::new(),
+        );
+
+        // The second operation and the next page wait for the following dispatch
+        assert!(handler.handle(command).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_handle_leaves_out_zero_amounts() {
+        let bank_connection = bank_connection_fixture();
+        let command = SyncBankConnectionCommand::new(bank_connection.id().value(), None, "Bank".to_string());
+        let page = TransactionPage::new(
+            vec![
+                amount_fixture("t-1", Decimal::ZERO),
+                amount_fixture("t-2", Decimal::new(-4, 3)),
+                amount_fixture("t-3", Decimal::new(-5, 3)),
+            ],
+            "1".to_string(),
+            false,
+        );
+
+        let mut handler = SyncBankConnectionCommandHandler::new(
+            MockBankConnectionRepository::with_bank_connections(vec![Versioned::new(bank_connection, 1)]),
+            accounts(),
+            MockOperationCreator::new(),
+            connectors(page),
+            cipher(),
+        );
+
+        let events = handler.handle(command).await.unwrap();
+
+        let Event::BankConnectionEvent(BankConnectionEvent::BankTransactionsSynced(synced)) = &events[0] else {
+            panic!("Transactions are not synced");
+        };
+        assert_eq!(synced.payload().transactions().len(), 1);
+        assert_eq!(synced.payload().transactions()[0].transaction().id(), "t-3");
+    }
+
+    #[tokio::test]
+    async fn test_handle_skips_rejected_operation() {
+        let bank_connection = synced_fixture();
+        let operation_id = bank_connection.last_transactions()[0].operation_id().clone();
+        let command = SyncBankConnectionCommand::new(bank_connection.id().value(), None, "Bank".to_string());
+
+        let mut operations = MockOperationCreator::new();
+        operations.expect_exists()
+            .returning(|_| async { Ok(false) }.boxed());
+        operations.expect_create()
+            .times(1)
+            .returning(|_| async { Err(FeatureError::Operation(OperationError::Domain(OperationDomainError::InvalidAmount("Amount must be positive".to_string())))) }.boxed());
+
+        let mut handler = SyncBankConnectionCommandHandler::new(
+            MockBankConnectionRepository::with_bank_connections(vec![Versioned::new(bank_connection.clone(), 2)]),
+            accounts(),
+            operations,
+            MockBankConnectors::new(),
+            MockCipher::new(),
+        );
+
+        let events = handler.handle(command).await.unwrap();
+
+        let Event::BankConnectionEvent(event @ BankConnectionEvent::BankTransactionSkipped(skipped)) = &events[0] else {
+            panic!("Transaction is not skipped");
+        };
+        assert_eq!(skipped.payload().operation_id(), &operation_id);
+        assert!(BankConnection::apply(Some(bank_connection), event).unwrap().last_transactions().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_handle_access_denied() {
+        let bank_connection = bank_connection_fixture();
+        let command = SyncBankConnectionCommand::new(bank_connection.id().value(), Some(Uuid::new_v4()), "Bank".to_string());
+
+        let mut handler = SyncBankConnectionCommandHandler::new(
+            MockBankConnectionRepository::with_bank_connections(vec![Versioned::new(bank_connection, 1)]),
+            MockAccountSource::new(),
+            MockOperationCreator::new(),
+            MockBankConnectors::new(),
+            MockCipher::new(),
+        );
+
+        let result = handler.handle(command).await;
+
+        assert!(matches!(result, Err(FeatureError::Banking(BankingError::Domain(DomainError::AccessDenied)))));
+    }
+
+    fn synced_fixture() -> BankConnection {
+        let bank_connection = bank_connection_fixture();
+        let page = TransactionPage::new(vec![transaction_fixture("t-1", "USD")], "1".to_string(), false);
+        let event = bank_connection.handle_sync(&page, "USD").unwrap();
+
+        BankConnection::apply(Some(bank_connection), &event).unwrap()
+    }
+
+    fn amount_fixture(id: &str, amount: Decimal) -> BankTransaction {
+        BankTransaction::new(id.to_string(), NaiveDate::from_ymd_opt(2024, 4, 1).unwrap(), amount, "USD".to_string(), "Fee".to_string())
+    }
+
+    fn accounts() -> MockAccountSource {
+        let mut accounts = MockAccountSource::new();
+        accounts.expect_account()
+            .returning(|_| async { Ok(Some(OperationAccount::new(Uuid::new_v4(), "USD".to_string()))) }.boxed());
+
+        accounts
+    }
+
+    fn connectors(page: TransactionPage) -> MockBankConnectors {
+        let mut connectors = MockBankConnectors::new();
+        connectors.expect_connector()
+            .returning(move |_| {
+                let page = page.clone();
+                let mut connector = MockBankConnector::new();
+                connector.expect_transactions()
+                    .returning(move |_, _, _| {
+                        let page = page.clone();
+                        async move { Ok(page) }.boxed()
+                    });
+
+                Ok(Box::new(connector) as Box<dyn BankConnector>)
+            });
+
+        connectors
+    }
+
+    fn cipher() -> MockCipher {
+        let mut cipher = MockCipher::new();
+        cipher.expect_decrypt()
+            .returning(|_| Ok("token".to_string()));
+
+        cipher
+    }
+}
@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+use mockall::automock;
+use uuid::Uuid;
+use crate::features::banking::application::queries::bank_connection_view::BankConnectionView;
+use crate::features::banking::domain::events::bank_connection_created::BankConnectionCreated;
+use crate::features::banking::domain::events::bank_connection_removed::BankConnectionRemoved;
+use crate::features::banking::domain::events::bank_transactions_synced::BankTransactionsSynced;
+use crate::features::banking::error::BankingError;
+
+#[async_trait]
+#[automock]
+pub trait BankConnectionProjectionRepository {
+    async fn find(&self, user_id: Uuid) -> Result<Vec<BankConnectionView>, BankingError>;
+
+    /// Ids of all the connections that are synced by the worker.
+    async fn find_active(&self) -> Result<Vec<Uuid>, BankingError>;
+
+    async fn apply_bank_connection_created(&self, event: &BankConnectionCreated) -> Result<(), BankingError>;
+
+    async fn apply_bank_transactions_synced(&self, event: &BankTransactionsSynced) -> Result<(), BankingError>;
+
+    async fn apply_bank_connection_removed(&self, event: &BankConnectionRemoved) -> Result<(), BankingError>;
+}
@@ -0,0 +1,75 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Read model row of the `bank_connections` projection. Credentials are never part of it.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct BankConnectionView {
+    id: Uuid,
+    user_id: Uuid,
+    account_id: Uuid,
+    provider: String,
+    external_account_id: String,
+    synced_transactions: i32,
+    last_synced_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+}
+
+impl BankConnectionView {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: Uuid,
+        user_id: Uuid,
+        account_id: Uuid,
+        provider: String,
+        external_account_id: String,
+        synced_transactions: i32,
+        last_synced_at: Option<DateTime<Utc>>,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            account_id,
+            provider,
+            external_account_id,
+            synced_transactions,
+            last_synced_at,
+            created_at,
+        }
+    }
+
+    pub fn id(&self) -> &Uuid {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    pub fn account_id(&self) -> &Uuid {
+        &self.account_id
+    }
+
+    pub fn provider(&self) -> &str {
+        &self.provider
+    }
+
+    pub fn external_account_id(&self) -> &str {
+        &self.external_account_id
+    }
+
+    /// Number of transactions synced as operations so far.
+    pub fn synced_transactions(&self) -> i32 {
+        self.synced_transactions
+    }
+
+    pub fn last_synced_at(&self) -> &Option<DateTime<Utc>> {
+        &self.last_synced_at
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+}
@@ -0,0 +1,61 @@
+use async_trait::async_trait;
+use crate::features::banking::application::queries::list_bank_accounts::query::ListBankAccountsQuery;
+use crate::features::banking::domain::bank_account::BankAccount;
+use crate::features::banking::domain::bank_connectors::BankConnectors;
+use crate::features::banking::error::BankingError;
+use crate::support::error::FeatureError;
+use crate::support::query_bus::QueryHandler;
+
+/// Accounts the credentials give access to, for the user to pick the one to connect.
+pub struct ListBankAccountsQueryHandler<K>
+    where
+        K: BankConnectors + Send + Sync,
+{
+    connectors: K,
+}
+
+impl<K> ListBankAccountsQueryHandler<K>
+    where
+        K: BankConnectors + Send + Sync,
+{
+    pub fn new(connectors: K) -> Self {
+        Self {
+            connectors,
+        }
+    }
+}
+
+#[async_trait]
+impl<K> QueryHandler<ListBankAccountsQuery> for ListBankAccountsQueryHandler<K>
+    where
+        K: BankConnectors + Send + Sync,
+{
+    type Output = Vec<BankAccount>;
+
+    async fn handle(&self, query: ListBankAccountsQuery) -> Result<Vec<BankAccount>, FeatureError> {
+        self.connectors.connector(query.provider())
+            .map_err(|e| FeatureError::Banking(BankingError::Domain(e)))?
+            .accounts(query.credentials())
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::features::banking::domain::bank_connectors::MockBankConnectors;
+    use crate::features::banking::domain::error::DomainError;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle_unknown_provider() {
+        let mut connectors = MockBankConnectors::new();
+        connectors.expect_connector()
+            .returning(|provider| Err(DomainError::UnknownProvider(provider.to_string())));
+
+        let result = ListBankAccountsQueryHandler::new(connectors)
+            .handle(ListBankAccountsQuery::new("other".to_string(), "token".to_string()))
+            .await;
+
+        assert!(matches!(result, Err(FeatureError::Banking(BankingError::Domain(DomainError::UnknownProvider(_))))));
+    }
+}
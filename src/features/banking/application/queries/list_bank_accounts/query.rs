@@ -0,0 +1,32 @@
+use crate::support::query_bus::Query;
+
+const NAME: &str = "list_bank_accounts";
+
+#[derive(Debug, Clone)]
+pub struct ListBankAccountsQuery {
+    provider: String,
+    credentials: String,
+}
+
+impl ListBankAccountsQuery {
+    pub fn new(provider: String, credentials: String) -> Self {
+        Self {
+            provider,
+            credentials,
+        }
+    }
+
+    pub fn provider(&self) -> &str {
+        &self.provider
+    }
+
+    pub fn credentials(&self) -> &str {
+        &self.credentials
+    }
+}
+
+impl Query for ListBankAccountsQuery {
+    fn name() -> &'static str {
+        NAME
+    }
+}
@@ -0,0 +1,69 @@
+use async_trait::async_trait;
+use crate::features::banking::application::queries::bank_connection_projection_repository::BankConnectionProjectionRepository;
+use crate::features::banking::application::queries::bank_connection_view::BankConnectionView;
+use crate::features::banking::application::queries::list_bank_connections::query::ListBankConnectionsQuery;
+use crate::support::error::FeatureError;
+use crate::support::query_bus::QueryHandler;
+
+pub struct ListBankConnectionsQueryHandler<R>
+    where
+        R: BankConnectionProjectionRepository + Send + Sync,
+{
+    rep: R,
+}
+
+impl<R> ListBankConnectionsQueryHandler<R>
+    where
+        R: BankConnectionProjectionRepository + Send + Sync,
+{
+    pub fn new(rep: R) -> Self {
+        Self {
+            rep,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> QueryHandler<ListBankConnectionsQuery> for ListBankConnectionsQueryHandler<R>
+    where
+        R: BankConnectionProjectionRepository + Send + Sync,
+{
+    type Output = Vec<BankConnectionView>;
+
+    async fn handle(&self, query: ListBankConnectionsQuery) -> Result<Vec<BankConnectionView>, FeatureError> {
+        self.rep.find(*query.user_id())
+            .await
+            .map_err(FeatureError::Banking)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use futures_util::FutureExt;
+    use mockall::predicate::eq;
+    use uuid::Uuid;
+    use crate::features::banking::application::queries::bank_connection_projection_repository::MockBankConnectionProjectionRepository;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle_success() {
+        let user_id = Uuid::new_v4();
+        let views = vec![
+            BankConnectionView::new(Uuid::new_v4(), user_id, Uuid::new_v4(), "mock".to_string(), "acc-1".to_string(), 3, None, Utc::now()),
+        ];
+
+        let mut rep = MockBankConnectionProjectionRepository::new();
+        rep.expect_find()
+            .with(eq(user_id))
+            .returning(move |_| {
+                let views = views.clone();
+                async move { Ok(views) }.boxed()
+            });
+
+        let result = ListBankConnectionsQueryHandler::new(rep).handle(ListBankConnectionsQuery::new(user_id)).await.unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].external_account_id(), "acc-1");
+    }
+}
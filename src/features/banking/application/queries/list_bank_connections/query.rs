@@ -0,0 +1,27 @@
+use uuid::Uuid;
+use crate::support::query_bus::Query;
+
+const NAME: &str = "list_bank_connections";
+
+#[derive(Debug, Clone)]
+pub struct ListBankConnectionsQuery {
+    user_id: Uuid,
+}
+
+impl ListBankConnectionsQuery {
+    pub fn new(user_id: Uuid) -> Self {
+        Self {
+            user_id,
+        }
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+}
+
+impl Query for ListBankConnectionsQuery {
+    fn name() -> &'static str {
+        NAME
+    }
+}
@@ -0,0 +1,4 @@
+pub mod bank_connection_projection_repository;
+pub mod bank_connection_view;
+pub mod list_bank_accounts;
+pub mod list_bank_connections;
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+/// Account held at a bank, as the bank describes it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BankAccount {
+    id: String,
+    name: String,
+    currency: String,
+}
+
+impl BankAccount {
+    pub fn new(id: String, name: String, currency: String) -> Self {
+        Self {
+            id,
+            name,
+            currency,
+        }
+    }
+
+    /// Id of the account at the bank.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+}
@@ -0,0 +1,311 @@
+use std::collections::HashSet;
+use uuid::Uuid;
+use crate::features::banking::application::commands::create_bank_connection::command::CreateBankConnectionCommand;
+use crate::features::banking::application::commands::remove_bank_connection::command::RemoveBankConnectionCommand;
+use crate::features::banking::domain::bank_connector::TransactionPage;
+use crate::features::banking::domain::error::DomainError;
+use crate::features::banking::domain::events::bank_connection_created::BankConnectionCreated;
+use crate::features::banking::domain::events::bank_connection_event::BankConnectionEvent;
+use crate::features::banking::domain::events::bank_connection_removed::BankConnectionRemoved;
+use crate::features::banking::domain::events::bank_transaction_skipped::BankTransactionSkipped;
+use crate::features::banking::domain::events::bank_transactions_synced::BankTransactionsSynced;
+use crate::features::banking::domain::synced_transaction::SyncedTransaction;
+use crate::support::currency::Currency;
+use crate::support::event_store::{Aggregate, EventStoreError};
+use crate::support::id::Id;
+use crate::support::money::{round_money, DEFAULT_MINOR_UNITS};
+
+/// Link between an account of the user and an account at a bank whose transactions are synced as operations.
+/// Synced transactions are recorded with the ids of their operations before the operations are created,
+/// so a creation that has to be repeated keeps the id and cannot produce a second operation.
+/// A transaction whose operation is rejected is skipped instead, so it cannot hold back the next pages.
+#[derive(Debug, Clone)]
+pub struct BankConnection {
+    id: Id,
+    user_id: Id,
+    account_id: Id,
+    provider: String,
+    external_account_id: String,
+    credentials: String,
+    cursor: Option<String>,
+    synced_ids: HashSet<String>,
+    last_transactions: Vec<SyncedTransaction>,
+    removed: bool,
+}
+
+impl BankConnection {
+    /// `credentials` are the encrypted credentials of the command.
+    pub fn handle_creation(command: CreateBankConnectionCommand, credentials: String) -> BankConnectionEvent {
+        BankConnectionEvent::BankConnectionCreated(
+            BankConnectionCreated::new(
+                Id::new(Id::generate()),
+                Id::new(Id::generate()),
+                Id::new(*command.user_id()),
+                Id::new(*command.account_id()),
+                command.provider().to_string(),
+                command.external_account_id().to_string(),
+                credentials,
+            )
+        )
+    }
+
+    /// Records the transactions of a page that were not synced before. Transactions in another currency than
+    /// the account, and those that round to zero in it, are left out. Returns `None` once the page brings nothing new.
+    pub fn handle_sync(&self, page: &TransactionPage, currency: &str) -> Option<BankConnectionEvent> {
+        let minor_units = Currency::find(currency).map(|currency| currency.minor_units()).unwrap_or(DEFAULT_MINOR_UNITS);
+        let mut seen = HashSet::new();
+        let transactions: Vec<SyncedTransaction> = page.transactions().iter()
+            .filter(|transaction| transaction.currency() == currency)
+            .filter(|transaction| !round_money(transaction.amount(), minor_units).is_zero())
+            .filter(|transaction| !self.synced_ids.contains(transaction.id()) && seen.insert(transaction.id()))
+            .map(|transaction| SyncedTransaction::new(transaction.clone(), Id::new(Id::generate())))
+            .collect();
+
+        if transactions.is_empty() && self.cursor.as_deref() == Some(page.cursor()) {
+            return None;
+        }
+
+        Some(
+            BankConnectionEvent::BankTransactionsSynced(
+                BankTransactionsSynced::new(
+                    Id::new(Id::generate()),
+                    self.id.clone(),
+                    self.user_id.clone(),
+                    self.account_id.clone(),
+                    page.cursor().to_string(),
+                    transactions,
+                )
+            )
+        )
+    }
+
+    /// Gives up on the operation of a synced transaction that can not be created.
+    pub fn handle_skip(&self, operation_id: Id, reason: String) -> BankConnectionEvent {
+        BankConnectionEvent::BankTransactionSkipped(
+            BankTransactionSkipped::new(
+                Id::new(Id::generate()),
+                self.id.clone(),
+                self.user_id.clone(),
+                operation_id,
+                reason,
+            )
+        )
+    }
+
+    pub fn handle_removal(&self, command: RemoveBankConnectionCommand) -> Result<BankConnectionEvent, DomainError> {
+        self.check_access(command.user_id())?;
+
+        if self.removed {
+            return Err(DomainError::AlreadyRemoved);
+        }
+
+        Ok(
+            BankConnectionEvent::BankConnectionRemoved(
+                BankConnectionRemoved::new(
+                    Id::new(Id::generate()),
+                    self.id.clone(),
+                    self.user_id.clone(),
+                )
+            )
+        )
+    }
+
+    pub fn check_access(&self, user_id: &Uuid) -> Result<(), DomainError> {
+        if self.user_id.value() != *user_id {
+            return Err(DomainError::AccessDenied);
+        }
+
+        Ok(())
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Id {
+        &self.user_id
+    }
+
+    pub fn account_id(&self) -> &Id {
+        &self.account_id
+    }
+
+    pub fn provider(&self) -> &str {
+        &self.provider
+    }
+
+    pub fn external_account_id(&self) -> &str {
+        &self.external_account_id
+    }
+
+    /// Encrypted credentials of the bank API.
+    pub fn credentials(&self) -> &str {
+        &self.credentials
+    }
+
+    pub fn cursor(&self) -> &Option<String> {
+        &self.cursor
+    }
+
+    /// Transactions of the latest sync, their operations may not be created yet if the creation was interrupted.
+    pub fn last_transactions(&self) -> &[SyncedTransaction] {
+        &self.last_transactions
+    }
+
+    pub fn is_removed(&self) -> bool {
+        self.removed
+    }
+}
+
+impl Aggregate for BankConnection {
+    type Event = BankConnectionEvent;
+
+    fn apply(state: Option<Self>, event: &BankConnectionEvent) -> Result<Self, EventStoreError> {
+        match (state, event) {
+            (None, BankConnectionEvent::BankConnectionCreated(created)) => {
+                let payload = created.payload();
+
+                Ok(
+                    Self {
+                        id: payload.id().clone(),
+                        user_id: payload.user_id().clone(),
+                        account_id: payload.account_id().clone(),
+                        provider: payload.provider().to_string(),
+                        external_account_id: payload.external_account_id().to_string(),
+                        credentials: payload.credentials().to_string(),
+                        cursor: None,
+                        synced_ids: HashSet::new(),
+                        last_transactions: vec![],
+                        removed: false,
+                    }
+                )
+            }
+            (Some(bank_connection), BankConnectionEvent::BankConnectionCreated(_)) => Err(
+                EventStoreError::Rehydration(
+                    format!("Bank connection {} is already created", bank_connection.id().value())
+                )
+            ),
+            (Some(mut bank_connection), BankConnectionEvent::BankTransactionsSynced(synced)) => {
+                let payload = synced.payload();
+
+                bank_connection.synced_ids.extend(
+                    payload.transactions().iter().map(|synced| synced.transaction().id().to_string())
+                );
+
+                Ok(
+                    Self {
+                        cursor: Some(payload.cursor().to_string()),
+                        last_transactions: payload.transactions().to_vec(),
+                        ..bank_connection
+                    }
+                )
+            }
+            (Some(mut bank_connection), BankConnectionEvent::BankTransactionSkipped(skipped)) => {
+                bank_connection.last_transactions.retain(|synced| synced.operation_id() != skipped.payload().operation_id());
+
+                Ok(bank_connection)
+            }
+            (Some(bank_connection), BankConnectionEvent::BankConnectionRemoved(_)) => Ok(
+                Self {
+                    removed: true,
+                    ..bank_connection
+                }
+            ),
+            (None, event) => Err(
+                EventStoreError::Rehydration(
+                    format!("Bank connection stream must start with bank_connection_created, got {}", event.name())
+                )
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use chrono::NaiveDate;
+    use rust_decimal::Decimal;
+    use crate::features::banking::domain::bank_transaction::BankTransaction;
+    use super::*;
+
+    #[test]
+    fn test_handle_sync() {
+        let bank_connection = bank_connection_fixture();
+        let page = TransactionPage::new(
+            vec![
+                transaction_fixture("t-1", "USD"),
+                transaction_fixture("t-2", "EUR"),
+                transaction_fixture("t-1", "USD"),
+            ],
+            "2".to_string(),
+            false,
+        );
+
+        let Some(BankConnectionEvent::BankTransactionsSynced(synced)) = bank_connection.handle_sync(&page, "USD") else {
+            panic!("Transactions are not synced");
+        };
+
+        assert_eq!(synced.payload().cursor(), "2");
+        assert_eq!(synced.payload().transactions().len(), 1);
+        assert_eq!(synced.payload().transactions()[0].transaction().id(), "t-1");
+    }
+
+    #[test]
+    fn test_handle_sync_skips_synced_transactions() {
+        let bank_connection = bank_connection_fixture();
+        let page = TransactionPage::new(vec![transaction_fixture("t-1", "USD")], "1".to_string(), false);
+        let event = bank_connection.handle_sync(&page, "USD").unwrap();
+        let bank_connection = BankConnection::apply(Some(bank_connection), &event).unwrap();
+
+        assert_eq!(bank_connection.cursor(), &Some("1".to_string()));
+        assert_eq!(bank_connection.last_transactions().len(), 1);
+        assert!(bank_connection.handle_sync(&page, "USD").is_none());
+
+        let moved = TransactionPage::new(vec![transaction_fixture("t-1", "USD")], "2".to_string(), false);
+        let Some(BankConnectionEvent::BankTransactionsSynced(synced)) = bank_connection.handle_sync(&moved, "USD") else {
+            panic!("Cursor is not recorded");
+        };
+        assert!(synced.payload().transactions().is_empty());
+    }
+
+    #[test]
+    fn test_handle_removal() {
+        let bank_connection = bank_connection_fixture();
+        let user_id = bank_connection.user_id().value();
+
+        assert!(matches!(
+            bank_connection.handle_removal(RemoveBankConnectionCommand::new(bank_connection.id().value(), Uuid::new_v4())),
+            Err(DomainError::AccessDenied)
+        ));
+
+        let event = bank_connection.handle_removal(RemoveBankConnectionCommand::new(bank_connection.id().value(), user_id)).unwrap();
+        let bank_connection = BankConnection::apply(Some(bank_connection), &event).unwrap();
+
+        assert!(bank_connection.is_removed());
+        assert!(matches!(
+            bank_connection.handle_removal(RemoveBankConnectionCommand::new(bank_connection.id().value(), user_id)),
+            Err(DomainError::AlreadyRemoved)
+        ));
+    }
+
+    pub fn bank_connection_fixture() -> BankConnection {
+        let command = CreateBankConnectionCommand::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "mock".to_string(),
+            "acc-1".to_string(),
+            "token".to_string(),
+        );
+
+        BankConnection::apply(None, &BankConnection::handle_creation(command, "encrypted".to_string())).unwrap()
+    }
+
+    pub fn transaction_fixture(id: &str, currency: &str) -> BankTransaction {
+        BankTransaction::new(
+            id.to_string(),
+            NaiveDate::from_ymd_opt(2024, 4, 1).unwrap(),
+            Decimal::new(-1250, 2),
+            currency.to_string(),
+            "Groceries".to_string(),
+        )
+    }
+}
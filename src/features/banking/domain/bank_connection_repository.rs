@@ -0,0 +1,58 @@
+use std::collections::HashMap;
+use async_trait::async_trait;
+use uuid::Uuid;
+use crate::features::banking::domain::events::bank_connection_event::BankConnectionEvent;
+use crate::features::banking::domain::bank_connection::BankConnection;
+use crate::features::banking::error::BankingError;
+use crate::features::banking::infrastructure::error::InfrastructureError;
+use crate::support::event_store::Versioned;
+
+#[async_trait]
+pub trait BankConnectionRepository {
+    async fn load(&self, connection_id: Uuid) -> Result<Option<Versioned<BankConnection>>, BankingError>;
+
+    /// Fails with `InfrastructureError::Conflict` when the stream is no longer at `expected_version`.
+    async fn append(&self, connection_id: Uuid, expected_version: i32, events: &[BankConnectionEvent]) -> Result<i32, BankingError>;
+}
+
+pub struct MockBankConnectionRepository {
+    has_error: bool,
+    bank_connections: HashMap<Uuid, Versioned<BankConnection>>,
+}
+
+impl MockBankConnectionRepository {
+    pub fn new(has_error: bool) -> Self {
+        Self {
+            has_error,
+            bank_connections: HashMap::new(),
+        }
+    }
+
+    pub fn with_bank_connections(bank_connections: Vec<Versioned<BankConnection>>) -> Self {
+        Self {
+            has_error: false,
+            bank_connections: bank_connections.into_iter()
+                .map(|bank_connection| (bank_connection.aggregate().id().value(), bank_connection))
+                .collect(),
+        }
+    }
+}
+
+#[async_trait]
+impl BankConnectionRepository for MockBankConnectionRepository {
+    async fn load(&self, connection_id: Uuid) -> Result<Option<Versioned<BankConnection>>, BankingError> {
+        Ok(self.bank_connections.get(&connection_id).cloned())
+    }
+
+    async fn append(&self, _connection_id: Uuid, expected_version: i32, events: &[BankConnectionEvent]) -> Result<i32, BankingError> {
+        if self.has_error {
+            return Err(
+                BankingError::Infrastructure(
+                    InfrastructureError::Repository("Mock repository error".into())
+                )
+            );
+        }
+
+        Ok(expected_version + events.len() as i32)
+    }
+}
@@ -0,0 +1,58 @@
+use async_trait::async_trait;
+use mockall::automock;
+use serde::{Deserialize, Serialize};
+use crate::features::banking::domain::bank_account::BankAccount;
+use crate::features::banking::domain::bank_transaction::BankTransaction;
+use crate::support::error::FeatureError;
+
+/// Transactions of a bank account following a cursor, in the order the bank booked them.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransactionPage {
+    transactions: Vec<BankTransaction>,
+    cursor: String,
+    has_more: bool,
+}
+
+impl TransactionPage {
+    pub fn new(transactions: Vec<BankTransaction>, cursor: String, has_more: bool) -> Self {
+        Self {
+            transactions,
+            cursor,
+            has_more,
+        }
+    }
+
+    pub fn transactions(&self) -> &[BankTransaction] {
+        &self.transactions
+    }
+
+    /// Position after the last transaction of the page, the next page starts there.
+    pub fn cursor(&self) -> &str {
+        &self.cursor
+    }
+
+    pub fn has_more(&self) -> bool {
+        self.has_more
+    }
+}
+
+/// Open-banking API of a bank, reached with the credentials the user granted to the application.
+#[async_trait]
+#[automock]
+pub trait BankConnector: Send + Sync {
+    async fn accounts(&self, credentials: &str) -> Result<Vec<BankAccount>, FeatureError>;
+
+    /// Transactions of the account booked after `cursor`, from the first one when there is no cursor yet.
+    async fn transactions(&self, credentials: &str, account_id: &str, cursor: Option<String>) -> Result<TransactionPage, FeatureError>;
+}
+
+#[async_trait]
+impl BankConnector for Box<dyn BankConnector> {
+    async fn accounts(&self, credentials: &str) -> Result<Vec<BankAccount>, FeatureError> {
+        self.as_ref().accounts(credentials).await
+    }
+
+    async fn transactions(&self, credentials: &str, account_id: &str, cursor: Option<String>) -> Result<TransactionPage, FeatureError> {
+        self.as_ref().transactions(credentials, account_id, cursor).await
+    }
+}
@@ -0,0 +1,9 @@
+use mockall::automock;
+use crate::features::banking::domain::bank_connector::BankConnector;
+use crate::features::banking::domain::error::DomainError;
+
+/// Connectors of the banks the application is configured for, by provider name.
+#[automock]
+pub trait BankConnectors {
+    fn connector(&self, provider: &str) -> Result<Box<dyn BankConnector>, DomainError>;
+}
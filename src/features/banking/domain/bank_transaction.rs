@@ -0,0 +1,46 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Transaction booked on a bank account. Money leaving the account has a negative amount.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BankTransaction {
+    id: String,
+    booked_on: NaiveDate,
+    amount: Decimal,
+    currency: String,
+    label: String,
+}
+
+impl BankTransaction {
+    pub fn new(id: String, booked_on: NaiveDate, amount: Decimal, currency: String, label: String) -> Self {
+        Self {
+            id,
+            booked_on,
+            amount,
+            currency,
+            label,
+        }
+    }
+
+    /// Id of the transaction at the bank.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn booked_on(&self) -> &NaiveDate {
+        &self.booked_on
+    }
+
+    pub fn amount(&self) -> Decimal {
+        self.amount
+    }
+
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+}
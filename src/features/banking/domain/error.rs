@@ -0,0 +1,25 @@
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone)]
+pub enum DomainError {
+    #[error("Bank connection {0} not found")]
+    BankConnectionNotFound(String),
+
+    #[error("Bank connection belongs to another user")]
+    AccessDenied,
+
+    #[error("Bank connection is already removed")]
+    AlreadyRemoved,
+
+    #[error("Account not found")]
+    AccountNotFound,
+
+    #[error("Unknown bank provider {0}")]
+    UnknownProvider(String),
+
+    #[error("Bank account {0} not found")]
+    BankAccountNotFound(String),
+
+    #[error("Currency mismatch. {0}")]
+    CurrencyMismatch(String),
+}
@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+use crate::support::id::Id;
+
+pub const BANK_CONNECTION_CREATED_NAME: &str = "bank_connection_created";
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BankConnectionCreated {
+    id: Id,
+    name: String,
+    payload: BankConnectionCreatedPayload,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BankConnectionCreatedPayload {
+    id: Id,
+    user_id: Id,
+    account_id: Id,
+    provider: String,
+    external_account_id: String,
+    credentials: String,
+}
+
+impl BankConnectionCreated {
+    pub fn new(id: Id, connection_id: Id, user_id: Id, account_id: Id, provider: String, external_account_id: String, credentials: String) -> Self {
+        Self {
+            id,
+            name: BANK_CONNECTION_CREATED_NAME.to_string(),
+            payload: BankConnectionCreatedPayload {
+                id: connection_id,
+                user_id,
+                account_id,
+                provider,
+                external_account_id,
+                credentials,
+            }
+        }
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn payload(&self) -> &BankConnectionCreatedPayload {
+        &self.payload
+    }
+}
+
+impl BankConnectionCreatedPayload {
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Id {
+        &self.user_id
+    }
+
+    pub fn account_id(&self) -> &Id {
+        &self.account_id
+    }
+
+    pub fn provider(&self) -> &str {
+        &self.provider
+    }
+
+    pub fn external_account_id(&self) -> &str {
+        &self.external_account_id
+    }
+
+    pub fn credentials(&self) -> &str {
+        &self.credentials
+    }
+}
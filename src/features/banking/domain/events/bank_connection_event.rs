@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+use crate::features::banking::domain::events::bank_connection_created::{BANK_CONNECTION_CREATED_NAME, BankConnectionCreated};
+use crate::features::banking::domain::events::bank_connection_removed::{BANK_CONNECTION_REMOVED_NAME, BankConnectionRemoved};
+use crate::features::banking::domain::events::bank_transaction_skipped::{BANK_TRANSACTION_SKIPPED_NAME, BankTransactionSkipped};
+use crate::features::banking::domain::events::bank_transactions_synced::{BANK_TRANSACTIONS_SYNCED_NAME, BankTransactionsSynced};
+use crate::support::event_store::{decode_event, EventStoreError, NewEvent, StorableEvent, StoredEvent};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum BankConnectionEvent {
+    BankConnectionCreated(BankConnectionCreated),
+    BankTransactionsSynced(BankTransactionsSynced),
+    BankConnectionRemoved(BankConnectionRemoved),
+    BankTransactionSkipped(BankTransactionSkipped),
+}
+
+impl BankConnectionEvent {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::BankConnectionCreated(event) => event.name(),
+            Self::BankTransactionsSynced(event) => event.name(),
+            Self::BankConnectionRemoved(event) => event.name(),
+            Self::BankTransactionSkipped(event) => event.name(),
+        }
+    }
+}
+
+impl StorableEvent for BankConnectionEvent {
+    fn from_stored(stored: &StoredEvent) -> Result<Self, EventStoreError> {
+        match stored.name() {
+            BANK_CONNECTION_CREATED_NAME => Ok(Self::BankConnectionCreated(decode_event(stored)?)),
+            BANK_TRANSACTIONS_SYNCED_NAME => Ok(Self::BankTransactionsSynced(decode_event(stored)?)),
+            BANK_CONNECTION_REMOVED_NAME => Ok(Self::BankConnectionRemoved(decode_event(stored)?)),
+            BANK_TRANSACTION_SKIPPED_NAME => Ok(Self::BankTransactionSkipped(decode_event(stored)?)),
+            name => Err(
+                EventStoreError::Serialization(format!("Unknown bank connection event {}", name))
+            ),
+        }
+    }
+
+    fn to_new(&self) -> Result<NewEvent, EventStoreError> {
+        match self {
+            Self::BankConnectionCreated(event) => NewEvent::from_event(event),
+            Self::BankTransactionsSynced(event) => NewEvent::from_event(event),
+            Self::BankConnectionRemoved(event) => NewEvent::from_event(event),
+            Self::BankTransactionSkipped(event) => NewEvent::from_event(event),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+use crate::support::id::Id;
+
+pub const BANK_CONNECTION_REMOVED_NAME: &str = "bank_connection_removed";
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BankConnectionRemoved {
+    id: Id,
+    name: String,
+    payload: BankConnectionRemovedPayload,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BankConnectionRemovedPayload {
+    id: Id,
+    user_id: Id,
+}
+
+impl BankConnectionRemoved {
+    pub fn new(id: Id, connection_id: Id, user_id: Id) -> Self {
+        Self {
+            id,
+            name: BANK_CONNECTION_REMOVED_NAME.to_string(),
+            payload: BankConnectionRemovedPayload {
+                id: connection_id,
+                user_id,
+            }
+        }
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn payload(&self) -> &BankConnectionRemovedPayload {
+        &self.payload
+    }
+}
+
+impl BankConnectionRemovedPayload {
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Id {
+        &self.user_id
+    }
+}
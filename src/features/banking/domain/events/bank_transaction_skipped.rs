@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+use crate::support::id::Id;
+
+pub const BANK_TRANSACTION_SKIPPED_NAME: &str = "bank_transaction_skipped";
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BankTransactionSkipped {
+    id: Id,
+    name: String,
+    payload: BankTransactionSkippedPayload,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BankTransactionSkippedPayload {
+    id: Id,
+    user_id: Id,
+    operation_id: Id,
+    reason: String,
+}
+
+impl BankTransactionSkipped {
+    pub fn new(id: Id, connection_id: Id, user_id: Id, operation_id: Id, reason: String) -> Self {
+        Self {
+            id,
+            name: BANK_TRANSACTION_SKIPPED_NAME.to_string(),
+            payload: BankTransactionSkippedPayload {
+                id: connection_id,
+                user_id,
+                operation_id,
+                reason,
+            }
+        }
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn payload(&self) -> &BankTransactionSkippedPayload {
+        &self.payload
+    }
+}
+
+impl BankTransactionSkippedPayload {
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Id {
+        &self.user_id
+    }
+
+    /// Operation the synced transaction was to be booked as.
+    pub fn operation_id(&self) -> &Id {
+        &self.operation_id
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
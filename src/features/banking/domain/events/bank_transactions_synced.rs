@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+use crate::features::banking::domain::synced_transaction::SyncedTransaction;
+use crate::support::id::Id;
+
+pub const BANK_TRANSACTIONS_SYNCED_NAME: &str = "bank_transactions_synced";
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BankTransactionsSynced {
+    id: Id,
+    name: String,
+    payload: BankTransactionsSyncedPayload,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BankTransactionsSyncedPayload {
+    id: Id,
+    user_id: Id,
+    account_id: Id,
+    cursor: String,
+    transactions: Vec<SyncedTransaction>,
+}
+
+impl BankTransactionsSynced {
+    pub fn new(id: Id, connection_id: Id, user_id: Id, account_id: Id, cursor: String, transactions: Vec<SyncedTransaction>) -> Self {
+        Self {
+            id,
+            name: BANK_TRANSACTIONS_SYNCED_NAME.to_string(),
+            payload: BankTransactionsSyncedPayload {
+                id: connection_id,
+                user_id,
+                account_id,
+                cursor,
+                transactions,
+            }
+        }
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn payload(&self) -> &BankTransactionsSyncedPayload {
+        &self.payload
+    }
+}
+
+impl BankTransactionsSyncedPayload {
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Id {
+        &self.user_id
+    }
+
+    pub fn account_id(&self) -> &Id {
+        &self.account_id
+    }
+
+    pub fn cursor(&self) -> &str {
+        &self.cursor
+    }
+
+    pub fn transactions(&self) -> &[SyncedTransaction] {
+        &self.transactions
+    }
+}
@@ -0,0 +1,5 @@
+pub mod bank_connection_created;
+pub mod bank_connection_event;
+pub mod bank_connection_removed;
+pub mod bank_transaction_skipped;
+pub mod bank_transactions_synced;
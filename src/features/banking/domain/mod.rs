@@ -0,0 +1,9 @@
+pub mod bank_account;
+pub mod bank_connection;
+pub mod bank_connection_repository;
+pub mod bank_connector;
+pub mod bank_connectors;
+pub mod bank_transaction;
+pub mod error;
+pub mod events;
+pub mod synced_transaction;
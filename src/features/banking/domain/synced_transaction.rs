@@ -0,0 +1,53 @@
+use chrono::{TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::features::banking::domain::bank_transaction::BankTransaction;
+use crate::features::operations::application::commands::create_operation::command::CreateOperationCommand;
+use crate::features::operations::domain::kind::Kind;
+use crate::support::id::Id;
+
+/// Bank transaction taken into the application with the id of the operation it is booked as.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyncedTransaction {
+    transaction: BankTransaction,
+    operation_id: Id,
+}
+
+impl SyncedTransaction {
+    pub fn new(transaction: BankTransaction, operation_id: Id) -> Self {
+        Self {
+            transaction,
+            operation_id,
+        }
+    }
+
+    pub fn transaction(&self) -> &BankTransaction {
+        &self.transaction
+    }
+
+    pub fn operation_id(&self) -> &Id {
+        &self.operation_id
+    }
+
+    /// Income or expense on `account_id`, dated on the day the bank booked the transaction.
+    pub fn to_command(&self, user_id: Uuid, account_id: Uuid, category_name: &str) -> CreateOperationCommand {
+        let transaction = &self.transaction;
+        let kind = if transaction.amount().is_sign_positive() { Kind::Income } else { Kind::Expense };
+
+        CreateOperationCommand::new(
+            kind.to_str().to_string(),
+            user_id,
+            account_id,
+            None,
+            category_name.to_string(),
+            None,
+            transaction.currency().to_string(),
+            transaction.amount().abs(),
+            None,
+            transaction.label().to_string(),
+            vec![],
+        )
+            .with_operation_id(self.operation_id.value())
+            .with_created_at(Utc.from_utc_datetime(&transaction.booked_on().and_time(Default::default())))
+    }
+}
@@ -0,0 +1,12 @@
+use thiserror::Error;
+use crate::features::banking::domain::error::DomainError;
+use crate::features::banking::infrastructure::error::InfrastructureError;
+
+#[derive(Error, Debug, Clone)]
+pub enum BankingError {
+    #[error("Banking domain error. {0}")]
+    Domain(DomainError),
+
+    #[error("Banking infrastructure error. {0}")]
+    Infrastructure(InfrastructureError),
+}
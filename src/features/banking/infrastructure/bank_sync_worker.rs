@@ -0,0 +1,103 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use uuid::Uuid;
+use crate::di::service_container::ServiceContainer;
+use crate::events::error::EventError;
+use crate::events::event_bus::EventBus;
+use crate::features::accounts::infrastructure::db_account_projection_repository::DbAccountProjectionRepository;
+use crate::features::banking::application::commands::sync_bank_connection::command::SyncBankConnectionCommand;
+use crate::features::banking::application::commands::sync_bank_connection::handler::SyncBankConnectionCommandHandler;
+use crate::features::banking::application::queries::bank_connection_projection_repository::BankConnectionProjectionRepository;
+use crate::features::banking::infrastructure::configured_bank_connectors::ConfiguredBankConnectors;
+use crate::features::banking::infrastructure::db_bank_connection_projection_repository::DbBankConnectionProjectionRepository;
+use crate::features::banking::infrastructure::db_bank_connection_repository::DbBankConnectionRepository;
+use crate::features::operations::infrastructure::command_operation_creator::CommandOperationCreator;
+use crate::features::operations::infrastructure::projection_account_source::ProjectionAccountSource;
+use crate::support::error::FeatureError;
+use crate::{log_error, log_info};
+
+/// Syncs the transactions of every bank connection.
+/// A failing connection is logged and retried on the next run without blocking the others.
+pub struct BankSyncWorker;
+
+impl BankSyncWorker {
+    pub fn spawn(service_container: Arc<ServiceContainer>, event_bus: Arc<Box<dyn EventBus>>) -> JoinHandle<()> {
+        let interval = service_container.config().banking().sync_interval();
+
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(Duration::from_secs(interval.max(1)));
+
+            loop {
+                ticker.tick().await;
+
+                match Self::active(&service_container).await {
+                    Ok(ids) => {
+                        for id in ids {
+                            if let Err(e) = Self::sync(&service_container, &event_bus, id, None).await {
+                                log_error!("Failed to sync bank connection {}. {}", id, e);
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        log_error!("Failed to find bank connections. {}", e);
+                    }
+                }
+            }
+        })
+    }
+
+    async fn active(service_container: &ServiceContainer) -> Result<Vec<Uuid>, FeatureError> {
+        DbBankConnectionProjectionRepository::new(service_container.db_manager())
+            .find_active()
+            .await
+            .map_err(FeatureError::Banking)
+    }
+
+    /// Syncs step after step until the bank has nothing new, publishing the events of each step before the next.
+    /// Every event of a step is published even if one fails, the first failure stops the sync.
+    /// `user_id` restricts the sync to connections of that user.
+    pub async fn sync(
+        service_container: &Arc<ServiceContainer>,
+        event_bus: &Arc<Box<dyn EventBus>>,
+        id: Uuid,
+        user_id: Option<Uuid>,
+    ) -> Result<(), EventError> {
+        let config = service_container.config().banking();
+
+        loop {
+            let db_manager = service_container.db_manager();
+            let handler = SyncBankConnectionCommandHandler::new(
+                DbBankConnectionRepository::new(db_manager.clone()),
+                ProjectionAccountSource::new(DbAccountProjectionRepository::new(db_manager)),
+                CommandOperationCreator::new(service_container.clone()),
+                ConfiguredBankConnectors::new(config.providers().to_vec()),
+                service_container.cipher(),
+            );
+
+            let mut command_bus = service_container.command_bus();
+            command_bus.register(handler);
+            let events = command_bus.dispatch(SyncBankConnectionCommand::new(id, user_id, config.category().to_string()))
+                .await
+                .map_err(EventError::Feature)?;
+
+            if events.is_empty() {
+                return Ok(());
+            }
+
+            log_info!("Bank connection {} synced", id);
+
+            let mut failure = None;
+
+            for event in events {
+                if let Err(e) = event_bus.publish(event).await {
+                    failure.get_or_insert(e);
+                }
+            }
+
+            if let Some(e) = failure {
+                return Err(e);
+            }
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use crate::config::structs::banking::BankProviderConfig;
+use crate::features::banking::domain::bank_connector::BankConnector;
+use crate::features::banking::domain::bank_connectors::BankConnectors;
+use crate::features::banking::domain::error::DomainError;
+use crate::features::banking::infrastructure::rest_bank_connector::RestBankConnector;
+use crate::services::http_client::ReqwestClient;
+
+/// REST connectors of the providers listed in the banking configuration.
+pub struct ConfiguredBankConnectors {
+    providers: Vec<BankProviderConfig>,
+}
+
+impl ConfiguredBankConnectors {
+    pub fn new(providers: Vec<BankProviderConfig>) -> Self {
+        Self {
+            providers,
+        }
+    }
+}
+
+impl BankConnectors for ConfiguredBankConnectors {
+    fn connector(&self, provider: &str) -> Result<Box<dyn BankConnector>, DomainError> {
+        let config = self.providers.iter()
+            .find(|config| config.name() == provider)
+            .ok_or(DomainError::UnknownProvider(provider.to_string()))?;
+
+        Ok(
+            Box::new(
+                RestBankConnector::new(ReqwestClient::new(), config.url().to_string())
+            )
+        )
+    }
+}
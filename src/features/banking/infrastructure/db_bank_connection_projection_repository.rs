@@ -0,0 +1,126 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use sqlx::{query, query_as, query_scalar, Pool, Postgres};
+use sqlx::postgres::PgArguments;
+use sqlx::query::Query;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+use crate::db::manager::DbManager;
+use crate::features::banking::application::queries::bank_connection_projection_repository::BankConnectionProjectionRepository;
+use crate::features::banking::application::queries::bank_connection_view::BankConnectionView;
+use crate::features::banking::domain::events::bank_connection_created::BankConnectionCreated;
+use crate::features::banking::domain::events::bank_connection_removed::BankConnectionRemoved;
+use crate::features::banking::domain::events::bank_transactions_synced::BankTransactionsSynced;
+use crate::features::banking::error::BankingError;
+use crate::features::banking::infrastructure::error::InfrastructureError;
+
+#[derive(Clone)]
+pub struct DbBankConnectionProjectionRepository {
+    db_manager: Arc<Mutex<DbManager>>,
+}
+
+impl DbBankConnectionProjectionRepository {
+    pub fn new(db_manager: Arc<Mutex<DbManager>>) -> Self {
+        Self {
+            db_manager,
+        }
+    }
+
+    async fn pool(&self) -> Result<Pool<Postgres>, BankingError> {
+        let guard = self.db_manager.lock().await;
+
+        guard.pool()
+            .map_err(|e|
+                BankingError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to get pool: {}", e)
+                    )
+                )
+            )
+    }
+
+    async fn execute(&self, res_query: Query<'_, Postgres, PgArguments>) -> Result<(), BankingError> {
+        let pool = self.pool().await?;
+
+        res_query.execute(&pool)
+            .await
+            .map_err(|e|
+                BankingError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to project bank connection: {}", e)
+                    )
+                )
+            )?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl BankConnectionProjectionRepository for DbBankConnectionProjectionRepository {
+    async fn find(&self, user_id: Uuid) -> Result<Vec<BankConnectionView>, BankingError> {
+        let q = "
+            SELECT id, user_id, account_id, provider, external_account_id, synced_transactions, last_synced_at, created_at
+            FROM bank_connections
+            WHERE user_id = $1
+            ORDER BY created_at, id
+        ";
+
+        let pool = self.pool().await?;
+
+        query_as::<_, BankConnectionView>(q)
+            .bind(user_id)
+            .fetch_all(&pool)
+            .await
+            .map_err(|e|
+                BankingError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to fetch bank connections: {}", e)
+                    )
+                )
+            )
+    }
+
+    async fn find_active(&self) -> Result<Vec<Uuid>, BankingError> {
+        let pool = self.pool().await?;
+
+        query_scalar::<_, Uuid>("SELECT id FROM bank_connections ORDER BY id")
+            .fetch_all(&pool)
+            .await
+            .map_err(|e|
+                BankingError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to fetch bank connections: {}", e)
+                    )
+                )
+            )
+    }
+
+    async fn apply_bank_connection_created(&self, event: &BankConnectionCreated) -> Result<(), BankingError> {
+        let payload = event.payload();
+
+        self.execute(
+            query("INSERT INTO bank_connections (id, user_id, account_id, provider, external_account_id) VALUES ($1, $2, $3, $4, $5) ON CONFLICT DO NOTHING")
+                .bind(payload.id().value())
+                .bind(payload.user_id().value())
+                .bind(payload.account_id().value())
+                .bind(payload.provider().to_string())
+                .bind(payload.external_account_id().to_string())
+        ).await
+    }
+
+    async fn apply_bank_transactions_synced(&self, event: &BankTransactionsSynced) -> Result<(), BankingError> {
+        self.execute(
+            query("UPDATE bank_connections SET synced_transactions = synced_transactions + $2, last_synced_at = CURRENT_TIMESTAMP WHERE id = $1")
+                .bind(event.payload().id().value())
+                .bind(event.payload().transactions().len() as i32)
+        ).await
+    }
+
+    async fn apply_bank_connection_removed(&self, event: &BankConnectionRemoved) -> Result<(), BankingError> {
+        self.execute(
+            query("DELETE FROM bank_connections WHERE id = $1")
+                .bind(event.payload().id().value())
+        ).await
+    }
+}
@@ -0,0 +1,64 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+use crate::db::manager::DbManager;
+use crate::features::banking::domain::bank_connection::BankConnection;
+use crate::features::banking::domain::bank_connection_repository::BankConnectionRepository;
+use crate::features::banking::domain::events::bank_connection_event::BankConnectionEvent;
+use crate::features::banking::error::BankingError;
+use crate::features::banking::infrastructure::error::InfrastructureError;
+use crate::support::event_store::{Aggregate, EventStore, StorableEvent, Versioned};
+use crate::support::pg_event_store::PgEventStore;
+
+const EVENTS_TABLE: &str = "bank_connection_events";
+
+#[derive(Clone)]
+pub struct DbBankConnectionRepository {
+    event_store: PgEventStore,
+}
+
+impl DbBankConnectionRepository {
+    pub fn new(db_manager: Arc<Mutex<DbManager>>) -> Self {
+        Self {
+            event_store: PgEventStore::new(db_manager, EVENTS_TABLE),
+        }
+    }
+}
+
+#[async_trait]
+impl BankConnectionRepository for DbBankConnectionRepository {
+    async fn load(&self, connection_id: Uuid) -> Result<Option<Versioned<BankConnection>>, BankingError> {
+        let stream = self.event_store.load(connection_id)
+            .await
+            .map_err(|e|
+                BankingError::Infrastructure(
+                    InfrastructureError::Repository(e.to_string())
+                )
+            )?;
+
+        BankConnection::rehydrate(&stream)
+            .map_err(|e|
+                BankingError::Infrastructure(
+                    InfrastructureError::Repository(e.to_string())
+                )
+            )
+    }
+
+    async fn append(&self, connection_id: Uuid, expected_version: i32, events: &[BankConnectionEvent]) -> Result<i32, BankingError> {
+        let events = events.iter()
+            .map(|event| event.to_new())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e|
+                BankingError::Infrastructure(
+                    InfrastructureError::Repository(e.to_string())
+                )
+            )?;
+
+        self.event_store.append(connection_id, expected_version, &events)
+            .await
+            .map_err(|e|
+                BankingError::Infrastructure(e.into())
+            )
+    }
+}
@@ -0,0 +1,26 @@
+use thiserror::Error;
+use crate::support::event_store::EventStoreError;
+
+#[derive(Error, Debug, Clone)]
+pub enum InfrastructureError {
+    #[error("Banking repository error. {0}")]
+    Repository(String),
+
+    #[error("Banking conflict error. {0}")]
+    Conflict(String),
+
+    #[error("Bank provider error. {0}")]
+    Provider(String),
+
+    #[error("Bank credentials error. {0}")]
+    Credentials(String),
+}
+
+impl From<EventStoreError> for InfrastructureError {
+    fn from(e: EventStoreError) -> Self {
+        match e {
+            EventStoreError::Concurrency { .. } => Self::Conflict(e.to_string()),
+            _ => Self::Repository(e.to_string()),
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use async_trait::async_trait;
+use crate::events::error::EventError;
+use crate::events::event::Event;
+use crate::events::event_listener::EventListener;
+use crate::features::banking::application::queries::bank_connection_projection_repository::BankConnectionProjectionRepository;
+use crate::features::banking::domain::events::bank_connection_event::BankConnectionEvent;
+use crate::support::error::FeatureError;
+
+/// Keeps the `bank_connections` read model in sync with the bank connection event stream.
+/// One instance is registered per bank connection event name.
+pub struct BankConnectionProjectionListener<R>
+    where
+        R: BankConnectionProjectionRepository + Send + Sync + 'static,
+{
+    rep: R,
+    event_name: &'static str,
+}
+
+#[async_trait]
+impl<R> EventListener for BankConnectionProjectionListener<R>
+    where
+        R: BankConnectionProjectionRepository + Send + Sync + 'static,
+{
+    async fn on_event(&mut self, event: Event) -> Result<Vec<Event>, EventError> {
+        let res = match self.parse_event(event)? {
+            BankConnectionEvent::BankConnectionCreated(event) => self.rep.apply_bank_connection_created(&event).await,
+            BankConnectionEvent::BankTransactionsSynced(event) => self.rep.apply_bank_transactions_synced(&event).await,
+            BankConnectionEvent::BankConnectionRemoved(event) => self.rep.apply_bank_connection_removed(&event).await,
+            // The projection does not track single transactions
+            BankConnectionEvent::BankTransactionSkipped(_) => Ok(()),
+        };
+
+        res.map_err(|e|
+            EventError::Feature(
+                FeatureError::Banking(e)
+            )
+        )?;
+
+        Ok(vec![])
+    }
+
+    fn event_name(&self) -> &str {
+        self.event_name
+    }
+}
+
+impl<R> BankConnectionProjectionListener<R>
+    where
+        R: BankConnectionProjectionRepository + Send + Sync + 'static,
+{
+    pub fn new(rep: R, event_name: &'static str) -> Self {
+        Self {
+            rep,
+            event_name,
+        }
+    }
+
+    pub fn parse_event(&self, event: Event) -> Result<BankConnectionEvent, EventError> {
+        match event {
+            Event::BankConnectionEvent(bank_connection_event) => Ok(bank_connection_event),
+            _ => Err(
+                EventError::Parsing(
+                    format!("Invalid event type. Expected BankConnectionEvent, got {:?}", event)
+                )
+            )
+        }
+    }
+}
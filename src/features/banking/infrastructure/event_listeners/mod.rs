@@ -0,0 +1 @@
+pub mod bank_connection_projection_listener;
@@ -0,0 +1,7 @@
+pub mod bank_sync_worker;
+pub mod configured_bank_connectors;
+pub mod db_bank_connection_projection_repository;
+pub mod db_bank_connection_repository;
+pub mod error;
+pub mod event_listeners;
+pub mod rest_bank_connector;
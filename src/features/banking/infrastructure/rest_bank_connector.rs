@@ -0,0 +1,122 @@
+use async_trait::async_trait;
+use reqwest::Url;
+use serde::Deserialize;
+use crate::features::banking::domain::bank_account::BankAccount;
+use crate::features::banking::domain::bank_connector::{BankConnector, TransactionPage};
+use crate::features::banking::error::BankingError;
+use crate::features::banking::infrastructure::error::InfrastructureError;
+use crate::services::http_client::HttpClient;
+use crate::support::error::FeatureError;
+
+#[derive(Deserialize)]
+struct AccountsResponse {
+    accounts: Vec<BankAccount>,
+}
+
+/// Talks to an Open-Banking-style REST API with the credentials as a bearer token:
+/// `GET {url}/accounts` and `GET {url}/accounts/{id}/transactions?cursor={cursor}`.
+pub struct RestBankConnector<C>
+    where
+        C: HttpClient + Send + Sync,
+{
+    client: C,
+    url: String,
+}
+
+impl<C> RestBankConnector<C>
+    where
+        C: HttpClient + Send + Sync,
+{
+    pub fn new(client: C, url: String) -> Self {
+        Self {
+            client,
+            url,
+        }
+    }
+
+    fn endpoint(&self, segments: &[&str]) -> Result<Url, FeatureError> {
+        let mut url = Url::parse(&self.url)
+            .map_err(|e| Self::error(format!("Invalid bank url {}: {}", self.url, e)))?;
+
+        url.path_segments_mut()
+            .map_err(|_| Self::error(format!("Invalid bank url {}", self.url)))?
+            .pop_if_empty()
+            .extend(segments);
+
+        Ok(url)
+    }
+
+    async fn fetch<T>(&self, url: Url, credentials: &str) -> Result<T, FeatureError>
+        where
+            T: for<'de> Deserialize<'de>,
+    {
+        let body = self.client.get_authorized(url.as_str(), credentials)
+            .await
+            .map_err(|e| Self::error(e.to_string()))?;
+
+        serde_json::from_str(&body)
+            .map_err(|e| Self::error(format!("Failed to parse response of {}: {}", url, e)))
+    }
+
+    fn error(message: String) -> FeatureError {
+        FeatureError::Banking(
+            BankingError::Infrastructure(
+                InfrastructureError::Provider(message)
+            )
+        )
+    }
+}
+
+#[async_trait]
+impl<C> BankConnector for RestBankConnector<C>
+    where
+        C: HttpClient + Send + Sync,
+{
+    async fn accounts(&self, credentials: &str) -> Result<Vec<BankAccount>, FeatureError> {
+        let response: AccountsResponse = self.fetch(self.endpoint(&["accounts"])?, credentials).await?;
+
+        Ok(response.accounts)
+    }
+
+    async fn transactions(&self, credentials: &str, account_id: &str, cursor: Option<String>) -> Result<TransactionPage, FeatureError> {
+        let mut url = self.endpoint(&["accounts", account_id, "transactions"])?;
+
+        if let Some(cursor) = cursor {
+            url.query_pairs_mut().append_pair("cursor", &cursor);
+        }
+
+        self.fetch(url, credentials).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+    use crate::test_utils::stub_http_client::StubHttpClient;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_transactions() {
+        let client = StubHttpClient::new(r#"{"transactions": [{"id": "t-3", "booked_on": "2024-04-02", "amount": "-12.50", "currency": "USD", "label": "Groceries"}], "cursor": "3", "has_more": false}"#);
+        let connector = RestBankConnector::new(client, "http://bank.example.com/api/".to_string());
+
+        let page = connector.transactions("token", "acc 1", Some("2".to_string())).await.unwrap();
+
+        assert_eq!(
+            connector.client.requested(),
+            [("http://bank.example.com/api/accounts/acc%201/transactions?cursor=2".to_string(), Some("token".to_string()))]
+        );
+        assert_eq!(page.cursor(), "3");
+        assert_eq!(page.transactions()[0].amount(), Decimal::new(-1250, 2));
+    }
+
+    #[tokio::test]
+    async fn test_accounts_invalid_body() {
+        let client = StubHttpClient::new("Unauthorized");
+        let connector = RestBankConnector::new(client, "http://bank.example.com".to_string());
+
+        let result = connector.accounts("token").await;
+
+        assert!(matches!(result, Err(FeatureError::Banking(BankingError::Infrastructure(InfrastructureError::Provider(_))))));
+    }
+}
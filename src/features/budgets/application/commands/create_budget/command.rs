@@ -0,0 +1,83 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use crate::support::command_bus::Command;
+
+const NAME : &str = "create_budget";
+
+#[derive(Debug, Clone)]
+pub struct CreateBudgetCommand {
+    user_id: Uuid,
+    category_id: Uuid,
+    period: String,
+    currency: String,
+    limit: Decimal,
+    rollover: String,
+    thresholds: Vec<u32>,
+    starts_on: NaiveDate,
+}
+
+impl CreateBudgetCommand {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        user_id: Uuid,
+        category_id: Uuid,
+        period: String,
+        currency: String,
+        limit: Decimal,
+        rollover: String,
+        thresholds: Vec<u32>,
+        starts_on: NaiveDate,
+    ) -> Self {
+        Self {
+            user_id,
+            category_id,
+            period,
+            currency,
+            limit,
+            rollover,
+            thresholds,
+            starts_on,
+        }
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    pub fn category_id(&self) -> &Uuid {
+        &self.category_id
+    }
+
+    pub fn period(&self) -> &str {
+        &self.period
+    }
+
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    /// Amount that may be spent in each period, in the budget currency.
+    pub fn limit(&self) -> Decimal {
+        self.limit
+    }
+
+    pub fn rollover(&self) -> &str {
+        &self.rollover
+    }
+
+    /// Percentages of the available amount announced when spending reaches them.
+    pub fn thresholds(&self) -> &[u32] {
+        &self.thresholds
+    }
+
+    pub fn starts_on(&self) -> &NaiveDate {
+        &self.starts_on
+    }
+}
+
+impl Command for CreateBudgetCommand {
+    fn name() -> &'static str {
+        NAME
+    }
+}
@@ -0,0 +1,102 @@
+use async_trait::async_trait;
+use crate::events::event::Event;
+use crate::features::budgets::application::commands::create_budget::command::CreateBudgetCommand;
+use crate::features::budgets::domain::events::budget_event::BudgetEvent;
+use crate::features::budgets::domain::budget::Budget;
+use crate::features::budgets::domain::budget_repository::BudgetRepository;
+use crate::features::budgets::error::BudgetError;
+use crate::support::command_bus::CommandHandler;
+use crate::support::error::FeatureError;
+
+pub struct CreateBudgetCommandHandler<R>
+    where
+        R: BudgetRepository + Send + Sync,
+{
+    budget_repository: R,
+}
+
+impl<R> CreateBudgetCommandHandler<R>
+    where
+        R: BudgetRepository + Send + Sync,
+{
+    pub fn new(budget_repository: R) -> Self {
+        Self {
+            budget_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> CommandHandler<CreateBudgetCommand> for CreateBudgetCommandHandler<R>
+    where
+        R: BudgetRepository + Send + Sync,
+{
+    async fn handle(&mut self, command: CreateBudgetCommand) -> Result<Vec<Event>, FeatureError> {
+        let event = Budget::handle_creation(command)
+            .map_err(|e|
+                FeatureError::Budget(
+                    BudgetError::Domain(e)
+                )
+            )?;
+
+        if let BudgetEvent::BudgetCreated(budget_created) = &event {
+            self.budget_repository.append(budget_created.payload().id().value(), 0, std::slice::from_ref(&event))
+                .await
+                .map_err(FeatureError::Budget)?;
+        }
+
+        Ok(
+            vec![Event::BudgetEvent(event)]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use rust_decimal::Decimal;
+    use uuid::Uuid;
+    use crate::features::budgets::domain::error::DomainError;
+    use crate::features::budgets::domain::budget_repository::MockBudgetRepository;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle_success() {
+        let mut handler = CreateBudgetCommandHandler::new(MockBudgetRepository::new(false));
+
+        let events = handler.handle(command_fixture("USD")).await.unwrap();
+
+        assert!(matches!(events[0], Event::BudgetEvent(BudgetEvent::BudgetCreated(_))));
+    }
+
+    #[tokio::test]
+    async fn test_handle_unknown_currency() {
+        let mut handler = CreateBudgetCommandHandler::new(MockBudgetRepository::new(false));
+
+        let result = handler.handle(command_fixture("XXX")).await;
+
+        assert!(matches!(result, Err(FeatureError::Budget(BudgetError::Domain(DomainError::UnknownCurrency(_))))));
+    }
+
+    #[tokio::test]
+    async fn test_handle_repository_error() {
+        let mut handler = CreateBudgetCommandHandler::new(MockBudgetRepository::new(true));
+
+        let result = handler.handle(command_fixture("USD")).await;
+
+        assert!(matches!(result, Err(FeatureError::Budget(BudgetError::Infrastructure(_)))));
+    }
+
+    fn command_fixture(currency: &str) -> CreateBudgetCommand {
+        CreateBudgetCommand::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "Monthly".to_string(),
+            currency.to_string(),
+            Decimal::from(400),
+            "Unspent".to_string(),
+            vec![80, 100],
+            NaiveDate::from_ymd_opt(2024, 4, 1).unwrap(),
+        )
+    }
+}
@@ -0,0 +1,3 @@
+pub mod create_budget;
+pub mod record_spending;
+pub mod revert_spending;
\ No newline at end of file
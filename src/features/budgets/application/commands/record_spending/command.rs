@@ -0,0 +1,77 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use crate::support::command_bus::Command;
+
+const NAME : &str = "record_spending";
+
+/// Expense to count against the budgets of its category.
+#[derive(Debug, Clone)]
+pub struct RecordSpendingCommand {
+    operation_id: Uuid,
+    user_id: Uuid,
+    category_id: Uuid,
+    currency: String,
+    currency_amount: Decimal,
+    amount: Decimal,
+    date: NaiveDate,
+}
+
+impl RecordSpendingCommand {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        operation_id: Uuid,
+        user_id: Uuid,
+        category_id: Uuid,
+        currency: String,
+        currency_amount: Decimal,
+        amount: Decimal,
+        date: NaiveDate,
+    ) -> Self {
+        Self {
+            operation_id,
+            user_id,
+            category_id,
+            currency,
+            currency_amount,
+            amount,
+            date,
+        }
+    }
+
+    pub fn operation_id(&self) -> &Uuid {
+        &self.operation_id
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    pub fn category_id(&self) -> &Uuid {
+        &self.category_id
+    }
+
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    /// Amount in the operation currency.
+    pub fn currency_amount(&self) -> Decimal {
+        self.currency_amount
+    }
+
+    /// Amount in the currency of operation amounts.
+    pub fn amount(&self) -> Decimal {
+        self.amount
+    }
+
+    pub fn date(&self) -> &NaiveDate {
+        &self.date
+    }
+}
+
+impl Command for RecordSpendingCommand {
+    fn name() -> &'static str {
+        NAME
+    }
+}
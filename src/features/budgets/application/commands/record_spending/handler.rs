@@ -0,0 +1,165 @@
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use crate::events::event::Event;
+use crate::features::budgets::application::commands::record_spending::command::RecordSpendingCommand;
+use crate::features::budgets::application::queries::budget_projection_repository::BudgetProjectionRepository;
+use crate::features::budgets::domain::budget::Budget;
+use crate::features::budgets::domain::budget_repository::BudgetRepository;
+use crate::features::rates::domain::rate_source::RateSource;
+use crate::support::command_bus::CommandHandler;
+use crate::support::error::FeatureError;
+use crate::support::event_store::Versioned;
+
+/// Counts an expense against every budget of its category.
+pub struct RecordSpendingCommandHandler<R, P, S>
+    where
+        R: BudgetRepository + Send + Sync,
+        P: BudgetProjectionRepository + Send + Sync,
+        S: RateSource + Send + Sync,
+{
+    budget_repository: R,
+    projection_repository: P,
+    rates: S,
+}
+
+impl<R, P, S> RecordSpendingCommandHandler<R, P, S>
+    where
+        R: BudgetRepository + Send + Sync,
+        P: BudgetProjectionRepository + Send + Sync,
+        S: RateSource + Send + Sync,
+{
+    pub fn new(budget_repository: R, projection_repository: P, rates: S) -> Self {
+        Self {
+            budget_repository,
+            projection_repository,
+            rates,
+        }
+    }
+
+    async fn load(&self, budget_id: Uuid) -> Result<Option<Versioned<Budget>>, FeatureError> {
+        self.budget_repository.load(budget_id)
+            .await
+            .map_err(FeatureError::Budget)
+    }
+
+    /// The operation amount in the budget currency: taken as is when the currencies match,
+    /// otherwise converted from the amount in the currency of operation amounts.
+    async fn amount(&self, budget: &Budget, command: &RecordSpendingCommand) -> Result<Decimal, FeatureError> {
+        if budget.currency().code() == command.currency() {
+            return Ok(command.currency_amount());
+        }
+
+        let rate = self.rates.rate(budget.currency().code(), *command.date()).await?;
+
+        Ok(command.amount() / rate)
+    }
+}
+
+#[async_trait]
+impl<R, P, S> CommandHandler<RecordSpendingCommand> for RecordSpendingCommandHandler<R, P, S>
+    where
+        R: BudgetRepository + Send + Sync,
+        P: BudgetProjectionRepository + Send + Sync,
+        S: RateSource + Send + Sync,
+{
+    async fn handle(&mut self, command: RecordSpendingCommand) -> Result<Vec<Event>, FeatureError> {
+        let budget_ids = self.projection_repository.find_ids(*command.user_id(), *command.category_id())
+            .await
+            .map_err(FeatureError::Budget)?;
+
+        let mut events = vec![];
+
+        for budget_id in budget_ids {
+            let Some(budget) = self.load(budget_id).await? else {
+                continue;
+            };
+
+            let amount = self.amount(budget.aggregate(), &command).await?;
+            let budget_events = budget.aggregate().handle_spending(command.operation_id(), amount, command.date());
+
+            if budget_events.is_empty() {
+                continue;
+            }
+
+            self.budget_repository.append(budget_id, budget.version(), &budget_events)
+                .await
+                .map_err(FeatureError::Budget)?;
+
+            events.extend(budget_events.into_iter().map(Event::BudgetEvent));
+        }
+
+        Ok(events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use futures_util::FutureExt;
+    use mockall::predicate::eq;
+    use crate::features::budgets::application::queries::budget_projection_repository::MockBudgetProjectionRepository;
+    use crate::features::budgets::domain::budget::tests::budget_fixture;
+    use crate::features::budgets::domain::budget_repository::MockBudgetRepository;
+    use crate::features::budgets::domain::events::budget_event::BudgetEvent;
+    use crate::features::rates::domain::rate_source::MockRateSource;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle_records_spending_in_budget_currency() {
+        let budget = budget_fixture();
+        let budget_id = budget.id().value();
+        let command = command_fixture(&budget, "EUR", Decimal::from(400), Decimal::from(440));
+
+        let mut projection = MockBudgetProjectionRepository::new();
+        projection.expect_find_ids()
+            .with(eq(*command.user_id()), eq(*command.category_id()))
+            .returning(move |_, _| async move { Ok(vec![budget_id]) }.boxed());
+        let mut rates = MockRateSource::new();
+        rates.expect_rate()
+            .returning(|_, _| async { Ok(Decimal::ONE) }.boxed());
+
+        let rep = MockBudgetRepository::with_budgets(vec![Versioned::new(budget, 1)]);
+        let events = RecordSpendingCommandHandler::new(rep, projection, rates)
+            .handle(command)
+            .await
+            .unwrap();
+
+        assert_eq!(events.len(), 2);
+        assert!(matches!(&events[0], Event::BudgetEvent(BudgetEvent::BudgetSpendingRecorded(recorded)) if recorded.payload().amount() == Decimal::from(440)));
+        assert!(matches!(&events[1], Event::BudgetEvent(BudgetEvent::BudgetThresholdReached(reached)) if reached.payload().threshold() == 80));
+    }
+
+    #[tokio::test]
+    async fn test_handle_same_currency_skips_rates() {
+        let budget = budget_fixture();
+        let budget_id = budget.id().value();
+        let command = command_fixture(&budget, "USD", Decimal::from(50), Decimal::from(45));
+
+        let mut projection = MockBudgetProjectionRepository::new();
+        projection.expect_find_ids()
+            .returning(move |_, _| async move { Ok(vec![budget_id]) }.boxed());
+        let mut rates = MockRateSource::new();
+        rates.expect_rate().never();
+
+        let rep = MockBudgetRepository::with_budgets(vec![Versioned::new(budget, 1)]);
+        let events = RecordSpendingCommandHandler::new(rep, projection, rates)
+            .handle(command)
+            .await
+            .unwrap();
+
+        assert!(matches!(&events[0], Event::BudgetEvent(BudgetEvent::BudgetSpendingRecorded(recorded)) if recorded.payload().amount() == Decimal::from(50)));
+    }
+
+    fn command_fixture(budget: &Budget, currency: &str, currency_amount: Decimal, amount: Decimal) -> RecordSpendingCommand {
+        RecordSpendingCommand::new(
+            Uuid::new_v4(),
+            budget.user_id().value(),
+            budget.category_id().value(),
+            currency.to_string(),
+            currency_amount,
+            amount,
+            NaiveDate::from_ymd_opt(2024, 2, 10).unwrap(),
+        )
+    }
+}
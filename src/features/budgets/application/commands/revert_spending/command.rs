@@ -0,0 +1,34 @@
+use uuid::Uuid;
+use crate::support::command_bus::Command;
+
+const NAME : &str = "revert_spending";
+
+/// Operation whose spending is taken back from the budgets that counted it.
+#[derive(Debug, Clone)]
+pub struct RevertSpendingCommand {
+    operation_id: Uuid,
+    user_id: Uuid,
+}
+
+impl RevertSpendingCommand {
+    pub fn new(operation_id: Uuid, user_id: Uuid) -> Self {
+        Self {
+            operation_id,
+            user_id,
+        }
+    }
+
+    pub fn operation_id(&self) -> &Uuid {
+        &self.operation_id
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+}
+
+impl Command for RevertSpendingCommand {
+    fn name() -> &'static str {
+        NAME
+    }
+}
@@ -0,0 +1,125 @@
+use async_trait::async_trait;
+use crate::events::event::Event;
+use crate::features::budgets::application::commands::revert_spending::command::RevertSpendingCommand;
+use crate::features::budgets::application::queries::budget_projection_repository::BudgetProjectionRepository;
+use crate::features::budgets::domain::budget_repository::BudgetRepository;
+use crate::support::command_bus::CommandHandler;
+use crate::support::error::FeatureError;
+
+/// Takes an operation back from every budget of the user that counted it.
+/// The budgets are asked one by one, the category the operation had when it was counted is not known any more.
+pub struct RevertSpendingCommandHandler<R, P>
+    where
+        R: BudgetRepository + Send + Sync,
+        P: BudgetProjectionRepository + Send + Sync,
+{
+    budget_repository: R,
+    projection_repository: P,
+}
+
+impl<R, P> RevertSpendingCommandHandler<R, P>
+    where
+        R: BudgetRepository + Send + Sync,
+        P: BudgetProjectionRepository + Send + Sync,
+{
+    pub fn new(budget_repository: R, projection_repository: P) -> Self {
+        Self {
+            budget_repository,
+            projection_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<R, P> CommandHandler<RevertSpendingCommand> for RevertSpendingCommandHandler<R, P>
+    where
+        R: BudgetRepository + Send + Sync,
+        P: BudgetProjectionRepository + Send + Sync,
+{
+    async fn handle(&mut self, command: RevertSpendingCommand) -> Result<Vec<Event>, FeatureError> {
+        let budgets = self.projection_repository.find(*command.user_id())
+            .await
+            .map_err(FeatureError::Budget)?;
+
+        let mut events = vec![];
+
+        for view in budgets {
+            let Some(budget) = self.budget_repository.load(*view.id()).await.map_err(FeatureError::Budget)? else {
+                continue;
+            };
+
+            let Some(event) = budget.aggregate().handle_spending_reversal(command.operation_id()) else {
+                continue;
+            };
+
+            self.budget_repository.append(*view.id(), budget.version(), std::slice::from_ref(&event))
+                .await
+                .map_err(FeatureError::Budget)?;
+
+            events.push(Event::BudgetEvent(event));
+        }
+
+        Ok(events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, Utc};
+    use futures_util::FutureExt;
+    use mockall::predicate::eq;
+    use rust_decimal::Decimal;
+    use uuid::Uuid;
+    use crate::features::budgets::application::queries::budget_projection_repository::MockBudgetProjectionRepository;
+    use crate::features::budgets::application::queries::budget_view::BudgetView;
+    use crate::features::budgets::domain::budget::Budget;
+    use crate::features::budgets::domain::budget::tests::budget_fixture;
+    use crate::features::budgets::domain::budget_repository::MockBudgetRepository;
+    use crate::features::budgets::domain::events::budget_event::BudgetEvent;
+    use crate::support::event_store::{Aggregate, Versioned};
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle_reverts_budgets_that_counted_the_operation() {
+        let operation_id = Uuid::new_v4();
+        let counting = budget_fixture();
+        let event = counting.handle_spending(&operation_id, Decimal::from(40), &NaiveDate::from_ymd_opt(2024, 2, 10).unwrap()).remove(0);
+        let counting = Budget::apply(Some(counting), &event).unwrap();
+        let other = budget_fixture();
+        let user_id = counting.user_id().value();
+        let views = vec![view(&counting), view(&other)];
+
+        let mut projection = MockBudgetProjectionRepository::new();
+        projection.expect_find()
+            .with(eq(user_id))
+            .returning(move |_| {
+                let views = views.clone();
+                async move { Ok(views) }.boxed()
+            });
+
+        let rep = MockBudgetRepository::with_budgets(vec![Versioned::new(counting, 2), Versioned::new(other, 1)]);
+        let events = RevertSpendingCommandHandler::new(rep, projection)
+            .handle(RevertSpendingCommand::new(operation_id, user_id))
+            .await
+            .unwrap();
+
+        assert_eq!(events.len(), 1);
+        assert!(matches!(&events[0], Event::BudgetEvent(BudgetEvent::BudgetSpendingReverted(reverted)) if reverted.payload().amount() == Decimal::from(40)));
+    }
+
+    fn view(budget: &Budget) -> BudgetView {
+        BudgetView::new(
+            budget.id().value(),
+            budget.user_id().value(),
+            budget.category_id().value(),
+            "Monthly".to_string(),
+            "USD".to_string(),
+            Decimal::from(500),
+            "None".to_string(),
+            vec![],
+            NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            vec![],
+            Utc::now(),
+        )
+    }
+}
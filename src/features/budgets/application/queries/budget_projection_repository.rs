@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+use mockall::automock;
+use uuid::Uuid;
+use crate::features::budgets::application::queries::budget_view::BudgetView;
+use crate::features::budgets::domain::events::budget_created::BudgetCreated;
+use crate::features::budgets::domain::events::budget_spending_recorded::BudgetSpendingRecorded;
+use crate::features::budgets::domain::events::budget_spending_reverted::BudgetSpendingReverted;
+use crate::features::budgets::error::BudgetError;
+
+#[async_trait]
+#[automock]
+pub trait BudgetProjectionRepository {
+    async fn find(&self, user_id: Uuid) -> Result<Vec<BudgetView>, BudgetError>;
+
+    /// Ids of the user's budgets on a category.
+    async fn find_ids(&self, user_id: Uuid, category_id: Uuid) -> Result<Vec<Uuid>, BudgetError>;
+
+    async fn apply_budget_created(&self, event: &BudgetCreated) -> Result<(), BudgetError>;
+
+    async fn apply_budget_spending_recorded(&self, event: &BudgetSpendingRecorded) -> Result<(), BudgetError>;
+
+    async fn apply_budget_spending_reverted(&self, event: &BudgetSpendingReverted) -> Result<(), BudgetError>;
+}
@@ -0,0 +1,180 @@
+use std::collections::BTreeMap;
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use sqlx::types::Json;
+use uuid::Uuid;
+use crate::features::budgets::domain::budget_period::BudgetPeriod;
+use crate::features::budgets::domain::rollover::Rollover;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PeriodSpending {
+    period_start: NaiveDate,
+    amount: Decimal,
+}
+
+impl PeriodSpending {
+    pub fn new(period_start: NaiveDate, amount: Decimal) -> Self {
+        Self {
+            period_start,
+            amount,
+        }
+    }
+
+    pub fn period_start(&self) -> &NaiveDate {
+        &self.period_start
+    }
+
+    pub fn amount(&self) -> Decimal {
+        self.amount
+    }
+}
+
+/// Read model row of the `budgets` projection with the amounts spent per period.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct BudgetView {
+    id: Uuid,
+    user_id: Uuid,
+    category_id: Uuid,
+    period: String,
+    currency: String,
+    amount_limit: Decimal,
+    rollover: String,
+    thresholds: Json<Vec<u32>>,
+    starts_on: NaiveDate,
+    spendings: Json<Vec<PeriodSpending>>,
+    created_at: DateTime<Utc>,
+}
+
+impl BudgetView {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: Uuid,
+        user_id: Uuid,
+        category_id: Uuid,
+        period: String,
+        currency: String,
+        amount_limit: Decimal,
+        rollover: String,
+        thresholds: Vec<u32>,
+        starts_on: NaiveDate,
+        spendings: Vec<PeriodSpending>,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            category_id,
+            period,
+            currency,
+            amount_limit,
+            rollover,
+            thresholds: Json(thresholds),
+            starts_on,
+            spendings: Json(spendings),
+            created_at,
+        }
+    }
+
+    pub fn id(&self) -> &Uuid {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    pub fn category_id(&self) -> &Uuid {
+        &self.category_id
+    }
+
+    pub fn period(&self) -> &str {
+        &self.period
+    }
+
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    pub fn amount_limit(&self) -> Decimal {
+        self.amount_limit
+    }
+
+    pub fn rollover(&self) -> &str {
+        &self.rollover
+    }
+
+    pub fn thresholds(&self) -> &[u32] {
+        &self.thresholds
+    }
+
+    pub fn starts_on(&self) -> &NaiveDate {
+        &self.starts_on
+    }
+
+    pub fn spendings(&self) -> &[PeriodSpending] {
+        &self.spendings
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+}
+
+/// Budget with its standing in the period containing a date.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BudgetStatus {
+    #[serde(flatten)]
+    budget: BudgetView,
+    period_start: NaiveDate,
+    available: Decimal,
+    spent: Decimal,
+    remaining: Decimal,
+}
+
+impl BudgetStatus {
+    /// Fails when the projection holds a period or rollover the domain no longer knows.
+    pub fn new(budget: BudgetView, date: &NaiveDate) -> Result<Self, String> {
+        let period = BudgetPeriod::new(&budget.period).map_err(|e| e.to_string())?;
+        let rollover = Rollover::new(&budget.rollover).map_err(|e| e.to_string())?;
+
+        let spent: BTreeMap<NaiveDate, Decimal> = budget.spendings.iter()
+            .map(|spending| (spending.period_start, spending.amount))
+            .collect();
+
+        let period_start = period.start(date);
+        let available = rollover.available(&period, budget.amount_limit, &budget.starts_on, &spent, &period_start);
+        let spent = spent.get(&period_start).copied().unwrap_or_default();
+
+        Ok(
+            Self {
+                period_start,
+                available,
+                spent,
+                remaining: available - spent,
+                budget,
+            }
+        )
+    }
+
+    pub fn budget(&self) -> &BudgetView {
+        &self.budget
+    }
+
+    pub fn period_start(&self) -> &NaiveDate {
+        &self.period_start
+    }
+
+    pub fn available(&self) -> Decimal {
+        self.available
+    }
+
+    pub fn spent(&self) -> Decimal {
+        self.spent
+    }
+
+    pub fn remaining(&self) -> Decimal {
+        self.remaining
+    }
+}
@@ -0,0 +1,105 @@
+use async_trait::async_trait;
+use crate::features::budgets::application::queries::budget_projection_repository::BudgetProjectionRepository;
+use crate::features::budgets::application::queries::budget_view::BudgetStatus;
+use crate::features::budgets::application::queries::list_budgets::query::ListBudgetsQuery;
+use crate::features::budgets::error::BudgetError;
+use crate::features::budgets::infrastructure::error::InfrastructureError;
+use crate::support::error::FeatureError;
+use crate::support::query_bus::QueryHandler;
+
+pub struct ListBudgetsQueryHandler<R>
+    where
+        R: BudgetProjectionRepository + Send + Sync,
+{
+    rep: R,
+}
+
+impl<R> ListBudgetsQueryHandler<R>
+    where
+        R: BudgetProjectionRepository + Send + Sync,
+{
+    pub fn new(rep: R) -> Self {
+        Self {
+            rep,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> QueryHandler<ListBudgetsQuery> for ListBudgetsQueryHandler<R>
+    where
+        R: BudgetProjectionRepository + Send + Sync,
+{
+    type Output = Vec<BudgetStatus>;
+
+    async fn handle(&self, query: ListBudgetsQuery) -> Result<Vec<BudgetStatus>, FeatureError> {
+        let budgets = self.rep.find(*query.user_id())
+            .await
+            .map_err(FeatureError::Budget)?;
+
+        budgets.into_iter()
+            .map(|budget| BudgetStatus::new(budget, query.date()))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e|
+                FeatureError::Budget(
+                    BudgetError::Infrastructure(
+                        InfrastructureError::Repository(e)
+                    )
+                )
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, Utc};
+    use futures_util::FutureExt;
+    use mockall::predicate::eq;
+    use rust_decimal::Decimal;
+    use uuid::Uuid;
+    use crate::features::budgets::application::queries::budget_projection_repository::MockBudgetProjectionRepository;
+    use crate::features::budgets::application::queries::budget_view::{BudgetView, PeriodSpending};
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle_reports_current_period() {
+        let user_id = Uuid::new_v4();
+        let budgets = vec![
+            BudgetView::new(
+                Uuid::new_v4(),
+                user_id,
+                Uuid::new_v4(),
+                "Monthly".to_string(),
+                "USD".to_string(),
+                Decimal::from(300),
+                "Unspent".to_string(),
+                vec![80, 100],
+                date(2024, 1, 1),
+                vec![
+                    PeriodSpending::new(date(2024, 1, 1), Decimal::from(200)),
+                    PeriodSpending::new(date(2024, 2, 1), Decimal::from(150)),
+                ],
+                Utc::now(),
+            ),
+        ];
+
+        let mut rep = MockBudgetProjectionRepository::new();
+        rep.expect_find()
+            .with(eq(user_id))
+            .returning(move |_| {
+                let budgets = budgets.clone();
+                async move { Ok(budgets) }.boxed()
+            });
+
+        let result = ListBudgetsQueryHandler::new(rep).handle(ListBudgetsQuery::new(user_id, date(2024, 2, 20))).await.unwrap();
+
+        assert_eq!(result[0].period_start(), &date(2024, 2, 1));
+        assert_eq!(result[0].available(), Decimal::from(400));
+        assert_eq!(result[0].spent(), Decimal::from(150));
+        assert_eq!(result[0].remaining(), Decimal::from(250));
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+}
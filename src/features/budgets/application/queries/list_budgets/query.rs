@@ -0,0 +1,35 @@
+use chrono::NaiveDate;
+use uuid::Uuid;
+use crate::support::query_bus::Query;
+
+const NAME: &str = "list_budgets";
+
+#[derive(Debug, Clone)]
+pub struct ListBudgetsQuery {
+    user_id: Uuid,
+    date: NaiveDate,
+}
+
+impl ListBudgetsQuery {
+    pub fn new(user_id: Uuid, date: NaiveDate) -> Self {
+        Self {
+            user_id,
+            date,
+        }
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    /// Budgets are reported for the period containing this day.
+    pub fn date(&self) -> &NaiveDate {
+        &self.date
+    }
+}
+
+impl Query for ListBudgetsQuery {
+    fn name() -> &'static str {
+        NAME
+    }
+}
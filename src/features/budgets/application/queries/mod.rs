@@ -0,0 +1,3 @@
+pub mod budget_projection_repository;
+pub mod budget_view;
+pub mod list_budgets;
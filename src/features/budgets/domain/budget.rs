@@ -0,0 +1,333 @@
+use std::collections::{BTreeMap, HashMap};
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use crate::features::budgets::application::commands::create_budget::command::CreateBudgetCommand;
+use crate::features::budgets::domain::budget_period::BudgetPeriod;
+use crate::features::budgets::domain::error::DomainError;
+use crate::features::budgets::domain::events::budget_created::BudgetCreated;
+use crate::features::budgets::domain::events::budget_event::BudgetEvent;
+use crate::features::budgets::domain::events::budget_spending_recorded::BudgetSpendingRecorded;
+use crate::features::budgets::domain::events::budget_spending_reverted::BudgetSpendingReverted;
+use crate::features::budgets::domain::events::budget_threshold_reached::BudgetThresholdReached;
+use crate::features::budgets::domain::rollover::Rollover;
+use crate::support::currency::Currency;
+use crate::support::event_store::{Aggregate, EventStoreError};
+use crate::support::id::Id;
+use crate::support::money::round_money;
+
+/// Limit on the expenses of a category in each period.
+#[derive(Debug, Clone)]
+pub struct Budget {
+    id: Id,
+    user_id: Id,
+    category_id: Id,
+    period: BudgetPeriod,
+    currency: Currency,
+    limit: Decimal,
+    rollover: Rollover,
+    thresholds: Vec<u32>,
+    starts_on: NaiveDate,
+    spent: BTreeMap<NaiveDate, Decimal>,
+    /// Period start and amount counted for each operation.
+    spendings: HashMap<Uuid, (NaiveDate, Decimal)>,
+}
+
+impl Budget {
+    pub fn handle_creation(command: CreateBudgetCommand) -> Result<BudgetEvent, DomainError> {
+        let period = BudgetPeriod::new(command.period())?;
+        let rollover = Rollover::new(command.rollover())?;
+        let currency = Currency::find(command.currency())
+            .ok_or_else(|| DomainError::UnknownCurrency(command.currency().to_string()))?;
+        let limit = round_money(command.limit(), currency.minor_units());
+
+        if limit <= Decimal::ZERO {
+            return Err(DomainError::InvalidLimit);
+        }
+
+        if command.thresholds().contains(&0) {
+            return Err(DomainError::InvalidThreshold);
+        }
+
+        let mut thresholds = command.thresholds().to_vec();
+        thresholds.sort_unstable();
+        thresholds.dedup();
+
+        Ok(
+            BudgetEvent::BudgetCreated(
+                BudgetCreated::new(
+                    Id::new(Id::generate()),
+                    Id::new(Id::generate()),
+                    Id::new(*command.user_id()),
+                    Id::new(*command.category_id()),
+                    period,
+                    currency,
+                    limit,
+                    rollover,
+                    thresholds,
+                    period.start(command.starts_on()),
+                )
+            )
+        )
+    }
+
+    /// Counts an expense of `amount` in the budget currency and announces the thresholds it crosses.
+    /// Expenses dated before the budget starts and operations counted already are ignored.
+    pub fn handle_spending(&self, operation_id: &Uuid, amount: Decimal, date: &NaiveDate) -> Vec<BudgetEvent> {
+        if *date < self.starts_on || self.spendings.contains_key(operation_id) {
+            return vec![];
+        }
+
+        let amount = round_money(amount, self.currency.minor_units());
+        let period_start = self.period.start(date);
+        let available = self.available(&period_start);
+        let before = self.spent(&period_start);
+        let after = before + amount;
+
+        let mut events = vec![
+            BudgetEvent::BudgetSpendingRecorded(
+                BudgetSpendingRecorded::new(
+                    Id::new(Id::generate()),
+                    self.id.clone(),
+                    self.user_id.clone(),
+                    Id::new(*operation_id),
+                    period_start,
+                    amount,
+                )
+            )
+        ];
+
+        let reached = |spent: Decimal, threshold: u32| spent * Decimal::ONE_HUNDRED >= available * Decimal::from(threshold);
+
+        events.extend(
+            self.thresholds.iter()
+                .filter(|threshold| !reached(before, **threshold) && reached(after, **threshold))
+                .map(|threshold|
+                    BudgetEvent::BudgetThresholdReached(
+                        BudgetThresholdReached::new(
+                            Id::new(Id::generate()),
+                            self.id.clone(),
+                            self.user_id.clone(),
+                            self.category_id.clone(),
+                            period_start,
+                            *threshold,
+                            after,
+                            available,
+                        )
+                    )
+                )
+        );
+
+        events
+    }
+
+    /// Takes back what an operation counted, so it can be counted again with its new state.
+    /// Nothing happens for operations the budget did not count.
+    pub fn handle_spending_reversal(&self, operation_id: &Uuid) -> Option<BudgetEvent> {
+        let (period_start, amount) = self.spendings.get(operation_id)?;
+
+        Some(
+            BudgetEvent::BudgetSpendingReverted(
+                BudgetSpendingReverted::new(
+                    Id::new(Id::generate()),
+                    self.id.clone(),
+                    self.user_id.clone(),
+                    Id::new(*operation_id),
+                    *period_start,
+                    *amount,
+                )
+            )
+        )
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Id {
+        &self.user_id
+    }
+
+    pub fn category_id(&self) -> &Id {
+        &self.category_id
+    }
+
+    pub fn currency(&self) -> &Currency {
+        &self.currency
+    }
+
+    /// Amount that may be spent in the period starting on `period_start`, rollover included.
+    pub fn available(&self, period_start: &NaiveDate) -> Decimal {
+        self.rollover.available(&self.period, self.limit, &self.starts_on, &self.spent, period_start)
+    }
+
+    pub fn spent(&self, period_start: &NaiveDate) -> Decimal {
+        self.spent.get(period_start).copied().unwrap_or_default()
+    }
+}
+
+impl Aggregate for Budget {
+    type Event = BudgetEvent;
+
+    fn apply(state: Option<Self>, event: &BudgetEvent) -> Result<Self, EventStoreError> {
+        match (state, event) {
+            (None, BudgetEvent::BudgetCreated(created)) => {
+                let payload = created.payload();
+
+                Ok(
+                    Self {
+                        id: payload.id().clone(),
+                        user_id: payload.user_id().clone(),
+                        category_id: payload.category_id().clone(),
+                        period: *payload.period(),
+                        currency: *payload.currency(),
+                        limit: payload.limit(),
+                        rollover: *payload.rollover(),
+                        thresholds: payload.thresholds().to_vec(),
+                        starts_on: *payload.starts_on(),
+                        spent: BTreeMap::new(),
+                        spendings: HashMap::new(),
+                    }
+                )
+            }
+            (Some(budget), BudgetEvent::BudgetCreated(_)) => Err(
+                EventStoreError::Rehydration(
+                    format!("Budget {} is already created", budget.id().value())
+                )
+            ),
+            (Some(mut budget), BudgetEvent::BudgetSpendingRecorded(recorded)) => {
+                let payload = recorded.payload();
+
+                *budget.spent.entry(*payload.period_start()).or_default() += payload.amount();
+                budget.spendings.insert(payload.operation_id().value(), (*payload.period_start(), payload.amount()));
+
+                Ok(budget)
+            }
+            (Some(mut budget), BudgetEvent::BudgetSpendingReverted(reverted)) => {
+                let payload = reverted.payload();
+
+                *budget.spent.entry(*payload.period_start()).or_default() -= payload.amount();
+                budget.spendings.remove(&payload.operation_id().value());
+
+                Ok(budget)
+            }
+            (Some(budget), BudgetEvent::BudgetThresholdReached(_)) => Ok(budget),
+            (None, event) => Err(
+                EventStoreError::Rehydration(
+                    format!("Budget stream must start with budget_created, got {}", event.name())
+                )
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn test_handle_creation() {
+        let command = command_fixture("Monthly", Decimal::from(500), vec![100, 80, 80]);
+
+        let budget = Budget::apply(None, &Budget::handle_creation(command.clone()).unwrap()).unwrap();
+
+        assert_eq!(budget.user_id().value(), *command.user_id());
+        assert_eq!(budget.thresholds, vec![80, 100]);
+        assert_eq!(budget.starts_on, date(2024, 1, 1));
+        assert_eq!(budget.available(&date(2024, 1, 1)), Decimal::from(500));
+    }
+
+    #[test]
+    fn test_handle_creation_with_invalid_data() {
+        let unknown_period = command_fixture("Daily", Decimal::from(500), vec![]);
+        let invalid_limit = command_fixture("Monthly", Decimal::ZERO, vec![]);
+        let invalid_threshold = command_fixture("Monthly", Decimal::from(500), vec![0]);
+
+        assert!(matches!(Budget::handle_creation(unknown_period), Err(DomainError::UnknownPeriod(_))));
+        assert!(matches!(Budget::handle_creation(invalid_limit), Err(DomainError::InvalidLimit)));
+        assert!(matches!(Budget::handle_creation(invalid_threshold), Err(DomainError::InvalidThreshold)));
+    }
+
+    #[test]
+    fn test_handle_spending_reaches_thresholds_once() {
+        let budget = budget_fixture();
+
+        let (budget, first) = spend(budget, Decimal::from(350), date(2024, 1, 10));
+        let (budget, second) = spend(budget, Decimal::from(100), date(2024, 1, 20));
+        let (budget, third) = spend(budget, Decimal::from(100), date(2024, 1, 25));
+
+        assert_eq!(thresholds(&first), Vec::<u32>::new());
+        assert_eq!(thresholds(&second), vec![80]);
+        assert_eq!(thresholds(&third), vec![100]);
+        assert_eq!(budget.spent(&date(2024, 1, 1)), Decimal::from(550));
+    }
+
+    #[test]
+    fn test_handle_spending_ignores_counted_and_earlier_operations() {
+        let budget = budget_fixture();
+        let operation_id = Uuid::new_v4();
+
+        let event = budget.handle_spending(&operation_id, Decimal::from(10), &date(2024, 2, 1)).remove(0);
+        let budget = Budget::apply(Some(budget), &event).unwrap();
+
+        assert!(budget.handle_spending(&operation_id, Decimal::from(10), &date(2024, 2, 1)).is_empty());
+        assert!(budget.handle_spending(&Uuid::new_v4(), Decimal::from(10), &date(2023, 12, 31)).is_empty());
+    }
+
+    #[test]
+    fn test_handle_spending_reversal() {
+        let budget = budget_fixture();
+        let operation_id = Uuid::new_v4();
+
+        let event = budget.handle_spending(&operation_id, Decimal::from(10), &date(2024, 2, 1)).remove(0);
+        let budget = Budget::apply(Some(budget), &event).unwrap();
+
+        assert!(budget.handle_spending_reversal(&Uuid::new_v4()).is_none());
+
+        let event = budget.handle_spending_reversal(&operation_id).unwrap();
+        let budget = Budget::apply(Some(budget), &event).unwrap();
+
+        assert_eq!(budget.spent(&date(2024, 2, 1)), Decimal::ZERO);
+        assert!(budget.handle_spending_reversal(&operation_id).is_none());
+        assert_eq!(budget.handle_spending(&operation_id, Decimal::from(20), &date(2024, 2, 1)).len(), 1);
+    }
+
+    pub fn budget_fixture() -> Budget {
+        Budget::apply(None, &Budget::handle_creation(command_fixture("Monthly", Decimal::from(500), vec![80, 100])).unwrap()).unwrap()
+    }
+
+    fn spend(budget: Budget, amount: Decimal, on: NaiveDate) -> (Budget, Vec<BudgetEvent>) {
+        let events = budget.handle_spending(&Uuid::new_v4(), amount, &on);
+        let budget = events.iter()
+            .try_fold(budget, |budget, event| Budget::apply(Some(budget), event))
+            .unwrap();
+
+        (budget, events)
+    }
+
+    fn thresholds(events: &[BudgetEvent]) -> Vec<u32> {
+        events.iter()
+            .filter_map(|event| match event {
+                BudgetEvent::BudgetThresholdReached(reached) => Some(reached.payload().threshold()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn command_fixture(period: &str, limit: Decimal, thresholds: Vec<u32>) -> CreateBudgetCommand {
+        CreateBudgetCommand::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            period.to_string(),
+            "USD".to_string(),
+            limit,
+            "None".to_string(),
+            thresholds,
+            date(2024, 1, 15),
+        )
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+}
@@ -0,0 +1,65 @@
+use chrono::{Datelike, Days, Months, NaiveDate};
+use serde::{Deserialize, Serialize};
+use crate::features::budgets::domain::error::DomainError;
+
+/// Calendar period a budget limit applies to. Weeks start on Monday.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BudgetPeriod {
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+impl BudgetPeriod {
+    pub fn new(value: &str) -> Result<Self, DomainError> {
+        match value {
+            "Weekly" => Ok(Self::Weekly),
+            "Monthly" => Ok(Self::Monthly),
+            "Yearly" => Ok(Self::Yearly),
+            _ => Err(DomainError::UnknownPeriod(value.to_string())),
+        }
+    }
+
+    pub fn to_str(&self) -> &str {
+        match self {
+            Self::Weekly => "Weekly",
+            Self::Monthly => "Monthly",
+            Self::Yearly => "Yearly",
+        }
+    }
+
+    /// First day of the period containing `date`.
+    pub fn start(&self, date: &NaiveDate) -> NaiveDate {
+        match self {
+            Self::Weekly => *date - Days::new(date.weekday().num_days_from_monday() as u64),
+            Self::Monthly => date.with_day(1).unwrap_or(*date),
+            Self::Yearly => date.with_ordinal(1).unwrap_or(*date),
+        }
+    }
+
+    /// First day of the period following the one starting on `start`.
+    pub fn next(&self, start: &NaiveDate) -> NaiveDate {
+        match self {
+            Self::Weekly => *start + Days::new(7),
+            Self::Monthly => *start + Months::new(1),
+            Self::Yearly => *start + Months::new(12),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_start_and_next() {
+        let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+
+        assert_eq!(BudgetPeriod::Weekly.start(&date), NaiveDate::from_ymd_opt(2024, 2, 26).unwrap());
+        assert_eq!(BudgetPeriod::Monthly.start(&date), NaiveDate::from_ymd_opt(2024, 2, 1).unwrap());
+        assert_eq!(BudgetPeriod::Yearly.start(&date), NaiveDate::from_ymd_opt(2024, 1, 1).unwrap());
+        assert_eq!(BudgetPeriod::Weekly.next(&BudgetPeriod::Weekly.start(&date)), NaiveDate::from_ymd_opt(2024, 3, 4).unwrap());
+        assert_eq!(BudgetPeriod::Monthly.next(&BudgetPeriod::Monthly.start(&date)), NaiveDate::from_ymd_opt(2024, 3, 1).unwrap());
+        assert_eq!(BudgetPeriod::Yearly.next(&BudgetPeriod::Yearly.start(&date)), NaiveDate::from_ymd_opt(2025, 1, 1).unwrap());
+    }
+}
@@ -0,0 +1,58 @@
+use std::collections::HashMap;
+use async_trait::async_trait;
+use uuid::Uuid;
+use crate::features::budgets::domain::events::budget_event::BudgetEvent;
+use crate::features::budgets::domain::budget::Budget;
+use crate::features::budgets::error::BudgetError;
+use crate::features::budgets::infrastructure::error::InfrastructureError;
+use crate::support::event_store::Versioned;
+
+#[async_trait]
+pub trait BudgetRepository {
+    async fn load(&self, budget_id: Uuid) -> Result<Option<Versioned<Budget>>, BudgetError>;
+
+    /// Fails with `InfrastructureError::Conflict` when the stream is no longer at `expected_version`.
+    async fn append(&self, budget_id: Uuid, expected_version: i32, events: &[BudgetEvent]) -> Result<i32, BudgetError>;
+}
+
+pub struct MockBudgetRepository {
+    has_error: bool,
+    budgets: HashMap<Uuid, Versioned<Budget>>,
+}
+
+impl MockBudgetRepository {
+    pub fn new(has_error: bool) -> Self {
+        Self {
+            has_error,
+            budgets: HashMap::new(),
+        }
+    }
+
+    pub fn with_budgets(budgets: Vec<Versioned<Budget>>) -> Self {
+        Self {
+            has_error: false,
+            budgets: budgets.into_iter()
+                .map(|budget| (budget.aggregate().id().value(), budget))
+                .collect(),
+        }
+    }
+}
+
+#[async_trait]
+impl BudgetRepository for MockBudgetRepository {
+    async fn load(&self, budget_id: Uuid) -> Result<Option<Versioned<Budget>>, BudgetError> {
+        Ok(self.budgets.get(&budget_id).cloned())
+    }
+
+    async fn append(&self, _budget_id: Uuid, expected_version: i32, events: &[BudgetEvent]) -> Result<i32, BudgetError> {
+        if self.has_error {
+            return Err(
+                BudgetError::Infrastructure(
+                    InfrastructureError::Repository("Mock repository error".into())
+                )
+            );
+        }
+
+        Ok(expected_version + events.len() as i32)
+    }
+}
@@ -0,0 +1,25 @@
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone)]
+pub enum DomainError {
+    #[error("Budget {0} not found")]
+    BudgetNotFound(String),
+
+    #[error("Budget belongs to another user")]
+    AccessDenied,
+
+    #[error("Unknown budget period {0}")]
+    UnknownPeriod(String),
+
+    #[error("Unknown rollover {0}")]
+    UnknownRollover(String),
+
+    #[error("Unknown currency {0}")]
+    UnknownCurrency(String),
+
+    #[error("Limit must be greater than zero")]
+    InvalidLimit,
+
+    #[error("Threshold must be greater than zero")]
+    InvalidThreshold,
+}
@@ -0,0 +1,111 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use crate::features::budgets::domain::budget_period::BudgetPeriod;
+use crate::features::budgets::domain::rollover::Rollover;
+use crate::support::currency::Currency;
+use crate::support::id::Id;
+
+pub const BUDGET_CREATED_NAME: &str = "budget_created";
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BudgetCreated {
+    id: Id,
+    name: String,
+    payload: BudgetCreatedPayload,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BudgetCreatedPayload {
+    id: Id,
+    user_id: Id,
+    category_id: Id,
+    period: BudgetPeriod,
+    currency: Currency,
+    limit: Decimal,
+    rollover: Rollover,
+    thresholds: Vec<u32>,
+    starts_on: NaiveDate,
+}
+
+impl BudgetCreated {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: Id,
+        budget_id: Id,
+        user_id: Id,
+        category_id: Id,
+        period: BudgetPeriod,
+        currency: Currency,
+        limit: Decimal,
+        rollover: Rollover,
+        thresholds: Vec<u32>,
+        starts_on: NaiveDate,
+    ) -> Self {
+        Self {
+            id,
+            name: BUDGET_CREATED_NAME.to_string(),
+            payload: BudgetCreatedPayload {
+                id: budget_id,
+                user_id,
+                category_id,
+                period,
+                currency,
+                limit,
+                rollover,
+                thresholds,
+                starts_on,
+            }
+        }
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn payload(&self) -> &BudgetCreatedPayload {
+        &self.payload
+    }
+}
+
+impl BudgetCreatedPayload {
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Id {
+        &self.user_id
+    }
+
+    pub fn category_id(&self) -> &Id {
+        &self.category_id
+    }
+
+    pub fn period(&self) -> &BudgetPeriod {
+        &self.period
+    }
+
+    pub fn currency(&self) -> &Currency {
+        &self.currency
+    }
+
+    pub fn limit(&self) -> Decimal {
+        self.limit
+    }
+
+    pub fn rollover(&self) -> &Rollover {
+        &self.rollover
+    }
+
+    pub fn thresholds(&self) -> &[u32] {
+        &self.thresholds
+    }
+
+    pub fn starts_on(&self) -> &NaiveDate {
+        &self.starts_on
+    }
+}
@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+use crate::features::budgets::domain::events::budget_created::{BUDGET_CREATED_NAME, BudgetCreated};
+use crate::features::budgets::domain::events::budget_spending_recorded::{BUDGET_SPENDING_RECORDED_NAME, BudgetSpendingRecorded};
+use crate::features::budgets::domain::events::budget_spending_reverted::{BUDGET_SPENDING_REVERTED_NAME, BudgetSpendingReverted};
+use crate::features::budgets::domain::events::budget_threshold_reached::{BUDGET_THRESHOLD_REACHED_NAME, BudgetThresholdReached};
+use crate::support::event_store::{decode_event, EventStoreError, NewEvent, StorableEvent, StoredEvent};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum BudgetEvent {
+    BudgetCreated(BudgetCreated),
+    BudgetSpendingRecorded(BudgetSpendingRecorded),
+    BudgetSpendingReverted(BudgetSpendingReverted),
+    BudgetThresholdReached(BudgetThresholdReached),
+}
+
+impl BudgetEvent {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::BudgetCreated(event) => event.name(),
+            Self::BudgetSpendingRecorded(event) => event.name(),
+            Self::BudgetSpendingReverted(event) => event.name(),
+            Self::BudgetThresholdReached(event) => event.name(),
+        }
+    }
+}
+
+impl StorableEvent for BudgetEvent {
+    fn from_stored(stored: &StoredEvent) -> Result<Self, EventStoreError> {
+        match stored.name() {
+            BUDGET_CREATED_NAME => Ok(Self::BudgetCreated(decode_event(stored)?)),
+            BUDGET_SPENDING_RECORDED_NAME => Ok(Self::BudgetSpendingRecorded(decode_event(stored)?)),
+            BUDGET_SPENDING_REVERTED_NAME => Ok(Self::BudgetSpendingReverted(decode_event(stored)?)),
+            BUDGET_THRESHOLD_REACHED_NAME => Ok(Self::BudgetThresholdReached(decode_event(stored)?)),
+            name => Err(
+                EventStoreError::Serialization(format!("Unknown budget event {}", name))
+            ),
+        }
+    }
+
+    fn to_new(&self) -> Result<NewEvent, EventStoreError> {
+        match self {
+            Self::BudgetCreated(event) => NewEvent::from_event(event),
+            Self::BudgetSpendingRecorded(event) => NewEvent::from_event(event),
+            Self::BudgetSpendingReverted(event) => NewEvent::from_event(event),
+            Self::BudgetThresholdReached(event) => NewEvent::from_event(event),
+        }
+    }
+}
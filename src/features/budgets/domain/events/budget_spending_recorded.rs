@@ -0,0 +1,73 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use crate::support::id::Id;
+
+pub const BUDGET_SPENDING_RECORDED_NAME: &str = "budget_spending_recorded";
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BudgetSpendingRecorded {
+    id: Id,
+    name: String,
+    payload: BudgetSpendingRecordedPayload,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BudgetSpendingRecordedPayload {
+    id: Id,
+    user_id: Id,
+    operation_id: Id,
+    period_start: NaiveDate,
+    amount: Decimal,
+}
+
+impl BudgetSpendingRecorded {
+    pub fn new(id: Id, budget_id: Id, user_id: Id, operation_id: Id, period_start: NaiveDate, amount: Decimal) -> Self {
+        Self {
+            id,
+            name: BUDGET_SPENDING_RECORDED_NAME.to_string(),
+            payload: BudgetSpendingRecordedPayload {
+                id: budget_id,
+                user_id,
+                operation_id,
+                period_start,
+                amount,
+            }
+        }
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn payload(&self) -> &BudgetSpendingRecordedPayload {
+        &self.payload
+    }
+}
+
+impl BudgetSpendingRecordedPayload {
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Id {
+        &self.user_id
+    }
+
+    pub fn operation_id(&self) -> &Id {
+        &self.operation_id
+    }
+
+    pub fn period_start(&self) -> &NaiveDate {
+        &self.period_start
+    }
+
+    /// Amount in the budget currency.
+    pub fn amount(&self) -> Decimal {
+        self.amount
+    }
+}
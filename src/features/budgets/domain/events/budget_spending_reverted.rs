@@ -0,0 +1,74 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use crate::support::id::Id;
+
+pub const BUDGET_SPENDING_REVERTED_NAME: &str = "budget_spending_reverted";
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BudgetSpendingReverted {
+    id: Id,
+    name: String,
+    payload: BudgetSpendingRevertedPayload,
+}
+
+/// Takes back the spending an operation recorded, when the operation changed or was deleted.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BudgetSpendingRevertedPayload {
+    id: Id,
+    user_id: Id,
+    operation_id: Id,
+    period_start: NaiveDate,
+    amount: Decimal,
+}
+
+impl BudgetSpendingReverted {
+    pub fn new(id: Id, budget_id: Id, user_id: Id, operation_id: Id, period_start: NaiveDate, amount: Decimal) -> Self {
+        Self {
+            id,
+            name: BUDGET_SPENDING_REVERTED_NAME.to_string(),
+            payload: BudgetSpendingRevertedPayload {
+                id: budget_id,
+                user_id,
+                operation_id,
+                period_start,
+                amount,
+            }
+        }
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn payload(&self) -> &BudgetSpendingRevertedPayload {
+        &self.payload
+    }
+}
+
+impl BudgetSpendingRevertedPayload {
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Id {
+        &self.user_id
+    }
+
+    pub fn operation_id(&self) -> &Id {
+        &self.operation_id
+    }
+
+    pub fn period_start(&self) -> &NaiveDate {
+        &self.period_start
+    }
+
+    /// Amount in the budget currency, as it was recorded.
+    pub fn amount(&self) -> Decimal {
+        self.amount
+    }
+}
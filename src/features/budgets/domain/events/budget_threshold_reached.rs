@@ -0,0 +1,95 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use crate::support::id::Id;
+
+pub const BUDGET_THRESHOLD_REACHED_NAME: &str = "budget_threshold_reached";
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BudgetThresholdReached {
+    id: Id,
+    name: String,
+    payload: BudgetThresholdReachedPayload,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BudgetThresholdReachedPayload {
+    id: Id,
+    user_id: Id,
+    category_id: Id,
+    period_start: NaiveDate,
+    threshold: u32,
+    spent: Decimal,
+    available: Decimal,
+}
+
+impl BudgetThresholdReached {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: Id,
+        budget_id: Id,
+        user_id: Id,
+        category_id: Id,
+        period_start: NaiveDate,
+        threshold: u32,
+        spent: Decimal,
+        available: Decimal,
+    ) -> Self {
+        Self {
+            id,
+            name: BUDGET_THRESHOLD_REACHED_NAME.to_string(),
+            payload: BudgetThresholdReachedPayload {
+                id: budget_id,
+                user_id,
+                category_id,
+                period_start,
+                threshold,
+                spent,
+                available,
+            }
+        }
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn payload(&self) -> &BudgetThresholdReachedPayload {
+        &self.payload
+    }
+}
+
+impl BudgetThresholdReachedPayload {
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Id {
+        &self.user_id
+    }
+
+    pub fn category_id(&self) -> &Id {
+        &self.category_id
+    }
+
+    pub fn period_start(&self) -> &NaiveDate {
+        &self.period_start
+    }
+
+    /// Percentage of the available amount that was reached.
+    pub fn threshold(&self) -> u32 {
+        self.threshold
+    }
+
+    pub fn spent(&self) -> Decimal {
+        self.spent
+    }
+
+    pub fn available(&self) -> Decimal {
+        self.available
+    }
+}
@@ -0,0 +1,5 @@
+pub mod budget_created;
+pub mod budget_event;
+pub mod budget_spending_recorded;
+pub mod budget_spending_reverted;
+pub mod budget_threshold_reached;
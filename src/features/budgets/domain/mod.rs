@@ -0,0 +1,6 @@
+pub mod budget;
+pub mod budget_period;
+pub mod budget_repository;
+pub mod error;
+pub mod events;
+pub mod rollover;
@@ -0,0 +1,90 @@
+use std::collections::BTreeMap;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use crate::features::budgets::domain::budget_period::BudgetPeriod;
+use crate::features::budgets::domain::error::DomainError;
+
+/// What is left of a period's limit when the next period starts: `None` starts every period afresh,
+/// `Unspent` carries what was not spent, `Full` also carries overspending as a smaller next limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Rollover {
+    None,
+    Unspent,
+    Full,
+}
+
+impl Rollover {
+    pub fn new(value: &str) -> Result<Self, DomainError> {
+        match value {
+            "None" => Ok(Self::None),
+            "Unspent" => Ok(Self::Unspent),
+            "Full" => Ok(Self::Full),
+            _ => Err(DomainError::UnknownRollover(value.to_string())),
+        }
+    }
+
+    pub fn to_str(&self) -> &str {
+        match self {
+            Self::None => "None",
+            Self::Unspent => "Unspent",
+            Self::Full => "Full",
+        }
+    }
+
+    fn carry(&self, available: Decimal, spent: Decimal) -> Decimal {
+        match self {
+            Self::None => Decimal::ZERO,
+            Self::Unspent => (available - spent).max(Decimal::ZERO),
+            Self::Full => available - spent,
+        }
+    }
+
+    /// Amount available in the period starting on `period_start`: the limit plus what rolled over
+    /// from the periods since `starts_on`. `spent` is keyed by period start.
+    pub fn available(
+        &self,
+        period: &BudgetPeriod,
+        limit: Decimal,
+        starts_on: &NaiveDate,
+        spent: &BTreeMap<NaiveDate, Decimal>,
+        period_start: &NaiveDate,
+    ) -> Decimal {
+        let mut start = period.start(starts_on);
+        let mut available = limit;
+
+        while start < *period_start {
+            let carried = self.carry(available, spent.get(&start).copied().unwrap_or_default());
+
+            available = limit + carried;
+            start = period.next(&start);
+        }
+
+        available
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_available() {
+        let starts_on = date(2024, 1, 10);
+        let spent = BTreeMap::from([
+            (date(2024, 1, 1), Decimal::from(70)),
+            (date(2024, 2, 1), Decimal::from(150)),
+        ]);
+        let available = |rollover: Rollover, period_start| rollover.available(&BudgetPeriod::Monthly, Decimal::from(100), &starts_on, &spent, &period_start);
+
+        assert_eq!(available(Rollover::None, date(2024, 3, 1)), Decimal::from(100));
+        assert_eq!(available(Rollover::Unspent, date(2024, 2, 1)), Decimal::from(130));
+        assert_eq!(available(Rollover::Unspent, date(2024, 3, 1)), Decimal::from(100));
+        assert_eq!(available(Rollover::Full, date(2024, 3, 1)), Decimal::from(80));
+        assert_eq!(available(Rollover::Full, date(2024, 4, 1)), Decimal::from(180));
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+}
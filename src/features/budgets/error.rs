@@ -0,0 +1,12 @@
+use thiserror::Error;
+use crate::features::budgets::domain::error::DomainError;
+use crate::features::budgets::infrastructure::error::InfrastructureError;
+
+#[derive(Error, Debug, Clone)]
+pub enum BudgetError {
+    #[error("Budget domain error: {0}")]
+    Domain(DomainError),
+
+    #[error("Budget infrastructure error: {0}")]
+    Infrastructure(InfrastructureError)
+}
@@ -0,0 +1,168 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use sqlx::{query, query_as, query_scalar, Pool, Postgres};
+use sqlx::postgres::PgArguments;
+use sqlx::query::Query;
+use sqlx::types::Json;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+use crate::db::manager::DbManager;
+use crate::features::budgets::application::queries::budget_projection_repository::BudgetProjectionRepository;
+use crate::features::budgets::application::queries::budget_view::BudgetView;
+use crate::features::budgets::domain::events::budget_created::BudgetCreated;
+use crate::features::budgets::domain::events::budget_spending_recorded::BudgetSpendingRecorded;
+use crate::features::budgets::domain::events::budget_spending_reverted::BudgetSpendingReverted;
+use crate::features::budgets::error::BudgetError;
+use crate::features::budgets::infrastructure::error::InfrastructureError;
+
+/// Budgets with their spendings summed per period.
+const SELECT_BUDGETS: &str = "
+    SELECT b.id,
+           b.user_id,
+           b.category_id,
+           b.period,
+           b.currency,
+           b.amount_limit,
+           b.rollover,
+           b.thresholds,
+           b.starts_on,
+           COALESCE(
+               (
+                   SELECT jsonb_agg(
+                       jsonb_build_object('period_start', s.period_start, 'amount', s.amount::TEXT)
+                       ORDER BY s.period_start
+                   )
+                   FROM (
+                       SELECT period_start, SUM(amount) AS amount
+                       FROM budget_spendings
+                       WHERE budget_id = b.id
+                       GROUP BY period_start
+                   ) s
+               ),
+               '[]'::JSONB
+           ) AS spendings,
+           b.created_at
+    FROM budgets b
+";
+
+#[derive(Clone)]
+pub struct DbBudgetProjectionRepository {
+    db_manager: Arc<Mutex<DbManager>>,
+}
+
+impl DbBudgetProjectionRepository {
+    pub fn new(db_manager: Arc<Mutex<DbManager>>) -> Self {
+        Self {
+            db_manager,
+        }
+    }
+
+    async fn pool(&self) -> Result<Pool<Postgres>, BudgetError> {
+        let guard = self.db_manager.lock().await;
+
+        guard.pool()
+            .map_err(|e|
+                BudgetError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to get pool: {}", e)
+                    )
+                )
+            )
+    }
+
+    async fn execute(&self, res_query: Query<'_, Postgres, PgArguments>) -> Result<(), BudgetError> {
+        let pool = self.pool().await?;
+
+        res_query.execute(&pool)
+            .await
+            .map_err(|e|
+                BudgetError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to project budget: {}", e)
+                    )
+                )
+            )?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl BudgetProjectionRepository for DbBudgetProjectionRepository {
+    async fn find(&self, user_id: Uuid) -> Result<Vec<BudgetView>, BudgetError> {
+        let q = format!("{} WHERE b.user_id = $1 ORDER BY b.created_at, b.id", SELECT_BUDGETS);
+
+        let pool = self.pool().await?;
+
+        query_as::<_, BudgetView>(&q)
+            .bind(user_id)
+            .fetch_all(&pool)
+            .await
+            .map_err(|e|
+                BudgetError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to fetch budgets: {}", e)
+                    )
+                )
+            )
+    }
+
+    async fn find_ids(&self, user_id: Uuid, category_id: Uuid) -> Result<Vec<Uuid>, BudgetError> {
+        let q = "SELECT id FROM budgets WHERE user_id = $1 AND category_id = $2 ORDER BY id";
+
+        let pool = self.pool().await?;
+
+        query_scalar::<_, Uuid>(q)
+            .bind(user_id)
+            .bind(category_id)
+            .fetch_all(&pool)
+            .await
+            .map_err(|e|
+                BudgetError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to fetch budgets of category {}: {}", category_id, e)
+                    )
+                )
+            )
+    }
+
+    async fn apply_budget_created(&self, event: &BudgetCreated) -> Result<(), BudgetError> {
+        let payload = event.payload();
+
+        self.execute(
+            query("INSERT INTO budgets (id, user_id, category_id, period, currency, amount_limit, rollover, thresholds, starts_on) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9) ON CONFLICT DO NOTHING")
+                .bind(payload.id().value())
+                .bind(payload.user_id().value())
+                .bind(payload.category_id().value())
+                .bind(payload.period().to_str().to_string())
+                .bind(payload.currency().code())
+                .bind(payload.limit())
+                .bind(payload.rollover().to_str().to_string())
+                .bind(Json(payload.thresholds().to_vec()))
+                .bind(*payload.starts_on())
+        ).await
+    }
+
+    async fn apply_budget_spending_recorded(&self, event: &BudgetSpendingRecorded) -> Result<(), BudgetError> {
+        let payload = event.payload();
+
+        self.execute(
+            query("INSERT INTO budget_spendings (id, budget_id, operation_id, period_start, amount) VALUES ($1, $2, $3, $4, $5) ON CONFLICT DO NOTHING")
+                .bind(event.id().value())
+                .bind(payload.id().value())
+                .bind(payload.operation_id().value())
+                .bind(*payload.period_start())
+                .bind(payload.amount())
+        ).await
+    }
+
+    async fn apply_budget_spending_reverted(&self, event: &BudgetSpendingReverted) -> Result<(), BudgetError> {
+        let payload = event.payload();
+
+        self.execute(
+            query("DELETE FROM budget_spendings WHERE budget_id = $1 AND operation_id = $2")
+                .bind(payload.id().value())
+                .bind(payload.operation_id().value())
+        ).await
+    }
+}
@@ -0,0 +1,64 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+use crate::db::manager::DbManager;
+use crate::features::budgets::domain::budget::Budget;
+use crate::features::budgets::domain::budget_repository::BudgetRepository;
+use crate::features::budgets::domain::events::budget_event::BudgetEvent;
+use crate::features::budgets::error::BudgetError;
+use crate::features::budgets::infrastructure::error::InfrastructureError;
+use crate::support::event_store::{Aggregate, EventStore, StorableEvent, Versioned};
+use crate::support::pg_event_store::PgEventStore;
+
+const EVENTS_TABLE: &str = "budget_events";
+
+#[derive(Clone)]
+pub struct DbBudgetRepository {
+    event_store: PgEventStore,
+}
+
+impl DbBudgetRepository {
+    pub fn new(db_manager: Arc<Mutex<DbManager>>) -> Self {
+        Self {
+            event_store: PgEventStore::new(db_manager, EVENTS_TABLE),
+        }
+    }
+}
+
+#[async_trait]
+impl BudgetRepository for DbBudgetRepository {
+    async fn load(&self, budget_id: Uuid) -> Result<Option<Versioned<Budget>>, BudgetError> {
+        let stream = self.event_store.load(budget_id)
+            .await
+            .map_err(|e|
+                BudgetError::Infrastructure(
+                    InfrastructureError::Repository(e.to_string())
+                )
+            )?;
+
+        Budget::rehydrate(&stream)
+            .map_err(|e|
+                BudgetError::Infrastructure(
+                    InfrastructureError::Repository(e.to_string())
+                )
+            )
+    }
+
+    async fn append(&self, budget_id: Uuid, expected_version: i32, events: &[BudgetEvent]) -> Result<i32, BudgetError> {
+        let events = events.iter()
+            .map(|event| event.to_new())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e|
+                BudgetError::Infrastructure(
+                    InfrastructureError::Repository(e.to_string())
+                )
+            )?;
+
+        self.event_store.append(budget_id, expected_version, &events)
+            .await
+            .map_err(|e|
+                BudgetError::Infrastructure(e.into())
+            )
+    }
+}
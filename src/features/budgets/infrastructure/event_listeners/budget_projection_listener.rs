@@ -0,0 +1,67 @@
+use async_trait::async_trait;
+use crate::events::error::EventError;
+use crate::events::event::Event;
+use crate::events::event_listener::EventListener;
+use crate::features::budgets::application::queries::budget_projection_repository::BudgetProjectionRepository;
+use crate::features::budgets::domain::events::budget_event::BudgetEvent;
+use crate::support::error::FeatureError;
+
+/// Keeps the `budgets` read model in sync with the budget event stream.
+/// One instance is registered per budget event name it projects.
+pub struct BudgetProjectionListener<R>
+    where
+        R: BudgetProjectionRepository + Send + Sync + 'static,
+{
+    rep: R,
+    event_name: &'static str,
+}
+
+#[async_trait]
+impl<R> EventListener for BudgetProjectionListener<R>
+    where
+        R: BudgetProjectionRepository + Send + Sync + 'static,
+{
+    async fn on_event(&mut self, event: Event) -> Result<Vec<Event>, EventError> {
+        let res = match self.parse_event(event)? {
+            BudgetEvent::BudgetCreated(event) => self.rep.apply_budget_created(&event).await,
+            BudgetEvent::BudgetSpendingRecorded(event) => self.rep.apply_budget_spending_recorded(&event).await,
+            BudgetEvent::BudgetSpendingReverted(event) => self.rep.apply_budget_spending_reverted(&event).await,
+            BudgetEvent::BudgetThresholdReached(_) => Ok(()),
+        };
+
+        res.map_err(|e|
+            EventError::Feature(
+                FeatureError::Budget(e)
+            )
+        )?;
+
+        Ok(vec![])
+    }
+
+    fn event_name(&self) -> &str {
+        self.event_name
+    }
+}
+
+impl<R> BudgetProjectionListener<R>
+    where
+        R: BudgetProjectionRepository + Send + Sync + 'static,
+{
+    pub fn new(rep: R, event_name: &'static str) -> Self {
+        Self {
+            rep,
+            event_name,
+        }
+    }
+
+    pub fn parse_event(&self, event: Event) -> Result<BudgetEvent, EventError> {
+        match event {
+            Event::BudgetEvent(budget_event) => Ok(budget_event),
+            _ => Err(
+                EventError::Parsing(
+                    format!("Invalid event type. Expected BudgetEvent, got {:?}", event)
+                )
+            )
+        }
+    }
+}
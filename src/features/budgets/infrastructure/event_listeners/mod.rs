@@ -0,0 +1,2 @@
+pub mod budget_projection_listener;
+pub mod operation_spending_listener;
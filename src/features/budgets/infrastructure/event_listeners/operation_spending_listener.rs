@@ -0,0 +1,253 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+use crate::events::error::EventError;
+use crate::events::event::Event;
+use crate::events::event_listener::EventListener;
+use crate::features::budgets::application::commands::record_spending::command::RecordSpendingCommand;
+use crate::features::budgets::application::commands::record_spending::handler::RecordSpendingCommandHandler;
+use crate::features::budgets::application::commands::revert_spending::command::RevertSpendingCommand;
+use crate::features::budgets::application::commands::revert_spending::handler::RevertSpendingCommandHandler;
+use crate::features::budgets::application::queries::budget_projection_repository::BudgetProjectionRepository;
+use crate::features::budgets::domain::budget_repository::BudgetRepository;
+use crate::features::operations::domain::amount::Amount;
+use crate::features::operations::domain::events::operation_event::OperationEvent;
+use crate::features::operations::domain::kind::Kind;
+use crate::features::operations::domain::split_line::SplitLine;
+use crate::features::rates::domain::rate_source::RateSource;
+use crate::support::command_bus::CommandBus;
+use crate::support::currency::Currency;
+use crate::support::id::Id;
+
+type SpendingCommandBus<R, P, S> = Arc<Mutex<CommandBus<RecordSpendingCommand, RecordSpendingCommandHandler<R, P, S>>>>;
+type ReversalCommandBus<R, P> = Arc<Mutex<CommandBus<RevertSpendingCommand, RevertSpendingCommandHandler<R, P>>>>;
+
+/// Spending part of an operation state, as far as budgets are concerned.
+struct Expense<'a> {
+    operation_id: &'a Id,
+    user_id: &'a Id,
+    kind: &'a Kind,
+    category_id: &'a Id,
+    currency: &'a Currency,
+    currency_amount: &'a Amount,
+    amount: &'a Amount,
+    lines: &'a [SplitLine],
+    date: NaiveDate,
+}
+
+impl Expense<'_> {
+    /// Spendings to record, none for operations other than expenses.
+    fn commands(&self) -> Vec<RecordSpendingCommand> {
+        if *self.kind != Kind::Expense {
+            return vec![];
+        }
+
+        // Budgets count an operation once, so the lines of a split are summed per category
+        let mut spendings: Vec<(Uuid, Decimal, Decimal)> = vec![];
+
+        match self.lines.is_empty() {
+            true => spendings.push((self.category_id.value(), self.currency_amount.value(), self.amount.value())),
+            false => for line in self.lines {
+                match spendings.iter_mut().find(|(category_id, _, _)| *category_id == line.category_id().value()) {
+                    Some((_, currency_amount, amount)) => {
+                        *currency_amount += line.amount_currency().value();
+                        *amount += line.amount().value();
+                    }
+                    None => spendings.push((line.category_id().value(), line.amount_currency().value(), line.amount().value())),
+                }
+            },
+        }
+
+        spendings.into_iter()
+            .map(|(category_id, currency_amount, amount)|
+                RecordSpendingCommand::new(
+                    self.operation_id.value(),
+                    self.user_id.value(),
+                    category_id,
+                    self.currency.code().to_string(),
+                    currency_amount,
+                    amount,
+                    self.date,
+                )
+            )
+            .collect()
+    }
+}
+
+/// Counts expenses against the budgets of their category, or of the categories of their lines.
+/// An update takes back what the previous state counted and counts the new one, a deletion takes it back.
+/// One instance is registered per operation event name.
+pub struct OperationSpendingListener<R, P, S>
+    where
+        R: BudgetRepository + Send + Sync + 'static,
+        P: BudgetProjectionRepository + Send + Sync + 'static,
+        S: RateSource + Send + Sync + 'static,
+{
+    command_bus: SpendingCommandBus<R, P, S>,
+    reversal_bus: ReversalCommandBus<R, P>,
+    event_name: &'static str,
+}
+
+#[async_trait]
+impl<R, P, S> EventListener for OperationSpendingListener<R, P, S>
+    where
+        R: BudgetRepository + Clone + Send + Sync + 'static,
+        P: BudgetProjectionRepository + Clone + Send + Sync + 'static,
+        S: RateSource + Send + Sync + 'static,
+{
+    async fn on_event(&mut self, event: Event) -> Result<Vec<Event>, EventError> {
+        let mut events = vec![];
+
+        let (reverted, commands) = match &self.parse_event(event)? {
+            OperationEvent::OperationCreated(operation_created) => {
+                let payload = operation_created.payload();
+
+                (
+                    None,
+                    Expense {
+                        operation_id: payload.id(),
+                        user_id: payload.user_id(),
+                        kind: payload.kind(),
+                        category_id: payload.category_id(),
+                        currency: payload.currency(),
+                        currency_amount: payload.amount_currency(),
+                        amount: payload.amount(),
+                        lines: payload.lines(),
+                        date: payload.created_at().date_naive(),
+                    }.commands(),
+                )
+            }
+            OperationEvent::OperationUpdated(operation_updated) => {
+                let payload = operation_updated.payload();
+
+                (
+                    Some(RevertSpendingCommand::new(payload.id().value(), payload.user_id().value())),
+                    Expense {
+                        operation_id: payload.id(),
+                        user_id: payload.user_id(),
+                        kind: payload.kind(),
+                        category_id: payload.category_id(),
+                        currency: payload.currency(),
+                        currency_amount: payload.amount_currency(),
+                        amount: payload.amount(),
+                        lines: payload.lines(),
+                        // Updates recorded before they carried the date of the operation count on the update day
+                        date: payload.created_at().unwrap_or(payload.updated_at()).date_naive(),
+                    }.commands(),
+                )
+            }
+            OperationEvent::OperationDeleted(operation_deleted) => {
+                let payload = operation_deleted.payload();
+
+                (Some(RevertSpendingCommand::new(payload.id().value(), payload.user_id().value())), vec![])
+            }
+            // Requests to other bounded contexts do not spend anything
+            _ => (None, vec![]),
+        };
+
+        if let Some(command) = reverted {
+            events.extend(
+                self.reversal_bus.lock()
+                    .await
+                    .dispatch(command)
+                    .await
+                    .map_err(EventError::Feature)?
+            );
+        }
+
+        let mut guard = self.command_bus.lock().await;
+
+        for command in commands {
+            events.extend(
+                guard.dispatch(command)
+                    .await
+                    .map_err(EventError::Feature)?
+            );
+        }
+
+        Ok(events)
+    }
+
+    fn event_name(&self) -> &str {
+        self.event_name
+    }
+}
+
+impl<R, P, S> OperationSpendingListener<R, P, S>
+    where
+        R: BudgetRepository + Clone + Send + Sync + 'static,
+        P: BudgetProjectionRepository + Clone + Send + Sync + 'static,
+        S: RateSource + Send + Sync + 'static,
+{
+    pub async fn new(
+        command_bus: SpendingCommandBus<R, P, S>,
+        reversal_bus: ReversalCommandBus<R, P>,
+        rep: R,
+        projection_rep: P,
+        rates: S,
+        event_name: &'static str,
+    ) -> Self {
+        reversal_bus.lock()
+            .await
+            .register(RevertSpendingCommandHandler::new(rep.clone(), projection_rep.clone()));
+        command_bus.lock()
+            .await
+            .register(RecordSpendingCommandHandler::new(rep, projection_rep, rates));
+
+        Self {
+            command_bus: command_bus.clone(),
+            reversal_bus: reversal_bus.clone(),
+            event_name,
+        }
+    }
+
+    pub fn parse_event(&self, event: Event) -> Result<OperationEvent, EventError> {
+        match event {
+            Event::OperationEvent(operation_event) => Ok(operation_event),
+            _ => Err(
+                EventError::Parsing(
+                    format!("Invalid event type. Expected OperationEvent, got {:?}", event)
+                )
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_commands_sum_split_lines_per_category() {
+        let (operation_id, user_id, groceries, household) = (Id::new(Id::generate()), Id::new(Id::generate()), Id::new(Id::generate()), Id::new(Id::generate()));
+        let amount = |value: i64| Amount::new(Decimal::from(value)).unwrap();
+        let (currency, total) = (Currency::find("USD").unwrap(), amount(60));
+        let lines = vec![
+            SplitLine::new(groceries.clone(), amount(20), amount(20), "Food".to_string(), vec![]),
+            SplitLine::new(household.clone(), amount(30), amount(30), "Soap".to_string(), vec![]),
+            SplitLine::new(groceries.clone(), amount(10), amount(10), "Drinks".to_string(), vec![]),
+        ];
+        let expense = |kind: &'static Kind| Expense {
+            operation_id: &operation_id,
+            user_id: &user_id,
+            kind,
+            category_id: &groceries,
+            currency: &currency,
+            currency_amount: &total,
+            amount: &total,
+            lines: &lines,
+            date: NaiveDate::from_ymd_opt(2024, 4, 2).unwrap(),
+        }.commands();
+
+        let commands = expense(&Kind::Expense);
+
+        assert_eq!(commands.len(), 2);
+        assert_eq!(*commands[0].category_id(), groceries.value());
+        assert_eq!(commands[0].currency_amount(), Decimal::from(30));
+        assert_eq!(commands[1].amount(), Decimal::from(30));
+        assert!(expense(&Kind::Income).is_empty());
+    }
+}
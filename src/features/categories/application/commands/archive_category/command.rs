@@ -0,0 +1,31 @@
+use uuid::Uuid;
+use crate::support::command_bus::Command;
+
+#[derive(Debug, Clone)]
+pub struct ArchiveCategoryCommand {
+    category_id: Uuid,
+    user_id: Uuid,
+}
+
+impl ArchiveCategoryCommand {
+    pub fn new(category_id: Uuid, user_id: Uuid) -> Self {
+        Self {
+            category_id,
+            user_id,
+        }
+    }
+
+    pub fn category_id(&self) -> &Uuid {
+        &self.category_id
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+}
+
+impl Command for ArchiveCategoryCommand {
+    fn name() -> &'static str {
+        "archive_category"
+    }
+}
@@ -0,0 +1,60 @@
+use async_trait::async_trait;
+use crate::events::event::Event;
+use crate::features::categories::application::commands::archive_category::command::ArchiveCategoryCommand;
+use crate::features::categories::domain::category_repository::CategoryRepository;
+use crate::features::categories::domain::error::DomainError;
+use crate::features::categories::error::CategoryError;
+use crate::support::command_bus::CommandHandler;
+use crate::support::error::FeatureError;
+
+pub struct ArchiveCategoryCommandHandler<R>
+    where
+        R: CategoryRepository + Send + Sync,
+{
+    category_repository: R,
+}
+
+impl<R> ArchiveCategoryCommandHandler<R>
+    where
+        R: CategoryRepository + Send + Sync,
+{
+    pub fn new(category_repository: R) -> Self {
+        Self {
+            category_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> CommandHandler<ArchiveCategoryCommand> for ArchiveCategoryCommandHandler<R>
+    where
+        R: CategoryRepository + Send + Sync,
+{
+    async fn handle(&mut self, command: ArchiveCategoryCommand) -> Result<Vec<Event>, FeatureError> {
+        let category = self.category_repository.load(*command.category_id())
+            .await
+            .map_err(FeatureError::Category)?
+            .ok_or(
+                FeatureError::Category(
+                    CategoryError::Domain(
+                        DomainError::CategoryNotFound(command.category_id().to_string())
+                    )
+                )
+            )?;
+
+        let event = category.aggregate().handle_archiving(command)
+            .map_err(|e|
+                FeatureError::Category(
+                    CategoryError::Domain(e)
+                )
+            )?;
+
+        self.category_repository.append(category.aggregate().id().value(), category.version(), std::slice::from_ref(&event))
+            .await
+            .map_err(FeatureError::Category)?;
+
+        Ok(
+            vec![Event::CategoryEvent(event)]
+        )
+    }
+}
@@ -0,0 +1,37 @@
+use uuid::Uuid;
+use crate::support::command_bus::Command;
+
+#[derive(Debug, Clone)]
+pub struct ChangeCategoryIconCommand {
+    category_id: Uuid,
+    user_id: Uuid,
+    icon: Option<String>,
+}
+
+impl ChangeCategoryIconCommand {
+    pub fn new(category_id: Uuid, user_id: Uuid, icon: Option<String>) -> Self {
+        Self {
+            category_id,
+            user_id,
+            icon,
+        }
+    }
+
+    pub fn category_id(&self) -> &Uuid {
+        &self.category_id
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    pub fn icon(&self) -> &Option<String> {
+        &self.icon
+    }
+}
+
+impl Command for ChangeCategoryIconCommand {
+    fn name() -> &'static str {
+        "change_category_icon"
+    }
+}
@@ -0,0 +1,82 @@
+use async_trait::async_trait;
+use crate::events::event::Event;
+use crate::features::categories::application::commands::change_category_icon::command::ChangeCategoryIconCommand;
+use crate::features::categories::domain::category_repository::CategoryRepository;
+use crate::features::categories::domain::error::DomainError;
+use crate::features::categories::error::CategoryError;
+use crate::support::command_bus::CommandHandler;
+use crate::support::error::FeatureError;
+
+pub struct ChangeCategoryIconCommandHandler<R>
+    where
+        R: CategoryRepository + Send + Sync,
+{
+    category_repository: R,
+}
+
+impl<R> ChangeCategoryIconCommandHandler<R>
+    where
+        R: CategoryRepository + Send + Sync,
+{
+    pub fn new(category_repository: R) -> Self {
+        Self {
+            category_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> CommandHandler<ChangeCategoryIconCommand> for ChangeCategoryIconCommandHandler<R>
+    where
+        R: CategoryRepository + Send + Sync,
+{
+    async fn handle(&mut self, command: ChangeCategoryIconCommand) -> Result<Vec<Event>, FeatureError> {
+        let category = self.category_repository.load(*command.category_id())
+            .await
+            .map_err(FeatureError::Category)?
+            .ok_or(
+                FeatureError::Category(
+                    CategoryError::Domain(
+                        DomainError::CategoryNotFound(command.category_id().to_string())
+                    )
+                )
+            )?;
+
+        let event = category.aggregate().handle_icon_change(command)
+            .map_err(|e|
+                FeatureError::Category(
+                    CategoryError::Domain(e)
+                )
+            )?;
+
+        self.category_repository.append(category.aggregate().id().value(), category.version(), std::slice::from_ref(&event))
+            .await
+            .map_err(FeatureError::Category)?;
+
+        Ok(
+            vec![Event::CategoryEvent(event)]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::FutureExt;
+    use uuid::Uuid;
+    use crate::features::categories::domain::category_repository::MockCategoryRepository;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_change_category_icon_command_handler_not_found() {
+        let mut rep = MockCategoryRepository::new();
+        rep.expect_load()
+            .times(1)
+            .returning(|_| async { Ok(None) }.boxed());
+        rep.expect_append().never();
+
+        let command = ChangeCategoryIconCommand::new(Uuid::new_v4(), Uuid::new_v4(), Some("cart".to_string()));
+        let result = ChangeCategoryIconCommandHandler::new(rep).handle(command).await;
+
+        assert!(matches!(result, Err(FeatureError::Category(CategoryError::Domain(DomainError::CategoryNotFound(_))))));
+    }
+}
@@ -6,14 +6,16 @@ pub struct CreateCategoryCommand {
     user_id: Uuid,
     name: String,
     icon: Option<String>,
+    parent_id: Option<Uuid>,
 }
 
 impl CreateCategoryCommand {
-    pub fn new(user_id: Uuid, name: String, icon: Option<String>) -> Self {
+    pub fn new(user_id: Uuid, name: String, icon: Option<String>, parent_id: Option<Uuid>) -> Self {
         Self {
             user_id,
             name,
             icon,
+            parent_id,
         }
     }
 
@@ -28,6 +30,10 @@ impl CreateCategoryCommand {
     pub fn icon(&self) -> &Option<String> {
         &self.icon
     }
+
+    pub fn parent_id(&self) -> &Option<Uuid> {
+        &self.parent_id
+    }
 }
 
 impl Command for CreateCategoryCommand {
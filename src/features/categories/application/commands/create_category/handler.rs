@@ -4,8 +4,6 @@ use crate::features::categories::application::commands::create_category::command
 use crate::features::categories::domain::category::Category;
 use crate::features::categories::domain::category_repository::CategoryRepository;
 use crate::features::categories::domain::error::DomainError;
-use crate::features::categories::domain::events::category_created::CATEGORY_CREATED_NAME;
-use crate::features::categories::domain::events::category_deleted::CATEGORY_DELETED_NAME;
 use crate::features::categories::domain::events::category_event::CategoryEvent;
 use crate::features::categories::error::CategoryError;
 use crate::support::command_bus::CommandHandler;
@@ -36,10 +34,11 @@ impl<R> CommandHandler<CreateCategoryCommand> for CreateCategoryCommandHandler<R
         R: CategoryRepository + Send + Sync,
 {
     async fn handle(&mut self, command: CreateCategoryCommand) -> Result<Vec<Event>, FeatureError> {
-        let exists = self.category_repository.exists(CATEGORY_CREATED_NAME, CATEGORY_DELETED_NAME, &command.category_name()).await
-            .map_err(|e| FeatureError::Category(e))?;
+        let existing_id = self.category_repository.find_id_by_name(*command.user_id(), command.category_name())
+            .await
+            .map_err(FeatureError::Category)?;
 
-        if exists {
+        if existing_id.is_some() {
             return Err(
                 FeatureError::Category(
                     CategoryError::Domain(
@@ -49,22 +48,34 @@ impl<R> CommandHandler<CreateCategoryCommand> for CreateCategoryCommandHandler<R
             );
         }
 
-        let event = Category::handle_creation(command)
+        let parent = match command.parent_id() {
+            Some(parent_id) => Some(
+                self.category_repository.load(*parent_id)
+                    .await
+                    .map_err(FeatureError::Category)?
+                    .ok_or(
+                        FeatureError::Category(
+                            CategoryError::Domain(
+                                DomainError::InvalidParentCategory(parent_id.to_string())
+                            )
+                        )
+                    )?
+            ),
+            None => None,
+        };
+
+        let event = Category::handle_creation(command, parent.as_ref().map(|parent| parent.aggregate()))
             .map_err(|e|
                 FeatureError::Category(
                     CategoryError::Domain(e)
                 )
             )?;
 
-        match event.clone() {
-            CategoryEvent::CategoryCreated(category_created) => {
-                self.category_repository.persist_category_created_event(&category_created)
-                    .await
-                    .map_err(|e|
-                        FeatureError::Category(e)
-                    )?;
-            }
-        };
+        if let CategoryEvent::CategoryCreated(category_created) = &event {
+            self.category_repository.persist_category_created_event(category_created)
+                .await
+                .map_err(FeatureError::Category)?;
+        }
 
         Ok(
             vec![Event::CategoryEvent(event)]
@@ -82,11 +93,11 @@ mod tests {
     #[tokio::test]
     async fn test_create_category_command_handler_success() {
         let mut rep = MockCategoryRepository::new();
-        rep.expect_exists()
+        rep.expect_find_id_by_name()
             .times(1)
-            .returning(|_, _, _| {
+            .returning(|_, _| {
                 async move {
-                    Ok(false) // Симулируем асинхронное выполнение
+                    Ok(None) // Симулируем асинхронное выполнение
                 }.boxed()
             });
         rep.expect_persist_category_created_event()
@@ -104,9 +115,9 @@ mod tests {
     #[tokio::test]
     async fn test_create_category_command_handler_existing_category() {
         let mut rep = MockCategoryRepository::new();
-        rep.expect_exists()
+        rep.expect_find_id_by_name()
             .times(1)
-            .returning(|_, _, _| async { Ok(true) }.boxed()); // Имитация существующей категории
+            .returning(|_, _| async { Ok(Some(Uuid::new_v4())) }.boxed()); // Имитация существующей категории
 
         let mut create_category_command_handler = CreateCategoryCommandHandler::new(rep);
 
@@ -119,9 +130,9 @@ mod tests {
     #[tokio::test]
     async fn test_create_category_command_handler_repository_error() {
         let mut rep = MockCategoryRepository::new();
-        rep.expect_exists()
+        rep.expect_find_id_by_name()
             .times(1)
-            .returning(|_, _, _| async {
+            .returning(|_, _| async {
                 Err(
                     CategoryError::Domain(
                         DomainError::CategoryAlreadyExists("Test Category".to_string())
@@ -138,11 +149,29 @@ mod tests {
     }
 
 
+    #[tokio::test]
+    async fn test_create_category_command_handler_unknown_parent() {
+        let mut rep = MockCategoryRepository::new();
+        rep.expect_find_id_by_name()
+            .times(1)
+            .returning(|_, _| async { Ok(None) }.boxed());
+        rep.expect_load()
+            .times(1)
+            .returning(|_| async { Ok(None) }.boxed());
+        rep.expect_persist_category_created_event().never();
+
+        let command = CreateCategoryCommand::new(Uuid::new_v4(), "Produce".to_string(), None, Some(Uuid::new_v4()));
+        let result = CreateCategoryCommandHandler::new(rep).handle(command).await;
+
+        assert!(matches!(result, Err(FeatureError::Category(CategoryError::Domain(DomainError::InvalidParentCategory(_))))));
+    }
+
     fn create_command_fixture() -> CreateCategoryCommand {
         CreateCategoryCommand::new(
             Uuid::new_v4(),
             "Test Category".to_string(),
             None,
+            None,
         )
     }
 }
\ No newline at end of file
@@ -0,0 +1,37 @@
+use uuid::Uuid;
+use crate::support::command_bus::Command;
+
+#[derive(Debug, Clone)]
+pub struct DeleteCategoryCommand {
+    category_id: Uuid,
+    user_id: Uuid,
+    fallback_category_id: Uuid,
+}
+
+impl DeleteCategoryCommand {
+    pub fn new(category_id: Uuid, user_id: Uuid, fallback_category_id: Uuid) -> Self {
+        Self {
+            category_id,
+            user_id,
+            fallback_category_id,
+        }
+    }
+
+    pub fn category_id(&self) -> &Uuid {
+        &self.category_id
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    pub fn fallback_category_id(&self) -> &Uuid {
+        &self.fallback_category_id
+    }
+}
+
+impl Command for DeleteCategoryCommand {
+    fn name() -> &'static str {
+        "delete_category"
+    }
+}
@@ -0,0 +1,225 @@
+use async_trait::async_trait;
+use crate::events::event::Event;
+use crate::features::categories::application::commands::delete_category::command::DeleteCategoryCommand;
+use crate::features::categories::application::queries::category_projection_repository::CategoryProjectionRepository;
+use crate::features::categories::domain::category_repository::CategoryRepository;
+use crate::features::categories::domain::error::DomainError;
+use crate::features::categories::error::CategoryError;
+use crate::support::command_bus::CommandHandler;
+use crate::support::error::FeatureError;
+
+pub struct DeleteCategoryCommandHandler<R, P>
+    where
+        R: CategoryRepository + Send + Sync,
+        P: CategoryProjectionRepository + Send + Sync,
+{
+    category_repository: R,
+    projection_repository: P,
+}
+
+impl<R, P> DeleteCategoryCommandHandler<R, P>
+    where
+        R: CategoryRepository + Send + Sync,
+        P: CategoryProjectionRepository + Send + Sync,
+{
+    pub fn new(category_repository: R, projection_repository: P) -> Self {
+        Self {
+            category_repository,
+            projection_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<R, P> CommandHandler<DeleteCategoryCommand> for DeleteCategoryCommandHandler<R, P>
+    where
+        R: CategoryRepository + Send + Sync,
+        P: CategoryProjectionRepository + Send + Sync,
+{
+    async fn handle(&mut self, command: DeleteCategoryCommand) -> Result<Vec<Event>, FeatureError> {
+        let category = self.category_repository.load(*command.category_id())
+            .await
+            .map_err(FeatureError::Category)?
+            .ok_or(
+                FeatureError::Category(
+                    CategoryError::Domain(
+                        DomainError::CategoryNotFound(command.category_id().to_string())
+                    )
+                )
+            )?;
+
+        let fallback = self.category_repository.load(*command.fallback_category_id())
+            .await
+            .map_err(FeatureError::Category)?
+            .ok_or(
+                FeatureError::Category(
+                    CategoryError::Domain(
+                        DomainError::InvalidFallbackCategory(command.fallback_category_id().to_string())
+                    )
+                )
+            )?;
+
+        let user_id = *command.user_id();
+        let event = category.aggregate().handle_deletion(command, fallback.aggregate())
+            .map_err(|e|
+                FeatureError::Category(
+                    CategoryError::Domain(e)
+                )
+            )?;
+
+        self.category_repository.append(category.aggregate().id().value(), category.version(), std::slice::from_ref(&event))
+            .await
+            .map_err(FeatureError::Category)?;
+
+        let mut events = vec![Event::CategoryEvent(event)];
+
+        let child_ids = self.projection_repository.find_child_ids(user_id, category.aggregate().id().value())
+            .await
+            .map_err(FeatureError::Category)?;
+
+        for child_id in child_ids {
+            let Some(child) = self.category_repository.load(child_id)
+                .await
+                .map_err(FeatureError::Category)? else {
+                continue;
+            };
+
+            // The read model may lag behind, the child could have been moved or deleted meanwhile
+            if child.aggregate().is_deleted() || child.aggregate().parent_id().as_ref() != Some(category.aggregate().id()) {
+                continue;
+            }
+
+            let moved = child.aggregate().handle_parent_deletion(category.aggregate())
+                .map_err(|e|
+                    FeatureError::Category(
+                        CategoryError::Domain(e)
+                    )
+                )?;
+
+            self.category_repository.append(child_id, child.version(), std::slice::from_ref(&moved))
+                .await
+                .map_err(FeatureError::Category)?;
+
+            events.push(Event::CategoryEvent(moved));
+        }
+
+        Ok(events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::FutureExt;
+    use uuid::Uuid;
+    use crate::features::categories::application::commands::create_category::command::CreateCategoryCommand;
+    use crate::features::categories::application::queries::category_projection_repository::MockCategoryProjectionRepository;
+    use crate::features::categories::domain::category::Category;
+    use crate::features::categories::domain::category_repository::MockCategoryRepository;
+    use crate::features::categories::domain::events::category_event::CategoryEvent;
+    use crate::support::event_store::{Aggregate, Versioned};
+    use super::*;
+
+    #[tokio::test]
+    async fn test_delete_category_command_handler_success() {
+        let user_id = Uuid::new_v4();
+        let category = category_fixture(user_id);
+        let fallback = category_fixture(user_id);
+        let command = DeleteCategoryCommand::new(category.aggregate().id().value(), user_id, fallback.aggregate().id().value());
+
+        let mut rep = MockCategoryRepository::new();
+        rep.expect_load()
+            .times(2)
+            .returning(move |id| {
+                let found = if id == category.aggregate().id().value() { category.clone() } else { fallback.clone() };
+                async move { Ok(Some(found)) }.boxed()
+            });
+        rep.expect_append()
+            .times(1)
+            .returning(|_, version, _| async move { Ok(version + 1) }.boxed());
+
+        let mut projection_rep = MockCategoryProjectionRepository::new();
+        projection_rep.expect_find_child_ids()
+            .times(1)
+            .returning(|_, _| async { Ok(vec![]) }.boxed());
+
+        let events = DeleteCategoryCommandHandler::new(rep, projection_rep).handle(command).await.unwrap();
+
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], Event::CategoryEvent(CategoryEvent::CategoryDeleted(_))));
+    }
+
+    #[tokio::test]
+    async fn test_delete_category_command_handler_moves_children_up() {
+        let user_id = Uuid::new_v4();
+        let food = category_fixture(user_id);
+        let groceries = child_fixture(food.aggregate());
+        let produce = child_fixture(groceries.aggregate());
+        let fallback = category_fixture(user_id);
+        let command = DeleteCategoryCommand::new(groceries.aggregate().id().value(), user_id, fallback.aggregate().id().value());
+        let produce_id = produce.aggregate().id().value();
+
+        let categories = [groceries.clone(), produce, fallback];
+        let mut rep = MockCategoryRepository::new();
+        rep.expect_load()
+            .times(3)
+            .returning(move |id| {
+                let found = categories.iter().find(|category| category.aggregate().id().value() == id).cloned();
+                async move { Ok(found) }.boxed()
+            });
+        rep.expect_append()
+            .times(2)
+            .returning(|_, version, _| async move { Ok(version + 1) }.boxed());
+
+        let mut projection_rep = MockCategoryProjectionRepository::new();
+        projection_rep.expect_find_child_ids()
+            .times(1)
+            .returning(move |_, _| async move { Ok(vec![produce_id]) }.boxed());
+
+        let events = DeleteCategoryCommandHandler::new(rep, projection_rep).handle(command).await.unwrap();
+
+        match &events[1] {
+            Event::CategoryEvent(CategoryEvent::CategoryMoved(category_moved)) => {
+                assert_eq!(category_moved.payload().id().value(), produce_id);
+                assert_eq!(category_moved.payload().parent_id(), &Some(food.aggregate().id().clone()));
+            }
+            _ => panic!("Expected CategoryMoved event"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_delete_category_command_handler_unknown_fallback() {
+        let user_id = Uuid::new_v4();
+        let category = category_fixture(user_id);
+        let command = DeleteCategoryCommand::new(category.aggregate().id().value(), user_id, Uuid::new_v4());
+
+        let mut rep = MockCategoryRepository::new();
+        rep.expect_load()
+            .times(2)
+            .returning(move |id| {
+                let found = (id == category.aggregate().id().value()).then(|| category.clone());
+                async move { Ok(found) }.boxed()
+            });
+        rep.expect_append().never();
+
+        let mut projection_rep = MockCategoryProjectionRepository::new();
+        projection_rep.expect_find_child_ids().never();
+
+        let result = DeleteCategoryCommandHandler::new(rep, projection_rep).handle(command).await;
+
+        assert!(matches!(result, Err(FeatureError::Category(CategoryError::Domain(DomainError::InvalidFallbackCategory(_))))));
+    }
+
+    fn category_fixture(user_id: Uuid) -> Versioned<Category> {
+        let command = CreateCategoryCommand::new(user_id, "Food".to_string(), None, None);
+        let event = Category::handle_creation(command, None).unwrap();
+
+        Versioned::new(Category::apply(None, &event).unwrap(), 1)
+    }
+
+    fn child_fixture(parent: &Category) -> Versioned<Category> {
+        let command = CreateCategoryCommand::new(parent.user_id().value(), "Groceries".to_string(), None, Some(parent.id().value()));
+        let event = Category::handle_creation(command, Some(parent)).unwrap();
+
+        Versioned::new(Category::apply(None, &event).unwrap(), 1)
+    }
+}
@@ -1 +1,7 @@
-pub mod create_category;
\ No newline at end of file
+pub mod archive_category;
+pub mod change_category_icon;
+pub mod create_category;
+pub mod delete_category;
+pub mod move_category;
+pub mod rename_category;
+pub mod resolve_category;
\ No newline at end of file
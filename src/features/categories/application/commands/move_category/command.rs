@@ -0,0 +1,38 @@
+use uuid::Uuid;
+use crate::support::command_bus::Command;
+
+#[derive(Debug, Clone)]
+pub struct MoveCategoryCommand {
+    category_id: Uuid,
+    user_id: Uuid,
+    parent_id: Option<Uuid>,
+}
+
+impl MoveCategoryCommand {
+    pub fn new(category_id: Uuid, user_id: Uuid, parent_id: Option<Uuid>) -> Self {
+        Self {
+            category_id,
+            user_id,
+            parent_id,
+        }
+    }
+
+    pub fn category_id(&self) -> &Uuid {
+        &self.category_id
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    /// `None` moves the category to the root.
+    pub fn parent_id(&self) -> &Option<Uuid> {
+        &self.parent_id
+    }
+}
+
+impl Command for MoveCategoryCommand {
+    fn name() -> &'static str {
+        "move_category"
+    }
+}
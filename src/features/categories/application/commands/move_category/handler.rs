@@ -0,0 +1,179 @@
+use std::collections::HashSet;
+use async_trait::async_trait;
+use uuid::Uuid;
+use crate::events::event::Event;
+use crate::features::categories::application::commands::move_category::command::MoveCategoryCommand;
+use crate::features::categories::domain::category::Category;
+use crate::features::categories::domain::category_repository::CategoryRepository;
+use crate::features::categories::domain::error::DomainError;
+use crate::features::categories::error::CategoryError;
+use crate::support::command_bus::CommandHandler;
+use crate::support::error::FeatureError;
+
+pub struct MoveCategoryCommandHandler<R>
+    where
+        R: CategoryRepository + Send + Sync,
+{
+    category_repository: R,
+}
+
+impl<R> MoveCategoryCommandHandler<R>
+    where
+        R: CategoryRepository + Send + Sync,
+{
+    pub fn new(category_repository: R) -> Self {
+        Self {
+            category_repository,
+        }
+    }
+
+    /// Loads `parent_id` and its ancestors up to the root. The walk stops early once it reaches
+    /// `category_id`, which is all the domain needs to reject the move.
+    async fn load_lineage(&self, category_id: Uuid, parent_id: Option<Uuid>) -> Result<Vec<Category>, FeatureError> {
+        let mut lineage = vec![];
+        let mut visited = HashSet::new();
+        let mut next_id = parent_id;
+
+        while let Some(id) = next_id {
+            if !visited.insert(id) {
+                break;
+            }
+
+            let ancestor = self.category_repository.load(id)
+                .await
+                .map_err(FeatureError::Category)?;
+
+            let Some(ancestor) = ancestor else {
+                break;
+            };
+
+            let ancestor = ancestor.into_aggregate();
+            next_id = match ancestor.id().value() == category_id {
+                true => None,
+                false => ancestor.parent_id().as_ref().map(|parent_id| parent_id.value()),
+            };
+
+            lineage.push(ancestor);
+        }
+
+        Ok(lineage)
+    }
+}
+
+#[async_trait]
+impl<R> CommandHandler<MoveCategoryCommand> for MoveCategoryCommandHandler<R>
+    where
+        R: CategoryRepository + Send + Sync,
+{
+    async fn handle(&mut self, command: MoveCategoryCommand) -> Result<Vec<Event>, FeatureError> {
+        let category = self.category_repository.load(*command.category_id())
+            .await
+            .map_err(FeatureError::Category)?
+            .ok_or(
+                FeatureError::Category(
+                    CategoryError::Domain(
+                        DomainError::CategoryNotFound(command.category_id().to_string())
+                    )
+                )
+            )?;
+
+        let lineage = self.load_lineage(*command.category_id(), *command.parent_id()).await?;
+
+        let event = category.aggregate().handle_move(command, &lineage)
+            .map_err(|e|
+                FeatureError::Category(
+                    CategoryError::Domain(e)
+                )
+            )?;
+
+        self.category_repository.append(category.aggregate().id().value(), category.version(), std::slice::from_ref(&event))
+            .await
+            .map_err(FeatureError::Category)?;
+
+        Ok(
+            vec![Event::CategoryEvent(event)]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use futures_util::FutureExt;
+    use crate::features::categories::application::commands::create_category::command::CreateCategoryCommand;
+    use crate::features::categories::domain::category_repository::MockCategoryRepository;
+    use crate::features::categories::domain::events::category_event::CategoryEvent;
+    use crate::support::event_store::{Aggregate, Versioned};
+    use super::*;
+
+    #[tokio::test]
+    async fn test_move_category_command_handler_success() {
+        let user_id = Uuid::new_v4();
+        let food = category_fixture(user_id, None);
+        let home = category_fixture(user_id, None);
+        let groceries = category_fixture(user_id, Some(food.aggregate()));
+        let command = MoveCategoryCommand::new(groceries.aggregate().id().value(), user_id, Some(home.aggregate().id().value()));
+
+        let mut rep = repository_fixture(vec![food, home, groceries]);
+        rep.expect_append()
+            .times(1)
+            .returning(|_, version, _| async move { Ok(version + 1) }.boxed());
+
+        let events = MoveCategoryCommandHandler::new(rep).handle(command).await.unwrap();
+
+        assert!(matches!(events[0], Event::CategoryEvent(CategoryEvent::CategoryMoved(_))));
+    }
+
+    #[tokio::test]
+    async fn test_move_category_command_handler_under_descendant() {
+        let user_id = Uuid::new_v4();
+        let food = category_fixture(user_id, None);
+        let groceries = category_fixture(user_id, Some(food.aggregate()));
+        let produce = category_fixture(user_id, Some(groceries.aggregate()));
+        let command = MoveCategoryCommand::new(food.aggregate().id().value(), user_id, Some(produce.aggregate().id().value()));
+
+        let mut rep = repository_fixture(vec![food, groceries, produce]);
+        rep.expect_append().never();
+
+        let result = MoveCategoryCommandHandler::new(rep).handle(command).await;
+
+        assert!(matches!(result, Err(FeatureError::Category(CategoryError::Domain(DomainError::CategoryCycle(_))))));
+    }
+
+    #[tokio::test]
+    async fn test_move_category_command_handler_unknown_parent() {
+        let user_id = Uuid::new_v4();
+        let food = category_fixture(user_id, None);
+        let command = MoveCategoryCommand::new(food.aggregate().id().value(), user_id, Some(Uuid::new_v4()));
+
+        let mut rep = repository_fixture(vec![food]);
+        rep.expect_append().never();
+
+        let result = MoveCategoryCommandHandler::new(rep).handle(command).await;
+
+        assert!(matches!(result, Err(FeatureError::Category(CategoryError::Domain(DomainError::InvalidParentCategory(_))))));
+    }
+
+    fn repository_fixture(categories: Vec<Versioned<Category>>) -> MockCategoryRepository {
+        let categories = categories.into_iter()
+            .map(|category| (category.aggregate().id().value(), category))
+            .collect::<HashMap<_, _>>();
+
+        let mut rep = MockCategoryRepository::new();
+        rep.expect_load()
+            .returning(move |id| {
+                let found = categories.get(&id).cloned();
+                async move { Ok(found) }.boxed()
+            });
+
+        rep
+    }
+
+    fn category_fixture(user_id: Uuid, parent: Option<&Category>) -> Versioned<Category> {
+        let parent_id = parent.map(|parent| parent.id().value());
+        let command = CreateCategoryCommand::new(user_id, "Food".to_string(), None, parent_id);
+        let event = Category::handle_creation(command, parent).unwrap();
+
+        Versioned::new(Category::apply(None, &event).unwrap(), 1)
+    }
+}
@@ -0,0 +1,37 @@
+use uuid::Uuid;
+use crate::support::command_bus::Command;
+
+#[derive(Debug, Clone)]
+pub struct RenameCategoryCommand {
+    category_id: Uuid,
+    user_id: Uuid,
+    name: String,
+}
+
+impl RenameCategoryCommand {
+    pub fn new(category_id: Uuid, user_id: Uuid, name: String) -> Self {
+        Self {
+            category_id,
+            user_id,
+            name,
+        }
+    }
+
+    pub fn category_id(&self) -> &Uuid {
+        &self.category_id
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    pub fn category_name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Command for RenameCategoryCommand {
+    fn name() -> &'static str {
+        "rename_category"
+    }
+}
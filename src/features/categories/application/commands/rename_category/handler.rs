@@ -0,0 +1,164 @@
+use async_trait::async_trait;
+use crate::events::event::Event;
+use crate::features::categories::application::commands::rename_category::command::RenameCategoryCommand;
+use crate::features::categories::domain::category_repository::CategoryRepository;
+use crate::features::categories::domain::error::DomainError;
+use crate::features::categories::error::CategoryError;
+use crate::support::command_bus::CommandHandler;
+use crate::support::error::FeatureError;
+
+pub struct RenameCategoryCommandHandler<R>
+    where
+        R: CategoryRepository + Send + Sync,
+{
+    category_repository: R,
+}
+
+impl<R> RenameCategoryCommandHandler<R>
+    where
+        R: CategoryRepository + Send + Sync,
+{
+    pub fn new(category_repository: R) -> Self {
+        Self {
+            category_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> CommandHandler<RenameCategoryCommand> for RenameCategoryCommandHandler<R>
+    where
+        R: CategoryRepository + Send + Sync,
+{
+    async fn handle(&mut self, command: RenameCategoryCommand) -> Result<Vec<Event>, FeatureError> {
+        let category = self.category_repository.load(*command.category_id())
+            .await
+            .map_err(FeatureError::Category)?
+            .ok_or(
+                FeatureError::Category(
+                    CategoryError::Domain(
+                        DomainError::CategoryNotFound(command.category_id().to_string())
+                    )
+                )
+            )?;
+
+        let existing_id = self.category_repository.find_id_by_name(*command.user_id(), command.category_name())
+            .await
+            .map_err(FeatureError::Category)?;
+
+        // Changing only the case or spacing of the name keeps the category itself
+        if existing_id.is_some_and(|existing_id| existing_id != category.aggregate().id().value()) {
+            return Err(
+                FeatureError::Category(
+                    CategoryError::Domain(
+                        DomainError::CategoryAlreadyExists(command.category_name().to_string())
+                    )
+                )
+            );
+        }
+
+        let event = category.aggregate().handle_rename(command)
+            .map_err(|e|
+                FeatureError::Category(
+                    CategoryError::Domain(e)
+                )
+            )?;
+
+        self.category_repository.append(category.aggregate().id().value(), category.version(), std::slice::from_ref(&event))
+            .await
+            .map_err(FeatureError::Category)?;
+
+        Ok(
+            vec![Event::CategoryEvent(event)]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::FutureExt;
+    use uuid::Uuid;
+    use crate::features::categories::application::commands::create_category::command::CreateCategoryCommand;
+    use crate::features::categories::domain::category::Category;
+    use crate::features::categories::domain::category_repository::MockCategoryRepository;
+    use crate::support::event_store::{Aggregate, Versioned};
+    use super::*;
+
+    #[tokio::test]
+    async fn test_rename_category_command_handler_success() {
+        let category = category_fixture();
+        let command = RenameCategoryCommand::new(category.aggregate().id().value(), category.aggregate().user_id().value(), "Groceries".to_string());
+
+        let mut rep = MockCategoryRepository::new();
+        rep.expect_load()
+            .times(1)
+            .returning(move |_| {
+                let category = category.clone();
+                async move { Ok(Some(category)) }.boxed()
+            });
+        rep.expect_find_id_by_name()
+            .times(1)
+            .returning(|_, _| async { Ok(None) }.boxed());
+        rep.expect_append()
+            .times(1)
+            .returning(|_, version, _| async move { Ok(version + 1) }.boxed());
+
+        let result = RenameCategoryCommandHandler::new(rep).handle(command).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_rename_category_command_handler_existing_name() {
+        let category = category_fixture();
+        let command = RenameCategoryCommand::new(category.aggregate().id().value(), category.aggregate().user_id().value(), "Groceries".to_string());
+
+        let mut rep = MockCategoryRepository::new();
+        rep.expect_load()
+            .times(1)
+            .returning(move |_| {
+                let category = category.clone();
+                async move { Ok(Some(category)) }.boxed()
+            });
+        rep.expect_find_id_by_name()
+            .times(1)
+            .returning(|_, _| async { Ok(Some(Uuid::new_v4())) }.boxed());
+        rep.expect_append().never();
+
+        let result = RenameCategoryCommandHandler::new(rep).handle(command).await;
+
+        assert!(matches!(result, Err(FeatureError::Category(CategoryError::Domain(DomainError::CategoryAlreadyExists(_))))));
+    }
+
+    #[tokio::test]
+    async fn test_rename_category_command_handler_same_normalized_name() {
+        let category = category_fixture();
+        let category_id = category.aggregate().id().value();
+        let command = RenameCategoryCommand::new(category_id, category.aggregate().user_id().value(), "  FOOD ".to_string());
+
+        let mut rep = MockCategoryRepository::new();
+        rep.expect_load()
+            .times(1)
+            .returning(move |_| {
+                let category = category.clone();
+                async move { Ok(Some(category)) }.boxed()
+            });
+        rep.expect_find_id_by_name()
+            .times(1)
+            .returning(move |_, _| async move { Ok(Some(category_id)) }.boxed());
+        rep.expect_append()
+            .times(1)
+            .returning(|_, version, _| async move { Ok(version + 1) }.boxed());
+
+        let result = RenameCategoryCommandHandler::new(rep).handle(command).await;
+
+        assert!(result.is_ok());
+    }
+
+    fn category_fixture() -> Versioned<Category> {
+        let command = CreateCategoryCommand::new(Uuid::new_v4(), "Food".to_string(), None, None);
+        let event = Category::handle_creation(command, None).unwrap();
+
+        Versioned::new(Category::apply(None, &event).unwrap(), 1)
+    }
+}
@@ -0,0 +1,45 @@
+use uuid::Uuid;
+use crate::support::command_bus::Command;
+
+/// Makes sure the category an operation was created with exists, either by reusing a category
+/// with the same name or by creating one under `category_id`.
+#[derive(Debug, Clone)]
+pub struct ResolveCategoryCommand {
+    operation_id: Uuid,
+    user_id: Uuid,
+    category_id: Uuid,
+    name: String,
+}
+
+impl ResolveCategoryCommand {
+    pub fn new(operation_id: Uuid, user_id: Uuid, category_id: Uuid, name: String) -> Self {
+        Self {
+            operation_id,
+            user_id,
+            category_id,
+            name,
+        }
+    }
+
+    pub fn operation_id(&self) -> &Uuid {
+        &self.operation_id
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    pub fn category_id(&self) -> &Uuid {
+        &self.category_id
+    }
+
+    pub fn category_name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Command for ResolveCategoryCommand {
+    fn name() -> &'static str {
+        "resolve_category"
+    }
+}
@@ -0,0 +1,151 @@
+use async_trait::async_trait;
+use crate::events::event::Event;
+use crate::features::categories::application::commands::resolve_category::command::ResolveCategoryCommand;
+use crate::features::categories::domain::category::Category;
+use crate::features::categories::domain::category_repository::CategoryRepository;
+use crate::features::categories::domain::error::DomainError;
+use crate::features::categories::domain::events::category_event::CategoryEvent;
+use crate::features::categories::error::CategoryError;
+use crate::support::command_bus::CommandHandler;
+use crate::support::error::FeatureError;
+
+pub struct ResolveCategoryCommandHandler<R>
+    where
+        R: CategoryRepository + Send + Sync,
+{
+    category_repository: R,
+}
+
+impl<R> ResolveCategoryCommandHandler<R>
+    where
+        R: CategoryRepository + Send + Sync,
+{
+    pub fn new(category_repository: R) -> Self {
+        Self {
+            category_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> CommandHandler<ResolveCategoryCommand> for ResolveCategoryCommandHandler<R>
+    where
+        R: CategoryRepository + Send + Sync,
+{
+    async fn handle(&mut self, command: ResolveCategoryCommand) -> Result<Vec<Event>, FeatureError> {
+        let existing_id = self.category_repository.find_id_by_name(*command.user_id(), command.category_name())
+            .await
+            .map_err(FeatureError::Category)?;
+
+        if let Some(existing_id) = existing_id {
+            let category = self.category_repository.load(existing_id)
+                .await
+                .map_err(FeatureError::Category)?
+                .ok_or(
+                    FeatureError::Category(
+                        CategoryError::Domain(
+                            DomainError::CategoryNotFound(existing_id.to_string())
+                        )
+                    )
+                )?;
+
+            let event = category.aggregate().handle_reuse(command)
+                .map_err(|e|
+                    FeatureError::Category(
+                        CategoryError::Domain(e)
+                    )
+                )?;
+
+            return Ok(
+                vec![Event::CategoryEvent(event)]
+            );
+        }
+
+        let event = Category::handle_requested_creation(command)
+            .map_err(|e|
+                FeatureError::Category(
+                    CategoryError::Domain(e)
+                )
+            )?;
+
+        if let CategoryEvent::CategoryCreated(category_created) = &event {
+            self.category_repository.persist_category_created_event(category_created)
+                .await
+                .map_err(FeatureError::Category)?;
+        }
+
+        Ok(
+            vec![Event::CategoryEvent(event)]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::FutureExt;
+    use uuid::Uuid;
+    use crate::features::categories::application::commands::create_category::command::CreateCategoryCommand;
+    use crate::features::categories::domain::category_repository::MockCategoryRepository;
+    use crate::support::event_store::{Aggregate, Versioned};
+    use super::*;
+
+    #[tokio::test]
+    async fn test_resolve_category_command_handler_reuses_existing_category() {
+        let category = category_fixture();
+        let category_id = category.aggregate().id().value();
+        let command = ResolveCategoryCommand::new(Uuid::new_v4(), category.aggregate().user_id().value(), Uuid::new_v4(), "  food".to_string());
+        let requested_id = *command.category_id();
+
+        let mut rep = MockCategoryRepository::new();
+        rep.expect_find_id_by_name()
+            .times(1)
+            .returning(move |_, _| async move { Ok(Some(category_id)) }.boxed());
+        rep.expect_load()
+            .times(1)
+            .returning(move |_| {
+                let category = category.clone();
+                async move { Ok(Some(category)) }.boxed()
+            });
+        rep.expect_persist_category_created_event().never();
+
+        let events = ResolveCategoryCommandHandler::new(rep).handle(command).await.unwrap();
+
+        match &events[0] {
+            Event::CategoryEvent(CategoryEvent::CategoryReused(category_reused)) => {
+                assert_eq!(category_reused.payload().id().value(), category_id);
+                assert_eq!(category_reused.payload().requested_category_id().value(), requested_id);
+            }
+            event => panic!("Unexpected event {:?}", event),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resolve_category_command_handler_creates_requested_category() {
+        let command = ResolveCategoryCommand::new(Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4(), "Food".to_string());
+        let requested_id = *command.category_id();
+
+        let mut rep = MockCategoryRepository::new();
+        rep.expect_find_id_by_name()
+            .times(1)
+            .returning(|_, _| async { Ok(None) }.boxed());
+        rep.expect_persist_category_created_event()
+            .times(1)
+            .returning(|_| async { Ok(()) }.boxed());
+
+        let events = ResolveCategoryCommandHandler::new(rep).handle(command).await.unwrap();
+
+        match &events[0] {
+            Event::CategoryEvent(CategoryEvent::CategoryCreated(category_created)) => {
+                assert_eq!(category_created.payload().id().value(), requested_id);
+            }
+            event => panic!("Unexpected event {:?}", event),
+        }
+    }
+
+    fn category_fixture() -> Versioned<Category> {
+        let command = CreateCategoryCommand::new(Uuid::new_v4(), "Food".to_string(), None, None);
+        let event = Category::handle_creation(command, None).unwrap();
+
+        Versioned::new(Category::apply(None, &event).unwrap(), 1)
+    }
+}
@@ -0,0 +1,42 @@
+use async_trait::async_trait;
+use mockall::automock;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use crate::features::categories::application::queries::category_total::CategoryTotal;
+use crate::features::categories::application::queries::category_view::CategoryView;
+use crate::features::categories::domain::events::category_archived::CategoryArchived;
+use crate::features::categories::domain::events::category_created::CategoryCreated;
+use crate::features::categories::domain::events::category_deleted::CategoryDeleted;
+use crate::features::categories::domain::events::category_icon_changed::CategoryIconChanged;
+use crate::features::categories::domain::events::category_moved::CategoryMoved;
+use crate::features::categories::domain::events::category_renamed::CategoryRenamed;
+use crate::features::categories::error::CategoryError;
+
+#[async_trait]
+#[automock]
+pub trait CategoryProjectionRepository {
+    async fn find(&self, user_id: Uuid, include_archived: bool) -> Result<Vec<CategoryView>, CategoryError>;
+
+    async fn find_child_ids(&self, user_id: Uuid, category_id: Uuid) -> Result<Vec<Uuid>, CategoryError>;
+
+    /// Sums the operations of each category of the user, split operations per line, descendants are not included.
+    async fn find_totals(
+        &self,
+        user_id: Uuid,
+        kind: Option<String>,
+        date_from: Option<DateTime<Utc>>,
+        date_to: Option<DateTime<Utc>>,
+    ) -> Result<Vec<CategoryTotal>, CategoryError>;
+
+    async fn apply_category_created(&self, event: &CategoryCreated) -> Result<(), CategoryError>;
+
+    async fn apply_category_renamed(&self, event: &CategoryRenamed) -> Result<(), CategoryError>;
+
+    async fn apply_category_icon_changed(&self, event: &CategoryIconChanged) -> Result<(), CategoryError>;
+
+    async fn apply_category_moved(&self, event: &CategoryMoved) -> Result<(), CategoryError>;
+
+    async fn apply_category_archived(&self, event: &CategoryArchived) -> Result<(), CategoryError>;
+
+    async fn apply_category_deleted(&self, event: &CategoryDeleted) -> Result<(), CategoryError>;
+}
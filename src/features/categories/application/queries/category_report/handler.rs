@@ -0,0 +1,108 @@
+use async_trait::async_trait;
+use crate::features::categories::application::queries::category_projection_repository::CategoryProjectionRepository;
+use crate::features::categories::application::queries::category_report::query::CategoryReportQuery;
+use crate::features::categories::application::queries::category_report::report::{CategoryReportLine, roll_up};
+use crate::features::categories::error::CategoryError;
+use crate::support::error::FeatureError;
+use crate::support::query_bus::QueryHandler;
+
+pub struct CategoryReportQueryHandler<R>
+    where
+        R: CategoryProjectionRepository + Send + Sync,
+{
+    rep: R,
+}
+
+impl<R> CategoryReportQueryHandler<R>
+    where
+        R: CategoryProjectionRepository + Send + Sync,
+{
+    pub fn new(rep: R) -> Self {
+        Self {
+            rep,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> QueryHandler<CategoryReportQuery> for CategoryReportQueryHandler<R>
+    where
+        R: CategoryProjectionRepository + Send + Sync,
+{
+    type Output = Vec<CategoryReportLine>;
+
+    async fn handle(&self, query: CategoryReportQuery) -> Result<Vec<CategoryReportLine>, FeatureError> {
+        query.validate()
+            .map_err(|e|
+                FeatureError::Category(
+                    CategoryError::Domain(e)
+                )
+            )?;
+
+        // Archived categories still hold operations of the period, so they take part in the report
+        let categories = self.rep.find(*query.user_id(), true)
+            .await
+            .map_err(FeatureError::Category)?;
+
+        let totals = self.rep.find_totals(*query.user_id(), query.kind().clone(), query.date_from(), query.date_to())
+            .await
+            .map_err(FeatureError::Category)?;
+
+        Ok(roll_up(&categories, &totals))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+    use chrono::Utc;
+    use futures_util::FutureExt;
+    use uuid::Uuid;
+    use crate::features::categories::application::queries::category_projection_repository::MockCategoryProjectionRepository;
+    use crate::features::categories::application::queries::category_total::CategoryTotal;
+    use crate::features::categories::application::queries::category_view::CategoryView;
+    use crate::features::categories::domain::error::DomainError;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle_rolls_up_totals() {
+        let user_id = Uuid::new_v4();
+        let food = CategoryView::new(Uuid::new_v4(), user_id, "Food".to_string(), None, None, false, Utc::now());
+        let groceries = CategoryView::new(Uuid::new_v4(), user_id, "Groceries".to_string(), None, Some(*food.id()), true, Utc::now());
+        let totals = vec![CategoryTotal::new(*groceries.id(), Decimal::from(12))];
+
+        let mut rep = MockCategoryProjectionRepository::new();
+        rep.expect_find()
+            .times(1)
+            .returning(move |_, _| {
+                let categories = vec![food.clone(), groceries.clone()];
+                async move { Ok(categories) }.boxed()
+            });
+        rep.expect_find_totals()
+            .times(1)
+            .returning(move |_, _, _, _| {
+                let totals = totals.clone();
+                async move { Ok(totals) }.boxed()
+            });
+
+        let report = CategoryReportQueryHandler::new(rep)
+            .handle(CategoryReportQuery::new(user_id, Some("Expense".to_string()), None, None))
+            .await
+            .unwrap();
+
+        assert_eq!(report[0].rolled_up_total(), Decimal::from(12));
+        assert_eq!(report[1].total(), Decimal::from(12));
+    }
+
+    #[tokio::test]
+    async fn test_handle_invalid_filter() {
+        let mut rep = MockCategoryProjectionRepository::new();
+        rep.expect_find().never();
+
+        let result = CategoryReportQueryHandler::new(rep)
+            .handle(CategoryReportQuery::new(Uuid::new_v4(), Some("Gift".to_string()), None, None))
+            .await;
+
+        assert!(matches!(result, Err(FeatureError::Category(CategoryError::Domain(DomainError::InvalidReportFilter(_))))));
+    }
+}
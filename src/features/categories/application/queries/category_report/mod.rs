@@ -0,0 +1,3 @@
+pub mod handler;
+pub mod query;
+pub mod report;
\ No newline at end of file
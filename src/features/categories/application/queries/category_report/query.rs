@@ -0,0 +1,102 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use crate::features::categories::domain::error::DomainError;
+use crate::features::operations::domain::kind::Kind;
+use crate::support::query_bus::Query;
+
+const NAME: &str = "category_report";
+
+#[derive(Debug, Clone)]
+pub struct CategoryReportQuery {
+    user_id: Uuid,
+    kind: Option<String>,
+    date_from: Option<DateTime<Utc>>,
+    date_to: Option<DateTime<Utc>>,
+}
+
+impl CategoryReportQuery {
+    pub fn new(user_id: Uuid, kind: Option<String>, date_from: Option<DateTime<Utc>>, date_to: Option<DateTime<Utc>>) -> Self {
+        Self {
+            user_id,
+            kind,
+            date_from,
+            date_to,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), DomainError> {
+        if let Some(kind) = &self.kind {
+            let kind = Kind::new(kind)
+                .map_err(|_|
+                    DomainError::InvalidReportFilter(format!("Unknown operation kind {}", kind))
+                )?;
+
+            // Transfers move money between accounts of the user, they are neither incomes nor expenses
+            if kind == Kind::Transfer {
+                return Err(
+                    DomainError::InvalidReportFilter("Transfers are not reported".to_string())
+                );
+            }
+        }
+
+        if let (Some(date_from), Some(date_to)) = (self.date_from, self.date_to) {
+            if date_from > date_to {
+                return Err(
+                    DomainError::InvalidReportFilter("Date range start must not be after its end".to_string())
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    pub fn kind(&self) -> &Option<String> {
+        &self.kind
+    }
+
+    pub fn date_from(&self) -> Option<DateTime<Utc>> {
+        self.date_from
+    }
+
+    pub fn date_to(&self) -> Option<DateTime<Utc>> {
+        self.date_to
+    }
+}
+
+impl Query for CategoryReportQuery {
+    fn name() -> &'static str {
+        NAME
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+    use super::*;
+
+    #[test]
+    fn test_validate_unknown_kind() {
+        let query = CategoryReportQuery::new(Uuid::new_v4(), Some("Gift".to_string()), None, None);
+
+        assert!(matches!(query.validate(), Err(DomainError::InvalidReportFilter(_))));
+    }
+
+    #[test]
+    fn test_validate_transfer_kind() {
+        let query = CategoryReportQuery::new(Uuid::new_v4(), Some("Transfer".to_string()), None, None);
+
+        assert!(matches!(query.validate(), Err(DomainError::InvalidReportFilter(_))));
+    }
+
+    #[test]
+    fn test_validate_reversed_dates() {
+        let now = Utc::now();
+        let query = CategoryReportQuery::new(Uuid::new_v4(), Some("Expense".to_string()), Some(now), Some(now - Duration::days(1)));
+
+        assert!(matches!(query.validate(), Err(DomainError::InvalidReportFilter(_))));
+    }
+}
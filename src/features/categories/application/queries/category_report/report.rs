@@ -0,0 +1,128 @@
+use std::collections::{HashMap, HashSet};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::features::categories::application::queries::category_total::CategoryTotal;
+use crate::features::categories::application::queries::category_view::CategoryView;
+
+/// Report row of a single category. `total` covers the operations recorded in the category itself,
+/// `rolled_up_total` adds the operations of all its descendants.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CategoryReportLine {
+    category_id: Uuid,
+    parent_id: Option<Uuid>,
+    name: String,
+    total: Decimal,
+    rolled_up_total: Decimal,
+}
+
+impl CategoryReportLine {
+    pub fn category_id(&self) -> &Uuid {
+        &self.category_id
+    }
+
+    pub fn parent_id(&self) -> &Option<Uuid> {
+        &self.parent_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn total(&self) -> Decimal {
+        self.total
+    }
+
+    pub fn rolled_up_total(&self) -> Decimal {
+        self.rolled_up_total
+    }
+}
+
+/// Adds the total of every category to each of its ancestors. Totals of unknown categories are skipped.
+pub fn roll_up(categories: &[CategoryView], totals: &[CategoryTotal]) -> Vec<CategoryReportLine> {
+    let parents = categories.iter()
+        .map(|category| (*category.id(), *category.parent_id()))
+        .collect::<HashMap<_, _>>();
+
+    let own = totals.iter()
+        .filter(|total| parents.contains_key(total.category_id()))
+        .fold(HashMap::new(), |mut own, total| {
+            *own.entry(*total.category_id()).or_insert(Decimal::ZERO) += total.total();
+            own
+        });
+
+    let mut rolled_up = own.clone();
+    for (category_id, total) in &own {
+        let mut visited = HashSet::from([*category_id]);
+        let mut next_id = parents.get(category_id).copied().flatten();
+
+        // The visited set guards against a cycle left in the read model
+        while let Some(ancestor_id) = next_id {
+            if !visited.insert(ancestor_id) || !parents.contains_key(&ancestor_id) {
+                break;
+            }
+
+            *rolled_up.entry(ancestor_id).or_insert(Decimal::ZERO) += total;
+            next_id = parents.get(&ancestor_id).copied().flatten();
+        }
+    }
+
+    categories.iter()
+        .map(|category|
+            CategoryReportLine {
+                category_id: *category.id(),
+                parent_id: *category.parent_id(),
+                name: category.name().to_string(),
+                total: own.get(category.id()).copied().unwrap_or(Decimal::ZERO),
+                rolled_up_total: rolled_up.get(category.id()).copied().unwrap_or(Decimal::ZERO),
+            }
+        )
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use super::*;
+
+    #[test]
+    fn test_roll_up_to_ancestors() {
+        let user_id = Uuid::new_v4();
+        let food = view_fixture(user_id, "Food", None);
+        let groceries = view_fixture(user_id, "Groceries", Some(*food.id()));
+        let produce = view_fixture(user_id, "Produce", Some(*groceries.id()));
+        let home = view_fixture(user_id, "Home", None);
+
+        let totals = vec![
+            CategoryTotal::new(*food.id(), Decimal::from(5)),
+            CategoryTotal::new(*groceries.id(), Decimal::from(20)),
+            CategoryTotal::new(*produce.id(), Decimal::new(75, 1)),
+            CategoryTotal::new(*home.id(), Decimal::from(100)),
+            CategoryTotal::new(Uuid::new_v4(), Decimal::from(1000)),
+        ];
+
+        let report = roll_up(&[food, groceries, produce, home], &totals);
+        let lines = report.iter()
+            .map(|line| (line.name(), (line.total(), line.rolled_up_total())))
+            .collect::<HashMap<_, _>>();
+
+        assert_eq!(lines["Food"], (Decimal::from(5), Decimal::new(325, 1)));
+        assert_eq!(lines["Groceries"], (Decimal::from(20), Decimal::new(275, 1)));
+        assert_eq!(lines["Produce"], (Decimal::new(75, 1), Decimal::new(75, 1)));
+        assert_eq!(lines["Home"], (Decimal::from(100), Decimal::from(100)));
+    }
+
+    #[test]
+    fn test_roll_up_without_operations() {
+        let food = view_fixture(Uuid::new_v4(), "Food", None);
+
+        let report = roll_up(&[food], &[]);
+
+        assert_eq!(report[0].total(), Decimal::ZERO);
+        assert_eq!(report[0].rolled_up_total(), Decimal::ZERO);
+    }
+
+    fn view_fixture(user_id: Uuid, name: &str, parent_id: Option<Uuid>) -> CategoryView {
+        CategoryView::new(Uuid::new_v4(), user_id, name.to_string(), None, parent_id, false, Utc::now())
+    }
+}
@@ -0,0 +1,28 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Sum of the operations recorded directly in a category.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct CategoryTotal {
+    category_id: Uuid,
+    total: Decimal,
+}
+
+impl CategoryTotal {
+    pub fn new(category_id: Uuid, total: Decimal) -> Self {
+        Self {
+            category_id,
+            total,
+        }
+    }
+
+    pub fn category_id(&self) -> &Uuid {
+        &self.category_id
+    }
+
+    pub fn total(&self) -> Decimal {
+        self.total
+    }
+}
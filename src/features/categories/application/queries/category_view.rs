@@ -0,0 +1,58 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Read model row of the `categories` projection.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct CategoryView {
+    id: Uuid,
+    user_id: Uuid,
+    name: String,
+    icon: Option<String>,
+    parent_id: Option<Uuid>,
+    archived: bool,
+    created_at: DateTime<Utc>,
+}
+
+impl CategoryView {
+    pub fn new(id: Uuid, user_id: Uuid, name: String, icon: Option<String>, parent_id: Option<Uuid>, archived: bool, created_at: DateTime<Utc>) -> Self {
+        Self {
+            id,
+            user_id,
+            name,
+            icon,
+            parent_id,
+            archived,
+            created_at,
+        }
+    }
+
+    pub fn id(&self) -> &Uuid {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn icon(&self) -> &Option<String> {
+        &self.icon
+    }
+
+    pub fn parent_id(&self) -> &Option<Uuid> {
+        &self.parent_id
+    }
+
+    pub fn is_archived(&self) -> bool {
+        self.archived
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+}
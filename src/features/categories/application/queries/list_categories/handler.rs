@@ -0,0 +1,70 @@
+use async_trait::async_trait;
+use crate::features::categories::application::queries::category_projection_repository::CategoryProjectionRepository;
+use crate::features::categories::application::queries::category_view::CategoryView;
+use crate::features::categories::application::queries::list_categories::query::ListCategoriesQuery;
+use crate::support::error::FeatureError;
+use crate::support::query_bus::QueryHandler;
+
+pub struct ListCategoriesQueryHandler<R>
+    where
+        R: CategoryProjectionRepository + Send + Sync,
+{
+    rep: R,
+}
+
+impl<R> ListCategoriesQueryHandler<R>
+    where
+        R: CategoryProjectionRepository + Send + Sync,
+{
+    pub fn new(rep: R) -> Self {
+        Self {
+            rep,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> QueryHandler<ListCategoriesQuery> for ListCategoriesQueryHandler<R>
+    where
+        R: CategoryProjectionRepository + Send + Sync,
+{
+    type Output = Vec<CategoryView>;
+
+    async fn handle(&self, query: ListCategoriesQuery) -> Result<Vec<CategoryView>, FeatureError> {
+        self.rep.find(*query.user_id(), query.include_archived())
+            .await
+            .map_err(FeatureError::Category)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use futures_util::FutureExt;
+    use mockall::predicate::eq;
+    use uuid::Uuid;
+    use crate::features::categories::application::queries::category_projection_repository::MockCategoryProjectionRepository;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle_passes_archived_flag() {
+        let user_id = Uuid::new_v4();
+
+        let mut rep = MockCategoryProjectionRepository::new();
+        rep.expect_find()
+            .with(eq(user_id), eq(false))
+            .times(1)
+            .returning(move |user_id, _| {
+                let view = CategoryView::new(Uuid::new_v4(), user_id, "Food".to_string(), None, None, false, Utc::now());
+                async move { Ok(vec![view]) }.boxed()
+            });
+
+        let categories = ListCategoriesQueryHandler::new(rep)
+            .handle(ListCategoriesQuery::new(user_id, false))
+            .await
+            .unwrap();
+
+        assert_eq!(categories.len(), 1);
+        assert_eq!(categories[0].name(), "Food");
+    }
+}
@@ -0,0 +1,33 @@
+use uuid::Uuid;
+use crate::support::query_bus::Query;
+
+const NAME: &str = "list_categories";
+
+#[derive(Debug, Clone)]
+pub struct ListCategoriesQuery {
+    user_id: Uuid,
+    include_archived: bool,
+}
+
+impl ListCategoriesQuery {
+    pub fn new(user_id: Uuid, include_archived: bool) -> Self {
+        Self {
+            user_id,
+            include_archived,
+        }
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    pub fn include_archived(&self) -> bool {
+        self.include_archived
+    }
+}
+
+impl Query for ListCategoriesQuery {
+    fn name() -> &'static str {
+        NAME
+    }
+}
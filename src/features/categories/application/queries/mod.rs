@@ -0,0 +1,5 @@
+pub mod category_projection_repository;
+pub mod category_report;
+pub mod category_total;
+pub mod category_view;
+pub mod list_categories;
\ No newline at end of file
@@ -1,28 +1,54 @@
+use uuid::Uuid;
+use crate::features::categories::application::commands::archive_category::command::ArchiveCategoryCommand;
+use crate::features::categories::application::commands::change_category_icon::command::ChangeCategoryIconCommand;
 use crate::features::categories::application::commands::create_category::command::CreateCategoryCommand;
+use crate::features::categories::application::commands::delete_category::command::DeleteCategoryCommand;
+use crate::features::categories::application::commands::move_category::command::MoveCategoryCommand;
+use crate::features::categories::application::commands::rename_category::command::RenameCategoryCommand;
+use crate::features::categories::application::commands::resolve_category::command::ResolveCategoryCommand;
 use crate::features::categories::domain::error::DomainError;
-use crate::features::categories::domain::events::category_created::CategoryCreated;
+use crate::features::categories::domain::events::category_archived::CategoryArchived;
+use crate::features::categories::domain::events::category_created::{CATEGORY_CREATED_NAME, CategoryCreated};
+use crate::features::categories::domain::events::category_deleted::CategoryDeleted;
 use crate::features::categories::domain::events::category_event::CategoryEvent;
+use crate::features::categories::domain::events::category_icon_changed::CategoryIconChanged;
+use crate::features::categories::domain::events::category_moved::CategoryMoved;
+use crate::features::categories::domain::events::category_renamed::CategoryRenamed;
+use crate::features::categories::domain::events::category_reused::CategoryReused;
+use crate::support::event_store::{Aggregate, EventStoreError};
 use crate::support::id::Id;
 
+#[derive(Debug, Clone)]
 pub struct Category {
     id: Id,
     user_id: Id,
     name: String,
     icon: Option<String>,
+    parent_id: Option<Id>,
+    archived: bool,
+    deleted: bool,
 }
 
 impl Category {
-    pub fn handle_creation(command: CreateCategoryCommand) -> Result<CategoryEvent, DomainError> {
+    /// `parent` is the loaded category referenced by `command.parent_id()`, if any.
+    pub fn handle_creation(command: CreateCategoryCommand, parent: Option<&Category>) -> Result<CategoryEvent, DomainError> {
         let id = Id::new(Id::generate());
         let user_id = Id::new(command.user_id().clone());
         let name = command.category_name().to_string();
         let icon = command.icon().clone();
 
+        if let Some(parent) = parent {
+            parent.check_parent_for(&user_id)?;
+        }
+
         let category = Self {
             id,
             user_id,
             name,
             icon,
+            parent_id: parent.map(|parent| parent.id.clone()),
+            archived: false,
+            deleted: false,
         };
 
         let category_created = CategoryEvent::CategoryCreated(
@@ -32,12 +58,167 @@ impl Category {
                 category.user_id().clone(),
                 category.name().to_string(),
                 category.icon().clone(),
+                category.parent_id().clone(),
             )
         );
 
         Ok(category_created)
     }
 
+    /// Creates the root category an operation asked for under the id the operation already references.
+    pub fn handle_requested_creation(command: ResolveCategoryCommand) -> Result<CategoryEvent, DomainError> {
+        Ok(
+            CategoryEvent::CategoryCreated(
+                CategoryCreated::new(
+                    Id::new(Id::generate()),
+                    Id::new(*command.category_id()),
+                    Id::new(*command.user_id()),
+                    command.category_name().to_string(),
+                    None,
+                    None,
+                )
+            )
+        )
+    }
+
+    /// Hands the category over to an operation that asked for a new one with the same name.
+    pub fn handle_reuse(&self, command: ResolveCategoryCommand) -> Result<CategoryEvent, DomainError> {
+        self.check_access(command.user_id())?;
+
+        Ok(
+            CategoryEvent::CategoryReused(
+                CategoryReused::new(
+                    Id::new(Id::generate()),
+                    self.id.clone(),
+                    self.user_id.clone(),
+                    Id::new(*command.operation_id()),
+                    Id::new(*command.category_id()),
+                )
+            )
+        )
+    }
+
+    pub fn handle_rename(&self, command: RenameCategoryCommand) -> Result<CategoryEvent, DomainError> {
+        self.check_access(command.user_id())?;
+
+        Ok(
+            CategoryEvent::CategoryRenamed(
+                CategoryRenamed::new(
+                    Id::new(Id::generate()),
+                    self.id.clone(),
+                    self.user_id.clone(),
+                    command.category_name().to_string(),
+                )
+            )
+        )
+    }
+
+    pub fn handle_icon_change(&self, command: ChangeCategoryIconCommand) -> Result<CategoryEvent, DomainError> {
+        self.check_access(command.user_id())?;
+
+        Ok(
+            CategoryEvent::CategoryIconChanged(
+                CategoryIconChanged::new(
+                    Id::new(Id::generate()),
+                    self.id.clone(),
+                    self.user_id.clone(),
+                    command.icon().clone(),
+                )
+            )
+        )
+    }
+
+    /// `lineage` is the new parent followed by its ancestors up to the root, it is empty when the category becomes a root.
+    /// Finding the moved category in it means the new parent is one of its descendants.
+    pub fn handle_move(&self, command: MoveCategoryCommand, lineage: &[Category]) -> Result<CategoryEvent, DomainError> {
+        self.check_access(command.user_id())?;
+
+        let parent_id = match (command.parent_id(), lineage.first()) {
+            (None, _) => None,
+            (Some(parent_id), Some(parent)) if parent.id.value() == *parent_id => {
+                parent.check_parent_for(&self.user_id)?;
+
+                Some(parent.id.clone())
+            }
+            (Some(parent_id), _) => {
+                return Err(DomainError::InvalidParentCategory(parent_id.to_string()));
+            }
+        };
+
+        if parent_id.is_some() && lineage.iter().any(|ancestor| ancestor.id == self.id) {
+            return Err(DomainError::CategoryCycle(self.id.to_string()));
+        }
+
+        Ok(
+            CategoryEvent::CategoryMoved(
+                CategoryMoved::new(
+                    Id::new(Id::generate()),
+                    self.id.clone(),
+                    self.user_id.clone(),
+                    parent_id,
+                    self.parent_id.clone(),
+                )
+            )
+        )
+    }
+
+    /// Moves a child of the deleted `parent` one level up, so the subtree stays attached to the tree.
+    pub fn handle_parent_deletion(&self, parent: &Category) -> Result<CategoryEvent, DomainError> {
+        if self.deleted || self.parent_id.as_ref() != Some(&parent.id) {
+            return Err(DomainError::InvalidParentCategory(parent.id.to_string()));
+        }
+
+        Ok(
+            CategoryEvent::CategoryMoved(
+                CategoryMoved::new(
+                    Id::new(Id::generate()),
+                    self.id.clone(),
+                    self.user_id.clone(),
+                    parent.parent_id.clone(),
+                    self.parent_id.clone(),
+                )
+            )
+        )
+    }
+
+    pub fn handle_archiving(&self, command: ArchiveCategoryCommand) -> Result<CategoryEvent, DomainError> {
+        self.check_access(command.user_id())?;
+
+        if self.archived {
+            return Err(DomainError::CategoryAlreadyArchived(self.name.clone()));
+        }
+
+        Ok(
+            CategoryEvent::CategoryArchived(
+                CategoryArchived::new(
+                    Id::new(Id::generate()),
+                    self.id.clone(),
+                    self.user_id.clone(),
+                )
+            )
+        )
+    }
+
+    /// Operations of the deleted category are moved to `fallback`, so it has to be another live category of the same user.
+    pub fn handle_deletion(&self, command: DeleteCategoryCommand, fallback: &Category) -> Result<CategoryEvent, DomainError> {
+        self.check_access(command.user_id())?;
+
+        if fallback.deleted || fallback.id == self.id || fallback.user_id != self.user_id {
+            return Err(DomainError::InvalidFallbackCategory(fallback.id.to_string()));
+        }
+
+        Ok(
+            CategoryEvent::CategoryDeleted(
+                CategoryDeleted::new(
+                    Id::new(Id::generate()),
+                    self.id.clone(),
+                    self.user_id.clone(),
+                    fallback.id.clone(),
+                )
+            )
+        )
+    }
+
     pub fn id(&self) -> &Id {
         &self.id
     }
@@ -53,4 +234,306 @@ impl Category {
     pub fn icon(&self) -> &Option<String> {
         &self.icon
     }
-}
\ No newline at end of file
+
+    pub fn parent_id(&self) -> &Option<Id> {
+        &self.parent_id
+    }
+
+    pub fn is_archived(&self) -> bool {
+        self.archived
+    }
+
+    pub fn is_deleted(&self) -> bool {
+        self.deleted
+    }
+
+    fn check_access(&self, user_id: &Uuid) -> Result<(), DomainError> {
+        if self.deleted {
+            return Err(DomainError::CategoryNotFound(self.id.to_string()));
+        }
+
+        if self.user_id.value() != *user_id {
+            return Err(DomainError::AccessDenied);
+        }
+
+        Ok(())
+    }
+
+    fn check_parent_for(&self, user_id: &Id) -> Result<(), DomainError> {
+        if self.deleted || self.user_id != *user_id {
+            return Err(DomainError::InvalidParentCategory(self.id.to_string()));
+        }
+
+        Ok(())
+    }
+}
+
+impl Aggregate for Category {
+    type Event = CategoryEvent;
+
+    fn apply(state: Option<Self>, event: &CategoryEvent) -> Result<Self, EventStoreError> {
+        match (state, event) {
+            (None, CategoryEvent::CategoryCreated(category_created)) => {
+                let payload = category_created.payload();
+
+                Ok(
+                    Self {
+                        id: payload.id().clone(),
+                        user_id: payload.user_id().clone(),
+                        name: payload.name().to_string(),
+                        icon: payload.icon().clone(),
+                        parent_id: payload.parent_id().clone(),
+                        archived: false,
+                        deleted: false,
+                    }
+                )
+            }
+            (Some(category), CategoryEvent::CategoryCreated(_)) => Err(
+                EventStoreError::Rehydration(
+                    format!("Category {} is already created", category.id().to_string())
+                )
+            ),
+            (Some(category), _) if category.deleted => Err(
+                EventStoreError::Rehydration(
+                    format!("Category {} is changed after deletion", category.id().to_string())
+                )
+            ),
+            (Some(category), CategoryEvent::CategoryRenamed(category_renamed)) => Ok(
+                Self {
+                    name: category_renamed.payload().name().to_string(),
+                    ..category
+                }
+            ),
+            (Some(category), CategoryEvent::CategoryIconChanged(category_icon_changed)) => Ok(
+                Self {
+                    icon: category_icon_changed.payload().icon().clone(),
+                    ..category
+                }
+            ),
+            (Some(category), CategoryEvent::CategoryMoved(category_moved)) => Ok(
+                Self {
+                    parent_id: category_moved.payload().parent_id().clone(),
+                    ..category
+                }
+            ),
+            (Some(category), CategoryEvent::CategoryArchived(_)) => Ok(
+                Self {
+                    archived: true,
+                    ..category
+                }
+            ),
+            (Some(category), CategoryEvent::CategoryDeleted(_)) => Ok(
+                Self {
+                    deleted: true,
+                    ..category
+                }
+            ),
+            (Some(category), CategoryEvent::CategoryReused(_)) => Err(
+                EventStoreError::Rehydration(
+                    format!("Category {} stream cannot contain {}", category.id().to_string(), event.name())
+                )
+            ),
+            (None, event) => Err(
+                EventStoreError::Rehydration(
+                    format!("Category stream must start with {}, got {}", CATEGORY_CREATED_NAME, event.name())
+                )
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use crate::support::event_store::{StorableEvent, StoredEvent};
+    use super::*;
+
+    #[test]
+    fn test_rehydrate_from_category_created() {
+        let user_id = Id::generate();
+        let command = CreateCategoryCommand::new(user_id, "Food".to_string(), Some("icon".to_string()), None);
+        let event = Category::handle_creation(command, None).unwrap();
+
+        let category = Category::rehydrate(&[stored_fixture(&event, 1)]).unwrap().unwrap();
+
+        assert_eq!(category.version(), 1);
+        assert_eq!(category.aggregate().user_id().value(), user_id);
+        assert_eq!(category.aggregate().name(), "Food");
+        assert_eq!(category.aggregate().icon(), &Some("icon".to_string()));
+    }
+
+    #[test]
+    fn test_rehydrate_twice_created_stream() {
+        let command = CreateCategoryCommand::new(Id::generate(), "Food".to_string(), None, None);
+        let event = Category::handle_creation(command, None).unwrap();
+
+        let res = Category::rehydrate(&[stored_fixture(&event, 1), stored_fixture(&event, 2)]);
+
+        assert!(matches!(res, Err(EventStoreError::Rehydration(..))));
+    }
+
+    #[test]
+    fn test_rename_and_change_icon() {
+        let category = category_fixture(Id::generate());
+        let user_id = category.user_id().value();
+
+        let renamed = category.handle_rename(RenameCategoryCommand::new(category.id().value(), user_id, "Groceries".to_string())).unwrap();
+        let category = Category::apply(Some(category), &renamed).unwrap();
+
+        let icon_changed = category.handle_icon_change(ChangeCategoryIconCommand::new(category.id().value(), user_id, Some("cart".to_string()))).unwrap();
+        let category = Category::apply(Some(category), &icon_changed).unwrap();
+
+        assert_eq!(category.name(), "Groceries");
+        assert_eq!(category.icon(), &Some("cart".to_string()));
+    }
+
+    #[test]
+    fn test_rename_foreign_category() {
+        let category = category_fixture(Id::generate());
+        let command = RenameCategoryCommand::new(category.id().value(), Id::generate(), "Groceries".to_string());
+
+        assert!(matches!(category.handle_rename(command), Err(DomainError::AccessDenied)));
+    }
+
+    #[test]
+    fn test_archive_twice() {
+        let category = category_fixture(Id::generate());
+        let command = ArchiveCategoryCommand::new(category.id().value(), category.user_id().value());
+
+        let archived = category.handle_archiving(command.clone()).unwrap();
+        let category = Category::apply(Some(category), &archived).unwrap();
+
+        assert!(category.is_archived());
+        assert!(matches!(category.handle_archiving(command), Err(DomainError::CategoryAlreadyArchived(_))));
+    }
+
+    #[test]
+    fn test_delete_with_fallback() {
+        let user_id = Id::generate();
+        let category = category_fixture(user_id);
+        let fallback = category_fixture(user_id);
+        let command = DeleteCategoryCommand::new(category.id().value(), user_id, fallback.id().value());
+
+        let event = category.handle_deletion(command, &fallback).unwrap();
+
+        match &event {
+            CategoryEvent::CategoryDeleted(category_deleted) => {
+                assert_eq!(category_deleted.payload().fallback_category_id(), fallback.id());
+            }
+            _ => panic!("Expected CategoryDeleted event"),
+        }
+
+        let category = Category::apply(Some(category), &event).unwrap();
+        let command = RenameCategoryCommand::new(category.id().value(), user_id, "Groceries".to_string());
+
+        assert!(category.is_deleted());
+        assert!(matches!(category.handle_rename(command), Err(DomainError::CategoryNotFound(_))));
+    }
+
+    #[test]
+    fn test_delete_with_invalid_fallback() {
+        let user_id = Id::generate();
+        let category = category_fixture(user_id);
+        let foreign = category_fixture(Id::generate());
+
+        let command = DeleteCategoryCommand::new(category.id().value(), user_id, category.id().value());
+        assert!(matches!(category.handle_deletion(command, &category), Err(DomainError::InvalidFallbackCategory(_))));
+
+        let command = DeleteCategoryCommand::new(category.id().value(), user_id, foreign.id().value());
+        assert!(matches!(category.handle_deletion(command, &foreign), Err(DomainError::InvalidFallbackCategory(_))));
+    }
+
+    #[test]
+    fn test_create_under_parent() {
+        let user_id = Id::generate();
+        let parent = category_fixture(user_id);
+        let child = child_fixture(&parent);
+
+        assert_eq!(child.parent_id(), &Some(parent.id().clone()));
+
+        let command = CreateCategoryCommand::new(Id::generate(), "Produce".to_string(), None, Some(parent.id().value()));
+        assert!(matches!(Category::handle_creation(command, Some(&parent)), Err(DomainError::InvalidParentCategory(_))));
+    }
+
+    #[test]
+    fn test_move_to_another_parent_and_to_root() {
+        let user_id = Id::generate();
+        let food = category_fixture(user_id);
+        let groceries = child_fixture(&food);
+        let home = category_fixture(user_id);
+
+        let command = MoveCategoryCommand::new(groceries.id().value(), user_id, Some(home.id().value()));
+        let moved = groceries.handle_move(command, &[home.clone()]).unwrap();
+        let groceries = Category::apply(Some(groceries), &moved).unwrap();
+
+        assert_eq!(groceries.parent_id(), &Some(home.id().clone()));
+
+        let command = MoveCategoryCommand::new(groceries.id().value(), user_id, None);
+        let moved = groceries.handle_move(command, &[]).unwrap();
+        let groceries = Category::apply(Some(groceries), &moved).unwrap();
+
+        assert_eq!(groceries.parent_id(), &None);
+    }
+
+    #[test]
+    fn test_move_under_descendant() {
+        let user_id = Id::generate();
+        let food = category_fixture(user_id);
+        let groceries = child_fixture(&food);
+        let produce = child_fixture(&groceries);
+
+        let command = MoveCategoryCommand::new(food.id().value(), user_id, Some(produce.id().value()));
+        let res = food.handle_move(command, &[produce.clone(), groceries.clone(), food.clone()]);
+        assert!(matches!(res, Err(DomainError::CategoryCycle(_))));
+
+        let command = MoveCategoryCommand::new(food.id().value(), user_id, Some(food.id().value()));
+        let res = food.handle_move(command, &[food.clone()]);
+        assert!(matches!(res, Err(DomainError::CategoryCycle(_))));
+    }
+
+    #[test]
+    fn test_move_under_foreign_parent() {
+        let user_id = Id::generate();
+        let category = category_fixture(user_id);
+        let foreign = category_fixture(Id::generate());
+
+        let command = MoveCategoryCommand::new(category.id().value(), user_id, Some(foreign.id().value()));
+        let res = category.handle_move(command, &[foreign]);
+
+        assert!(matches!(res, Err(DomainError::InvalidParentCategory(_))));
+    }
+
+    #[test]
+    fn test_parent_deletion_moves_child_up() {
+        let user_id = Id::generate();
+        let food = category_fixture(user_id);
+        let groceries = child_fixture(&food);
+        let produce = child_fixture(&groceries);
+
+        let moved = produce.handle_parent_deletion(&groceries).unwrap();
+        let produce = Category::apply(Some(produce), &moved).unwrap();
+
+        assert_eq!(produce.parent_id(), &Some(food.id().clone()));
+        assert!(matches!(produce.handle_parent_deletion(&groceries), Err(DomainError::InvalidParentCategory(_))));
+    }
+
+    fn category_fixture(user_id: Uuid) -> Category {
+        let command = CreateCategoryCommand::new(user_id, "Food".to_string(), None, None);
+        let event = Category::handle_creation(command, None).unwrap();
+
+        Category::apply(None, &event).unwrap()
+    }
+
+    fn child_fixture(parent: &Category) -> Category {
+        let command = CreateCategoryCommand::new(parent.user_id().value(), "Groceries".to_string(), None, Some(parent.id().value()));
+        let event = Category::handle_creation(command, Some(parent)).unwrap();
+
+        Category::apply(None, &event).unwrap()
+    }
+
+    fn stored_fixture(event: &CategoryEvent, version: i32) -> StoredEvent {
+        let new = event.to_new().unwrap();
+
+        StoredEvent::new(Id::generate(), Id::generate(), new.name().to_string(), new.payload().clone(), version, Utc::now())
+    }
+}
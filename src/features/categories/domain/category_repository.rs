@@ -1,12 +1,22 @@
 use async_trait::async_trait;
 use mockall::automock;
+use uuid::Uuid;
+use crate::features::categories::domain::category::Category;
 use crate::features::categories::domain::events::category_created::CategoryCreated;
+use crate::features::categories::domain::events::category_event::CategoryEvent;
 use crate::features::categories::error::CategoryError;
+use crate::support::event_store::Versioned;
 
 #[async_trait]
 #[automock]
 pub trait CategoryRepository {
-    async fn exists(&self, category_created_name: &str, category_deleted_name: &str, name: &str) -> Result<bool, CategoryError>;
+    /// Looks up a live category of the user by its normalized name, see `support::name::normalize_name`.
+    async fn find_id_by_name(&self, user_id: Uuid, name: &str) -> Result<Option<Uuid>, CategoryError>;
+
+    async fn load(&self, category_id: Uuid) -> Result<Option<Versioned<Category>>, CategoryError>;
+
+    /// Fails with `InfrastructureError::Conflict` when the stream is no longer at `expected_version`.
+    async fn append(&self, category_id: Uuid, expected_version: i32, events: &[CategoryEvent]) -> Result<i32, CategoryError>;
 
     async fn persist_category_created_event(&self, category: &CategoryCreated) -> Result<(), CategoryError>;
 }
\ No newline at end of file
@@ -7,4 +7,22 @@ pub enum DomainError {
 
     #[error("Category {0} not found")]
     CategoryNotFound(String),
+
+    #[error("Category {0} is already archived")]
+    CategoryAlreadyArchived(String),
+
+    #[error("Category {0} can not be used as fallback")]
+    InvalidFallbackCategory(String),
+
+    #[error("Category {0} can not be used as parent")]
+    InvalidParentCategory(String),
+
+    #[error("Category {0} can not be moved under its own descendant")]
+    CategoryCycle(String),
+
+    #[error("Invalid report filter. {0}")]
+    InvalidReportFilter(String),
+
+    #[error("Category belongs to another user")]
+    AccessDenied,
 }
\ No newline at end of file
@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+use crate::support::id::Id;
+
+pub const CATEGORY_ARCHIVED_NAME: &str = "category_archived";
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CategoryArchived {
+    id: Id,
+    name: String,
+    payload: CategoryArchivedPayload,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CategoryArchivedPayload {
+    id: Id,
+    user_id: Id,
+}
+
+impl CategoryArchived {
+    pub fn new(id: Id, category_id: Id, user_id: Id) -> Self {
+        Self {
+            id,
+            name: CATEGORY_ARCHIVED_NAME.to_string(),
+            payload: CategoryArchivedPayload {
+                id: category_id,
+                user_id,
+            },
+        }
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn payload(&self) -> &CategoryArchivedPayload {
+        &self.payload
+    }
+}
+
+impl CategoryArchivedPayload {
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Id {
+        &self.user_id
+    }
+}
@@ -16,10 +16,13 @@ pub struct CategoryCreatedPayload {
     user_id: Id,
     name: String,
     icon: Option<String>,
+    // Absent in events recorded before categories could be nested
+    #[serde(default)]
+    parent_id: Option<Id>,
 }
 
 impl CategoryCreated {
-    pub fn new(id: Id, category_id: Id, user_id: Id, name: String, icon: Option<String>) -> Self {
+    pub fn new(id: Id, category_id: Id, user_id: Id, name: String, icon: Option<String>, parent_id: Option<Id>) -> Self {
         Self {
             id,
             name: CATEGORY_CREATED_NAME.to_string(),
@@ -28,6 +31,7 @@ impl CategoryCreated {
                 user_id,
                 name,
                 icon,
+                parent_id,
             },
         }
     }
@@ -61,4 +65,8 @@ impl CategoryCreatedPayload {
     pub fn icon(&self) -> &Option<String> {
         &self.icon
     }
+
+    pub fn parent_id(&self) -> &Option<Id> {
+        &self.parent_id
+    }
 }
\ No newline at end of file
@@ -1 +1,59 @@
-pub const CATEGORY_DELETED_NAME: &str = "category_deleted";
\ No newline at end of file
+use serde::{Deserialize, Serialize};
+use crate::support::id::Id;
+
+pub const CATEGORY_DELETED_NAME: &str = "category_deleted";
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CategoryDeleted {
+    id: Id,
+    name: String,
+    payload: CategoryDeletedPayload,
+}
+
+/// Operations of the deleted category are moved to `fallback_category_id`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CategoryDeletedPayload {
+    id: Id,
+    user_id: Id,
+    fallback_category_id: Id,
+}
+
+impl CategoryDeleted {
+    pub fn new(id: Id, category_id: Id, user_id: Id, fallback_category_id: Id) -> Self {
+        Self {
+            id,
+            name: CATEGORY_DELETED_NAME.to_string(),
+            payload: CategoryDeletedPayload {
+                id: category_id,
+                user_id,
+                fallback_category_id,
+            },
+        }
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn payload(&self) -> &CategoryDeletedPayload {
+        &self.payload
+    }
+}
+
+impl CategoryDeletedPayload {
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Id {
+        &self.user_id
+    }
+
+    pub fn fallback_category_id(&self) -> &Id {
+        &self.fallback_category_id
+    }
+}
@@ -1,15 +1,63 @@
 use serde::{Deserialize, Serialize};
-use crate::features::categories::domain::events::category_created::CategoryCreated;
+use crate::features::categories::domain::events::category_archived::{CATEGORY_ARCHIVED_NAME, CategoryArchived};
+use crate::features::categories::domain::events::category_created::{CATEGORY_CREATED_NAME, CategoryCreated};
+use crate::features::categories::domain::events::category_deleted::{CATEGORY_DELETED_NAME, CategoryDeleted};
+use crate::features::categories::domain::events::category_icon_changed::{CATEGORY_ICON_CHANGED_NAME, CategoryIconChanged};
+use crate::features::categories::domain::events::category_moved::{CATEGORY_MOVED_NAME, CategoryMoved};
+use crate::features::categories::domain::events::category_renamed::{CATEGORY_RENAMED_NAME, CategoryRenamed};
+use crate::features::categories::domain::events::category_reused::{CATEGORY_REUSED_NAME, CategoryReused};
+use crate::support::event_store::{decode_event, EventStoreError, NewEvent, StorableEvent, StoredEvent};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum CategoryEvent {
     CategoryCreated(CategoryCreated),
+    CategoryRenamed(CategoryRenamed),
+    CategoryIconChanged(CategoryIconChanged),
+    CategoryMoved(CategoryMoved),
+    CategoryArchived(CategoryArchived),
+    CategoryDeleted(CategoryDeleted),
+    CategoryReused(CategoryReused),
 }
 
 impl CategoryEvent {
    pub fn name(&self) -> &str {
        match self {
-           CategoryEvent::CategoryCreated(event) => event.name()
+           CategoryEvent::CategoryCreated(event) => event.name(),
+           CategoryEvent::CategoryRenamed(event) => event.name(),
+           CategoryEvent::CategoryIconChanged(event) => event.name(),
+           CategoryEvent::CategoryMoved(event) => event.name(),
+           CategoryEvent::CategoryArchived(event) => event.name(),
+           CategoryEvent::CategoryDeleted(event) => event.name(),
+           CategoryEvent::CategoryReused(event) => event.name(),
        }
    }
-}
\ No newline at end of file
+}
+
+impl StorableEvent for CategoryEvent {
+    fn from_stored(stored: &StoredEvent) -> Result<Self, EventStoreError> {
+        match stored.name() {
+            CATEGORY_CREATED_NAME => Ok(Self::CategoryCreated(decode_event(stored)?)),
+            CATEGORY_RENAMED_NAME => Ok(Self::CategoryRenamed(decode_event(stored)?)),
+            CATEGORY_ICON_CHANGED_NAME => Ok(Self::CategoryIconChanged(decode_event(stored)?)),
+            CATEGORY_MOVED_NAME => Ok(Self::CategoryMoved(decode_event(stored)?)),
+            CATEGORY_ARCHIVED_NAME => Ok(Self::CategoryArchived(decode_event(stored)?)),
+            CATEGORY_DELETED_NAME => Ok(Self::CategoryDeleted(decode_event(stored)?)),
+            CATEGORY_REUSED_NAME => Ok(Self::CategoryReused(decode_event(stored)?)),
+            name => Err(
+                EventStoreError::Serialization(format!("Unknown category event {}", name))
+            ),
+        }
+    }
+
+    fn to_new(&self) -> Result<NewEvent, EventStoreError> {
+        match self {
+            Self::CategoryCreated(event) => NewEvent::from_event(event),
+            Self::CategoryRenamed(event) => NewEvent::from_event(event),
+            Self::CategoryIconChanged(event) => NewEvent::from_event(event),
+            Self::CategoryMoved(event) => NewEvent::from_event(event),
+            Self::CategoryArchived(event) => NewEvent::from_event(event),
+            Self::CategoryDeleted(event) => NewEvent::from_event(event),
+            Self::CategoryReused(event) => NewEvent::from_event(event),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+use crate::support::id::Id;
+
+pub const CATEGORY_ICON_CHANGED_NAME: &str = "category_icon_changed";
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CategoryIconChanged {
+    id: Id,
+    name: String,
+    payload: CategoryIconChangedPayload,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CategoryIconChangedPayload {
+    id: Id,
+    user_id: Id,
+    icon: Option<String>,
+}
+
+impl CategoryIconChanged {
+    pub fn new(id: Id, category_id: Id, user_id: Id, icon: Option<String>) -> Self {
+        Self {
+            id,
+            name: CATEGORY_ICON_CHANGED_NAME.to_string(),
+            payload: CategoryIconChangedPayload {
+                id: category_id,
+                user_id,
+                icon,
+            },
+        }
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn payload(&self) -> &CategoryIconChangedPayload {
+        &self.payload
+    }
+}
+
+impl CategoryIconChangedPayload {
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Id {
+        &self.user_id
+    }
+
+    pub fn icon(&self) -> &Option<String> {
+        &self.icon
+    }
+}
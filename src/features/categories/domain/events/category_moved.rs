@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+use crate::support::id::Id;
+
+pub const CATEGORY_MOVED_NAME: &str = "category_moved";
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CategoryMoved {
+    id: Id,
+    name: String,
+    payload: CategoryMovedPayload,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CategoryMovedPayload {
+    id: Id,
+    user_id: Id,
+    parent_id: Option<Id>,
+    previous_parent_id: Option<Id>,
+}
+
+impl CategoryMoved {
+    pub fn new(id: Id, category_id: Id, user_id: Id, parent_id: Option<Id>, previous_parent_id: Option<Id>) -> Self {
+        Self {
+            id,
+            name: CATEGORY_MOVED_NAME.to_string(),
+            payload: CategoryMovedPayload {
+                id: category_id,
+                user_id,
+                parent_id,
+                previous_parent_id,
+            },
+        }
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn payload(&self) -> &CategoryMovedPayload {
+        &self.payload
+    }
+}
+
+impl CategoryMovedPayload {
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Id {
+        &self.user_id
+    }
+
+    /// `None` when the category becomes a root category.
+    pub fn parent_id(&self) -> &Option<Id> {
+        &self.parent_id
+    }
+
+    pub fn previous_parent_id(&self) -> &Option<Id> {
+        &self.previous_parent_id
+    }
+}
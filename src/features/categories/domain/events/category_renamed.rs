@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+use crate::support::id::Id;
+
+pub const CATEGORY_RENAMED_NAME: &str = "category_renamed";
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CategoryRenamed {
+    id: Id,
+    name: String,
+    payload: CategoryRenamedPayload,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CategoryRenamedPayload {
+    id: Id,
+    user_id: Id,
+    name: String,
+}
+
+impl CategoryRenamed {
+    pub fn new(id: Id, category_id: Id, user_id: Id, name: String) -> Self {
+        Self {
+            id,
+            name: CATEGORY_RENAMED_NAME.to_string(),
+            payload: CategoryRenamedPayload {
+                id: category_id,
+                user_id,
+                name,
+            },
+        }
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn payload(&self) -> &CategoryRenamedPayload {
+        &self.payload
+    }
+}
+
+impl CategoryRenamedPayload {
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Id {
+        &self.user_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+use crate::support::id::Id;
+
+pub const CATEGORY_REUSED_NAME: &str = "category_reused";
+
+/// Creation of a category requested by an operation resolved to an existing category with the same name.
+/// It is dispatched only and never appended to the category stream.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CategoryReused {
+    id: Id,
+    name: String,
+    payload: CategoryReusedPayload,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CategoryReusedPayload {
+    id: Id,
+    user_id: Id,
+    operation_id: Id,
+    requested_category_id: Id,
+}
+
+impl CategoryReused {
+    pub fn new(id: Id, category_id: Id, user_id: Id, operation_id: Id, requested_category_id: Id) -> Self {
+        Self {
+            id,
+            name: CATEGORY_REUSED_NAME.to_string(),
+            payload: CategoryReusedPayload {
+                id: category_id,
+                user_id,
+                operation_id,
+                requested_category_id,
+            },
+        }
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn payload(&self) -> &CategoryReusedPayload {
+        &self.payload
+    }
+}
+
+impl CategoryReusedPayload {
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Id {
+        &self.user_id
+    }
+
+    pub fn operation_id(&self) -> &Id {
+        &self.operation_id
+    }
+
+    pub fn requested_category_id(&self) -> &Id {
+        &self.requested_category_id
+    }
+}
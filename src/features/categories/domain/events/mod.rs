@@ -1,3 +1,8 @@
+pub mod category_archived;
 pub mod category_created;
 pub mod category_event;
-pub mod category_deleted;
\ No newline at end of file
+pub mod category_deleted;
+pub mod category_icon_changed;
+pub mod category_moved;
+pub mod category_renamed;
+pub mod category_reused;
\ No newline at end of file
@@ -0,0 +1,202 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{query, query_as, query_scalar, Pool, Postgres};
+use sqlx::postgres::PgArguments;
+use sqlx::query::Query;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+use crate::db::manager::DbManager;
+use crate::features::categories::application::queries::category_projection_repository::CategoryProjectionRepository;
+use crate::features::categories::application::queries::category_total::CategoryTotal;
+use crate::features::categories::application::queries::category_view::CategoryView;
+use crate::features::categories::domain::events::category_archived::CategoryArchived;
+use crate::features::categories::domain::events::category_created::CategoryCreated;
+use crate::features::categories::domain::events::category_deleted::CategoryDeleted;
+use crate::features::categories::domain::events::category_icon_changed::CategoryIconChanged;
+use crate::features::categories::domain::events::category_moved::CategoryMoved;
+use crate::features::categories::domain::events::category_renamed::CategoryRenamed;
+use crate::features::categories::error::CategoryError;
+use crate::features::categories::infrastructure::error::InfrastructureError;
+use crate::support::name::normalize_name;
+
+#[derive(Clone)]
+pub struct DbCategoryProjectionRepository {
+    db_manager: Arc<Mutex<DbManager>>,
+}
+
+impl DbCategoryProjectionRepository {
+    pub fn new(db_manager: Arc<Mutex<DbManager>>) -> Self {
+        Self {
+            db_manager,
+        }
+    }
+
+    async fn pool(&self) -> Result<Pool<Postgres>, CategoryError> {
+        let guard = self.db_manager.lock().await;
+
+        guard.pool()
+            .map_err(|e|
+                CategoryError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to get pool: {}", e)
+                    )
+                )
+            )
+    }
+
+    async fn execute(&self, res_query: Query<'_, Postgres, PgArguments>) -> Result<(), CategoryError> {
+        let pool = self.pool().await?;
+
+        res_query.execute(&pool)
+            .await
+            .map_err(|e|
+                CategoryError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to project category: {}", e)
+                    )
+                )
+            )?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl CategoryProjectionRepository for DbCategoryProjectionRepository {
+    async fn find(&self, user_id: Uuid, include_archived: bool) -> Result<Vec<CategoryView>, CategoryError> {
+        let q = "
+            SELECT id, user_id, name, icon, parent_id, archived, created_at
+            FROM categories
+            WHERE user_id = $1
+                AND ($2 OR NOT archived)
+            ORDER BY name, id
+        ";
+
+        let pool = self.pool().await?;
+
+        query_as::<_, CategoryView>(q)
+            .bind(user_id)
+            .bind(include_archived)
+            .fetch_all(&pool)
+            .await
+            .map_err(|e|
+                CategoryError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to fetch categories: {}", e)
+                    )
+                )
+            )
+    }
+
+    async fn find_child_ids(&self, user_id: Uuid, category_id: Uuid) -> Result<Vec<Uuid>, CategoryError> {
+        let q = "SELECT id FROM categories WHERE user_id = $1 AND parent_id = $2";
+
+        let pool = self.pool().await?;
+
+        query_scalar::<_, Uuid>(q)
+            .bind(user_id)
+            .bind(category_id)
+            .fetch_all(&pool)
+            .await
+            .map_err(|e|
+                CategoryError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to fetch child categories: {}", e)
+                    )
+                )
+            )
+    }
+
+    async fn find_totals(
+        &self,
+        user_id: Uuid,
+        kind: Option<String>,
+        date_from: Option<DateTime<Utc>>,
+        date_to: Option<DateTime<Utc>>,
+    ) -> Result<Vec<CategoryTotal>, CategoryError> {
+        // Lines of split operations count towards their own categories
+        let q = "
+            SELECT COALESCE((line ->> 'category_id')::uuid, category_id) AS category_id,
+                   SUM(COALESCE((line ->> 'amount')::NUMERIC, amount)) AS total
+            FROM operations
+            LEFT JOIN LATERAL jsonb_array_elements(lines) AS line ON TRUE
+            WHERE user_id = $1
+                AND kind <> 'Transfer'
+                AND ($2::VARCHAR IS NULL OR kind = $2)
+                AND ($3::TIMESTAMPTZ IS NULL OR created_at >= $3)
+                AND ($4::TIMESTAMPTZ IS NULL OR created_at <= $4)
+            GROUP BY 1
+        ";
+
+        let pool = self.pool().await?;
+
+        query_as::<_, CategoryTotal>(q)
+            .bind(user_id)
+            .bind(kind)
+            .bind(date_from)
+            .bind(date_to)
+            .fetch_all(&pool)
+            .await
+            .map_err(|e|
+                CategoryError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to fetch category totals: {}", e)
+                    )
+                )
+            )
+    }
+
+    async fn apply_category_created(&self, event: &CategoryCreated) -> Result<(), CategoryError> {
+        let payload = event.payload();
+
+        self.execute(
+            query("INSERT INTO categories (id, user_id, name, normalized_name, icon, parent_id) VALUES ($1, $2, $3, $4, $5, $6) ON CONFLICT DO NOTHING")
+                .bind(payload.id().value())
+                .bind(payload.user_id().value())
+                .bind(payload.name().to_string())
+                .bind(normalize_name(payload.name()))
+                .bind(payload.icon().clone())
+                .bind(payload.parent_id().as_ref().map(|parent_id| parent_id.value()))
+        ).await
+    }
+
+    async fn apply_category_renamed(&self, event: &CategoryRenamed) -> Result<(), CategoryError> {
+        self.execute(
+            query("UPDATE categories SET name = $2, normalized_name = $3 WHERE id = $1")
+                .bind(event.payload().id().value())
+                .bind(event.payload().name().to_string())
+                .bind(normalize_name(event.payload().name()))
+        ).await
+    }
+
+    async fn apply_category_icon_changed(&self, event: &CategoryIconChanged) -> Result<(), CategoryError> {
+        self.execute(
+            query("UPDATE categories SET icon = $2 WHERE id = $1")
+                .bind(event.payload().id().value())
+                .bind(event.payload().icon().clone())
+        ).await
+    }
+
+    async fn apply_category_moved(&self, event: &CategoryMoved) -> Result<(), CategoryError> {
+        self.execute(
+            query("UPDATE categories SET parent_id = $2 WHERE id = $1")
+                .bind(event.payload().id().value())
+                .bind(event.payload().parent_id().as_ref().map(|parent_id| parent_id.value()))
+        ).await
+    }
+
+    async fn apply_category_archived(&self, event: &CategoryArchived) -> Result<(), CategoryError> {
+        self.execute(
+            query("UPDATE categories SET archived = TRUE WHERE id = $1")
+                .bind(event.payload().id().value())
+        ).await
+    }
+
+    async fn apply_category_deleted(&self, event: &CategoryDeleted) -> Result<(), CategoryError> {
+        self.execute(
+            query("DELETE FROM categories WHERE id = $1")
+                .bind(event.payload().id().value())
+        ).await
+    }
+}
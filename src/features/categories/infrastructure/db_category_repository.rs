@@ -1,23 +1,33 @@
 use std::sync::Arc;
 use async_trait::async_trait;
-use sqlx::{query, Row};
+use sqlx::query_scalar;
 use tokio::sync::Mutex;
+use uuid::Uuid;
 use crate::db::manager::DbManager;
+use crate::features::categories::domain::category::Category;
 use crate::features::categories::domain::category_repository::CategoryRepository;
 use crate::features::categories::domain::events::category_created::CategoryCreated;
+use crate::features::categories::domain::events::category_event::CategoryEvent;
 use crate::features::categories::error::CategoryError;
 use crate::features::categories::infrastructure::error::InfrastructureError;
 use crate::services::serializer::Serializer;
+use crate::support::event_store::{Aggregate, EventStore, StorableEvent, Versioned};
+use crate::support::name::normalize_name;
+use crate::support::pg_event_store::PgEventStore;
+
+const EVENTS_TABLE: &str = "category_events";
 
 #[derive(Clone)]
 pub struct DbCategoryRepository {
     db_manager: Arc<Mutex<DbManager>>,
+    event_store: PgEventStore,
     serializer: Serializer,
 }
 
 impl DbCategoryRepository {
     pub fn new(db_manager: Arc<Mutex<DbManager>>, serializer: Serializer) -> Self {
         Self {
+            event_store: PgEventStore::new(db_manager.clone(), EVENTS_TABLE),
             db_manager,
             serializer,
         }
@@ -26,96 +36,76 @@ impl DbCategoryRepository {
 
 #[async_trait]
 impl CategoryRepository for DbCategoryRepository {
-    async fn exists(&self, category_created_name: &str, category_deleted_name: &str, name: &str) -> Result<bool, CategoryError> {
+    async fn find_id_by_name(&self, user_id: Uuid, name: &str) -> Result<Option<Uuid>, CategoryError> {
         let guard = self.db_manager.lock().await;
         let pool = guard.pool()
             .map_err(|e|
                 CategoryError::Infrastructure(
                     InfrastructureError::Repository(
-                        format!("Failed to get pool: {}", e.to_string())
+                        format!("Failed to get pool: {}", e)
                     )
                 )
             )?;
 
-        let q = "
-            SELECT EXISTS (
-                SELECT 1
-                FROM category_events
-                WHERE payload->>'name' = $1
-                    AND name = $2
-            AND NOT EXISTS (
-                SELECT 1
-                FROM category_events
-                WHERE payload->>'name' = $1
-                    AND name = $3
-            )
-            ) AS exists
-        ";
-
-        let query = query(q)
-            .bind(name)
-            .bind(category_created_name)
-            .bind(category_deleted_name);
-
-        let row = query.fetch_one(&pool).await
+        query_scalar::<_, Uuid>("SELECT id FROM categories WHERE user_id = $1 AND normalized_name = $2")
+            .bind(user_id)
+            .bind(normalize_name(name))
+            .fetch_optional(&pool)
+            .await
             .map_err(|e|
                 CategoryError::Infrastructure(
                     InfrastructureError::Repository(
-                        format!("Failed to execute persist category creation event query: {}", e.to_string())
+                        format!("Failed to find category by name: {}", e)
                     )
                 )
-            )?;
+            )
+    }
 
-        let exists = row.try_get::<bool, _>(0)
+    async fn load(&self, category_id: Uuid) -> Result<Option<Versioned<Category>>, CategoryError> {
+        let stream = self.event_store.load(category_id)
+            .await
             .map_err(|e|
                 CategoryError::Infrastructure(
                     InfrastructureError::Repository(
-                        format!("Failed to get exists value: {}", e.to_string())
+                        format!("Failed to load category events: {}", e)
                     )
                 )
             )?;
 
-        Ok(exists)
-    }
-
-    async fn persist_category_created_event(&self, category_created: &CategoryCreated) -> Result<(), CategoryError> {
-        let q = "INSERT INTO category_events (id, name, payload) VALUES ($1, $2, $3)";
-
-        let payload = serde_json::to_value(
-            &category_created.payload()
-        ).map_err(|e|
-            CategoryError::Infrastructure(
-                InfrastructureError::Repository(
-                    format!("Failed to serialize category event payload: {}", e.to_string())
-                )
-            )
-        )?;
-
-        let query = query(q)
-            .bind(category_created.id().value())
-            .bind(category_created.name())
-            .bind(payload);
-
-        let guard = self.db_manager.lock().await;
-        let pool = guard.pool()
+        Category::rehydrate(&stream)
             .map_err(|e|
                 CategoryError::Infrastructure(
                     InfrastructureError::Repository(
-                        format!("Failed to get pool: {}", e.to_string())
+                        format!("Failed to rehydrate category: {}", e)
                     )
                 )
-            )?;
+            )
+    }
 
-        query.execute(&pool)
-            .await
+    async fn append(&self, category_id: Uuid, expected_version: i32, events: &[CategoryEvent]) -> Result<i32, CategoryError> {
+        let events = events.iter()
+            .map(|event| event.to_new())
+            .collect::<Result<Vec<_>, _>>()
             .map_err(|e|
                 CategoryError::Infrastructure(
                     InfrastructureError::Repository(
-                        format!("Failed to persist category event: {}", e.to_string())
+                        format!("Failed to serialize category event payload: {}", e)
                     )
                 )
             )?;
 
+        self.event_store.append(category_id, expected_version, &events)
+            .await
+            .map_err(|e|
+                CategoryError::Infrastructure(e.into())
+            )
+    }
+
+    async fn persist_category_created_event(&self, category_created: &CategoryCreated) -> Result<(), CategoryError> {
+        let category_id = category_created.payload().id().value();
+
+        self.append(category_id, 0, &[CategoryEvent::CategoryCreated(category_created.clone())]).await?;
+
         Ok(())
     }
-}
\ No newline at end of file
+}
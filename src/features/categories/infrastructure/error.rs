@@ -1,7 +1,20 @@
 use thiserror::Error;
+use crate::support::event_store::EventStoreError;
 
 #[derive(Debug, Clone, Error)]
 pub enum InfrastructureError {
     #[error("Category repository error. {0}")]
-    Repository(String)
+    Repository(String),
+
+    #[error("Category conflict error. {0}")]
+    Conflict(String),
+}
+
+impl From<EventStoreError> for InfrastructureError {
+    fn from(e: EventStoreError) -> Self {
+        match e {
+            EventStoreError::Concurrency { .. } => Self::Conflict(e.to_string()),
+            _ => Self::Repository(e.to_string()),
+        }
+    }
 }
\ No newline at end of file
@@ -4,8 +4,8 @@ use tokio::sync::Mutex;
 use crate::events::error::EventError;
 use crate::events::event::Event;
 use crate::events::event_listener::EventListener;
-use crate::features::categories::application::commands::create_category::command::CreateCategoryCommand;
-use crate::features::categories::application::commands::create_category::handler::CreateCategoryCommandHandler;
+use crate::features::categories::application::commands::resolve_category::command::ResolveCategoryCommand;
+use crate::features::categories::application::commands::resolve_category::handler::ResolveCategoryCommandHandler;
 use crate::features::categories::domain::category_repository::CategoryRepository;
 use crate::features::operations::domain::events::category_creation_requested::CategoryCreationRequested;
 use crate::features::operations::domain::events::operation_event::OperationEvent;
@@ -13,11 +13,12 @@ use crate::support::command_bus::{CommandBus};
 
 const EVENT_NAME: &str = "category_creation_requested";
 
+/// Resolves the category an operation asked for by name, reusing the user's category with the same name if there is one.
 pub struct CategoryCreationRequestedListener<R>
     where
         R: CategoryRepository + Clone + Send + Sync + 'static,
 {
-    command_bus: Arc<Mutex<CommandBus<CreateCategoryCommand, CreateCategoryCommandHandler<R>>>>,
+    command_bus: Arc<Mutex<CommandBus<ResolveCategoryCommand, ResolveCategoryCommandHandler<R>>>>,
 }
 
 #[async_trait]
@@ -28,7 +29,12 @@ impl<R> EventListener for CategoryCreationRequestedListener<R>
     async fn on_event(&mut self, event: Event) -> Result<Vec<Event>, EventError> {
         let event = self.parse_event(event)?;
 
-        let command = CreateCategoryCommand::new(event.payload().user_id().value(), event.payload().category_name().to_string(), None);
+        let command = ResolveCategoryCommand::new(
+            event.payload().operation_id().value(),
+            event.payload().user_id().value(),
+            event.payload().category_id().value(),
+            event.payload().category_name().to_string(),
+        );
 
         let mut guard = self.command_bus.lock().await;
         let events = guard.dispatch(command)
@@ -48,13 +54,11 @@ impl<R> CategoryCreationRequestedListener<R>
         R: CategoryRepository + Clone + Send + Sync + 'static,
 {
     pub async fn new(
-        command_bus: Arc<Mutex<CommandBus<CreateCategoryCommand, CreateCategoryCommandHandler<R>>>>,
+        command_bus: Arc<Mutex<CommandBus<ResolveCategoryCommand, ResolveCategoryCommandHandler<R>>>>,
         rep: R
     ) -> Self {
-        let handler = CreateCategoryCommandHandler::new(rep);
-
         let mut guard = command_bus.lock().await;
-        guard.register(handler.clone());
+        guard.register(ResolveCategoryCommandHandler::new(rep));
 
         Self {
             command_bus: command_bus.clone(),
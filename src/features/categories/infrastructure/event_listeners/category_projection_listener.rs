@@ -0,0 +1,71 @@
+use async_trait::async_trait;
+use crate::events::error::EventError;
+use crate::events::event::Event;
+use crate::events::event_listener::EventListener;
+use crate::features::categories::application::queries::category_projection_repository::CategoryProjectionRepository;
+use crate::features::categories::domain::events::category_event::CategoryEvent;
+use crate::support::error::FeatureError;
+
+/// Keeps the `categories` read model in sync with the category event stream.
+/// One instance is registered per category event name.
+pub struct CategoryProjectionListener<R>
+    where
+        R: CategoryProjectionRepository + Send + Sync + 'static,
+{
+    rep: R,
+    event_name: &'static str,
+}
+
+#[async_trait]
+impl<R> EventListener for CategoryProjectionListener<R>
+    where
+        R: CategoryProjectionRepository + Send + Sync + 'static,
+{
+    async fn on_event(&mut self, event: Event) -> Result<Vec<Event>, EventError> {
+        let res = match self.parse_event(event)? {
+            CategoryEvent::CategoryCreated(event) => self.rep.apply_category_created(&event).await,
+            CategoryEvent::CategoryRenamed(event) => self.rep.apply_category_renamed(&event).await,
+            CategoryEvent::CategoryIconChanged(event) => self.rep.apply_category_icon_changed(&event).await,
+            CategoryEvent::CategoryMoved(event) => self.rep.apply_category_moved(&event).await,
+            CategoryEvent::CategoryArchived(event) => self.rep.apply_category_archived(&event).await,
+            CategoryEvent::CategoryDeleted(event) => self.rep.apply_category_deleted(&event).await,
+            // Dispatched only, it changes nothing in the category itself
+            CategoryEvent::CategoryReused(_) => Ok(()),
+        };
+
+        res.map_err(|e|
+            EventError::Feature(
+                FeatureError::Category(e)
+            )
+        )?;
+
+        Ok(vec![])
+    }
+
+    fn event_name(&self) -> &str {
+        self.event_name
+    }
+}
+
+impl<R> CategoryProjectionListener<R>
+    where
+        R: CategoryProjectionRepository + Send + Sync + 'static,
+{
+    pub fn new(rep: R, event_name: &'static str) -> Self {
+        Self {
+            rep,
+            event_name,
+        }
+    }
+
+    pub fn parse_event(&self, event: Event) -> Result<CategoryEvent, EventError> {
+        match event {
+            Event::CategoryEvent(category_event) => Ok(category_event),
+            _ => Err(
+                EventError::Parsing(
+                    format!("Invalid event type. Expected CategoryEvent, got {:?}", event)
+                )
+            )
+        }
+    }
+}
@@ -1 +1,2 @@
-pub mod category_creation_requested_listener;
\ No newline at end of file
+pub mod category_creation_requested_listener;
+pub mod category_projection_listener;
\ No newline at end of file
@@ -0,0 +1,67 @@
+use uuid::Uuid;
+use crate::features::imports::domain::column_mapping::ColumnMapping;
+use crate::support::command_bus::Command;
+
+const NAME: &str = "import_statement";
+
+#[derive(Debug, Clone)]
+pub struct ImportStatementCommand {
+    user_id: Uuid,
+    account_id: Uuid,
+    format: String,
+    content: Vec<u8>,
+    mapping: Option<ColumnMapping>,
+    category_name: String,
+}
+
+impl ImportStatementCommand {
+    pub fn new(
+        user_id: Uuid,
+        account_id: Uuid,
+        format: String,
+        content: Vec<u8>,
+        mapping: Option<ColumnMapping>,
+        category_name: String,
+    ) -> Self {
+        Self {
+            user_id,
+            account_id,
+            format,
+            content,
+            mapping,
+            category_name,
+        }
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    pub fn account_id(&self) -> &Uuid {
+        &self.account_id
+    }
+
+    pub fn format(&self) -> &str {
+        &self.format
+    }
+
+    pub fn content(&self) -> &[u8] {
+        &self.content
+    }
+
+    /// Columns of a CSV statement, the default mapping when omitted.
+    pub fn mapping(&self) -> &Option<ColumnMapping> {
+        &self.mapping
+    }
+
+    /// Category the imported operations are booked to.
+    pub fn category_name(&self) -> &str {
+        &self.category_name
+    }
+}
+
+impl Command for ImportStatementCommand {
+    fn name() -> &'static str {
+        NAME
+    }
+}
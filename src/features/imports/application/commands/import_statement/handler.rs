@@ -0,0 +1,231 @@
+use async_trait::async_trait;
+use chrono::{TimeZone, Utc};
+use crate::events::event::Event;
+use crate::features::imports::application::commands::import_statement::command::ImportStatementCommand;
+use crate::features::imports::application::statement_planner::StatementPlanner;
+use crate::features::imports::domain::fingerprint_source::FingerprintSource;
+use crate::features::imports::domain::import_row::{ImportRow, RowStatus};
+use crate::features::imports::domain::statement_format::StatementFormat;
+use crate::features::imports::domain::statement_import::StatementImport;
+use crate::features::imports::domain::statement_import_repository::StatementImportRepository;
+use crate::features::imports::error::ImportError;
+use crate::features::operations::application::commands::create_operation::command::CreateOperationCommand;
+use crate::features::operations::domain::account_source::AccountSource;
+use crate::features::operations::domain::kind::Kind;
+use crate::features::operations::domain::operation_creator::OperationCreator;
+use crate::support::command_bus::CommandHandler;
+use crate::support::error::FeatureError;
+use crate::support::id::Id;
+
+/// Creates an operation for each new row of a statement, dated on the day the row was booked.
+///
+/// A row that fails to be created is reported as failed and does not stop the rest of the import.
+/// The outcome of every row is recorded with the `statement_imported` event, returned after the events
+/// of the created operations.
+pub struct ImportStatementCommandHandler<R, A, F, C>
+    where
+        R: StatementImportRepository + Send + Sync,
+        A: AccountSource + Send + Sync,
+        F: FingerprintSource + Send + Sync,
+        C: OperationCreator + Send + Sync,
+{
+    statement_import_repository: R,
+    planner: StatementPlanner<A, F>,
+    operations: C,
+}
+
+impl<R, A, F, C> ImportStatementCommandHandler<R, A, F, C>
+    where
+        R: StatementImportRepository + Send + Sync,
+        A: AccountSource + Send + Sync,
+        F: FingerprintSource + Send + Sync,
+        C: OperationCreator + Send + Sync,
+{
+    pub fn new(statement_import_repository: R, accounts: A, fingerprints: F, operations: C) -> Self {
+        Self {
+            statement_import_repository,
+            planner: StatementPlanner::new(accounts, fingerprints),
+            operations,
+        }
+    }
+}
+
+#[async_trait]
+impl<R, A, F, C> CommandHandler<ImportStatementCommand> for ImportStatementCommandHandler<R, A, F, C>
+    where
+        R: StatementImportRepository + Send + Sync,
+        A: AccountSource + Send + Sync,
+        F: FingerprintSource + Send + Sync,
+        C: OperationCreator + Send + Sync,
+{
+    async fn handle(&mut self, command: ImportStatementCommand) -> Result<Vec<Event>, FeatureError> {
+        let format = StatementFormat::new(command.format())
+            .map_err(|e| FeatureError::Import(ImportError::Domain(e)))?;
+
+        let planned = self.planner.plan(
+            *command.user_id(),
+            *command.account_id(),
+            format,
+            command.content(),
+            &command.mapping().clone().unwrap_or_default(),
+        ).await?;
+
+        let mut events = vec![];
+        let mut rows: Vec<ImportRow> = vec![];
+
+        for row in planned {
+            let (RowStatus::Ready, Some(date), Some(amount), Some(currency), Some(label), Some(operation_id)) =
+                (row.status(), row.date(), row.amount(), row.currency(), row.label(), row.operation_id()) else {
+                rows.push(row);
+                continue;
+            };
+
+            let kind = if amount.is_sign_positive() { Kind::Income } else { Kind::Expense };
+            let created_at = Utc.from_utc_datetime(&date.and_time(Default::default()));
+
+            let operation = CreateOperationCommand::new(
+                kind.to_str().to_string(),
+                *command.user_id(),
+                *command.account_id(),
+                None,
+                command.category_name().to_string(),
+                None,
+                currency.clone(),
+                amount.abs(),
+                None,
+                label.clone(),
+                vec![],
+            )
+                .with_operation_id(*operation_id)
+                .with_created_at(created_at);
+
+            match self.operations.create(operation).await {
+                Ok(created) => {
+                    events.extend(created);
+                    rows.push(row.created());
+                }
+                Err(e) => rows.push(row.failed(e.to_string())),
+            }
+        }
+
+        let import_id = Id::generate();
+        let event = StatementImport::handle_import(import_id, *command.user_id(), *command.account_id(), format, rows);
+
+        self.statement_import_repository.append(import_id, 0, std::slice::from_ref(&event))
+            .await
+            .map_err(FeatureError::Import)?;
+
+        events.push(Event::StatementImportEvent(event));
+
+        Ok(events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::FutureExt;
+    use uuid::Uuid;
+    use crate::features::imports::domain::events::statement_import_event::StatementImportEvent;
+    use crate::features::imports::domain::fingerprint_source::MockFingerprintSource;
+    use crate::features::imports::domain::statement_import_repository::MockStatementImportRepository;
+    use crate::features::operations::domain::account_source::{MockAccountSource, OperationAccount};
+    use crate::features::operations::domain::operation_creator::MockOperationCreator;
+    use crate::features::operations::error::OperationError;
+    use crate::features::operations::infrastructure::error::InfrastructureError;
+    use super::*;
+
+    const STATEMENT: &str = "date,amount,label\n2024-04-01,-3.50,Coffee\n2024-04-02,100,Salary\n2024-04-03,,Unknown\n";
+
+    #[tokio::test]
+    async fn test_handle_success() {
+        let user_id = Uuid::new_v4();
+
+        let mut operations = MockOperationCreator::new();
+        operations.expect_create()
+            .withf(|command| command.kind() == "Income" && command.created_at().is_some())
+            .times(1)
+            .returning(|_| async { Ok(vec![]) }.boxed());
+
+        let mut handler = ImportStatementCommandHandler::new(
+            MockStatementImportRepository::new(false),
+            accounts(user_id),
+            fingerprints(vec!["2024-04-01|-3.5|coffee".to_string()]),
+            operations,
+        );
+
+        let events = handler.handle(command_fixture(user_id)).await.unwrap();
+
+        let Some(Event::StatementImportEvent(StatementImportEvent::StatementImported(imported))) = events.last() else {
+            panic!("Statement import is not recorded");
+        };
+        let statuses: Vec<RowStatus> = imported.payload().rows().iter().map(|row| row.status()).collect();
+        assert_eq!(statuses, vec![RowStatus::Duplicate, RowStatus::Created, RowStatus::Invalid]);
+    }
+
+    #[tokio::test]
+    async fn test_handle_failed_operation() {
+        let user_id = Uuid::new_v4();
+
+        let mut operations = MockOperationCreator::new();
+        operations.expect_create()
+            .times(2)
+            .returning(|_| async {
+                Err(FeatureError::Operation(OperationError::Infrastructure(InfrastructureError::Repository("Error".to_string()))))
+            }.boxed());
+
+        let mut handler = ImportStatementCommandHandler::new(
+            MockStatementImportRepository::new(false),
+            accounts(user_id),
+            fingerprints(vec![]),
+            operations,
+        );
+
+        let events = handler.handle(command_fixture(user_id)).await.unwrap();
+
+        let Some(Event::StatementImportEvent(StatementImportEvent::StatementImported(imported))) = events.last() else {
+            panic!("Statement import is not recorded");
+        };
+        assert_eq!(imported.payload().rows()[0].status(), RowStatus::Failed);
+        assert!(imported.payload().rows()[0].error().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_handle_unknown_format() {
+        let user_id = Uuid::new_v4();
+        let command = ImportStatementCommand::new(user_id, Uuid::new_v4(), "xls".to_string(), vec![], None, "Imported".to_string());
+
+        let mut handler = ImportStatementCommandHandler::new(
+            MockStatementImportRepository::new(false),
+            accounts(user_id),
+            fingerprints(vec![]),
+            MockOperationCreator::new(),
+        );
+
+        let result = handler.handle(command).await;
+
+        assert!(matches!(result, Err(FeatureError::Import(ImportError::Domain(_)))));
+    }
+
+    fn command_fixture(user_id: Uuid) -> ImportStatementCommand {
+        ImportStatementCommand::new(user_id, Uuid::new_v4(), "Csv".to_string(), STATEMENT.into(), None, "Imported".to_string())
+    }
+
+    fn accounts(user_id: Uuid) -> MockAccountSource {
+        let mut accounts = MockAccountSource::new();
+        accounts.expect_account()
+            .returning(move |_| async move { Ok(Some(OperationAccount::new(user_id, "USD".to_string()))) }.boxed());
+
+        accounts
+    }
+
+    fn fingerprints(existing: Vec<String>) -> MockFingerprintSource {
+        let mut fingerprints = MockFingerprintSource::new();
+        fingerprints.expect_fingerprints()
+            .returning(move |_, _, _| {
+                let existing = existing.clone();
+                async move { Ok(existing) }.boxed()
+            });
+
+        fingerprints
+    }
+}
@@ -0,0 +1,3 @@
+pub mod commands;
+pub mod queries;
+pub mod statement_planner;
@@ -0,0 +1,102 @@
+use async_trait::async_trait;
+use crate::features::imports::application::queries::preview_statement::query::PreviewStatementQuery;
+use crate::features::imports::application::statement_planner::StatementPlanner;
+use crate::features::imports::domain::fingerprint_source::FingerprintSource;
+use crate::features::imports::domain::import_row::ImportRow;
+use crate::features::imports::domain::statement_format::StatementFormat;
+use crate::features::imports::error::ImportError;
+use crate::features::operations::domain::account_source::AccountSource;
+use crate::support::error::FeatureError;
+use crate::support::query_bus::QueryHandler;
+
+/// Dry run of a statement import, reporting what would happen to each row without creating anything.
+pub struct PreviewStatementQueryHandler<A, F>
+    where
+        A: AccountSource + Send + Sync,
+        F: FingerprintSource + Send + Sync,
+{
+    planner: StatementPlanner<A, F>,
+}
+
+impl<A, F> PreviewStatementQueryHandler<A, F>
+    where
+        A: AccountSource + Send + Sync,
+        F: FingerprintSource + Send + Sync,
+{
+    pub fn new(accounts: A, fingerprints: F) -> Self {
+        Self {
+            planner: StatementPlanner::new(accounts, fingerprints),
+        }
+    }
+}
+
+#[async_trait]
+impl<A, F> QueryHandler<PreviewStatementQuery> for PreviewStatementQueryHandler<A, F>
+    where
+        A: AccountSource + Send + Sync,
+        F: FingerprintSource + Send + Sync,
+{
+    type Output = Vec<ImportRow>;
+
+    async fn handle(&self, query: PreviewStatementQuery) -> Result<Vec<ImportRow>, FeatureError> {
+        let format = StatementFormat::new(query.format())
+            .map_err(|e| FeatureError::Import(ImportError::Domain(e)))?;
+
+        self.planner.plan(
+            *query.user_id(),
+            *query.account_id(),
+            format,
+            query.content(),
+            &query.mapping().clone().unwrap_or_default(),
+        ).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::FutureExt;
+    use uuid::Uuid;
+    use crate::features::imports::domain::error::DomainError;
+    use crate::features::imports::domain::fingerprint_source::MockFingerprintSource;
+    use crate::features::imports::domain::import_row::RowStatus;
+    use crate::features::operations::domain::account_source::{MockAccountSource, OperationAccount};
+    use super::*;
+
+    const STATEMENT: &str = "date,amount,label\n2024-04-01,-3.50,Coffee\n2024-04-02,100,Salary\n";
+
+    #[tokio::test]
+    async fn test_handle() {
+        let user_id = Uuid::new_v4();
+        let query = PreviewStatementQuery::new(user_id, Uuid::new_v4(), "Csv".to_string(), STATEMENT.into(), None);
+
+        let mut fingerprints = MockFingerprintSource::new();
+        fingerprints.expect_fingerprints()
+            .returning(|_, _, _| async { Ok(vec!["2024-04-01|-3.5|coffee".to_string()]) }.boxed());
+
+        let rows = PreviewStatementQueryHandler::new(accounts(user_id), fingerprints).handle(query).await.unwrap();
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].status(), RowStatus::Duplicate);
+        assert_eq!(rows[1].status(), RowStatus::Ready);
+    }
+
+    #[tokio::test]
+    async fn test_handle_account_of_other_user() {
+        let query = PreviewStatementQuery::new(Uuid::new_v4(), Uuid::new_v4(), "Csv".to_string(), STATEMENT.into(), None);
+
+        let mut fingerprints = MockFingerprintSource::new();
+        fingerprints.expect_fingerprints().never();
+
+        let result = PreviewStatementQueryHandler::new(accounts(Uuid::new_v4()), fingerprints).handle(query).await;
+
+        assert!(matches!(result, Err(FeatureError::Import(ImportError::Domain(DomainError::AccountNotFound)))));
+    }
+
+    fn accounts(user_id: Uuid) -> MockAccountSource {
+        let mut accounts = MockAccountSource::new();
+        accounts.expect_account()
+            .returning(move |_| async move { Ok(Some(OperationAccount::new(user_id, "USD".to_string()))) }.boxed());
+
+        accounts
+    }
+}
@@ -0,0 +1,53 @@
+use uuid::Uuid;
+use crate::features::imports::domain::column_mapping::ColumnMapping;
+use crate::support::query_bus::Query;
+
+const NAME: &str = "preview_statement";
+
+#[derive(Debug, Clone)]
+pub struct PreviewStatementQuery {
+    user_id: Uuid,
+    account_id: Uuid,
+    format: String,
+    content: Vec<u8>,
+    mapping: Option<ColumnMapping>,
+}
+
+impl PreviewStatementQuery {
+    pub fn new(user_id: Uuid, account_id: Uuid, format: String, content: Vec<u8>, mapping: Option<ColumnMapping>) -> Self {
+        Self {
+            user_id,
+            account_id,
+            format,
+            content,
+            mapping,
+        }
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    pub fn account_id(&self) -> &Uuid {
+        &self.account_id
+    }
+
+    pub fn format(&self) -> &str {
+        &self.format
+    }
+
+    pub fn content(&self) -> &[u8] {
+        &self.content
+    }
+
+    /// Columns of a CSV statement, the default mapping when omitted.
+    pub fn mapping(&self) -> &Option<ColumnMapping> {
+        &self.mapping
+    }
+}
+
+impl Query for PreviewStatementQuery {
+    fn name() -> &'static str {
+        NAME
+    }
+}
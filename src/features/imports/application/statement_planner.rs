@@ -0,0 +1,60 @@
+use uuid::Uuid;
+use crate::features::imports::domain::column_mapping::ColumnMapping;
+use crate::features::imports::domain::error::DomainError;
+use crate::features::imports::domain::fingerprint_source::FingerprintSource;
+use crate::features::imports::domain::import_row::ImportRow;
+use crate::features::imports::domain::parsers::parse;
+use crate::features::imports::domain::statement_format::StatementFormat;
+use crate::features::imports::domain::statement_import::StatementImport;
+use crate::features::imports::error::ImportError;
+use crate::features::operations::domain::account_source::AccountSource;
+use crate::support::error::FeatureError;
+
+/// Reads a statement and sorts out its rows against the operations already booked to the account,
+/// the same way for a preview and for an import.
+pub struct StatementPlanner<A, F>
+    where
+        A: AccountSource + Send + Sync,
+        F: FingerprintSource + Send + Sync,
+{
+    accounts: A,
+    fingerprints: F,
+}
+
+impl<A, F> StatementPlanner<A, F>
+    where
+        A: AccountSource + Send + Sync,
+        F: FingerprintSource + Send + Sync,
+{
+    pub fn new(accounts: A, fingerprints: F) -> Self {
+        Self {
+            accounts,
+            fingerprints,
+        }
+    }
+
+    pub async fn plan(
+        &self,
+        user_id: Uuid,
+        account_id: Uuid,
+        format: StatementFormat,
+        content: &[u8],
+        mapping: &ColumnMapping,
+    ) -> Result<Vec<ImportRow>, FeatureError> {
+        let account = self.accounts.account(account_id)
+            .await?
+            .filter(|account| account.check_owner(&user_id).is_ok())
+            .ok_or(FeatureError::Import(ImportError::Domain(DomainError::AccountNotFound)))?;
+
+        let rows = parse(format, content, mapping)
+            .map_err(|e| FeatureError::Import(ImportError::Domain(e)))?;
+
+        let dates = rows.iter().filter_map(|row| row.as_ref().ok().map(|row| *row.date()));
+        let existing = match (dates.clone().min(), dates.max()) {
+            (Some(date_from), Some(date_to)) => self.fingerprints.fingerprints(account_id, date_from, date_to).await?,
+            _ => vec![],
+        };
+
+        Ok(StatementImport::plan(rows, account.currency(), &existing))
+    }
+}
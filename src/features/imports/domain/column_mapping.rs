@@ -0,0 +1,106 @@
+use serde::{Deserialize, Serialize};
+use crate::features::imports::domain::error::DomainError;
+
+/// Where the fields of an operation are found in a CSV statement. Columns are named by their header.
+/// Amounts are signed, expenses are negative. Banks that put them in two columns set `debit` and `credit` instead.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ColumnMapping {
+    date: String,
+    date_format: String,
+    amount: Option<String>,
+    debit: Option<String>,
+    credit: Option<String>,
+    label: String,
+    currency: Option<String>,
+    delimiter: char,
+    decimal_separator: char,
+}
+
+impl Default for ColumnMapping {
+    fn default() -> Self {
+        Self {
+            date: "date".to_string(),
+            date_format: "%Y-%m-%d".to_string(),
+            amount: Some("amount".to_string()),
+            debit: None,
+            credit: None,
+            label: "label".to_string(),
+            currency: None,
+            delimiter: ',',
+            decimal_separator: '.',
+        }
+    }
+}
+
+impl ColumnMapping {
+    pub fn validate(&self) -> Result<(), DomainError> {
+        if self.amount.is_none() && (self.debit.is_none() || self.credit.is_none()) {
+            return Err(
+                DomainError::InvalidMapping("Either the amount or both the debit and credit columns are required".to_string())
+            );
+        }
+
+        if !self.delimiter.is_ascii() || self.delimiter == self.decimal_separator {
+            return Err(
+                DomainError::InvalidMapping("Delimiter must be an ASCII character other than the decimal separator".to_string())
+            );
+        }
+
+        Ok(())
+    }
+
+    pub fn date(&self) -> &str {
+        &self.date
+    }
+
+    /// `chrono` format of the dates, e.g. `%d.%m.%Y`.
+    pub fn date_format(&self) -> &str {
+        &self.date_format
+    }
+
+    pub fn amount(&self) -> &Option<String> {
+        &self.amount
+    }
+
+    pub fn debit(&self) -> &Option<String> {
+        &self.debit
+    }
+
+    pub fn credit(&self) -> &Option<String> {
+        &self.credit
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Currency of each row, rows are in the currency of the account when omitted.
+    pub fn currency(&self) -> &Option<String> {
+        &self.currency
+    }
+
+    pub fn delimiter(&self) -> char {
+        self.delimiter
+    }
+
+    pub fn decimal_separator(&self) -> char {
+        self.decimal_separator
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        assert!(ColumnMapping::default().validate().is_ok());
+
+        let mapping: ColumnMapping = serde_json::from_str(r#"{"amount": null, "debit": "Debit"}"#).unwrap();
+        assert!(matches!(mapping.validate(), Err(DomainError::InvalidMapping(_))));
+
+        let mapping: ColumnMapping = serde_json::from_str(r#"{"delimiter": ",", "decimal_separator": ","}"#).unwrap();
+        assert!(matches!(mapping.validate(), Err(DomainError::InvalidMapping(_))));
+    }
+}
@@ -0,0 +1,16 @@
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone)]
+pub enum DomainError {
+    #[error("Unknown statement format {0}")]
+    UnknownFormat(String),
+
+    #[error("Invalid statement: {0}")]
+    InvalidStatement(String),
+
+    #[error("Invalid column mapping: {0}")]
+    InvalidMapping(String),
+
+    #[error("Account not found")]
+    AccountNotFound,
+}
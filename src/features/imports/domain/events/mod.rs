@@ -0,0 +1,2 @@
+pub mod statement_import_event;
+pub mod statement_imported;
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+use crate::features::imports::domain::events::statement_imported::{STATEMENT_IMPORTED_NAME, StatementImported};
+use crate::support::event_store::{decode_event, EventStoreError, NewEvent, StorableEvent, StoredEvent};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum StatementImportEvent {
+    StatementImported(StatementImported),
+}
+
+impl StatementImportEvent {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::StatementImported(event) => event.name(),
+        }
+    }
+}
+
+impl StorableEvent for StatementImportEvent {
+    fn from_stored(stored: &StoredEvent) -> Result<Self, EventStoreError> {
+        match stored.name() {
+            STATEMENT_IMPORTED_NAME => Ok(Self::StatementImported(decode_event(stored)?)),
+            name => Err(
+                EventStoreError::Serialization(format!("Unknown statement import event {}", name))
+            ),
+        }
+    }
+
+    fn to_new(&self) -> Result<NewEvent, EventStoreError> {
+        match self {
+            Self::StatementImported(event) => NewEvent::from_event(event),
+        }
+    }
+}
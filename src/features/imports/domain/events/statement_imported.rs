@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+use crate::features::imports::domain::import_row::ImportRow;
+use crate::features::imports::domain::statement_format::StatementFormat;
+use crate::support::id::Id;
+
+pub const STATEMENT_IMPORTED_NAME: &str = "statement_imported";
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StatementImported {
+    id: Id,
+    name: String,
+    payload: StatementImportedPayload,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StatementImportedPayload {
+    id: Id,
+    user_id: Id,
+    account_id: Id,
+    format: StatementFormat,
+    rows: Vec<ImportRow>,
+}
+
+impl StatementImported {
+    pub fn new(id: Id, import_id: Id, user_id: Id, account_id: Id, format: StatementFormat, rows: Vec<ImportRow>) -> Self {
+        Self {
+            id,
+            name: STATEMENT_IMPORTED_NAME.to_string(),
+            payload: StatementImportedPayload {
+                id: import_id,
+                user_id,
+                account_id,
+                format,
+                rows,
+            }
+        }
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn payload(&self) -> &StatementImportedPayload {
+        &self.payload
+    }
+}
+
+impl StatementImportedPayload {
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Id {
+        &self.user_id
+    }
+
+    pub fn account_id(&self) -> &Id {
+        &self.account_id
+    }
+
+    pub fn format(&self) -> &StatementFormat {
+        &self.format
+    }
+
+    pub fn rows(&self) -> &[ImportRow] {
+        &self.rows
+    }
+}
@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use mockall::automock;
+use uuid::Uuid;
+use crate::support::error::FeatureError;
+
+/// Operations already recorded on an account, to recognize statement rows that are imported twice.
+#[async_trait]
+#[automock]
+pub trait FingerprintSource {
+    /// Fingerprints of the operations of the account booked from `date_from` to `date_to` inclusive,
+    /// computed the way `statement_row::fingerprint` does.
+    async fn fingerprints(&self, account_id: Uuid, date_from: NaiveDate, date_to: NaiveDate) -> Result<Vec<String>, FeatureError>;
+}
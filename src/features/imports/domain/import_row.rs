@@ -0,0 +1,118 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Outcome of a statement row. Rows are `Ready` in a preview and end up `Created` or `Failed` once imported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RowStatus {
+    Ready,
+    Created,
+    Duplicate,
+    Invalid,
+    Failed,
+}
+
+/// Statement row with what its import did, or would do in a preview.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ImportRow {
+    line: usize,
+    status: RowStatus,
+    date: Option<NaiveDate>,
+    amount: Option<Decimal>,
+    currency: Option<String>,
+    label: Option<String>,
+    operation_id: Option<Uuid>,
+    error: Option<String>,
+}
+
+impl ImportRow {
+    pub fn ready(line: usize, date: NaiveDate, amount: Decimal, currency: String, label: String, operation_id: Uuid) -> Self {
+        Self {
+            line,
+            status: RowStatus::Ready,
+            date: Some(date),
+            amount: Some(amount),
+            currency: Some(currency),
+            label: Some(label),
+            operation_id: Some(operation_id),
+            error: None,
+        }
+    }
+
+    pub fn duplicate(line: usize, date: NaiveDate, amount: Decimal, currency: String, label: String) -> Self {
+        Self {
+            line,
+            status: RowStatus::Duplicate,
+            date: Some(date),
+            amount: Some(amount),
+            currency: Some(currency),
+            label: Some(label),
+            operation_id: None,
+            error: None,
+        }
+    }
+
+    pub fn invalid(line: usize, error: String) -> Self {
+        Self {
+            line,
+            status: RowStatus::Invalid,
+            date: None,
+            amount: None,
+            currency: None,
+            label: None,
+            operation_id: None,
+            error: Some(error),
+        }
+    }
+
+    pub fn created(self) -> Self {
+        Self {
+            status: RowStatus::Created,
+            ..self
+        }
+    }
+
+    pub fn failed(self, error: String) -> Self {
+        Self {
+            status: RowStatus::Failed,
+            operation_id: None,
+            error: Some(error),
+            ..self
+        }
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn status(&self) -> RowStatus {
+        self.status
+    }
+
+    pub fn date(&self) -> &Option<NaiveDate> {
+        &self.date
+    }
+
+    /// Signed amount in the currency of the row, expenses are negative.
+    pub fn amount(&self) -> Option<Decimal> {
+        self.amount
+    }
+
+    pub fn currency(&self) -> &Option<String> {
+        &self.currency
+    }
+
+    pub fn label(&self) -> &Option<String> {
+        &self.label
+    }
+
+    /// Id of the operation the row is imported as, generated ahead so that the import can be followed up.
+    pub fn operation_id(&self) -> &Option<Uuid> {
+        &self.operation_id
+    }
+
+    pub fn error(&self) -> &Option<String> {
+        &self.error
+    }
+}
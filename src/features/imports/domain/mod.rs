@@ -0,0 +1,10 @@
+pub mod column_mapping;
+pub mod error;
+pub mod events;
+pub mod fingerprint_source;
+pub mod import_row;
+pub mod parsers;
+pub mod statement_format;
+pub mod statement_import;
+pub mod statement_import_repository;
+pub mod statement_row;
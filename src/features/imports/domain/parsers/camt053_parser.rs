@@ -0,0 +1,125 @@
+use chrono::NaiveDate;
+use roxmltree::{Document, Node};
+use crate::features::imports::domain::error::DomainError;
+use crate::features::imports::domain::parsers::parse_amount;
+use crate::features::imports::domain::statement_row::{ParsedRow, RowError, StatementRow};
+
+/// Reads the entries of ISO 20022 `BkToCstmrStmt` documents. Elements are matched by their local name,
+/// so that every version of the camt.053 schema is read the same way.
+pub fn parse(content: &[u8]) -> Result<Vec<ParsedRow>, DomainError> {
+    let content = std::str::from_utf8(content)
+        .map_err(|e| DomainError::InvalidStatement(e.to_string()))?;
+    let document = Document::parse(content)
+        .map_err(|e| DomainError::InvalidStatement(e.to_string()))?;
+
+    if !document.descendants().any(|node| is(&node, "BkToCstmrStmt")) {
+        return Err(DomainError::InvalidStatement("BkToCstmrStmt element not found".to_string()));
+    }
+
+    Ok(
+        document.descendants()
+            .filter(|node| is(node, "Ntry"))
+            .map(|entry| {
+                let line = document.text_pos_at(entry.range().start).row as usize;
+
+                parse_entry(line, &entry)
+            })
+            .collect()
+    )
+}
+
+fn parse_entry(line: usize, entry: &Node) -> ParsedRow {
+    // Pending entries may still change, only booked ones are imported
+    let status = child(entry, "Sts")
+        .map(|status| child(&status, "Cd").map_or_else(|| text(&status), |code| text(&code)))
+        .unwrap_or_default();
+    if status != "BOOK" {
+        return Err(RowError::new(line, format!("Entry is not booked, its status is {}", status)));
+    }
+
+    let amount_node = child(entry, "Amt")
+        .ok_or_else(|| RowError::new(line, "Amount not found".to_string()))?;
+    let amount = parse_amount(&text(&amount_node), '.')
+        .ok_or_else(|| RowError::new(line, format!("Invalid amount {}", text(&amount_node))))?;
+    let amount = match child(entry, "CdtDbtInd").map(|indicator| text(&indicator)).as_deref() {
+        Some("CRDT") => amount,
+        Some("DBIT") => -amount,
+        indicator => return Err(RowError::new(line, format!("Invalid credit/debit indicator {:?}", indicator))),
+    };
+    let currency = amount_node.attribute("Ccy").map(|currency| currency.to_uppercase());
+
+    // Dates are either plain dates or date-times, both start with YYYY-MM-DD
+    let date = ["BookgDt", "ValDt"].iter()
+        .filter_map(|name| child(entry, name))
+        .filter_map(|date| date.children().find(|node| is(node, "Dt") || is(node, "DtTm")))
+        .find_map(|date| text(&date).get(..10).and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()))
+        .ok_or_else(|| RowError::new(line, "Booking date not found".to_string()))?;
+
+    let label = ["Ustrd", "AddtlTxInf", "AddtlNtryInf", "Nm"].iter()
+        .filter_map(|name| entry.descendants().find(|node| is(node, name)))
+        .map(|node| text(&node))
+        .find(|label| !label.is_empty())
+        .unwrap_or_default();
+
+    Ok(StatementRow::new(line, date, amount, currency, label))
+}
+
+fn is(node: &Node, name: &str) -> bool {
+    node.is_element() && node.tag_name().name() == name
+}
+
+fn child<'a, 'input>(node: &Node<'a, 'input>, name: &str) -> Option<Node<'a, 'input>> {
+    node.children().find(|child| is(child, name))
+}
+
+fn text(node: &Node) -> String {
+    node.text().unwrap_or_default().trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let content = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Document xmlns="urn:iso:std:iso:20022:tech:xsd:camt.053.001.08">
+  <BkToCstmrStmt>
+    <Stmt>
+      <Ntry>
+        <Amt Ccy="EUR">42.10</Amt>
+        <CdtDbtInd>DBIT</CdtDbtInd>
+        <Sts><Cd>BOOK</Cd></Sts>
+        <BookgDt><DtTm>2024-04-02T10:00:00</DtTm></BookgDt>
+        <NtryDtls><TxDtls><RmtInf><Ustrd>Groceries</Ustrd></RmtInf></TxDtls></NtryDtls>
+      </Ntry>
+      <Ntry>
+        <Amt Ccy="EUR">5.00</Amt>
+        <CdtDbtInd>CRDT</CdtDbtInd>
+        <Sts>PDNG</Sts>
+        <BookgDt><Dt>2024-04-03</Dt></BookgDt>
+      </Ntry>
+    </Stmt>
+  </BkToCstmrStmt>
+</Document>"#;
+
+        let rows = parse(content.as_bytes()).unwrap();
+
+        assert_eq!(rows.len(), 2);
+
+        let row = rows[0].as_ref().unwrap();
+        assert_eq!(row.line(), 5);
+        assert_eq!(row.date(), &NaiveDate::from_ymd_opt(2024, 4, 2).unwrap());
+        assert_eq!(row.amount(), Decimal::new(-4210, 2));
+        assert_eq!(row.currency(), &Some("EUR".to_string()));
+        assert_eq!(row.label(), "Groceries");
+
+        assert_eq!(rows[1].as_ref().unwrap_err().line(), 12);
+    }
+
+    #[test]
+    fn test_parse_not_camt() {
+        assert!(matches!(parse(b"<OFX></OFX>"), Err(DomainError::InvalidStatement(_))));
+    }
+}
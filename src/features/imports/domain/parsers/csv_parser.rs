@@ -0,0 +1,134 @@
+use chrono::NaiveDate;
+use csv::{ReaderBuilder, StringRecord, Trim};
+use rust_decimal::Decimal;
+use crate::features::imports::domain::column_mapping::ColumnMapping;
+use crate::features::imports::domain::error::DomainError;
+use crate::features::imports::domain::parsers::parse_amount;
+use crate::features::imports::domain::statement_row::{ParsedRow, RowError, StatementRow};
+
+/// Columns of the mapping found in the header of the statement.
+struct Columns {
+    date: usize,
+    amount: Option<usize>,
+    debit: Option<usize>,
+    credit: Option<usize>,
+    label: usize,
+    currency: Option<usize>,
+}
+
+pub fn parse(content: &[u8], mapping: &ColumnMapping) -> Result<Vec<ParsedRow>, DomainError> {
+    mapping.validate()?;
+
+    let mut reader = ReaderBuilder::new()
+        .delimiter(mapping.delimiter() as u8)
+        .trim(Trim::All)
+        .flexible(true)
+        .from_reader(content);
+
+    let headers = reader.headers()
+        .map_err(|e| DomainError::InvalidStatement(e.to_string()))?
+        .clone();
+
+    let column = |name: &str| headers.iter()
+        .position(|header| header == name)
+        .ok_or_else(|| DomainError::InvalidMapping(format!("Column {} not found", name)));
+
+    let columns = Columns {
+        date: column(mapping.date())?,
+        amount: mapping.amount().as_deref().map(column).transpose()?,
+        debit: mapping.debit().as_deref().map(column).transpose()?,
+        credit: mapping.credit().as_deref().map(column).transpose()?,
+        label: column(mapping.label())?,
+        currency: mapping.currency().as_deref().map(column).transpose()?,
+    };
+
+    let mut rows = vec![];
+    // The header takes the first line
+    let mut line = 1;
+
+    for record in reader.records() {
+        // A quoted field may span several lines, so the line is taken from where the record starts
+        let position = match &record {
+            Ok(record) => record.position(),
+            Err(e) => e.position(),
+        };
+        line = position.map_or(line + 1, |position| position.line() as usize);
+
+        rows.push(
+            record
+                .map_err(|e| RowError::new(line, e.to_string()))
+                .and_then(|record| parse_record(line, &record, &columns, mapping))
+        );
+    }
+
+    Ok(rows)
+}
+
+fn parse_record(line: usize, record: &StringRecord, columns: &Columns, mapping: &ColumnMapping) -> ParsedRow {
+    let field = |index: usize| record.get(index).unwrap_or("");
+    let amount = |index: usize| parse_amount(field(index), mapping.decimal_separator())
+        .ok_or_else(|| RowError::new(line, format!("Invalid amount {}", field(index))));
+    // Banks leave the debit or the credit column of a row empty
+    let optional_amount = |index: Option<usize>| match index {
+        Some(index) if !field(index).is_empty() => amount(index).map(|amount| amount.abs()),
+        _ => Ok(Decimal::ZERO),
+    };
+
+    let date = NaiveDate::parse_from_str(field(columns.date), mapping.date_format())
+        .map_err(|_| RowError::new(line, format!("Invalid date {}", field(columns.date))))?;
+
+    let amount = match columns.amount {
+        Some(index) => amount(index)?,
+        None => optional_amount(columns.credit)? - optional_amount(columns.debit)?,
+    };
+
+    let currency = columns.currency
+        .map(|index| field(index).to_uppercase())
+        .filter(|currency| !currency.is_empty());
+
+    Ok(StatementRow::new(line, date, amount, currency, field(columns.label).to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let content = "Date;Debit;Credit;Details\n01.04.2024;12,50;;Coffee\n02.04.2024;;1 000,00;Salary\n03.04.2024;x;;Broken\n";
+        let mapping: ColumnMapping = serde_json::from_str(r#"{
+            "date": "Date", "date_format": "%d.%m.%Y", "amount": null, "debit": "Debit", "credit": "Credit",
+            "label": "Details", "delimiter": ";", "decimal_separator": ","
+        }"#).unwrap();
+
+        let rows = parse(content.as_bytes(), &mapping).unwrap();
+
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0].as_ref().unwrap().amount(), Decimal::new(-1250, 2));
+        assert_eq!(rows[1].as_ref().unwrap().amount(), Decimal::from(1000));
+        assert_eq!(rows[1].as_ref().unwrap().label(), "Salary");
+        assert_eq!(rows[2].as_ref().unwrap_err().line(), 4);
+    }
+
+    #[test]
+    fn test_parse_multiline_field() {
+        let content = "Date,Amount,Details\n2024-04-01,-12.50,\"Coffee\nand cake\"\n2024-04-02,x,Broken\n";
+        let mapping: ColumnMapping = serde_json::from_str(r#"{
+            "date": "Date", "date_format": "%Y-%m-%d", "amount": "Amount", "debit": null, "credit": null,
+            "label": "Details", "delimiter": ",", "decimal_separator": "."
+        }"#).unwrap();
+
+        let rows = parse(content.as_bytes(), &mapping).unwrap();
+
+        assert_eq!(rows[0].as_ref().unwrap().line(), 2);
+        assert_eq!(rows[0].as_ref().unwrap().label(), "Coffee\nand cake");
+        assert_eq!(rows[1].as_ref().unwrap_err().line(), 4);
+    }
+
+    #[test]
+    fn test_parse_missing_column() {
+        let result = parse("when,amount,label\n".as_bytes(), &ColumnMapping::default());
+
+        assert!(matches!(result, Err(DomainError::InvalidMapping(_))));
+    }
+}
@@ -0,0 +1,48 @@
+use std::str::FromStr;
+use rust_decimal::Decimal;
+use crate::features::imports::domain::column_mapping::ColumnMapping;
+use crate::features::imports::domain::error::DomainError;
+use crate::features::imports::domain::statement_format::StatementFormat;
+use crate::features::imports::domain::statement_row::ParsedRow;
+
+pub mod camt053_parser;
+pub mod csv_parser;
+pub mod ofx_parser;
+
+const BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// Reads the rows of a statement. A file that is not a statement of the format fails as a whole,
+/// a row that cannot be read is reported on its own so that the rest of the statement can still be imported.
+/// The column mapping only applies to CSV statements.
+pub fn parse(format: StatementFormat, content: &[u8], mapping: &ColumnMapping) -> Result<Vec<ParsedRow>, DomainError> {
+    let content = content.strip_prefix(BOM).unwrap_or(content);
+
+    match format {
+        StatementFormat::Csv => csv_parser::parse(content, mapping),
+        StatementFormat::Ofx => ofx_parser::parse(content),
+        StatementFormat::Camt053 => camt053_parser::parse(content),
+    }
+}
+
+/// Reads an amount written with `decimal_separator`, ignoring spaces and the other separator used to group thousands.
+pub fn parse_amount(value: &str, decimal_separator: char) -> Option<Decimal> {
+    let value: String = value.chars()
+        .filter(|c| !c.is_whitespace() && *c != if decimal_separator == ',' { '.' } else { ',' })
+        .map(|c| if c == decimal_separator { '.' } else { c })
+        .collect();
+
+    Decimal::from_str(&value).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_amount() {
+        assert_eq!(parse_amount("-1,234.50", '.'), Some(Decimal::new(-123450, 2)));
+        assert_eq!(parse_amount("1.234,50", ','), Some(Decimal::new(123450, 2)));
+        assert_eq!(parse_amount("12 000", '.'), Some(Decimal::from(12000)));
+        assert_eq!(parse_amount("n/a", '.'), None);
+    }
+}
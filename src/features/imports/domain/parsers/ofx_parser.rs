@@ -0,0 +1,106 @@
+use chrono::NaiveDate;
+use crate::features::imports::domain::error::DomainError;
+use crate::features::imports::domain::parsers::parse_amount;
+use crate::features::imports::domain::statement_row::{ParsedRow, RowError, StatementRow};
+
+const TRANSACTION_START: &str = "<STMTTRN>";
+const TRANSACTION_END: &str = "</STMTTRN>";
+
+/// Reads both SGML (OFX 1.x) and XML (OFX 2.x) statements. Elements of SGML statements are not closed,
+/// so the value of an element runs up to the next tag.
+pub fn parse(content: &[u8]) -> Result<Vec<ParsedRow>, DomainError> {
+    let content = String::from_utf8_lossy(content);
+
+    if !content.to_uppercase().contains("<OFX>") {
+        return Err(DomainError::InvalidStatement("OFX root element not found".to_string()));
+    }
+
+    let currency = value(&content, "CURDEF").map(|currency| currency.to_uppercase());
+
+    let mut rows = vec![];
+    let mut rest = content.as_ref();
+    let mut offset = 0;
+    // Lines are counted from the previous transaction on, so the statement is scanned once
+    let mut line = 1;
+    let mut counted = 0;
+
+    while let Some(start) = rest.find(TRANSACTION_START) {
+        let end = rest[start..].find(TRANSACTION_END).map_or(rest.len(), |end| start + end);
+        line += content[counted..offset + start].matches('\n').count();
+        counted = offset + start;
+
+        rows.push(parse_transaction(line, &rest[start..end], &currency));
+
+        offset += end;
+        rest = &rest[end..];
+    }
+
+    Ok(rows)
+}
+
+fn parse_transaction(line: usize, transaction: &str, currency: &Option<String>) -> ParsedRow {
+    // Dates are written as YYYYMMDD, optionally followed by a time and a time zone
+    let posted = value(transaction, "DTPOSTED").unwrap_or_default();
+    let date = posted.get(..8)
+        .and_then(|date| NaiveDate::parse_from_str(date, "%Y%m%d").ok())
+        .ok_or_else(|| RowError::new(line, format!("Invalid date {}", posted)))?;
+
+    let amount = value(transaction, "TRNAMT").unwrap_or_default();
+    let amount = parse_amount(&amount.replace(',', "."), '.')
+        .ok_or_else(|| RowError::new(line, format!("Invalid amount {}", amount)))?;
+
+    let label = value(transaction, "NAME")
+        .filter(|name| !name.is_empty())
+        .or_else(|| value(transaction, "MEMO"))
+        .unwrap_or_default();
+
+    Ok(StatementRow::new(line, date, amount, currency.clone(), unescape(&label)))
+}
+
+fn value(content: &str, tag: &str) -> Option<String> {
+    let open = format!("<{}>", tag);
+    let start = content.find(&open)? + open.len();
+    let end = content[start..].find('<').map_or(content.len(), |end| start + end);
+
+    Some(content[start..end].trim().to_string())
+}
+
+fn unescape(value: &str) -> String {
+    value.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+    use super::*;
+
+    #[test]
+    fn test_parse_sgml() {
+        let content = "OFXHEADER:100\nDATA:OFXSGML\n\n<OFX>\n<BANKMSGSRSV1><STMTTRNRS><STMTRS>\n<CURDEF>usd\n<BANKTRANLIST>\n\
+            <STMTTRN>\n<TRNTYPE>DEBIT\n<DTPOSTED>20240401120000[-5:EST]\n<TRNAMT>-12.50\n<FITID>1\n<NAME>Coffee &amp; Co\n</STMTTRN>\n\
+            <STMTTRN>\n<TRNTYPE>CREDIT\n<DTPOSTED>2024-04\n<TRNAMT>100\n<FITID>2\n<MEMO>Refund\n</STMTTRN>\n\
+            </BANKTRANLIST></STMTRS></STMTTRNRS></BANKMSGSRSV1>\n</OFX>\n";
+
+        let rows = parse(content.as_bytes()).unwrap();
+
+        assert_eq!(rows.len(), 2);
+
+        let row = rows[0].as_ref().unwrap();
+        assert_eq!(row.line(), 8);
+        assert_eq!(row.date(), &NaiveDate::from_ymd_opt(2024, 4, 1).unwrap());
+        assert_eq!(row.amount(), Decimal::new(-1250, 2));
+        assert_eq!(row.currency(), &Some("USD".to_string()));
+        assert_eq!(row.label(), "Coffee & Co");
+
+        assert_eq!(rows[1].as_ref().unwrap_err().line(), 15);
+    }
+
+    #[test]
+    fn test_parse_not_ofx() {
+        assert!(matches!(parse(b"date,amount\n"), Err(DomainError::InvalidStatement(_))));
+    }
+}
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+use crate::features::imports::domain::error::DomainError;
+
+/// File formats statements are imported from. QFX files are OFX files, they are imported as such.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StatementFormat {
+    Csv,
+    Ofx,
+    Camt053,
+}
+
+impl StatementFormat {
+    pub fn new(value: &str) -> Result<Self, DomainError> {
+        match value {
+            "Csv" => Ok(Self::Csv),
+            "Ofx" | "Qfx" => Ok(Self::Ofx),
+            "Camt053" => Ok(Self::Camt053),
+            _ => Err(DomainError::UnknownFormat(value.to_string())),
+        }
+    }
+
+    pub fn to_str(&self) -> &str {
+        match self {
+            Self::Csv => "Csv",
+            Self::Ofx => "Ofx",
+            Self::Camt053 => "Camt053",
+        }
+    }
+}
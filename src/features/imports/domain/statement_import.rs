@@ -0,0 +1,150 @@
+use std::collections::HashMap;
+use uuid::Uuid;
+use crate::features::imports::domain::events::statement_import_event::StatementImportEvent;
+use crate::features::imports::domain::events::statement_imported::StatementImported;
+use crate::features::imports::domain::import_row::ImportRow;
+use crate::features::imports::domain::statement_format::StatementFormat;
+use crate::features::imports::domain::statement_row::ParsedRow;
+use crate::support::event_store::{Aggregate, EventStoreError};
+use crate::support::id::Id;
+
+/// Statement imported into an account, with the outcome of each of its rows.
+#[derive(Debug, Clone)]
+pub struct StatementImport {
+    id: Id,
+    user_id: Id,
+    account_id: Id,
+    format: StatementFormat,
+    rows: Vec<ImportRow>,
+}
+
+impl StatementImport {
+    /// Sorts out the rows of a statement booked to an account in `currency`. Rows that could not be read or are
+    /// in another currency are invalid. A row is a duplicate while the account holds more operations with its
+    /// fingerprint than the rows met so far, so that identical purchases of a day are all imported once.
+    pub fn plan(rows: Vec<ParsedRow>, currency: &str, existing: &[String]) -> Vec<ImportRow> {
+        let mut recorded: HashMap<&str, usize> = HashMap::new();
+        for fingerprint in existing {
+            *recorded.entry(fingerprint.as_str()).or_default() += 1;
+        }
+
+        rows.into_iter()
+            .map(|row| {
+                let row = match row {
+                    Ok(row) => row,
+                    Err(e) => return ImportRow::invalid(e.line(), e.message().to_string()),
+                };
+
+                if let Some(row_currency) = row.currency().as_deref().filter(|row_currency| *row_currency != currency) {
+                    return ImportRow::invalid(
+                        row.line(),
+                        format!("Row currency {} differs from account currency {}", row_currency, currency),
+                    );
+                }
+
+                if row.amount().is_zero() {
+                    return ImportRow::invalid(row.line(), "Amount must not be zero".to_string());
+                }
+
+                let fingerprint = row.fingerprint();
+                match recorded.get_mut(fingerprint.as_str()) {
+                    Some(count) if *count > 0 => {
+                        *count -= 1;
+                        ImportRow::duplicate(row.line(), *row.date(), row.amount(), currency.to_string(), row.label().to_string())
+                    }
+                    _ => ImportRow::ready(row.line(), *row.date(), row.amount(), currency.to_string(), row.label().to_string(), Id::generate()),
+                }
+            })
+            .collect()
+    }
+
+    pub fn handle_import(import_id: Uuid, user_id: Uuid, account_id: Uuid, format: StatementFormat, rows: Vec<ImportRow>) -> StatementImportEvent {
+        StatementImportEvent::StatementImported(
+            StatementImported::new(
+                Id::new(Id::generate()),
+                Id::new(import_id),
+                Id::new(user_id),
+                Id::new(account_id),
+                format,
+                rows,
+            )
+        )
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Id {
+        &self.user_id
+    }
+
+    pub fn account_id(&self) -> &Id {
+        &self.account_id
+    }
+
+    pub fn format(&self) -> &StatementFormat {
+        &self.format
+    }
+
+    pub fn rows(&self) -> &[ImportRow] {
+        &self.rows
+    }
+}
+
+impl Aggregate for StatementImport {
+    type Event = StatementImportEvent;
+
+    fn apply(state: Option<Self>, event: &StatementImportEvent) -> Result<Self, EventStoreError> {
+        match (state, event) {
+            (None, StatementImportEvent::StatementImported(imported)) => {
+                let payload = imported.payload();
+
+                Ok(
+                    Self {
+                        id: payload.id().clone(),
+                        user_id: payload.user_id().clone(),
+                        account_id: payload.account_id().clone(),
+                        format: *payload.format(),
+                        rows: payload.rows().to_vec(),
+                    }
+                )
+            }
+            (Some(statement_import), StatementImportEvent::StatementImported(_)) => Err(
+                EventStoreError::Rehydration(
+                    format!("Statement import {} is already recorded", statement_import.id().value())
+                )
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use rust_decimal::Decimal;
+    use crate::features::imports::domain::import_row::RowStatus;
+    use crate::features::imports::domain::statement_row::{RowError, StatementRow};
+    use super::*;
+
+    #[test]
+    fn test_plan() {
+        let date = NaiveDate::from_ymd_opt(2024, 4, 1).unwrap();
+        let coffee = StatementRow::new(2, date, Decimal::new(-350, 2), None, "Coffee".to_string());
+        let rows = vec![
+            Ok(coffee.clone()),
+            Ok(StatementRow::new(3, date, Decimal::new(-350, 2), None, "Coffee".to_string())),
+            Ok(StatementRow::new(4, date, Decimal::from(10), Some("EUR".to_string()), "Refund".to_string())),
+            Err(RowError::new(5, "Invalid date".to_string())),
+        ];
+
+        let planned = StatementImport::plan(rows, "USD", &[coffee.fingerprint()]);
+
+        assert_eq!(planned[0].status(), RowStatus::Duplicate);
+        assert_eq!(planned[1].status(), RowStatus::Ready);
+        assert!(planned[1].operation_id().is_some());
+        assert_eq!(planned[2].status(), RowStatus::Invalid);
+        assert_eq!(planned[3].status(), RowStatus::Invalid);
+        assert_eq!(planned[3].line(), 5);
+    }
+}
@@ -0,0 +1,49 @@
+use std::collections::HashMap;
+use async_trait::async_trait;
+use uuid::Uuid;
+use crate::features::imports::domain::events::statement_import_event::StatementImportEvent;
+use crate::features::imports::domain::statement_import::StatementImport;
+use crate::features::imports::error::ImportError;
+use crate::features::imports::infrastructure::error::InfrastructureError;
+use crate::support::event_store::Versioned;
+
+#[async_trait]
+pub trait StatementImportRepository {
+    async fn load(&self, import_id: Uuid) -> Result<Option<Versioned<StatementImport>>, ImportError>;
+
+    /// Fails with `InfrastructureError::Conflict` when the stream is no longer at `expected_version`.
+    async fn append(&self, import_id: Uuid, expected_version: i32, events: &[StatementImportEvent]) -> Result<i32, ImportError>;
+}
+
+pub struct MockStatementImportRepository {
+    has_error: bool,
+    imports: HashMap<Uuid, Versioned<StatementImport>>,
+}
+
+impl MockStatementImportRepository {
+    pub fn new(has_error: bool) -> Self {
+        Self {
+            has_error,
+            imports: HashMap::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl StatementImportRepository for MockStatementImportRepository {
+    async fn load(&self, import_id: Uuid) -> Result<Option<Versioned<StatementImport>>, ImportError> {
+        Ok(self.imports.get(&import_id).cloned())
+    }
+
+    async fn append(&self, _import_id: Uuid, expected_version: i32, events: &[StatementImportEvent]) -> Result<i32, ImportError> {
+        if self.has_error {
+            return Err(
+                ImportError::Infrastructure(
+                    InfrastructureError::Repository("Mock repository error".into())
+                )
+            );
+        }
+
+        Ok(expected_version + events.len() as i32)
+    }
+}
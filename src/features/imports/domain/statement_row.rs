@@ -0,0 +1,106 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Transaction read from a statement. `line` is the position of the row in the file, counted from 1.
+/// Amounts are signed, expenses are negative.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StatementRow {
+    line: usize,
+    date: NaiveDate,
+    amount: Decimal,
+    currency: Option<String>,
+    label: String,
+}
+
+impl StatementRow {
+    pub fn new(line: usize, date: NaiveDate, amount: Decimal, currency: Option<String>, label: String) -> Self {
+        Self {
+            line,
+            date,
+            amount,
+            currency,
+            label: label.trim().to_string(),
+        }
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn date(&self) -> &NaiveDate {
+        &self.date
+    }
+
+    pub fn amount(&self) -> Decimal {
+        self.amount
+    }
+
+    pub fn currency(&self) -> &Option<String> {
+        &self.currency
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub fn fingerprint(&self) -> String {
+        fingerprint(&self.date, self.amount, &self.label)
+    }
+}
+
+/// Row of a statement that could not be read.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RowError {
+    line: usize,
+    message: String,
+}
+
+impl RowError {
+    pub fn new(line: usize, message: String) -> Self {
+        Self {
+            line,
+            message,
+        }
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+pub type ParsedRow = Result<StatementRow, RowError>;
+
+/// Identifies a transaction by its date, signed amount and label, so that it is recognized when imported again
+/// or when it was already entered by hand. Labels are compared case-insensitively, ignoring runs of whitespace.
+pub fn fingerprint(date: &NaiveDate, amount: Decimal, label: &str) -> String {
+    let label = label.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase();
+
+    format!("{}|{}|{}", date, amount.normalize(), label)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fingerprint() {
+        let date = NaiveDate::from_ymd_opt(2024, 4, 1).unwrap();
+
+        assert_eq!(
+            fingerprint(&date, Decimal::new(-1250, 2), "Coffee  Shop "),
+            fingerprint(&date, Decimal::new(-125, 1), "coffee shop"),
+        );
+        assert_ne!(
+            fingerprint(&date, Decimal::new(-1250, 2), "Coffee shop"),
+            fingerprint(&date, Decimal::new(1250, 2), "Coffee shop"),
+        );
+    }
+}
@@ -0,0 +1,12 @@
+use thiserror::Error;
+use crate::features::imports::domain::error::DomainError;
+use crate::features::imports::infrastructure::error::InfrastructureError;
+
+#[derive(Error, Debug, Clone)]
+pub enum ImportError {
+    #[error("Import domain error. {0}")]
+    Domain(DomainError),
+
+    #[error("Import infrastructure error. {0}")]
+    Infrastructure(InfrastructureError),
+}
@@ -0,0 +1,78 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Days, NaiveDate, TimeZone, Utc};
+use rust_decimal::Decimal;
+use sqlx::query_as;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+use crate::db::manager::DbManager;
+use crate::features::imports::domain::fingerprint_source::FingerprintSource;
+use crate::features::imports::domain::statement_row::fingerprint;
+use crate::features::imports::error::ImportError;
+use crate::features::imports::infrastructure::error::InfrastructureError;
+use crate::features::operations::domain::kind::Kind;
+use crate::support::error::FeatureError;
+
+/// Takes the fingerprints from the operations projection. Transfers received by the account count
+/// as money coming in, the way a bank statement shows them.
+pub struct DbFingerprintSource {
+    db_manager: Arc<Mutex<DbManager>>,
+}
+
+impl DbFingerprintSource {
+    pub fn new(db_manager: Arc<Mutex<DbManager>>) -> Self {
+        Self {
+            db_manager,
+        }
+    }
+
+    fn error(message: String) -> FeatureError {
+        FeatureError::Import(
+            ImportError::Infrastructure(
+                InfrastructureError::Repository(message)
+            )
+        )
+    }
+}
+
+#[async_trait]
+impl FingerprintSource for DbFingerprintSource {
+    async fn fingerprints(&self, account_id: Uuid, date_from: NaiveDate, date_to: NaiveDate) -> Result<Vec<String>, FeatureError> {
+        let q = "
+            SELECT kind, currency_amount AS amount, label, created_at, FALSE AS incoming
+            FROM operations
+            WHERE account_id = $1 AND created_at >= $2 AND created_at < $3
+            UNION ALL
+            SELECT kind, transfer_amount AS amount, label, created_at, TRUE AS incoming
+            FROM operations
+            WHERE transfer_account_id = $1 AND transfer_amount IS NOT NULL AND created_at >= $2 AND created_at < $3
+        ";
+
+        let pool = self.db_manager.lock().await
+            .pool()
+            .map_err(|e| Self::error(format!("Failed to get pool: {}", e)))?;
+
+        let date_from = Utc.from_utc_datetime(&date_from.and_time(Default::default()));
+        let date_to = Utc.from_utc_datetime(&(date_to + Days::new(1)).and_time(Default::default()));
+
+        let operations = query_as::<_, (String, Decimal, String, DateTime<Utc>, bool)>(q)
+            .bind(account_id)
+            .bind(date_from)
+            .bind(date_to)
+            .fetch_all(&pool)
+            .await
+            .map_err(|e| Self::error(format!("Failed to fetch operations: {}", e)))?;
+
+        operations.into_iter()
+            .map(|(kind, amount, label, created_at, incoming)| {
+                let sign = if incoming {
+                    Decimal::ONE
+                } else {
+                    Kind::new(&kind).map_err(|e| Self::error(e.to_string()))?.sign()
+                };
+
+                Ok(fingerprint(&created_at.date_naive(), sign * amount, &label))
+            })
+            .collect()
+    }
+}
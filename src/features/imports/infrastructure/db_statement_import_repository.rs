@@ -0,0 +1,64 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+use crate::db::manager::DbManager;
+use crate::features::imports::domain::statement_import::StatementImport;
+use crate::features::imports::domain::statement_import_repository::StatementImportRepository;
+use crate::features::imports::domain::events::statement_import_event::StatementImportEvent;
+use crate::features::imports::error::ImportError;
+use crate::features::imports::infrastructure::error::InfrastructureError;
+use crate::support::event_store::{Aggregate, EventStore, StorableEvent, Versioned};
+use crate::support::pg_event_store::PgEventStore;
+
+const EVENTS_TABLE: &str = "statement_import_events";
+
+#[derive(Clone)]
+pub struct DbStatementImportRepository {
+    event_store: PgEventStore,
+}
+
+impl DbStatementImportRepository {
+    pub fn new(db_manager: Arc<Mutex<DbManager>>) -> Self {
+        Self {
+            event_store: PgEventStore::new(db_manager, EVENTS_TABLE),
+        }
+    }
+}
+
+#[async_trait]
+impl StatementImportRepository for DbStatementImportRepository {
+    async fn load(&self, import_id: Uuid) -> Result<Option<Versioned<StatementImport>>, ImportError> {
+        let stream = self.event_store.load(import_id)
+            .await
+            .map_err(|e|
+                ImportError::Infrastructure(
+                    InfrastructureError::Repository(e.to_string())
+                )
+            )?;
+
+        StatementImport::rehydrate(&stream)
+            .map_err(|e|
+                ImportError::Infrastructure(
+                    InfrastructureError::Repository(e.to_string())
+                )
+            )
+    }
+
+    async fn append(&self, import_id: Uuid, expected_version: i32, events: &[StatementImportEvent]) -> Result<i32, ImportError> {
+        let events = events.iter()
+            .map(|event| event.to_new())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e|
+                ImportError::Infrastructure(
+                    InfrastructureError::Repository(e.to_string())
+                )
+            )?;
+
+        self.event_store.append(import_id, expected_version, &events)
+            .await
+            .map_err(|e|
+                ImportError::Infrastructure(e.into())
+            )
+    }
+}
@@ -0,0 +1,20 @@
+use thiserror::Error;
+use crate::support::event_store::EventStoreError;
+
+#[derive(Error, Debug, Clone)]
+pub enum InfrastructureError {
+    #[error("Import repository error. {0}")]
+    Repository(String),
+
+    #[error("Import conflict error. {0}")]
+    Conflict(String),
+}
+
+impl From<EventStoreError> for InfrastructureError {
+    fn from(e: EventStoreError) -> Self {
+        match e {
+            EventStoreError::Concurrency { .. } => Self::Conflict(e.to_string()),
+            _ => Self::Repository(e.to_string()),
+        }
+    }
+}
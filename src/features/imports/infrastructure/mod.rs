@@ -0,0 +1,3 @@
+pub mod db_fingerprint_source;
+pub mod db_statement_import_repository;
+pub mod error;
@@ -0,0 +1,82 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use crate::support::command_bus::Command;
+
+const NAME : &str = "create_loan";
+
+#[derive(Debug, Clone)]
+pub struct CreateLoanCommand {
+    user_id: Uuid,
+    direction: String,
+    counterparty: String,
+    principal: Decimal,
+    currency: String,
+    interest_rate: Decimal,
+    issued_on: NaiveDate,
+    term_months: u32,
+}
+
+impl CreateLoanCommand {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        user_id: Uuid,
+        direction: String,
+        counterparty: String,
+        principal: Decimal,
+        currency: String,
+        interest_rate: Decimal,
+        issued_on: NaiveDate,
+        term_months: u32,
+    ) -> Self {
+        Self {
+            user_id,
+            direction,
+            counterparty,
+            principal,
+            currency,
+            interest_rate,
+            issued_on,
+            term_months,
+        }
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    pub fn direction(&self) -> &str {
+        &self.direction
+    }
+
+    pub fn counterparty(&self) -> &str {
+        &self.counterparty
+    }
+
+    pub fn principal(&self) -> Decimal {
+        self.principal
+    }
+
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    /// Annual interest rate in percent.
+    pub fn interest_rate(&self) -> Decimal {
+        self.interest_rate
+    }
+
+    pub fn issued_on(&self) -> &NaiveDate {
+        &self.issued_on
+    }
+
+    pub fn term_months(&self) -> u32 {
+        self.term_months
+    }
+}
+
+impl Command for CreateLoanCommand {
+    fn name() -> &'static str {
+        NAME
+    }
+}
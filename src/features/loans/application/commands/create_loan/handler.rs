@@ -0,0 +1,102 @@
+use async_trait::async_trait;
+use crate::events::event::Event;
+use crate::features::loans::application::commands::create_loan::command::CreateLoanCommand;
+use crate::features::loans::domain::events::loan_event::LoanEvent;
+use crate::features::loans::domain::loan::Loan;
+use crate::features::loans::domain::loan_repository::LoanRepository;
+use crate::features::loans::error::LoanError;
+use crate::support::command_bus::CommandHandler;
+use crate::support::error::FeatureError;
+
+pub struct CreateLoanCommandHandler<R>
+    where
+        R: LoanRepository + Send + Sync,
+{
+    loan_repository: R,
+}
+
+impl<R> CreateLoanCommandHandler<R>
+    where
+        R: LoanRepository + Send + Sync,
+{
+    pub fn new(loan_repository: R) -> Self {
+        Self {
+            loan_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> CommandHandler<CreateLoanCommand> for CreateLoanCommandHandler<R>
+    where
+        R: LoanRepository + Send + Sync,
+{
+    async fn handle(&mut self, command: CreateLoanCommand) -> Result<Vec<Event>, FeatureError> {
+        let event = Loan::handle_creation(command)
+            .map_err(|e|
+                FeatureError::Loan(
+                    LoanError::Domain(e)
+                )
+            )?;
+
+        if let LoanEvent::LoanCreated(loan_created) = &event {
+            self.loan_repository.append(loan_created.payload().id().value(), 0, std::slice::from_ref(&event))
+                .await
+                .map_err(FeatureError::Loan)?;
+        }
+
+        Ok(
+            vec![Event::LoanEvent(event)]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use rust_decimal::Decimal;
+    use uuid::Uuid;
+    use crate::features::loans::domain::error::DomainError;
+    use crate::features::loans::domain::loan_repository::MockLoanRepository;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle_success() {
+        let mut handler = CreateLoanCommandHandler::new(MockLoanRepository::new(false));
+
+        let events = handler.handle(command_fixture("USD")).await.unwrap();
+
+        assert!(matches!(events[0], Event::LoanEvent(LoanEvent::LoanCreated(_))));
+    }
+
+    #[tokio::test]
+    async fn test_handle_unknown_currency() {
+        let mut handler = CreateLoanCommandHandler::new(MockLoanRepository::new(false));
+
+        let result = handler.handle(command_fixture("XXX")).await;
+
+        assert!(matches!(result, Err(FeatureError::Loan(LoanError::Domain(DomainError::UnknownCurrency(_))))));
+    }
+
+    #[tokio::test]
+    async fn test_handle_repository_error() {
+        let mut handler = CreateLoanCommandHandler::new(MockLoanRepository::new(true));
+
+        let result = handler.handle(command_fixture("USD")).await;
+
+        assert!(matches!(result, Err(FeatureError::Loan(LoanError::Infrastructure(_)))));
+    }
+
+    fn command_fixture(currency: &str) -> CreateLoanCommand {
+        CreateLoanCommand::new(
+            Uuid::new_v4(),
+            "Taken".to_string(),
+            "Bank".to_string(),
+            Decimal::from(1000),
+            currency.to_string(),
+            Decimal::from(12),
+            NaiveDate::from_ymd_opt(2024, 4, 1).unwrap(),
+            12,
+        )
+    }
+}
@@ -0,0 +1,2 @@
+pub mod create_loan;
+pub mod record_repayment;
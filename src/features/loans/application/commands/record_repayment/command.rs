@@ -0,0 +1,40 @@
+use uuid::Uuid;
+use crate::support::command_bus::Command;
+
+const NAME : &str = "record_repayment";
+
+#[derive(Debug, Clone)]
+pub struct RecordRepaymentCommand {
+    loan_id: Uuid,
+    user_id: Uuid,
+    operation_id: Uuid,
+}
+
+impl RecordRepaymentCommand {
+    pub fn new(loan_id: Uuid, user_id: Uuid, operation_id: Uuid) -> Self {
+        Self {
+            loan_id,
+            user_id,
+            operation_id,
+        }
+    }
+
+    pub fn loan_id(&self) -> &Uuid {
+        &self.loan_id
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    /// Operation that moved the repaid money, its amount is the amount of the repayment.
+    pub fn operation_id(&self) -> &Uuid {
+        &self.operation_id
+    }
+}
+
+impl Command for RecordRepaymentCommand {
+    fn name() -> &'static str {
+        NAME
+    }
+}
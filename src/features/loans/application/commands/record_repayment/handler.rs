@@ -0,0 +1,159 @@
+use async_trait::async_trait;
+use crate::events::event::Event;
+use crate::features::loans::application::commands::record_repayment::command::RecordRepaymentCommand;
+use crate::features::loans::domain::error::DomainError;
+use crate::features::loans::domain::loan_repository::LoanRepository;
+use crate::features::loans::domain::operation_source::OperationSource;
+use crate::features::loans::error::LoanError;
+use crate::support::command_bus::CommandHandler;
+use crate::support::error::FeatureError;
+
+pub struct RecordRepaymentCommandHandler<R, O>
+    where
+        R: LoanRepository + Send + Sync,
+        O: OperationSource + Send + Sync,
+{
+    loan_repository: R,
+    operations: O,
+}
+
+impl<R, O> RecordRepaymentCommandHandler<R, O>
+    where
+        R: LoanRepository + Send + Sync,
+        O: OperationSource + Send + Sync,
+{
+    pub fn new(loan_repository: R, operations: O) -> Self {
+        Self {
+            loan_repository,
+            operations,
+        }
+    }
+}
+
+#[async_trait]
+impl<R, O> CommandHandler<RecordRepaymentCommand> for RecordRepaymentCommandHandler<R, O>
+    where
+        R: LoanRepository + Send + Sync,
+        O: OperationSource + Send + Sync,
+{
+    async fn handle(&mut self, command: RecordRepaymentCommand) -> Result<Vec<Event>, FeatureError> {
+        let loan = self.loan_repository.load(*command.loan_id())
+            .await
+            .map_err(FeatureError::Loan)?
+            .ok_or(
+                FeatureError::Loan(
+                    LoanError::Domain(
+                        DomainError::LoanNotFound(command.loan_id().to_string())
+                    )
+                )
+            )?;
+
+        let operation = self.operations.operation(*command.operation_id())
+            .await?
+            .ok_or(
+                FeatureError::Loan(
+                    LoanError::Domain(
+                        DomainError::OperationNotFound(command.operation_id().to_string())
+                    )
+                )
+            )?;
+
+        let event = loan.aggregate().handle_repayment(command, &operation)
+            .map_err(|e|
+                FeatureError::Loan(
+                    LoanError::Domain(e)
+                )
+            )?;
+
+        self.loan_repository.append(loan.aggregate().id().value(), loan.version(), std::slice::from_ref(&event))
+            .await
+            .map_err(FeatureError::Loan)?;
+
+        Ok(
+            vec![Event::LoanEvent(event)]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use futures_util::FutureExt;
+    use mockall::predicate::eq;
+    use rust_decimal::Decimal;
+    use uuid::Uuid;
+    use crate::features::loans::application::commands::create_loan::command::CreateLoanCommand;
+    use crate::features::loans::domain::events::loan_event::LoanEvent;
+    use crate::features::loans::domain::loan::Loan;
+    use crate::features::loans::domain::loan_repository::MockLoanRepository;
+    use crate::features::loans::domain::operation_source::{MockOperationSource, RepaymentOperation};
+    use crate::support::event_store::{Aggregate, Versioned};
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle_success() {
+        let loan = loan_fixture();
+        let user_id = loan.aggregate().user_id().value();
+        let operation_id = Uuid::new_v4();
+
+        let mut operations = MockOperationSource::new();
+        operations.expect_operation()
+            .with(eq(operation_id))
+            .returning(move |_| async move {
+                Ok(Some(RepaymentOperation::new(user_id, "USD".to_string(), Decimal::from(100), Decimal::ONE, NaiveDate::from_ymd_opt(2024, 2, 1).unwrap())))
+            }.boxed());
+
+        let command = RecordRepaymentCommand::new(loan.aggregate().id().value(), user_id, operation_id);
+        let events = RecordRepaymentCommandHandler::new(MockLoanRepository::with_loans(vec![loan]), operations)
+            .handle(command)
+            .await
+            .unwrap();
+
+        assert!(matches!(events[0], Event::LoanEvent(LoanEvent::LoanRepaymentRecorded(_))));
+    }
+
+    #[tokio::test]
+    async fn test_handle_unknown_operation() {
+        let loan = loan_fixture();
+        let user_id = loan.aggregate().user_id().value();
+
+        let mut operations = MockOperationSource::new();
+        operations.expect_operation()
+            .returning(|_| async { Ok(None) }.boxed());
+
+        let command = RecordRepaymentCommand::new(loan.aggregate().id().value(), user_id, Uuid::new_v4());
+        let result = RecordRepaymentCommandHandler::new(MockLoanRepository::with_loans(vec![loan]), operations)
+            .handle(command)
+            .await;
+
+        assert!(matches!(result, Err(FeatureError::Loan(LoanError::Domain(DomainError::OperationNotFound(_))))));
+    }
+
+    #[tokio::test]
+    async fn test_handle_unknown_loan() {
+        let mut operations = MockOperationSource::new();
+        operations.expect_operation().never();
+
+        let command = RecordRepaymentCommand::new(Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let result = RecordRepaymentCommandHandler::new(MockLoanRepository::new(false), operations)
+            .handle(command)
+            .await;
+
+        assert!(matches!(result, Err(FeatureError::Loan(LoanError::Domain(DomainError::LoanNotFound(_))))));
+    }
+
+    fn loan_fixture() -> Versioned<Loan> {
+        let command = CreateLoanCommand::new(
+            Uuid::new_v4(),
+            "Given".to_string(),
+            "Friend".to_string(),
+            Decimal::from(300),
+            "USD".to_string(),
+            Decimal::ZERO,
+            NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            3,
+        );
+
+        Versioned::new(Loan::apply(None, &Loan::handle_creation(command).unwrap()).unwrap(), 1)
+    }
+}
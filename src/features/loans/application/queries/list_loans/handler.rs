@@ -0,0 +1,114 @@
+use async_trait::async_trait;
+use crate::features::loans::application::queries::list_loans::query::ListLoansQuery;
+use crate::features::loans::application::queries::loan_projection_repository::LoanProjectionRepository;
+use crate::features::loans::application::queries::loan_view::LoanStatus;
+use crate::support::error::FeatureError;
+use crate::support::query_bus::QueryHandler;
+
+pub struct ListLoansQueryHandler<R>
+    where
+        R: LoanProjectionRepository + Send + Sync,
+{
+    rep: R,
+}
+
+impl<R> ListLoansQueryHandler<R>
+    where
+        R: LoanProjectionRepository + Send + Sync,
+{
+    pub fn new(rep: R) -> Self {
+        Self {
+            rep,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> QueryHandler<ListLoansQuery> for ListLoansQueryHandler<R>
+    where
+        R: LoanProjectionRepository + Send + Sync,
+{
+    type Output = Vec<LoanStatus>;
+
+    async fn handle(&self, query: ListLoansQuery) -> Result<Vec<LoanStatus>, FeatureError> {
+        let loans = self.rep.find(*query.user_id())
+            .await
+            .map_err(FeatureError::Loan)?;
+
+        Ok(
+            loans.into_iter()
+                .map(|loan| LoanStatus::new(loan, query.date()))
+                .filter(|status| !query.overdue_only() || status.is_overdue())
+                .collect()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, Utc};
+    use futures_util::FutureExt;
+    use mockall::predicate::eq;
+    use rust_decimal::Decimal;
+    use uuid::Uuid;
+    use crate::features::loans::application::queries::loan_projection_repository::MockLoanProjectionRepository;
+    use crate::features::loans::application::queries::loan_view::{LoanView, RepaymentView};
+    use crate::features::loans::domain::schedule::annuity_schedule;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle_reports_outstanding_and_overdue() {
+        let user_id = Uuid::new_v4();
+        let loans = vec![
+            loan_fixture(user_id, "Bank", Decimal::ZERO),
+            loan_fixture(user_id, "Friend", Decimal::from(200)),
+        ];
+
+        let mut rep = MockLoanProjectionRepository::new();
+        rep.expect_find()
+            .with(eq(user_id))
+            .returning(move |_| {
+                let loans = loans.clone();
+                async move { Ok(loans) }.boxed()
+            });
+        let handler = ListLoansQueryHandler::new(rep);
+
+        let all = handler.handle(ListLoansQuery::new(user_id, date(2024, 3, 15), false)).await.unwrap();
+        let overdue = handler.handle(ListLoansQuery::new(user_id, date(2024, 3, 15), true)).await.unwrap();
+
+        assert_eq!(all.len(), 2);
+        assert_eq!(all[0].outstanding(), Decimal::from(300));
+        assert_eq!(all[0].overdue(), Decimal::from(200));
+        assert_eq!(all[0].next_due_on(), &Some(date(2024, 2, 1)));
+        assert_eq!(all[1].outstanding(), Decimal::from(100));
+        assert_eq!(all[1].next_due_on(), &Some(date(2024, 4, 1)));
+        assert_eq!(overdue.len(), 1);
+        assert_eq!(overdue[0].loan().counterparty(), "Bank");
+    }
+
+    fn loan_fixture(user_id: Uuid, counterparty: &str, repaid: Decimal) -> LoanView {
+        let repayments = if repaid.is_zero() {
+            vec![]
+        } else {
+            vec![RepaymentView::new(Uuid::new_v4(), repaid, date(2024, 3, 1))]
+        };
+
+        LoanView::new(
+            Uuid::new_v4(),
+            user_id,
+            "Given".to_string(),
+            counterparty.to_string(),
+            Decimal::from(300),
+            "USD".to_string(),
+            Decimal::ZERO,
+            date(2024, 1, 1),
+            annuity_schedule(Decimal::from(300), Decimal::ZERO, 3, date(2024, 1, 1), 2).unwrap(),
+            repayments,
+            Utc::now(),
+        )
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+}
@@ -0,0 +1,41 @@
+use chrono::NaiveDate;
+use uuid::Uuid;
+use crate::support::query_bus::Query;
+
+const NAME: &str = "list_loans";
+
+#[derive(Debug, Clone)]
+pub struct ListLoansQuery {
+    user_id: Uuid,
+    date: NaiveDate,
+    overdue_only: bool,
+}
+
+impl ListLoansQuery {
+    pub fn new(user_id: Uuid, date: NaiveDate, overdue_only: bool) -> Self {
+        Self {
+            user_id,
+            date,
+            overdue_only,
+        }
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    /// Day the loans are reported on, installments due before it are overdue unless repaid.
+    pub fn date(&self) -> &NaiveDate {
+        &self.date
+    }
+
+    pub fn overdue_only(&self) -> bool {
+        self.overdue_only
+    }
+}
+
+impl Query for ListLoansQuery {
+    fn name() -> &'static str {
+        NAME
+    }
+}
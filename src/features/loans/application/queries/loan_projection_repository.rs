@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+use mockall::automock;
+use uuid::Uuid;
+use crate::features::loans::application::queries::loan_view::LoanView;
+use crate::features::loans::domain::events::loan_created::LoanCreated;
+use crate::features::loans::domain::events::loan_repayment_recorded::LoanRepaymentRecorded;
+use crate::features::loans::error::LoanError;
+
+#[async_trait]
+#[automock]
+pub trait LoanProjectionRepository {
+    async fn find(&self, user_id: Uuid) -> Result<Vec<LoanView>, LoanError>;
+
+    async fn apply_loan_created(&self, event: &LoanCreated) -> Result<(), LoanError>;
+
+    async fn apply_loan_repayment_recorded(&self, event: &LoanRepaymentRecorded) -> Result<(), LoanError>;
+}
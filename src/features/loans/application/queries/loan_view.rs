@@ -0,0 +1,200 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use sqlx::types::Json;
+use uuid::Uuid;
+use crate::features::loans::domain::schedule::{due_before, Installment};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RepaymentView {
+    operation_id: Uuid,
+    amount: Decimal,
+    paid_on: NaiveDate,
+}
+
+impl RepaymentView {
+    pub fn new(operation_id: Uuid, amount: Decimal, paid_on: NaiveDate) -> Self {
+        Self {
+            operation_id,
+            amount,
+            paid_on,
+        }
+    }
+
+    pub fn operation_id(&self) -> &Uuid {
+        &self.operation_id
+    }
+
+    pub fn amount(&self) -> Decimal {
+        self.amount
+    }
+
+    pub fn paid_on(&self) -> &NaiveDate {
+        &self.paid_on
+    }
+}
+
+/// Read model row of the `loans` projection with its schedule and the repayments linked so far.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct LoanView {
+    id: Uuid,
+    user_id: Uuid,
+    direction: String,
+    counterparty: String,
+    principal: Decimal,
+    currency: String,
+    interest_rate: Decimal,
+    issued_on: NaiveDate,
+    schedule: Json<Vec<Installment>>,
+    repayments: Json<Vec<RepaymentView>>,
+    created_at: DateTime<Utc>,
+}
+
+impl LoanView {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: Uuid,
+        user_id: Uuid,
+        direction: String,
+        counterparty: String,
+        principal: Decimal,
+        currency: String,
+        interest_rate: Decimal,
+        issued_on: NaiveDate,
+        schedule: Vec<Installment>,
+        repayments: Vec<RepaymentView>,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            direction,
+            counterparty,
+            principal,
+            currency,
+            interest_rate,
+            issued_on,
+            schedule: Json(schedule),
+            repayments: Json(repayments),
+            created_at,
+        }
+    }
+
+    pub fn id(&self) -> &Uuid {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    pub fn direction(&self) -> &str {
+        &self.direction
+    }
+
+    pub fn counterparty(&self) -> &str {
+        &self.counterparty
+    }
+
+    pub fn principal(&self) -> Decimal {
+        self.principal
+    }
+
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    pub fn interest_rate(&self) -> Decimal {
+        self.interest_rate
+    }
+
+    pub fn issued_on(&self) -> &NaiveDate {
+        &self.issued_on
+    }
+
+    pub fn schedule(&self) -> &[Installment] {
+        &self.schedule
+    }
+
+    pub fn repayments(&self) -> &[RepaymentView] {
+        &self.repayments
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    fn repaid(&self) -> Decimal {
+        self.repayments.iter().map(RepaymentView::amount).sum()
+    }
+
+    /// Due dates of the installments the repayments do not cover yet, with what is left to pay on each.
+    /// Repayments cover installments in schedule order.
+    pub fn unpaid_installments(&self) -> Vec<(NaiveDate, Decimal)> {
+        let mut repaid = self.repaid();
+
+        self.schedule.iter()
+            .filter_map(|installment| {
+                let covered = repaid.min(installment.amount());
+                repaid -= covered;
+
+                let left = installment.amount() - covered;
+                (left > Decimal::ZERO).then_some((*installment.due_on(), left))
+            })
+            .collect()
+    }
+}
+
+/// Loan with its standing on a date: what is left to repay, what is overdue and when the next installment is due.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoanStatus {
+    #[serde(flatten)]
+    loan: LoanView,
+    outstanding: Decimal,
+    overdue: Decimal,
+    next_due_on: Option<NaiveDate>,
+}
+
+impl LoanStatus {
+    /// Repayments cover installments in schedule order, so the next installment is the first one they do not cover.
+    pub fn new(loan: LoanView, date: &NaiveDate) -> Self {
+        let repaid = loan.repaid();
+        let scheduled: Decimal = loan.schedule.iter().map(Installment::amount).sum();
+
+        let mut covered = Decimal::ZERO;
+        let next_due_on = loan.schedule.iter()
+            .find(|installment| {
+                covered += installment.amount();
+                covered > repaid
+            })
+            .map(|installment| *installment.due_on());
+
+        Self {
+            outstanding: scheduled - repaid,
+            overdue: (due_before(&loan.schedule, date) - repaid).max(Decimal::ZERO),
+            next_due_on,
+            loan,
+        }
+    }
+
+    pub fn loan(&self) -> &LoanView {
+        &self.loan
+    }
+
+    pub fn outstanding(&self) -> Decimal {
+        self.outstanding
+    }
+
+    pub fn overdue(&self) -> Decimal {
+        self.overdue
+    }
+
+    pub fn is_overdue(&self) -> bool {
+        self.overdue > Decimal::ZERO
+    }
+
+    pub fn next_due_on(&self) -> &Option<NaiveDate> {
+        &self.next_due_on
+    }
+}
@@ -0,0 +1,3 @@
+pub mod list_loans;
+pub mod loan_projection_repository;
+pub mod loan_view;
@@ -0,0 +1,28 @@
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone)]
+pub enum DomainError {
+    #[error("Loan {0} not found")]
+    LoanNotFound(String),
+
+    #[error("Loan belongs to another user")]
+    AccessDenied,
+
+    #[error("Counterparty cannot be empty")]
+    EmptyCounterparty,
+
+    #[error("Unknown loan direction {0}")]
+    UnknownDirection(String),
+
+    #[error("Unknown currency {0}")]
+    UnknownCurrency(String),
+
+    #[error("Invalid loan terms. {0}")]
+    InvalidTerms(String),
+
+    #[error("Operation {0} not found")]
+    OperationNotFound(String),
+
+    #[error("Invalid repayment. {0}")]
+    InvalidRepayment(String),
+}
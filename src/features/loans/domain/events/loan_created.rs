@@ -0,0 +1,111 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use crate::features::loans::domain::loan_direction::LoanDirection;
+use crate::features::loans::domain::schedule::Installment;
+use crate::support::currency::Currency;
+use crate::support::id::Id;
+
+pub const LOAN_CREATED_NAME: &str = "loan_created";
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LoanCreated {
+    id: Id,
+    name: String,
+    payload: LoanCreatedPayload,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LoanCreatedPayload {
+    id: Id,
+    user_id: Id,
+    direction: LoanDirection,
+    counterparty: String,
+    principal: Decimal,
+    currency: Currency,
+    interest_rate: Decimal,
+    issued_on: NaiveDate,
+    schedule: Vec<Installment>,
+}
+
+impl LoanCreated {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: Id,
+        loan_id: Id,
+        user_id: Id,
+        direction: LoanDirection,
+        counterparty: String,
+        principal: Decimal,
+        currency: Currency,
+        interest_rate: Decimal,
+        issued_on: NaiveDate,
+        schedule: Vec<Installment>,
+    ) -> Self {
+        Self {
+            id,
+            name: LOAN_CREATED_NAME.to_string(),
+            payload: LoanCreatedPayload {
+                id: loan_id,
+                user_id,
+                direction,
+                counterparty,
+                principal,
+                currency,
+                interest_rate,
+                issued_on,
+                schedule,
+            }
+        }
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn payload(&self) -> &LoanCreatedPayload {
+        &self.payload
+    }
+}
+
+impl LoanCreatedPayload {
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Id {
+        &self.user_id
+    }
+
+    pub fn direction(&self) -> &LoanDirection {
+        &self.direction
+    }
+
+    pub fn counterparty(&self) -> &str {
+        &self.counterparty
+    }
+
+    pub fn principal(&self) -> Decimal {
+        self.principal
+    }
+
+    pub fn currency(&self) -> &Currency {
+        &self.currency
+    }
+
+    pub fn interest_rate(&self) -> Decimal {
+        self.interest_rate
+    }
+
+    pub fn issued_on(&self) -> &NaiveDate {
+        &self.issued_on
+    }
+
+    pub fn schedule(&self) -> &[Installment] {
+        &self.schedule
+    }
+}
@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+use crate::features::loans::domain::events::loan_created::{LOAN_CREATED_NAME, LoanCreated};
+use crate::features::loans::domain::events::loan_repayment_recorded::{LOAN_REPAYMENT_RECORDED_NAME, LoanRepaymentRecorded};
+use crate::support::event_store::{decode_event, EventStoreError, NewEvent, StorableEvent, StoredEvent};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum LoanEvent {
+    LoanCreated(LoanCreated),
+    LoanRepaymentRecorded(LoanRepaymentRecorded),
+}
+
+impl LoanEvent {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::LoanCreated(event) => event.name(),
+            Self::LoanRepaymentRecorded(event) => event.name(),
+        }
+    }
+}
+
+impl StorableEvent for LoanEvent {
+    fn from_stored(stored: &StoredEvent) -> Result<Self, EventStoreError> {
+        match stored.name() {
+            LOAN_CREATED_NAME => Ok(Self::LoanCreated(decode_event(stored)?)),
+            LOAN_REPAYMENT_RECORDED_NAME => Ok(Self::LoanRepaymentRecorded(decode_event(stored)?)),
+            name => Err(
+                EventStoreError::Serialization(format!("Unknown loan event {}", name))
+            ),
+        }
+    }
+
+    fn to_new(&self) -> Result<NewEvent, EventStoreError> {
+        match self {
+            Self::LoanCreated(event) => NewEvent::from_event(event),
+            Self::LoanRepaymentRecorded(event) => NewEvent::from_event(event),
+        }
+    }
+}
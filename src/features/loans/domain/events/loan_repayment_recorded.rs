@@ -0,0 +1,72 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use crate::support::id::Id;
+
+pub const LOAN_REPAYMENT_RECORDED_NAME: &str = "loan_repayment_recorded";
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LoanRepaymentRecorded {
+    id: Id,
+    name: String,
+    payload: LoanRepaymentRecordedPayload,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LoanRepaymentRecordedPayload {
+    id: Id,
+    user_id: Id,
+    operation_id: Id,
+    amount: Decimal,
+    paid_on: NaiveDate,
+}
+
+impl LoanRepaymentRecorded {
+    pub fn new(id: Id, loan_id: Id, user_id: Id, operation_id: Id, amount: Decimal, paid_on: NaiveDate) -> Self {
+        Self {
+            id,
+            name: LOAN_REPAYMENT_RECORDED_NAME.to_string(),
+            payload: LoanRepaymentRecordedPayload {
+                id: loan_id,
+                user_id,
+                operation_id,
+                amount,
+                paid_on,
+            }
+        }
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn payload(&self) -> &LoanRepaymentRecordedPayload {
+        &self.payload
+    }
+}
+
+impl LoanRepaymentRecordedPayload {
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Id {
+        &self.user_id
+    }
+
+    pub fn operation_id(&self) -> &Id {
+        &self.operation_id
+    }
+
+    pub fn amount(&self) -> Decimal {
+        self.amount
+    }
+
+    pub fn paid_on(&self) -> &NaiveDate {
+        &self.paid_on
+    }
+}
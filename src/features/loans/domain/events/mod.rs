@@ -0,0 +1,3 @@
+pub mod loan_created;
+pub mod loan_event;
+pub mod loan_repayment_recorded;
@@ -0,0 +1,372 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use crate::features::loans::application::commands::create_loan::command::CreateLoanCommand;
+use crate::features::loans::application::commands::record_repayment::command::RecordRepaymentCommand;
+use crate::features::loans::domain::error::DomainError;
+use crate::features::loans::domain::events::loan_created::LoanCreated;
+use crate::features::loans::domain::events::loan_event::LoanEvent;
+use crate::features::loans::domain::events::loan_repayment_recorded::LoanRepaymentRecorded;
+use crate::features::loans::domain::loan_direction::LoanDirection;
+use crate::features::loans::domain::operation_source::RepaymentOperation;
+use crate::features::loans::domain::repayment::Repayment;
+use crate::features::loans::domain::schedule::{annuity_schedule, due_before, Installment};
+use crate::support::currency::Currency;
+use crate::support::event_store::{Aggregate, EventStoreError};
+use crate::support::id::Id;
+use crate::support::money::round_money;
+
+const MAX_TERM_MONTHS: u32 = 600;
+const MAX_INTEREST_RATE: u32 = 1000;
+
+/// Money lent to or borrowed from a counterparty, paid back by a schedule of monthly installments.
+#[derive(Debug, Clone)]
+pub struct Loan {
+    id: Id,
+    user_id: Id,
+    direction: LoanDirection,
+    counterparty: String,
+    principal: Decimal,
+    currency: Currency,
+    interest_rate: Decimal,
+    issued_on: NaiveDate,
+    schedule: Vec<Installment>,
+    repayments: Vec<Repayment>,
+}
+
+impl Loan {
+    pub fn handle_creation(command: CreateLoanCommand) -> Result<LoanEvent, DomainError> {
+        let direction = LoanDirection::new(command.direction())?;
+        let counterparty = command.counterparty().trim();
+
+        if counterparty.is_empty() {
+            return Err(DomainError::EmptyCounterparty);
+        }
+
+        let currency = Currency::find(command.currency())
+            .ok_or_else(|| DomainError::UnknownCurrency(command.currency().to_string()))?;
+        let principal = round_money(command.principal(), currency.minor_units());
+
+        if principal <= Decimal::ZERO {
+            return Err(DomainError::InvalidTerms("Principal must be greater than zero".to_string()));
+        }
+
+        if command.interest_rate() < Decimal::ZERO || command.interest_rate() > Decimal::from(MAX_INTEREST_RATE) {
+            return Err(
+                DomainError::InvalidTerms(
+                    format!("Interest rate must be between 0 and {} percent", MAX_INTEREST_RATE)
+                )
+            );
+        }
+
+        if command.term_months() == 0 || command.term_months() > MAX_TERM_MONTHS {
+            return Err(
+                DomainError::InvalidTerms(
+                    format!("Term must be between 1 and {} months", MAX_TERM_MONTHS)
+                )
+            );
+        }
+
+        let schedule = annuity_schedule(principal, command.interest_rate(), command.term_months(), *command.issued_on(), currency.minor_units())
+            .ok_or_else(|| DomainError::InvalidTerms("Repayment schedule cannot be calculated".to_string()))?;
+
+        Ok(
+            LoanEvent::LoanCreated(
+                LoanCreated::new(
+                    Id::new(Id::generate()),
+                    Id::new(Id::generate()),
+                    Id::new(*command.user_id()),
+                    direction,
+                    counterparty.to_string(),
+                    principal,
+                    currency,
+                    command.interest_rate(),
+                    *command.issued_on(),
+                    schedule,
+                )
+            )
+        )
+    }
+
+    /// Links an operation of the user to the loan. The operation must move money in the direction of a repayment,
+    /// in the loan currency, and may not pay more than is outstanding.
+    pub fn handle_repayment(&self, command: RecordRepaymentCommand, operation: &RepaymentOperation) -> Result<LoanEvent, DomainError> {
+        self.check_access(command.user_id())?;
+
+        if operation.user_id() != command.user_id() {
+            return Err(DomainError::OperationNotFound(command.operation_id().to_string()));
+        }
+
+        if self.repayments.iter().any(|repayment| repayment.operation_id().value() == *command.operation_id()) {
+            return Err(
+                DomainError::InvalidRepayment(
+                    format!("Operation {} is already linked to the loan", command.operation_id())
+                )
+            );
+        }
+
+        if operation.currency() != self.currency.code() {
+            return Err(
+                DomainError::InvalidRepayment(
+                    format!("Operation currency {} differs from loan currency {}", operation.currency(), self.currency)
+                )
+            );
+        }
+
+        if operation.sign() != self.direction.repayment_sign() {
+            return Err(
+                DomainError::InvalidRepayment(
+                    match self.direction {
+                        LoanDirection::Given => "Repayments of a given loan must bring money in".to_string(),
+                        LoanDirection::Taken => "Repayments of a taken loan must take money out".to_string(),
+                    }
+                )
+            );
+        }
+
+        if operation.amount() > self.outstanding() {
+            return Err(
+                DomainError::InvalidRepayment(
+                    format!("Repayment {} exceeds outstanding balance {}", operation.amount(), self.outstanding())
+                )
+            );
+        }
+
+        Ok(
+            LoanEvent::LoanRepaymentRecorded(
+                LoanRepaymentRecorded::new(
+                    Id::new(Id::generate()),
+                    self.id.clone(),
+                    self.user_id.clone(),
+                    Id::new(*command.operation_id()),
+                    operation.amount(),
+                    *operation.date(),
+                )
+            )
+        )
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Id {
+        &self.user_id
+    }
+
+    pub fn direction(&self) -> &LoanDirection {
+        &self.direction
+    }
+
+    pub fn counterparty(&self) -> &str {
+        &self.counterparty
+    }
+
+    pub fn principal(&self) -> Decimal {
+        self.principal
+    }
+
+    pub fn currency(&self) -> &Currency {
+        &self.currency
+    }
+
+    pub fn interest_rate(&self) -> Decimal {
+        self.interest_rate
+    }
+
+    pub fn issued_on(&self) -> &NaiveDate {
+        &self.issued_on
+    }
+
+    pub fn schedule(&self) -> &[Installment] {
+        &self.schedule
+    }
+
+    pub fn repayments(&self) -> &[Repayment] {
+        &self.repayments
+    }
+
+    /// Scheduled principal and interest not repaid yet.
+    pub fn outstanding(&self) -> Decimal {
+        self.schedule.iter().map(Installment::amount).sum::<Decimal>() - self.repaid()
+    }
+
+    /// Installments due before `date` that repayments do not cover.
+    pub fn overdue(&self, date: &NaiveDate) -> Decimal {
+        (due_before(&self.schedule, date) - self.repaid()).max(Decimal::ZERO)
+    }
+
+    fn repaid(&self) -> Decimal {
+        self.repayments.iter().map(Repayment::amount).sum()
+    }
+
+    fn check_access(&self, user_id: &Uuid) -> Result<(), DomainError> {
+        if self.user_id.value() != *user_id {
+            return Err(DomainError::AccessDenied);
+        }
+
+        Ok(())
+    }
+}
+
+impl Aggregate for Loan {
+    type Event = LoanEvent;
+
+    fn apply(state: Option<Self>, event: &LoanEvent) -> Result<Self, EventStoreError> {
+        match (state, event) {
+            (None, LoanEvent::LoanCreated(loan_created)) => {
+                let payload = loan_created.payload();
+
+                Ok(
+                    Self {
+                        id: payload.id().clone(),
+                        user_id: payload.user_id().clone(),
+                        direction: *payload.direction(),
+                        counterparty: payload.counterparty().to_string(),
+                        principal: payload.principal(),
+                        currency: *payload.currency(),
+                        interest_rate: payload.interest_rate(),
+                        issued_on: *payload.issued_on(),
+                        schedule: payload.schedule().to_vec(),
+                        repayments: vec![],
+                    }
+                )
+            }
+            (Some(loan), LoanEvent::LoanCreated(_)) => Err(
+                EventStoreError::Rehydration(
+                    format!("Loan {} is already created", loan.id().value())
+                )
+            ),
+            (Some(mut loan), LoanEvent::LoanRepaymentRecorded(repayment_recorded)) => {
+                let payload = repayment_recorded.payload();
+
+                loan.repayments.push(
+                    Repayment::new(payload.operation_id().clone(), payload.amount(), *payload.paid_on())
+                );
+
+                Ok(loan)
+            }
+            (None, event) => Err(
+                EventStoreError::Rehydration(
+                    format!("Loan stream must start with loan_created, got {}", event.name())
+                )
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_handle_creation() {
+        let command = command_fixture("Taken", Decimal::new(100005, 3), 2);
+
+        let loan = Loan::apply(None, &Loan::handle_creation(command.clone()).unwrap()).unwrap();
+
+        assert_eq!(loan.user_id().value(), *command.user_id());
+        assert_eq!(loan.counterparty(), "Bank");
+        assert_eq!(loan.principal(), Decimal::new(10001, 2));
+        assert_eq!(loan.schedule().len(), 2);
+        assert_eq!(loan.outstanding(), Decimal::new(10001, 2));
+    }
+
+    #[test]
+    fn test_handle_creation_with_invalid_terms() {
+        assert!(matches!(Loan::handle_creation(command_fixture("Lent", Decimal::from(100), 2)), Err(DomainError::UnknownDirection(_))));
+        assert!(matches!(Loan::handle_creation(command_fixture("Given", Decimal::ZERO, 2)), Err(DomainError::InvalidTerms(_))));
+        assert!(matches!(Loan::handle_creation(command_fixture("Given", Decimal::from(100), 0)), Err(DomainError::InvalidTerms(_))));
+    }
+
+    #[test]
+    fn test_handle_creation_with_extreme_terms() {
+        let command = CreateLoanCommand::new(
+            Uuid::new_v4(),
+            "Taken".to_string(),
+            "Bank".to_string(),
+            Decimal::from(1_000_000),
+            "USD".to_string(),
+            Decimal::from(1000),
+            date(2024, 1, 1),
+            100,
+        );
+
+        assert!(matches!(Loan::handle_creation(command), Err(DomainError::InvalidTerms(_))));
+    }
+
+    #[test]
+    fn test_handle_repayment() {
+        let loan = loan_fixture("Taken");
+        let user_id = loan.user_id().value();
+        let operation = RepaymentOperation::new(user_id, "USD".to_string(), Decimal::from(50), Decimal::NEGATIVE_ONE, date(2024, 2, 1));
+
+        let event = loan.handle_repayment(RecordRepaymentCommand::new(loan.id().value(), user_id, Uuid::new_v4()), &operation).unwrap();
+        let loan = Loan::apply(Some(loan), &event).unwrap();
+
+        assert_eq!(loan.repayments().len(), 1);
+        assert_eq!(loan.outstanding(), Decimal::from(50));
+    }
+
+    #[test]
+    fn test_handle_invalid_repayment() {
+        let loan = loan_fixture("Given");
+        let user_id = loan.user_id().value();
+        let command = RecordRepaymentCommand::new(loan.id().value(), user_id, Uuid::new_v4());
+        let operation = |currency: &str, amount: Decimal, sign: Decimal| RepaymentOperation::new(user_id, currency.to_string(), amount, sign, date(2024, 2, 1));
+
+        let outgoing = loan.handle_repayment(command.clone(), &operation("USD", Decimal::from(10), Decimal::NEGATIVE_ONE));
+        let other_currency = loan.handle_repayment(command.clone(), &operation("EUR", Decimal::from(10), Decimal::ONE));
+        let overpaid = loan.handle_repayment(command, &operation("USD", Decimal::from(101), Decimal::ONE));
+
+        assert!(matches!(outgoing, Err(DomainError::InvalidRepayment(_))));
+        assert!(matches!(other_currency, Err(DomainError::InvalidRepayment(_))));
+        assert!(matches!(overpaid, Err(DomainError::InvalidRepayment(_))));
+    }
+
+    #[test]
+    fn test_handle_repayment_by_another_user() {
+        let loan = loan_fixture("Given");
+        let user_id = Uuid::new_v4();
+        let operation = RepaymentOperation::new(user_id, "USD".to_string(), Decimal::from(10), Decimal::ONE, date(2024, 2, 1));
+
+        let result = loan.handle_repayment(RecordRepaymentCommand::new(loan.id().value(), user_id, Uuid::new_v4()), &operation);
+
+        assert!(matches!(result, Err(DomainError::AccessDenied)));
+    }
+
+    #[test]
+    fn test_overdue() {
+        let loan = loan_fixture("Given");
+        let user_id = loan.user_id().value();
+        let operation = RepaymentOperation::new(user_id, "USD".to_string(), Decimal::from(30), Decimal::ONE, date(2024, 2, 1));
+
+        let event = loan.handle_repayment(RecordRepaymentCommand::new(loan.id().value(), user_id, Uuid::new_v4()), &operation).unwrap();
+        let loan = Loan::apply(Some(loan), &event).unwrap();
+
+        assert_eq!(loan.overdue(&date(2024, 2, 1)), Decimal::ZERO);
+        assert_eq!(loan.overdue(&date(2024, 2, 2)), Decimal::from(20));
+        assert_eq!(loan.overdue(&date(2024, 3, 2)), Decimal::from(70));
+    }
+
+    fn loan_fixture(direction: &str) -> Loan {
+        Loan::apply(None, &Loan::handle_creation(command_fixture(direction, Decimal::from(100), 2)).unwrap()).unwrap()
+    }
+
+    fn command_fixture(direction: &str, principal: Decimal, term_months: u32) -> CreateLoanCommand {
+        CreateLoanCommand::new(
+            Uuid::new_v4(),
+            direction.to_string(),
+            " Bank ".to_string(),
+            principal,
+            "USD".to_string(),
+            Decimal::ZERO,
+            date(2024, 1, 1),
+            term_months,
+        )
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+}
@@ -0,0 +1,36 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use crate::features::loans::domain::error::DomainError;
+
+/// Side of the user in a loan: `Given` is money lent to the counterparty, `Taken` is money borrowed from it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LoanDirection {
+    Given,
+    Taken,
+}
+
+impl LoanDirection {
+    pub fn new(value: &str) -> Result<Self, DomainError> {
+        match value {
+            "Given" => Ok(Self::Given),
+            "Taken" => Ok(Self::Taken),
+            _ => Err(DomainError::UnknownDirection(value.to_string())),
+        }
+    }
+
+    pub fn to_str(&self) -> &str {
+        match self {
+            Self::Given => "Given",
+            Self::Taken => "Taken",
+        }
+    }
+
+    /// Direction of the money of a repayment on the user's accounts: given loans are repaid to the user,
+    /// taken loans are repaid by the user.
+    pub fn repayment_sign(&self) -> Decimal {
+        match self {
+            Self::Given => Decimal::ONE,
+            Self::Taken => Decimal::NEGATIVE_ONE,
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use std::collections::HashMap;
+use async_trait::async_trait;
+use uuid::Uuid;
+use crate::features::loans::domain::events::loan_event::LoanEvent;
+use crate::features::loans::domain::loan::Loan;
+use crate::features::loans::error::LoanError;
+use crate::features::loans::infrastructure::error::InfrastructureError;
+use crate::support::event_store::Versioned;
+
+#[async_trait]
+pub trait LoanRepository {
+    async fn load(&self, loan_id: Uuid) -> Result<Option<Versioned<Loan>>, LoanError>;
+
+    /// Fails with `InfrastructureError::Conflict` when the stream is no longer at `expected_version`.
+    async fn append(&self, loan_id: Uuid, expected_version: i32, events: &[LoanEvent]) -> Result<i32, LoanError>;
+}
+
+pub struct MockLoanRepository {
+    has_error: bool,
+    loans: HashMap<Uuid, Versioned<Loan>>,
+}
+
+impl MockLoanRepository {
+    pub fn new(has_error: bool) -> Self {
+        Self {
+            has_error,
+            loans: HashMap::new(),
+        }
+    }
+
+    pub fn with_loans(loans: Vec<Versioned<Loan>>) -> Self {
+        Self {
+            has_error: false,
+            loans: loans.into_iter()
+                .map(|loan| (loan.aggregate().id().value(), loan))
+                .collect(),
+        }
+    }
+}
+
+#[async_trait]
+impl LoanRepository for MockLoanRepository {
+    async fn load(&self, loan_id: Uuid) -> Result<Option<Versioned<Loan>>, LoanError> {
+        Ok(self.loans.get(&loan_id).cloned())
+    }
+
+    async fn append(&self, _loan_id: Uuid, expected_version: i32, events: &[LoanEvent]) -> Result<i32, LoanError> {
+        if self.has_error {
+            return Err(
+                LoanError::Infrastructure(
+                    InfrastructureError::Repository("Mock repository error".into())
+                )
+            );
+        }
+
+        Ok(expected_version + events.len() as i32)
+    }
+}
@@ -0,0 +1,8 @@
+pub mod error;
+pub mod events;
+pub mod loan;
+pub mod loan_direction;
+pub mod loan_repository;
+pub mod operation_source;
+pub mod repayment;
+pub mod schedule;
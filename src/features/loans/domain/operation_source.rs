@@ -0,0 +1,56 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use mockall::automock;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use crate::support::error::FeatureError;
+
+/// Operation a repayment is linked to, as far as loans are concerned.
+#[derive(Debug, Clone)]
+pub struct RepaymentOperation {
+    user_id: Uuid,
+    currency: String,
+    amount: Decimal,
+    sign: Decimal,
+    date: NaiveDate,
+}
+
+impl RepaymentOperation {
+    pub fn new(user_id: Uuid, currency: String, amount: Decimal, sign: Decimal, date: NaiveDate) -> Self {
+        Self {
+            user_id,
+            currency,
+            amount,
+            sign,
+            date,
+        }
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    /// Amount in the operation currency.
+    pub fn amount(&self) -> Decimal {
+        self.amount
+    }
+
+    /// Direction of the operation on its account, see `Kind::sign`.
+    pub fn sign(&self) -> Decimal {
+        self.sign
+    }
+
+    pub fn date(&self) -> &NaiveDate {
+        &self.date
+    }
+}
+
+#[async_trait]
+#[automock]
+pub trait OperationSource {
+    async fn operation(&self, operation_id: Uuid) -> Result<Option<RepaymentOperation>, FeatureError>;
+}
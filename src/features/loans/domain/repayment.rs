@@ -0,0 +1,33 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use crate::support::id::Id;
+
+/// Part of a loan paid back with an operation, in the loan currency.
+#[derive(Debug, Clone)]
+pub struct Repayment {
+    operation_id: Id,
+    amount: Decimal,
+    paid_on: NaiveDate,
+}
+
+impl Repayment {
+    pub fn new(operation_id: Id, amount: Decimal, paid_on: NaiveDate) -> Self {
+        Self {
+            operation_id,
+            amount,
+            paid_on,
+        }
+    }
+
+    pub fn operation_id(&self) -> &Id {
+        &self.operation_id
+    }
+
+    pub fn amount(&self) -> Decimal {
+        self.amount
+    }
+
+    pub fn paid_on(&self) -> &NaiveDate {
+        &self.paid_on
+    }
+}
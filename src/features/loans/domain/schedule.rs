@@ -0,0 +1,149 @@
+use chrono::{Months, NaiveDate};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use crate::support::money::round_money;
+
+/// Payment of a repayment schedule, due on the same day of the month as the loan was issued.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Installment {
+    number: u32,
+    due_on: NaiveDate,
+    principal: Decimal,
+    interest: Decimal,
+}
+
+impl Installment {
+    pub fn new(number: u32, due_on: NaiveDate, principal: Decimal, interest: Decimal) -> Self {
+        Self {
+            number,
+            due_on,
+            principal,
+            interest,
+        }
+    }
+
+    pub fn number(&self) -> u32 {
+        self.number
+    }
+
+    pub fn due_on(&self) -> &NaiveDate {
+        &self.due_on
+    }
+
+    pub fn principal(&self) -> Decimal {
+        self.principal
+    }
+
+    pub fn interest(&self) -> Decimal {
+        self.interest
+    }
+
+    pub fn amount(&self) -> Decimal {
+        self.principal + self.interest
+    }
+}
+
+/// Annuity schedule: equal monthly payments of interest on the remaining principal and a part of the principal.
+/// Amounts are rounded to `minor_units`, the last installment takes what rounding left of the principal.
+pub fn annuity_schedule(
+    principal: Decimal,
+    annual_interest_rate: Decimal,
+    term_months: u32,
+    issued_on: NaiveDate,
+    minor_units: u32,
+) -> Option<Vec<Installment>> {
+    let monthly_rate = annual_interest_rate / Decimal::from(1200);
+    let term = Decimal::from(term_months);
+
+    // Large principals at high rates over long terms do not fit in a decimal, the schedule is then not calculated
+    let payment = if monthly_rate.is_zero() {
+        principal.checked_div(term)?
+    } else {
+        let mut growth = Decimal::ONE;
+
+        for _ in 0..term_months {
+            growth = growth.checked_mul(Decimal::ONE + monthly_rate)?;
+        }
+
+        principal.checked_mul(monthly_rate)?
+            .checked_mul(growth)?
+            .checked_div(growth - Decimal::ONE)?
+    };
+    let payment = round_money(payment, minor_units);
+
+    let mut remaining = principal;
+    let mut installments = Vec::with_capacity(term_months as usize);
+
+    for number in 1..=term_months {
+        let interest = round_money(remaining * monthly_rate, minor_units);
+        let part = if number == term_months {
+            remaining
+        } else {
+            (payment - interest).min(remaining)
+        };
+
+        remaining -= part;
+        installments.push(
+            Installment::new(number, issued_on.checked_add_months(Months::new(number))?, part, interest)
+        );
+    }
+
+    Some(installments)
+}
+
+/// Sum of the installments due before `date`.
+pub fn due_before(installments: &[Installment], date: &NaiveDate) -> Decimal {
+    installments.iter()
+        .filter(|installment| installment.due_on < *date)
+        .map(Installment::amount)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_annuity_schedule() {
+        let issued_on = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+
+        let schedule = annuity_schedule(Decimal::from(1000), Decimal::from(12), 3, issued_on, 2).unwrap();
+
+        assert_eq!(schedule.len(), 3);
+        assert_eq!(schedule[0].due_on(), &NaiveDate::from_ymd_opt(2024, 2, 29).unwrap());
+        assert_eq!(schedule[0].interest(), Decimal::from(10));
+        assert_eq!(schedule[0].amount(), Decimal::new(34002, 2));
+        assert_eq!(schedule[1].amount(), Decimal::new(34002, 2));
+        assert_eq!(schedule.iter().map(Installment::principal).sum::<Decimal>(), Decimal::from(1000));
+    }
+
+    #[test]
+    fn test_annuity_schedule_with_extreme_terms() {
+        let issued_on = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+
+        let schedule = annuity_schedule(Decimal::from(1_000_000), Decimal::from(1000), 100, issued_on, 2);
+
+        assert!(schedule.is_none());
+    }
+
+    #[test]
+    fn test_interest_free_schedule() {
+        let issued_on = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+
+        let schedule = annuity_schedule(Decimal::from(100), Decimal::ZERO, 3, issued_on, 2).unwrap();
+
+        assert_eq!(
+            schedule.iter().map(Installment::amount).collect::<Vec<_>>(),
+            [Decimal::new(3333, 2), Decimal::new(3333, 2), Decimal::new(3334, 2)]
+        );
+    }
+
+    #[test]
+    fn test_due_before() {
+        let issued_on = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        let schedule = annuity_schedule(Decimal::from(300), Decimal::ZERO, 3, issued_on, 2).unwrap();
+
+        assert_eq!(due_before(&schedule, &NaiveDate::from_ymd_opt(2024, 4, 1).unwrap()), Decimal::ZERO);
+        assert_eq!(due_before(&schedule, &NaiveDate::from_ymd_opt(2024, 5, 2).unwrap()), Decimal::from(200));
+    }
+}
@@ -0,0 +1,12 @@
+use thiserror::Error;
+use crate::features::loans::domain::error::DomainError;
+use crate::features::loans::infrastructure::error::InfrastructureError;
+
+#[derive(Error, Debug, Clone)]
+pub enum LoanError {
+    #[error("Loan domain error: {0}")]
+    Domain(DomainError),
+
+    #[error("Loan infrastructure error: {0}")]
+    Infrastructure(InfrastructureError)
+}
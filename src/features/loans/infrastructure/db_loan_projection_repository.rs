@@ -0,0 +1,134 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use sqlx::{query, query_as, Pool, Postgres};
+use sqlx::postgres::PgArguments;
+use sqlx::query::Query;
+use sqlx::types::Json;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+use crate::db::manager::DbManager;
+use crate::features::loans::application::queries::loan_projection_repository::LoanProjectionRepository;
+use crate::features::loans::application::queries::loan_view::LoanView;
+use crate::features::loans::domain::events::loan_created::LoanCreated;
+use crate::features::loans::domain::events::loan_repayment_recorded::LoanRepaymentRecorded;
+use crate::features::loans::error::LoanError;
+use crate::features::loans::infrastructure::error::InfrastructureError;
+
+/// Loans with the repayments linked to them, oldest first.
+const SELECT_LOANS: &str = "
+    SELECT l.id,
+           l.user_id,
+           l.direction,
+           l.counterparty,
+           l.principal,
+           l.currency,
+           l.interest_rate,
+           l.issued_on,
+           l.schedule,
+           COALESCE(
+               (
+                   SELECT jsonb_agg(
+                       jsonb_build_object('operation_id', r.operation_id, 'amount', r.amount::TEXT, 'paid_on', r.paid_on)
+                       ORDER BY r.paid_on, r.id
+                   )
+                   FROM loan_repayments r
+                   WHERE r.loan_id = l.id
+               ),
+               '[]'::JSONB
+           ) AS repayments,
+           l.created_at
+    FROM loans l
+";
+
+#[derive(Clone)]
+pub struct DbLoanProjectionRepository {
+    db_manager: Arc<Mutex<DbManager>>,
+}
+
+impl DbLoanProjectionRepository {
+    pub fn new(db_manager: Arc<Mutex<DbManager>>) -> Self {
+        Self {
+            db_manager,
+        }
+    }
+
+    async fn pool(&self) -> Result<Pool<Postgres>, LoanError> {
+        let guard = self.db_manager.lock().await;
+
+        guard.pool()
+            .map_err(|e|
+                LoanError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to get pool: {}", e)
+                    )
+                )
+            )
+    }
+
+    async fn execute(&self, res_query: Query<'_, Postgres, PgArguments>) -> Result<(), LoanError> {
+        let pool = self.pool().await?;
+
+        res_query.execute(&pool)
+            .await
+            .map_err(|e|
+                LoanError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to project loan: {}", e)
+                    )
+                )
+            )?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl LoanProjectionRepository for DbLoanProjectionRepository {
+    async fn find(&self, user_id: Uuid) -> Result<Vec<LoanView>, LoanError> {
+        let q = format!("{} WHERE l.user_id = $1 ORDER BY l.issued_on, l.id", SELECT_LOANS);
+
+        let pool = self.pool().await?;
+
+        query_as::<_, LoanView>(&q)
+            .bind(user_id)
+            .fetch_all(&pool)
+            .await
+            .map_err(|e|
+                LoanError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to fetch loans: {}", e)
+                    )
+                )
+            )
+    }
+
+    async fn apply_loan_created(&self, event: &LoanCreated) -> Result<(), LoanError> {
+        let payload = event.payload();
+
+        self.execute(
+            query("INSERT INTO loans (id, user_id, direction, counterparty, principal, currency, interest_rate, issued_on, schedule) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9) ON CONFLICT DO NOTHING")
+                .bind(payload.id().value())
+                .bind(payload.user_id().value())
+                .bind(payload.direction().to_str().to_string())
+                .bind(payload.counterparty().to_string())
+                .bind(payload.principal())
+                .bind(payload.currency().code())
+                .bind(payload.interest_rate())
+                .bind(*payload.issued_on())
+                .bind(Json(payload.schedule().to_vec()))
+        ).await
+    }
+
+    async fn apply_loan_repayment_recorded(&self, event: &LoanRepaymentRecorded) -> Result<(), LoanError> {
+        let payload = event.payload();
+
+        self.execute(
+            query("INSERT INTO loan_repayments (id, loan_id, operation_id, amount, paid_on) VALUES ($1, $2, $3, $4, $5) ON CONFLICT DO NOTHING")
+                .bind(event.id().value())
+                .bind(payload.id().value())
+                .bind(payload.operation_id().value())
+                .bind(payload.amount())
+                .bind(*payload.paid_on())
+        ).await
+    }
+}
@@ -0,0 +1,64 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+use crate::db::manager::DbManager;
+use crate::features::loans::domain::loan::Loan;
+use crate::features::loans::domain::loan_repository::LoanRepository;
+use crate::features::loans::domain::events::loan_event::LoanEvent;
+use crate::features::loans::error::LoanError;
+use crate::features::loans::infrastructure::error::InfrastructureError;
+use crate::support::event_store::{Aggregate, EventStore, StorableEvent, Versioned};
+use crate::support::pg_event_store::PgEventStore;
+
+const EVENTS_TABLE: &str = "loan_events";
+
+#[derive(Clone)]
+pub struct DbLoanRepository {
+    event_store: PgEventStore,
+}
+
+impl DbLoanRepository {
+    pub fn new(db_manager: Arc<Mutex<DbManager>>) -> Self {
+        Self {
+            event_store: PgEventStore::new(db_manager, EVENTS_TABLE),
+        }
+    }
+}
+
+#[async_trait]
+impl LoanRepository for DbLoanRepository {
+    async fn load(&self, loan_id: Uuid) -> Result<Option<Versioned<Loan>>, LoanError> {
+        let stream = self.event_store.load(loan_id)
+            .await
+            .map_err(|e|
+                LoanError::Infrastructure(
+                    InfrastructureError::Repository(e.to_string())
+                )
+            )?;
+
+        Loan::rehydrate(&stream)
+            .map_err(|e|
+                LoanError::Infrastructure(
+                    InfrastructureError::Repository(e.to_string())
+                )
+            )
+    }
+
+    async fn append(&self, loan_id: Uuid, expected_version: i32, events: &[LoanEvent]) -> Result<i32, LoanError> {
+        let events = events.iter()
+            .map(|event| event.to_new())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e|
+                LoanError::Infrastructure(
+                    InfrastructureError::Repository(e.to_string())
+                )
+            )?;
+
+        self.event_store.append(loan_id, expected_version, &events)
+            .await
+            .map_err(|e|
+                LoanError::Infrastructure(e.into())
+            )
+    }
+}
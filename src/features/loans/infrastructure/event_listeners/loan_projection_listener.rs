@@ -0,0 +1,65 @@
+use async_trait::async_trait;
+use crate::events::error::EventError;
+use crate::events::event::Event;
+use crate::events::event_listener::EventListener;
+use crate::features::loans::application::queries::loan_projection_repository::LoanProjectionRepository;
+use crate::features::loans::domain::events::loan_event::LoanEvent;
+use crate::support::error::FeatureError;
+
+/// Keeps the `loans` read model in sync with the loan event stream.
+/// One instance is registered per loan event name.
+pub struct LoanProjectionListener<R>
+    where
+        R: LoanProjectionRepository + Send + Sync + 'static,
+{
+    rep: R,
+    event_name: &'static str,
+}
+
+#[async_trait]
+impl<R> EventListener for LoanProjectionListener<R>
+    where
+        R: LoanProjectionRepository + Send + Sync + 'static,
+{
+    async fn on_event(&mut self, event: Event) -> Result<Vec<Event>, EventError> {
+        let res = match self.parse_event(event)? {
+            LoanEvent::LoanCreated(event) => self.rep.apply_loan_created(&event).await,
+            LoanEvent::LoanRepaymentRecorded(event) => self.rep.apply_loan_repayment_recorded(&event).await,
+        };
+
+        res.map_err(|e|
+            EventError::Feature(
+                FeatureError::Loan(e)
+            )
+        )?;
+
+        Ok(vec![])
+    }
+
+    fn event_name(&self) -> &str {
+        self.event_name
+    }
+}
+
+impl<R> LoanProjectionListener<R>
+    where
+        R: LoanProjectionRepository + Send + Sync + 'static,
+{
+    pub fn new(rep: R, event_name: &'static str) -> Self {
+        Self {
+            rep,
+            event_name,
+        }
+    }
+
+    pub fn parse_event(&self, event: Event) -> Result<LoanEvent, EventError> {
+        match event {
+            Event::LoanEvent(loan_event) => Ok(loan_event),
+            _ => Err(
+                EventError::Parsing(
+                    format!("Invalid event type. Expected LoanEvent, got {:?}", event)
+                )
+            )
+        }
+    }
+}
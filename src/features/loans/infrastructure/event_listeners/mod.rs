@@ -0,0 +1 @@
+pub mod loan_projection_listener;
@@ -0,0 +1,5 @@
+pub mod db_loan_projection_repository;
+pub mod db_loan_repository;
+pub mod error;
+pub mod event_listeners;
+pub mod projection_operation_source;
@@ -0,0 +1,53 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use crate::features::loans::domain::operation_source::{OperationSource, RepaymentOperation};
+use crate::features::operations::application::queries::operation_projection_repository::OperationProjectionRepository;
+use crate::features::operations::domain::kind::Kind;
+use crate::features::operations::error::OperationError;
+use crate::support::error::FeatureError;
+
+/// Takes the operations linked to repayments from the operations projection.
+pub struct ProjectionOperationSource<R>
+    where
+        R: OperationProjectionRepository + Send + Sync,
+{
+    rep: R,
+}
+
+impl<R> ProjectionOperationSource<R>
+    where
+        R: OperationProjectionRepository + Send + Sync,
+{
+    pub fn new(rep: R) -> Self {
+        Self {
+            rep,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> OperationSource for ProjectionOperationSource<R>
+    where
+        R: OperationProjectionRepository + Send + Sync,
+{
+    async fn operation(&self, operation_id: Uuid) -> Result<Option<RepaymentOperation>, FeatureError> {
+        let operation = self.rep.find_by_id(operation_id)
+            .await
+            .map_err(FeatureError::Operation)?;
+
+        operation.map(|operation| {
+            let kind = Kind::new(operation.kind())
+                .map_err(|e| FeatureError::Operation(OperationError::Domain(e)))?;
+
+            Ok(
+                RepaymentOperation::new(
+                    *operation.user_id(),
+                    operation.currency().to_string(),
+                    operation.currency_amount(),
+                    kind.sign(),
+                    operation.created_at().date_naive(),
+                )
+            )
+        }).transpose()
+    }
+}
@@ -1,5 +1,15 @@
+pub mod accounts;
 pub mod auth;
 pub mod operations;
 pub mod categories;
 pub mod tags;
-pub mod balance;
\ No newline at end of file
+pub mod balance;
+pub mod rates;
+pub mod loans;
+pub mod recurrences;
+pub mod budgets;
+pub mod reports;
+pub mod imports;
+pub mod banking;
+pub mod rules;
+pub mod suggestions;
\ No newline at end of file
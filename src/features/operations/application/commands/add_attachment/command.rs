@@ -0,0 +1,43 @@
+use uuid::Uuid;
+use crate::support::command_bus::Command;
+
+#[derive(Debug, Clone)]
+pub struct AddAttachmentCommand {
+    operation_id: Uuid,
+    user_id: Uuid,
+    file_name: String,
+    content: Vec<u8>,
+}
+
+impl Command for AddAttachmentCommand {
+    fn name() -> &'static str {
+        "AddAttachmentCommand"
+    }
+}
+
+impl AddAttachmentCommand {
+    pub fn new(operation_id: Uuid, user_id: Uuid, file_name: String, content: Vec<u8>) -> Self {
+        Self {
+            operation_id,
+            user_id,
+            file_name,
+            content,
+        }
+    }
+
+    pub fn operation_id(&self) -> &Uuid {
+        &self.operation_id
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    pub fn file_name(&self) -> &str {
+        &self.file_name
+    }
+
+    pub fn content(&self) -> &[u8] {
+        &self.content
+    }
+}
@@ -0,0 +1,167 @@
+use async_trait::async_trait;
+use crate::events::event::Event;
+use crate::features::operations::application::commands::add_attachment::command::AddAttachmentCommand;
+use crate::features::operations::domain::attachment::Attachment;
+use crate::features::operations::domain::error::DomainError;
+use crate::features::operations::domain::events::operation_event::OperationEvent;
+use crate::features::operations::domain::operation_repository::OperationRepository;
+use crate::features::operations::error::OperationError;
+use crate::features::operations::infrastructure::error::InfrastructureError;
+use crate::services::storage::Storage;
+use crate::support::command_bus::CommandHandler;
+use crate::support::error::FeatureError;
+
+/// Stores the file first and records the attachment after, so a recorded attachment always has its content.
+pub struct AddAttachmentCommandHandler<R, S>
+    where
+        R: OperationRepository + Send + Sync,
+        S: Storage,
+{
+    rep: R,
+    storage: S,
+    max_size: usize,
+    content_types: Vec<String>,
+}
+
+impl<R, S> AddAttachmentCommandHandler<R, S>
+    where
+        R: OperationRepository + Send + Sync,
+        S: Storage,
+{
+    pub fn new(rep: R, storage: S, max_size: usize, content_types: Vec<String>) -> Self {
+        Self {
+            rep,
+            storage,
+            max_size,
+            content_types,
+        }
+    }
+}
+
+#[async_trait]
+impl<R, S> CommandHandler<AddAttachmentCommand> for AddAttachmentCommandHandler<R, S>
+    where
+        R: OperationRepository + Send + Sync,
+        S: Storage,
+{
+    async fn handle(&mut self, command: AddAttachmentCommand) -> Result<Vec<Event>, FeatureError> {
+        let operation = self.rep.load(*command.operation_id())
+            .await
+            .map_err(FeatureError::Operation)?
+            .ok_or(
+                FeatureError::Operation(
+                    OperationError::Domain(DomainError::OperationNotFound)
+                )
+            )?;
+
+        let operation_events = operation.aggregate().handle_attachment_addition(&command, self.max_size, &self.content_types)
+            .map_err(|e|
+                FeatureError::Operation(
+                    OperationError::Domain(e)
+                )
+            )?;
+
+        for event in &operation_events {
+            if let OperationEvent::AttachmentAdded(attachment_added) = event {
+                let key = Attachment::storage_key(command.operation_id(), &attachment_added.payload().attachment().id().value());
+
+                self.storage.put(&key, command.content())
+                    .await
+                    .map_err(|e|
+                        FeatureError::Operation(
+                            OperationError::Infrastructure(
+                                InfrastructureError::Storage(e.to_string())
+                            )
+                        )
+                    )?;
+            }
+        }
+
+        self.rep.append(operation.aggregate().id().value(), operation.version(), &operation_events)
+            .await
+            .map_err(FeatureError::Operation)?;
+
+        Ok(
+            operation_events.into_iter()
+                .map(Event::OperationEvent)
+                .collect()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::FutureExt;
+    use crate::features::operations::domain::operation_repository::MockOperationRepository;
+    use crate::features::operations::domain::operation::operation_update_tests::versioned_operation_fixture;
+    use crate::services::error::ServiceError;
+    use crate::services::storage::MockStorage;
+    use crate::support::id::Id;
+    use super::*;
+
+    const PDF: &[u8] = b"%PDF-1.7\n%receipt";
+
+    #[tokio::test]
+    async fn test_handle_success() {
+        let operation = versioned_operation_fixture();
+        let operation_id = operation.aggregate().id().value();
+        let command = AddAttachmentCommand::new(operation_id, operation.aggregate().user_id().value(), "receipt.pdf".to_string(), PDF.to_vec());
+
+        let mut storage = MockStorage::new();
+        storage.expect_put()
+            .withf(move |key, content| key.starts_with(&format!("operations/{}/attachments/", operation_id)) && content == PDF)
+            .times(1)
+            .returning(|_, _| async { Ok(()) }.boxed());
+
+        let mut handler = AddAttachmentCommandHandler::new(MockOperationRepository::with_operation(operation), storage, 1024, content_types_fixture());
+
+        let events = handler.handle(command).await.unwrap();
+
+        assert_eq!(events.len(), 1);
+        match &events[0] {
+            Event::OperationEvent(OperationEvent::AttachmentAdded(added)) => {
+                assert_eq!(added.payload().attachment().file_name(), "receipt.pdf");
+                assert_eq!(added.payload().attachment().content_type(), "application/pdf");
+                assert_eq!(added.payload().attachment().size(), PDF.len() as u64);
+            }
+            _ => panic!("Unexpected event type"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handle_invalid_file_is_not_stored() {
+        let operation = versioned_operation_fixture();
+        let user_id = operation.aggregate().user_id().value();
+        let operation_id = operation.aggregate().id().value();
+
+        let mut storage = MockStorage::new();
+        storage.expect_put().never();
+
+        let mut handler = AddAttachmentCommandHandler::new(MockOperationRepository::with_operation(operation), storage, 8, content_types_fixture());
+
+        let too_large = AddAttachmentCommand::new(operation_id, user_id, "receipt.pdf".to_string(), PDF.to_vec());
+        let foreign = AddAttachmentCommand::new(operation_id, Id::generate(), "receipt.pdf".to_string(), b"%PDF-".to_vec());
+
+        assert!(matches!(handler.handle(too_large).await, Err(FeatureError::Operation(OperationError::Domain(DomainError::InvalidAttachment(_))))));
+        assert!(matches!(handler.handle(foreign).await, Err(FeatureError::Operation(OperationError::Domain(DomainError::AccessDenied)))));
+    }
+
+    #[tokio::test]
+    async fn test_handle_storage_error() {
+        let operation = versioned_operation_fixture();
+        let command = AddAttachmentCommand::new(operation.aggregate().id().value(), operation.aggregate().user_id().value(), "receipt.pdf".to_string(), PDF.to_vec());
+
+        let mut storage = MockStorage::new();
+        storage.expect_put().returning(|_, _| async { Err(ServiceError::Storage("Disk is full".to_string())) }.boxed());
+
+        let mut handler = AddAttachmentCommandHandler::new(MockOperationRepository::with_operation(operation), storage, 1024, content_types_fixture());
+
+        let res = handler.handle(command).await;
+
+        assert!(matches!(res, Err(FeatureError::Operation(OperationError::Infrastructure(InfrastructureError::Storage(_))))));
+    }
+
+    fn content_types_fixture() -> Vec<String> {
+        vec!["image/jpeg".to_string(), "application/pdf".to_string()]
+    }
+}
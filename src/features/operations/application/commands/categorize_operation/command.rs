@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::support::command_bus::Command;
+
+/// Moves an operation to `category_id` and adds `tag_ids` to its tags, e.g. when a rule matches it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CategorizeOperationCommand {
+    operation_id: Uuid,
+    user_id: Uuid,
+    category_id: Uuid,
+    tag_ids: Vec<Uuid>,
+}
+
+impl Command for CategorizeOperationCommand {
+    fn name() -> &'static str {
+        "CategorizeOperationCommand"
+    }
+}
+
+impl CategorizeOperationCommand {
+    pub fn new(operation_id: Uuid, user_id: Uuid, category_id: Uuid, tag_ids: Vec<Uuid>) -> Self {
+        Self {
+            operation_id,
+            user_id,
+            category_id,
+            tag_ids,
+        }
+    }
+
+    pub fn operation_id(&self) -> &Uuid {
+        &self.operation_id
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    pub fn category_id(&self) -> &Uuid {
+        &self.category_id
+    }
+
+    pub fn tag_ids(&self) -> &[Uuid] {
+        &self.tag_ids
+    }
+}
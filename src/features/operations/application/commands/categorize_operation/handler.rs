@@ -0,0 +1,101 @@
+use async_trait::async_trait;
+use crate::events::event::Event;
+use crate::features::operations::application::commands::categorize_operation::command::CategorizeOperationCommand;
+use crate::features::operations::domain::error::DomainError;
+use crate::features::operations::domain::operation_repository::OperationRepository;
+use crate::features::operations::error::OperationError;
+use crate::support::command_bus::CommandHandler;
+use crate::support::error::FeatureError;
+
+pub struct CategorizeOperationCommandHandler<R>
+    where
+        R: OperationRepository + Send + Sync,
+{
+    rep: R,
+}
+
+impl<R> CategorizeOperationCommandHandler<R>
+    where
+        R: OperationRepository + Send + Sync,
+{
+    pub fn new(rep: R) -> Self {
+        Self {
+            rep,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> CommandHandler<CategorizeOperationCommand> for CategorizeOperationCommandHandler<R>
+    where
+        R: OperationRepository + Send + Sync,
+{
+    async fn handle(&mut self, command: CategorizeOperationCommand) -> Result<Vec<Event>, FeatureError> {
+        let operation = self.rep.load(*command.operation_id())
+            .await
+            .map_err(FeatureError::Operation)?
+            .ok_or(
+                FeatureError::Operation(
+                    OperationError::Domain(DomainError::OperationNotFound)
+                )
+            )?;
+
+        let operation_events = operation.aggregate().handle_categorization(command)
+            .map_err(|e|
+                FeatureError::Operation(
+                    OperationError::Domain(e)
+                )
+            )?;
+
+        if operation_events.is_empty() {
+            return Ok(vec![]);
+        }
+
+        self.rep.append(operation.aggregate().id().value(), operation.version(), &operation_events)
+            .await
+            .map_err(FeatureError::Operation)?;
+
+        Ok(
+            operation_events.into_iter()
+                .map(Event::OperationEvent)
+                .collect()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::features::operations::domain::events::operation_event::OperationEvent;
+    use crate::features::operations::domain::operation_repository::MockOperationRepository;
+    use crate::features::operations::domain::operation::operation_update_tests::versioned_operation_fixture;
+    use crate::support::id::Id;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle_success() {
+        let operation = versioned_operation_fixture();
+        let command = CategorizeOperationCommand::new(
+            operation.aggregate().id().value(),
+            operation.aggregate().user_id().value(),
+            Id::generate(),
+            vec![],
+        );
+        let mut handler = CategorizeOperationCommandHandler::new(MockOperationRepository::with_operation(operation));
+
+        let events = handler.handle(command).await.unwrap();
+
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], Event::OperationEvent(OperationEvent::OperationUpdated(_))));
+    }
+
+    #[tokio::test]
+    async fn test_handle_foreign_operation() {
+        let operation = versioned_operation_fixture();
+        let command = CategorizeOperationCommand::new(operation.aggregate().id().value(), Id::generate(), Id::generate(), vec![]);
+        let mut handler = CategorizeOperationCommandHandler::new(MockOperationRepository::with_operation(operation));
+
+        let res = handler.handle(command).await;
+
+        assert!(matches!(res, Err(FeatureError::Operation(OperationError::Domain(DomainError::AccessDenied)))));
+    }
+}
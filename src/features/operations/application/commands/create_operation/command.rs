@@ -1,19 +1,29 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use crate::support::command_bus::Command;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateOperationCommand {
+    #[serde(default)]
+    operation_id: Option<Uuid>,
+    #[serde(default)]
+    created_at: Option<DateTime<Utc>>,
     kind: String,
     user_id: Uuid,
+    account_id: Uuid,
     category_id: Option<Uuid>,
     category_name: String,
-    amount: f64,
+    amount: Option<Decimal>,
     currency: String,
-    currency_amount: f64,
-    rate: f64,
+    currency_amount: Decimal,
+    rate: Option<Decimal>,
     label: String,
     tags: Vec<TagData>,
+    transfer: Option<TransferData>,
+    #[serde(default)]
+    lines: Vec<LineData>,
 }
 
 impl Command for CreateOperationCommand {
@@ -23,21 +33,26 @@ impl Command for CreateOperationCommand {
 }
 
 impl CreateOperationCommand {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         kind: String,
         user_id: Uuid,
+        account_id: Uuid,
         category_id: Option<Uuid>,
         category_name: String,
-        amount: f64,
+        amount: Option<Decimal>,
         currency: String,
-        currency_amount: f64,
-        rate: f64,
+        currency_amount: Decimal,
+        rate: Option<Decimal>,
         label: String,
         tags: Vec<TagData>,
     ) -> Self {
         Self {
+            operation_id: None,
+            created_at: None,
             kind,
             user_id,
+            account_id,
             category_id,
             category_name,
             amount,
@@ -46,6 +61,34 @@ impl CreateOperationCommand {
             rate,
             label,
             tags,
+            transfer: None,
+            lines: vec![],
+        }
+    }
+
+    /// Id the operation is created with, generated when omitted. A retried creation with the same id
+    /// fails with a conflict instead of creating the operation twice.
+    pub fn operation_id(&self) -> &Option<Uuid> {
+        &self.operation_id
+    }
+
+    pub fn with_operation_id(self, operation_id: Uuid) -> Self {
+        Self {
+            operation_id: Some(operation_id),
+            ..self
+        }
+    }
+
+    /// Date the operation is booked on, the time of its creation when omitted.
+    /// Imported operations keep the date of their statement.
+    pub fn created_at(&self) -> Option<DateTime<Utc>> {
+        self.created_at
+    }
+
+    pub fn with_created_at(self, created_at: DateTime<Utc>) -> Self {
+        Self {
+            created_at: Some(created_at),
+            ..self
         }
     }
 
@@ -57,6 +100,10 @@ impl CreateOperationCommand {
         &self.user_id
     }
 
+    pub fn account_id(&self) -> &Uuid {
+        &self.account_id
+    }
+
     pub fn category_id(&self) -> &Option<Uuid> {
         &self.category_id
     }
@@ -65,7 +112,8 @@ impl CreateOperationCommand {
         &self.category_name
     }
 
-    pub fn amount(&self) -> f64 {
+    /// Amount in the base currency, derived from the currency amount and the rate when omitted.
+    pub fn amount(&self) -> Option<Decimal> {
         self.amount
     }
 
@@ -73,14 +121,22 @@ impl CreateOperationCommand {
         &self.currency
     }
 
-    pub fn currency_amount(&self) -> f64 {
+    pub fn currency_amount(&self) -> Decimal {
         self.currency_amount
     }
 
-    pub fn rate(&self) -> f64 {
+    /// Rate of the currency to the base currency, looked up for the operation date when omitted.
+    pub fn rate(&self) -> Option<Decimal> {
         self.rate
     }
 
+    pub fn with_rate(self, rate: Decimal) -> Self {
+        Self {
+            rate: Some(rate),
+            ..self
+        }
+    }
+
     pub fn label(&self) -> &str {
         &self.label
     }
@@ -88,6 +144,48 @@ impl CreateOperationCommand {
     pub fn tags(&self) -> &[TagData] {
         &self.tags
     }
+
+    /// Books the operation to `category_id` and adds the tags of `tag_ids` it does not have yet.
+    /// Tags given by id need no name.
+    pub fn with_categorization(self, category_id: Uuid, tag_ids: &[Uuid]) -> Self {
+        let mut tags = self.tags;
+
+        for tag_id in tag_ids {
+            if !tags.iter().any(|tag| tag.id() == &Some(*tag_id)) {
+                tags.push(TagData::new(Some(*tag_id), String::new()));
+            }
+        }
+
+        Self {
+            category_id: Some(category_id),
+            tags,
+            ..self
+        }
+    }
+
+    /// Destination of a transfer, only transfers have one.
+    pub fn transfer(&self) -> &Option<TransferData> {
+        &self.transfer
+    }
+
+    pub fn with_transfer(self, transfer: TransferData) -> Self {
+        Self {
+            transfer: Some(transfer),
+            ..self
+        }
+    }
+
+    /// Lines of a split operation, an operation without lines is booked to its category as a whole.
+    pub fn lines(&self) -> &[LineData] {
+        &self.lines
+    }
+
+    pub fn with_lines(self, lines: Vec<LineData>) -> Self {
+        Self {
+            lines,
+            ..self
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -111,4 +209,95 @@ impl TagData {
     pub fn name(&self) -> &str {
         &self.name
     }
-}
\ No newline at end of file
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransferData {
+    account_id: Uuid,
+    amount: Option<Decimal>,
+    currency: Option<String>,
+    rate: Option<Decimal>,
+}
+
+impl TransferData {
+    pub fn new(account_id: Uuid, amount: Option<Decimal>) -> Self {
+        Self {
+            account_id,
+            amount,
+            currency: None,
+            rate: None,
+        }
+    }
+
+    pub fn account_id(&self) -> &Uuid {
+        &self.account_id
+    }
+
+    /// Amount credited to the destination account, in its currency.
+    pub fn amount(&self) -> Option<Decimal> {
+        self.amount
+    }
+
+    /// Currency of the destination account, resolved by the command handler.
+    pub fn currency(&self) -> Option<&str> {
+        self.currency.as_deref()
+    }
+
+    pub fn with_currency(self, currency: String) -> Self {
+        Self {
+            currency: Some(currency),
+            ..self
+        }
+    }
+
+    /// Rate of the destination currency to the base currency, used when the credited amount is omitted.
+    pub fn rate(&self) -> Option<Decimal> {
+        self.rate
+    }
+
+    pub fn with_rate(self, rate: Decimal) -> Self {
+        Self {
+            rate: Some(rate),
+            ..self
+        }
+    }
+}
+
+/// Line of a split operation. Lines refer to existing categories and tags by id.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LineData {
+    category_id: Uuid,
+    currency_amount: Decimal,
+    #[serde(default)]
+    label: String,
+    #[serde(default)]
+    tag_ids: Vec<Uuid>,
+}
+
+impl LineData {
+    pub fn new(category_id: Uuid, currency_amount: Decimal, label: String, tag_ids: Vec<Uuid>) -> Self {
+        Self {
+            category_id,
+            currency_amount,
+            label,
+            tag_ids,
+        }
+    }
+
+    pub fn category_id(&self) -> &Uuid {
+        &self.category_id
+    }
+
+    /// Part of the currency amount of the operation.
+    pub fn currency_amount(&self) -> Decimal {
+        self.currency_amount
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub fn tag_ids(&self) -> &[Uuid] {
+        &self.tag_ids
+    }
+}
@@ -1,29 +1,46 @@
 use std::error::Error;
 use async_trait::async_trait;
+use chrono::Utc;
 use crate::events::event::Event;
 use crate::features::operations::application::commands::create_operation::command::CreateOperationCommand;
+use crate::features::operations::domain::account_source::AccountSource;
+use crate::features::operations::domain::categorization_source::CategorizationSource;
+use crate::features::operations::domain::error::DomainError;
 use crate::features::operations::domain::events::operation_event::OperationEvent;
 use crate::features::operations::domain::operation::Operation;
 use crate::features::operations::domain::operation_repository::OperationRepository;
 use crate::features::operations::error::OperationError;
+use crate::features::rates::domain::rate_source::RateSource;
 use crate::support::command_bus::CommandHandler;
 use crate::support::error::FeatureError;
 
 #[derive(Debug)]
-pub struct CreateOperationCommandHandler<R>
+pub struct CreateOperationCommandHandler<R, S, A, C>
     where
         R: OperationRepository + Send + Sync,
+        S: RateSource + Send + Sync,
+        A: AccountSource + Send + Sync,
+        C: CategorizationSource + Send + Sync,
 {
     rep: R,
+    rates: S,
+    accounts: A,
+    categorizations: C,
 }
 
-impl<R> CreateOperationCommandHandler<R>
+impl<R, S, A, C> CreateOperationCommandHandler<R, S, A, C>
     where
         R: OperationRepository + Send + Sync,
+        S: RateSource + Send + Sync,
+        A: AccountSource + Send + Sync,
+        C: CategorizationSource + Send + Sync,
 {
-    pub fn new(rep: R) -> Self {
+    pub fn new(rep: R, rates: S, accounts: A, categorizations: C) -> Self {
         Self {
             rep,
+            rates,
+            accounts,
+            categorizations,
         }
     }
 
@@ -33,11 +50,90 @@ impl<R> CreateOperationCommandHandler<R>
 }
 
 #[async_trait]
-impl<R> CommandHandler<CreateOperationCommand> for CreateOperationCommandHandler<R>
+impl<R, S, A, C> CommandHandler<CreateOperationCommand> for CreateOperationCommandHandler<R, S, A, C>
     where
         R: OperationRepository + Send + Sync,
+        S: RateSource + Send + Sync,
+        A: AccountSource + Send + Sync,
+        C: CategorizationSource + Send + Sync,
 {
     async fn handle(&mut self, command: CreateOperationCommand) -> Result<Vec<Event>, FeatureError> {
+        self.accounts.account(*command.account_id())
+            .await?
+            .ok_or(
+                FeatureError::Operation(
+                    OperationError::Domain(DomainError::AccountNotFound)
+                )
+            )?
+            .check(command.user_id(), command.currency())
+            .map_err(|e|
+                FeatureError::Operation(
+                    OperationError::Domain(e)
+                )
+            )?;
+
+        // Rules only categorize operations whose category is not chosen by id,
+        // so a matching rule replaces the category that would otherwise be requested by name
+        let command = match command.category_id() {
+            Some(_) => command,
+            None => {
+                let categorization = self.categorizations.categorization(
+                    *command.user_id(),
+                    command.kind(),
+                    command.label(),
+                    command.currency(),
+                    command.currency_amount(),
+                ).await?;
+
+                match categorization {
+                    Some(categorization) => command.with_categorization(*categorization.category_id(), categorization.tag_ids()),
+                    None => command,
+                }
+            }
+        };
+
+        // Rates are taken for the day the operation is booked on
+        let booked_on = command.created_at().unwrap_or_else(Utc::now).date_naive();
+
+        let command = match command.transfer().clone() {
+            Some(transfer) => {
+                let destination = self.accounts.account(*transfer.account_id())
+                    .await?
+                    .ok_or(
+                        FeatureError::Operation(
+                            OperationError::Domain(DomainError::AccountNotFound)
+                        )
+                    )?;
+
+                destination.check_owner(command.user_id())
+                    .map_err(|e|
+                        FeatureError::Operation(
+                            OperationError::Domain(e)
+                        )
+                    )?;
+
+                let transfer = transfer.with_currency(destination.currency().to_string());
+                let transfer = match transfer.amount() {
+                    None if destination.currency() != command.currency() => {
+                        let rate = self.rates.rate(destination.currency(), booked_on).await?;
+                        transfer.with_rate(rate)
+                    }
+                    _ => transfer,
+                };
+
+                command.with_transfer(transfer)
+            }
+            None => command,
+        };
+
+        let command = match command.rate() {
+            Some(_) => command,
+            None => {
+                let rate = self.rates.rate(command.currency(), booked_on).await?;
+                command.with_rate(rate)
+            }
+        };
+
         let mut events = vec![];
         let operation_events = Operation::handle_creation(command)
             .map_err(|e|
@@ -71,7 +167,14 @@ impl<R> CommandHandler<CreateOperationCommand> for CreateOperationCommandHandler
 
 #[cfg(test)]
 mod tests {
+    use futures_util::FutureExt;
+    use rust_decimal::Decimal;
+    use uuid::Uuid;
+    use crate::features::operations::application::commands::create_operation::command::TransferData;
+    use crate::features::operations::domain::account_source::{MockAccountSource, OperationAccount};
+    use crate::features::operations::domain::categorization_source::{MockCategorizationSource, OperationCategorization};
     use crate::features::operations::domain::operation_repository::MockOperationRepository;
+    use crate::features::rates::domain::rate_source::MockRateSource;
     use crate::support::id::Id;
     use super::*;
 
@@ -80,7 +183,8 @@ mod tests {
         let rep = MockOperationRepository::new(false);
 
         let command = command_fixture();
-        let mut handler = CreateOperationCommandHandler::new(rep);
+        let accounts = accounts_fixture(*command.user_id(), "USD");
+        let mut handler = CreateOperationCommandHandler::new(rep, MockRateSource::new(), accounts, MockCategorizationSource::new());
 
         let res = handler.handle(command).await;
 
@@ -92,23 +196,204 @@ mod tests {
         let rep = MockOperationRepository::new(true);
 
         let command = command_fixture();
-        let mut handler = CreateOperationCommandHandler::new(rep);
+        let accounts = accounts_fixture(*command.user_id(), "USD");
+        let mut handler = CreateOperationCommandHandler::new(rep, MockRateSource::new(), accounts, MockCategorizationSource::new());
 
         let res = handler.handle(command).await;
 
         assert!(res.is_err());
     }
 
+    #[tokio::test]
+    async fn test_handle_looks_up_missing_rate() {
+        let rep = MockOperationRepository::new(false);
+        let mut rates = MockRateSource::new();
+        rates.expect_rate()
+            .withf(|currency, _| currency == "EUR")
+            .times(1)
+            .returning(|_, _| async { Ok(Decimal::new(108, 2)) }.boxed());
+
+        let command = CreateOperationCommand::new(
+            String::from("Expense"),
+            Id::generate(),
+            Id::generate(),
+            Some(Id::generate()),
+            String::from("Food"),
+            None,
+            String::from("EUR"),
+            Decimal::from(50),
+            None,
+            String::from("Grocery Shopping"),
+            vec![],
+        );
+        let accounts = accounts_fixture(*command.user_id(), "EUR");
+        let mut handler = CreateOperationCommandHandler::new(rep, rates, accounts, MockCategorizationSource::new());
+
+        let events = handler.handle(command).await.unwrap();
+
+        match &events[0] {
+            Event::OperationEvent(OperationEvent::OperationCreated(data)) => {
+                assert_eq!(data.payload().amount().value(), Decimal::from(54));
+                assert_eq!(data.payload().rate().value(), Decimal::new(108, 2));
+            }
+            _ => panic!("Unexpected event type"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handle_keeps_given_rate() {
+        let rep = MockOperationRepository::new(false);
+        let mut rates = MockRateSource::new();
+        rates.expect_rate().never();
+
+        let command = command_fixture();
+        let accounts = accounts_fixture(*command.user_id(), "USD");
+        let mut handler = CreateOperationCommandHandler::new(rep, rates, accounts, MockCategorizationSource::new());
+
+        let res = handler.handle(command).await;
+
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_handle_missing_account() {
+        let mut accounts = MockAccountSource::new();
+        accounts.expect_account()
+            .returning(|_| async { Ok(None) }.boxed());
+        let mut handler = CreateOperationCommandHandler::new(MockOperationRepository::new(false), MockRateSource::new(), accounts, MockCategorizationSource::new());
+
+        let res = handler.handle(command_fixture()).await;
+
+        assert!(matches!(res, Err(FeatureError::Operation(OperationError::Domain(DomainError::AccountNotFound)))));
+    }
+
+    #[tokio::test]
+    async fn test_handle_account_in_other_currency() {
+        let command = command_fixture();
+        let accounts = accounts_fixture(*command.user_id(), "EUR");
+        let mut handler = CreateOperationCommandHandler::new(MockOperationRepository::new(false), MockRateSource::new(), accounts, MockCategorizationSource::new());
+
+        let res = handler.handle(command).await;
+
+        assert!(matches!(res, Err(FeatureError::Operation(OperationError::Domain(DomainError::CurrencyMismatch(_))))));
+    }
+
+    #[tokio::test]
+    async fn test_handle_transfer_to_account_in_other_currency() {
+        let user_id = Id::generate();
+        let source_id = Id::generate();
+        let destination_id = Id::generate();
+
+        let mut accounts = MockAccountSource::new();
+        accounts.expect_account()
+            .returning(move |account_id| {
+                let currency = if account_id == destination_id { "EUR" } else { "USD" };
+                async move { Ok(Some(OperationAccount::new(user_id, currency.to_string()))) }.boxed()
+            });
+        let mut rates = MockRateSource::new();
+        rates.expect_rate()
+            .withf(|currency, _| currency == "EUR")
+            .times(1)
+            .returning(|_, _| async { Ok(Decimal::new(125, 2)) }.boxed());
+
+        let command = CreateOperationCommand::new(
+            String::from("Transfer"),
+            user_id,
+            source_id,
+            Some(Id::generate()),
+            String::from("Transfers"),
+            None,
+            String::from("USD"),
+            Decimal::from(100),
+            Some(Decimal::ONE),
+            String::from("To savings"),
+            vec![],
+        ).with_transfer(TransferData::new(destination_id, None));
+        let mut handler = CreateOperationCommandHandler::new(MockOperationRepository::new(false), rates, accounts, MockCategorizationSource::new());
+
+        let events = handler.handle(command).await.unwrap();
+
+        match &events[0] {
+            Event::OperationEvent(OperationEvent::OperationCreated(data)) => {
+                let transfer = data.payload().transfer().as_ref().unwrap();
+
+                assert_eq!(transfer.account_id().value(), destination_id);
+                assert_eq!(transfer.amount().value(), Decimal::from(80));
+            }
+            _ => panic!("Unexpected event type"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handle_applies_matching_rule() {
+        let category_id = Id::generate();
+        let tag_id = Id::generate();
+
+        let mut categorizations = MockCategorizationSource::new();
+        categorizations.expect_categorization()
+            .withf(|_, kind, label, currency, _| kind == "Expense" && label == "Coffee shop" && currency == "USD")
+            .times(1)
+            .returning(move |_, _, _, _, _| async move { Ok(Some(OperationCategorization::new(category_id, vec![tag_id]))) }.boxed());
+
+        let command = CreateOperationCommand::new(
+            String::from("Expense"),
+            Id::generate(),
+            Id::generate(),
+            None,
+            String::from("Imported"),
+            None,
+            String::from("USD"),
+            Decimal::from(4),
+            Some(Decimal::ONE),
+            String::from("Coffee shop"),
+            vec![],
+        );
+        let accounts = accounts_fixture(*command.user_id(), "USD");
+        let mut handler = CreateOperationCommandHandler::new(MockOperationRepository::new(false), MockRateSource::new(), accounts, categorizations);
+
+        let events = handler.handle(command).await.unwrap();
+
+        assert_eq!(events.len(), 1);
+        match &events[0] {
+            Event::OperationEvent(OperationEvent::OperationCreated(data)) => {
+                assert_eq!(data.payload().category_id().value(), category_id);
+                assert_eq!(data.payload().tag_ids().len(), 1);
+            }
+            _ => panic!("Unexpected event type"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handle_keeps_given_category() {
+        let mut categorizations = MockCategorizationSource::new();
+        categorizations.expect_categorization().never();
+
+        let command = command_fixture();
+        let accounts = accounts_fixture(*command.user_id(), "USD");
+        let mut handler = CreateOperationCommandHandler::new(MockOperationRepository::new(false), MockRateSource::new(), accounts, categorizations);
+
+        assert!(handler.handle(command).await.is_ok());
+    }
+
+    fn accounts_fixture(user_id: Uuid, currency: &'static str) -> MockAccountSource {
+        let mut accounts = MockAccountSource::new();
+        accounts.expect_account()
+            .returning(move |_| async move { Ok(Some(OperationAccount::new(user_id, currency.to_string()))) }.boxed());
+
+        accounts
+    }
+
     fn command_fixture() -> CreateOperationCommand {
         CreateOperationCommand::new(
             String::from("Income"),
             Id::generate(),
+            Id::generate(),
             Some(Id::generate()),
             String::from("Food"),
-            100.0,
+            Some(Decimal::from(100)),
             String::from("USD"),
-            100.0,
-            1.0,
+            Decimal::from(100),
+            Some(Decimal::ONE),
             String::from("Grocery Shopping"),
             vec![],
         )
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::support::command_bus::Command;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteOperationCommand {
+    operation_id: Uuid,
+    user_id: Uuid,
+}
+
+impl Command for DeleteOperationCommand {
+    fn name() -> &'static str {
+        "DeleteOperationCommand"
+    }
+}
+
+impl DeleteOperationCommand {
+    pub fn new(operation_id: Uuid, user_id: Uuid) -> Self {
+        Self {
+            operation_id,
+            user_id,
+        }
+    }
+
+    pub fn operation_id(&self) -> &Uuid {
+        &self.operation_id
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+}
@@ -0,0 +1,3 @@
+pub mod command;
+
+pub mod handler;
\ No newline at end of file
@@ -1 +1,9 @@
-pub mod create_operation;
\ No newline at end of file
+pub mod add_attachment;
+pub mod categorize_operation;
+pub mod create_operation;
+pub mod delete_operation;
+pub mod reassign_category;
+pub mod replace_tag;
+pub mod reuse_category;
+pub mod reuse_tag;
+pub mod update_operation;
\ No newline at end of file
@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::support::command_bus::Command;
+
+/// Moves every operation of `category_id` to `fallback_category_id`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReassignCategoryCommand {
+    user_id: Uuid,
+    category_id: Uuid,
+    fallback_category_id: Uuid,
+}
+
+impl Command for ReassignCategoryCommand {
+    fn name() -> &'static str {
+        "ReassignCategoryCommand"
+    }
+}
+
+impl ReassignCategoryCommand {
+    pub fn new(user_id: Uuid, category_id: Uuid, fallback_category_id: Uuid) -> Self {
+        Self {
+            user_id,
+            category_id,
+            fallback_category_id,
+        }
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    pub fn category_id(&self) -> &Uuid {
+        &self.category_id
+    }
+
+    pub fn fallback_category_id(&self) -> &Uuid {
+        &self.fallback_category_id
+    }
+}
@@ -0,0 +1,112 @@
+use async_trait::async_trait;
+use crate::events::event::Event;
+use crate::features::operations::application::commands::reassign_category::command::ReassignCategoryCommand;
+use crate::features::operations::application::queries::operation_projection_repository::OperationProjectionRepository;
+use crate::features::operations::domain::operation_repository::OperationRepository;
+use crate::features::operations::error::OperationError;
+use crate::support::command_bus::CommandHandler;
+use crate::support::error::FeatureError;
+use crate::support::id::Id;
+
+pub struct ReassignCategoryCommandHandler<R, P>
+    where
+        R: OperationRepository + Send + Sync,
+        P: OperationProjectionRepository + Send + Sync,
+{
+    rep: R,
+    projection_rep: P,
+}
+
+impl<R, P> ReassignCategoryCommandHandler<R, P>
+    where
+        R: OperationRepository + Send + Sync,
+        P: OperationProjectionRepository + Send + Sync,
+{
+    pub fn new(rep: R, projection_rep: P) -> Self {
+        Self {
+            rep,
+            projection_rep,
+        }
+    }
+}
+
+#[async_trait]
+impl<R, P> CommandHandler<ReassignCategoryCommand> for ReassignCategoryCommandHandler<R, P>
+    where
+        R: OperationRepository + Send + Sync,
+        P: OperationProjectionRepository + Send + Sync,
+{
+    async fn handle(&mut self, command: ReassignCategoryCommand) -> Result<Vec<Event>, FeatureError> {
+        let operation_ids = self.projection_rep.find_ids_by_category(*command.user_id(), *command.category_id())
+            .await
+            .map_err(FeatureError::Operation)?;
+
+        let mut events = vec![];
+
+        for operation_id in operation_ids {
+            let operation = match self.rep.load(operation_id).await.map_err(FeatureError::Operation)? {
+                Some(operation) => operation,
+                None => continue,
+            };
+
+            // The read model may lag behind the streams, skip operations that have already moved
+            if operation.aggregate().is_deleted()
+                || operation.aggregate().user_id().value() != *command.user_id()
+                || !operation.aggregate().has_category(&Id::new(*command.category_id())) {
+                continue;
+            }
+
+            let operation_events = operation.aggregate().handle_category_replacement(&Id::new(*command.category_id()), Id::new(*command.fallback_category_id()))
+                .map_err(|e|
+                    FeatureError::Operation(
+                        OperationError::Domain(e)
+                    )
+                )?;
+
+            self.rep.append(operation_id, operation.version(), &operation_events)
+                .await
+                .map_err(FeatureError::Operation)?;
+
+            events.extend(operation_events.into_iter().map(Event::OperationEvent));
+        }
+
+        Ok(events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::FutureExt;
+    use crate::features::operations::application::queries::operation_projection_repository::MockOperationProjectionRepository;
+    use crate::features::operations::domain::events::operation_event::OperationEvent;
+    use crate::features::operations::domain::operation::operation_update_tests::versioned_operation_fixture;
+    use crate::features::operations::domain::operation_repository::MockOperationRepository;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle_moves_operations() {
+        let operation = versioned_operation_fixture();
+        let operation_id = operation.aggregate().id().value();
+        let command = ReassignCategoryCommand::new(
+            operation.aggregate().user_id().value(),
+            operation.aggregate().category_id().value(),
+            Id::generate(),
+        );
+
+        let mut projection_rep = MockOperationProjectionRepository::new();
+        projection_rep.expect_find_ids_by_category()
+            .times(1)
+            .returning(move |_, _| async move { Ok(vec![operation_id]) }.boxed());
+
+        let mut handler = ReassignCategoryCommandHandler::new(MockOperationRepository::with_operation(operation), projection_rep);
+        let events = handler.handle(command.clone()).await.unwrap();
+
+        assert_eq!(events.len(), 1);
+        match &events[0] {
+            Event::OperationEvent(OperationEvent::OperationUpdated(operation_updated)) => {
+                assert_eq!(operation_updated.payload().category_id().value(), *command.fallback_category_id());
+            }
+            _ => panic!("Expected OperationUpdated event"),
+        }
+    }
+}
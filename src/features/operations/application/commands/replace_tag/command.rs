@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::support::command_bus::Command;
+
+/// Replaces `tag_id` with `replacement_tag_id` in every operation of the user, or just removes it when there is no replacement.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplaceTagCommand {
+    user_id: Uuid,
+    tag_id: Uuid,
+    replacement_tag_id: Option<Uuid>,
+}
+
+impl Command for ReplaceTagCommand {
+    fn name() -> &'static str {
+        "ReplaceTagCommand"
+    }
+}
+
+impl ReplaceTagCommand {
+    pub fn new(user_id: Uuid, tag_id: Uuid, replacement_tag_id: Option<Uuid>) -> Self {
+        Self {
+            user_id,
+            tag_id,
+            replacement_tag_id,
+        }
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    pub fn tag_id(&self) -> &Uuid {
+        &self.tag_id
+    }
+
+    pub fn replacement_tag_id(&self) -> &Option<Uuid> {
+        &self.replacement_tag_id
+    }
+}
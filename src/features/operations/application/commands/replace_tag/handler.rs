@@ -0,0 +1,134 @@
+use async_trait::async_trait;
+use crate::events::event::Event;
+use crate::features::operations::application::commands::replace_tag::command::ReplaceTagCommand;
+use crate::features::operations::application::queries::operation_projection_repository::OperationProjectionRepository;
+use crate::features::operations::domain::operation_repository::OperationRepository;
+use crate::features::operations::error::OperationError;
+use crate::support::command_bus::CommandHandler;
+use crate::support::error::FeatureError;
+use crate::support::id::Id;
+
+pub struct ReplaceTagCommandHandler<R, P>
+    where
+        R: OperationRepository + Send + Sync,
+        P: OperationProjectionRepository + Send + Sync,
+{
+    rep: R,
+    projection_rep: P,
+}
+
+impl<R, P> ReplaceTagCommandHandler<R, P>
+    where
+        R: OperationRepository + Send + Sync,
+        P: OperationProjectionRepository + Send + Sync,
+{
+    pub fn new(rep: R, projection_rep: P) -> Self {
+        Self {
+            rep,
+            projection_rep,
+        }
+    }
+}
+
+#[async_trait]
+impl<R, P> CommandHandler<ReplaceTagCommand> for ReplaceTagCommandHandler<R, P>
+    where
+        R: OperationRepository + Send + Sync,
+        P: OperationProjectionRepository + Send + Sync,
+{
+    async fn handle(&mut self, command: ReplaceTagCommand) -> Result<Vec<Event>, FeatureError> {
+        let operation_ids = self.projection_rep.find_ids_by_tag(*command.user_id(), *command.tag_id())
+            .await
+            .map_err(FeatureError::Operation)?;
+
+        let tag_id = Id::new(*command.tag_id());
+        let replacement = command.replacement_tag_id().map(Id::new);
+        let mut events = vec![];
+
+        for operation_id in operation_ids {
+            let operation = match self.rep.load(operation_id).await.map_err(FeatureError::Operation)? {
+                Some(operation) => operation,
+                None => continue,
+            };
+
+            // The read model may lag behind the streams, skip operations that no longer carry the tag
+            if operation.aggregate().is_deleted()
+                || operation.aggregate().user_id().value() != *command.user_id()
+                || !operation.aggregate().has_tag(&tag_id) {
+                continue;
+            }
+
+            let operation_events = operation.aggregate().handle_tag_replacement(&tag_id, replacement.clone())
+                .map_err(|e|
+                    FeatureError::Operation(
+                        OperationError::Domain(e)
+                    )
+                )?;
+
+            self.rep.append(operation_id, operation.version(), &operation_events)
+                .await
+                .map_err(FeatureError::Operation)?;
+
+            events.extend(operation_events.into_iter().map(Event::OperationEvent));
+        }
+
+        Ok(events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::FutureExt;
+    use crate::features::operations::application::queries::operation_projection_repository::MockOperationProjectionRepository;
+    use crate::features::operations::domain::events::operation_event::OperationEvent;
+    use crate::features::operations::domain::operation::operation_update_tests::versioned_operation_fixture;
+    use crate::features::operations::domain::operation_repository::MockOperationRepository;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle_replaces_tag() {
+        let operation = versioned_operation_fixture();
+        let operation_id = operation.aggregate().id().value();
+        let command = ReplaceTagCommand::new(
+            operation.aggregate().user_id().value(),
+            operation.aggregate().tag_ids()[0].value(),
+            Some(Id::generate()),
+        );
+
+        let mut projection_rep = MockOperationProjectionRepository::new();
+        projection_rep.expect_find_ids_by_tag()
+            .times(1)
+            .returning(move |_, _| async move { Ok(vec![operation_id]) }.boxed());
+
+        let mut handler = ReplaceTagCommandHandler::new(MockOperationRepository::with_operation(operation), projection_rep);
+        let events = handler.handle(command.clone()).await.unwrap();
+
+        assert_eq!(events.len(), 1);
+        match &events[0] {
+            Event::OperationEvent(OperationEvent::OperationUpdated(operation_updated)) => {
+                let tag_ids = operation_updated.payload().tag_ids();
+
+                assert!(!tag_ids.contains(&Id::new(*command.tag_id())));
+                assert!(tag_ids.contains(&Id::new(command.replacement_tag_id().unwrap())));
+            }
+            _ => panic!("Expected OperationUpdated event"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handle_skips_operations_without_tag() {
+        let operation = versioned_operation_fixture();
+        let operation_id = operation.aggregate().id().value();
+        let command = ReplaceTagCommand::new(operation.aggregate().user_id().value(), Id::generate(), None);
+
+        let mut projection_rep = MockOperationProjectionRepository::new();
+        projection_rep.expect_find_ids_by_tag()
+            .times(1)
+            .returning(move |_, _| async move { Ok(vec![operation_id]) }.boxed());
+
+        let mut handler = ReplaceTagCommandHandler::new(MockOperationRepository::with_operation(operation), projection_rep);
+        let events = handler.handle(command).await.unwrap();
+
+        assert!(events.is_empty());
+    }
+}
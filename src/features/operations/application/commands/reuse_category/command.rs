@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::support::command_bus::Command;
+
+/// Points the operation at an existing category instead of the one it asked to create.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReuseCategoryCommand {
+    operation_id: Uuid,
+    user_id: Uuid,
+    requested_category_id: Uuid,
+    category_id: Uuid,
+}
+
+impl Command for ReuseCategoryCommand {
+    fn name() -> &'static str {
+        "ReuseCategoryCommand"
+    }
+}
+
+impl ReuseCategoryCommand {
+    pub fn new(operation_id: Uuid, user_id: Uuid, requested_category_id: Uuid, category_id: Uuid) -> Self {
+        Self {
+            operation_id,
+            user_id,
+            requested_category_id,
+            category_id,
+        }
+    }
+
+    pub fn operation_id(&self) -> &Uuid {
+        &self.operation_id
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    pub fn requested_category_id(&self) -> &Uuid {
+        &self.requested_category_id
+    }
+
+    pub fn category_id(&self) -> &Uuid {
+        &self.category_id
+    }
+}
@@ -0,0 +1,111 @@
+use async_trait::async_trait;
+use crate::events::event::Event;
+use crate::features::operations::application::commands::reuse_category::command::ReuseCategoryCommand;
+use crate::features::operations::domain::operation_repository::OperationRepository;
+use crate::features::operations::error::OperationError;
+use crate::support::command_bus::CommandHandler;
+use crate::support::error::FeatureError;
+use crate::support::id::Id;
+
+pub struct ReuseCategoryCommandHandler<R>
+    where
+        R: OperationRepository + Send + Sync,
+{
+    rep: R,
+}
+
+impl<R> ReuseCategoryCommandHandler<R>
+    where
+        R: OperationRepository + Send + Sync,
+{
+    pub fn new(rep: R) -> Self {
+        Self {
+            rep,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> CommandHandler<ReuseCategoryCommand> for ReuseCategoryCommandHandler<R>
+    where
+        R: OperationRepository + Send + Sync,
+{
+    async fn handle(&mut self, command: ReuseCategoryCommand) -> Result<Vec<Event>, FeatureError> {
+        let operation = match self.rep.load(*command.operation_id()).await.map_err(FeatureError::Operation)? {
+            Some(operation) => operation,
+            None => return Ok(vec![]),
+        };
+
+        let requested_category_id = Id::new(*command.requested_category_id());
+
+        // The operation may have been changed or deleted before the category got resolved
+        if operation.aggregate().is_deleted()
+            || operation.aggregate().user_id().value() != *command.user_id()
+            || *operation.aggregate().category_id() != requested_category_id {
+            return Ok(vec![]);
+        }
+
+        let operation_events = operation.aggregate().handle_category_change(Id::new(*command.category_id()))
+            .map_err(|e|
+                FeatureError::Operation(
+                    OperationError::Domain(e)
+                )
+            )?;
+
+        self.rep.append(*command.operation_id(), operation.version(), &operation_events)
+            .await
+            .map_err(FeatureError::Operation)?;
+
+        Ok(
+            operation_events.into_iter()
+                .map(Event::OperationEvent)
+                .collect()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::features::operations::domain::events::operation_event::OperationEvent;
+    use crate::features::operations::domain::operation::operation_update_tests::versioned_operation_fixture;
+    use crate::features::operations::domain::operation_repository::MockOperationRepository;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle_replaces_requested_category() {
+        let operation = versioned_operation_fixture();
+        let command = ReuseCategoryCommand::new(
+            operation.aggregate().id().value(),
+            operation.aggregate().user_id().value(),
+            operation.aggregate().category_id().value(),
+            Id::generate(),
+        );
+
+        let mut handler = ReuseCategoryCommandHandler::new(MockOperationRepository::with_operation(operation));
+        let events = handler.handle(command.clone()).await.unwrap();
+
+        assert_eq!(events.len(), 1);
+        match &events[0] {
+            Event::OperationEvent(OperationEvent::OperationUpdated(operation_updated)) => {
+                assert_eq!(operation_updated.payload().category_id().value(), *command.category_id());
+            }
+            _ => panic!("Expected OperationUpdated event"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handle_skips_operation_of_another_user() {
+        let operation = versioned_operation_fixture();
+        let command = ReuseCategoryCommand::new(
+            operation.aggregate().id().value(),
+            Id::generate(),
+            operation.aggregate().category_id().value(),
+            Id::generate(),
+        );
+
+        let mut handler = ReuseCategoryCommandHandler::new(MockOperationRepository::with_operation(operation));
+        let events = handler.handle(command).await.unwrap();
+
+        assert!(events.is_empty());
+    }
+}
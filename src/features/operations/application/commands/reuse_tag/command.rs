@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::support::command_bus::Command;
+
+/// Points the operation at an existing tag instead of the one it asked to create.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReuseTagCommand {
+    operation_id: Uuid,
+    user_id: Uuid,
+    requested_tag_id: Uuid,
+    tag_id: Uuid,
+}
+
+impl Command for ReuseTagCommand {
+    fn name() -> &'static str {
+        "ReuseTagCommand"
+    }
+}
+
+impl ReuseTagCommand {
+    pub fn new(operation_id: Uuid, user_id: Uuid, requested_tag_id: Uuid, tag_id: Uuid) -> Self {
+        Self {
+            operation_id,
+            user_id,
+            requested_tag_id,
+            tag_id,
+        }
+    }
+
+    pub fn operation_id(&self) -> &Uuid {
+        &self.operation_id
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    pub fn requested_tag_id(&self) -> &Uuid {
+        &self.requested_tag_id
+    }
+
+    pub fn tag_id(&self) -> &Uuid {
+        &self.tag_id
+    }
+}
@@ -0,0 +1,114 @@
+use async_trait::async_trait;
+use crate::events::event::Event;
+use crate::features::operations::application::commands::reuse_tag::command::ReuseTagCommand;
+use crate::features::operations::domain::operation_repository::OperationRepository;
+use crate::features::operations::error::OperationError;
+use crate::support::command_bus::CommandHandler;
+use crate::support::error::FeatureError;
+use crate::support::id::Id;
+
+pub struct ReuseTagCommandHandler<R>
+    where
+        R: OperationRepository + Send + Sync,
+{
+    rep: R,
+}
+
+impl<R> ReuseTagCommandHandler<R>
+    where
+        R: OperationRepository + Send + Sync,
+{
+    pub fn new(rep: R) -> Self {
+        Self {
+            rep,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> CommandHandler<ReuseTagCommand> for ReuseTagCommandHandler<R>
+    where
+        R: OperationRepository + Send + Sync,
+{
+    async fn handle(&mut self, command: ReuseTagCommand) -> Result<Vec<Event>, FeatureError> {
+        let operation = match self.rep.load(*command.operation_id()).await.map_err(FeatureError::Operation)? {
+            Some(operation) => operation,
+            None => return Ok(vec![]),
+        };
+
+        let requested_tag_id = Id::new(*command.requested_tag_id());
+
+        // The operation may have been changed or deleted before the tag got resolved
+        if operation.aggregate().is_deleted()
+            || operation.aggregate().user_id().value() != *command.user_id()
+            || !operation.aggregate().tag_ids().contains(&requested_tag_id) {
+            return Ok(vec![]);
+        }
+
+        let operation_events = operation.aggregate().handle_tag_replacement(&requested_tag_id, Some(Id::new(*command.tag_id())))
+            .map_err(|e|
+                FeatureError::Operation(
+                    OperationError::Domain(e)
+                )
+            )?;
+
+        self.rep.append(*command.operation_id(), operation.version(), &operation_events)
+            .await
+            .map_err(FeatureError::Operation)?;
+
+        Ok(
+            operation_events.into_iter()
+                .map(Event::OperationEvent)
+                .collect()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::features::operations::domain::events::operation_event::OperationEvent;
+    use crate::features::operations::domain::operation::operation_update_tests::versioned_operation_fixture;
+    use crate::features::operations::domain::operation_repository::MockOperationRepository;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle_replaces_requested_tag() {
+        let operation = versioned_operation_fixture();
+        let command = ReuseTagCommand::new(
+            operation.aggregate().id().value(),
+            operation.aggregate().user_id().value(),
+            operation.aggregate().tag_ids()[0].value(),
+            Id::generate(),
+        );
+
+        let mut handler = ReuseTagCommandHandler::new(MockOperationRepository::with_operation(operation));
+        let events = handler.handle(command.clone()).await.unwrap();
+
+        assert_eq!(events.len(), 1);
+        match &events[0] {
+            Event::OperationEvent(OperationEvent::OperationUpdated(operation_updated)) => {
+                let tag_ids = operation_updated.payload().tag_ids();
+
+                assert!(!tag_ids.contains(&Id::new(*command.requested_tag_id())));
+                assert!(tag_ids.contains(&Id::new(*command.tag_id())));
+            }
+            _ => panic!("Expected OperationUpdated event"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handle_skips_operation_of_another_user() {
+        let operation = versioned_operation_fixture();
+        let command = ReuseTagCommand::new(
+            operation.aggregate().id().value(),
+            Id::generate(),
+            operation.aggregate().tag_ids()[0].value(),
+            Id::generate(),
+        );
+
+        let mut handler = ReuseTagCommandHandler::new(MockOperationRepository::with_operation(operation));
+        let events = handler.handle(command).await.unwrap();
+
+        assert!(events.is_empty());
+    }
+}
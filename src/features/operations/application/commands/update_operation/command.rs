@@ -0,0 +1,137 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::features::operations::application::commands::create_operation::command::{LineData, TagData, TransferData};
+use crate::support::command_bus::Command;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateOperationCommand {
+    operation_id: Uuid,
+    kind: String,
+    user_id: Uuid,
+    account_id: Uuid,
+    category_id: Option<Uuid>,
+    category_name: String,
+    amount: Decimal,
+    currency: String,
+    currency_amount: Decimal,
+    rate: Decimal,
+    label: String,
+    tags: Vec<TagData>,
+    transfer: Option<TransferData>,
+    #[serde(default)]
+    lines: Vec<LineData>,
+}
+
+impl Command for UpdateOperationCommand {
+    fn name() -> &'static str {
+        "UpdateOperationCommand"
+    }
+}
+
+impl UpdateOperationCommand {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        operation_id: Uuid,
+        kind: String,
+        user_id: Uuid,
+        account_id: Uuid,
+        category_id: Option<Uuid>,
+        category_name: String,
+        amount: Decimal,
+        currency: String,
+        currency_amount: Decimal,
+        rate: Decimal,
+        label: String,
+        tags: Vec<TagData>,
+    ) -> Self {
+        Self {
+            operation_id,
+            kind,
+            user_id,
+            account_id,
+            category_id,
+            category_name,
+            amount,
+            currency,
+            currency_amount,
+            rate,
+            label,
+            tags,
+            transfer: None,
+            lines: vec![],
+        }
+    }
+
+    pub fn operation_id(&self) -> &Uuid {
+        &self.operation_id
+    }
+
+    pub fn kind(&self) -> &str {
+        &self.kind
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    pub fn account_id(&self) -> &Uuid {
+        &self.account_id
+    }
+
+    pub fn category_id(&self) -> &Option<Uuid> {
+        &self.category_id
+    }
+
+    pub fn category_name(&self) -> &str {
+        &self.category_name
+    }
+
+    pub fn amount(&self) -> Decimal {
+        self.amount
+    }
+
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    pub fn currency_amount(&self) -> Decimal {
+        self.currency_amount
+    }
+
+    pub fn rate(&self) -> Decimal {
+        self.rate
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub fn tags(&self) -> &[TagData] {
+        &self.tags
+    }
+
+    /// Destination of a transfer, only transfers have one.
+    pub fn transfer(&self) -> &Option<TransferData> {
+        &self.transfer
+    }
+
+    pub fn with_transfer(self, transfer: TransferData) -> Self {
+        Self {
+            transfer: Some(transfer),
+            ..self
+        }
+    }
+
+    /// Lines of a split operation, an update without lines books the operation to its category as a whole.
+    pub fn lines(&self) -> &[LineData] {
+        &self.lines
+    }
+
+    pub fn with_lines(self, lines: Vec<LineData>) -> Self {
+        Self {
+            lines,
+            ..self
+        }
+    }
+}
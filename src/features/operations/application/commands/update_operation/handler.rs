@@ -0,0 +1,194 @@
+use async_trait::async_trait;
+use crate::events::event::Event;
+use crate::features::operations::application::commands::update_operation::command::UpdateOperationCommand;
+use crate::features::operations::domain::account_source::AccountSource;
+use crate::features::operations::domain::error::DomainError;
+use crate::features::operations::domain::events::operation_event::OperationEvent;
+use crate::features::operations::domain::operation_repository::OperationRepository;
+use crate::features::operations::error::OperationError;
+use crate::support::command_bus::CommandHandler;
+use crate::support::error::FeatureError;
+
+pub struct UpdateOperationCommandHandler<R, A>
+    where
+        R: OperationRepository + Send + Sync,
+        A: AccountSource + Send + Sync,
+{
+    rep: R,
+    accounts: A,
+}
+
+impl<R, A> UpdateOperationCommandHandler<R, A>
+    where
+        R: OperationRepository + Send + Sync,
+        A: AccountSource + Send + Sync,
+{
+    pub fn new(rep: R, accounts: A) -> Self {
+        Self {
+            rep,
+            accounts,
+        }
+    }
+}
+
+#[async_trait]
+impl<R, A> CommandHandler<UpdateOperationCommand> for UpdateOperationCommandHandler<R, A>
+    where
+        R: OperationRepository + Send + Sync,
+        A: AccountSource + Send + Sync,
+{
+    async fn handle(&mut self, command: UpdateOperationCommand) -> Result<Vec<Event>, FeatureError> {
+        let operation = self.rep.load(*command.operation_id())
+            .await
+            .map_err(FeatureError::Operation)?
+            .ok_or(
+                FeatureError::Operation(
+                    OperationError::Domain(DomainError::OperationNotFound)
+                )
+            )?;
+
+        self.accounts.account(*command.account_id())
+            .await?
+            .ok_or(
+                FeatureError::Operation(
+                    OperationError::Domain(DomainError::AccountNotFound)
+                )
+            )?
+            .check(command.user_id(), command.currency())
+            .map_err(|e|
+                FeatureError::Operation(
+                    OperationError::Domain(e)
+                )
+            )?;
+
+        let command = match command.transfer().clone() {
+            Some(transfer) => {
+                let destination = self.accounts.account(*transfer.account_id())
+                    .await?
+                    .ok_or(
+                        FeatureError::Operation(
+                            OperationError::Domain(DomainError::AccountNotFound)
+                        )
+                    )?;
+
+                destination.check_owner(command.user_id())
+                    .map_err(|e|
+                        FeatureError::Operation(
+                            OperationError::Domain(e)
+                        )
+                    )?;
+
+                command.with_transfer(transfer.with_currency(destination.currency().to_string()))
+            }
+            None => command,
+        };
+
+        let operation_events = operation.aggregate().handle_update(command)
+            .map_err(|e|
+                FeatureError::Operation(
+                    OperationError::Domain(e)
+                )
+            )?;
+
+        // Creation requests are handled by other bounded contexts, only the update belongs to the operation stream
+        let stream_events: Vec<OperationEvent> = operation_events.iter()
+            .filter(|event| matches!(event, OperationEvent::OperationUpdated(_)))
+            .cloned()
+            .collect();
+
+        self.rep.append(operation.aggregate().id().value(), operation.version(), &stream_events)
+            .await
+            .map_err(FeatureError::Operation)?;
+
+        Ok(
+            operation_events.into_iter()
+                .map(Event::OperationEvent)
+                .collect()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::FutureExt;
+    use rust_decimal::Decimal;
+    use crate::features::operations::application::commands::create_operation::command::TagData;
+    use crate::features::operations::domain::account_source::{MockAccountSource, OperationAccount};
+    use crate::features::operations::domain::operation_repository::MockOperationRepository;
+    use crate::features::operations::domain::operation::operation_update_tests::versioned_operation_fixture;
+    use crate::support::id::Id;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle_success() {
+        let operation = versioned_operation_fixture();
+        let command = command_fixture(operation.aggregate().id().value(), operation.aggregate().user_id().value());
+        let accounts = accounts_fixture(operation.aggregate().user_id().value());
+        let mut handler = UpdateOperationCommandHandler::new(MockOperationRepository::with_operation(operation), accounts);
+
+        let events = handler.handle(command).await.unwrap();
+
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[0], Event::OperationEvent(OperationEvent::TagCreationRequested(_))));
+        assert!(matches!(events[1], Event::OperationEvent(OperationEvent::OperationUpdated(_))));
+    }
+
+    #[tokio::test]
+    async fn test_handle_not_found() {
+        let command = command_fixture(Id::generate(), Id::generate());
+        let mut handler = UpdateOperationCommandHandler::new(MockOperationRepository::new(false), MockAccountSource::new());
+
+        let res = handler.handle(command).await;
+
+        assert!(matches!(res, Err(FeatureError::Operation(OperationError::Domain(DomainError::OperationNotFound)))));
+    }
+
+    #[tokio::test]
+    async fn test_handle_foreign_operation() {
+        let operation = versioned_operation_fixture();
+        let command = command_fixture(operation.aggregate().id().value(), Id::generate());
+        let accounts = accounts_fixture(*command.user_id());
+        let mut handler = UpdateOperationCommandHandler::new(MockOperationRepository::with_operation(operation), accounts);
+
+        let res = handler.handle(command).await;
+
+        assert!(matches!(res, Err(FeatureError::Operation(OperationError::Domain(DomainError::AccessDenied)))));
+    }
+
+    #[tokio::test]
+    async fn test_handle_foreign_account() {
+        let operation = versioned_operation_fixture();
+        let command = command_fixture(operation.aggregate().id().value(), operation.aggregate().user_id().value());
+        let accounts = accounts_fixture(Id::generate());
+        let mut handler = UpdateOperationCommandHandler::new(MockOperationRepository::with_operation(operation), accounts);
+
+        let res = handler.handle(command).await;
+
+        assert!(matches!(res, Err(FeatureError::Operation(OperationError::Domain(DomainError::AccountNotFound)))));
+    }
+
+    fn accounts_fixture(user_id: uuid::Uuid) -> MockAccountSource {
+        let mut accounts = MockAccountSource::new();
+        accounts.expect_account()
+            .returning(move |_| async move { Ok(Some(OperationAccount::new(user_id, "USD".to_string()))) }.boxed());
+
+        accounts
+    }
+
+    fn command_fixture(operation_id: uuid::Uuid, user_id: uuid::Uuid) -> UpdateOperationCommand {
+        UpdateOperationCommand::new(
+            operation_id,
+            String::from("Expense"),
+            user_id,
+            Id::generate(),
+            Some(Id::generate()),
+            String::from("Food"),
+            Decimal::from(50),
+            String::from("USD"),
+            Decimal::from(50),
+            Decimal::ONE,
+            String::from("Grocery Shopping"),
+            vec![TagData::new(None, String::from("market"))],
+        )
+    }
+}
@@ -0,0 +1,160 @@
+use async_trait::async_trait;
+use crate::features::operations::application::queries::get_attachment::query::GetAttachmentQuery;
+use crate::features::operations::application::queries::operation_projection_repository::OperationProjectionRepository;
+use crate::features::operations::application::queries::operation_view::AttachmentView;
+use crate::features::operations::domain::attachment::Attachment;
+use crate::features::operations::domain::error::DomainError;
+use crate::features::operations::error::OperationError;
+use crate::features::operations::infrastructure::error::InfrastructureError;
+use crate::services::storage::Storage;
+use crate::support::error::FeatureError;
+use crate::support::query_bus::QueryHandler;
+
+/// Attachment of an operation of the user with its stored content.
+pub struct GetAttachmentQueryHandler<R, S>
+    where
+        R: OperationProjectionRepository + Send + Sync,
+        S: Storage,
+{
+    rep: R,
+    storage: S,
+}
+
+impl<R, S> GetAttachmentQueryHandler<R, S>
+    where
+        R: OperationProjectionRepository + Send + Sync,
+        S: Storage,
+{
+    pub fn new(rep: R, storage: S) -> Self {
+        Self {
+            rep,
+            storage,
+        }
+    }
+}
+
+#[async_trait]
+impl<R, S> QueryHandler<GetAttachmentQuery> for GetAttachmentQueryHandler<R, S>
+    where
+        R: OperationProjectionRepository + Send + Sync,
+        S: Storage,
+{
+    type Output = (AttachmentView, Vec<u8>);
+
+    async fn handle(&self, query: GetAttachmentQuery) -> Result<(AttachmentView, Vec<u8>), FeatureError> {
+        let domain_error = |e: DomainError| FeatureError::Operation(OperationError::Domain(e));
+
+        let operation = self.rep.find_by_id(*query.operation_id())
+            .await
+            .map_err(FeatureError::Operation)?
+            .ok_or(domain_error(DomainError::OperationNotFound))?;
+
+        if operation.user_id() != query.user_id() {
+            return Err(domain_error(DomainError::AccessDenied));
+        }
+
+        let attachment = operation.attachments().iter()
+            .find(|attachment| attachment.id() == query.attachment_id())
+            .cloned()
+            .ok_or(domain_error(DomainError::AttachmentNotFound))?;
+
+        let content = self.storage.get(&Attachment::storage_key(query.operation_id(), query.attachment_id()))
+            .await
+            .map_err(|e|
+                FeatureError::Operation(
+                    OperationError::Infrastructure(
+                        InfrastructureError::Storage(e.to_string())
+                    )
+                )
+            )?
+            .ok_or(
+                FeatureError::Operation(
+                    OperationError::Infrastructure(
+                        InfrastructureError::Storage(format!("Content of attachment {} is missing", attachment.id()))
+                    )
+                )
+            )?;
+
+        Ok((attachment, content))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use futures_util::FutureExt;
+    use serde_json::json;
+    use uuid::Uuid;
+    use crate::features::operations::application::queries::operation_projection_repository::MockOperationProjectionRepository;
+    use crate::features::operations::application::queries::operation_view::OperationView;
+    use crate::services::storage::MockStorage;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle_success() {
+        let (user_id, attachment_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let view = view_fixture(user_id, attachment_id);
+
+        let mut rep = MockOperationProjectionRepository::new();
+        rep.expect_find_by_id()
+            .returning(move |_| { let view = view.clone(); async move { Ok(Some(view)) }.boxed() });
+
+        let mut storage = MockStorage::new();
+        storage.expect_get()
+            .times(1)
+            .returning(|_| async { Ok(Some(b"%PDF-".to_vec())) }.boxed());
+
+        let handler = GetAttachmentQueryHandler::new(rep, storage);
+        let (attachment, content) = handler.handle(GetAttachmentQuery::new(Uuid::new_v4(), attachment_id, user_id)).await.unwrap();
+
+        assert_eq!(attachment.file_name(), "receipt.pdf");
+        assert_eq!(content, b"%PDF-".to_vec());
+    }
+
+    #[tokio::test]
+    async fn test_handle_foreign_or_unknown_attachment() {
+        let (user_id, attachment_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let view = view_fixture(user_id, attachment_id);
+
+        let mut rep = MockOperationProjectionRepository::new();
+        rep.expect_find_by_id()
+            .returning(move |_| { let view = view.clone(); async move { Ok(Some(view)) }.boxed() });
+
+        let mut storage = MockStorage::new();
+        storage.expect_get().never();
+
+        let handler = GetAttachmentQueryHandler::new(rep, storage);
+
+        let foreign = handler.handle(GetAttachmentQuery::new(Uuid::new_v4(), attachment_id, Uuid::new_v4())).await;
+        let unknown = handler.handle(GetAttachmentQuery::new(Uuid::new_v4(), Uuid::new_v4(), user_id)).await;
+
+        assert!(matches!(foreign, Err(FeatureError::Operation(OperationError::Domain(DomainError::AccessDenied)))));
+        assert!(matches!(unknown, Err(FeatureError::Operation(OperationError::Domain(DomainError::AttachmentNotFound)))));
+    }
+
+    fn view_fixture(user_id: Uuid, attachment_id: Uuid) -> OperationView {
+        serde_json::from_value(json!({
+            "id": Uuid::new_v4(),
+            "user_id": user_id,
+            "account_id": Uuid::new_v4(),
+            "kind": "Expense",
+            "category_id": Uuid::new_v4(),
+            "amount": 100.0,
+            "currency": "USD",
+            "currency_amount": 100.0,
+            "rate": 1.0,
+            "label": "Lunch",
+            "tag_ids": [],
+            "attachments": [
+                {
+                    "id": attachment_id,
+                    "file_name": "receipt.pdf",
+                    "content_type": "application/pdf",
+                    "size": 5,
+                    "created_at": Utc::now(),
+                }
+            ],
+            "created_at": Utc::now(),
+        })).unwrap()
+    }
+}
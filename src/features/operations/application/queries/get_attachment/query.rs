@@ -0,0 +1,39 @@
+use uuid::Uuid;
+use crate::support::query_bus::Query;
+
+const NAME: &str = "get_attachment";
+
+#[derive(Debug, Clone)]
+pub struct GetAttachmentQuery {
+    operation_id: Uuid,
+    attachment_id: Uuid,
+    user_id: Uuid,
+}
+
+impl Query for GetAttachmentQuery {
+    fn name() -> &'static str {
+        NAME
+    }
+}
+
+impl GetAttachmentQuery {
+    pub fn new(operation_id: Uuid, attachment_id: Uuid, user_id: Uuid) -> Self {
+        Self {
+            operation_id,
+            attachment_id,
+            user_id,
+        }
+    }
+
+    pub fn operation_id(&self) -> &Uuid {
+        &self.operation_id
+    }
+
+    pub fn attachment_id(&self) -> &Uuid {
+        &self.attachment_id
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+}
@@ -0,0 +1,101 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::features::operations::application::queries::list_operations::query::SortField;
+use crate::features::operations::application::queries::operation_view::OperationView;
+use crate::features::operations::domain::error::DomainError;
+
+/// Position of the last row of a page, keyed by the sort column and the operation id as a tie-breaker.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum CursorPosition {
+    CreatedAt(DateTime<Utc>),
+    Amount(Decimal),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OperationCursor {
+    position: CursorPosition,
+    id: Uuid,
+}
+
+impl OperationCursor {
+    pub fn new(position: CursorPosition, id: Uuid) -> Self {
+        Self {
+            position,
+            id,
+        }
+    }
+
+    pub fn from_view(view: &OperationView, sort_field: SortField) -> Self {
+        let position = match sort_field {
+            SortField::CreatedAt => CursorPosition::CreatedAt(*view.created_at()),
+            SortField::Amount => CursorPosition::Amount(view.amount()),
+        };
+
+        Self::new(position, *view.id())
+    }
+
+    pub fn encode(&self) -> Result<String, DomainError> {
+        let json = serde_json::to_vec(self)
+            .map_err(|_| DomainError::InvalidFilter("Failed to encode cursor".to_string()))?;
+
+        Ok(
+            json.iter().map(|byte| format!("{:02x}", byte)).collect()
+        )
+    }
+
+    pub fn decode(value: &str) -> Result<Self, DomainError> {
+        let invalid = || DomainError::InvalidFilter("Invalid cursor".to_string());
+
+        if !value.len().is_multiple_of(2) || !value.is_ascii() {
+            return Err(invalid());
+        }
+
+        let bytes = (0..value.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&value[i..i + 2], 16))
+            .collect::<Result<Vec<u8>, _>>()
+            .map_err(|_| invalid())?;
+
+        serde_json::from_slice(&bytes).map_err(|_| invalid())
+    }
+
+    pub fn sort_field(&self) -> SortField {
+        match self.position {
+            CursorPosition::CreatedAt(_) => SortField::CreatedAt,
+            CursorPosition::Amount(_) => SortField::Amount,
+        }
+    }
+
+    pub fn position(&self) -> &CursorPosition {
+        &self.position
+    }
+
+    pub fn id(&self) -> &Uuid {
+        &self.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_decode_roundtrip() {
+        let cursor = OperationCursor::new(CursorPosition::Amount(Decimal::new(333, 2)), Uuid::new_v4());
+
+        let encoded = cursor.encode().unwrap();
+        let decoded = OperationCursor::decode(&encoded).unwrap();
+
+        assert_eq!(decoded, cursor);
+        assert_eq!(decoded.sort_field(), SortField::Amount);
+    }
+
+    #[test]
+    fn test_decode_invalid_cursor() {
+        assert!(OperationCursor::decode("abc").is_err());
+        assert!(OperationCursor::decode("zz").is_err());
+        assert!(OperationCursor::decode("7b7d").is_err());
+    }
+}
@@ -0,0 +1,172 @@
+use async_trait::async_trait;
+use crate::features::operations::application::queries::list_operations::cursor::OperationCursor;
+use crate::features::operations::application::queries::list_operations::query::{DEFAULT_LIMIT, ListOperationsQuery, MAX_LIMIT, SortField, SortOrder};
+use crate::features::operations::application::queries::operation_projection_repository::OperationProjectionRepository;
+use crate::features::operations::application::queries::operation_view::OperationsPage;
+use crate::features::operations::domain::error::DomainError;
+use crate::features::operations::error::OperationError;
+use crate::support::error::FeatureError;
+use crate::support::query_bus::QueryHandler;
+
+pub struct ListOperationsQueryHandler<R>
+    where
+        R: OperationProjectionRepository + Send + Sync,
+{
+    rep: R,
+}
+
+impl<R> ListOperationsQueryHandler<R>
+    where
+        R: OperationProjectionRepository + Send + Sync,
+{
+    pub fn new(rep: R) -> Self {
+        Self {
+            rep,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> QueryHandler<ListOperationsQuery> for ListOperationsQueryHandler<R>
+    where
+        R: OperationProjectionRepository + Send + Sync,
+{
+    type Output = OperationsPage;
+
+    async fn handle(&self, query: ListOperationsQuery) -> Result<OperationsPage, FeatureError> {
+        let domain_error = |e: DomainError| FeatureError::Operation(OperationError::Domain(e));
+
+        query.filter().validate().map_err(domain_error)?;
+
+        let sort_field = SortField::new(query.sort()).map_err(domain_error)?;
+        let sort_order = SortOrder::new(query.order()).map_err(domain_error)?;
+
+        let limit = query.limit().unwrap_or(DEFAULT_LIMIT);
+        if limit == 0 || limit > MAX_LIMIT {
+            return Err(
+                domain_error(
+                    DomainError::InvalidFilter(format!("Limit must be between 1 and {}", MAX_LIMIT))
+                )
+            );
+        }
+
+        let after = match query.cursor() {
+            Some(cursor) => {
+                let cursor = OperationCursor::decode(cursor).map_err(domain_error)?;
+
+                if cursor.sort_field() != sort_field {
+                    return Err(
+                        domain_error(
+                            DomainError::InvalidFilter("Cursor does not match the sort field".to_string())
+                        )
+                    );
+                }
+
+                Some(cursor)
+            }
+            None => None,
+        };
+
+        // One extra row tells whether there is a next page without a separate count query.
+        let mut items = self.rep.find(*query.user_id(), query.filter(), sort_field, sort_order, after, limit + 1)
+            .await
+            .map_err(FeatureError::Operation)?;
+
+        let next_cursor = if items.len() > limit as usize {
+            items.truncate(limit as usize);
+
+            match items.last() {
+                Some(last) => Some(
+                    OperationCursor::from_view(last, sort_field).encode().map_err(domain_error)?
+                ),
+                None => None,
+            }
+        } else {
+            None
+        };
+
+        Ok(OperationsPage::new(items, next_cursor))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+    use futures_util::FutureExt;
+    use serde_json::json;
+    use uuid::Uuid;
+    use crate::features::operations::application::queries::list_operations::query::OperationFilter;
+    use crate::features::operations::application::queries::operation_projection_repository::MockOperationProjectionRepository;
+    use crate::features::operations::application::queries::operation_view::OperationView;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle_returns_next_cursor_when_more_rows_exist() {
+        let mut rep = MockOperationProjectionRepository::new();
+        rep.expect_find()
+            .times(1)
+            .withf(|_, _, _, _, _, limit| *limit == 3)
+            .returning(|_, _, _, _, _, _| async { Ok(views_fixture(3)) }.boxed());
+
+        let handler = ListOperationsQueryHandler::new(rep);
+        let page = handler.handle(query_fixture(Some(2), None)).await.unwrap();
+
+        assert_eq!(page.items().len(), 2);
+
+        let cursor = OperationCursor::decode(page.next_cursor().as_ref().unwrap()).unwrap();
+        assert_eq!(cursor.id(), page.items()[1].id());
+    }
+
+    #[tokio::test]
+    async fn test_handle_last_page_has_no_cursor() {
+        let mut rep = MockOperationProjectionRepository::new();
+        rep.expect_find()
+            .times(1)
+            .returning(|_, _, _, _, _, _| async { Ok(views_fixture(1)) }.boxed());
+
+        let handler = ListOperationsQueryHandler::new(rep);
+        let page = handler.handle(query_fixture(Some(2), None)).await.unwrap();
+
+        assert_eq!(page.items().len(), 1);
+        assert!(page.next_cursor().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_handle_rejects_invalid_limit_and_cursor() {
+        let handler = ListOperationsQueryHandler::new(MockOperationProjectionRepository::new());
+
+        let res = handler.handle(query_fixture(Some(0), None)).await;
+        assert!(matches!(res, Err(FeatureError::Operation(OperationError::Domain(DomainError::InvalidFilter(..))))));
+
+        let res = handler.handle(query_fixture(None, Some("not-a-cursor".to_string()))).await;
+        assert!(matches!(res, Err(FeatureError::Operation(OperationError::Domain(DomainError::InvalidFilter(..))))));
+    }
+
+    fn query_fixture(limit: Option<u32>, cursor: Option<String>) -> ListOperationsQuery {
+        ListOperationsQuery::new(
+            Uuid::new_v4(),
+            OperationFilter::default(),
+            "created_at".to_string(),
+            "desc".to_string(),
+            limit,
+            cursor,
+        )
+    }
+
+    fn views_fixture(count: usize) -> Vec<OperationView> {
+        (0..count).map(|i| serde_json::from_value(json!({
+            "id": Uuid::new_v4(),
+            "user_id": Uuid::new_v4(),
+            "account_id": Uuid::new_v4(),
+            "kind": "Expense",
+            "category_id": Uuid::new_v4(),
+            "amount": 100.0,
+            "currency": "USD",
+            "currency_amount": 100.0,
+            "rate": 1.0,
+            "label": "Lunch",
+            "tag_ids": [],
+            "created_at": Utc::now() - Duration::minutes(i as i64),
+        })).unwrap()).collect()
+    }
+}
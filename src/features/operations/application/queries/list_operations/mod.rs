@@ -0,0 +1,3 @@
+pub mod cursor;
+pub mod handler;
+pub mod query;
\ No newline at end of file
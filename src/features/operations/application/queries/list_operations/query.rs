@@ -0,0 +1,294 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::features::operations::domain::error::DomainError;
+use crate::features::operations::domain::kind::Kind;
+use crate::support::currency::Currency;
+use crate::support::query_bus::Query;
+
+const NAME: &str = "list_operations";
+
+pub const DEFAULT_LIMIT: u32 = 50;
+pub const MAX_LIMIT: u32 = 100;
+
+#[derive(Debug, Clone)]
+pub struct ListOperationsQuery {
+    user_id: Uuid,
+    filter: OperationFilter,
+    sort: String,
+    order: String,
+    limit: Option<u32>,
+    cursor: Option<String>,
+}
+
+impl ListOperationsQuery {
+    pub fn new(
+        user_id: Uuid,
+        filter: OperationFilter,
+        sort: String,
+        order: String,
+        limit: Option<u32>,
+        cursor: Option<String>,
+    ) -> Self {
+        Self {
+            user_id,
+            filter,
+            sort,
+            order,
+            limit,
+            cursor,
+        }
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    pub fn filter(&self) -> &OperationFilter {
+        &self.filter
+    }
+
+    pub fn sort(&self) -> &str {
+        &self.sort
+    }
+
+    pub fn order(&self) -> &str {
+        &self.order
+    }
+
+    pub fn limit(&self) -> Option<u32> {
+        self.limit
+    }
+
+    pub fn cursor(&self) -> &Option<String> {
+        &self.cursor
+    }
+}
+
+impl Query for ListOperationsQuery {
+    fn name() -> &'static str {
+        NAME
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct OperationFilter {
+    kind: Option<String>,
+    account_id: Option<Uuid>,
+    category_id: Option<Uuid>,
+    tag_ids: Vec<Uuid>,
+    currency: Option<String>,
+    dates: Range<DateTime<Utc>>,
+    amounts: Range<Decimal>,
+}
+
+impl OperationFilter {
+    pub fn new(
+        kind: Option<String>,
+        account_id: Option<Uuid>,
+        category_id: Option<Uuid>,
+        tag_ids: Vec<Uuid>,
+        currency: Option<String>,
+        dates: Range<DateTime<Utc>>,
+        amounts: Range<Decimal>,
+    ) -> Self {
+        Self {
+            kind,
+            account_id,
+            category_id,
+            tag_ids,
+            currency,
+            dates,
+            amounts,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), DomainError> {
+        if let Some(kind) = &self.kind {
+            Kind::new(kind)?;
+        }
+
+        if let Some(currency) = &self.currency {
+            Currency::find(currency)
+                .ok_or(DomainError::UnknownCurrency)?;
+        }
+
+        if !self.dates.is_ordered() {
+            return Err(
+                DomainError::InvalidFilter("Date range start must not be after its end".to_string())
+            );
+        }
+
+        if !self.amounts.is_ordered() {
+            return Err(
+                DomainError::InvalidFilter("Amount range start must not be greater than its end".to_string())
+            );
+        }
+
+        Ok(())
+    }
+
+    pub fn kind(&self) -> &Option<String> {
+        &self.kind
+    }
+
+    pub fn account_id(&self) -> &Option<Uuid> {
+        &self.account_id
+    }
+
+    pub fn category_id(&self) -> &Option<Uuid> {
+        &self.category_id
+    }
+
+    pub fn tag_ids(&self) -> &[Uuid] {
+        &self.tag_ids
+    }
+
+    pub fn currency(&self) -> &Option<String> {
+        &self.currency
+    }
+
+    pub fn dates(&self) -> &Range<DateTime<Utc>> {
+        &self.dates
+    }
+
+    pub fn amounts(&self) -> &Range<Decimal> {
+        &self.amounts
+    }
+}
+
+/// Inclusive range where either bound may be omitted.
+#[derive(Debug, Clone)]
+pub struct Range<T> {
+    from: Option<T>,
+    to: Option<T>,
+}
+
+impl<T> Default for Range<T> {
+    fn default() -> Self {
+        Self { from: None, to: None }
+    }
+}
+
+impl<T: PartialOrd + Copy> Range<T> {
+    pub fn new(from: Option<T>, to: Option<T>) -> Self {
+        Self { from, to }
+    }
+
+    pub fn from(&self) -> Option<T> {
+        self.from
+    }
+
+    pub fn to(&self) -> Option<T> {
+        self.to
+    }
+
+    fn is_ordered(&self) -> bool {
+        match (self.from, self.to) {
+            (Some(from), Some(to)) => from <= to,
+            _ => true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SortField {
+    CreatedAt,
+    Amount,
+}
+
+impl SortField {
+    pub fn new(value: &str) -> Result<Self, DomainError> {
+        match value {
+            "created_at" => Ok(Self::CreatedAt),
+            "amount" => Ok(Self::Amount),
+            _ => Err(
+                DomainError::InvalidFilter(format!("Unknown sort field {}", value))
+            ),
+        }
+    }
+
+    pub fn column(&self) -> &'static str {
+        match self {
+            Self::CreatedAt => "created_at",
+            Self::Amount => "amount",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+impl SortOrder {
+    pub fn new(value: &str) -> Result<Self, DomainError> {
+        match value {
+            "asc" => Ok(Self::Asc),
+            "desc" => Ok(Self::Desc),
+            _ => Err(
+                DomainError::InvalidFilter(format!("Unknown sort order {}", value))
+            ),
+        }
+    }
+
+    pub fn keyword(&self) -> &'static str {
+        match self {
+            Self::Asc => "ASC",
+            Self::Desc => "DESC",
+        }
+    }
+
+    /// Comparison operator that selects rows positioned after the cursor.
+    pub fn comparison(&self) -> &'static str {
+        match self {
+            Self::Asc => ">",
+            Self::Desc => "<",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+    use super::*;
+
+    #[test]
+    fn test_validate_empty_filter() {
+        assert!(OperationFilter::default().validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_unknown_kind() {
+        let filter = OperationFilter::new(Some("Gift".to_string()), None, None, vec![], None, Range::default(), Range::default());
+
+        assert!(matches!(filter.validate(), Err(DomainError::UnknownOperationKind)));
+    }
+
+    #[test]
+    fn test_validate_unknown_currency() {
+        let filter = OperationFilter::new(None, None, None, vec![], Some("XXX".to_string()), Range::default(), Range::default());
+
+        assert!(matches!(filter.validate(), Err(DomainError::UnknownCurrency)));
+    }
+
+    #[test]
+    fn test_validate_reversed_ranges() {
+        let now = Utc::now();
+        let dates = OperationFilter::new(None, None, None, vec![], None, Range::new(Some(now), Some(now - Duration::days(1))), Range::default());
+        let amounts = OperationFilter::new(None, None, None, vec![], None, Range::default(), Range::new(Some(Decimal::from(10)), Some(Decimal::from(5))));
+
+        assert!(matches!(dates.validate(), Err(DomainError::InvalidFilter(..))));
+        assert!(matches!(amounts.validate(), Err(DomainError::InvalidFilter(..))));
+    }
+
+    #[test]
+    fn test_sorting_parsing() {
+        assert_eq!(SortField::new("amount").unwrap(), SortField::Amount);
+        assert_eq!(SortOrder::new("asc").unwrap(), SortOrder::Asc);
+        assert!(SortField::new("label").is_err());
+        assert!(SortOrder::new("up").is_err());
+    }
+}
@@ -0,0 +1,4 @@
+pub mod get_attachment;
+pub mod list_operations;
+pub mod operation_projection_repository;
+pub mod operation_view;
\ No newline at end of file
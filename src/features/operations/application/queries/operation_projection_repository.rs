@@ -0,0 +1,39 @@
+use async_trait::async_trait;
+use mockall::automock;
+use uuid::Uuid;
+use crate::features::operations::application::queries::list_operations::cursor::OperationCursor;
+use crate::features::operations::application::queries::list_operations::query::{OperationFilter, SortField, SortOrder};
+use crate::features::operations::application::queries::operation_view::OperationView;
+use crate::features::operations::domain::events::attachment_added::AttachmentAdded;
+use crate::features::operations::domain::events::operation_created::OperationCreated;
+use crate::features::operations::domain::events::operation_deleted::OperationDeleted;
+use crate::features::operations::domain::events::operation_updated::OperationUpdated;
+use crate::features::operations::error::OperationError;
+
+#[async_trait]
+#[automock]
+pub trait OperationProjectionRepository {
+    async fn find(
+        &self,
+        user_id: Uuid,
+        filter: &OperationFilter,
+        sort_field: SortField,
+        sort_order: SortOrder,
+        after: Option<OperationCursor>,
+        limit: u32,
+    ) -> Result<Vec<OperationView>, OperationError>;
+
+    async fn find_by_id(&self, operation_id: Uuid) -> Result<Option<OperationView>, OperationError>;
+
+    async fn find_ids_by_category(&self, user_id: Uuid, category_id: Uuid) -> Result<Vec<Uuid>, OperationError>;
+
+    async fn find_ids_by_tag(&self, user_id: Uuid, tag_id: Uuid) -> Result<Vec<Uuid>, OperationError>;
+
+    async fn apply_operation_created(&self, event: &OperationCreated) -> Result<(), OperationError>;
+
+    async fn apply_operation_updated(&self, event: &OperationUpdated) -> Result<(), OperationError>;
+
+    async fn apply_operation_deleted(&self, event: &OperationDeleted) -> Result<(), OperationError>;
+
+    async fn apply_attachment_added(&self, event: &AttachmentAdded) -> Result<(), OperationError>;
+}
@@ -0,0 +1,208 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use sqlx::types::Json;
+use uuid::Uuid;
+
+/// Read model row of the `operations` projection.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct OperationView {
+    id: Uuid,
+    user_id: Uuid,
+    account_id: Uuid,
+    kind: String,
+    category_id: Uuid,
+    amount: Decimal,
+    currency: String,
+    currency_amount: Decimal,
+    rate: Decimal,
+    label: String,
+    tag_ids: Vec<Uuid>,
+    transfer_account_id: Option<Uuid>,
+    transfer_currency: Option<String>,
+    transfer_amount: Option<Decimal>,
+    #[serde(default)]
+    lines: Json<Vec<LineView>>,
+    #[serde(default)]
+    attachments: Json<Vec<AttachmentView>>,
+    created_at: DateTime<Utc>,
+}
+
+impl OperationView {
+    pub fn id(&self) -> &Uuid {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    pub fn account_id(&self) -> &Uuid {
+        &self.account_id
+    }
+
+    pub fn kind(&self) -> &str {
+        &self.kind
+    }
+
+    pub fn category_id(&self) -> &Uuid {
+        &self.category_id
+    }
+
+    pub fn amount(&self) -> Decimal {
+        self.amount
+    }
+
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    pub fn currency_amount(&self) -> Decimal {
+        self.currency_amount
+    }
+
+    pub fn rate(&self) -> Decimal {
+        self.rate
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub fn tag_ids(&self) -> &[Uuid] {
+        &self.tag_ids
+    }
+
+    /// Account credited by a transfer.
+    pub fn transfer_account_id(&self) -> &Option<Uuid> {
+        &self.transfer_account_id
+    }
+
+    pub fn transfer_currency(&self) -> &Option<String> {
+        &self.transfer_currency
+    }
+
+    /// Amount credited by a transfer, in the currency of its account.
+    pub fn transfer_amount(&self) -> Option<Decimal> {
+        self.transfer_amount
+    }
+
+    /// Lines of a split operation, each booked to its own category.
+    pub fn lines(&self) -> &[LineView] {
+        &self.lines
+    }
+
+    pub fn attachments(&self) -> &[AttachmentView] {
+        &self.attachments
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LineView {
+    category_id: Uuid,
+    amount: Decimal,
+    currency_amount: Decimal,
+    label: String,
+    tag_ids: Vec<Uuid>,
+}
+
+impl LineView {
+    pub fn new(category_id: Uuid, amount: Decimal, currency_amount: Decimal, label: String, tag_ids: Vec<Uuid>) -> Self {
+        Self {
+            category_id,
+            amount,
+            currency_amount,
+            label,
+            tag_ids,
+        }
+    }
+
+    pub fn category_id(&self) -> &Uuid {
+        &self.category_id
+    }
+
+    pub fn amount(&self) -> Decimal {
+        self.amount
+    }
+
+    pub fn currency_amount(&self) -> Decimal {
+        self.currency_amount
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub fn tag_ids(&self) -> &[Uuid] {
+        &self.tag_ids
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttachmentView {
+    id: Uuid,
+    file_name: String,
+    content_type: String,
+    size: u64,
+    created_at: DateTime<Utc>,
+}
+
+impl AttachmentView {
+    pub fn new(id: Uuid, file_name: String, content_type: String, size: u64, created_at: DateTime<Utc>) -> Self {
+        Self {
+            id,
+            file_name,
+            content_type,
+            size,
+            created_at,
+        }
+    }
+
+    pub fn id(&self) -> &Uuid {
+        &self.id
+    }
+
+    pub fn file_name(&self) -> &str {
+        &self.file_name
+    }
+
+    pub fn content_type(&self) -> &str {
+        &self.content_type
+    }
+
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OperationsPage {
+    items: Vec<OperationView>,
+    next_cursor: Option<String>,
+}
+
+impl OperationsPage {
+    pub fn new(items: Vec<OperationView>, next_cursor: Option<String>) -> Self {
+        Self {
+            items,
+            next_cursor,
+        }
+    }
+
+    pub fn items(&self) -> &[OperationView] {
+        &self.items
+    }
+
+    pub fn next_cursor(&self) -> &Option<String> {
+        &self.next_cursor
+    }
+}
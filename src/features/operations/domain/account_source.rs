@@ -0,0 +1,70 @@
+use async_trait::async_trait;
+use mockall::automock;
+use uuid::Uuid;
+use crate::features::operations::domain::error::DomainError;
+use crate::support::error::FeatureError;
+
+/// Account an operation is booked to, as far as operations are concerned.
+#[derive(Debug, Clone)]
+pub struct OperationAccount {
+    user_id: Uuid,
+    currency: String,
+}
+
+impl OperationAccount {
+    pub fn new(user_id: Uuid, currency: String) -> Self {
+        Self {
+            user_id,
+            currency,
+        }
+    }
+
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    /// Accounts of other users are reported as missing.
+    pub fn check_owner(&self, user_id: &Uuid) -> Result<(), DomainError> {
+        if self.user_id != *user_id {
+            return Err(DomainError::AccountNotFound);
+        }
+
+        Ok(())
+    }
+
+    /// Operations are booked to accounts of their user, in the currency of the account.
+    pub fn check(&self, user_id: &Uuid, currency: &str) -> Result<(), DomainError> {
+        self.check_owner(user_id)?;
+
+        if self.currency != currency {
+            return Err(
+                DomainError::CurrencyMismatch(
+                    format!("Operation currency {} differs from account currency {}", currency, self.currency)
+                )
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+#[automock]
+pub trait AccountSource {
+    async fn account(&self, account_id: Uuid) -> Result<Option<OperationAccount>, FeatureError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let user_id = Uuid::new_v4();
+        let account = OperationAccount::new(user_id, "USD".to_string());
+
+        assert!(account.check(&user_id, "USD").is_ok());
+        assert!(matches!(account.check(&Uuid::new_v4(), "USD"), Err(DomainError::AccountNotFound)));
+        assert!(matches!(account.check(&user_id, "EUR"), Err(DomainError::CurrencyMismatch(_))));
+    }
+}
@@ -1,12 +1,14 @@
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use crate::features::operations::domain::error::DomainError;
+use crate::support::money::round_money;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Amount(f64);
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Amount(Decimal);
 
 impl Amount {
-    pub fn new(value: f64) -> Result<Self, DomainError> {
-        if value <= 0.0 {
+    pub fn new(value: Decimal) -> Result<Self, DomainError> {
+        if value <= Decimal::ZERO {
             return Err(
                 DomainError::InvalidAmount("Amount must be greater than zero".to_string())
             );
@@ -15,7 +17,12 @@ impl Amount {
         Ok(Self(value))
     }
 
-    pub fn value(&self) -> f64 {
+    /// Amount of money rounded to `minor_units` decimal places, it must stay greater than zero.
+    pub fn money(value: Decimal, minor_units: u32) -> Result<Self, DomainError> {
+        Self::new(round_money(value, minor_units))
+    }
+
+    pub fn value(&self) -> Decimal {
         self.0
     }
 }
@@ -26,19 +33,31 @@ mod tests {
 
     #[test]
     fn test_new() {
-        let amount = Amount::new(100.0).unwrap();
-        assert_eq!(amount.value(), 100.0);
+        let amount = Amount::new(Decimal::from(100)).unwrap();
+        assert_eq!(amount.value(), Decimal::from(100));
     }
 
     #[test]
     fn test_new_zero() {
-        let amount = Amount::new(0.0);
+        let amount = Amount::new(Decimal::ZERO);
         assert!(amount.is_err());
     }
 
     #[test]
     fn test_new_negative() {
-        let amount = Amount::new(-100.0);
+        let amount = Amount::new(Decimal::from(-100));
+        assert!(amount.is_err());
+    }
+
+    #[test]
+    fn test_money_rounds_to_minor_units() {
+        let amount = Amount::money(Decimal::new(10005, 3), 2).unwrap();
+        assert_eq!(amount.value(), Decimal::new(1001, 2));
+    }
+
+    #[test]
+    fn test_money_rounded_to_zero() {
+        let amount = Amount::money(Decimal::new(4, 3), 2);
         assert!(amount.is_err());
     }
 }
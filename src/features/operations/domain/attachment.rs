@@ -0,0 +1,86 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::support::id::Id;
+
+/// Signatures of the file types attachments are recognized as, with their MIME type.
+const SIGNATURES: [(&[u8], &str); 5] = [
+    (b"\xFF\xD8\xFF", "image/jpeg"),
+    (b"\x89PNG\r\n\x1A\n", "image/png"),
+    (b"GIF8", "image/gif"),
+    (b"%PDF-", "application/pdf"),
+    (b"RIFF", "image/webp"),
+];
+
+/// File attached to an operation, such as the photo of a receipt. The content itself is kept in the storage.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Attachment {
+    id: Id,
+    file_name: String,
+    content_type: String,
+    size: u64,
+    created_at: DateTime<Utc>,
+}
+
+impl Attachment {
+    pub fn new(id: Id, file_name: String, content_type: String, size: u64, created_at: DateTime<Utc>) -> Self {
+        Self {
+            id,
+            file_name,
+            content_type,
+            size,
+            created_at,
+        }
+    }
+
+    /// MIME type recognized from the first bytes of `content`, the type declared by the client is not trusted.
+    pub fn detect_content_type(content: &[u8]) -> Option<&'static str> {
+        SIGNATURES.iter()
+            .find(|(signature, content_type)| {
+                content.starts_with(signature)
+                    && (*content_type != "image/webp" || content.get(8..12) == Some(b"WEBP".as_slice()))
+            })
+            .map(|(_, content_type)| *content_type)
+    }
+
+    /// Key the content of an attachment is stored under.
+    pub fn storage_key(operation_id: &Uuid, attachment_id: &Uuid) -> String {
+        format!("operations/{}/attachments/{}", operation_id, attachment_id)
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn file_name(&self) -> &str {
+        &self.file_name
+    }
+
+    pub fn content_type(&self) -> &str {
+        &self.content_type
+    }
+
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_content_type() {
+        assert_eq!(Attachment::detect_content_type(b"\xFF\xD8\xFF\xE0\x00\x10JFIF"), Some("image/jpeg"));
+        assert_eq!(Attachment::detect_content_type(b"\x89PNG\r\n\x1A\n\x00\x00"), Some("image/png"));
+        assert_eq!(Attachment::detect_content_type(b"%PDF-1.7\n"), Some("application/pdf"));
+        assert_eq!(Attachment::detect_content_type(b"RIFF\x24\x00\x00\x00WEBPVP8 "), Some("image/webp"));
+        assert_eq!(Attachment::detect_content_type(b"RIFF\x24\x00\x00\x00WAVEfmt "), None);
+        assert_eq!(Attachment::detect_content_type(b"<html></html>"), None);
+        assert_eq!(Attachment::detect_content_type(b""), None);
+    }
+}
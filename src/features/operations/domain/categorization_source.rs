@@ -0,0 +1,44 @@
+use async_trait::async_trait;
+use mockall::automock;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use crate::support::error::FeatureError;
+
+/// Category and tags the rules of the user assign to an operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OperationCategorization {
+    category_id: Uuid,
+    tag_ids: Vec<Uuid>,
+}
+
+impl OperationCategorization {
+    pub fn new(category_id: Uuid, tag_ids: Vec<Uuid>) -> Self {
+        Self {
+            category_id,
+            tag_ids,
+        }
+    }
+
+    pub fn category_id(&self) -> &Uuid {
+        &self.category_id
+    }
+
+    pub fn tag_ids(&self) -> &[Uuid] {
+        &self.tag_ids
+    }
+}
+
+/// Categorization rules consulted when an operation is created without a category id.
+#[async_trait]
+#[automock]
+pub trait CategorizationSource {
+    /// Categorization of the first rule of the user matching the operation, `None` when no rule matches.
+    async fn categorization(
+        &self,
+        user_id: Uuid,
+        kind: &str,
+        label: &str,
+        currency: &str,
+        currency_amount: Decimal,
+    ) -> Result<Option<OperationCategorization>, FeatureError>;
+}
@@ -5,9 +5,39 @@ pub enum DomainError {
     #[error("Invalid amount: {0}")]
     InvalidAmount(String),
 
+    #[error("Rate is required")]
+    RateRequired,
+
     #[error("Unknown currency")]
     UnknownCurrency,
 
     #[error("Unknown operation kind")]
     UnknownOperationKind,
+
+    #[error("Invalid filter: {0}")]
+    InvalidFilter(String),
+
+    #[error("Operation not found")]
+    OperationNotFound,
+
+    #[error("Operation belongs to another user")]
+    AccessDenied,
+
+    #[error("Account not found")]
+    AccountNotFound,
+
+    #[error("Currency mismatch: {0}")]
+    CurrencyMismatch(String),
+
+    #[error("Invalid transfer: {0}")]
+    InvalidTransfer(String),
+
+    #[error("Invalid split: {0}")]
+    InvalidSplit(String),
+
+    #[error("Invalid attachment: {0}")]
+    InvalidAttachment(String),
+
+    #[error("Attachment not found")]
+    AttachmentNotFound,
 }
\ No newline at end of file
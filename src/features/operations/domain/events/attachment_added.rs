@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+use crate::features::operations::domain::attachment::Attachment;
+use crate::support::id::Id;
+
+pub const ATTACHMENT_ADDED_NAME: &str = "attachment_added";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttachmentAdded {
+    id: Id,
+    name: String,
+    payload: AttachmentAddedPayload,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttachmentAddedPayload {
+    operation_id: Id,
+    user_id: Id,
+    attachment: Attachment,
+}
+
+impl AttachmentAdded {
+    pub fn new(id: Id, operation_id: Id, user_id: Id, attachment: Attachment) -> Self {
+        Self {
+            id,
+            name: ATTACHMENT_ADDED_NAME.to_string(),
+            payload: AttachmentAddedPayload {
+                operation_id,
+                user_id,
+                attachment,
+            },
+        }
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn payload(&self) -> &AttachmentAddedPayload {
+        &self.payload
+    }
+}
+
+impl AttachmentAddedPayload {
+    pub fn operation_id(&self) -> &Id {
+        &self.operation_id
+    }
+
+    pub fn user_id(&self) -> &Id {
+        &self.user_id
+    }
+
+    pub fn attachment(&self) -> &Attachment {
+        &self.attachment
+    }
+}
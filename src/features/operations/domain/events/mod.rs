@@ -1,6 +1,9 @@
+pub mod attachment_added;
 pub mod category_creation_requested;
 pub mod operation_event;
 pub mod operation_created;
+pub mod operation_deleted;
+pub mod operation_updated;
 pub mod tag_creation_requested;
 
 
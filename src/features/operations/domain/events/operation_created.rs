@@ -1,8 +1,11 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use crate::features::operations::domain::amount::Amount;
-use crate::features::operations::domain::currency::Currency;
 use crate::features::operations::domain::kind::Kind;
+use crate::features::operations::domain::operation::Operation;
+use crate::features::operations::domain::split_line::SplitLine;
+use crate::features::operations::domain::transfer::Transfer;
+use crate::support::currency::Currency;
 use crate::support::id::Id;
 
 pub const OPERATION_CREATED_NAME: &str = "operation_created";
@@ -18,6 +21,7 @@ pub struct OperationCreated {
 pub struct OperationCreatedPayload {
     id: Id,
     user_id: Id,
+    account_id: Id,
     kind: Kind,
     category_id: Id,
     amount: Amount,
@@ -26,41 +30,34 @@ pub struct OperationCreatedPayload {
     rate: Amount,
     label: String,
     tag_ids: Vec<Id>,
+    #[serde(default)]
+    transfer: Option<Transfer>,
+    #[serde(default)]
+    lines: Vec<SplitLine>,
     created_at: DateTime<Utc>,
 }
 
 impl OperationCreated {
-    pub fn new(
-        id: Id,
-        operation_id: Id,
-        user_id: Id,
-        kind: Kind,
-        category_id: Id,
-        amount: Amount,
-        amount_currency: Amount,
-        currency: Currency,
-        rate: Amount,
-        label: String,
-        tag_ids: Vec<Id>,
-        created_at: DateTime<Utc>,
-    ) -> Self {
+    pub fn new(id: Id, operation: &Operation) -> Self {
         Self {
             id,
             name: OPERATION_CREATED_NAME.to_string(),
             payload: OperationCreatedPayload {
-                id: operation_id,
-                user_id,
-                kind,
-                category_id,
-                amount,
-                amount_currency,
-                currency,
-                rate,
-                label,
-                tag_ids,
-                created_at,
+                id: operation.id().clone(),
+                user_id: operation.user_id().clone(),
+                account_id: operation.account_id().clone(),
+                kind: operation.kind().clone(),
+                category_id: operation.category_id().clone(),
+                amount: operation.amount().clone(),
+                amount_currency: operation.currency_amount().clone(),
+                currency: *operation.currency(),
+                rate: operation.rate().clone(),
+                label: operation.label().to_string(),
+                tag_ids: operation.tag_ids().to_vec(),
+                transfer: operation.transfer().clone(),
+                lines: operation.lines().to_vec(),
+                created_at: *operation.created_at(),
             },
-
         }
     }
 
@@ -86,6 +83,10 @@ impl OperationCreatedPayload {
         &self.user_id
     }
 
+    pub fn account_id(&self) -> &Id {
+        &self.account_id
+    }
+
     pub fn kind(&self) -> &Kind {
         &self.kind
     }
@@ -118,6 +119,15 @@ impl OperationCreatedPayload {
         &self.tag_ids
     }
 
+    pub fn transfer(&self) -> &Option<Transfer> {
+        &self.transfer
+    }
+
+    /// Lines of a split operation, empty when the operation is booked to its category as a whole.
+    pub fn lines(&self) -> &[SplitLine] {
+        &self.lines
+    }
+
     pub fn created_at(&self) -> &DateTime<Utc> {
         &self.created_at
     }
@@ -0,0 +1,114 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::features::operations::domain::amount::Amount;
+use crate::features::operations::domain::kind::Kind;
+use crate::features::operations::domain::operation::Operation;
+use crate::features::operations::domain::transfer::Transfer;
+use crate::support::currency::Currency;
+use crate::support::id::Id;
+
+pub const OPERATION_DELETED_NAME: &str = "operation_deleted";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OperationDeleted {
+    id: Id,
+    name: String,
+    payload: OperationDeletedPayload,
+}
+
+/// Last state of the deleted operation, so listeners can revert its effect.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OperationDeletedPayload {
+    id: Id,
+    user_id: Id,
+    account_id: Id,
+    kind: Kind,
+    category_id: Id,
+    amount: Amount,
+    amount_currency: Amount,
+    currency: Currency,
+    rate: Amount,
+    #[serde(default)]
+    transfer: Option<Transfer>,
+    deleted_at: DateTime<Utc>,
+}
+
+impl OperationDeleted {
+    pub fn new(id: Id, operation: &Operation, deleted_at: DateTime<Utc>) -> Self {
+        Self {
+            id,
+            name: OPERATION_DELETED_NAME.to_string(),
+            payload: OperationDeletedPayload {
+                id: operation.id().clone(),
+                user_id: operation.user_id().clone(),
+                account_id: operation.account_id().clone(),
+                kind: operation.kind().clone(),
+                category_id: operation.category_id().clone(),
+                amount: operation.amount().clone(),
+                amount_currency: operation.currency_amount().clone(),
+                currency: *operation.currency(),
+                rate: operation.rate().clone(),
+                transfer: operation.transfer().clone(),
+                deleted_at,
+            },
+        }
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn payload(&self) -> &OperationDeletedPayload {
+        &self.payload
+    }
+}
+
+impl OperationDeletedPayload {
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Id {
+        &self.user_id
+    }
+
+    pub fn account_id(&self) -> &Id {
+        &self.account_id
+    }
+
+    pub fn kind(&self) -> &Kind {
+        &self.kind
+    }
+
+    pub fn category_id(&self) -> &Id {
+        &self.category_id
+    }
+
+    pub fn amount(&self) -> &Amount {
+        &self.amount
+    }
+
+    pub fn amount_currency(&self) -> &Amount {
+        &self.amount_currency
+    }
+
+    pub fn currency(&self) -> &Currency {
+        &self.currency
+    }
+
+    pub fn rate(&self) -> &Amount {
+        &self.rate
+    }
+
+    pub fn transfer(&self) -> &Option<Transfer> {
+        &self.transfer
+    }
+
+    pub fn deleted_at(&self) -> &DateTime<Utc> {
+        &self.deleted_at
+    }
+}
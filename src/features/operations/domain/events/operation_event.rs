@@ -1,21 +1,58 @@
 use serde::{Deserialize, Serialize};
+use crate::features::operations::domain::events::attachment_added::{ATTACHMENT_ADDED_NAME, AttachmentAdded};
 use crate::features::operations::domain::events::category_creation_requested::CategoryCreationRequested;
-use crate::features::operations::domain::events::operation_created::OperationCreated;
+use crate::features::operations::domain::events::operation_created::{OPERATION_CREATED_NAME, OperationCreated};
+use crate::features::operations::domain::events::operation_deleted::{OPERATION_DELETED_NAME, OperationDeleted};
+use crate::features::operations::domain::events::operation_updated::{OPERATION_UPDATED_NAME, OperationUpdated};
 use crate::features::operations::domain::events::tag_creation_requested::TagCreationRequested;
+use crate::support::event_store::{decode_event, EventStoreError, NewEvent, StorableEvent, StoredEvent};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum OperationEvent {
     OperationCreated(OperationCreated),
+    OperationUpdated(OperationUpdated),
+    OperationDeleted(OperationDeleted),
     CategoryCreationRequested(CategoryCreationRequested),
-    TagCreationRequested(TagCreationRequested)
+    TagCreationRequested(TagCreationRequested),
+    AttachmentAdded(AttachmentAdded),
 }
 
 impl OperationEvent {
     pub fn name(&self) -> &str {
         match self {
             OperationEvent::OperationCreated(_) => "operation_created",
+            OperationEvent::OperationUpdated(_) => OPERATION_UPDATED_NAME,
+            OperationEvent::OperationDeleted(_) => OPERATION_DELETED_NAME,
             OperationEvent::CategoryCreationRequested(_) => "category_creation_requested",
-            OperationEvent::TagCreationRequested(_) => "tag_creation_requested"
+            OperationEvent::TagCreationRequested(_) => "tag_creation_requested",
+            OperationEvent::AttachmentAdded(_) => ATTACHMENT_ADDED_NAME,
+        }
+    }
+}
+
+impl StorableEvent for OperationEvent {
+    fn from_stored(stored: &StoredEvent) -> Result<Self, EventStoreError> {
+        match stored.name() {
+            OPERATION_CREATED_NAME => Ok(Self::OperationCreated(decode_event(stored)?)),
+            OPERATION_UPDATED_NAME => Ok(Self::OperationUpdated(decode_event(stored)?)),
+            OPERATION_DELETED_NAME => Ok(Self::OperationDeleted(decode_event(stored)?)),
+            "category_creation_requested" => Ok(Self::CategoryCreationRequested(decode_event(stored)?)),
+            "tag_creation_requested" => Ok(Self::TagCreationRequested(decode_event(stored)?)),
+            ATTACHMENT_ADDED_NAME => Ok(Self::AttachmentAdded(decode_event(stored)?)),
+            name => Err(
+                EventStoreError::Serialization(format!("Unknown operation event {}", name))
+            ),
+        }
+    }
+
+    fn to_new(&self) -> Result<NewEvent, EventStoreError> {
+        match self {
+            Self::OperationCreated(event) => NewEvent::from_event(event),
+            Self::OperationUpdated(event) => NewEvent::from_event(event),
+            Self::OperationDeleted(event) => NewEvent::from_event(event),
+            Self::CategoryCreationRequested(event) => NewEvent::from_event(event),
+            Self::TagCreationRequested(event) => NewEvent::from_event(event),
+            Self::AttachmentAdded(event) => NewEvent::from_event(event),
         }
     }
 }
\ No newline at end of file
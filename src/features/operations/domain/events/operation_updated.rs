@@ -0,0 +1,186 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::features::operations::domain::amount::Amount;
+use crate::features::operations::domain::kind::Kind;
+use crate::features::operations::domain::operation::Operation;
+use crate::features::operations::domain::split_line::SplitLine;
+use crate::features::operations::domain::transfer::Transfer;
+use crate::support::currency::Currency;
+use crate::support::id::Id;
+
+pub const OPERATION_UPDATED_NAME: &str = "operation_updated";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OperationUpdated {
+    id: Id,
+    name: String,
+    payload: OperationUpdatedPayload,
+}
+
+/// New state of the operation. The `previous_*` fields keep the account and money part of the replaced state,
+/// so listeners can revert its effect without loading the stream. `created_at` is the unchanged date of the operation,
+/// missing from updates recorded before it was added.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OperationUpdatedPayload {
+    id: Id,
+    user_id: Id,
+    account_id: Id,
+    kind: Kind,
+    category_id: Id,
+    amount: Amount,
+    amount_currency: Amount,
+    currency: Currency,
+    rate: Amount,
+    label: String,
+    tag_ids: Vec<Id>,
+    #[serde(default)]
+    transfer: Option<Transfer>,
+    #[serde(default)]
+    lines: Vec<SplitLine>,
+    previous_account_id: Id,
+    previous_kind: Kind,
+    previous_amount: Amount,
+    previous_amount_currency: Amount,
+    previous_currency: Currency,
+    previous_rate: Amount,
+    #[serde(default)]
+    previous_transfer: Option<Transfer>,
+    #[serde(default)]
+    created_at: Option<DateTime<Utc>>,
+    updated_at: DateTime<Utc>,
+}
+
+impl OperationUpdated {
+    pub fn new(id: Id, previous: &Operation, current: &Operation, updated_at: DateTime<Utc>) -> Self {
+        Self {
+            id,
+            name: OPERATION_UPDATED_NAME.to_string(),
+            payload: OperationUpdatedPayload {
+                id: current.id().clone(),
+                user_id: current.user_id().clone(),
+                account_id: current.account_id().clone(),
+                kind: current.kind().clone(),
+                category_id: current.category_id().clone(),
+                amount: current.amount().clone(),
+                amount_currency: current.currency_amount().clone(),
+                currency: *current.currency(),
+                rate: current.rate().clone(),
+                label: current.label().to_string(),
+                tag_ids: current.tag_ids().to_vec(),
+                transfer: current.transfer().clone(),
+                lines: current.lines().to_vec(),
+                previous_account_id: previous.account_id().clone(),
+                previous_kind: previous.kind().clone(),
+                previous_amount: previous.amount().clone(),
+                previous_amount_currency: previous.currency_amount().clone(),
+                previous_currency: *previous.currency(),
+                previous_rate: previous.rate().clone(),
+                previous_transfer: previous.transfer().clone(),
+                created_at: Some(*current.created_at()),
+                updated_at,
+            },
+        }
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn payload(&self) -> &OperationUpdatedPayload {
+        &self.payload
+    }
+}
+
+impl OperationUpdatedPayload {
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Id {
+        &self.user_id
+    }
+
+    pub fn account_id(&self) -> &Id {
+        &self.account_id
+    }
+
+    pub fn kind(&self) -> &Kind {
+        &self.kind
+    }
+
+    pub fn category_id(&self) -> &Id {
+        &self.category_id
+    }
+
+    pub fn amount(&self) -> &Amount {
+        &self.amount
+    }
+
+    pub fn amount_currency(&self) -> &Amount {
+        &self.amount_currency
+    }
+
+    pub fn currency(&self) -> &Currency {
+        &self.currency
+    }
+
+    pub fn rate(&self) -> &Amount {
+        &self.rate
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub fn tag_ids(&self) -> &Vec<Id> {
+        &self.tag_ids
+    }
+
+    pub fn transfer(&self) -> &Option<Transfer> {
+        &self.transfer
+    }
+
+    pub fn lines(&self) -> &[SplitLine] {
+        &self.lines
+    }
+
+    pub fn previous_account_id(&self) -> &Id {
+        &self.previous_account_id
+    }
+
+    pub fn previous_kind(&self) -> &Kind {
+        &self.previous_kind
+    }
+
+    pub fn previous_amount(&self) -> &Amount {
+        &self.previous_amount
+    }
+
+    pub fn previous_amount_currency(&self) -> &Amount {
+        &self.previous_amount_currency
+    }
+
+    pub fn previous_currency(&self) -> &Currency {
+        &self.previous_currency
+    }
+
+    pub fn previous_rate(&self) -> &Amount {
+        &self.previous_rate
+    }
+
+    pub fn previous_transfer(&self) -> &Option<Transfer> {
+        &self.previous_transfer
+    }
+
+    pub fn created_at(&self) -> Option<&DateTime<Utc>> {
+        self.created_at.as_ref()
+    }
+
+    pub fn updated_at(&self) -> &DateTime<Utc> {
+        &self.updated_at
+    }
+}
@@ -1,3 +1,4 @@
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use crate::features::operations::domain::error::DomainError;
 
@@ -31,4 +32,13 @@ impl Kind {
             Self::Transfer => "Transfer",
         }
     }
+
+    /// Direction in which an operation moves the balance of its account. Credits are money borrowed by the user
+    /// and come in, debts are money lent by the user and go out. Transfers leave their account for another one.
+    pub fn sign(&self) -> Decimal {
+        match self {
+            Self::Income | Self::Credit => Decimal::ONE,
+            Self::Expense | Self::Debt | Self::Transfer => Decimal::NEGATIVE_ONE,
+        }
+    }
 }
\ No newline at end of file
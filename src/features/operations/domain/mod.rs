@@ -1,7 +1,12 @@
 pub mod operation;
-pub mod currency;
+pub mod account_source;
 pub mod amount;
+pub mod attachment;
+pub mod categorization_source;
 pub mod kind;
+pub mod operation_creator;
 pub mod operation_repository;
+pub mod split_line;
+pub mod transfer;
 pub mod events;
 pub mod error;
\ No newline at end of file
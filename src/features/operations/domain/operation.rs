@@ -1,18 +1,34 @@
-use chrono::{Utc};
-use crate::features::operations::application::commands::create_operation::command::CreateOperationCommand;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use crate::features::operations::application::commands::add_attachment::command::AddAttachmentCommand;
+use crate::features::operations::application::commands::categorize_operation::command::CategorizeOperationCommand;
+use crate::features::operations::application::commands::create_operation::command::{CreateOperationCommand, LineData, TagData, TransferData};
+use crate::features::operations::application::commands::delete_operation::command::DeleteOperationCommand;
+use crate::features::operations::application::commands::update_operation::command::UpdateOperationCommand;
 use crate::features::operations::domain::amount::Amount;
-use crate::features::operations::domain::currency::Currency;
+use crate::features::operations::domain::attachment::Attachment;
 use crate::features::operations::domain::error::DomainError;
+use crate::features::operations::domain::events::attachment_added::AttachmentAdded;
 use crate::features::operations::domain::events::category_creation_requested::CategoryCreationRequested;
-use crate::features::operations::domain::events::operation_created::OperationCreated;
+use crate::features::operations::domain::events::operation_created::{OPERATION_CREATED_NAME, OperationCreated};
+use crate::features::operations::domain::events::operation_deleted::OperationDeleted;
+use crate::features::operations::domain::events::operation_updated::OperationUpdated;
 use crate::features::operations::domain::events::tag_creation_requested::TagCreationRequested;
 use crate::features::operations::domain::events::operation_event::OperationEvent;
 use crate::features::operations::domain::kind::Kind;
+use crate::features::operations::domain::split_line::SplitLine;
+use crate::features::operations::domain::transfer::Transfer;
+use crate::support::currency::Currency;
+use crate::support::event_store::{Aggregate, EventStoreError};
 use crate::support::id::Id;
+use crate::support::money::{allocate, is_conversion_of, DEFAULT_MINOR_UNITS};
 
+#[derive(Debug, Clone)]
 pub struct Operation {
     id: Id,
     user_id: Id,
+    account_id: Id,
     kind: Kind,
     category_id: Id,
     amount: Amount,
@@ -21,21 +37,422 @@ pub struct Operation {
     rate: Amount,
     label: String,
     tags: Vec<Id>,
+    transfer: Option<Transfer>,
+    lines: Vec<SplitLine>,
+    attachments: Vec<Attachment>,
+    created_at: DateTime<Utc>,
+    deleted: bool,
 }
 
 impl Operation {
     pub fn handle_creation(command: CreateOperationCommand) -> Result<Vec<OperationEvent>, DomainError> {
         let mut events: Vec<OperationEvent> = vec![];
 
-        let operation_id = Id::new(Id::generate());
-        let now = Utc::now();
+        let operation_id = Id::new(command.operation_id().unwrap_or_else(Id::generate));
+        let created_at = command.created_at().unwrap_or_else(Utc::now);
         let user_id = Id::new(command.user_id().clone());
+        let account_id = Id::new(*command.account_id());
         let kind = Kind::new(command.kind())?;
-        let amount = Amount::new(command.amount())?;
-        let currency_amount = Amount::new(command.currency_amount())?;
+        let currency = Currency::find(command.currency())
+            .ok_or(DomainError::UnknownCurrency)?;
+        let currency_amount = Amount::money(command.currency_amount(), currency.minor_units())?;
+        let rate = Amount::new(command.rate().ok_or(DomainError::RateRequired)?)?;
+        let amount = match command.amount() {
+            Some(amount) => Amount::money(amount, DEFAULT_MINOR_UNITS)?,
+            None => Self::convert(&currency_amount, &rate)?,
+        };
+
+        Self::check_amount(&amount, &currency_amount, &rate, &currency)?;
+
+        let transfer = Self::checked_transfer(&kind, command.transfer(), &account_id, &currency, &currency_amount, &amount)?;
+
+        let lines = Self::checked_lines(&kind, command.lines(), &currency, &currency_amount, &amount)?;
+
+        let category_id = Self::category_id_or_request(
+            &operation_id,
+            &user_id,
+            command.category_id(),
+            command.category_name(),
+            &mut events,
+        );
+
+        let label = command.label().to_string();
+
+        let tags = Self::tag_ids_or_requests(&operation_id, &user_id, command.tags(), &mut events);
+
+        let operation = Self {
+            id: operation_id,
+            user_id,
+            account_id,
+            kind,
+            category_id: category_id.clone(),
+            amount,
+            currency,
+            currency_amount,
+            rate,
+            label,
+            tags,
+            transfer,
+            lines,
+            attachments: vec![],
+            created_at,
+            deleted: false,
+        };
+
+        let operation_created = OperationEvent::OperationCreated(
+            OperationCreated::new(Id::new(Id::generate()), &operation)
+        );
+
+        events.push(operation_created);
+
+        Ok(events)
+    }
+
+    pub fn handle_update(&self, command: UpdateOperationCommand) -> Result<Vec<OperationEvent>, DomainError> {
+        self.check_access(command.user_id())?;
+
+        let mut events: Vec<OperationEvent> = vec![];
+
+        let kind = Kind::new(command.kind())?;
+        let currency = Currency::find(command.currency())
+            .ok_or(DomainError::UnknownCurrency)?;
+        let amount = Amount::money(command.amount(), DEFAULT_MINOR_UNITS)?;
+        let currency_amount = Amount::money(command.currency_amount(), currency.minor_units())?;
         let rate = Amount::new(command.rate())?;
+        let account_id = Id::new(*command.account_id());
+
+        Self::check_amount(&amount, &currency_amount, &rate, &currency)?;
+
+        let transfer = Self::checked_transfer(&kind, command.transfer(), &account_id, &currency, &currency_amount, &amount)?;
+
+        let lines = Self::checked_lines(&kind, command.lines(), &currency, &currency_amount, &amount)?;
+
+        let category_id = Self::category_id_or_request(
+            &self.id,
+            &self.user_id,
+            command.category_id(),
+            command.category_name(),
+            &mut events,
+        );
+        let tags = Self::tag_ids_or_requests(&self.id, &self.user_id, command.tags(), &mut events);
+
+        let updated = Self {
+            account_id,
+            kind,
+            category_id,
+            amount,
+            currency,
+            currency_amount,
+            rate,
+            label: command.label().to_string(),
+            tags,
+            transfer,
+            lines,
+            ..self.clone()
+        };
+
+        events.push(
+            OperationEvent::OperationUpdated(
+                OperationUpdated::new(Id::new(Id::generate()), self, &updated, Utc::now())
+            )
+        );
+
+        Ok(events)
+    }
+
+    /// Moves the operation to another category, e.g. when its category is deleted.
+    pub fn handle_category_change(&self, category_id: Id) -> Result<Vec<OperationEvent>, DomainError> {
+        if self.deleted {
+            return Err(DomainError::OperationNotFound);
+        }
+
+        let updated = Self {
+            category_id,
+            ..self.clone()
+        };
+
+        Ok(
+            vec![
+                OperationEvent::OperationUpdated(
+                    OperationUpdated::new(Id::new(Id::generate()), self, &updated, Utc::now())
+                )
+            ]
+        )
+    }
+
+    /// Moves the operation and its lines booked to `category_id` to `replacement`, e.g. when the category is deleted.
+    pub fn handle_category_replacement(&self, category_id: &Id, replacement: Id) -> Result<Vec<OperationEvent>, DomainError> {
+        if self.deleted {
+            return Err(DomainError::OperationNotFound);
+        }
+
+        let lines = self.lines.iter()
+            .map(|line| match line.category_id() == category_id {
+                true => line.clone().with_category_id(replacement.clone()),
+                false => line.clone(),
+            })
+            .collect();
+
+        let updated = Self {
+            category_id: match &self.category_id == category_id {
+                true => replacement,
+                false => self.category_id.clone(),
+            },
+            lines,
+            ..self.clone()
+        };
+
+        Ok(
+            vec![
+                OperationEvent::OperationUpdated(
+                    OperationUpdated::new(Id::new(Id::generate()), self, &updated, Utc::now())
+                )
+            ]
+        )
+    }
+
+    /// Drops `tag_id` from the operation and its lines and adds `replacement` instead, e.g. when tags are merged.
+    pub fn handle_tag_replacement(&self, tag_id: &Id, replacement: Option<Id>) -> Result<Vec<OperationEvent>, DomainError> {
+        if self.deleted {
+            return Err(DomainError::OperationNotFound);
+        }
+
+        let lines = self.lines.iter()
+            .map(|line| match line.tag_ids().contains(tag_id) {
+                true => line.clone().with_tag_ids(Self::replaced_tag_ids(line.tag_ids(), tag_id, replacement.clone())),
+                false => line.clone(),
+            })
+            .collect();
+
+        let tags = match self.tags.contains(tag_id) {
+            true => Self::replaced_tag_ids(&self.tags, tag_id, replacement),
+            false => self.tags.clone(),
+        };
+
+        let updated = Self {
+            tags,
+            lines,
+            ..self.clone()
+        };
+
+        Ok(
+            vec![
+                OperationEvent::OperationUpdated(
+                    OperationUpdated::new(Id::new(Id::generate()), self, &updated, Utc::now())
+                )
+            ]
+        )
+    }
+
+    /// Moves the operation to the category of the command and adds the tags it misses.
+    /// Nothing happens when the operation already has them.
+    pub fn handle_categorization(&self, command: CategorizeOperationCommand) -> Result<Vec<OperationEvent>, DomainError> {
+        self.check_access(command.user_id())?;
+
+        let category_id = Id::new(*command.category_id());
+        let mut tags = self.tags.clone();
+
+        for tag_id in command.tag_ids() {
+            let tag_id = Id::new(*tag_id);
+            if !tags.contains(&tag_id) {
+                tags.push(tag_id);
+            }
+        }
+
+        if category_id == self.category_id && tags == self.tags {
+            return Ok(vec![]);
+        }
+
+        let updated = Self {
+            category_id,
+            tags,
+            ..self.clone()
+        };
+
+        Ok(
+            vec![
+                OperationEvent::OperationUpdated(
+                    OperationUpdated::new(Id::new(Id::generate()), self, &updated, Utc::now())
+                )
+            ]
+        )
+    }
+
+    pub fn handle_deletion(&self, command: DeleteOperationCommand) -> Result<Vec<OperationEvent>, DomainError> {
+        self.check_access(command.user_id())?;
+
+        Ok(
+            vec![
+                OperationEvent::OperationDeleted(
+                    OperationDeleted::new(Id::new(Id::generate()), self, Utc::now())
+                )
+            ]
+        )
+    }
+
+    /// Attaches a file of at most `max_size` bytes whose detected type is one of `content_types`.
+    pub fn handle_attachment_addition(
+        &self,
+        command: &AddAttachmentCommand,
+        max_size: usize,
+        content_types: &[String],
+    ) -> Result<Vec<OperationEvent>, DomainError> {
+        self.check_access(command.user_id())?;
+
+        // Only the last segment of a client path is kept
+        let file_name = command.file_name()
+            .rsplit(['/', '\\'])
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_string();
+
+        if file_name.is_empty() {
+            return Err(
+                DomainError::InvalidAttachment("File name is required".to_string())
+            );
+        }
+
+        if command.content().is_empty() {
+            return Err(
+                DomainError::InvalidAttachment("File is empty".to_string())
+            );
+        }
 
-        if amount.value() != currency_amount.value() * rate.value() {
+        if command.content().len() > max_size {
+            return Err(
+                DomainError::InvalidAttachment(format!("File exceeds {} bytes", max_size))
+            );
+        }
+
+        let content_type = Attachment::detect_content_type(command.content())
+            .filter(|content_type| content_types.iter().any(|allowed| allowed == content_type))
+            .ok_or(
+                DomainError::InvalidAttachment(
+                    format!("Unsupported file type, allowed types are {}", content_types.join(", "))
+                )
+            )?;
+
+        let attachment = Attachment::new(
+            Id::new(Id::generate()),
+            file_name,
+            content_type.to_string(),
+            command.content().len() as u64,
+            Utc::now(),
+        );
+
+        Ok(
+            vec![
+                OperationEvent::AttachmentAdded(
+                    AttachmentAdded::new(Id::new(Id::generate()), self.id.clone(), self.user_id.clone(), attachment)
+                )
+            ]
+        )
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Id {
+        &self.user_id
+    }
+
+    pub fn account_id(&self) -> &Id {
+        &self.account_id
+    }
+
+    pub fn kind(&self) -> &Kind {
+        &self.kind
+    }
+
+    pub fn category_id(&self) -> &Id {
+        &self.category_id
+    }
+
+    pub fn amount(&self) -> &Amount {
+        &self.amount
+    }
+
+    pub fn currency(&self) -> &Currency {
+        &self.currency
+    }
+
+    pub fn currency_amount(&self) -> &Amount {
+        &self.currency_amount
+    }
+
+    pub fn rate(&self) -> &Amount {
+        &self.rate
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    pub fn tag_ids(&self) -> &[Id] {
+        &self.tags
+    }
+
+    pub fn transfer(&self) -> &Option<Transfer> {
+        &self.transfer
+    }
+
+    pub fn lines(&self) -> &[SplitLine] {
+        &self.lines
+    }
+
+    pub fn attachments(&self) -> &[Attachment] {
+        &self.attachments
+    }
+
+    /// Whether the operation or one of its lines is booked to `category_id`.
+    pub fn has_category(&self, category_id: &Id) -> bool {
+        &self.category_id == category_id || self.lines.iter().any(|line| line.category_id() == category_id)
+    }
+
+    /// Whether the operation or one of its lines carries `tag_id`.
+    pub fn has_tag(&self, tag_id: &Id) -> bool {
+        self.tags.contains(tag_id) || self.lines.iter().any(|line| line.tag_ids().contains(tag_id))
+    }
+
+    pub fn is_deleted(&self) -> bool {
+        self.deleted
+    }
+
+    /// Deleted operations are reported as missing, operations of other users are forbidden.
+    fn check_access(&self, user_id: &Uuid) -> Result<(), DomainError> {
+        if self.deleted {
+            return Err(DomainError::OperationNotFound);
+        }
+
+        if self.user_id.value() != *user_id {
+            return Err(DomainError::AccessDenied);
+        }
+
+        Ok(())
+    }
+
+    /// Amount in the base currency for the given currency amount.
+    fn convert(currency_amount: &Amount, rate: &Amount) -> Result<Amount, DomainError> {
+        let value = currency_amount.value()
+            .checked_mul(rate.value())
+            .ok_or_else(||
+                DomainError::InvalidAmount(
+                    format!("Currency amount {} by rate {} is too large", currency_amount.value(), rate.value())
+                )
+            )?;
+
+        Amount::money(value, DEFAULT_MINOR_UNITS)
+    }
+
+    /// `amount` is `currency_amount` converted at `rate`, up to the rounding of both amounts to their minor units.
+    fn check_amount(amount: &Amount, currency_amount: &Amount, rate: &Amount, currency: &Currency) -> Result<(), DomainError> {
+        if !is_conversion_of(amount.value(), DEFAULT_MINOR_UNITS, currency_amount.value(), currency.minor_units(), rate.value()) {
             return Err(
                 DomainError::InvalidAmount(
                     format!("Amount {} is not equal to currency amount {} by rate {}", amount.value(), currency_amount.value(), rate.value())
@@ -43,8 +460,152 @@ impl Operation {
             );
         }
 
-        let category_id = match command.category_id() {
-            Some(id) => Id::new(id.clone()),
+        Ok(())
+    }
+
+    /// Transfers credit another account of the user, in its currency. The credited amount is the currency amount
+    /// between accounts in the same currency, otherwise it is taken from the data or converted from the base amount.
+    fn checked_transfer(
+        kind: &Kind,
+        data: &Option<TransferData>,
+        account_id: &Id,
+        currency: &Currency,
+        currency_amount: &Amount,
+        amount: &Amount,
+    ) -> Result<Option<Transfer>, DomainError> {
+        let data = match (kind, data) {
+            (Kind::Transfer, Some(data)) => data,
+            (Kind::Transfer, None) => return Err(
+                DomainError::InvalidTransfer("Destination account is required".to_string())
+            ),
+            (_, Some(_)) => return Err(
+                DomainError::InvalidTransfer("Only transfers have a destination account".to_string())
+            ),
+            (_, None) => return Ok(None),
+        };
+
+        let destination_id = Id::new(*data.account_id());
+
+        if &destination_id == account_id {
+            return Err(
+                DomainError::InvalidTransfer("Source and destination accounts must differ".to_string())
+            );
+        }
+
+        let destination_currency = data.currency()
+            .ok_or_else(|| DomainError::InvalidTransfer("Destination currency is unknown".to_string()))
+            .and_then(|code| Currency::find(code).ok_or(DomainError::UnknownCurrency))?;
+
+        let destination_amount = match data.amount() {
+            Some(value) => Amount::money(value, destination_currency.minor_units())?,
+            None if &destination_currency == currency => currency_amount.clone(),
+            None => {
+                let rate = Amount::new(data.rate().ok_or(DomainError::RateRequired)?)?;
+                let value = amount.value()
+                    .checked_div(rate.value())
+                    .ok_or_else(||
+                        DomainError::InvalidAmount(
+                            format!("Amount {} by rate {} is out of range", amount.value(), rate.value())
+                        )
+                    )?;
+
+                Amount::money(value, destination_currency.minor_units())?
+            }
+        };
+
+        Ok(Some(Transfer::new(destination_id, destination_currency, destination_amount)))
+    }
+
+    /// Lines of a split operation. Their currency amounts must add up to the currency amount of the operation,
+    /// their base amounts are converted at the rate of the operation and the last line takes the rounding difference.
+    fn checked_lines(
+        kind: &Kind,
+        lines: &[LineData],
+        currency: &Currency,
+        currency_amount: &Amount,
+        amount: &Amount,
+    ) -> Result<Vec<SplitLine>, DomainError> {
+        if lines.is_empty() {
+            return Ok(vec![]);
+        }
+
+        if kind == &Kind::Transfer {
+            return Err(
+                DomainError::InvalidSplit("Transfers cannot be split".to_string())
+            );
+        }
+
+        if lines.len() < 2 {
+            return Err(
+                DomainError::InvalidSplit("A split needs at least two lines".to_string())
+            );
+        }
+
+        let currency_amounts = lines.iter()
+            .map(|line| Amount::money(line.currency_amount(), currency.minor_units()))
+            .collect::<Result<Vec<Amount>, DomainError>>()?;
+
+        let total = currency_amounts.iter()
+            .try_fold(Decimal::ZERO, |total, line_amount| total.checked_add(line_amount.value()));
+
+        if total != Some(currency_amount.value()) {
+            return Err(
+                DomainError::InvalidSplit(
+                    format!("Lines do not add up to the currency amount {}", currency_amount.value())
+                )
+            );
+        }
+
+        let weights: Vec<Decimal> = currency_amounts.iter().map(Amount::value).collect();
+        let line_amounts = allocate(amount.value(), &weights, DEFAULT_MINOR_UNITS);
+
+        let mut split: Vec<SplitLine> = vec![];
+
+        for (i, ((line, line_currency_amount), line_amount)) in lines.iter().zip(currency_amounts).zip(line_amounts).enumerate() {
+            let line_amount = Amount::new(line_amount).map_err(|_|
+                DomainError::InvalidSplit(format!("Line {} is too small to be booked in the base currency", i + 1))
+            )?;
+
+            let mut tag_ids: Vec<Id> = vec![];
+            for tag_id in line.tag_ids() {
+                let tag_id = Id::new(*tag_id);
+                if !tag_ids.contains(&tag_id) {
+                    tag_ids.push(tag_id);
+                }
+            }
+
+            split.push(
+                SplitLine::new(Id::new(*line.category_id()), line_amount, line_currency_amount, line.label().to_string(), tag_ids)
+            );
+        }
+
+        Ok(split)
+    }
+
+    fn replaced_tag_ids(tag_ids: &[Id], tag_id: &Id, replacement: Option<Id>) -> Vec<Id> {
+        let mut tags: Vec<Id> = tag_ids.iter()
+            .filter(|id| *id != tag_id)
+            .cloned()
+            .collect();
+
+        if let Some(replacement) = replacement {
+            if !tags.contains(&replacement) {
+                tags.push(replacement);
+            }
+        }
+
+        tags
+    }
+
+    fn category_id_or_request(
+        operation_id: &Id,
+        user_id: &Id,
+        category_id: &Option<Uuid>,
+        category_name: &str,
+        events: &mut Vec<OperationEvent>,
+    ) -> Id {
+        match category_id {
+            Some(id) => Id::new(*id),
             None => {
                 let category_id = Id::new(Id::generate());
                 let category_created = OperationEvent::CategoryCreationRequested(
@@ -53,7 +614,7 @@ impl Operation {
                         operation_id.clone(),
                         user_id.clone(),
                         category_id.clone(),
-                        command.category_name().to_string(),
+                        category_name.to_string(),
                     )
                 );
 
@@ -61,14 +622,13 @@ impl Operation {
 
                 category_id
             }
-        };
-
-        let currency = Currency::new(command.currency())?;
-        let label = command.label().to_string();
+        }
+    }
 
-        let mut tags: Vec<Id> = vec![];
+    fn tag_ids_or_requests(operation_id: &Id, user_id: &Id, tags: &[TagData], events: &mut Vec<OperationEvent>) -> Vec<Id> {
+        let mut tag_ids: Vec<Id> = vec![];
 
-        for tag in command.tags() {
+        for tag in tags {
             if tag.id().is_none() {
                 let tag_id = Id::new(Id::generate());
                 let tag_creation_requested = OperationEvent::TagCreationRequested(
@@ -81,94 +641,383 @@ impl Operation {
                     )
                 );
 
-                tags.push(tag_id);
+                tag_ids.push(tag_id);
                 events.push(tag_creation_requested);
             } else {
-                tags.push(Id::new(tag.id().unwrap().clone()));
+                tag_ids.push(Id::new(tag.id().unwrap()));
             }
         }
 
-        let operation = Self {
-            id: operation_id,
-            user_id: user_id.clone(),
-            kind,
-            category_id: category_id.clone(),
-            amount,
-            currency,
-            currency_amount,
-            rate,
-            label,
-            tags,
+        tag_ids
+    }
+}
+
+impl Aggregate for Operation {
+    type Event = OperationEvent;
+
+    fn apply(state: Option<Self>, event: &OperationEvent) -> Result<Self, EventStoreError> {
+        match (state, event) {
+            (None, OperationEvent::OperationCreated(operation_created)) => {
+                let payload = operation_created.payload();
+
+                Ok(
+                    Self {
+                        id: payload.id().clone(),
+                        user_id: payload.user_id().clone(),
+                        account_id: payload.account_id().clone(),
+                        kind: payload.kind().clone(),
+                        category_id: payload.category_id().clone(),
+                        amount: payload.amount().clone(),
+                        currency: *payload.currency(),
+                        currency_amount: payload.amount_currency().clone(),
+                        rate: payload.rate().clone(),
+                        label: payload.label().to_string(),
+                        tags: payload.tag_ids().clone(),
+                        transfer: payload.transfer().clone(),
+                        lines: payload.lines().to_vec(),
+                        attachments: vec![],
+                        created_at: *payload.created_at(),
+                        deleted: false,
+                    }
+                )
+            }
+            (Some(operation), OperationEvent::OperationCreated(_)) => Err(
+                EventStoreError::Rehydration(
+                    format!("Operation {} is already created", operation.id().to_string())
+                )
+            ),
+            (Some(operation), _) if operation.deleted => Err(
+                EventStoreError::Rehydration(
+                    format!("Operation {} is changed after deletion", operation.id().to_string())
+                )
+            ),
+            (Some(operation), OperationEvent::OperationUpdated(operation_updated)) => {
+                let payload = operation_updated.payload();
+
+                Ok(
+                    Self {
+                        account_id: payload.account_id().clone(),
+                        kind: payload.kind().clone(),
+                        category_id: payload.category_id().clone(),
+                        amount: payload.amount().clone(),
+                        currency: *payload.currency(),
+                        currency_amount: payload.amount_currency().clone(),
+                        rate: payload.rate().clone(),
+                        label: payload.label().to_string(),
+                        tags: payload.tag_ids().clone(),
+                        transfer: payload.transfer().clone(),
+                        lines: payload.lines().to_vec(),
+                        ..operation
+                    }
+                )
+            }
+            (Some(mut operation), OperationEvent::AttachmentAdded(attachment_added)) => {
+                operation.attachments.push(attachment_added.payload().attachment().clone());
+
+                Ok(operation)
+            }
+            (Some(operation), OperationEvent::OperationDeleted(_)) => Ok(
+                Self {
+                    deleted: true,
+                    ..operation
+                }
+            ),
+            // Requests to other bounded contexts do not change the operation itself
+            (Some(operation), _) => Ok(operation),
+            (None, event) => Err(
+                EventStoreError::Rehydration(
+                    format!("Operation stream must start with {}, got {}", OPERATION_CREATED_NAME, event.name())
+                )
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod operation_rehydration_tests {
+    use chrono::Utc;
+    use crate::support::event_store::{StorableEvent, StoredEvent};
+    use super::*;
+    use super::operation_creation_tests::create_operation_command_fixture;
+
+    #[test]
+    fn test_rehydrate_from_operation_created() {
+        let command = create_operation_command_fixture(true, true, false);
+        let events = Operation::handle_creation(command.clone()).unwrap();
+        let stored = stored_fixture(&events);
+
+        let operation = Operation::rehydrate(&stored).unwrap().unwrap();
+
+        assert_eq!(operation.version(), 1);
+        assert_eq!(operation.aggregate().user_id().value(), *command.user_id());
+        assert_eq!(operation.aggregate().category_id().value(), command.category_id().unwrap());
+        assert_eq!(Some(operation.aggregate().amount().value()), command.amount());
+        assert_eq!(operation.aggregate().label(), command.label());
+        assert_eq!(operation.aggregate().tag_ids().len(), 2);
+    }
+
+    #[test]
+    fn test_rehydrate_twice_created_stream() {
+        let command = create_operation_command_fixture(true, false, false);
+        let mut events = Operation::handle_creation(command.clone()).unwrap();
+        events.extend(Operation::handle_creation(command).unwrap());
+
+        let res = Operation::rehydrate(&stored_fixture(&events));
+
+        assert!(matches!(res, Err(EventStoreError::Rehydration(..))));
+    }
+
+    fn stored_fixture(events: &[OperationEvent]) -> Vec<StoredEvent> {
+        let aggregate_id = Id::generate();
+
+        events.iter()
+            .enumerate()
+            .map(|(i, event)| {
+                let new = event.to_new().unwrap();
+
+                StoredEvent::new(*new.id(), aggregate_id, new.name().to_string(), new.payload().clone(), i as i32 + 1, Utc::now())
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+pub mod operation_update_tests {
+    use rust_decimal::Decimal;
+    use crate::features::operations::application::commands::create_operation::command::TagData;
+    use crate::support::event_store::Versioned;
+    use super::*;
+    use super::operation_creation_tests::{create_operation_command_fixture, split_command_fixture};
+
+    #[test]
+    fn test_handle_update() {
+        let operation = versioned_operation_fixture().into_aggregate();
+        let command = update_command_fixture(&operation, operation.user_id().value(), Decimal::from(200));
+
+        let events = operation.handle_update(command.clone()).unwrap();
+
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[0], OperationEvent::TagCreationRequested(_)));
+
+        let operation_updated = match &events[1] {
+            OperationEvent::OperationUpdated(operation_updated) => operation_updated,
+            _ => panic!("Expected OperationUpdated event"),
         };
 
-        let operation_created = OperationEvent::OperationCreated(
-            OperationCreated::new(
-                Id::new(Id::generate()),
-                operation.id().clone(),
-                user_id.clone(),
-                operation.kind().clone(),
-                operation.category_id().clone(),
-                operation.amount().clone(),
-                operation.currency_amount().clone(),
-                operation.currency().clone(),
-                operation.rate().clone(),
-                operation.label().to_string(),
-                operation.tag_ids().to_vec(),
-                now,
-            )
-        );
+        let payload = operation_updated.payload();
+        assert_eq!(payload.id(), operation.id());
+        assert_eq!(payload.amount().value(), Decimal::from(200));
+        assert_eq!(payload.previous_amount().value(), operation.amount().value());
+        assert_eq!(payload.label(), command.label());
+        assert_eq!(payload.tag_ids().len(), 1);
+    }
 
-        events.push(operation_created);
+    #[test]
+    fn test_handle_update_of_foreign_operation() {
+        let operation = versioned_operation_fixture().into_aggregate();
+        let command = update_command_fixture(&operation, Id::generate(), Decimal::from(200));
 
-        Ok(events)
+        assert!(matches!(operation.handle_update(command), Err(DomainError::AccessDenied)));
     }
 
-    pub fn id(&self) -> &Id {
-        &self.id
+    #[test]
+    fn test_handle_update_with_incorrect_amount() {
+        let operation = versioned_operation_fixture().into_aggregate();
+        let command = UpdateOperationCommand::new(
+            operation.id().value(),
+            "Expense".to_string(),
+            operation.user_id().value(),
+            operation.account_id().value(),
+            Some(operation.category_id().value()),
+            "".to_string(),
+            Decimal::from(200),
+            "USD".to_string(),
+            Decimal::from(100),
+            Decimal::ONE,
+            "Corrected label".to_string(),
+            vec![],
+        );
+
+        assert!(matches!(operation.handle_update(command), Err(DomainError::InvalidAmount(_))));
     }
 
-    pub fn user_id(&self) -> &Id {
-        &self.user_id
+    #[test]
+    fn test_handle_deletion() {
+        let operation = versioned_operation_fixture().into_aggregate();
+        let command = DeleteOperationCommand::new(operation.id().value(), operation.user_id().value());
+
+        let events = operation.handle_deletion(command).unwrap();
+
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], OperationEvent::OperationDeleted(_)));
+
+        let deleted = Operation::apply(Some(operation), &events[0]).unwrap();
+        let command = DeleteOperationCommand::new(deleted.id().value(), deleted.user_id().value());
+
+        assert!(deleted.is_deleted());
+        assert!(matches!(deleted.handle_deletion(command), Err(DomainError::OperationNotFound)));
     }
 
-    pub fn kind(&self) -> &Kind {
-        &self.kind
+    #[test]
+    fn test_handle_category_change() {
+        let operation = versioned_operation_fixture().into_aggregate();
+        let category_id = Id::new(Id::generate());
+
+        let events = operation.handle_category_change(category_id.clone()).unwrap();
+        let updated = Operation::apply(Some(operation.clone()), &events[0]).unwrap();
+
+        assert_eq!(updated.category_id(), &category_id);
+        assert_eq!(updated.amount().value(), operation.amount().value());
+        assert_eq!(updated.label(), operation.label());
     }
 
-    pub fn category_id(&self) -> &Id {
-        &self.category_id
+    #[test]
+    fn test_handle_categorization() {
+        let operation = versioned_operation_fixture().into_aggregate();
+        let category_id = Id::generate();
+        let kept = operation.tag_ids()[0].value();
+        let added = Id::generate();
+        let command = CategorizeOperationCommand::new(operation.id().value(), operation.user_id().value(), category_id, vec![kept, added]);
+
+        let events = operation.handle_categorization(command.clone()).unwrap();
+        let categorized = Operation::apply(Some(operation.clone()), &events[0]).unwrap();
+
+        assert_eq!(categorized.category_id().value(), category_id);
+        assert_eq!(categorized.tag_ids().len(), operation.tag_ids().len() + 1);
+        assert!(categorized.handle_categorization(command).unwrap().is_empty());
     }
 
-    pub fn amount(&self) -> &Amount {
-        &self.amount
+    #[test]
+    fn test_handle_tag_replacement() {
+        let operation = versioned_operation_fixture().into_aggregate();
+        let source = operation.tag_ids()[0].clone();
+        let kept = operation.tag_ids()[1].clone();
+
+        let events = operation.handle_tag_replacement(&source, Some(kept.clone())).unwrap();
+        let merged = Operation::apply(Some(operation.clone()), &events[0]).unwrap();
+        assert_eq!(merged.tag_ids(), &[kept.clone()]);
+
+        let events = merged.handle_tag_replacement(&kept, None).unwrap();
+        let cleared = Operation::apply(Some(merged), &events[0]).unwrap();
+        assert!(cleared.tag_ids().is_empty());
     }
 
-    pub fn currency(&self) -> &Currency {
-        &self.currency
+    #[test]
+    fn test_handle_replacements_in_split_lines() {
+        let tag_id = Id::generate();
+        let category_id = Id::generate();
+        let command = split_command_fixture(vec![
+            LineData::new(category_id, Decimal::from(60), String::new(), vec![tag_id]),
+            LineData::new(Id::generate(), Decimal::from(40), String::new(), vec![]),
+        ]);
+        let operation = Operation::handle_creation(command).unwrap().iter()
+            .try_fold(None, |state, event| Operation::apply(state, event).map(Some))
+            .unwrap()
+            .unwrap();
+        let replacement = Id::new(Id::generate());
+
+        assert!(operation.has_category(&Id::new(category_id)));
+        assert!(operation.has_tag(&Id::new(tag_id)));
+
+        let events = operation.handle_category_replacement(&Id::new(category_id), replacement.clone()).unwrap();
+        let recategorized = Operation::apply(Some(operation.clone()), &events[0]).unwrap();
+        assert_eq!(recategorized.lines()[0].category_id(), &replacement);
+        assert_eq!(recategorized.category_id(), operation.category_id());
+
+        let events = recategorized.handle_tag_replacement(&Id::new(tag_id), None).unwrap();
+        let untagged = Operation::apply(Some(recategorized), &events[0]).unwrap();
+        assert!(untagged.lines()[0].tag_ids().is_empty());
+        assert!(!untagged.has_tag(&Id::new(tag_id)));
     }
 
-    pub fn currency_amount(&self) -> &Amount {
-        &self.currency_amount
+    #[test]
+    fn test_handle_attachment_addition() {
+        let operation = versioned_operation_fixture().into_aggregate();
+        let content_types = vec!["application/pdf".to_string()];
+        let command = AddAttachmentCommand::new(
+            operation.id().value(),
+            operation.user_id().value(),
+            "C:\\Receipts\\lunch.pdf".to_string(),
+            b"%PDF-1.7".to_vec(),
+        );
+
+        let events = operation.handle_attachment_addition(&command, 1024, &content_types).unwrap();
+        let attached = Operation::apply(Some(operation), &events[0]).unwrap();
+
+        assert_eq!(attached.attachments().len(), 1);
+        assert_eq!(attached.attachments()[0].file_name(), "lunch.pdf");
+        assert_eq!(attached.attachments()[0].content_type(), "application/pdf");
+        assert_eq!(attached.attachments()[0].size(), 8);
     }
 
-    pub fn rate(&self) -> &Amount {
-        &self.rate
+    #[test]
+    fn test_handle_invalid_attachment_addition() {
+        let operation = versioned_operation_fixture().into_aggregate();
+        let content_types = vec!["application/pdf".to_string()];
+        let command = |file_name: &str, content: &[u8]| AddAttachmentCommand::new(
+            operation.id().value(),
+            operation.user_id().value(),
+            file_name.to_string(),
+            content.to_vec(),
+        );
+
+        for command in [
+            command("receipts/", b"%PDF-1.7"),
+            command("lunch.pdf", b""),
+            command("lunch.pdf", b"%PDF-1.7 with a long body"),
+            command("lunch.png", b"\x89PNG\r\n\x1A\n"),
+            command("lunch.pdf", b"<script></script>"),
+        ] {
+            assert!(matches!(operation.handle_attachment_addition(&command, 16, &content_types), Err(DomainError::InvalidAttachment(_))));
+        }
     }
 
-    pub fn label(&self) -> &str {
-        &self.label
+    #[test]
+    fn test_apply_update() {
+        let operation = versioned_operation_fixture().into_aggregate();
+        let command = update_command_fixture(&operation, operation.user_id().value(), Decimal::from(200));
+        let events = operation.handle_update(command).unwrap();
+
+        let updated = Operation::apply(Some(operation), &events[1]).unwrap();
+
+        assert_eq!(updated.amount().value(), Decimal::from(200));
+        assert_eq!(updated.currency_amount().value(), Decimal::from(200));
     }
 
-    pub fn tag_ids(&self) -> &[Id] {
-        &self.tags
+    pub fn versioned_operation_fixture() -> Versioned<Operation> {
+        let events = Operation::handle_creation(create_operation_command_fixture(true, true, false)).unwrap();
+        let operation = events.iter()
+            .try_fold(None, |state, event| Operation::apply(state, event).map(Some))
+            .unwrap()
+            .unwrap();
+
+        Versioned::new(operation, 1)
+    }
+
+    fn update_command_fixture(operation: &Operation, user_id: Uuid, amount: Decimal) -> UpdateOperationCommand {
+        UpdateOperationCommand::new(
+            operation.id().value(),
+            "Expense".to_string(),
+            user_id,
+            operation.account_id().value(),
+            Some(operation.category_id().value()),
+            "".to_string(),
+            amount,
+            "USD".to_string(),
+            amount,
+            Decimal::ONE,
+            "Corrected label".to_string(),
+            vec![TagData::new(None, "new_tag".to_string())],
+        )
     }
 }
 
 #[cfg(test)]
 mod operation_creation_tests {
+    use rust_decimal::Decimal;
     use uuid::Uuid;
     use super::*;
-    use crate::features::operations::application::commands::create_operation::command::{CreateOperationCommand, TagData};
+    use crate::features::operations::application::commands::create_operation::command::{CreateOperationCommand, TagData, TransferData};
 
     #[test]
     fn test_operation_creation_with_existing_category_and_tags() {
@@ -201,6 +1050,19 @@ mod operation_creation_tests {
         }
     }
 
+    #[test]
+    fn test_operation_creation_with_given_id() {
+        let operation_id = Uuid::new_v4();
+        let command = create_operation_command_fixture(true, false, false).with_operation_id(operation_id);
+
+        let events = Operation::handle_creation(command).unwrap();
+
+        match &events[0] {
+            OperationEvent::OperationCreated(data) => assert_eq!(data.payload().id().value(), operation_id),
+            _ => panic!("Unexpected event type"),
+        }
+    }
+
     #[test]
     pub fn test_operation_creation_with_new_category() {
         let command = create_operation_command_fixture(false, true, false);
@@ -331,12 +1193,13 @@ mod operation_creation_tests {
         let command = CreateOperationCommand::new(
             String::from("Income"),
             user_id,
+            Id::generate(),
             Some(category_id),
             String::from("Food"),
-            300.0,
+            Some(Decimal::from(300)),
             String::from("USD"),
-            100.0,
-            2.0,
+            Decimal::from(100),
+            Some(Decimal::from(2)),
             String::from("Grocery Shopping"),
             vec![],
         );
@@ -356,6 +1219,282 @@ mod operation_creation_tests {
         };
     }
 
+    #[test]
+    pub fn test_operation_creation_with_rounded_conversion() {
+        let command = CreateOperationCommand::new(
+            String::from("Expense"),
+            Id::generate(),
+            Id::generate(),
+            Some(Id::generate()),
+            String::from("Food"),
+            Some(Decimal::new(1000, 2)),
+            String::from("USD"),
+            Decimal::new(333, 2),
+            Some(Decimal::new(3003003, 6)),
+            String::from("Grocery Shopping"),
+            vec![],
+        );
+
+        let events = Operation::handle_creation(command).unwrap();
+
+        match &events[0] {
+            OperationEvent::OperationCreated(data) => {
+                assert_eq!(data.payload().amount().value(), Decimal::new(1000, 2));
+                assert_eq!(data.payload().amount_currency().value(), Decimal::new(333, 2));
+            }
+            _ => panic!("Unexpected event type"),
+        }
+    }
+
+    #[test]
+    pub fn test_operation_creation_rounds_to_minor_units() {
+        let command = CreateOperationCommand::new(
+            String::from("Expense"),
+            Id::generate(),
+            Id::generate(),
+            Some(Id::generate()),
+            String::from("Food"),
+            Some(Decimal::new(10005, 3)),
+            String::from("USD"),
+            Decimal::new(10005, 3),
+            Some(Decimal::ONE),
+            String::from("Grocery Shopping"),
+            vec![],
+        );
+
+        let events = Operation::handle_creation(command).unwrap();
+
+        match &events[0] {
+            OperationEvent::OperationCreated(data) => {
+                assert_eq!(data.payload().amount().value(), Decimal::new(1001, 2));
+                assert_eq!(data.payload().amount_currency().value(), Decimal::new(1001, 2));
+            }
+            _ => panic!("Unexpected event type"),
+        }
+    }
+
+    #[test]
+    pub fn test_operation_creation_derives_amount_from_rate() {
+        let command = CreateOperationCommand::new(
+            String::from("Expense"),
+            Id::generate(),
+            Id::generate(),
+            Some(Id::generate()),
+            String::from("Food"),
+            None,
+            String::from("EUR"),
+            Decimal::new(1999, 2),
+            Some(Decimal::new(10817, 4)),
+            String::from("Grocery Shopping"),
+            vec![],
+        );
+
+        let events = Operation::handle_creation(command).unwrap();
+
+        match &events[0] {
+            OperationEvent::OperationCreated(data) => {
+                assert_eq!(data.payload().amount().value(), Decimal::new(2162, 2));
+                assert_eq!(data.payload().rate().value(), Decimal::new(10817, 4));
+            }
+            _ => panic!("Unexpected event type"),
+        }
+    }
+
+    #[test]
+    pub fn test_operation_creation_without_rate() {
+        let command = CreateOperationCommand::new(
+            String::from("Expense"),
+            Id::generate(),
+            Id::generate(),
+            Some(Id::generate()),
+            String::from("Food"),
+            Some(Decimal::from(100)),
+            String::from("EUR"),
+            Decimal::from(100),
+            None,
+            String::from("Grocery Shopping"),
+            vec![],
+        );
+
+        let result = Operation::handle_creation(command);
+
+        assert!(matches!(result, Err(DomainError::RateRequired)));
+    }
+
+    #[test]
+    pub fn test_operation_creation_of_transfer() {
+        let destination_id = Id::generate();
+        let command = transfer_command_fixture("USD", Decimal::from(100), Decimal::ONE)
+            .with_transfer(TransferData::new(destination_id, None).with_currency("USD".to_string()));
+
+        let events = Operation::handle_creation(command).unwrap();
+
+        match &events[0] {
+            OperationEvent::OperationCreated(data) => {
+                let transfer = data.payload().transfer().as_ref().unwrap();
+
+                assert_eq!(transfer.account_id().value(), destination_id);
+                assert_eq!(transfer.currency().code(), "USD");
+                assert_eq!(transfer.amount().value(), Decimal::from(100));
+            }
+            _ => panic!("Unexpected event type"),
+        }
+    }
+
+    #[test]
+    pub fn test_operation_creation_of_cross_currency_transfer() {
+        let command = transfer_command_fixture("EUR", Decimal::from(100), Decimal::new(108, 2))
+            .with_transfer(
+                TransferData::new(Id::generate(), None)
+                    .with_currency("KZT".to_string())
+                    .with_rate(Decimal::new(22, 4))
+            );
+
+        let events = Operation::handle_creation(command).unwrap();
+
+        match &events[0] {
+            OperationEvent::OperationCreated(data) => {
+                let transfer = data.payload().transfer().as_ref().unwrap();
+
+                assert_eq!(data.payload().amount().value(), Decimal::from(108));
+                assert_eq!(transfer.amount().value(), Decimal::new(4909091, 2));
+            }
+            _ => panic!("Unexpected event type"),
+        }
+    }
+
+    #[test]
+    pub fn test_operation_creation_of_transfer_with_credited_amount() {
+        let command = transfer_command_fixture("EUR", Decimal::from(100), Decimal::new(108, 2))
+            .with_transfer(TransferData::new(Id::generate(), Some(Decimal::new(10805, 2))).with_currency("USD".to_string()));
+
+        let events = Operation::handle_creation(command).unwrap();
+
+        match &events[0] {
+            OperationEvent::OperationCreated(data) => {
+                assert_eq!(data.payload().transfer().as_ref().unwrap().amount().value(), Decimal::new(10805, 2));
+            }
+            _ => panic!("Unexpected event type"),
+        }
+    }
+
+    #[test]
+    pub fn test_operation_creation_of_invalid_transfers() {
+        let without_destination = transfer_command_fixture("USD", Decimal::from(100), Decimal::ONE);
+        let to_same_account = transfer_command_fixture("USD", Decimal::from(100), Decimal::ONE);
+        let to_same_account = to_same_account.clone()
+            .with_transfer(TransferData::new(*to_same_account.account_id(), None).with_currency("USD".to_string()));
+        let without_rate = transfer_command_fixture("USD", Decimal::from(100), Decimal::ONE)
+            .with_transfer(TransferData::new(Id::generate(), None).with_currency("EUR".to_string()));
+        let expense_with_destination = create_operation_command_fixture(true, false, false)
+            .with_transfer(TransferData::new(Id::generate(), None).with_currency("USD".to_string()));
+
+        assert!(matches!(Operation::handle_creation(without_destination), Err(DomainError::InvalidTransfer(_))));
+        assert!(matches!(Operation::handle_creation(to_same_account), Err(DomainError::InvalidTransfer(_))));
+        assert!(matches!(Operation::handle_creation(without_rate), Err(DomainError::RateRequired)));
+        assert!(matches!(Operation::handle_creation(expense_with_destination), Err(DomainError::InvalidTransfer(_))));
+    }
+
+    #[test]
+    pub fn test_operation_creation_of_split() {
+        let tag_id = Id::generate();
+        let command = split_command_fixture(vec![
+            LineData::new(Id::generate(), Decimal::new(3333, 2), String::from("Milk"), vec![tag_id, tag_id]),
+            LineData::new(Id::generate(), Decimal::new(3333, 2), String::new(), vec![]),
+            LineData::new(Id::generate(), Decimal::new(3334, 2), String::new(), vec![]),
+        ]);
+
+        let events = Operation::handle_creation(command.clone()).unwrap();
+
+        match &events[0] {
+            OperationEvent::OperationCreated(data) => {
+                let lines = data.payload().lines();
+                let amounts: Vec<Decimal> = lines.iter().map(|line| line.amount().value()).collect();
+
+                assert_eq!(lines.len(), 3);
+                assert_eq!(lines[0].category_id().value(), *command.lines()[0].category_id());
+                assert_eq!(lines[0].label(), "Milk");
+                assert_eq!(lines[0].tag_ids(), &[Id::new(tag_id)]);
+                assert_eq!(amounts, vec![Decimal::new(3600, 2), Decimal::new(3599, 2), Decimal::new(3601, 2)]);
+                assert_eq!(amounts.iter().sum::<Decimal>(), data.payload().amount().value());
+            }
+            _ => panic!("Unexpected event type"),
+        }
+    }
+
+    #[test]
+    pub fn test_operation_creation_of_invalid_splits() {
+        let mismatched = split_command_fixture(vec![
+            LineData::new(Id::generate(), Decimal::from(50), String::new(), vec![]),
+            LineData::new(Id::generate(), Decimal::from(40), String::new(), vec![]),
+        ]);
+        let single_line = split_command_fixture(vec![
+            LineData::new(Id::generate(), Decimal::from(100), String::new(), vec![]),
+        ]);
+        let transfer = transfer_command_fixture("USD", Decimal::from(100), Decimal::ONE)
+            .with_transfer(TransferData::new(Id::generate(), None).with_currency("USD".to_string()))
+            .with_lines(vec![
+                LineData::new(Id::generate(), Decimal::from(50), String::new(), vec![]),
+                LineData::new(Id::generate(), Decimal::from(50), String::new(), vec![]),
+            ]);
+        let rounded_to_zero = CreateOperationCommand::new(
+            String::from("Expense"),
+            Id::generate(),
+            Id::generate(),
+            Some(Id::generate()),
+            String::from("Food"),
+            None,
+            String::from("EUR"),
+            Decimal::new(3, 2),
+            Some(Decimal::new(5, 1)),
+            String::from("Sweets"),
+            vec![],
+        )
+            .with_lines(vec![
+                LineData::new(Id::generate(), Decimal::new(1, 2), String::new(), vec![]),
+                LineData::new(Id::generate(), Decimal::new(1, 2), String::new(), vec![]),
+                LineData::new(Id::generate(), Decimal::new(1, 2), String::new(), vec![]),
+            ]);
+
+        assert!(matches!(Operation::handle_creation(mismatched), Err(DomainError::InvalidSplit(_))));
+        assert!(matches!(Operation::handle_creation(rounded_to_zero), Err(DomainError::InvalidSplit(_))));
+        assert!(matches!(Operation::handle_creation(single_line), Err(DomainError::InvalidSplit(_))));
+        assert!(matches!(Operation::handle_creation(transfer), Err(DomainError::InvalidSplit(_))));
+    }
+
+    pub fn split_command_fixture(lines: Vec<LineData>) -> CreateOperationCommand {
+        CreateOperationCommand::new(
+            String::from("Expense"),
+            Id::generate(),
+            Id::generate(),
+            Some(Id::generate()),
+            String::from("Food"),
+            None,
+            String::from("EUR"),
+            Decimal::from(100),
+            Some(Decimal::new(108, 2)),
+            String::from("Grocery Shopping"),
+            vec![],
+        )
+            .with_lines(lines)
+    }
+
+    fn transfer_command_fixture(currency: &str, currency_amount: Decimal, rate: Decimal) -> CreateOperationCommand {
+        CreateOperationCommand::new(
+            String::from("Transfer"),
+            Id::generate(),
+            Id::generate(),
+            Some(Id::generate()),
+            String::from("Transfers"),
+            None,
+            currency.to_string(),
+            currency_amount,
+            Some(rate),
+            String::from("To savings"),
+            vec![],
+        )
+    }
+
     pub fn create_operation_command_fixture(has_category_id: bool, has_tags: bool, has_new_tags: bool) -> CreateOperationCommand {
         let user_id = Id::generate();
 
@@ -383,12 +1522,13 @@ mod operation_creation_tests {
         CreateOperationCommand::new(
             String::from("Income"),
             user_id,
+            Id::generate(),
             category_id,
             String::from("Food"),
-            100.0,
+            Some(Decimal::from(100)),
             String::from("USD"),
-            100.0,
-            1.0,
+            Decimal::from(100),
+            Some(Decimal::ONE),
             String::from("Grocery Shopping"),
             tags,
         )
@@ -399,10 +1539,10 @@ mod operation_creation_tests {
         assert_eq!(data.payload().id().to_string().len(), 36);
         assert_eq!(data.payload().user_id().value(), *command.user_id());
         assert_eq!(data.payload().kind().to_str(), command.kind());
-        assert_eq!(data.payload().amount().value(), command.amount());
+        assert_eq!(Some(data.payload().amount().value()), command.amount());
         assert_eq!(data.payload().amount_currency().value(), command.currency_amount());
-        assert_eq!(data.payload().currency().to_str(), command.currency());
-        assert_eq!(data.payload().rate().value(), command.rate());
+        assert_eq!(data.payload().currency().code(), command.currency());
+        assert_eq!(Some(data.payload().rate().value()), command.rate());
         assert_eq!(data.payload().label(), command.label());
     }
 
@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+use mockall::automock;
+use uuid::Uuid;
+use crate::events::event::Event;
+use crate::features::operations::application::commands::create_operation::command::CreateOperationCommand;
+use crate::support::error::FeatureError;
+
+/// Creates operations on behalf of other bounded contexts, e.g. occurrences of recurring operations, imported rows or synced bank transactions.
+/// An operation created with an id of its own can be looked up before the creation is repeated.
+#[async_trait]
+#[automock]
+pub trait OperationCreator {
+    async fn exists(&self, operation_id: Uuid) -> Result<bool, FeatureError>;
+
+    async fn create(&self, command: CreateOperationCommand) -> Result<Vec<Event>, FeatureError>;
+}
@@ -1,27 +1,67 @@
 use async_trait::async_trait;
+use uuid::Uuid;
 use crate::features::operations::domain::events::operation_created::OperationCreated;
+use crate::features::operations::domain::events::operation_event::OperationEvent;
+use crate::features::operations::domain::operation::Operation;
 use crate::features::operations::error::OperationError;
 use crate::features::operations::infrastructure::error::InfrastructureError;
+use crate::support::event_store::Versioned;
 
 #[async_trait]
 pub trait OperationRepository {
+    /// Rebuilds the operation from its event stream. Returns `None` when the stream is empty.
+    async fn load(&self, operation_id: Uuid) -> Result<Option<Versioned<Operation>>, OperationError>;
+
+    /// Appends events to the operation stream if it is still at `expected_version` and returns the new version.
+    /// A stale version fails with `InfrastructureError::Conflict`.
+    async fn append(&self, operation_id: Uuid, expected_version: i32, events: &[OperationEvent]) -> Result<i32, OperationError>;
+
     async fn persist_operation_created_event(&self, event_data: OperationCreated) -> Result<(), OperationError>;
 }
 
 pub struct MockOperationRepository {
     has_error: bool,
+    operation: Option<Versioned<Operation>>,
 }
 
 impl MockOperationRepository {
     pub fn new(has_error: bool) -> Self {
         Self {
-            has_error
+            has_error,
+            operation: None,
+        }
+    }
+
+    pub fn with_operation(operation: Versioned<Operation>) -> Self {
+        Self {
+            has_error: false,
+            operation: Some(operation),
         }
     }
 }
 
 #[async_trait]
 impl OperationRepository for MockOperationRepository {
+    async fn load(&self, _operation_id: Uuid) -> Result<Option<Versioned<Operation>>, OperationError> {
+        if self.has_error {
+            return Err(OperationError::Infrastructure(
+               InfrastructureError::Repository("Mock repository error".to_string())
+            ));
+        }
+
+        Ok(self.operation.clone())
+    }
+
+    async fn append(&self, _operation_id: Uuid, expected_version: i32, events: &[OperationEvent]) -> Result<i32, OperationError> {
+        if self.has_error {
+            return Err(OperationError::Infrastructure(
+               InfrastructureError::Repository("Mock repository error".to_string())
+            ));
+        }
+
+        Ok(expected_version + events.len() as i32)
+    }
+
     async fn persist_operation_created_event(&self, _event_data: OperationCreated) -> Result<(), OperationError> {
         if self.has_error {
             return Err(OperationError::Infrastructure(
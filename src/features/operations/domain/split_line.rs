@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+use crate::features::operations::domain::amount::Amount;
+use crate::support::id::Id;
+
+/// Part of a split operation booked to its own category and tagged on its own. The lines of an operation add up
+/// to its amounts.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SplitLine {
+    category_id: Id,
+    amount: Amount,
+    amount_currency: Amount,
+    label: String,
+    tag_ids: Vec<Id>,
+}
+
+impl SplitLine {
+    pub fn new(category_id: Id, amount: Amount, amount_currency: Amount, label: String, tag_ids: Vec<Id>) -> Self {
+        Self {
+            category_id,
+            amount,
+            amount_currency,
+            label,
+            tag_ids,
+        }
+    }
+
+    pub fn category_id(&self) -> &Id {
+        &self.category_id
+    }
+
+    /// Amount in the base currency.
+    pub fn amount(&self) -> &Amount {
+        &self.amount
+    }
+
+    pub fn amount_currency(&self) -> &Amount {
+        &self.amount_currency
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub fn tag_ids(&self) -> &[Id] {
+        &self.tag_ids
+    }
+
+    pub fn with_category_id(self, category_id: Id) -> Self {
+        Self {
+            category_id,
+            ..self
+        }
+    }
+
+    pub fn with_tag_ids(self, tag_ids: Vec<Id>) -> Self {
+        Self {
+            tag_ids,
+            ..self
+        }
+    }
+}
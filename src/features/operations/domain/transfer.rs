@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+use crate::features::operations::domain::amount::Amount;
+use crate::support::currency::Currency;
+use crate::support::id::Id;
+
+/// Credited side of a transfer. The debited side is the account, currency and currency amount of the operation itself.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Transfer {
+    account_id: Id,
+    currency: Currency,
+    amount: Amount,
+}
+
+impl Transfer {
+    pub fn new(account_id: Id, currency: Currency, amount: Amount) -> Self {
+        Self {
+            account_id,
+            currency,
+            amount,
+        }
+    }
+
+    pub fn account_id(&self) -> &Id {
+        &self.account_id
+    }
+
+    pub fn currency(&self) -> &Currency {
+        &self.currency
+    }
+
+    pub fn amount(&self) -> &Amount {
+        &self.amount
+    }
+}
@@ -0,0 +1,65 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use uuid::Uuid;
+use crate::di::service_container::ServiceContainer;
+use crate::events::event::Event;
+use crate::features::accounts::infrastructure::db_account_projection_repository::DbAccountProjectionRepository;
+use crate::features::operations::application::commands::create_operation::command::CreateOperationCommand;
+use crate::features::operations::application::commands::create_operation::handler::CreateOperationCommandHandler;
+use crate::features::operations::domain::operation_creator::OperationCreator;
+use crate::features::operations::domain::operation_repository::OperationRepository;
+use crate::features::operations::infrastructure::db_operation_repository::DbOperationRepository;
+use crate::features::operations::infrastructure::projection_account_source::ProjectionAccountSource;
+use crate::features::operations::infrastructure::query_categorization_source::QueryCategorizationSource;
+use crate::features::rates::application::queries::find_rate::handler::FindRateQueryHandler;
+use crate::features::rates::infrastructure::db_rate_repository::DbRateRepository;
+use crate::features::rates::infrastructure::query_rate_source::QueryRateSource;
+use crate::features::rules::application::queries::match_rule::handler::MatchRuleQueryHandler;
+use crate::features::rules::infrastructure::db_rule_projection_repository::DbRuleProjectionRepository;
+use crate::support::error::FeatureError;
+
+/// Creates operations through `CreateOperationCommandHandler`, the same way the operations endpoint does.
+pub struct CommandOperationCreator {
+    service_container: Arc<ServiceContainer>,
+}
+
+impl CommandOperationCreator {
+    pub fn new(service_container: Arc<ServiceContainer>) -> Self {
+        Self {
+            service_container,
+        }
+    }
+
+    fn operation_repository(&self) -> DbOperationRepository {
+        DbOperationRepository::new(self.service_container.db_manager(), self.service_container.serializer())
+    }
+}
+
+#[async_trait]
+impl OperationCreator for CommandOperationCreator {
+    async fn exists(&self, operation_id: Uuid) -> Result<bool, FeatureError> {
+        let operation = self.operation_repository().load(operation_id)
+            .await
+            .map_err(FeatureError::Operation)?;
+
+        Ok(operation.is_some())
+    }
+
+    async fn create(&self, command: CreateOperationCommand) -> Result<Vec<Event>, FeatureError> {
+        let db_manager = self.service_container.db_manager();
+
+        let rate_provider = self.service_container.rate_provider()
+            .map_err(FeatureError::Rate)?;
+        let rates = QueryRateSource::new(
+            FindRateQueryHandler::new(DbRateRepository::new(db_manager.clone()), rate_provider),
+            self.service_container.config().rates().base_currency().to_string(),
+        );
+
+        let accounts = ProjectionAccountSource::new(DbAccountProjectionRepository::new(db_manager.clone()));
+        let categorizations = QueryCategorizationSource::new(MatchRuleQueryHandler::new(DbRuleProjectionRepository::new(db_manager)));
+
+        let mut command_bus = self.service_container.command_bus();
+        command_bus.register(CreateOperationCommandHandler::new(self.operation_repository(), rates, accounts, categorizations));
+        command_bus.dispatch(command).await
+    }
+}
@@ -0,0 +1,402 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use sqlx::{query, query_as, query_scalar, Postgres, QueryBuilder};
+use sqlx::types::Json;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+use crate::db::manager::DbManager;
+use crate::features::operations::application::queries::list_operations::cursor::{CursorPosition, OperationCursor};
+use crate::features::operations::application::queries::list_operations::query::{OperationFilter, SortField, SortOrder};
+use crate::features::operations::application::queries::operation_projection_repository::OperationProjectionRepository;
+use crate::features::operations::application::queries::operation_view::{AttachmentView, LineView, OperationView};
+use crate::features::operations::domain::events::attachment_added::AttachmentAdded;
+use crate::features::operations::domain::events::operation_created::OperationCreated;
+use crate::features::operations::domain::events::operation_deleted::OperationDeleted;
+use crate::features::operations::domain::events::operation_updated::OperationUpdated;
+use crate::features::operations::domain::split_line::SplitLine;
+use crate::features::operations::error::OperationError;
+use crate::features::operations::infrastructure::error::InfrastructureError;
+
+const COLUMNS: &str = "id, user_id, account_id, kind, category_id, amount, currency, currency_amount, rate, label, tag_ids, transfer_account_id, transfer_currency, transfer_amount, lines, attachments, created_at";
+
+/// Split operations whose lines include the category bound next.
+const LINES_OF_CATEGORY: &str = "lines @> jsonb_build_array(jsonb_build_object('category_id', ";
+
+/// Split operations whose lines carry one of the tags bound next.
+const LINES_OF_TAGS: &str = "EXISTS (SELECT 1 FROM jsonb_array_elements(lines) AS line WHERE ARRAY(SELECT jsonb_array_elements_text(line -> 'tag_ids')::uuid) && ";
+
+#[derive(Clone)]
+pub struct DbOperationProjectionRepository {
+    db_manager: Arc<Mutex<DbManager>>,
+}
+
+impl DbOperationProjectionRepository {
+    pub fn new(db_manager: Arc<Mutex<DbManager>>) -> Self {
+        Self {
+            db_manager,
+        }
+    }
+
+    fn line_views(lines: &[SplitLine]) -> Json<Vec<LineView>> {
+        Json(
+            lines.iter()
+                .map(|line| LineView::new(
+                    line.category_id().value(),
+                    line.amount().value(),
+                    line.amount_currency().value(),
+                    line.label().to_string(),
+                    line.tag_ids().iter().map(|id| id.value()).collect(),
+                ))
+                .collect()
+        )
+    }
+}
+
+#[async_trait]
+impl OperationProjectionRepository for DbOperationProjectionRepository {
+    async fn find(
+        &self,
+        user_id: Uuid,
+        filter: &OperationFilter,
+        sort_field: SortField,
+        sort_order: SortOrder,
+        after: Option<OperationCursor>,
+        limit: u32,
+    ) -> Result<Vec<OperationView>, OperationError> {
+        let mut builder = QueryBuilder::<Postgres>::new(
+            format!("SELECT {} FROM operations WHERE user_id = ", COLUMNS)
+        );
+        builder.push_bind(user_id);
+
+        if let Some(kind) = filter.kind() {
+            builder.push(" AND kind = ").push_bind(kind.clone());
+        }
+
+        if let Some(account_id) = filter.account_id() {
+            builder.push(" AND (account_id = ").push_bind(*account_id)
+                .push(" OR transfer_account_id = ").push_bind(*account_id)
+                .push(")");
+        }
+
+        if let Some(category_id) = filter.category_id() {
+            builder.push(" AND (category_id = ").push_bind(*category_id)
+                .push(" OR ").push(LINES_OF_CATEGORY).push_bind(*category_id).push(")))");
+        }
+
+        if !filter.tag_ids().is_empty() {
+            builder.push(" AND (tag_ids && ").push_bind(filter.tag_ids().to_vec())
+                .push(" OR ").push(LINES_OF_TAGS).push_bind(filter.tag_ids().to_vec()).push("))");
+        }
+
+        if let Some(currency) = filter.currency() {
+            builder.push(" AND currency = ").push_bind(currency.clone());
+        }
+
+        if let Some(from) = filter.dates().from() {
+            builder.push(" AND created_at >= ").push_bind(from);
+        }
+
+        if let Some(to) = filter.dates().to() {
+            builder.push(" AND created_at <= ").push_bind(to);
+        }
+
+        if let Some(from) = filter.amounts().from() {
+            builder.push(" AND amount >= ").push_bind(from);
+        }
+
+        if let Some(to) = filter.amounts().to() {
+            builder.push(" AND amount <= ").push_bind(to);
+        }
+
+        if let Some(cursor) = after {
+            builder.push(format!(" AND ({}, id) {} (", sort_field.column(), sort_order.comparison()));
+
+            match cursor.position() {
+                CursorPosition::CreatedAt(created_at) => builder.push_bind(*created_at),
+                CursorPosition::Amount(amount) => builder.push_bind(*amount),
+            };
+
+            builder.push(", ").push_bind(*cursor.id()).push(")");
+        }
+
+        builder.push(format!(
+            " ORDER BY {} {}, id {} LIMIT ",
+            sort_field.column(),
+            sort_order.keyword(),
+            sort_order.keyword(),
+        ));
+        builder.push_bind(limit as i64);
+
+        let guard = self.db_manager.lock().await;
+        let pool = guard.pool()
+            .map_err(|e|
+                OperationError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to get pool: {}", e)
+                    )
+                )
+            )?;
+
+        let operations = builder.build_query_as::<OperationView>()
+            .fetch_all(&pool)
+            .await
+            .map_err(|e|
+                OperationError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to fetch operations: {}", e)
+                    )
+                )
+            )?;
+
+        Ok(operations)
+    }
+
+    async fn find_by_id(&self, operation_id: Uuid) -> Result<Option<OperationView>, OperationError> {
+        let q = format!("SELECT {} FROM operations WHERE id = $1", COLUMNS);
+
+        let guard = self.db_manager.lock().await;
+        let pool = guard.pool()
+            .map_err(|e|
+                OperationError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to get pool: {}", e)
+                    )
+                )
+            )?;
+
+        query_as::<_, OperationView>(&q)
+            .bind(operation_id)
+            .fetch_optional(&pool)
+            .await
+            .map_err(|e|
+                OperationError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to fetch operation {}: {}", operation_id, e)
+                    )
+                )
+            )
+    }
+
+    async fn find_ids_by_category(&self, user_id: Uuid, category_id: Uuid) -> Result<Vec<Uuid>, OperationError> {
+        let q = format!("SELECT id FROM operations WHERE user_id = $1 AND (category_id = $2 OR {}$2)))", LINES_OF_CATEGORY);
+
+        let guard = self.db_manager.lock().await;
+        let pool = guard.pool()
+            .map_err(|e|
+                OperationError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to get pool: {}", e)
+                    )
+                )
+            )?;
+
+        query_scalar::<_, Uuid>(&q)
+            .bind(user_id)
+            .bind(category_id)
+            .fetch_all(&pool)
+            .await
+            .map_err(|e|
+                OperationError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to fetch operations of category: {}", e)
+                    )
+                )
+            )
+    }
+
+    async fn find_ids_by_tag(&self, user_id: Uuid, tag_id: Uuid) -> Result<Vec<Uuid>, OperationError> {
+        let q = format!("SELECT id FROM operations WHERE user_id = $1 AND ($2 = ANY(tag_ids) OR {}ARRAY[$2]))", LINES_OF_TAGS);
+
+        let guard = self.db_manager.lock().await;
+        let pool = guard.pool()
+            .map_err(|e|
+                OperationError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to get pool: {}", e)
+                    )
+                )
+            )?;
+
+        query_scalar::<_, Uuid>(&q)
+            .bind(user_id)
+            .bind(tag_id)
+            .fetch_all(&pool)
+            .await
+            .map_err(|e|
+                OperationError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to fetch operations of tag: {}", e)
+                    )
+                )
+            )
+    }
+
+    async fn apply_operation_created(&self, event: &OperationCreated) -> Result<(), OperationError> {
+        let q = format!(
+            "INSERT INTO operations ({}) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16) ON CONFLICT (id) DO NOTHING",
+            COLUMNS
+        );
+
+        let payload = event.payload();
+        let tag_ids: Vec<Uuid> = payload.tag_ids().iter().map(|id| id.value()).collect();
+        let transfer = payload.transfer().as_ref();
+
+        let res_query = query(&q)
+            .bind(payload.id().value())
+            .bind(payload.user_id().value())
+            .bind(payload.account_id().value())
+            .bind(payload.kind().to_str())
+            .bind(payload.category_id().value())
+            .bind(payload.amount().value())
+            .bind(payload.currency().code())
+            .bind(payload.amount_currency().value())
+            .bind(payload.rate().value())
+            .bind(payload.label())
+            .bind(tag_ids)
+            .bind(transfer.map(|transfer| transfer.account_id().value()))
+            .bind(transfer.map(|transfer| transfer.currency().code()))
+            .bind(transfer.map(|transfer| transfer.amount().value()))
+            .bind(Self::line_views(payload.lines()))
+            .bind(payload.created_at());
+
+        let guard = self.db_manager.lock().await;
+        let pool = guard.pool()
+            .map_err(|e|
+                OperationError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to get pool: {}", e)
+                    )
+                )
+            )?;
+
+        res_query.execute(&pool)
+            .await
+            .map_err(|e|
+                OperationError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to project operation: {}", e)
+                    )
+                )
+            )?;
+
+        Ok(())
+    }
+
+    async fn apply_operation_updated(&self, event: &OperationUpdated) -> Result<(), OperationError> {
+        let q = "
+            UPDATE operations
+            SET account_id = $2, kind = $3, category_id = $4, amount = $5, currency = $6, currency_amount = $7, rate = $8, label = $9, tag_ids = $10,
+                transfer_account_id = $11, transfer_currency = $12, transfer_amount = $13, lines = $14
+            WHERE id = $1
+        ";
+
+        let payload = event.payload();
+        let tag_ids: Vec<Uuid> = payload.tag_ids().iter().map(|id| id.value()).collect();
+        let transfer = payload.transfer().as_ref();
+
+        let res_query = query(q)
+            .bind(payload.id().value())
+            .bind(payload.account_id().value())
+            .bind(payload.kind().to_str())
+            .bind(payload.category_id().value())
+            .bind(payload.amount().value())
+            .bind(payload.currency().code())
+            .bind(payload.amount_currency().value())
+            .bind(payload.rate().value())
+            .bind(payload.label())
+            .bind(tag_ids)
+            .bind(transfer.map(|transfer| transfer.account_id().value()))
+            .bind(transfer.map(|transfer| transfer.currency().code()))
+            .bind(transfer.map(|transfer| transfer.amount().value()))
+            .bind(Self::line_views(payload.lines()));
+
+        let guard = self.db_manager.lock().await;
+        let pool = guard.pool()
+            .map_err(|e|
+                OperationError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to get pool: {}", e)
+                    )
+                )
+            )?;
+
+        res_query.execute(&pool)
+            .await
+            .map_err(|e|
+                OperationError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to project operation update: {}", e)
+                    )
+                )
+            )?;
+
+        Ok(())
+    }
+
+    async fn apply_operation_deleted(&self, event: &OperationDeleted) -> Result<(), OperationError> {
+        let res_query = query("DELETE FROM operations WHERE id = $1")
+            .bind(event.payload().id().value());
+
+        let guard = self.db_manager.lock().await;
+        let pool = guard.pool()
+            .map_err(|e|
+                OperationError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to get pool: {}", e)
+                    )
+                )
+            )?;
+
+        res_query.execute(&pool)
+            .await
+            .map_err(|e|
+                OperationError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to project operation deletion: {}", e)
+                    )
+                )
+            )?;
+
+        Ok(())
+    }
+
+    async fn apply_attachment_added(&self, event: &AttachmentAdded) -> Result<(), OperationError> {
+        let payload = event.payload();
+        let attachment = payload.attachment();
+        let view = AttachmentView::new(
+            attachment.id().value(),
+            attachment.file_name().to_string(),
+            attachment.content_type().to_string(),
+            attachment.size(),
+            *attachment.created_at(),
+        );
+
+        // A redelivered event does not list the attachment twice
+        let res_query = query("UPDATE operations SET attachments = attachments || jsonb_build_array($2::jsonb) WHERE id = $1 AND NOT attachments @> jsonb_build_array(jsonb_build_object('id', $3))")
+            .bind(payload.operation_id().value())
+            .bind(Json(view))
+            .bind(attachment.id().value());
+
+        let guard = self.db_manager.lock().await;
+        let pool = guard.pool()
+            .map_err(|e|
+                OperationError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to get pool: {}", e)
+                    )
+                )
+            )?;
+
+        res_query.execute(&pool)
+            .await
+            .map_err(|e|
+                OperationError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to project attachment: {}", e)
+                    )
+                )
+            )?;
+
+        Ok(())
+    }
+}
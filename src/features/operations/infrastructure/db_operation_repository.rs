@@ -1,23 +1,29 @@
 use std::sync::Arc;
 use async_trait::async_trait;
-use sqlx::query;
 use tokio::sync::Mutex;
+use uuid::Uuid;
 use crate::db::manager::DbManager;
 use crate::features::operations::domain::events::operation_created::OperationCreated;
+use crate::features::operations::domain::events::operation_event::OperationEvent;
+use crate::features::operations::domain::operation::Operation;
 use crate::features::operations::domain::operation_repository::OperationRepository;
 use crate::features::operations::error::OperationError;
 use crate::features::operations::infrastructure::error::InfrastructureError;
 use crate::services::serializer::Serializer;
+use crate::support::event_store::{Aggregate, EventStore, StorableEvent, Versioned};
+use crate::support::pg_event_store::PgEventStore;
+
+const EVENTS_TABLE: &str = "operation_events";
 
 pub struct DbOperationRepository {
-    db_manager: Arc<Mutex<DbManager>>,
+    event_store: PgEventStore,
     serializer: Serializer,
 }
 
 impl DbOperationRepository {
     pub fn new(db_manager: Arc<Mutex<DbManager>>, serializer: Serializer) -> Self {
         Self {
-            db_manager,
+            event_store: PgEventStore::new(db_manager, EVENTS_TABLE),
             serializer,
         }
     }
@@ -25,48 +31,51 @@ impl DbOperationRepository {
 
 #[async_trait]
 impl OperationRepository for DbOperationRepository {
-    async fn persist_operation_created_event(&self, operation_created: OperationCreated) -> Result<(), OperationError> {
-        let q = "INSERT INTO operation_events (id, name, payload) VALUES ($1, $2, $3)";
-
-        let payload = serde_json::to_value(operation_created.payload()).map_err(|e| {
-            OperationError::Infrastructure(
-                InfrastructureError::Repository(
-                    format!("Failed to serialize operation event payload: {}", e.to_string())
+    async fn load(&self, operation_id: Uuid) -> Result<Option<Versioned<Operation>>, OperationError> {
+        let stream = self.event_store.load(operation_id)
+            .await
+            .map_err(|e|
+                OperationError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to load operation events: {}", e)
+                    )
                 )
-            )
-        })?;
+            )?;
 
-        let res_query = query(&q)
-            .bind(operation_created.id().value())
-            .bind(operation_created.name())
-            .bind(&payload);
-
-        let mut guard = self.db_manager.lock().await;
-        guard.begin().await.map_err(|e| {
-            OperationError::Infrastructure(
-                InfrastructureError::Repository(
-                    format!("Failed to begin transaction: {}", e.to_string())
+        Operation::rehydrate(&stream)
+            .map_err(|e|
+                OperationError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to rehydrate operation: {}", e)
+                    )
                 )
             )
-        })?;
+    }
 
-        let tx = guard.transaction().await
-            .map_err(|e| {
+    async fn append(&self, operation_id: Uuid, expected_version: i32, events: &[OperationEvent]) -> Result<i32, OperationError> {
+        let events = events.iter()
+            .map(|event| event.to_new())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e|
                 OperationError::Infrastructure(
                     InfrastructureError::Repository(
-                        format!("Failed to get transaction: {}", e.to_string())
+                        format!("Failed to serialize operation event payload: {}", e)
                     )
                 )
-            })?;
+            )?;
 
-        res_query.execute(&mut **tx).await.map_err(|e| {
-            OperationError::Infrastructure(
-                InfrastructureError::Repository(
-                    format!("Failed to persist operation event: {}", e.to_string())
-                )
+        self.event_store.append(operation_id, expected_version, &events)
+            .await
+            .map_err(|e|
+                OperationError::Infrastructure(e.into())
             )
-        })?;
+    }
+
+    async fn persist_operation_created_event(&self, operation_created: OperationCreated) -> Result<(), OperationError> {
+        let operation_id = operation_created.payload().id().value();
+
+        self.append(operation_id, 0, &[OperationEvent::OperationCreated(operation_created)]).await?;
 
         Ok(())
     }
-}
\ No newline at end of file
+}
@@ -1,7 +1,39 @@
 use thiserror::Error;
+use crate::support::event_store::EventStoreError;
 
 #[derive(Debug, Clone, Error)]
 pub enum InfrastructureError {
     #[error("Repository error. {0}")]
     Repository(String),
-}
\ No newline at end of file
+
+    /// The aggregate stream was changed by another writer since it was loaded
+    #[error("Conflict error. {0}")]
+    Conflict(String),
+
+    #[error("Storage error. {0}")]
+    Storage(String),
+}
+
+impl From<EventStoreError> for InfrastructureError {
+    fn from(e: EventStoreError) -> Self {
+        match e {
+            EventStoreError::Concurrency { .. } => Self::Conflict(e.to_string()),
+            _ => Self::Repository(e.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+    use super::*;
+
+    #[test]
+    fn test_from_event_store_error() {
+        let concurrency = EventStoreError::Concurrency { aggregate_id: Uuid::new_v4(), expected: 1, actual: 2 };
+        let storage = EventStoreError::Storage("Connection refused".to_string());
+
+        assert!(matches!(InfrastructureError::from(concurrency), InfrastructureError::Conflict(..)));
+        assert!(matches!(InfrastructureError::from(storage), InfrastructureError::Repository(..)));
+    }
+}
@@ -0,0 +1,62 @@
+use async_trait::async_trait;
+use crate::events::error::EventError;
+use crate::events::event::Event;
+use crate::events::event_listener::EventListener;
+use crate::features::operations::application::queries::operation_projection_repository::OperationProjectionRepository;
+use crate::features::operations::domain::events::attachment_added::{ATTACHMENT_ADDED_NAME, AttachmentAdded};
+use crate::features::operations::domain::events::operation_event::OperationEvent;
+use crate::support::error::FeatureError;
+
+/// Lists added attachments on the operation in the `operations` read model.
+pub struct AttachmentAddedListener<R>
+    where
+        R: OperationProjectionRepository + Send + Sync + 'static,
+{
+    rep: R,
+}
+
+#[async_trait]
+impl<R> EventListener for AttachmentAddedListener<R>
+    where
+        R: OperationProjectionRepository + Send + Sync + 'static,
+{
+    async fn on_event(&mut self, event: Event) -> Result<Vec<Event>, EventError> {
+        let event = self.parse_event(event)?;
+
+        self.rep.apply_attachment_added(&event)
+            .await
+            .map_err(|e|
+                EventError::Feature(
+                    FeatureError::Operation(e)
+                )
+            )?;
+
+        Ok(vec![])
+    }
+
+    fn event_name(&self) -> &str {
+        ATTACHMENT_ADDED_NAME
+    }
+}
+
+impl<R> AttachmentAddedListener<R>
+    where
+        R: OperationProjectionRepository + Send + Sync + 'static,
+{
+    pub fn new(rep: R) -> Self {
+        Self {
+            rep,
+        }
+    }
+
+    pub fn parse_event(&self, event: Event) -> Result<AttachmentAdded, EventError> {
+        match event {
+            Event::OperationEvent(OperationEvent::AttachmentAdded(attachment_added)) => Ok(attachment_added),
+            _ => Err(
+                EventError::Parsing(
+                    format!("Invalid event type. Expected AttachmentAdded, got {:?}", event)
+                )
+            )
+        }
+    }
+}
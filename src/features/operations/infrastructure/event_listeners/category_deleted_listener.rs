@@ -0,0 +1,80 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use tokio::sync::Mutex;
+use crate::events::error::EventError;
+use crate::events::event::Event;
+use crate::events::event_listener::EventListener;
+use crate::features::categories::domain::events::category_deleted::{CATEGORY_DELETED_NAME, CategoryDeleted};
+use crate::features::categories::domain::events::category_event::CategoryEvent;
+use crate::features::operations::application::commands::reassign_category::command::ReassignCategoryCommand;
+use crate::features::operations::application::commands::reassign_category::handler::ReassignCategoryCommandHandler;
+use crate::features::operations::application::queries::operation_projection_repository::OperationProjectionRepository;
+use crate::features::operations::domain::operation_repository::OperationRepository;
+use crate::support::command_bus::CommandBus;
+
+/// Moves operations of a deleted category to the fallback category chosen by the user.
+pub struct CategoryDeletedListener<R, P>
+    where
+        R: OperationRepository + Send + Sync + 'static,
+        P: OperationProjectionRepository + Send + Sync + 'static,
+{
+    command_bus: Arc<Mutex<CommandBus<ReassignCategoryCommand, ReassignCategoryCommandHandler<R, P>>>>,
+}
+
+#[async_trait]
+impl<R, P> EventListener for CategoryDeletedListener<R, P>
+    where
+        R: OperationRepository + Send + Sync + 'static,
+        P: OperationProjectionRepository + Send + Sync + 'static,
+{
+    async fn on_event(&mut self, event: Event) -> Result<Vec<Event>, EventError> {
+        let event = self.parse_event(event)?;
+
+        let command = ReassignCategoryCommand::new(
+            event.payload().user_id().value(),
+            event.payload().id().value(),
+            event.payload().fallback_category_id().value(),
+        );
+
+        let mut guard = self.command_bus.lock().await;
+        let events = guard.dispatch(command)
+            .await
+            .map_err(EventError::Feature)?;
+
+        Ok(events)
+    }
+
+    fn event_name(&self) -> &str {
+        CATEGORY_DELETED_NAME
+    }
+}
+
+impl<R, P> CategoryDeletedListener<R, P>
+    where
+        R: OperationRepository + Send + Sync + 'static,
+        P: OperationProjectionRepository + Send + Sync + 'static,
+{
+    pub async fn new(
+        command_bus: Arc<Mutex<CommandBus<ReassignCategoryCommand, ReassignCategoryCommandHandler<R, P>>>>,
+        rep: R,
+        projection_rep: P,
+    ) -> Self {
+        let mut guard = command_bus.lock().await;
+        guard.register(ReassignCategoryCommandHandler::new(rep, projection_rep));
+
+        Self {
+            command_bus: command_bus.clone(),
+        }
+    }
+
+    pub fn parse_event(&self, event: Event) -> Result<CategoryDeleted, EventError> {
+        match event {
+            Event::CategoryEvent(CategoryEvent::CategoryDeleted(category_deleted)) => Ok(category_deleted),
+            _ => Err(
+                EventError::Parsing(
+                    format!("Invalid event type. Expected CategoryDeleted, got {:?}", event)
+                )
+            )
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use tokio::sync::Mutex;
+use crate::events::error::EventError;
+use crate::events::event::Event;
+use crate::events::event_listener::EventListener;
+use crate::features::operations::application::commands::reuse_category::command::ReuseCategoryCommand;
+use crate::features::operations::application::commands::reuse_category::handler::ReuseCategoryCommandHandler;
+use crate::features::operations::domain::operation_repository::OperationRepository;
+use crate::features::categories::domain::events::category_event::CategoryEvent;
+use crate::features::categories::domain::events::category_reused::{CATEGORY_REUSED_NAME, CategoryReused};
+use crate::support::command_bus::CommandBus;
+
+/// Points the operation that asked for a new category at the existing category with the same name.
+pub struct CategoryReusedListener<R>
+    where
+        R: OperationRepository + Send + Sync + 'static,
+{
+    command_bus: Arc<Mutex<CommandBus<ReuseCategoryCommand, ReuseCategoryCommandHandler<R>>>>,
+}
+
+#[async_trait]
+impl<R> EventListener for CategoryReusedListener<R>
+    where
+        R: OperationRepository + Send + Sync + 'static,
+{
+    async fn on_event(&mut self, event: Event) -> Result<Vec<Event>, EventError> {
+        let event = self.parse_event(event)?;
+
+        let command = ReuseCategoryCommand::new(
+            event.payload().operation_id().value(),
+            event.payload().user_id().value(),
+            event.payload().requested_category_id().value(),
+            event.payload().id().value(),
+        );
+
+        let mut guard = self.command_bus.lock().await;
+        let events = guard.dispatch(command)
+            .await
+            .map_err(EventError::Feature)?;
+
+        Ok(events)
+    }
+
+    fn event_name(&self) -> &str {
+        CATEGORY_REUSED_NAME
+    }
+}
+
+impl<R> CategoryReusedListener<R>
+    where
+        R: OperationRepository + Send + Sync + 'static,
+{
+    pub async fn new(
+        command_bus: Arc<Mutex<CommandBus<ReuseCategoryCommand, ReuseCategoryCommandHandler<R>>>>,
+        rep: R,
+    ) -> Self {
+        let mut guard = command_bus.lock().await;
+        guard.register(ReuseCategoryCommandHandler::new(rep));
+
+        Self {
+            command_bus: command_bus.clone(),
+        }
+    }
+
+    pub fn parse_event(&self, event: Event) -> Result<CategoryReused, EventError> {
+        match event {
+            Event::CategoryEvent(CategoryEvent::CategoryReused(category_reused)) => Ok(category_reused),
+            _ => Err(
+                EventError::Parsing(
+                    format!("Invalid event type. Expected CategoryReused, got {:?}", event)
+                )
+            )
+        }
+    }
+}
@@ -0,0 +1,8 @@
+pub mod attachment_added_listener;
+pub mod category_deleted_listener;
+pub mod category_reused_listener;
+pub mod operation_created_listener;
+pub mod operation_deleted_listener;
+pub mod operation_updated_listener;
+pub mod tag_deleted_listener;
+pub mod tag_reused_listener;
\ No newline at end of file
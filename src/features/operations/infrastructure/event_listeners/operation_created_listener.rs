@@ -0,0 +1,62 @@
+use async_trait::async_trait;
+use crate::events::error::EventError;
+use crate::events::event::Event;
+use crate::events::event_listener::EventListener;
+use crate::features::operations::application::queries::operation_projection_repository::OperationProjectionRepository;
+use crate::features::operations::domain::events::operation_created::{OPERATION_CREATED_NAME, OperationCreated};
+use crate::features::operations::domain::events::operation_event::OperationEvent;
+use crate::support::error::FeatureError;
+
+/// Keeps the `operations` read model in sync with the operation event stream.
+pub struct OperationCreatedListener<R>
+    where
+        R: OperationProjectionRepository + Send + Sync + 'static,
+{
+    rep: R,
+}
+
+#[async_trait]
+impl<R> EventListener for OperationCreatedListener<R>
+    where
+        R: OperationProjectionRepository + Send + Sync + 'static,
+{
+    async fn on_event(&mut self, event: Event) -> Result<Vec<Event>, EventError> {
+        let event = self.parse_event(event)?;
+
+        self.rep.apply_operation_created(&event)
+            .await
+            .map_err(|e|
+                EventError::Feature(
+                    FeatureError::Operation(e)
+                )
+            )?;
+
+        Ok(vec![])
+    }
+
+    fn event_name(&self) -> &str {
+        OPERATION_CREATED_NAME
+    }
+}
+
+impl<R> OperationCreatedListener<R>
+    where
+        R: OperationProjectionRepository + Send + Sync + 'static,
+{
+    pub fn new(rep: R) -> Self {
+        Self {
+            rep,
+        }
+    }
+
+    pub fn parse_event(&self, event: Event) -> Result<OperationCreated, EventError> {
+        match event {
+            Event::OperationEvent(OperationEvent::OperationCreated(operation_created)) => Ok(operation_created),
+            _ => Err(
+                EventError::Parsing(
+                    format!("Invalid event type. Expected OperationCreated, got {:?}", event)
+                )
+            )
+        }
+    }
+}
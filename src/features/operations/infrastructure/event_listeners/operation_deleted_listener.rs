@@ -0,0 +1,61 @@
+use async_trait::async_trait;
+use crate::events::error::EventError;
+use crate::events::event::Event;
+use crate::events::event_listener::EventListener;
+use crate::features::operations::application::queries::operation_projection_repository::OperationProjectionRepository;
+use crate::features::operations::domain::events::operation_deleted::{OPERATION_DELETED_NAME, OperationDeleted};
+use crate::features::operations::domain::events::operation_event::OperationEvent;
+use crate::support::error::FeatureError;
+
+pub struct OperationDeletedListener<R>
+    where
+        R: OperationProjectionRepository + Send + Sync + 'static,
+{
+    rep: R,
+}
+
+#[async_trait]
+impl<R> EventListener for OperationDeletedListener<R>
+    where
+        R: OperationProjectionRepository + Send + Sync + 'static,
+{
+    async fn on_event(&mut self, event: Event) -> Result<Vec<Event>, EventError> {
+        let event = self.parse_event(event)?;
+
+        self.rep.apply_operation_deleted(&event)
+            .await
+            .map_err(|e|
+                EventError::Feature(
+                    FeatureError::Operation(e)
+                )
+            )?;
+
+        Ok(vec![])
+    }
+
+    fn event_name(&self) -> &str {
+        OPERATION_DELETED_NAME
+    }
+}
+
+impl<R> OperationDeletedListener<R>
+    where
+        R: OperationProjectionRepository + Send + Sync + 'static,
+{
+    pub fn new(rep: R) -> Self {
+        Self {
+            rep,
+        }
+    }
+
+    pub fn parse_event(&self, event: Event) -> Result<OperationDeleted, EventError> {
+        match event {
+            Event::OperationEvent(OperationEvent::OperationDeleted(operation_deleted)) => Ok(operation_deleted),
+            _ => Err(
+                EventError::Parsing(
+                    format!("Invalid event type. Expected OperationDeleted, got {:?}", event)
+                )
+            )
+        }
+    }
+}
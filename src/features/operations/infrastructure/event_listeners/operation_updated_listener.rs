@@ -0,0 +1,61 @@
+use async_trait::async_trait;
+use crate::events::error::EventError;
+use crate::events::event::Event;
+use crate::events::event_listener::EventListener;
+use crate::features::operations::application::queries::operation_projection_repository::OperationProjectionRepository;
+use crate::features::operations::domain::events::operation_updated::{OPERATION_UPDATED_NAME, OperationUpdated};
+use crate::features::operations::domain::events::operation_event::OperationEvent;
+use crate::support::error::FeatureError;
+
+pub struct OperationUpdatedListener<R>
+    where
+        R: OperationProjectionRepository + Send + Sync + 'static,
+{
+    rep: R,
+}
+
+#[async_trait]
+impl<R> EventListener for OperationUpdatedListener<R>
+    where
+        R: OperationProjectionRepository + Send + Sync + 'static,
+{
+    async fn on_event(&mut self, event: Event) -> Result<Vec<Event>, EventError> {
+        let event = self.parse_event(event)?;
+
+        self.rep.apply_operation_updated(&event)
+            .await
+            .map_err(|e|
+                EventError::Feature(
+                    FeatureError::Operation(e)
+                )
+            )?;
+
+        Ok(vec![])
+    }
+
+    fn event_name(&self) -> &str {
+        OPERATION_UPDATED_NAME
+    }
+}
+
+impl<R> OperationUpdatedListener<R>
+    where
+        R: OperationProjectionRepository + Send + Sync + 'static,
+{
+    pub fn new(rep: R) -> Self {
+        Self {
+            rep,
+        }
+    }
+
+    pub fn parse_event(&self, event: Event) -> Result<OperationUpdated, EventError> {
+        match event {
+            Event::OperationEvent(OperationEvent::OperationUpdated(operation_updated)) => Ok(operation_updated),
+            _ => Err(
+                EventError::Parsing(
+                    format!("Invalid event type. Expected OperationUpdated, got {:?}", event)
+                )
+            )
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use tokio::sync::Mutex;
+use crate::events::error::EventError;
+use crate::events::event::Event;
+use crate::events::event_listener::EventListener;
+use crate::features::operations::application::commands::replace_tag::command::ReplaceTagCommand;
+use crate::features::operations::application::commands::replace_tag::handler::ReplaceTagCommandHandler;
+use crate::features::operations::application::queries::operation_projection_repository::OperationProjectionRepository;
+use crate::features::operations::domain::operation_repository::OperationRepository;
+use crate::features::tags::domain::events::tag_deleted::{TAG_DELETED_NAME, TagDeleted};
+use crate::features::tags::domain::events::tag_event::TagEvent;
+use crate::support::command_bus::CommandBus;
+
+/// Removes a deleted tag from operations, or replaces it with the tag it was merged into.
+pub struct TagDeletedListener<R, P>
+    where
+        R: OperationRepository + Send + Sync + 'static,
+        P: OperationProjectionRepository + Send + Sync + 'static,
+{
+    command_bus: Arc<Mutex<CommandBus<ReplaceTagCommand, ReplaceTagCommandHandler<R, P>>>>,
+}
+
+#[async_trait]
+impl<R, P> EventListener for TagDeletedListener<R, P>
+    where
+        R: OperationRepository + Send + Sync + 'static,
+        P: OperationProjectionRepository + Send + Sync + 'static,
+{
+    async fn on_event(&mut self, event: Event) -> Result<Vec<Event>, EventError> {
+        let event = self.parse_event(event)?;
+
+        let command = ReplaceTagCommand::new(
+            event.payload().user_id().value(),
+            event.payload().id().value(),
+            event.payload().merged_into().as_ref().map(|tag_id| tag_id.value()),
+        );
+
+        let mut guard = self.command_bus.lock().await;
+        let events = guard.dispatch(command)
+            .await
+            .map_err(EventError::Feature)?;
+
+        Ok(events)
+    }
+
+    fn event_name(&self) -> &str {
+        TAG_DELETED_NAME
+    }
+}
+
+impl<R, P> TagDeletedListener<R, P>
+    where
+        R: OperationRepository + Send + Sync + 'static,
+        P: OperationProjectionRepository + Send + Sync + 'static,
+{
+    pub async fn new(
+        command_bus: Arc<Mutex<CommandBus<ReplaceTagCommand, ReplaceTagCommandHandler<R, P>>>>,
+        rep: R,
+        projection_rep: P,
+    ) -> Self {
+        let mut guard = command_bus.lock().await;
+        guard.register(ReplaceTagCommandHandler::new(rep, projection_rep));
+
+        Self {
+            command_bus: command_bus.clone(),
+        }
+    }
+
+    pub fn parse_event(&self, event: Event) -> Result<TagDeleted, EventError> {
+        match event {
+            Event::TagEvent(TagEvent::TagDeleted(tag_deleted)) => Ok(tag_deleted),
+            _ => Err(
+                EventError::Parsing(
+                    format!("Invalid event type. Expected TagDeleted, got {:?}", event)
+                )
+            )
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use tokio::sync::Mutex;
+use crate::events::error::EventError;
+use crate::events::event::Event;
+use crate::events::event_listener::EventListener;
+use crate::features::operations::application::commands::reuse_tag::command::ReuseTagCommand;
+use crate::features::operations::application::commands::reuse_tag::handler::ReuseTagCommandHandler;
+use crate::features::operations::domain::operation_repository::OperationRepository;
+use crate::features::tags::domain::events::tag_event::TagEvent;
+use crate::features::tags::domain::events::tag_reused::{TAG_REUSED_NAME, TagReused};
+use crate::support::command_bus::CommandBus;
+
+/// Points the operation that asked for a new tag at the existing tag with the same name.
+pub struct TagReusedListener<R>
+    where
+        R: OperationRepository + Send + Sync + 'static,
+{
+    command_bus: Arc<Mutex<CommandBus<ReuseTagCommand, ReuseTagCommandHandler<R>>>>,
+}
+
+#[async_trait]
+impl<R> EventListener for TagReusedListener<R>
+    where
+        R: OperationRepository + Send + Sync + 'static,
+{
+    async fn on_event(&mut self, event: Event) -> Result<Vec<Event>, EventError> {
+        let event = self.parse_event(event)?;
+
+        let command = ReuseTagCommand::new(
+            event.payload().operation_id().value(),
+            event.payload().user_id().value(),
+            event.payload().requested_tag_id().value(),
+            event.payload().id().value(),
+        );
+
+        let mut guard = self.command_bus.lock().await;
+        let events = guard.dispatch(command)
+            .await
+            .map_err(EventError::Feature)?;
+
+        Ok(events)
+    }
+
+    fn event_name(&self) -> &str {
+        TAG_REUSED_NAME
+    }
+}
+
+impl<R> TagReusedListener<R>
+    where
+        R: OperationRepository + Send + Sync + 'static,
+{
+    pub async fn new(
+        command_bus: Arc<Mutex<CommandBus<ReuseTagCommand, ReuseTagCommandHandler<R>>>>,
+        rep: R,
+    ) -> Self {
+        let mut guard = command_bus.lock().await;
+        guard.register(ReuseTagCommandHandler::new(rep));
+
+        Self {
+            command_bus: command_bus.clone(),
+        }
+    }
+
+    pub fn parse_event(&self, event: Event) -> Result<TagReused, EventError> {
+        match event {
+            Event::TagEvent(TagEvent::TagReused(tag_reused)) => Ok(tag_reused),
+            _ => Err(
+                EventError::Parsing(
+                    format!("Invalid event type. Expected TagReused, got {:?}", event)
+                )
+            )
+        }
+    }
+}
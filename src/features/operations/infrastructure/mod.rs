@@ -1,2 +1,7 @@
+pub mod command_operation_creator;
 pub mod db_operation_repository;
-pub mod error;
\ No newline at end of file
+pub mod db_operation_projection_repository;
+pub mod event_listeners;
+pub mod error;
+pub mod projection_account_source;
+pub mod query_categorization_source;
\ No newline at end of file
@@ -0,0 +1,42 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use crate::features::accounts::application::queries::account_projection_repository::AccountProjectionRepository;
+use crate::features::operations::domain::account_source::{AccountSource, OperationAccount};
+use crate::support::error::FeatureError;
+
+/// Takes the accounts of operations from the accounts projection.
+pub struct ProjectionAccountSource<R>
+    where
+        R: AccountProjectionRepository + Send + Sync,
+{
+    rep: R,
+}
+
+impl<R> ProjectionAccountSource<R>
+    where
+        R: AccountProjectionRepository + Send + Sync,
+{
+    pub fn new(rep: R) -> Self {
+        Self {
+            rep,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> AccountSource for ProjectionAccountSource<R>
+    where
+        R: AccountProjectionRepository + Send + Sync,
+{
+    async fn account(&self, account_id: Uuid) -> Result<Option<OperationAccount>, FeatureError> {
+        let account = self.rep.find_by_id(account_id)
+            .await
+            .map_err(FeatureError::Account)?;
+
+        Ok(
+            account.map(|account|
+                OperationAccount::new(*account.user_id(), account.currency().to_string())
+            )
+        )
+    }
+}
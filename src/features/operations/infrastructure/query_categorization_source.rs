@@ -0,0 +1,54 @@
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use crate::features::operations::domain::categorization_source::{CategorizationSource, OperationCategorization};
+use crate::features::rules::application::queries::match_rule::handler::MatchRuleQueryHandler;
+use crate::features::rules::application::queries::match_rule::query::MatchRuleQuery;
+use crate::features::rules::application::queries::rule_projection_repository::RuleProjectionRepository;
+use crate::features::rules::domain::rule_target::RuleTarget;
+use crate::support::error::FeatureError;
+use crate::support::query_bus::QueryHandler;
+
+/// Takes the categorization of operations from the rules bounded context.
+pub struct QueryCategorizationSource<R>
+    where
+        R: RuleProjectionRepository + Send + Sync,
+{
+    handler: MatchRuleQueryHandler<R>,
+}
+
+impl<R> QueryCategorizationSource<R>
+    where
+        R: RuleProjectionRepository + Send + Sync,
+{
+    pub fn new(handler: MatchRuleQueryHandler<R>) -> Self {
+        Self {
+            handler,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> CategorizationSource for QueryCategorizationSource<R>
+    where
+        R: RuleProjectionRepository + Send + Sync,
+{
+    async fn categorization(
+        &self,
+        user_id: Uuid,
+        kind: &str,
+        label: &str,
+        currency: &str,
+        currency_amount: Decimal,
+    ) -> Result<Option<OperationCategorization>, FeatureError> {
+        let target = RuleTarget::new(kind.to_string(), label.to_string(), currency.to_string(), currency_amount);
+
+        self.handler.handle(MatchRuleQuery::new(user_id, target))
+            .await
+            .map(|categorization|
+                categorization.map(|categorization|
+                    OperationCategorization::new(*categorization.category_id(), categorization.tag_ids().to_vec())
+                )
+            )
+    }
+}
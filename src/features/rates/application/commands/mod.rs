@@ -0,0 +1 @@
+pub mod refresh_rates;
\ No newline at end of file
@@ -0,0 +1,32 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use crate::support::command_bus::Command;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefreshRatesCommand {
+    base: String,
+    date: NaiveDate,
+}
+
+impl Command for RefreshRatesCommand {
+    fn name() -> &'static str {
+        "RefreshRatesCommand"
+    }
+}
+
+impl RefreshRatesCommand {
+    pub fn new(base: String, date: NaiveDate) -> Self {
+        Self {
+            base,
+            date,
+        }
+    }
+
+    pub fn base(&self) -> &str {
+        &self.base
+    }
+
+    pub fn date(&self) -> &NaiveDate {
+        &self.date
+    }
+}
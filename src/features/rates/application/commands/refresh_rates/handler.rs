@@ -0,0 +1,107 @@
+use async_trait::async_trait;
+use crate::events::event::Event;
+use crate::features::rates::application::commands::refresh_rates::command::RefreshRatesCommand;
+use crate::features::rates::domain::exchange_rate::ExchangeRate;
+use crate::features::rates::domain::rate_provider::RateProvider;
+use crate::features::rates::domain::rate_repository::RateRepository;
+use crate::features::rates::error::RateError;
+use crate::support::command_bus::CommandHandler;
+use crate::support::error::FeatureError;
+
+/// Copies the provider's rates for a date into the local rate history.
+pub struct RefreshRatesCommandHandler<R, P>
+    where
+        R: RateRepository + Send + Sync,
+        P: RateProvider,
+{
+    rep: R,
+    provider: P,
+}
+
+impl<R, P> RefreshRatesCommandHandler<R, P>
+    where
+        R: RateRepository + Send + Sync,
+        P: RateProvider,
+{
+    pub fn new(rep: R, provider: P) -> Self {
+        Self {
+            rep,
+            provider,
+        }
+    }
+}
+
+#[async_trait]
+impl<R, P> CommandHandler<RefreshRatesCommand> for RefreshRatesCommandHandler<R, P>
+    where
+        R: RateRepository + Send + Sync,
+        P: RateProvider,
+{
+    async fn handle(&mut self, command: RefreshRatesCommand) -> Result<Vec<Event>, FeatureError> {
+        let base = ExchangeRate::currency(command.base())
+            .map_err(|e| FeatureError::Rate(RateError::Domain(e)))?;
+
+        let rates = self.provider.fetch(base, *command.date())
+            .await
+            .map_err(FeatureError::Rate)?;
+
+        self.rep.save(&rates)
+            .await
+            .map_err(FeatureError::Rate)?;
+
+        Ok(vec![])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use futures_util::FutureExt;
+    use mockall::predicate::eq;
+    use rust_decimal::Decimal;
+    use crate::features::rates::domain::rate_provider::MockRateProvider;
+    use crate::features::rates::domain::rate_repository::MockRateRepository;
+    use crate::support::currency::Currency;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle_saves_fetched_rates() {
+        let usd = Currency::find("USD").unwrap();
+        let eur = Currency::find("EUR").unwrap();
+        let date = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        let fetched = vec![ExchangeRate::new(usd, eur, date, Decimal::new(92, 2)).unwrap()];
+        let expected = fetched.clone();
+
+        let mut provider = MockRateProvider::new();
+        provider.expect_fetch()
+            .with(eq(usd), eq(date))
+            .times(1)
+            .returning(move |_, _| {
+                let rates = fetched.clone();
+                async move { Ok(rates) }.boxed()
+            });
+        let mut rep = MockRateRepository::new();
+        rep.expect_save()
+            .withf(move |rates| rates == expected.as_slice())
+            .times(1)
+            .returning(|_| async { Ok(()) }.boxed());
+
+        let mut handler = RefreshRatesCommandHandler::new(rep, provider);
+        let events = handler.handle(RefreshRatesCommand::new("USD".to_string(), date))
+            .await
+            .unwrap();
+
+        assert!(events.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_handle_unknown_currency() {
+        let mut provider = MockRateProvider::new();
+        provider.expect_fetch().never();
+
+        let mut handler = RefreshRatesCommandHandler::new(MockRateRepository::new(), provider);
+        let res = handler.handle(RefreshRatesCommand::new("XXX".to_string(), NaiveDate::from_ymd_opt(2024, 3, 1).unwrap())).await;
+
+        assert!(res.is_err());
+    }
+}
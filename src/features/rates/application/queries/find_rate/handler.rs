@@ -0,0 +1,249 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use crate::features::rates::application::queries::find_rate::query::FindRateQuery;
+use crate::features::rates::domain::error::DomainError;
+use crate::features::rates::domain::exchange_rate::ExchangeRate;
+use crate::features::rates::domain::rate_provider::RateProvider;
+use crate::features::rates::domain::rate_repository::RateRepository;
+use crate::features::rates::error::RateError;
+use crate::log_warning;
+use crate::support::currency::Currency;
+use crate::support::error::FeatureError;
+use crate::support::query_bus::QueryHandler;
+
+/// Answers from the local rate history and asks the provider only when the history has no rate for the requested date.
+pub struct FindRateQueryHandler<R, P>
+    where
+        R: RateRepository + Send + Sync,
+        P: RateProvider,
+{
+    rep: R,
+    provider: P,
+}
+
+impl<R, P> FindRateQueryHandler<R, P>
+    where
+        R: RateRepository + Send + Sync,
+        P: RateProvider,
+{
+    pub fn new(rep: R, provider: P) -> Self {
+        Self {
+            rep,
+            provider,
+        }
+    }
+
+    /// Latest cached rate of the pair, stored in either direction.
+    async fn cached(&self, base: Currency, quote: Currency, date: NaiveDate) -> Result<Option<ExchangeRate>, RateError> {
+        let direct = self.rep.find(base, quote, date).await?;
+        let inverse = self.rep.find(quote, base, date)
+            .await?
+            .map(|rate| rate.inverse());
+
+        Ok(
+            match (direct, inverse) {
+                (Some(direct), Some(inverse)) if inverse.date() > direct.date() => Some(inverse),
+                (Some(direct), _) => Some(direct),
+                (None, inverse) => inverse,
+            }
+        )
+    }
+}
+
+#[async_trait]
+impl<R, P> QueryHandler<FindRateQuery> for FindRateQueryHandler<R, P>
+    where
+        R: RateRepository + Send + Sync,
+        P: RateProvider,
+{
+    type Output = ExchangeRate;
+
+    async fn handle(&self, query: FindRateQuery) -> Result<ExchangeRate, FeatureError> {
+        let date = *query.date();
+        let base = ExchangeRate::currency(query.base())
+            .map_err(|e| FeatureError::Rate(RateError::Domain(e)))?;
+        let quote = ExchangeRate::currency(query.quote())
+            .map_err(|e| FeatureError::Rate(RateError::Domain(e)))?;
+
+        if base == quote {
+            return Ok(ExchangeRate::identity(base, date));
+        }
+
+        let cached = self.cached(base, quote, date)
+            .await
+            .map_err(FeatureError::Rate)?;
+
+        if let Some(rate) = &cached {
+            if rate.date() == &date {
+                return Ok(rate.clone());
+            }
+        }
+
+        match self.provider.fetch(base, date).await {
+            Ok(rates) => {
+                self.rep.save(&rates)
+                    .await
+                    .map_err(FeatureError::Rate)?;
+            }
+            // An older rate is still better than failing the request
+            Err(e) if cached.is_some() => {
+                log_warning!("Failed to fetch {} rates for {}, using the cached ones. {}", base, date, e);
+            }
+            Err(e) => return Err(FeatureError::Rate(e)),
+        }
+
+        self.cached(base, quote, date)
+            .await
+            .map_err(FeatureError::Rate)?
+            .ok_or_else(||
+                FeatureError::Rate(
+                    RateError::Domain(
+                        DomainError::RateNotFound(
+                            format!("{} to {} on {}", base, quote, date)
+                        )
+                    )
+                )
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::FutureExt;
+    use mockall::predicate::eq;
+    use rust_decimal::Decimal;
+    use crate::features::rates::domain::rate_provider::MockRateProvider;
+    use crate::features::rates::domain::rate_repository::MockRateRepository;
+    use crate::features::rates::infrastructure::error::InfrastructureError;
+    use super::*;
+
+    fn currencies() -> (Currency, Currency) {
+        (Currency::find("EUR").unwrap(), Currency::find("USD").unwrap())
+    }
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 3, 1).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_handle_same_currency() {
+        let handler = FindRateQueryHandler::new(MockRateRepository::new(), MockRateProvider::new());
+
+        let rate = handler.handle(FindRateQuery::new("USD".to_string(), "USD".to_string(), date()))
+            .await
+            .unwrap();
+
+        assert_eq!(rate.rate(), Decimal::ONE);
+    }
+
+    #[tokio::test]
+    async fn test_handle_unknown_currency() {
+        let handler = FindRateQueryHandler::new(MockRateRepository::new(), MockRateProvider::new());
+
+        let res = handler.handle(FindRateQuery::new("XXX".to_string(), "USD".to_string(), date())).await;
+
+        assert!(matches!(res, Err(FeatureError::Rate(RateError::Domain(DomainError::UnknownCurrency(_))))));
+    }
+
+    #[tokio::test]
+    async fn test_handle_uses_cached_inverse_rate() {
+        let (eur, usd) = currencies();
+        let mut rep = MockRateRepository::new();
+        rep.expect_find()
+            .with(eq(eur), eq(usd), eq(date()))
+            .returning(|_, _, _| async { Ok(None) }.boxed());
+        rep.expect_find()
+            .with(eq(usd), eq(eur), eq(date()))
+            .returning(move |_, _, _| async move { Ok(Some(ExchangeRate::new(usd, eur, date(), Decimal::new(8, 1)).unwrap())) }.boxed());
+        let mut provider = MockRateProvider::new();
+        provider.expect_fetch().never();
+
+        let handler = FindRateQueryHandler::new(rep, provider);
+        let rate = handler.handle(FindRateQuery::new("EUR".to_string(), "USD".to_string(), date()))
+            .await
+            .unwrap();
+
+        assert_eq!(rate.rate(), Decimal::new(125, 2));
+    }
+
+    #[tokio::test]
+    async fn test_handle_fetches_and_stores_missing_rate() {
+        let (eur, usd) = currencies();
+        let fetched = ExchangeRate::new(eur, usd, date(), Decimal::new(108, 2)).unwrap();
+        let stored = fetched.clone();
+
+        let mut rep = MockRateRepository::new();
+        let mut calls = 0;
+        rep.expect_find()
+            .with(eq(eur), eq(usd), eq(date()))
+            .returning(move |_, _, _| {
+                calls += 1;
+                let found = if calls > 1 { Some(stored.clone()) } else { None };
+                async move { Ok(found) }.boxed()
+            });
+        rep.expect_find()
+            .with(eq(usd), eq(eur), eq(date()))
+            .returning(|_, _, _| async { Ok(None) }.boxed());
+        rep.expect_save()
+            .times(1)
+            .returning(|_| async { Ok(()) }.boxed());
+        let mut provider = MockRateProvider::new();
+        provider.expect_fetch()
+            .with(eq(eur), eq(date()))
+            .times(1)
+            .returning(move |_, _| {
+                let rates = vec![fetched.clone()];
+                async move { Ok(rates) }.boxed()
+            });
+
+        let handler = FindRateQueryHandler::new(rep, provider);
+        let rate = handler.handle(FindRateQuery::new("EUR".to_string(), "USD".to_string(), date()))
+            .await
+            .unwrap();
+
+        assert_eq!(rate.rate(), Decimal::new(108, 2));
+    }
+
+    #[tokio::test]
+    async fn test_handle_falls_back_to_older_rate_when_provider_fails() {
+        let (eur, usd) = currencies();
+        let older = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+
+        let mut rep = MockRateRepository::new();
+        rep.expect_find()
+            .with(eq(eur), eq(usd), eq(date()))
+            .returning(move |_, _, _| async move { Ok(Some(ExchangeRate::new(eur, usd, older, Decimal::new(107, 2)).unwrap())) }.boxed());
+        rep.expect_find()
+            .with(eq(usd), eq(eur), eq(date()))
+            .returning(|_, _, _| async { Ok(None) }.boxed());
+        let mut provider = MockRateProvider::new();
+        provider.expect_fetch()
+            .returning(|_, _| async {
+                Err(RateError::Infrastructure(InfrastructureError::Provider("Unavailable".to_string())))
+            }.boxed());
+
+        let handler = FindRateQueryHandler::new(rep, provider);
+        let rate = handler.handle(FindRateQuery::new("EUR".to_string(), "USD".to_string(), date()))
+            .await
+            .unwrap();
+
+        assert_eq!(rate.date(), &older);
+    }
+
+    #[tokio::test]
+    async fn test_handle_provider_error_without_cache() {
+        let mut rep = MockRateRepository::new();
+        rep.expect_find()
+            .returning(|_, _, _| async { Ok(None) }.boxed());
+        let mut provider = MockRateProvider::new();
+        provider.expect_fetch()
+            .returning(|_, _| async {
+                Err(RateError::Infrastructure(InfrastructureError::Provider("Unavailable".to_string())))
+            }.boxed());
+
+        let handler = FindRateQueryHandler::new(rep, provider);
+        let res = handler.handle(FindRateQuery::new("EUR".to_string(), "USD".to_string(), date())).await;
+
+        assert!(matches!(res, Err(FeatureError::Rate(RateError::Infrastructure(_)))));
+    }
+}
@@ -0,0 +1,39 @@
+use chrono::NaiveDate;
+use crate::support::query_bus::Query;
+
+const NAME: &str = "find_rate";
+
+#[derive(Debug, Clone)]
+pub struct FindRateQuery {
+    base: String,
+    quote: String,
+    date: NaiveDate,
+}
+
+impl FindRateQuery {
+    pub fn new(base: String, quote: String, date: NaiveDate) -> Self {
+        Self {
+            base,
+            quote,
+            date,
+        }
+    }
+
+    pub fn base(&self) -> &str {
+        &self.base
+    }
+
+    pub fn quote(&self) -> &str {
+        &self.quote
+    }
+
+    pub fn date(&self) -> &NaiveDate {
+        &self.date
+    }
+}
+
+impl Query for FindRateQuery {
+    fn name() -> &'static str {
+        NAME
+    }
+}
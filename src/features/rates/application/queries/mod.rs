@@ -0,0 +1 @@
+pub mod find_rate;
\ No newline at end of file
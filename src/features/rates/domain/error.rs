@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone)]
+pub enum DomainError {
+    #[error("Unknown currency: {0}")]
+    UnknownCurrency(String),
+
+    #[error("Invalid rate: {0}")]
+    InvalidRate(String),
+
+    #[error("Rate not found: {0}")]
+    RateNotFound(String),
+}
@@ -0,0 +1,106 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use crate::features::rates::domain::error::DomainError;
+use crate::support::currency::Currency;
+
+/// Price of one unit of `base` in `quote` on `date`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExchangeRate {
+    base: Currency,
+    quote: Currency,
+    date: NaiveDate,
+    rate: Decimal,
+}
+
+impl ExchangeRate {
+    pub fn new(base: Currency, quote: Currency, date: NaiveDate, rate: Decimal) -> Result<Self, DomainError> {
+        if rate <= Decimal::ZERO {
+            return Err(
+                DomainError::InvalidRate(
+                    format!("Rate {} of {} to {} must be positive", rate, base, quote)
+                )
+            );
+        }
+
+        Ok(
+            Self {
+                base,
+                quote,
+                date,
+                rate,
+            }
+        )
+    }
+
+    /// Resolves a currency code of a rate request.
+    pub fn currency(code: &str) -> Result<Currency, DomainError> {
+        Currency::find(code)
+            .ok_or_else(|| DomainError::UnknownCurrency(code.to_string()))
+    }
+
+    /// Rate of a currency to itself.
+    pub fn identity(currency: Currency, date: NaiveDate) -> Self {
+        Self {
+            base: currency,
+            quote: currency,
+            date,
+            rate: Decimal::ONE,
+        }
+    }
+
+    pub fn base(&self) -> &Currency {
+        &self.base
+    }
+
+    pub fn quote(&self) -> &Currency {
+        &self.quote
+    }
+
+    pub fn date(&self) -> &NaiveDate {
+        &self.date
+    }
+
+    pub fn rate(&self) -> Decimal {
+        self.rate
+    }
+
+    /// Same rate seen from the quote currency.
+    pub fn inverse(&self) -> Self {
+        Self {
+            base: self.quote,
+            quote: self.base,
+            date: self.date,
+            rate: Decimal::ONE / self.rate,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_rejects_non_positive_rate() {
+        let usd = Currency::find("USD").unwrap();
+        let eur = Currency::find("EUR").unwrap();
+        let date = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+
+        assert!(ExchangeRate::new(usd, eur, date, Decimal::ZERO).is_err());
+        assert!(ExchangeRate::new(usd, eur, date, Decimal::NEGATIVE_ONE).is_err());
+    }
+
+    #[test]
+    fn test_inverse() {
+        let usd = Currency::find("USD").unwrap();
+        let eur = Currency::find("EUR").unwrap();
+        let date = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+
+        let rate = ExchangeRate::new(usd, eur, date, Decimal::new(8, 1)).unwrap();
+        let inverse = rate.inverse();
+
+        assert_eq!(inverse.base(), &eur);
+        assert_eq!(inverse.quote(), &usd);
+        assert_eq!(inverse.rate(), Decimal::new(125, 2));
+    }
+}
@@ -0,0 +1,5 @@
+pub mod error;
+pub mod exchange_rate;
+pub mod rate_provider;
+pub mod rate_repository;
+pub mod rate_source;
@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use mockall::automock;
+use crate::features::rates::domain::exchange_rate::ExchangeRate;
+use crate::features::rates::error::RateError;
+use crate::support::currency::Currency;
+
+/// Source of exchange rates outside the application.
+#[async_trait]
+#[automock]
+pub trait RateProvider: Send + Sync {
+    /// Rates of `base` to the other known currencies published for `date`.
+    /// Providers may answer with the closest earlier publication, the returned rates carry its date.
+    async fn fetch(&self, base: Currency, date: NaiveDate) -> Result<Vec<ExchangeRate>, RateError>;
+}
+
+#[async_trait]
+impl RateProvider for Box<dyn RateProvider> {
+    async fn fetch(&self, base: Currency, date: NaiveDate) -> Result<Vec<ExchangeRate>, RateError> {
+        self.as_ref().fetch(base, date).await
+    }
+}
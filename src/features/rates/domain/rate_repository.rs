@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use mockall::automock;
+use crate::features::rates::domain::exchange_rate::ExchangeRate;
+use crate::features::rates::error::RateError;
+use crate::support::currency::Currency;
+
+/// Local history of the rates received from a provider.
+#[async_trait]
+#[automock]
+pub trait RateRepository {
+    /// Latest known rate of `base` to `quote` dated `date` or earlier.
+    async fn find(&self, base: Currency, quote: Currency, date: NaiveDate) -> Result<Option<ExchangeRate>, RateError>;
+
+    /// Stores the rates, replacing the ones already known for the same pair and date.
+    async fn save(&self, rates: &[ExchangeRate]) -> Result<(), RateError>;
+}
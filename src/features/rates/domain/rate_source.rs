@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use mockall::automock;
+use rust_decimal::Decimal;
+use crate::support::error::FeatureError;
+
+/// Rates other bounded contexts use to bring amounts into the base currency, e.g. when the client leaves
+/// the rate of an operation to the application or to count a spending against a budget.
+#[async_trait]
+#[automock]
+pub trait RateSource {
+    /// Price of one unit of `currency` in the base currency on `date`.
+    async fn rate(&self, currency: &str, date: NaiveDate) -> Result<Decimal, FeatureError>;
+}
@@ -0,0 +1,12 @@
+use thiserror::Error;
+use crate::features::rates::domain::error::DomainError;
+use crate::features::rates::infrastructure::error::InfrastructureError;
+
+#[derive(Error, Debug, Clone)]
+pub enum RateError {
+    #[error("Rate domain error. {0}")]
+    Domain(DomainError),
+
+    #[error("Rate infrastructure error. {0}")]
+    Infrastructure(InfrastructureError),
+}
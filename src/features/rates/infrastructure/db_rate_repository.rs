@@ -0,0 +1,140 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use sqlx::{query, query_as, FromRow, Pool, Postgres};
+use tokio::sync::Mutex;
+use crate::db::manager::DbManager;
+use crate::features::rates::domain::exchange_rate::ExchangeRate;
+use crate::features::rates::domain::rate_repository::RateRepository;
+use crate::features::rates::error::RateError;
+use crate::features::rates::infrastructure::error::InfrastructureError;
+use crate::support::currency::Currency;
+
+#[derive(FromRow)]
+struct RateRow {
+    base: String,
+    quote: String,
+    date: NaiveDate,
+    rate: Decimal,
+}
+
+impl RateRow {
+    fn into_rate(self) -> Result<ExchangeRate, RateError> {
+        let currency = |code: &str| Currency::find(code)
+            .ok_or_else(||
+                RateError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Unknown currency {} in rate history", code)
+                    )
+                )
+            );
+
+        ExchangeRate::new(currency(&self.base)?, currency(&self.quote)?, self.date, self.rate)
+            .map_err(RateError::Domain)
+    }
+}
+
+#[derive(Clone)]
+pub struct DbRateRepository {
+    db_manager: Arc<Mutex<DbManager>>,
+}
+
+impl DbRateRepository {
+    pub fn new(db_manager: Arc<Mutex<DbManager>>) -> Self {
+        Self {
+            db_manager,
+        }
+    }
+
+    async fn pool(&self) -> Result<Pool<Postgres>, RateError> {
+        let guard = self.db_manager.lock().await;
+
+        guard.pool()
+            .map_err(|e|
+                RateError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to get pool: {}", e)
+                    )
+                )
+            )
+    }
+}
+
+#[async_trait]
+impl RateRepository for DbRateRepository {
+    async fn find(&self, base: Currency, quote: Currency, date: NaiveDate) -> Result<Option<ExchangeRate>, RateError> {
+        let q = "
+            SELECT base, quote, date, rate
+            FROM exchange_rates
+            WHERE base = $1 AND quote = $2 AND date <= $3
+            ORDER BY date DESC
+            LIMIT 1
+        ";
+
+        let pool = self.pool().await?;
+
+        query_as::<_, RateRow>(q)
+            .bind(base.code())
+            .bind(quote.code())
+            .bind(date)
+            .fetch_optional(&pool)
+            .await
+            .map_err(|e|
+                RateError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to fetch rate of {} to {}: {}", base, quote, e)
+                    )
+                )
+            )?
+            .map(RateRow::into_rate)
+            .transpose()
+    }
+
+    async fn save(&self, rates: &[ExchangeRate]) -> Result<(), RateError> {
+        let q = "
+            INSERT INTO exchange_rates (base, quote, date, rate)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (base, quote, date) DO UPDATE SET rate = EXCLUDED.rate
+        ";
+
+        let pool = self.pool().await?;
+
+        let mut tx = pool.begin()
+            .await
+            .map_err(|e|
+                RateError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to begin transaction: {}", e)
+                    )
+                )
+            )?;
+
+        for rate in rates {
+            query(q)
+                .bind(rate.base().code())
+                .bind(rate.quote().code())
+                .bind(rate.date())
+                .bind(rate.rate())
+                .execute(&mut *tx)
+                .await
+                .map_err(|e|
+                    RateError::Infrastructure(
+                        InfrastructureError::Repository(
+                            format!("Failed to save rate of {} to {}: {}", rate.base(), rate.quote(), e)
+                        )
+                    )
+                )?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e|
+                RateError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to commit transaction: {}", e)
+                    )
+                )
+            )
+    }
+}
@@ -0,0 +1,10 @@
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone)]
+pub enum InfrastructureError {
+    #[error("Rate provider error. {0}")]
+    Provider(String),
+
+    #[error("Rate repository error. {0}")]
+    Repository(String),
+}
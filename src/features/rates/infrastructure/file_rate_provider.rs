@@ -0,0 +1,94 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use crate::features::rates::domain::exchange_rate::ExchangeRate;
+use crate::features::rates::domain::rate_provider::RateProvider;
+use crate::features::rates::error::RateError;
+use crate::features::rates::infrastructure::error::InfrastructureError;
+use crate::features::rates::infrastructure::rates_snapshot::RatesSnapshot;
+use crate::support::currency::Currency;
+
+/// Reads rates from a JSON file holding a list of snapshots, so that the application can run offline.
+pub struct FileRateProvider {
+    path: String,
+}
+
+impl FileRateProvider {
+    pub fn new(path: String) -> Self {
+        Self {
+            path,
+        }
+    }
+}
+
+#[async_trait]
+impl RateProvider for FileRateProvider {
+    async fn fetch(&self, base: Currency, date: NaiveDate) -> Result<Vec<ExchangeRate>, RateError> {
+        let content = tokio::fs::read_to_string(&self.path)
+            .await
+            .map_err(|e|
+                RateError::Infrastructure(
+                    InfrastructureError::Provider(
+                        format!("Failed to read rates file {}: {}", self.path, e)
+                    )
+                )
+            )?;
+
+        let snapshots: Vec<RatesSnapshot> = serde_json::from_str(&content)
+            .map_err(|e|
+                RateError::Infrastructure(
+                    InfrastructureError::Provider(
+                        format!("Failed to parse rates file {}: {}", self.path, e)
+                    )
+                )
+            )?;
+
+        // Like the public APIs, answer with the latest publication that is not newer than the date
+        snapshots.into_iter()
+            .filter(|snapshot| snapshot.base() == base.code() && snapshot.date() <= &date)
+            .max_by_key(|snapshot| *snapshot.date())
+            .map(RatesSnapshot::into_rates)
+            .unwrap_or(Ok(vec![]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+    use super::*;
+
+    fn provider() -> FileRateProvider {
+        FileRateProvider::new(format!("{}/tests/fixtures/rates.json", env!("CARGO_MANIFEST_DIR")))
+    }
+
+    #[tokio::test]
+    async fn test_fetch_latest_snapshot_before_date() {
+        let rates = provider()
+            .fetch(Currency::find("USD").unwrap(), NaiveDate::from_ymd_opt(2024, 3, 3).unwrap())
+            .await
+            .unwrap();
+
+        let eur = rates.iter().find(|rate| rate.quote().code() == "EUR").unwrap();
+
+        assert_eq!(eur.date(), &NaiveDate::from_ymd_opt(2024, 3, 1).unwrap());
+        assert_eq!(eur.rate(), Decimal::new(9245, 4));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_before_first_snapshot() {
+        let rates = provider()
+            .fetch(Currency::find("USD").unwrap(), NaiveDate::from_ymd_opt(2020, 1, 1).unwrap())
+            .await
+            .unwrap();
+
+        assert!(rates.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_missing_file() {
+        let provider = FileRateProvider::new("missing.json".to_string());
+
+        let res = provider.fetch(Currency::find("USD").unwrap(), NaiveDate::from_ymd_opt(2024, 3, 1).unwrap()).await;
+
+        assert!(res.is_err());
+    }
+}
@@ -0,0 +1,91 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use crate::features::rates::domain::exchange_rate::ExchangeRate;
+use crate::features::rates::domain::rate_provider::RateProvider;
+use crate::features::rates::error::RateError;
+use crate::features::rates::infrastructure::error::InfrastructureError;
+use crate::features::rates::infrastructure::rates_snapshot::RatesSnapshot;
+use crate::services::http_client::HttpClient;
+use crate::support::currency::Currency;
+
+/// Fetches rates from a Frankfurter-compatible API, `GET {url}/{date}?from={base}`.
+pub struct HttpRateProvider<C>
+    where
+        C: HttpClient + Send + Sync,
+{
+    client: C,
+    url: String,
+}
+
+impl<C> HttpRateProvider<C>
+    where
+        C: HttpClient + Send + Sync,
+{
+    pub fn new(client: C, url: String) -> Self {
+        Self {
+            client,
+            url,
+        }
+    }
+}
+
+#[async_trait]
+impl<C> RateProvider for HttpRateProvider<C>
+    where
+        C: HttpClient + Send + Sync,
+{
+    async fn fetch(&self, base: Currency, date: NaiveDate) -> Result<Vec<ExchangeRate>, RateError> {
+        let url = format!("{}/{}?from={}", self.url.trim_end_matches('/'), date.format("%Y-%m-%d"), base.code());
+
+        let body = self.client.get(&url)
+            .await
+            .map_err(|e|
+                RateError::Infrastructure(
+                    InfrastructureError::Provider(e.to_string())
+                )
+            )?;
+
+        let snapshot: RatesSnapshot = serde_json::from_str(&body)
+            .map_err(|e|
+                RateError::Infrastructure(
+                    InfrastructureError::Provider(
+                        format!("Failed to parse rates from {}: {}", url, e)
+                    )
+                )
+            )?;
+
+        snapshot.into_rates()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+    use crate::test_utils::stub_http_client::StubHttpClient;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_fetch() {
+        let client = StubHttpClient::new(r#"{"amount": 1.0, "base": "USD", "date": "2024-02-29", "rates": {"EUR": 0.92, "GEL": 2.65}}"#);
+        let provider = HttpRateProvider::new(client, "https://rates.example.com/".to_string());
+
+        let rates = provider.fetch(Currency::find("USD").unwrap(), NaiveDate::from_ymd_opt(2024, 3, 2).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(provider.client.requested(), [("https://rates.example.com/2024-03-02?from=USD".to_string(), None)]);
+        assert_eq!(rates.len(), 2);
+        assert_eq!(rates[0].date(), &NaiveDate::from_ymd_opt(2024, 2, 29).unwrap());
+        assert_eq!(rates[1].rate(), Decimal::new(265, 2));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_invalid_body() {
+        let client = StubHttpClient::new("Not found");
+        let provider = HttpRateProvider::new(client, "https://rates.example.com".to_string());
+
+        let res = provider.fetch(Currency::find("USD").unwrap(), NaiveDate::from_ymd_opt(2024, 3, 2).unwrap()).await;
+
+        assert!(matches!(res, Err(RateError::Infrastructure(InfrastructureError::Provider(_)))));
+    }
+}
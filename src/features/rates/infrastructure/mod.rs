@@ -0,0 +1,8 @@
+pub mod db_rate_repository;
+pub mod error;
+pub mod file_rate_provider;
+pub mod http_rate_provider;
+pub mod query_rate_source;
+pub mod rate_provider_factory;
+pub mod rate_refresher;
+pub mod rates_snapshot;
@@ -0,0 +1,48 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use crate::features::rates::application::queries::find_rate::handler::FindRateQueryHandler;
+use crate::features::rates::application::queries::find_rate::query::FindRateQuery;
+use crate::features::rates::domain::rate_provider::RateProvider;
+use crate::features::rates::domain::rate_repository::RateRepository;
+use crate::features::rates::domain::rate_source::RateSource;
+use crate::support::error::FeatureError;
+use crate::support::query_bus::QueryHandler;
+
+/// Takes the rates of currencies from the rates bounded context.
+pub struct QueryRateSource<R, P>
+    where
+        R: RateRepository + Send + Sync,
+        P: RateProvider,
+{
+    handler: FindRateQueryHandler<R, P>,
+    base_currency: String,
+}
+
+impl<R, P> QueryRateSource<R, P>
+    where
+        R: RateRepository + Send + Sync,
+        P: RateProvider,
+{
+    pub fn new(handler: FindRateQueryHandler<R, P>, base_currency: String) -> Self {
+        Self {
+            handler,
+            base_currency,
+        }
+    }
+}
+
+#[async_trait]
+impl<R, P> RateSource for QueryRateSource<R, P>
+    where
+        R: RateRepository + Send + Sync,
+        P: RateProvider,
+{
+    async fn rate(&self, currency: &str, date: NaiveDate) -> Result<Decimal, FeatureError> {
+        let query = FindRateQuery::new(currency.to_string(), self.base_currency.clone(), date);
+
+        self.handler.handle(query)
+            .await
+            .map(|rate| rate.rate())
+    }
+}
@@ -0,0 +1,33 @@
+use crate::config::structs::rates::RatesConfig;
+use crate::features::rates::domain::rate_provider::RateProvider;
+use crate::features::rates::error::RateError;
+use crate::features::rates::infrastructure::error::InfrastructureError;
+use crate::features::rates::infrastructure::file_rate_provider::FileRateProvider;
+use crate::features::rates::infrastructure::http_rate_provider::HttpRateProvider;
+use crate::services::http_client::ReqwestClient;
+
+pub struct RateProviderFactory;
+
+impl RateProviderFactory {
+    pub fn create(config: &RatesConfig) -> Result<Box<dyn RateProvider>, RateError> {
+        match config.provider() {
+            "http" => Ok(
+                Box::new(
+                    HttpRateProvider::new(ReqwestClient::new(), config.url().to_string())
+                )
+            ),
+            "file" => Ok(
+                Box::new(
+                    FileRateProvider::new(config.file().to_string())
+                )
+            ),
+            provider => Err(
+                RateError::Infrastructure(
+                    InfrastructureError::Provider(
+                        format!("Unknown rate provider {}", provider)
+                    )
+                )
+            ),
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use std::sync::Arc;
+use std::time::Duration;
+use chrono::Utc;
+use tokio::task::JoinHandle;
+use crate::di::service_container::ServiceContainer;
+use crate::features::rates::application::commands::refresh_rates::command::RefreshRatesCommand;
+use crate::features::rates::application::commands::refresh_rates::handler::RefreshRatesCommandHandler;
+use crate::features::rates::infrastructure::db_rate_repository::DbRateRepository;
+use crate::support::error::FeatureError;
+use crate::{log_error, log_info};
+
+/// Keeps today's rates of the base currency in the local rate history.
+pub struct RateRefresher;
+
+impl RateRefresher {
+    pub fn spawn(service_container: Arc<ServiceContainer>) -> JoinHandle<()> {
+        let interval = service_container.config().rates().refresh_interval();
+
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(Duration::from_secs(interval.max(1)));
+
+            loop {
+                ticker.tick().await;
+
+                match Self::refresh(&service_container).await {
+                    Ok(()) => {
+                        log_info!("Exchange rates refreshed");
+                    }
+                    Err(e) => {
+                        log_error!("Failed to refresh exchange rates. {}", e);
+                    }
+                }
+            }
+        })
+    }
+
+    async fn refresh(service_container: &ServiceContainer) -> Result<(), FeatureError> {
+        let provider = service_container.rate_provider()
+            .map_err(FeatureError::Rate)?;
+        let rep = DbRateRepository::new(service_container.db_manager());
+
+        let command = RefreshRatesCommand::new(
+            service_container.config().rates().base_currency().to_string(),
+            Utc::now().date_naive(),
+        );
+
+        let mut command_bus = service_container.command_bus();
+        command_bus.register(RefreshRatesCommandHandler::new(rep, provider));
+        command_bus.dispatch(command).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+use std::collections::BTreeMap;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use crate::features::rates::domain::exchange_rate::ExchangeRate;
+use crate::features::rates::error::RateError;
+use crate::features::rates::infrastructure::error::InfrastructureError;
+use crate::support::currency::Currency;
+
+/// Rates of one base currency as published by Frankfurter-compatible sources,
+/// e.g. `{"base": "USD", "date": "2024-03-01", "rates": {"EUR": 0.92}}`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RatesSnapshot {
+    base: String,
+    date: NaiveDate,
+    rates: BTreeMap<String, Decimal>,
+}
+
+impl RatesSnapshot {
+    pub fn base(&self) -> &str {
+        &self.base
+    }
+
+    pub fn date(&self) -> &NaiveDate {
+        &self.date
+    }
+
+    /// Rates of the snapshot, currencies missing from the registry are skipped.
+    pub fn into_rates(self) -> Result<Vec<ExchangeRate>, RateError> {
+        let base = Currency::find(&self.base)
+            .ok_or_else(||
+                RateError::Infrastructure(
+                    InfrastructureError::Provider(
+                        format!("Unknown base currency {}", self.base)
+                    )
+                )
+            )?;
+
+        self.rates.iter()
+            .filter_map(|(code, rate)| Currency::find(code).map(|quote| (quote, *rate)))
+            .map(|(quote, rate)|
+                ExchangeRate::new(base, quote, self.date, rate)
+                    .map_err(RateError::Domain)
+            )
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into_rates_skips_unknown_currencies() {
+        let snapshot: RatesSnapshot = serde_json::from_str(
+            r#"{"amount": 1.0, "base": "USD", "date": "2024-03-01", "rates": {"EUR": 0.9245, "XYZ": 3.5}}"#
+        ).unwrap();
+
+        let rates = snapshot.into_rates().unwrap();
+
+        assert_eq!(rates.len(), 1);
+        assert_eq!(rates[0].quote().code(), "EUR");
+        assert_eq!(rates[0].rate(), Decimal::new(9245, 4));
+    }
+}
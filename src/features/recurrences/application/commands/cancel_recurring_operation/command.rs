@@ -0,0 +1,33 @@
+use uuid::Uuid;
+use crate::support::command_bus::Command;
+
+const NAME : &str = "cancel_recurring_operation";
+
+#[derive(Debug, Clone)]
+pub struct CancelRecurringOperationCommand {
+    recurring_operation_id: Uuid,
+    user_id: Uuid,
+}
+
+impl CancelRecurringOperationCommand {
+    pub fn new(recurring_operation_id: Uuid, user_id: Uuid) -> Self {
+        Self {
+            recurring_operation_id,
+            user_id,
+        }
+    }
+
+    pub fn recurring_operation_id(&self) -> &Uuid {
+        &self.recurring_operation_id
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+}
+
+impl Command for CancelRecurringOperationCommand {
+    fn name() -> &'static str {
+        NAME
+    }
+}
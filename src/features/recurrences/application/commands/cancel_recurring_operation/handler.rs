@@ -0,0 +1,90 @@
+use async_trait::async_trait;
+use crate::events::event::Event;
+use crate::features::recurrences::application::commands::cancel_recurring_operation::command::CancelRecurringOperationCommand;
+use crate::features::recurrences::domain::error::DomainError;
+use crate::features::recurrences::domain::recurring_operation_repository::RecurringOperationRepository;
+use crate::features::recurrences::error::RecurrenceError;
+use crate::support::command_bus::CommandHandler;
+use crate::support::error::FeatureError;
+
+pub struct CancelRecurringOperationCommandHandler<R>
+    where
+        R: RecurringOperationRepository + Send + Sync,
+{
+    recurring_operation_repository: R,
+}
+
+impl<R> CancelRecurringOperationCommandHandler<R>
+    where
+        R: RecurringOperationRepository + Send + Sync,
+{
+    pub fn new(recurring_operation_repository: R) -> Self {
+        Self {
+            recurring_operation_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> CommandHandler<CancelRecurringOperationCommand> for CancelRecurringOperationCommandHandler<R>
+    where
+        R: RecurringOperationRepository + Send + Sync,
+{
+    async fn handle(&mut self, command: CancelRecurringOperationCommand) -> Result<Vec<Event>, FeatureError> {
+        let recurring_operation = self.recurring_operation_repository.load(*command.recurring_operation_id())
+            .await
+            .map_err(FeatureError::Recurrence)?
+            .ok_or(
+                FeatureError::Recurrence(
+                    RecurrenceError::Domain(
+                        DomainError::RecurringOperationNotFound(command.recurring_operation_id().to_string())
+                    )
+                )
+            )?;
+
+        let event = recurring_operation.aggregate().handle_cancellation(command)
+            .map_err(|e|
+                FeatureError::Recurrence(
+                    RecurrenceError::Domain(e)
+                )
+            )?;
+
+        self.recurring_operation_repository.append(recurring_operation.aggregate().id().value(), recurring_operation.version(), std::slice::from_ref(&event))
+            .await
+            .map_err(FeatureError::Recurrence)?;
+
+        Ok(
+            vec![Event::RecurringOperationEvent(event)]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+    use crate::features::recurrences::domain::events::recurring_operation_event::RecurringOperationEvent;
+    use crate::features::recurrences::domain::recurring_operation::tests::recurring_operation_fixture;
+    use crate::features::recurrences::domain::recurring_operation_repository::MockRecurringOperationRepository;
+    use crate::support::event_store::Versioned;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle_success() {
+        let recurring_operation = recurring_operation_fixture();
+        let command = CancelRecurringOperationCommand::new(recurring_operation.id().value(), recurring_operation.user_id().value());
+        let rep = MockRecurringOperationRepository::with_recurring_operations(vec![Versioned::new(recurring_operation, 1)]);
+
+        let events = CancelRecurringOperationCommandHandler::new(rep).handle(command).await.unwrap();
+
+        assert!(matches!(events[0], Event::RecurringOperationEvent(RecurringOperationEvent::RecurringOperationCancelled(_))));
+    }
+
+    #[tokio::test]
+    async fn test_handle_unknown_recurring_operation() {
+        let command = CancelRecurringOperationCommand::new(Uuid::new_v4(), Uuid::new_v4());
+
+        let result = CancelRecurringOperationCommandHandler::new(MockRecurringOperationRepository::new(false)).handle(command).await;
+
+        assert!(matches!(result, Err(FeatureError::Recurrence(RecurrenceError::Domain(DomainError::RecurringOperationNotFound(_))))));
+    }
+}
@@ -0,0 +1,153 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use crate::support::command_bus::Command;
+
+const NAME : &str = "create_recurring_operation";
+
+#[derive(Debug, Clone)]
+pub struct CreateRecurringOperationCommand {
+    user_id: Uuid,
+    recurrence: RecurrenceData,
+    template: TemplateData,
+}
+
+impl CreateRecurringOperationCommand {
+    pub fn new(user_id: Uuid, recurrence: RecurrenceData, template: TemplateData) -> Self {
+        Self {
+            user_id,
+            recurrence,
+            template,
+        }
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    pub fn recurrence(&self) -> &RecurrenceData {
+        &self.recurrence
+    }
+
+    pub fn template(&self) -> &TemplateData {
+        &self.template
+    }
+}
+
+impl Command for CreateRecurringOperationCommand {
+    fn name() -> &'static str {
+        NAME
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RecurrenceData {
+    frequency: String,
+    interval: u32,
+    starts_on: NaiveDate,
+    until: Option<NaiveDate>,
+    count: Option<u32>,
+}
+
+impl RecurrenceData {
+    pub fn new(frequency: String, interval: u32, starts_on: NaiveDate, until: Option<NaiveDate>, count: Option<u32>) -> Self {
+        Self {
+            frequency,
+            interval,
+            starts_on,
+            until,
+            count,
+        }
+    }
+
+    pub fn frequency(&self) -> &str {
+        &self.frequency
+    }
+
+    pub fn interval(&self) -> u32 {
+        self.interval
+    }
+
+    pub fn starts_on(&self) -> &NaiveDate {
+        &self.starts_on
+    }
+
+    /// Last day an occurrence may fall on.
+    pub fn until(&self) -> &Option<NaiveDate> {
+        &self.until
+    }
+
+    /// Number of occurrences, unlimited when omitted.
+    pub fn count(&self) -> Option<u32> {
+        self.count
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TemplateData {
+    kind: String,
+    account_id: Uuid,
+    category_id: Option<Uuid>,
+    category_name: String,
+    currency: String,
+    currency_amount: Decimal,
+    label: String,
+    transfer_account_id: Option<Uuid>,
+}
+
+impl TemplateData {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        kind: String,
+        account_id: Uuid,
+        category_id: Option<Uuid>,
+        category_name: String,
+        currency: String,
+        currency_amount: Decimal,
+        label: String,
+        transfer_account_id: Option<Uuid>,
+    ) -> Self {
+        Self {
+            kind,
+            account_id,
+            category_id,
+            category_name,
+            currency,
+            currency_amount,
+            label,
+            transfer_account_id,
+        }
+    }
+
+    pub fn kind(&self) -> &str {
+        &self.kind
+    }
+
+    pub fn account_id(&self) -> &Uuid {
+        &self.account_id
+    }
+
+    pub fn category_id(&self) -> &Option<Uuid> {
+        &self.category_id
+    }
+
+    pub fn category_name(&self) -> &str {
+        &self.category_name
+    }
+
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    pub fn currency_amount(&self) -> Decimal {
+        self.currency_amount
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub fn transfer_account_id(&self) -> &Option<Uuid> {
+        &self.transfer_account_id
+    }
+}
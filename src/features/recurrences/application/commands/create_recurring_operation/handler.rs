@@ -0,0 +1,107 @@
+use async_trait::async_trait;
+use crate::events::event::Event;
+use crate::features::recurrences::application::commands::create_recurring_operation::command::CreateRecurringOperationCommand;
+use crate::features::recurrences::domain::events::recurring_operation_event::RecurringOperationEvent;
+use crate::features::recurrences::domain::recurring_operation::RecurringOperation;
+use crate::features::recurrences::domain::recurring_operation_repository::RecurringOperationRepository;
+use crate::features::recurrences::error::RecurrenceError;
+use crate::support::command_bus::CommandHandler;
+use crate::support::error::FeatureError;
+
+pub struct CreateRecurringOperationCommandHandler<R>
+    where
+        R: RecurringOperationRepository + Send + Sync,
+{
+    recurring_operation_repository: R,
+}
+
+impl<R> CreateRecurringOperationCommandHandler<R>
+    where
+        R: RecurringOperationRepository + Send + Sync,
+{
+    pub fn new(recurring_operation_repository: R) -> Self {
+        Self {
+            recurring_operation_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> CommandHandler<CreateRecurringOperationCommand> for CreateRecurringOperationCommandHandler<R>
+    where
+        R: RecurringOperationRepository + Send + Sync,
+{
+    async fn handle(&mut self, command: CreateRecurringOperationCommand) -> Result<Vec<Event>, FeatureError> {
+        let event = RecurringOperation::handle_creation(command)
+            .map_err(|e|
+                FeatureError::Recurrence(
+                    RecurrenceError::Domain(e)
+                )
+            )?;
+
+        if let RecurringOperationEvent::RecurringOperationCreated(created) = &event {
+            self.recurring_operation_repository.append(created.payload().id().value(), 0, std::slice::from_ref(&event))
+                .await
+                .map_err(FeatureError::Recurrence)?;
+        }
+
+        Ok(
+            vec![Event::RecurringOperationEvent(event)]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use rust_decimal::Decimal;
+    use uuid::Uuid;
+    use crate::features::recurrences::application::commands::create_recurring_operation::command::{RecurrenceData, TemplateData};
+    use crate::features::recurrences::domain::error::DomainError;
+    use crate::features::recurrences::domain::recurring_operation_repository::MockRecurringOperationRepository;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle_success() {
+        let mut handler = CreateRecurringOperationCommandHandler::new(MockRecurringOperationRepository::new(false));
+
+        let events = handler.handle(command_fixture("Monthly")).await.unwrap();
+
+        assert!(matches!(events[0], Event::RecurringOperationEvent(RecurringOperationEvent::RecurringOperationCreated(_))));
+    }
+
+    #[tokio::test]
+    async fn test_handle_unknown_frequency() {
+        let mut handler = CreateRecurringOperationCommandHandler::new(MockRecurringOperationRepository::new(false));
+
+        let result = handler.handle(command_fixture("Hourly")).await;
+
+        assert!(matches!(result, Err(FeatureError::Recurrence(RecurrenceError::Domain(DomainError::UnknownFrequency(_))))));
+    }
+
+    #[tokio::test]
+    async fn test_handle_repository_error() {
+        let mut handler = CreateRecurringOperationCommandHandler::new(MockRecurringOperationRepository::new(true));
+
+        let result = handler.handle(command_fixture("Monthly")).await;
+
+        assert!(matches!(result, Err(FeatureError::Recurrence(RecurrenceError::Infrastructure(_)))));
+    }
+
+    fn command_fixture(frequency: &str) -> CreateRecurringOperationCommand {
+        CreateRecurringOperationCommand::new(
+            Uuid::new_v4(),
+            RecurrenceData::new(frequency.to_string(), 1, NaiveDate::from_ymd_opt(2024, 4, 1).unwrap(), None, None),
+            TemplateData::new(
+                "Income".to_string(),
+                Uuid::new_v4(),
+                None,
+                "Salary".to_string(),
+                "USD".to_string(),
+                Decimal::from(3000),
+                "Salary".to_string(),
+                None,
+            ),
+        )
+    }
+}
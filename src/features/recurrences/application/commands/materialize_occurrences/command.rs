@@ -0,0 +1,35 @@
+use chrono::NaiveDate;
+use uuid::Uuid;
+use crate::support::command_bus::Command;
+
+const NAME : &str = "materialize_occurrences";
+
+#[derive(Debug, Clone)]
+pub struct MaterializeOccurrencesCommand {
+    recurring_operation_id: Uuid,
+    date: NaiveDate,
+}
+
+impl MaterializeOccurrencesCommand {
+    pub fn new(recurring_operation_id: Uuid, date: NaiveDate) -> Self {
+        Self {
+            recurring_operation_id,
+            date,
+        }
+    }
+
+    pub fn recurring_operation_id(&self) -> &Uuid {
+        &self.recurring_operation_id
+    }
+
+    /// Occurrences due on or before this day are created.
+    pub fn date(&self) -> &NaiveDate {
+        &self.date
+    }
+}
+
+impl Command for MaterializeOccurrencesCommand {
+    fn name() -> &'static str {
+        NAME
+    }
+}
@@ -0,0 +1,245 @@
+use async_trait::async_trait;
+use crate::events::event::Event;
+use crate::features::operations::domain::operation_creator::OperationCreator;
+use crate::features::operations::error::OperationError;
+use crate::features::recurrences::application::commands::materialize_occurrences::command::MaterializeOccurrencesCommand;
+use crate::features::recurrences::domain::error::DomainError;
+use crate::features::recurrences::domain::events::recurring_operation_event::RecurringOperationEvent;
+use crate::features::recurrences::domain::recurring_operation::RecurringOperation;
+use crate::features::recurrences::domain::recurring_operation_repository::RecurringOperationRepository;
+use crate::features::recurrences::error::RecurrenceError;
+use crate::support::command_bus::CommandHandler;
+use crate::support::error::FeatureError;
+use crate::support::id::Id;
+
+/// Creates the operation of the next due occurrence, one occurrence per command.
+///
+/// The occurrence is scheduled with the id of its operation before the operation is created.
+/// If the creation was interrupted, the next command creates the missing operation under the
+/// same id instead of scheduling a new occurrence, so a restart never duplicates operations.
+/// A creation rejected by the operations domain would fail the same way on every run,
+/// so it pauses the recurring operation with the reason instead of being retried until the user resumes it.
+/// No events are returned once nothing is due or the recurring operation is paused.
+pub struct MaterializeOccurrencesCommandHandler<R, C>
+    where
+        R: RecurringOperationRepository + Send + Sync,
+        C: OperationCreator + Send + Sync,
+{
+    recurring_operation_repository: R,
+    operations: C,
+}
+
+impl<R, C> MaterializeOccurrencesCommandHandler<R, C>
+    where
+        R: RecurringOperationRepository + Send + Sync,
+        C: OperationCreator + Send + Sync,
+{
+    pub fn new(recurring_operation_repository: R, operations: C) -> Self {
+        Self {
+            recurring_operation_repository,
+            operations,
+        }
+    }
+
+    /// Creates the operation of an occurrence, pausing the recurring operation at `version` if it can never be created.
+    async fn create(&self, recurring_operation: &RecurringOperation, version: i32, operation_id: &Id) -> Result<Vec<Event>, FeatureError> {
+        let command = recurring_operation.template().to_command(recurring_operation.user_id().value(), operation_id.value());
+
+        match self.operations.create(command).await {
+            Err(e @ FeatureError::Operation(OperationError::Domain(_))) => {
+                let event = recurring_operation.handle_pause(operation_id.clone(), e.to_string());
+
+                self.recurring_operation_repository.append(recurring_operation.id().value(), version, std::slice::from_ref(&event))
+                    .await
+                    .map_err(FeatureError::Recurrence)?;
+
+                Ok(vec![Event::RecurringOperationEvent(event)])
+            }
+            res => res,
+        }
+    }
+}
+
+#[async_trait]
+impl<R, C> CommandHandler<MaterializeOccurrencesCommand> for MaterializeOccurrencesCommandHandler<R, C>
+    where
+        R: RecurringOperationRepository + Send + Sync,
+        C: OperationCreator + Send + Sync,
+{
+    async fn handle(&mut self, command: MaterializeOccurrencesCommand) -> Result<Vec<Event>, FeatureError> {
+        let recurring_operation = self.recurring_operation_repository.load(*command.recurring_operation_id())
+            .await
+            .map_err(FeatureError::Recurrence)?
+            .ok_or(
+                FeatureError::Recurrence(
+                    RecurrenceError::Domain(
+                        DomainError::RecurringOperationNotFound(command.recurring_operation_id().to_string())
+                    )
+                )
+            )?;
+
+        if recurring_operation.aggregate().is_paused() {
+            return Ok(vec![]);
+        }
+
+        if let Some(operation_id) = recurring_operation.aggregate().last_operation_id() {
+            if !self.operations.exists(operation_id.value()).await? {
+                return self.create(recurring_operation.aggregate(), recurring_operation.version(), operation_id).await;
+            }
+        }
+
+        let Some(event) = recurring_operation.aggregate().handle_occurrence(command.date()) else {
+            return Ok(vec![]);
+        };
+
+        self.recurring_operation_repository.append(recurring_operation.aggregate().id().value(), recurring_operation.version(), std::slice::from_ref(&event))
+            .await
+            .map_err(FeatureError::Recurrence)?;
+
+        let mut events = vec![];
+
+        if let RecurringOperationEvent::OccurrenceScheduled(scheduled) = &event {
+            events.extend(
+                self.create(recurring_operation.aggregate(), recurring_operation.version() + 1, scheduled.payload().operation_id()).await?
+            );
+        }
+
+        events.insert(0, Event::RecurringOperationEvent(event));
+
+        Ok(events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use futures_util::FutureExt;
+    use mockall::predicate::eq;
+    use crate::features::operations::domain::error::DomainError as OperationDomainError;
+    use crate::features::operations::domain::operation_creator::MockOperationCreator;
+    use crate::features::recurrences::domain::recurring_operation::RecurringOperation;
+    use crate::features::recurrences::domain::recurring_operation::tests::recurring_operation_fixture;
+    use crate::features::recurrences::domain::recurring_operation_repository::MockRecurringOperationRepository;
+    use crate::support::event_store::{Aggregate, Versioned};
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle_schedules_and_creates_due_occurrence() {
+        let recurring_operation = recurring_operation_fixture();
+        let command = MaterializeOccurrencesCommand::new(recurring_operation.id().value(), date(2024, 3, 5));
+
+        let mut operations = MockOperationCreator::new();
+        operations.expect_exists().never();
+        operations.expect_create()
+            .withf(|command| command.operation_id().is_some())
+            .times(1)
+            .returning(|_| async { Ok(vec![]) }.boxed());
+
+        let rep = MockRecurringOperationRepository::with_recurring_operations(vec![Versioned::new(recurring_operation, 1)]);
+        let events = MaterializeOccurrencesCommandHandler::new(rep, operations).handle(command).await.unwrap();
+
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], Event::RecurringOperationEvent(RecurringOperationEvent::OccurrenceScheduled(_))));
+    }
+
+    #[tokio::test]
+    async fn test_handle_creates_interrupted_occurrence_with_same_id() {
+        let recurring_operation = scheduled_fixture();
+        let operation_id = recurring_operation.last_operation_id().clone().unwrap().value();
+        let command = MaterializeOccurrencesCommand::new(recurring_operation.id().value(), date(2024, 3, 5));
+
+        let mut operations = MockOperationCreator::new();
+        operations.expect_exists()
+            .with(eq(operation_id))
+            .returning(|_| async { Ok(false) }.boxed());
+        operations.expect_create()
+            .withf(move |command| *command.operation_id() == Some(operation_id))
+            .times(1)
+            .returning(|_| async { Ok(vec![]) }.boxed());
+
+        let rep = MockRecurringOperationRepository::with_recurring_operations(vec![Versioned::new(recurring_operation, 2)]);
+        let events = MaterializeOccurrencesCommandHandler::new(rep, operations).handle(command).await.unwrap();
+
+        assert!(events.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_handle_skips_created_occurrence() {
+        let recurring_operation = scheduled_fixture();
+        let operation_id = recurring_operation.last_operation_id().clone().unwrap().value();
+        let command = MaterializeOccurrencesCommand::new(recurring_operation.id().value(), date(2024, 3, 5));
+
+        let mut operations = MockOperationCreator::new();
+        operations.expect_exists()
+            .returning(|_| async { Ok(true) }.boxed());
+        operations.expect_create()
+            .withf(move |command| *command.operation_id() != Some(operation_id))
+            .times(1)
+            .returning(|_| async { Ok(vec![]) }.boxed());
+
+        let rep = MockRecurringOperationRepository::with_recurring_operations(vec![Versioned::new(recurring_operation, 2)]);
+        let events = MaterializeOccurrencesCommandHandler::new(rep, operations).handle(command).await.unwrap();
+
+        assert!(matches!(events[0], Event::RecurringOperationEvent(RecurringOperationEvent::OccurrenceScheduled(_))));
+    }
+
+    #[tokio::test]
+    async fn test_handle_pauses_on_rejected_creation() {
+        let recurring_operation = recurring_operation_fixture();
+        let command = MaterializeOccurrencesCommand::new(recurring_operation.id().value(), date(2024, 3, 5));
+
+        let mut operations = MockOperationCreator::new();
+        operations.expect_create()
+            .times(1)
+            .returning(|_| async { Err(FeatureError::Operation(OperationError::Domain(OperationDomainError::UnknownCurrency))) }.boxed());
+
+        let rep = MockRecurringOperationRepository::with_recurring_operations(vec![Versioned::new(recurring_operation, 1)]);
+        let events = MaterializeOccurrencesCommandHandler::new(rep, operations).handle(command).await.unwrap();
+
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[0], Event::RecurringOperationEvent(RecurringOperationEvent::OccurrenceScheduled(_))));
+        assert!(matches!(&events[1], Event::RecurringOperationEvent(RecurringOperationEvent::RecurringOperationPaused(paused)) if paused.payload().reason().contains("Unknown currency")));
+    }
+
+    #[tokio::test]
+    async fn test_handle_paused() {
+        let recurring_operation = scheduled_fixture();
+        let event = recurring_operation.handle_pause(recurring_operation.last_operation_id().clone().unwrap(), "Unknown currency".to_string());
+        let recurring_operation = RecurringOperation::apply(Some(recurring_operation), &event).unwrap();
+        let command = MaterializeOccurrencesCommand::new(recurring_operation.id().value(), date(2024, 3, 5));
+
+        let mut operations = MockOperationCreator::new();
+        operations.expect_exists().never();
+        operations.expect_create().never();
+
+        let rep = MockRecurringOperationRepository::with_recurring_operations(vec![Versioned::new(recurring_operation, 3)]);
+        let events = MaterializeOccurrencesCommandHandler::new(rep, operations).handle(command).await.unwrap();
+
+        assert!(events.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_handle_nothing_due() {
+        let recurring_operation = recurring_operation_fixture();
+        let command = MaterializeOccurrencesCommand::new(recurring_operation.id().value(), date(2024, 1, 4));
+
+        let mut operations = MockOperationCreator::new();
+        operations.expect_create().never();
+
+        let rep = MockRecurringOperationRepository::with_recurring_operations(vec![Versioned::new(recurring_operation, 1)]);
+        let events = MaterializeOccurrencesCommandHandler::new(rep, operations).handle(command).await.unwrap();
+
+        assert!(events.is_empty());
+    }
+
+    fn scheduled_fixture() -> RecurringOperation {
+        let recurring_operation = recurring_operation_fixture();
+        let event = recurring_operation.handle_occurrence(&date(2024, 1, 5)).unwrap();
+
+        RecurringOperation::apply(Some(recurring_operation), &event).unwrap()
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+}
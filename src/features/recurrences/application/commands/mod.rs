@@ -0,0 +1,4 @@
+pub mod cancel_recurring_operation;
+pub mod create_recurring_operation;
+pub mod materialize_occurrences;
+pub mod resume_recurring_operation;
@@ -0,0 +1,33 @@
+use uuid::Uuid;
+use crate::support::command_bus::Command;
+
+const NAME : &str = "resume_recurring_operation";
+
+#[derive(Debug, Clone)]
+pub struct ResumeRecurringOperationCommand {
+    recurring_operation_id: Uuid,
+    user_id: Uuid,
+}
+
+impl ResumeRecurringOperationCommand {
+    pub fn new(recurring_operation_id: Uuid, user_id: Uuid) -> Self {
+        Self {
+            recurring_operation_id,
+            user_id,
+        }
+    }
+
+    pub fn recurring_operation_id(&self) -> &Uuid {
+        &self.recurring_operation_id
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+}
+
+impl Command for ResumeRecurringOperationCommand {
+    fn name() -> &'static str {
+        NAME
+    }
+}
@@ -0,0 +1,96 @@
+use async_trait::async_trait;
+use crate::events::event::Event;
+use crate::features::recurrences::application::commands::resume_recurring_operation::command::ResumeRecurringOperationCommand;
+use crate::features::recurrences::domain::error::DomainError;
+use crate::features::recurrences::domain::recurring_operation_repository::RecurringOperationRepository;
+use crate::features::recurrences::error::RecurrenceError;
+use crate::support::command_bus::CommandHandler;
+use crate::support::error::FeatureError;
+
+/// Resumes a paused recurring operation, the occurrence that paused it is created again on the next run.
+pub struct ResumeRecurringOperationCommandHandler<R>
+    where
+        R: RecurringOperationRepository + Send + Sync,
+{
+    recurring_operation_repository: R,
+}
+
+impl<R> ResumeRecurringOperationCommandHandler<R>
+    where
+        R: RecurringOperationRepository + Send + Sync,
+{
+    pub fn new(recurring_operation_repository: R) -> Self {
+        Self {
+            recurring_operation_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> CommandHandler<ResumeRecurringOperationCommand> for ResumeRecurringOperationCommandHandler<R>
+    where
+        R: RecurringOperationRepository + Send + Sync,
+{
+    async fn handle(&mut self, command: ResumeRecurringOperationCommand) -> Result<Vec<Event>, FeatureError> {
+        let recurring_operation = self.recurring_operation_repository.load(*command.recurring_operation_id())
+            .await
+            .map_err(FeatureError::Recurrence)?
+            .ok_or(
+                FeatureError::Recurrence(
+                    RecurrenceError::Domain(
+                        DomainError::RecurringOperationNotFound(command.recurring_operation_id().to_string())
+                    )
+                )
+            )?;
+
+        let event = recurring_operation.aggregate().handle_resume(command)
+            .map_err(|e|
+                FeatureError::Recurrence(
+                    RecurrenceError::Domain(e)
+                )
+            )?;
+
+        self.recurring_operation_repository.append(recurring_operation.aggregate().id().value(), recurring_operation.version(), std::slice::from_ref(&event))
+            .await
+            .map_err(FeatureError::Recurrence)?;
+
+        Ok(
+            vec![Event::RecurringOperationEvent(event)]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::features::recurrences::domain::events::recurring_operation_event::RecurringOperationEvent;
+    use crate::features::recurrences::domain::recurring_operation::RecurringOperation;
+    use crate::features::recurrences::domain::recurring_operation::tests::recurring_operation_fixture;
+    use crate::features::recurrences::domain::recurring_operation_repository::MockRecurringOperationRepository;
+    use crate::support::event_store::{Aggregate, Versioned};
+    use crate::support::id::Id;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle_success() {
+        let recurring_operation = recurring_operation_fixture();
+        let event = recurring_operation.handle_pause(Id::new(Id::generate()), "Account not found".to_string());
+        let recurring_operation = RecurringOperation::apply(Some(recurring_operation), &event).unwrap();
+        let command = ResumeRecurringOperationCommand::new(recurring_operation.id().value(), recurring_operation.user_id().value());
+        let rep = MockRecurringOperationRepository::with_recurring_operations(vec![Versioned::new(recurring_operation, 2)]);
+
+        let events = ResumeRecurringOperationCommandHandler::new(rep).handle(command).await.unwrap();
+
+        assert!(matches!(events[0], Event::RecurringOperationEvent(RecurringOperationEvent::RecurringOperationResumed(_))));
+    }
+
+    #[tokio::test]
+    async fn test_handle_not_paused() {
+        let recurring_operation = recurring_operation_fixture();
+        let command = ResumeRecurringOperationCommand::new(recurring_operation.id().value(), recurring_operation.user_id().value());
+        let rep = MockRecurringOperationRepository::with_recurring_operations(vec![Versioned::new(recurring_operation, 1)]);
+
+        let result = ResumeRecurringOperationCommandHandler::new(rep).handle(command).await;
+
+        assert!(matches!(result, Err(FeatureError::Recurrence(RecurrenceError::Domain(DomainError::NotPaused)))));
+    }
+}
@@ -0,0 +1,101 @@
+use async_trait::async_trait;
+use crate::features::recurrences::application::queries::list_recurring_operations::query::ListRecurringOperationsQuery;
+use crate::features::recurrences::application::queries::recurring_operation_projection_repository::RecurringOperationProjectionRepository;
+use crate::features::recurrences::application::queries::recurring_operation_view::RecurringOperationView;
+use crate::support::error::FeatureError;
+use crate::support::query_bus::QueryHandler;
+
+pub struct ListRecurringOperationsQueryHandler<R>
+    where
+        R: RecurringOperationProjectionRepository + Send + Sync,
+{
+    rep: R,
+}
+
+impl<R> ListRecurringOperationsQueryHandler<R>
+    where
+        R: RecurringOperationProjectionRepository + Send + Sync,
+{
+    pub fn new(rep: R) -> Self {
+        Self {
+            rep,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> QueryHandler<ListRecurringOperationsQuery> for ListRecurringOperationsQueryHandler<R>
+    where
+        R: RecurringOperationProjectionRepository + Send + Sync,
+{
+    type Output = Vec<RecurringOperationView>;
+
+    async fn handle(&self, query: ListRecurringOperationsQuery) -> Result<Vec<RecurringOperationView>, FeatureError> {
+        self.rep.find(*query.user_id())
+            .await
+            .map_err(FeatureError::Recurrence)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, Utc};
+    use futures_util::FutureExt;
+    use mockall::predicate::eq;
+    use rust_decimal::Decimal;
+    use uuid::Uuid;
+    use crate::features::recurrences::application::queries::recurring_operation_projection_repository::MockRecurringOperationProjectionRepository;
+    use crate::features::recurrences::domain::operation_template::OperationTemplate;
+    use crate::features::recurrences::error::RecurrenceError;
+    use crate::features::recurrences::infrastructure::error::InfrastructureError;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle_success() {
+        let user_id = Uuid::new_v4();
+        let template = OperationTemplate::new("Expense", Uuid::new_v4(), None, "Subscriptions", "USD", Decimal::from(10), "Music", None).unwrap();
+        let views = vec![
+            RecurringOperationView::new(
+                Uuid::new_v4(),
+                user_id,
+                "Monthly".to_string(),
+                1,
+                NaiveDate::from_ymd_opt(2024, 1, 10).unwrap(),
+                None,
+                None,
+                template,
+                3,
+                NaiveDate::from_ymd_opt(2024, 4, 10),
+                false,
+                None,
+                Utc::now(),
+            ),
+        ];
+
+        let mut rep = MockRecurringOperationProjectionRepository::new();
+        rep.expect_find()
+            .with(eq(user_id))
+            .returning(move |_| {
+                let views = views.clone();
+                async move { Ok(views) }.boxed()
+            });
+
+        let result = ListRecurringOperationsQueryHandler::new(rep).handle(ListRecurringOperationsQuery::new(user_id)).await.unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].template().label(), "Music");
+    }
+
+    #[tokio::test]
+    async fn test_handle_repository_error() {
+        let mut rep = MockRecurringOperationProjectionRepository::new();
+        rep.expect_find()
+            .returning(|_| async {
+                Err(RecurrenceError::Infrastructure(InfrastructureError::Repository("Mock repository error".into())))
+            }.boxed());
+
+        let result = ListRecurringOperationsQueryHandler::new(rep).handle(ListRecurringOperationsQuery::new(Uuid::new_v4())).await;
+
+        assert!(matches!(result, Err(FeatureError::Recurrence(_))));
+    }
+}
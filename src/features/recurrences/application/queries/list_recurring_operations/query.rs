@@ -0,0 +1,27 @@
+use uuid::Uuid;
+use crate::support::query_bus::Query;
+
+const NAME: &str = "list_recurring_operations";
+
+#[derive(Debug, Clone)]
+pub struct ListRecurringOperationsQuery {
+    user_id: Uuid,
+}
+
+impl ListRecurringOperationsQuery {
+    pub fn new(user_id: Uuid) -> Self {
+        Self {
+            user_id,
+        }
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+}
+
+impl Query for ListRecurringOperationsQuery {
+    fn name() -> &'static str {
+        NAME
+    }
+}
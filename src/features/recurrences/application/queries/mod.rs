@@ -0,0 +1,3 @@
+pub mod list_recurring_operations;
+pub mod recurring_operation_projection_repository;
+pub mod recurring_operation_view;
@@ -0,0 +1,30 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use mockall::automock;
+use uuid::Uuid;
+use crate::features::recurrences::application::queries::recurring_operation_view::RecurringOperationView;
+use crate::features::recurrences::domain::events::occurrence_scheduled::OccurrenceScheduled;
+use crate::features::recurrences::domain::events::recurring_operation_cancelled::RecurringOperationCancelled;
+use crate::features::recurrences::domain::events::recurring_operation_created::RecurringOperationCreated;
+use crate::features::recurrences::domain::events::recurring_operation_paused::RecurringOperationPaused;
+use crate::features::recurrences::domain::events::recurring_operation_resumed::RecurringOperationResumed;
+use crate::features::recurrences::error::RecurrenceError;
+
+#[async_trait]
+#[automock]
+pub trait RecurringOperationProjectionRepository {
+    async fn find(&self, user_id: Uuid) -> Result<Vec<RecurringOperationView>, RecurrenceError>;
+
+    /// Ids of the recurring operations with an occurrence due on or before `date`.
+    async fn find_due(&self, date: NaiveDate) -> Result<Vec<Uuid>, RecurrenceError>;
+
+    async fn apply_recurring_operation_created(&self, event: &RecurringOperationCreated) -> Result<(), RecurrenceError>;
+
+    async fn apply_occurrence_scheduled(&self, event: &OccurrenceScheduled) -> Result<(), RecurrenceError>;
+
+    async fn apply_recurring_operation_cancelled(&self, event: &RecurringOperationCancelled) -> Result<(), RecurrenceError>;
+
+    async fn apply_recurring_operation_paused(&self, event: &RecurringOperationPaused) -> Result<(), RecurrenceError>;
+
+    async fn apply_recurring_operation_resumed(&self, event: &RecurringOperationResumed) -> Result<(), RecurrenceError>;
+}
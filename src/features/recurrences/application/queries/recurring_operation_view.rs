@@ -0,0 +1,127 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use sqlx::types::Json;
+use uuid::Uuid;
+use crate::features::recurrences::domain::error::DomainError;
+use crate::features::recurrences::domain::frequency::Frequency;
+use crate::features::recurrences::domain::operation_template::OperationTemplate;
+use crate::features::recurrences::domain::recurrence_rule::RecurrenceRule;
+
+/// Read model row of the `recurring_operations` projection.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct RecurringOperationView {
+    id: Uuid,
+    user_id: Uuid,
+    frequency: String,
+    interval: i32,
+    starts_on: NaiveDate,
+    until: Option<NaiveDate>,
+    count: Option<i32>,
+    template: Json<OperationTemplate>,
+    occurrences: i32,
+    next_due_on: Option<NaiveDate>,
+    cancelled: bool,
+    paused_reason: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+impl RecurringOperationView {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: Uuid,
+        user_id: Uuid,
+        frequency: String,
+        interval: i32,
+        starts_on: NaiveDate,
+        until: Option<NaiveDate>,
+        count: Option<i32>,
+        template: OperationTemplate,
+        occurrences: i32,
+        next_due_on: Option<NaiveDate>,
+        cancelled: bool,
+        paused_reason: Option<String>,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            frequency,
+            interval,
+            starts_on,
+            until,
+            count,
+            template: Json(template),
+            occurrences,
+            next_due_on,
+            cancelled,
+            paused_reason,
+            created_at,
+        }
+    }
+
+    pub fn id(&self) -> &Uuid {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    pub fn frequency(&self) -> &str {
+        &self.frequency
+    }
+
+    pub fn interval(&self) -> i32 {
+        self.interval
+    }
+
+    pub fn starts_on(&self) -> &NaiveDate {
+        &self.starts_on
+    }
+
+    pub fn until(&self) -> &Option<NaiveDate> {
+        &self.until
+    }
+
+    pub fn count(&self) -> Option<i32> {
+        self.count
+    }
+
+    pub fn template(&self) -> &OperationTemplate {
+        &self.template
+    }
+
+    /// Number of occurrences scheduled so far.
+    pub fn occurrences(&self) -> i32 {
+        self.occurrences
+    }
+
+    pub fn next_due_on(&self) -> &Option<NaiveDate> {
+        &self.next_due_on
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled
+    }
+
+    /// Why the operation of the latest occurrence could not be created, `None` unless paused.
+    pub fn paused_reason(&self) -> &Option<String> {
+        &self.paused_reason
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    /// Rule the projected row was created with.
+    pub fn rule(&self) -> Result<RecurrenceRule, DomainError> {
+        RecurrenceRule::new(
+            Frequency::new(&self.frequency)?,
+            self.interval as u32,
+            self.starts_on,
+            self.until,
+            self.count.map(|count| count as u32),
+        )
+    }
+}
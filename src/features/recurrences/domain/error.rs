@@ -0,0 +1,25 @@
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone)]
+pub enum DomainError {
+    #[error("Recurring operation {0} not found")]
+    RecurringOperationNotFound(String),
+
+    #[error("Recurring operation belongs to another user")]
+    AccessDenied,
+
+    #[error("Recurring operation is already cancelled")]
+    AlreadyCancelled,
+
+    #[error("Recurring operation is not paused")]
+    NotPaused,
+
+    #[error("Unknown frequency {0}")]
+    UnknownFrequency(String),
+
+    #[error("Invalid recurrence. {0}")]
+    InvalidRecurrence(String),
+
+    #[error("Invalid operation template. {0}")]
+    InvalidTemplate(String),
+}
@@ -0,0 +1,6 @@
+pub mod occurrence_scheduled;
+pub mod recurring_operation_cancelled;
+pub mod recurring_operation_created;
+pub mod recurring_operation_paused;
+pub mod recurring_operation_resumed;
+pub mod recurring_operation_event;
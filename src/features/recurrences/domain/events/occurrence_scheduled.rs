@@ -0,0 +1,79 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use crate::support::id::Id;
+
+pub const OCCURRENCE_SCHEDULED_NAME: &str = "occurrence_scheduled";
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct OccurrenceScheduled {
+    id: Id,
+    name: String,
+    payload: OccurrenceScheduledPayload,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct OccurrenceScheduledPayload {
+    id: Id,
+    user_id: Id,
+    number: u32,
+    due_on: NaiveDate,
+    operation_id: Id,
+    next_due_on: Option<NaiveDate>,
+}
+
+impl OccurrenceScheduled {
+    pub fn new(id: Id, recurring_operation_id: Id, user_id: Id, number: u32, due_on: NaiveDate, operation_id: Id, next_due_on: Option<NaiveDate>) -> Self {
+        Self {
+            id,
+            name: OCCURRENCE_SCHEDULED_NAME.to_string(),
+            payload: OccurrenceScheduledPayload {
+                id: recurring_operation_id,
+                user_id,
+                number,
+                due_on,
+                operation_id,
+                next_due_on,
+            }
+        }
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn payload(&self) -> &OccurrenceScheduledPayload {
+        &self.payload
+    }
+}
+
+impl OccurrenceScheduledPayload {
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Id {
+        &self.user_id
+    }
+
+    /// Zero-based number of the occurrence.
+    pub fn number(&self) -> u32 {
+        self.number
+    }
+
+    pub fn due_on(&self) -> &NaiveDate {
+        &self.due_on
+    }
+
+    /// Id reserved for the operation of the occurrence before it is created.
+    pub fn operation_id(&self) -> &Id {
+        &self.operation_id
+    }
+
+    pub fn next_due_on(&self) -> &Option<NaiveDate> {
+        &self.next_due_on
+    }
+}
@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+use crate::support::id::Id;
+
+pub const RECURRING_OPERATION_CANCELLED_NAME: &str = "recurring_operation_cancelled";
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RecurringOperationCancelled {
+    id: Id,
+    name: String,
+    payload: RecurringOperationCancelledPayload,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RecurringOperationCancelledPayload {
+    id: Id,
+    user_id: Id,
+}
+
+impl RecurringOperationCancelled {
+    pub fn new(id: Id, recurring_operation_id: Id, user_id: Id) -> Self {
+        Self {
+            id,
+            name: RECURRING_OPERATION_CANCELLED_NAME.to_string(),
+            payload: RecurringOperationCancelledPayload {
+                id: recurring_operation_id,
+                user_id,
+            }
+        }
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn payload(&self) -> &RecurringOperationCancelledPayload {
+        &self.payload
+    }
+}
+
+impl RecurringOperationCancelledPayload {
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Id {
+        &self.user_id
+    }
+}
@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+use crate::features::recurrences::domain::operation_template::OperationTemplate;
+use crate::features::recurrences::domain::recurrence_rule::RecurrenceRule;
+use crate::support::id::Id;
+
+pub const RECURRING_OPERATION_CREATED_NAME: &str = "recurring_operation_created";
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RecurringOperationCreated {
+    id: Id,
+    name: String,
+    payload: RecurringOperationCreatedPayload,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RecurringOperationCreatedPayload {
+    id: Id,
+    user_id: Id,
+    rule: RecurrenceRule,
+    template: OperationTemplate,
+}
+
+impl RecurringOperationCreated {
+    pub fn new(id: Id, recurring_operation_id: Id, user_id: Id, rule: RecurrenceRule, template: OperationTemplate) -> Self {
+        Self {
+            id,
+            name: RECURRING_OPERATION_CREATED_NAME.to_string(),
+            payload: RecurringOperationCreatedPayload {
+                id: recurring_operation_id,
+                user_id,
+                rule,
+                template,
+            }
+        }
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn payload(&self) -> &RecurringOperationCreatedPayload {
+        &self.payload
+    }
+}
+
+impl RecurringOperationCreatedPayload {
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Id {
+        &self.user_id
+    }
+
+    pub fn rule(&self) -> &RecurrenceRule {
+        &self.rule
+    }
+
+    pub fn template(&self) -> &OperationTemplate {
+        &self.template
+    }
+}
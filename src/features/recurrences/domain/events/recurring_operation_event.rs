@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+use crate::features::recurrences::domain::events::occurrence_scheduled::{OCCURRENCE_SCHEDULED_NAME, OccurrenceScheduled};
+use crate::features::recurrences::domain::events::recurring_operation_cancelled::{RECURRING_OPERATION_CANCELLED_NAME, RecurringOperationCancelled};
+use crate::features::recurrences::domain::events::recurring_operation_created::{RECURRING_OPERATION_CREATED_NAME, RecurringOperationCreated};
+use crate::features::recurrences::domain::events::recurring_operation_paused::{RECURRING_OPERATION_PAUSED_NAME, RecurringOperationPaused};
+use crate::features::recurrences::domain::events::recurring_operation_resumed::{RECURRING_OPERATION_RESUMED_NAME, RecurringOperationResumed};
+use crate::support::event_store::{decode_event, EventStoreError, NewEvent, StorableEvent, StoredEvent};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum RecurringOperationEvent {
+    RecurringOperationCreated(RecurringOperationCreated),
+    OccurrenceScheduled(OccurrenceScheduled),
+    RecurringOperationCancelled(RecurringOperationCancelled),
+    RecurringOperationPaused(RecurringOperationPaused),
+    RecurringOperationResumed(RecurringOperationResumed),
+}
+
+impl RecurringOperationEvent {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::RecurringOperationCreated(event) => event.name(),
+            Self::OccurrenceScheduled(event) => event.name(),
+            Self::RecurringOperationCancelled(event) => event.name(),
+            Self::RecurringOperationPaused(event) => event.name(),
+            Self::RecurringOperationResumed(event) => event.name(),
+        }
+    }
+}
+
+impl StorableEvent for RecurringOperationEvent {
+    fn from_stored(stored: &StoredEvent) -> Result<Self, EventStoreError> {
+        match stored.name() {
+            RECURRING_OPERATION_CREATED_NAME => Ok(Self::RecurringOperationCreated(decode_event(stored)?)),
+            OCCURRENCE_SCHEDULED_NAME => Ok(Self::OccurrenceScheduled(decode_event(stored)?)),
+            RECURRING_OPERATION_CANCELLED_NAME => Ok(Self::RecurringOperationCancelled(decode_event(stored)?)),
+            RECURRING_OPERATION_PAUSED_NAME => Ok(Self::RecurringOperationPaused(decode_event(stored)?)),
+            RECURRING_OPERATION_RESUMED_NAME => Ok(Self::RecurringOperationResumed(decode_event(stored)?)),
+            name => Err(
+                EventStoreError::Serialization(format!("Unknown recurring operation event {}", name))
+            ),
+        }
+    }
+
+    fn to_new(&self) -> Result<NewEvent, EventStoreError> {
+        match self {
+            Self::RecurringOperationCreated(event) => NewEvent::from_event(event),
+            Self::OccurrenceScheduled(event) => NewEvent::from_event(event),
+            Self::RecurringOperationCancelled(event) => NewEvent::from_event(event),
+            Self::RecurringOperationPaused(event) => NewEvent::from_event(event),
+            Self::RecurringOperationResumed(event) => NewEvent::from_event(event),
+        }
+    }
+}
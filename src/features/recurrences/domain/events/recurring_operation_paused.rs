@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+use crate::support::id::Id;
+
+pub const RECURRING_OPERATION_PAUSED_NAME: &str = "recurring_operation_paused";
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RecurringOperationPaused {
+    id: Id,
+    name: String,
+    payload: RecurringOperationPausedPayload,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RecurringOperationPausedPayload {
+    id: Id,
+    user_id: Id,
+    operation_id: Id,
+    reason: String,
+}
+
+impl RecurringOperationPaused {
+    pub fn new(id: Id, recurring_operation_id: Id, user_id: Id, operation_id: Id, reason: String) -> Self {
+        Self {
+            id,
+            name: RECURRING_OPERATION_PAUSED_NAME.to_string(),
+            payload: RecurringOperationPausedPayload {
+                id: recurring_operation_id,
+                user_id,
+                operation_id,
+                reason,
+            }
+        }
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn payload(&self) -> &RecurringOperationPausedPayload {
+        &self.payload
+    }
+}
+
+impl RecurringOperationPausedPayload {
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Id {
+        &self.user_id
+    }
+
+    /// Operation of the occurrence that could not be created.
+    pub fn operation_id(&self) -> &Id {
+        &self.operation_id
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
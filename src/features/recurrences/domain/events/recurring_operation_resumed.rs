@@ -0,0 +1,59 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use crate::support::id::Id;
+
+pub const RECURRING_OPERATION_RESUMED_NAME: &str = "recurring_operation_resumed";
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RecurringOperationResumed {
+    id: Id,
+    name: String,
+    payload: RecurringOperationResumedPayload,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RecurringOperationResumedPayload {
+    id: Id,
+    user_id: Id,
+    next_due_on: Option<NaiveDate>,
+}
+
+impl RecurringOperationResumed {
+    pub fn new(id: Id, recurring_operation_id: Id, user_id: Id, next_due_on: Option<NaiveDate>) -> Self {
+        Self {
+            id,
+            name: RECURRING_OPERATION_RESUMED_NAME.to_string(),
+            payload: RecurringOperationResumedPayload {
+                id: recurring_operation_id,
+                user_id,
+                next_due_on,
+            }
+        }
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn payload(&self) -> &RecurringOperationResumedPayload {
+        &self.payload
+    }
+}
+
+impl RecurringOperationResumedPayload {
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Id {
+        &self.user_id
+    }
+
+    pub fn next_due_on(&self) -> &Option<NaiveDate> {
+        &self.next_due_on
+    }
+}
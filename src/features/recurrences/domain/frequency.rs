@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+use crate::features::recurrences::domain::error::DomainError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+impl Frequency {
+    pub fn new(value: &str) -> Result<Self, DomainError> {
+        match value {
+            "Daily" => Ok(Self::Daily),
+            "Weekly" => Ok(Self::Weekly),
+            "Monthly" => Ok(Self::Monthly),
+            "Yearly" => Ok(Self::Yearly),
+            _ => Err(DomainError::UnknownFrequency(value.to_string())),
+        }
+    }
+
+    pub fn to_str(&self) -> &str {
+        match self {
+            Self::Daily => "Daily",
+            Self::Weekly => "Weekly",
+            Self::Monthly => "Monthly",
+            Self::Yearly => "Yearly",
+        }
+    }
+}
@@ -0,0 +1,7 @@
+pub mod error;
+pub mod events;
+pub mod frequency;
+pub mod operation_template;
+pub mod recurrence_rule;
+pub mod recurring_operation;
+pub mod recurring_operation_repository;
@@ -0,0 +1,120 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::features::operations::application::commands::create_operation::command::{CreateOperationCommand, TransferData};
+use crate::features::operations::domain::kind::Kind;
+use crate::features::recurrences::domain::error::DomainError;
+use crate::support::currency::Currency;
+use crate::support::id::Id;
+use crate::support::money::round_money;
+
+/// Operation created on each occurrence. Rates are looked up on the day it is created.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OperationTemplate {
+    kind: Kind,
+    account_id: Id,
+    category_id: Option<Id>,
+    category_name: String,
+    currency: Currency,
+    currency_amount: Decimal,
+    label: String,
+    transfer_account_id: Option<Id>,
+}
+
+impl OperationTemplate {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        kind: &str,
+        account_id: Uuid,
+        category_id: Option<Uuid>,
+        category_name: &str,
+        currency: &str,
+        currency_amount: Decimal,
+        label: &str,
+        transfer_account_id: Option<Uuid>,
+    ) -> Result<Self, DomainError> {
+        let kind = Kind::new(kind)
+            .map_err(|_| DomainError::InvalidTemplate(format!("Unknown operation kind {}", kind)))?;
+        let currency = Currency::find(currency)
+            .ok_or_else(|| DomainError::InvalidTemplate(format!("Unknown currency {}", currency)))?;
+        let currency_amount = round_money(currency_amount, currency.minor_units());
+
+        if currency_amount <= Decimal::ZERO {
+            return Err(DomainError::InvalidTemplate("Amount must be greater than zero".to_string()));
+        }
+
+        if category_id.is_none() && category_name.trim().is_empty() {
+            return Err(DomainError::InvalidTemplate("Category is required".to_string()));
+        }
+
+        if (kind == Kind::Transfer) != transfer_account_id.is_some() {
+            return Err(DomainError::InvalidTemplate("Only transfers have a destination account".to_string()));
+        }
+
+        Ok(
+            Self {
+                kind,
+                account_id: Id::new(account_id),
+                category_id: category_id.map(Id::new),
+                category_name: category_name.trim().to_string(),
+                currency,
+                currency_amount,
+                label: label.to_string(),
+                transfer_account_id: transfer_account_id.map(Id::new),
+            }
+        )
+    }
+
+    pub fn kind(&self) -> &Kind {
+        &self.kind
+    }
+
+    pub fn account_id(&self) -> &Id {
+        &self.account_id
+    }
+
+    pub fn category_id(&self) -> &Option<Id> {
+        &self.category_id
+    }
+
+    pub fn category_name(&self) -> &str {
+        &self.category_name
+    }
+
+    pub fn currency(&self) -> &Currency {
+        &self.currency
+    }
+
+    pub fn currency_amount(&self) -> Decimal {
+        self.currency_amount
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub fn transfer_account_id(&self) -> &Option<Id> {
+        &self.transfer_account_id
+    }
+
+    pub fn to_command(&self, user_id: Uuid, operation_id: Uuid) -> CreateOperationCommand {
+        let command = CreateOperationCommand::new(
+            self.kind.to_str().to_string(),
+            user_id,
+            self.account_id.value(),
+            self.category_id.as_ref().map(Id::value),
+            self.category_name.clone(),
+            None,
+            self.currency.code().to_string(),
+            self.currency_amount,
+            None,
+            self.label.clone(),
+            vec![],
+        ).with_operation_id(operation_id);
+
+        match &self.transfer_account_id {
+            Some(account_id) => command.with_transfer(TransferData::new(account_id.value(), None)),
+            None => command,
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use chrono::{Days, Months, NaiveDate};
+use serde::{Deserialize, Serialize};
+use crate::features::recurrences::domain::error::DomainError;
+use crate::features::recurrences::domain::frequency::Frequency;
+
+const MAX_INTERVAL: u32 = 366;
+
+/// When a recurring operation happens, in the spirit of an iCalendar RRULE: every `interval` days, weeks, months
+/// or years from `starts_on`, until a date and/or for a number of occurrences.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecurrenceRule {
+    frequency: Frequency,
+    interval: u32,
+    starts_on: NaiveDate,
+    until: Option<NaiveDate>,
+    count: Option<u32>,
+}
+
+impl RecurrenceRule {
+    pub fn new(
+        frequency: Frequency,
+        interval: u32,
+        starts_on: NaiveDate,
+        until: Option<NaiveDate>,
+        count: Option<u32>,
+    ) -> Result<Self, DomainError> {
+        if interval == 0 || interval > MAX_INTERVAL {
+            return Err(
+                DomainError::InvalidRecurrence(
+                    format!("Interval must be between 1 and {}", MAX_INTERVAL)
+                )
+            );
+        }
+
+        if until.is_some_and(|until| until < starts_on) {
+            return Err(DomainError::InvalidRecurrence("End date is before the start date".to_string()));
+        }
+
+        if count == Some(0) {
+            return Err(DomainError::InvalidRecurrence("Count must be greater than zero".to_string()));
+        }
+
+        Ok(
+            Self {
+                frequency,
+                interval,
+                starts_on,
+                until,
+                count,
+            }
+        )
+    }
+
+    pub fn frequency(&self) -> &Frequency {
+        &self.frequency
+    }
+
+    pub fn interval(&self) -> u32 {
+        self.interval
+    }
+
+    pub fn starts_on(&self) -> &NaiveDate {
+        &self.starts_on
+    }
+
+    pub fn until(&self) -> &Option<NaiveDate> {
+        &self.until
+    }
+
+    pub fn count(&self) -> Option<u32> {
+        self.count
+    }
+
+    /// Date of the occurrence with the zero-based `number`, `None` past the end of the recurrence.
+    /// Dates are counted from the start, so a monthly rule from the 31st falls on the last day of shorter months.
+    pub fn occurrence(&self, number: u32) -> Option<NaiveDate> {
+        if self.count.is_some_and(|count| number >= count) {
+            return None;
+        }
+
+        let steps = number.checked_mul(self.interval)?;
+
+        let date = match self.frequency {
+            Frequency::Daily => self.starts_on.checked_add_days(Days::new(steps as u64)),
+            Frequency::Weekly => self.starts_on.checked_add_days(Days::new(steps as u64 * 7)),
+            Frequency::Monthly => self.starts_on.checked_add_months(Months::new(steps)),
+            Frequency::Yearly => self.starts_on.checked_add_months(Months::new(steps.checked_mul(12)?)),
+        }?;
+
+        match self.until {
+            Some(until) if date > until => None,
+            _ => Some(date),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_monthly_occurrences() {
+        let rule = RecurrenceRule::new(Frequency::Monthly, 1, date(2024, 1, 31), None, Some(3)).unwrap();
+
+        assert_eq!(rule.occurrence(0), Some(date(2024, 1, 31)));
+        assert_eq!(rule.occurrence(1), Some(date(2024, 2, 29)));
+        assert_eq!(rule.occurrence(2), Some(date(2024, 3, 31)));
+        assert_eq!(rule.occurrence(3), None);
+    }
+
+    #[test]
+    fn test_weekly_occurrences_until_date() {
+        let rule = RecurrenceRule::new(Frequency::Weekly, 2, date(2024, 4, 1), Some(date(2024, 4, 29)), None).unwrap();
+
+        assert_eq!(rule.occurrence(1), Some(date(2024, 4, 15)));
+        assert_eq!(rule.occurrence(2), Some(date(2024, 4, 29)));
+        assert_eq!(rule.occurrence(3), None);
+    }
+
+    #[test]
+    fn test_yearly_occurrences() {
+        let rule = RecurrenceRule::new(Frequency::Yearly, 1, date(2024, 2, 29), None, None).unwrap();
+
+        assert_eq!(rule.occurrence(1), Some(date(2025, 2, 28)));
+        assert_eq!(rule.occurrence(4), Some(date(2028, 2, 29)));
+    }
+
+    #[test]
+    fn test_invalid_rules() {
+        assert!(RecurrenceRule::new(Frequency::Daily, 0, date(2024, 4, 1), None, None).is_err());
+        assert!(RecurrenceRule::new(Frequency::Daily, 1, date(2024, 4, 1), Some(date(2024, 3, 1)), None).is_err());
+        assert!(RecurrenceRule::new(Frequency::Daily, 1, date(2024, 4, 1), None, Some(0)).is_err());
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+}
@@ -0,0 +1,367 @@
+use chrono::NaiveDate;
+use uuid::Uuid;
+use crate::features::recurrences::application::commands::cancel_recurring_operation::command::CancelRecurringOperationCommand;
+use crate::features::recurrences::application::commands::create_recurring_operation::command::CreateRecurringOperationCommand;
+use crate::features::recurrences::application::commands::resume_recurring_operation::command::ResumeRecurringOperationCommand;
+use crate::features::recurrences::domain::error::DomainError;
+use crate::features::recurrences::domain::events::occurrence_scheduled::OccurrenceScheduled;
+use crate::features::recurrences::domain::events::recurring_operation_cancelled::RecurringOperationCancelled;
+use crate::features::recurrences::domain::events::recurring_operation_created::RecurringOperationCreated;
+use crate::features::recurrences::domain::events::recurring_operation_event::RecurringOperationEvent;
+use crate::features::recurrences::domain::events::recurring_operation_paused::RecurringOperationPaused;
+use crate::features::recurrences::domain::events::recurring_operation_resumed::RecurringOperationResumed;
+use crate::features::recurrences::domain::frequency::Frequency;
+use crate::features::recurrences::domain::operation_template::OperationTemplate;
+use crate::features::recurrences::domain::recurrence_rule::RecurrenceRule;
+use crate::support::event_store::{Aggregate, EventStoreError};
+use crate::support::id::Id;
+
+/// Operation the user makes again and again, e.g. rent, a salary or a subscription.
+/// Each due occurrence is scheduled with the id of its operation before the operation is created,
+/// so a creation that has to be repeated keeps the id and cannot produce a second operation.
+/// A rejected creation pauses the recurring operation with the reason instead, until the user resumes it.
+#[derive(Debug, Clone)]
+pub struct RecurringOperation {
+    id: Id,
+    user_id: Id,
+    rule: RecurrenceRule,
+    template: OperationTemplate,
+    scheduled: u32,
+    last_operation_id: Option<Id>,
+    cancelled: bool,
+    paused_reason: Option<String>,
+}
+
+impl RecurringOperation {
+    pub fn handle_creation(command: CreateRecurringOperationCommand) -> Result<RecurringOperationEvent, DomainError> {
+        let recurrence = command.recurrence();
+        let rule = RecurrenceRule::new(
+            Frequency::new(recurrence.frequency())?,
+            recurrence.interval(),
+            *recurrence.starts_on(),
+            *recurrence.until(),
+            recurrence.count(),
+        )?;
+
+        let template = command.template();
+        let template = OperationTemplate::new(
+            template.kind(),
+            *template.account_id(),
+            *template.category_id(),
+            template.category_name(),
+            template.currency(),
+            template.currency_amount(),
+            template.label(),
+            *template.transfer_account_id(),
+        )?;
+
+        Ok(
+            RecurringOperationEvent::RecurringOperationCreated(
+                RecurringOperationCreated::new(
+                    Id::new(Id::generate()),
+                    Id::new(Id::generate()),
+                    Id::new(*command.user_id()),
+                    rule,
+                    template,
+                )
+            )
+        )
+    }
+
+    pub fn handle_cancellation(&self, command: CancelRecurringOperationCommand) -> Result<RecurringOperationEvent, DomainError> {
+        self.check_access(command.user_id())?;
+
+        if self.cancelled {
+            return Err(DomainError::AlreadyCancelled);
+        }
+
+        Ok(
+            RecurringOperationEvent::RecurringOperationCancelled(
+                RecurringOperationCancelled::new(
+                    Id::new(Id::generate()),
+                    self.id.clone(),
+                    self.user_id.clone(),
+                )
+            )
+        )
+    }
+
+    /// Stops scheduling occurrences because the operation of the given one cannot be created.
+    pub fn handle_pause(&self, operation_id: Id, reason: String) -> RecurringOperationEvent {
+        RecurringOperationEvent::RecurringOperationPaused(
+            RecurringOperationPaused::new(
+                Id::new(Id::generate()),
+                self.id.clone(),
+                self.user_id.clone(),
+                operation_id,
+                reason,
+            )
+        )
+    }
+
+    /// Resumes a paused recurring operation, starting with the occurrence whose operation was rejected.
+    pub fn handle_resume(&self, command: ResumeRecurringOperationCommand) -> Result<RecurringOperationEvent, DomainError> {
+        self.check_access(command.user_id())?;
+
+        if self.cancelled {
+            return Err(DomainError::AlreadyCancelled);
+        }
+
+        if !self.is_paused() {
+            return Err(DomainError::NotPaused);
+        }
+
+        Ok(
+            RecurringOperationEvent::RecurringOperationResumed(
+                RecurringOperationResumed::new(
+                    Id::new(Id::generate()),
+                    self.id.clone(),
+                    self.user_id.clone(),
+                    self.rule.occurrence(self.scheduled),
+                )
+            )
+        )
+    }
+
+    /// Schedules the next occurrence if it is due on or before `date`.
+    pub fn handle_occurrence(&self, date: &NaiveDate) -> Option<RecurringOperationEvent> {
+        let due_on = self.next_due_on().filter(|due_on| due_on <= date)?;
+
+        Some(
+            RecurringOperationEvent::OccurrenceScheduled(
+                OccurrenceScheduled::new(
+                    Id::new(Id::generate()),
+                    self.id.clone(),
+                    self.user_id.clone(),
+                    self.scheduled,
+                    due_on,
+                    Id::new(Id::generate()),
+                    self.rule.occurrence(self.scheduled + 1),
+                )
+            )
+        )
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Id {
+        &self.user_id
+    }
+
+    pub fn rule(&self) -> &RecurrenceRule {
+        &self.rule
+    }
+
+    pub fn template(&self) -> &OperationTemplate {
+        &self.template
+    }
+
+    /// Number of occurrences scheduled so far.
+    pub fn scheduled(&self) -> u32 {
+        self.scheduled
+    }
+
+    /// Operation of the latest occurrence, it may not be created yet if the creation was interrupted.
+    pub fn last_operation_id(&self) -> &Option<Id> {
+        &self.last_operation_id
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused_reason.is_some()
+    }
+
+    /// Why the operation of the latest occurrence could not be created, `None` unless paused.
+    pub fn paused_reason(&self) -> &Option<String> {
+        &self.paused_reason
+    }
+
+    /// Date of the next occurrence, `None` once the recurrence is over, cancelled or paused.
+    pub fn next_due_on(&self) -> Option<NaiveDate> {
+        if self.cancelled || self.is_paused() {
+            return None;
+        }
+
+        self.rule.occurrence(self.scheduled)
+    }
+
+    fn check_access(&self, user_id: &Uuid) -> Result<(), DomainError> {
+        if self.user_id.value() != *user_id {
+            return Err(DomainError::AccessDenied);
+        }
+
+        Ok(())
+    }
+}
+
+impl Aggregate for RecurringOperation {
+    type Event = RecurringOperationEvent;
+
+    fn apply(state: Option<Self>, event: &RecurringOperationEvent) -> Result<Self, EventStoreError> {
+        match (state, event) {
+            (None, RecurringOperationEvent::RecurringOperationCreated(created)) => {
+                let payload = created.payload();
+
+                Ok(
+                    Self {
+                        id: payload.id().clone(),
+                        user_id: payload.user_id().clone(),
+                        rule: payload.rule().clone(),
+                        template: payload.template().clone(),
+                        scheduled: 0,
+                        last_operation_id: None,
+                        cancelled: false,
+                        paused_reason: None,
+                    }
+                )
+            }
+            (Some(recurring_operation), RecurringOperationEvent::RecurringOperationCreated(_)) => Err(
+                EventStoreError::Rehydration(
+                    format!("Recurring operation {} is already created", recurring_operation.id().value())
+                )
+            ),
+            (Some(recurring_operation), RecurringOperationEvent::OccurrenceScheduled(scheduled)) => Ok(
+                Self {
+                    scheduled: scheduled.payload().number() + 1,
+                    last_operation_id: Some(scheduled.payload().operation_id().clone()),
+                    ..recurring_operation
+                }
+            ),
+            (Some(recurring_operation), RecurringOperationEvent::RecurringOperationCancelled(_)) => Ok(
+                Self {
+                    cancelled: true,
+                    ..recurring_operation
+                }
+            ),
+            (Some(recurring_operation), RecurringOperationEvent::RecurringOperationPaused(paused)) => Ok(
+                Self {
+                    paused_reason: Some(paused.payload().reason().to_string()),
+                    ..recurring_operation
+                }
+            ),
+            (Some(recurring_operation), RecurringOperationEvent::RecurringOperationResumed(_)) => Ok(
+                Self {
+                    paused_reason: None,
+                    ..recurring_operation
+                }
+            ),
+            (None, event) => Err(
+                EventStoreError::Rehydration(
+                    format!("Recurring operation stream must start with recurring_operation_created, got {}", event.name())
+                )
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use rust_decimal::Decimal;
+    use crate::features::recurrences::application::commands::create_recurring_operation::command::{RecurrenceData, TemplateData};
+    use super::*;
+
+    #[test]
+    fn test_handle_creation() {
+        let command = command_fixture("Expense", None);
+
+        let recurring_operation = RecurringOperation::apply(None, &RecurringOperation::handle_creation(command.clone()).unwrap()).unwrap();
+
+        assert_eq!(recurring_operation.user_id().value(), *command.user_id());
+        assert_eq!(recurring_operation.template().label(), "Rent");
+        assert_eq!(recurring_operation.next_due_on(), Some(date(2024, 1, 5)));
+    }
+
+    #[test]
+    fn test_handle_creation_with_invalid_template() {
+        let transfer_without_destination = command_fixture("Transfer", None);
+        let expense_with_destination = command_fixture("Expense", Some(Uuid::new_v4()));
+
+        assert!(matches!(RecurringOperation::handle_creation(transfer_without_destination), Err(DomainError::InvalidTemplate(_))));
+        assert!(matches!(RecurringOperation::handle_creation(expense_with_destination), Err(DomainError::InvalidTemplate(_))));
+    }
+
+    #[test]
+    fn test_handle_occurrence() {
+        let mut recurring_operation = recurring_operation_fixture();
+        let mut operation_ids = vec![];
+
+        while let Some(event) = recurring_operation.handle_occurrence(&date(2024, 3, 5)) {
+            recurring_operation = RecurringOperation::apply(Some(recurring_operation), &event).unwrap();
+            operation_ids.push(recurring_operation.last_operation_id().clone().unwrap());
+        }
+
+        assert_eq!(operation_ids.len(), 3);
+        assert_eq!(recurring_operation.scheduled(), 3);
+        assert_eq!(recurring_operation.next_due_on(), Some(date(2024, 4, 5)));
+        assert_ne!(operation_ids[0], operation_ids[1]);
+    }
+
+    #[test]
+    fn test_handle_cancellation() {
+        let recurring_operation = recurring_operation_fixture();
+        let command = CancelRecurringOperationCommand::new(recurring_operation.id().value(), recurring_operation.user_id().value());
+
+        let event = recurring_operation.handle_cancellation(command.clone()).unwrap();
+        let cancelled = RecurringOperation::apply(Some(recurring_operation), &event).unwrap();
+
+        assert!(cancelled.handle_occurrence(&date(2024, 3, 5)).is_none());
+        assert!(matches!(cancelled.handle_cancellation(command), Err(DomainError::AlreadyCancelled)));
+    }
+
+    #[test]
+    fn test_handle_pause() {
+        let recurring_operation = recurring_operation_fixture();
+
+        let event = recurring_operation.handle_pause(Id::new(Id::generate()), "Account not found".to_string());
+        let paused = RecurringOperation::apply(Some(recurring_operation), &event).unwrap();
+
+        assert_eq!(paused.paused_reason().as_deref(), Some("Account not found"));
+        assert!(paused.handle_occurrence(&date(2024, 3, 5)).is_none());
+
+        let command = ResumeRecurringOperationCommand::new(paused.id().value(), paused.user_id().value());
+        let event = paused.handle_resume(command.clone()).unwrap();
+        let resumed = RecurringOperation::apply(Some(paused), &event).unwrap();
+
+        assert!(!resumed.is_paused());
+        assert_eq!(resumed.next_due_on(), Some(date(2024, 1, 5)));
+        assert!(matches!(resumed.handle_resume(command), Err(DomainError::NotPaused)));
+    }
+
+    #[test]
+    fn test_handle_cancellation_by_another_user() {
+        let recurring_operation = recurring_operation_fixture();
+        let command = CancelRecurringOperationCommand::new(recurring_operation.id().value(), Uuid::new_v4());
+
+        assert!(matches!(recurring_operation.handle_cancellation(command), Err(DomainError::AccessDenied)));
+    }
+
+    pub fn recurring_operation_fixture() -> RecurringOperation {
+        RecurringOperation::apply(None, &RecurringOperation::handle_creation(command_fixture("Expense", None)).unwrap()).unwrap()
+    }
+
+    fn command_fixture(kind: &str, transfer_account_id: Option<Uuid>) -> CreateRecurringOperationCommand {
+        CreateRecurringOperationCommand::new(
+            Uuid::new_v4(),
+            RecurrenceData::new("Monthly".to_string(), 1, date(2024, 1, 5), None, Some(12)),
+            TemplateData::new(
+                kind.to_string(),
+                Uuid::new_v4(),
+                Some(Uuid::new_v4()),
+                "Housing".to_string(),
+                "USD".to_string(),
+                Decimal::from(1200),
+                "Rent".to_string(),
+                transfer_account_id,
+            ),
+        )
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+}
@@ -0,0 +1,58 @@
+use std::collections::HashMap;
+use async_trait::async_trait;
+use uuid::Uuid;
+use crate::features::recurrences::domain::events::recurring_operation_event::RecurringOperationEvent;
+use crate::features::recurrences::domain::recurring_operation::RecurringOperation;
+use crate::features::recurrences::error::RecurrenceError;
+use crate::features::recurrences::infrastructure::error::InfrastructureError;
+use crate::support::event_store::Versioned;
+
+#[async_trait]
+pub trait RecurringOperationRepository {
+    async fn load(&self, recurring_operation_id: Uuid) -> Result<Option<Versioned<RecurringOperation>>, RecurrenceError>;
+
+    /// Fails with `InfrastructureError::Conflict` when the stream is no longer at `expected_version`.
+    async fn append(&self, recurring_operation_id: Uuid, expected_version: i32, events: &[RecurringOperationEvent]) -> Result<i32, RecurrenceError>;
+}
+
+pub struct MockRecurringOperationRepository {
+    has_error: bool,
+    recurring_operations: HashMap<Uuid, Versioned<RecurringOperation>>,
+}
+
+impl MockRecurringOperationRepository {
+    pub fn new(has_error: bool) -> Self {
+        Self {
+            has_error,
+            recurring_operations: HashMap::new(),
+        }
+    }
+
+    pub fn with_recurring_operations(recurring_operations: Vec<Versioned<RecurringOperation>>) -> Self {
+        Self {
+            has_error: false,
+            recurring_operations: recurring_operations.into_iter()
+                .map(|recurring_operation| (recurring_operation.aggregate().id().value(), recurring_operation))
+                .collect(),
+        }
+    }
+}
+
+#[async_trait]
+impl RecurringOperationRepository for MockRecurringOperationRepository {
+    async fn load(&self, recurring_operation_id: Uuid) -> Result<Option<Versioned<RecurringOperation>>, RecurrenceError> {
+        Ok(self.recurring_operations.get(&recurring_operation_id).cloned())
+    }
+
+    async fn append(&self, _recurring_operation_id: Uuid, expected_version: i32, events: &[RecurringOperationEvent]) -> Result<i32, RecurrenceError> {
+        if self.has_error {
+            return Err(
+                RecurrenceError::Infrastructure(
+                    InfrastructureError::Repository("Mock repository error".into())
+                )
+            );
+        }
+
+        Ok(expected_version + events.len() as i32)
+    }
+}
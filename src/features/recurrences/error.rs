@@ -0,0 +1,12 @@
+use thiserror::Error;
+use crate::features::recurrences::domain::error::DomainError;
+use crate::features::recurrences::infrastructure::error::InfrastructureError;
+
+#[derive(Error, Debug, Clone)]
+pub enum RecurrenceError {
+    #[error("Recurrence domain error: {0}")]
+    Domain(DomainError),
+
+    #[error("Recurrence infrastructure error: {0}")]
+    Infrastructure(InfrastructureError)
+}
@@ -0,0 +1,167 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use sqlx::{query, query_as, query_scalar, Pool, Postgres};
+use sqlx::postgres::PgArguments;
+use sqlx::query::Query;
+use sqlx::types::Json;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+use crate::db::manager::DbManager;
+use crate::features::recurrences::application::queries::recurring_operation_projection_repository::RecurringOperationProjectionRepository;
+use crate::features::recurrences::application::queries::recurring_operation_view::RecurringOperationView;
+use crate::features::recurrences::domain::events::occurrence_scheduled::OccurrenceScheduled;
+use crate::features::recurrences::domain::events::recurring_operation_cancelled::RecurringOperationCancelled;
+use crate::features::recurrences::domain::events::recurring_operation_created::RecurringOperationCreated;
+use crate::features::recurrences::domain::events::recurring_operation_paused::RecurringOperationPaused;
+use crate::features::recurrences::domain::events::recurring_operation_resumed::RecurringOperationResumed;
+use crate::features::recurrences::error::RecurrenceError;
+use crate::features::recurrences::infrastructure::error::InfrastructureError;
+
+#[derive(Clone)]
+pub struct DbRecurringOperationProjectionRepository {
+    db_manager: Arc<Mutex<DbManager>>,
+}
+
+impl DbRecurringOperationProjectionRepository {
+    pub fn new(db_manager: Arc<Mutex<DbManager>>) -> Self {
+        Self {
+            db_manager,
+        }
+    }
+
+    async fn pool(&self) -> Result<Pool<Postgres>, RecurrenceError> {
+        let guard = self.db_manager.lock().await;
+
+        guard.pool()
+            .map_err(|e|
+                RecurrenceError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to get pool: {}", e)
+                    )
+                )
+            )
+    }
+
+    async fn execute(&self, res_query: Query<'_, Postgres, PgArguments>) -> Result<(), RecurrenceError> {
+        let pool = self.pool().await?;
+
+        res_query.execute(&pool)
+            .await
+            .map_err(|e|
+                RecurrenceError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to project recurring operation: {}", e)
+                    )
+                )
+            )?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl RecurringOperationProjectionRepository for DbRecurringOperationProjectionRepository {
+    async fn find(&self, user_id: Uuid) -> Result<Vec<RecurringOperationView>, RecurrenceError> {
+        let q = "
+            SELECT id, user_id, frequency, interval, starts_on, until, count, template, occurrences, next_due_on, cancelled, paused_reason, created_at
+            FROM recurring_operations
+            WHERE user_id = $1
+            ORDER BY created_at, id
+        ";
+
+        let pool = self.pool().await?;
+
+        query_as::<_, RecurringOperationView>(q)
+            .bind(user_id)
+            .fetch_all(&pool)
+            .await
+            .map_err(|e|
+                RecurrenceError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to fetch recurring operations: {}", e)
+                    )
+                )
+            )
+    }
+
+    /// Also returns recurring operations whose latest operation never reached the operations projection,
+    /// so an occurrence interrupted by a restart is completed on the next run. Paused recurring operations are left alone.
+    async fn find_due(&self, date: NaiveDate) -> Result<Vec<Uuid>, RecurrenceError> {
+        let q = "
+            SELECT r.id
+            FROM recurring_operations r
+            WHERE r.paused_reason IS NULL
+              AND ((NOT r.cancelled AND r.next_due_on <= $1)
+               OR (r.last_operation_id IS NOT NULL AND NOT EXISTS (SELECT 1 FROM operations o WHERE o.id = r.last_operation_id)))
+            ORDER BY r.id
+        ";
+
+        let pool = self.pool().await?;
+
+        query_scalar::<_, Uuid>(q)
+            .bind(date)
+            .fetch_all(&pool)
+            .await
+            .map_err(|e|
+                RecurrenceError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to fetch due recurring operations: {}", e)
+                    )
+                )
+            )
+    }
+
+    async fn apply_recurring_operation_created(&self, event: &RecurringOperationCreated) -> Result<(), RecurrenceError> {
+        let payload = event.payload();
+        let rule = payload.rule();
+
+        self.execute(
+            query("INSERT INTO recurring_operations (id, user_id, frequency, interval, starts_on, until, count, template, next_due_on) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9) ON CONFLICT DO NOTHING")
+                .bind(payload.id().value())
+                .bind(payload.user_id().value())
+                .bind(rule.frequency().to_str().to_string())
+                .bind(rule.interval() as i32)
+                .bind(*rule.starts_on())
+                .bind(*rule.until())
+                .bind(rule.count().map(|count| count as i32))
+                .bind(Json(payload.template().clone()))
+                .bind(rule.occurrence(0))
+        ).await
+    }
+
+    async fn apply_occurrence_scheduled(&self, event: &OccurrenceScheduled) -> Result<(), RecurrenceError> {
+        let payload = event.payload();
+
+        self.execute(
+            query("UPDATE recurring_operations SET occurrences = $2, last_operation_id = $3, next_due_on = $4 WHERE id = $1 AND occurrences < $2")
+                .bind(payload.id().value())
+                .bind(payload.number() as i32 + 1)
+                .bind(payload.operation_id().value())
+                .bind(*payload.next_due_on())
+        ).await
+    }
+
+    async fn apply_recurring_operation_cancelled(&self, event: &RecurringOperationCancelled) -> Result<(), RecurrenceError> {
+        self.execute(
+            query("UPDATE recurring_operations SET cancelled = TRUE, next_due_on = NULL WHERE id = $1")
+                .bind(event.payload().id().value())
+        ).await
+    }
+
+    async fn apply_recurring_operation_paused(&self, event: &RecurringOperationPaused) -> Result<(), RecurrenceError> {
+        self.execute(
+            query("UPDATE recurring_operations SET paused_reason = $2, next_due_on = NULL WHERE id = $1")
+                .bind(event.payload().id().value())
+                .bind(event.payload().reason())
+        ).await
+    }
+
+    async fn apply_recurring_operation_resumed(&self, event: &RecurringOperationResumed) -> Result<(), RecurrenceError> {
+        self.execute(
+            query("UPDATE recurring_operations SET paused_reason = NULL, next_due_on = $2 WHERE id = $1")
+                .bind(event.payload().id().value())
+                .bind(*event.payload().next_due_on())
+        ).await
+    }
+}
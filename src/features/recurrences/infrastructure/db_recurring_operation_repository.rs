@@ -0,0 +1,64 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+use crate::db::manager::DbManager;
+use crate::features::recurrences::domain::recurring_operation::RecurringOperation;
+use crate::features::recurrences::domain::recurring_operation_repository::RecurringOperationRepository;
+use crate::features::recurrences::domain::events::recurring_operation_event::RecurringOperationEvent;
+use crate::features::recurrences::error::RecurrenceError;
+use crate::features::recurrences::infrastructure::error::InfrastructureError;
+use crate::support::event_store::{Aggregate, EventStore, StorableEvent, Versioned};
+use crate::support::pg_event_store::PgEventStore;
+
+const EVENTS_TABLE: &str = "recurring_operation_events";
+
+#[derive(Clone)]
+pub struct DbRecurringOperationRepository {
+    event_store: PgEventStore,
+}
+
+impl DbRecurringOperationRepository {
+    pub fn new(db_manager: Arc<Mutex<DbManager>>) -> Self {
+        Self {
+            event_store: PgEventStore::new(db_manager, EVENTS_TABLE),
+        }
+    }
+}
+
+#[async_trait]
+impl RecurringOperationRepository for DbRecurringOperationRepository {
+    async fn load(&self, recurring_operation_id: Uuid) -> Result<Option<Versioned<RecurringOperation>>, RecurrenceError> {
+        let stream = self.event_store.load(recurring_operation_id)
+            .await
+            .map_err(|e|
+                RecurrenceError::Infrastructure(
+                    InfrastructureError::Repository(e.to_string())
+                )
+            )?;
+
+        RecurringOperation::rehydrate(&stream)
+            .map_err(|e|
+                RecurrenceError::Infrastructure(
+                    InfrastructureError::Repository(e.to_string())
+                )
+            )
+    }
+
+    async fn append(&self, recurring_operation_id: Uuid, expected_version: i32, events: &[RecurringOperationEvent]) -> Result<i32, RecurrenceError> {
+        let events = events.iter()
+            .map(|event| event.to_new())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e|
+                RecurrenceError::Infrastructure(
+                    InfrastructureError::Repository(e.to_string())
+                )
+            )?;
+
+        self.event_store.append(recurring_operation_id, expected_version, &events)
+            .await
+            .map_err(|e|
+                RecurrenceError::Infrastructure(e.into())
+            )
+    }
+}
@@ -0,0 +1 @@
+pub mod recurring_operation_projection_listener;
@@ -0,0 +1,68 @@
+use async_trait::async_trait;
+use crate::events::error::EventError;
+use crate::events::event::Event;
+use crate::events::event_listener::EventListener;
+use crate::features::recurrences::application::queries::recurring_operation_projection_repository::RecurringOperationProjectionRepository;
+use crate::features::recurrences::domain::events::recurring_operation_event::RecurringOperationEvent;
+use crate::support::error::FeatureError;
+
+/// Keeps the `recurring_operations` read model in sync with the recurring operation event stream.
+/// One instance is registered per recurring operation event name.
+pub struct RecurringOperationProjectionListener<R>
+    where
+        R: RecurringOperationProjectionRepository + Send + Sync + 'static,
+{
+    rep: R,
+    event_name: &'static str,
+}
+
+#[async_trait]
+impl<R> EventListener for RecurringOperationProjectionListener<R>
+    where
+        R: RecurringOperationProjectionRepository + Send + Sync + 'static,
+{
+    async fn on_event(&mut self, event: Event) -> Result<Vec<Event>, EventError> {
+        let res = match self.parse_event(event)? {
+            RecurringOperationEvent::RecurringOperationCreated(event) => self.rep.apply_recurring_operation_created(&event).await,
+            RecurringOperationEvent::OccurrenceScheduled(event) => self.rep.apply_occurrence_scheduled(&event).await,
+            RecurringOperationEvent::RecurringOperationCancelled(event) => self.rep.apply_recurring_operation_cancelled(&event).await,
+            RecurringOperationEvent::RecurringOperationPaused(event) => self.rep.apply_recurring_operation_paused(&event).await,
+            RecurringOperationEvent::RecurringOperationResumed(event) => self.rep.apply_recurring_operation_resumed(&event).await,
+        };
+
+        res.map_err(|e|
+            EventError::Feature(
+                FeatureError::Recurrence(e)
+            )
+        )?;
+
+        Ok(vec![])
+    }
+
+    fn event_name(&self) -> &str {
+        self.event_name
+    }
+}
+
+impl<R> RecurringOperationProjectionListener<R>
+    where
+        R: RecurringOperationProjectionRepository + Send + Sync + 'static,
+{
+    pub fn new(rep: R, event_name: &'static str) -> Self {
+        Self {
+            rep,
+            event_name,
+        }
+    }
+
+    pub fn parse_event(&self, event: Event) -> Result<RecurringOperationEvent, EventError> {
+        match event {
+            Event::RecurringOperationEvent(recurring_operation_event) => Ok(recurring_operation_event),
+            _ => Err(
+                EventError::Parsing(
+                    format!("Invalid event type. Expected RecurringOperationEvent, got {:?}", event)
+                )
+            )
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod db_recurring_operation_projection_repository;
+pub mod db_recurring_operation_repository;
+pub mod error;
+pub mod event_listeners;
+pub mod recurrence_worker;
@@ -0,0 +1,81 @@
+use std::sync::Arc;
+use std::time::Duration;
+use chrono::Utc;
+use tokio::task::JoinHandle;
+use uuid::Uuid;
+use crate::di::service_container::ServiceContainer;
+use crate::events::error::EventError;
+use crate::events::event_bus::EventBus;
+use crate::features::operations::infrastructure::command_operation_creator::CommandOperationCreator;
+use crate::features::recurrences::application::commands::materialize_occurrences::command::MaterializeOccurrencesCommand;
+use crate::features::recurrences::application::commands::materialize_occurrences::handler::MaterializeOccurrencesCommandHandler;
+use crate::features::recurrences::application::queries::recurring_operation_projection_repository::RecurringOperationProjectionRepository;
+use crate::features::recurrences::infrastructure::db_recurring_operation_projection_repository::DbRecurringOperationProjectionRepository;
+use crate::features::recurrences::infrastructure::db_recurring_operation_repository::DbRecurringOperationRepository;
+use crate::support::error::FeatureError;
+use crate::{log_error, log_info};
+
+/// Creates the operations of due occurrences of recurring operations.
+/// A failing recurring operation is logged and retried on the next run without blocking the others,
+/// unless its operation can never be created, in which case the handler pauses it.
+pub struct RecurrenceWorker;
+
+impl RecurrenceWorker {
+    pub fn spawn(service_container: Arc<ServiceContainer>, event_bus: Arc<Box<dyn EventBus>>) -> JoinHandle<()> {
+        let interval = service_container.config().recurrences().materialize_interval();
+
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(Duration::from_secs(interval.max(1)));
+
+            loop {
+                ticker.tick().await;
+
+                match Self::due(&service_container).await {
+                    Ok(ids) => {
+                        for id in ids {
+                            if let Err(e) = Self::materialize(&service_container, &event_bus, id).await {
+                                log_error!("Failed to materialize recurring operation {}. {}", id, e);
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        log_error!("Failed to find due recurring operations. {}", e);
+                    }
+                }
+            }
+        })
+    }
+
+    async fn due(service_container: &ServiceContainer) -> Result<Vec<Uuid>, FeatureError> {
+        DbRecurringOperationProjectionRepository::new(service_container.db_manager())
+            .find_due(Utc::now().date_naive())
+            .await
+            .map_err(FeatureError::Recurrence)
+    }
+
+    /// Materializes occurrences one by one until nothing is due, publishing the events of each.
+    async fn materialize(service_container: &Arc<ServiceContainer>, event_bus: &Arc<Box<dyn EventBus>>, id: Uuid) -> Result<(), EventError> {
+        let date = Utc::now().date_naive();
+
+        loop {
+            let rep = DbRecurringOperationRepository::new(service_container.db_manager());
+            let operations = CommandOperationCreator::new(service_container.clone());
+
+            let mut command_bus = service_container.command_bus();
+            command_bus.register(MaterializeOccurrencesCommandHandler::new(rep, operations));
+            let events = command_bus.dispatch(MaterializeOccurrencesCommand::new(id, date))
+                .await
+                .map_err(EventError::Feature)?;
+
+            if events.is_empty() {
+                return Ok(());
+            }
+
+            log_info!("Occurrence of recurring operation {} materialized", id);
+
+            for event in events {
+                event_bus.publish(event).await?;
+            }
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod domain;
+pub mod application;
+pub mod infrastructure;
+pub mod error;
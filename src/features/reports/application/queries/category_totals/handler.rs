@@ -0,0 +1,125 @@
+use async_trait::async_trait;
+use crate::features::categories::application::queries::category_projection_repository::CategoryProjectionRepository;
+use crate::features::categories::application::queries::category_report::report::{CategoryReportLine, roll_up};
+use crate::features::reports::application::queries::category_totals::query::CategoryTotalsQuery;
+use crate::features::reports::application::queries::report::Report;
+use crate::features::reports::application::queries::report_projection_repository::ReportProjectionRepository;
+use crate::features::reports::domain::report_filter::ReportFilter;
+use crate::features::reports::error::ReportError;
+use crate::support::error::FeatureError;
+use crate::support::query_bus::QueryHandler;
+
+pub struct CategoryTotalsQueryHandler<R, C>
+    where
+        R: ReportProjectionRepository + Send + Sync,
+        C: CategoryProjectionRepository + Send + Sync,
+{
+    rep: R,
+    category_rep: C,
+    currency: String,
+}
+
+impl<R, C> CategoryTotalsQueryHandler<R, C>
+    where
+        R: ReportProjectionRepository + Send + Sync,
+        C: CategoryProjectionRepository + Send + Sync,
+{
+    pub fn new(rep: R, category_rep: C, currency: String) -> Self {
+        Self {
+            rep,
+            category_rep,
+            currency,
+        }
+    }
+}
+
+#[async_trait]
+impl<R, C> QueryHandler<CategoryTotalsQuery> for CategoryTotalsQueryHandler<R, C>
+    where
+        R: ReportProjectionRepository + Send + Sync,
+        C: CategoryProjectionRepository + Send + Sync,
+{
+    type Output = Report<CategoryReportLine>;
+
+    async fn handle(&self, query: CategoryTotalsQuery) -> Result<Report<CategoryReportLine>, FeatureError> {
+        let filter = ReportFilter::new(query.kind().clone(), query.date_from(), query.date_to())
+            .map_err(|e|
+                FeatureError::Report(
+                    ReportError::Domain(e)
+                )
+            )?;
+
+        // Archived categories still hold operations of the period, so they take part in the report
+        let categories = self.category_rep.find(*query.user_id(), true)
+            .await
+            .map_err(FeatureError::Category)?;
+
+        let totals = self.rep.find_category_totals(*query.user_id(), &filter)
+            .await
+            .map_err(FeatureError::Report)?;
+
+        Ok(Report::new(self.currency.clone(), roll_up(&categories, &totals)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use futures_util::FutureExt;
+    use rust_decimal::Decimal;
+    use uuid::Uuid;
+    use crate::features::categories::application::queries::category_projection_repository::MockCategoryProjectionRepository;
+    use crate::features::categories::application::queries::category_total::CategoryTotal;
+    use crate::features::categories::application::queries::category_view::CategoryView;
+    use crate::features::reports::application::queries::report_projection_repository::MockReportProjectionRepository;
+    use crate::features::reports::domain::error::DomainError;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle_rolls_up_totals() {
+        let user_id = Uuid::new_v4();
+        let food = CategoryView::new(Uuid::new_v4(), user_id, "Food".to_string(), None, None, false, Utc::now());
+        let groceries = CategoryView::new(Uuid::new_v4(), user_id, "Groceries".to_string(), None, Some(*food.id()), false, Utc::now());
+        let totals = vec![
+            CategoryTotal::new(*food.id(), Decimal::from(3)),
+            CategoryTotal::new(*groceries.id(), Decimal::from(12)),
+        ];
+
+        let mut category_rep = MockCategoryProjectionRepository::new();
+        category_rep.expect_find()
+            .times(1)
+            .returning(move |_, _| {
+                let categories = vec![food.clone(), groceries.clone()];
+                async move { Ok(categories) }.boxed()
+            });
+
+        let mut rep = MockReportProjectionRepository::new();
+        rep.expect_find_category_totals()
+            .times(1)
+            .returning(move |_, _| {
+                let totals = totals.clone();
+                async move { Ok(totals) }.boxed()
+            });
+
+        let report = CategoryTotalsQueryHandler::new(rep, category_rep, "USD".to_string())
+            .handle(CategoryTotalsQuery::new(user_id, Some("Expense".to_string()), None, None))
+            .await
+            .unwrap();
+
+        assert_eq!(report.currency(), "USD");
+        assert_eq!(report.lines()[0].total(), Decimal::from(3));
+        assert_eq!(report.lines()[0].rolled_up_total(), Decimal::from(15));
+    }
+
+    #[tokio::test]
+    async fn test_handle_invalid_filter() {
+        let mut rep = MockReportProjectionRepository::new();
+        rep.expect_find_category_totals().never();
+
+        let result = CategoryTotalsQueryHandler::new(rep, MockCategoryProjectionRepository::new(), "USD".to_string())
+            .handle(CategoryTotalsQuery::new(Uuid::new_v4(), Some("Transfer".to_string()), None, None))
+            .await;
+
+        assert!(matches!(result, Err(FeatureError::Report(ReportError::Domain(DomainError::InvalidFilter(_))))));
+    }
+}
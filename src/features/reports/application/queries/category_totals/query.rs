@@ -0,0 +1,46 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use crate::support::query_bus::Query;
+
+const NAME: &str = "category_totals_report";
+
+#[derive(Debug, Clone)]
+pub struct CategoryTotalsQuery {
+    user_id: Uuid,
+    kind: Option<String>,
+    date_from: Option<DateTime<Utc>>,
+    date_to: Option<DateTime<Utc>>,
+}
+
+impl CategoryTotalsQuery {
+    pub fn new(user_id: Uuid, kind: Option<String>, date_from: Option<DateTime<Utc>>, date_to: Option<DateTime<Utc>>) -> Self {
+        Self {
+            user_id,
+            kind,
+            date_from,
+            date_to,
+        }
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    pub fn kind(&self) -> &Option<String> {
+        &self.kind
+    }
+
+    pub fn date_from(&self) -> Option<DateTime<Utc>> {
+        self.date_from
+    }
+
+    pub fn date_to(&self) -> Option<DateTime<Utc>> {
+        self.date_to
+    }
+}
+
+impl Query for CategoryTotalsQuery {
+    fn name() -> &'static str {
+        NAME
+    }
+}
@@ -0,0 +1,316 @@
+use async_trait::async_trait;
+use chrono::{Days, NaiveDate};
+use rust_decimal::Decimal;
+use crate::features::balance::application::queries::balance_projection_repository::BalanceProjectionRepository;
+use crate::features::loans::application::queries::loan_projection_repository::LoanProjectionRepository;
+use crate::features::loans::domain::loan_direction::LoanDirection;
+use crate::features::loans::error::LoanError;
+use crate::features::operations::domain::kind::Kind;
+use crate::features::rates::domain::rate_source::RateSource;
+use crate::features::recurrences::application::queries::recurring_operation_projection_repository::RecurringOperationProjectionRepository;
+use crate::features::recurrences::error::RecurrenceError;
+use crate::features::reports::application::queries::forecast::query::ForecastQuery;
+use crate::features::reports::application::queries::report::Report;
+use crate::features::reports::application::queries::report_projection_repository::ReportProjectionRepository;
+use crate::features::reports::domain::error::DomainError;
+use crate::features::reports::domain::forecast::{daily_trend, forecast, ForecastDay, ScheduledFlow};
+use crate::features::reports::error::ReportError;
+use crate::support::error::FeatureError;
+use crate::support::money::{DEFAULT_MINOR_UNITS, round_money};
+use crate::support::query_bus::QueryHandler;
+
+/// Projects the balance of a user from the current one: known recurring operations and loan installments
+/// are expected on their dates, everything else follows the trend of the last `lookback` days.
+/// Scheduled amounts are converted at the rates of the day the forecast starts from.
+pub struct ForecastQueryHandler<R, B, O, L, S>
+    where
+        R: ReportProjectionRepository + Send + Sync,
+        B: BalanceProjectionRepository + Send + Sync,
+        O: RecurringOperationProjectionRepository + Send + Sync,
+        L: LoanProjectionRepository + Send + Sync,
+        S: RateSource + Send + Sync,
+{
+    rep: R,
+    balance_rep: B,
+    recurrence_rep: O,
+    loan_rep: L,
+    rates: S,
+    currency: String,
+    lookback: u32,
+    max_days: u32,
+}
+
+impl<R, B, O, L, S> ForecastQueryHandler<R, B, O, L, S>
+    where
+        R: ReportProjectionRepository + Send + Sync,
+        B: BalanceProjectionRepository + Send + Sync,
+        O: RecurringOperationProjectionRepository + Send + Sync,
+        L: LoanProjectionRepository + Send + Sync,
+        S: RateSource + Send + Sync,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(rep: R, balance_rep: B, recurrence_rep: O, loan_rep: L, rates: S, currency: String, lookback: u32, max_days: u32) -> Self {
+        Self {
+            rep,
+            balance_rep,
+            recurrence_rep,
+            loan_rep,
+            rates,
+            currency,
+            lookback,
+            max_days,
+        }
+    }
+
+    /// Scheduled flows expected after `date_from`, and the sum of those that happened from `date_from` to `today`.
+    async fn recurring_flows(&self, query: &ForecastQuery, date_from: NaiveDate, date_to: NaiveDate) -> Result<(Vec<ScheduledFlow>, Decimal), FeatureError> {
+        let today = *query.date();
+        let mut flows = vec![];
+        let mut past = Decimal::ZERO;
+
+        let recurring_operations = self.recurrence_rep.find(*query.user_id())
+            .await
+            .map_err(FeatureError::Recurrence)?;
+
+        for recurring_operation in recurring_operations {
+            let template = recurring_operation.template();
+
+            // Transfers only move money between accounts of the user, paused ones no longer create operations
+            if recurring_operation.is_cancelled() || recurring_operation.paused_reason().is_some() || *template.kind() == Kind::Transfer {
+                continue;
+            }
+
+            let rule = recurring_operation.rule()
+                .map_err(|e|
+                    FeatureError::Recurrence(
+                        RecurrenceError::Domain(e)
+                    )
+                )?;
+            let rate = self.rates.rate(template.currency().code(), today).await?;
+            let amount = round_money(template.kind().sign() * template.currency_amount() * rate, DEFAULT_MINOR_UNITS);
+            let occurrences = recurring_operation.occurrences() as u32;
+
+            for number in 0.. {
+                let Some(date) = rule.occurrence(number) else {
+                    break;
+                };
+
+                if date > date_to {
+                    break;
+                }
+
+                if number < occurrences {
+                    if date >= date_from && date < today {
+                        past += amount;
+                    }
+                } else {
+                    flows.push(ScheduledFlow::new(date, amount));
+                }
+            }
+        }
+
+        Ok((flows, past))
+    }
+
+    /// Installments still to be paid up to `date_to`, and the sum of the repayments made from `date_from` to `today`.
+    /// Overdue installments are not expected to be paid on a known date, so they are left out.
+    async fn loan_flows(&self, query: &ForecastQuery, date_from: NaiveDate, date_to: NaiveDate) -> Result<(Vec<ScheduledFlow>, Decimal), FeatureError> {
+        let today = *query.date();
+        let mut flows = vec![];
+        let mut past = Decimal::ZERO;
+
+        let loans = self.loan_rep.find(*query.user_id())
+            .await
+            .map_err(FeatureError::Loan)?;
+
+        for loan in loans {
+            let sign = LoanDirection::new(loan.direction())
+                .map_err(|e|
+                    FeatureError::Loan(
+                        LoanError::Domain(e)
+                    )
+                )?
+                .repayment_sign();
+            let rate = self.rates.rate(loan.currency(), today).await?;
+
+            past += loan.repayments().iter()
+                .filter(|repayment| *repayment.paid_on() >= date_from && *repayment.paid_on() < today)
+                .map(|repayment| round_money(sign * repayment.amount() * rate, DEFAULT_MINOR_UNITS))
+                .sum::<Decimal>();
+
+            flows.extend(
+                loan.unpaid_installments()
+                    .into_iter()
+                    .filter(|(due_on, _)| *due_on > today && *due_on <= date_to)
+                    .map(|(due_on, amount)| ScheduledFlow::new(due_on, round_money(sign * amount * rate, DEFAULT_MINOR_UNITS)))
+            );
+        }
+
+        Ok((flows, past))
+    }
+}
+
+#[async_trait]
+impl<R, B, O, L, S> QueryHandler<ForecastQuery> for ForecastQueryHandler<R, B, O, L, S>
+    where
+        R: ReportProjectionRepository + Send + Sync,
+        B: BalanceProjectionRepository + Send + Sync,
+        O: RecurringOperationProjectionRepository + Send + Sync,
+        L: LoanProjectionRepository + Send + Sync,
+        S: RateSource + Send + Sync,
+{
+    type Output = Report<ForecastDay>;
+
+    async fn handle(&self, query: ForecastQuery) -> Result<Report<ForecastDay>, FeatureError> {
+        let today = *query.date();
+        let horizon = today.checked_add_days(Days::new(query.days() as u64));
+        let date_from = today.checked_sub_days(Days::new(self.lookback as u64)).unwrap_or(today);
+
+        let date_to = match horizon {
+            Some(date_to) if query.days() > 0 && query.days() <= self.max_days => date_to,
+            _ => return Err(
+                FeatureError::Report(
+                    ReportError::Domain(DomainError::InvalidHorizon(self.max_days))
+                )
+            ),
+        };
+
+        let mut balance = Decimal::ZERO;
+
+        for account in self.balance_rep.find(*query.user_id()).await.map_err(FeatureError::Balance)? {
+            // Opening balances count at the rates of the day, like the scheduled amounts
+            let rate = if account.opening_balance().is_zero() {
+                Decimal::ZERO
+            } else {
+                self.rates.rate(account.currency(), today).await?
+            };
+
+            balance += account.with_opening_balance_at(rate).amount();
+        }
+
+        let (mut flows, recurring_past) = self.recurring_flows(&query, date_from, date_to).await?;
+        let (loan_flows, loan_past) = self.loan_flows(&query, date_from, date_to).await?;
+        flows.extend(loan_flows);
+
+        let history = self.rep.find_net_flow(
+            *query.user_id(),
+            date_from.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc(),
+            today.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc(),
+        )
+            .await
+            .map_err(FeatureError::Report)?;
+
+        // A short history is averaged over the days it covers, not over the whole lookback
+        let observed_days = history.first_on()
+            .map(|first_on| (today - first_on.max(date_from)).num_days())
+            .unwrap_or(0);
+        let trend = daily_trend(history.total(), recurring_past + loan_past, observed_days);
+
+        Ok(Report::new(self.currency.clone(), forecast(balance, today, query.days(), trend, &flows)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use futures_util::FutureExt;
+    use uuid::Uuid;
+    use crate::features::balance::application::queries::balance_projection_repository::MockBalanceProjectionRepository;
+    use crate::features::balance::application::queries::balance_view::BalanceView;
+    use crate::features::loans::application::queries::loan_projection_repository::MockLoanProjectionRepository;
+    use crate::features::loans::application::queries::loan_view::LoanView;
+    use crate::features::loans::domain::schedule::Installment;
+    use crate::features::rates::domain::rate_source::MockRateSource;
+    use crate::features::recurrences::application::queries::recurring_operation_projection_repository::MockRecurringOperationProjectionRepository;
+    use crate::features::recurrences::application::queries::recurring_operation_view::RecurringOperationView;
+    use crate::features::recurrences::domain::operation_template::OperationTemplate;
+    use crate::features::reports::application::queries::report_lines::NetFlow;
+    use crate::features::reports::application::queries::report_projection_repository::MockReportProjectionRepository;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle() {
+        let user_id = Uuid::new_v4();
+
+        let mut balance_rep = MockBalanceProjectionRepository::new();
+        balance_rep.expect_find()
+            .times(1)
+            .returning(move |_| {
+                let balances = vec![BalanceView::new(Uuid::new_v4(), user_id, "USD".to_string(), Decimal::from(400), Decimal::from(500), Decimal::from(100), Utc::now())];
+                async move { Ok(balances) }.boxed()
+            });
+
+        // A monthly salary, paid once within the lookback and due again within the horizon
+        let template = OperationTemplate::new("Income", Uuid::new_v4(), None, "Salary", "USD", Decimal::from(1000), "Salary", None).unwrap();
+        let mut recurrence_rep = MockRecurringOperationProjectionRepository::new();
+        recurrence_rep.expect_find()
+            .times(1)
+            .returning(move |_| {
+                let recurring_operations = vec![
+                    RecurringOperationView::new(
+                        Uuid::new_v4(), user_id, "Monthly".to_string(), 1, date(2024, 3, 15), None, None,
+                        template.clone(), 1, Some(date(2024, 4, 15)), false, None, Utc::now(),
+                    ),
+                ];
+                async move { Ok(recurring_operations) }.boxed()
+            });
+
+        let mut loan_rep = MockLoanProjectionRepository::new();
+        loan_rep.expect_find()
+            .times(1)
+            .returning(move |_| {
+                let loans = vec![
+                    LoanView::new(
+                        Uuid::new_v4(), user_id, "Taken".to_string(), "Bank".to_string(), Decimal::from(100), "USD".to_string(),
+                        Decimal::ZERO, date(2024, 3, 18), vec![Installment::new(1, date(2024, 4, 18), Decimal::from(100), Decimal::ZERO)],
+                        vec![], Utc::now(),
+                    ),
+                ];
+                async move { Ok(loans) }.boxed()
+            });
+
+        let mut rep = MockReportProjectionRepository::new();
+        rep.expect_find_net_flow()
+            .times(1)
+            .returning(|_, _, _| async move { Ok(NetFlow::new(Decimal::from(700), Some(date(2024, 3, 11)))) }.boxed());
+
+        let mut rates = MockRateSource::new();
+        rates.expect_rate()
+            .returning(|_, _| async move { Ok(Decimal::ONE) }.boxed());
+
+        let report = ForecastQueryHandler::new(rep, balance_rep, recurrence_rep, loan_rep, rates, "USD".to_string(), 30, 365)
+            .handle(ForecastQuery::new(user_id, date(2024, 4, 10), 10))
+            .await
+            .unwrap();
+
+        let days = report.lines();
+
+        assert_eq!(days.len(), 10);
+        assert_eq!(days[0].trend(), Decimal::from(-10));
+        assert_eq!(days[4].scheduled(), Decimal::from(1000));
+        assert_eq!(days[7].scheduled(), Decimal::from(-100));
+        assert_eq!(days[9].balance(), Decimal::from(1300));
+    }
+
+    #[tokio::test]
+    async fn test_handle_invalid_horizon() {
+        let result = ForecastQueryHandler::new(
+            MockReportProjectionRepository::new(),
+            MockBalanceProjectionRepository::new(),
+            MockRecurringOperationProjectionRepository::new(),
+            MockLoanProjectionRepository::new(),
+            MockRateSource::new(),
+            "USD".to_string(),
+            30,
+            365,
+        )
+            .handle(ForecastQuery::new(Uuid::new_v4(), date(2024, 4, 10), 0))
+            .await;
+
+        assert!(matches!(result, Err(FeatureError::Report(ReportError::Domain(DomainError::InvalidHorizon(365))))));
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+}
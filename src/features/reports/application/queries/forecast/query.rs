@@ -0,0 +1,41 @@
+use chrono::NaiveDate;
+use uuid::Uuid;
+use crate::support::query_bus::Query;
+
+const NAME: &str = "forecast_report";
+
+#[derive(Debug, Clone)]
+pub struct ForecastQuery {
+    user_id: Uuid,
+    date: NaiveDate,
+    days: u32,
+}
+
+impl ForecastQuery {
+    pub fn new(user_id: Uuid, date: NaiveDate, days: u32) -> Self {
+        Self {
+            user_id,
+            date,
+            days,
+        }
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    /// Day the forecast starts from, its balance is the current one.
+    pub fn date(&self) -> &NaiveDate {
+        &self.date
+    }
+
+    pub fn days(&self) -> u32 {
+        self.days
+    }
+}
+
+impl Query for ForecastQuery {
+    fn name() -> &'static str {
+        NAME
+    }
+}
@@ -0,0 +1,52 @@
+use async_trait::async_trait;
+use crate::features::reports::application::queries::kind_totals::query::KindTotalsQuery;
+use crate::features::reports::application::queries::report::Report;
+use crate::features::reports::application::queries::report_lines::KindTotal;
+use crate::features::reports::application::queries::report_projection_repository::ReportProjectionRepository;
+use crate::features::reports::domain::report_filter::ReportFilter;
+use crate::features::reports::error::ReportError;
+use crate::support::error::FeatureError;
+use crate::support::query_bus::QueryHandler;
+
+pub struct KindTotalsQueryHandler<R>
+    where
+        R: ReportProjectionRepository + Send + Sync,
+{
+    rep: R,
+    currency: String,
+}
+
+impl<R> KindTotalsQueryHandler<R>
+    where
+        R: ReportProjectionRepository + Send + Sync,
+{
+    pub fn new(rep: R, currency: String) -> Self {
+        Self {
+            rep,
+            currency,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> QueryHandler<KindTotalsQuery> for KindTotalsQueryHandler<R>
+    where
+        R: ReportProjectionRepository + Send + Sync,
+{
+    type Output = Report<KindTotal>;
+
+    async fn handle(&self, query: KindTotalsQuery) -> Result<Report<KindTotal>, FeatureError> {
+        let filter = ReportFilter::new(query.kind().clone(), query.date_from(), query.date_to())
+            .map_err(|e|
+                FeatureError::Report(
+                    ReportError::Domain(e)
+                )
+            )?;
+
+        let totals = self.rep.find_kind_totals(*query.user_id(), &filter)
+            .await
+            .map_err(FeatureError::Report)?;
+
+        Ok(Report::new(self.currency.clone(), totals))
+    }
+}
@@ -0,0 +1,8 @@
+pub mod category_totals;
+pub mod forecast;
+pub mod kind_totals;
+pub mod report;
+pub mod report_lines;
+pub mod report_projection_repository;
+pub mod tag_totals;
+pub mod timeline;
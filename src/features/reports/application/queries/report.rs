@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+/// Report lines with the currency their totals are expressed in.
+/// Totals are always converted to the base currency at the rate of each operation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Report<T> {
+    currency: String,
+    lines: Vec<T>,
+}
+
+impl<T> Report<T> {
+    pub fn new(currency: String, lines: Vec<T>) -> Self {
+        Self {
+            currency,
+            lines,
+        }
+    }
+
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    pub fn lines(&self) -> &Vec<T> {
+        &self.lines
+    }
+}
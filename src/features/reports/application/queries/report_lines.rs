@@ -0,0 +1,119 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Sum of the operations marked with a tag. An operation with several tags counts for each of them.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TagTotal {
+    tag_id: Uuid,
+    name: String,
+    total: Decimal,
+}
+
+impl TagTotal {
+    pub fn new(tag_id: Uuid, name: String, total: Decimal) -> Self {
+        Self {
+            tag_id,
+            name,
+            total,
+        }
+    }
+
+    pub fn tag_id(&self) -> &Uuid {
+        &self.tag_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn total(&self) -> Decimal {
+        self.total
+    }
+}
+
+/// Sum and number of the operations of a kind.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct KindTotal {
+    kind: String,
+    total: Decimal,
+    count: i64,
+}
+
+impl KindTotal {
+    pub fn new(kind: String, total: Decimal, count: i64) -> Self {
+        Self {
+            kind,
+            total,
+            count,
+        }
+    }
+
+    pub fn kind(&self) -> &str {
+        &self.kind
+    }
+
+    pub fn total(&self) -> Decimal {
+        self.total
+    }
+
+    pub fn count(&self) -> i64 {
+        self.count
+    }
+}
+
+/// Sum of the operations of a kind within the bucket starting on `starts_on`.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct BucketTotal {
+    starts_on: NaiveDate,
+    kind: String,
+    total: Decimal,
+}
+
+impl BucketTotal {
+    pub fn new(starts_on: NaiveDate, kind: String, total: Decimal) -> Self {
+        Self {
+            starts_on,
+            kind,
+            total,
+        }
+    }
+
+    pub fn starts_on(&self) -> NaiveDate {
+        self.starts_on
+    }
+
+    pub fn kind(&self) -> &str {
+        &self.kind
+    }
+
+    pub fn total(&self) -> Decimal {
+        self.total
+    }
+}
+
+/// Net change of the balance over a period, with the day of the first operation in it.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct NetFlow {
+    total: Decimal,
+    first_on: Option<NaiveDate>,
+}
+
+impl NetFlow {
+    pub fn new(total: Decimal, first_on: Option<NaiveDate>) -> Self {
+        Self {
+            total,
+            first_on,
+        }
+    }
+
+    pub fn total(&self) -> Decimal {
+        self.total
+    }
+
+    pub fn first_on(&self) -> &Option<NaiveDate> {
+        &self.first_on
+    }
+}
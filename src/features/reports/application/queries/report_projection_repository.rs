@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use mockall::automock;
+use uuid::Uuid;
+use crate::features::categories::application::queries::category_total::CategoryTotal;
+use crate::features::operations::domain::events::operation_created::OperationCreated;
+use crate::features::operations::domain::events::operation_deleted::OperationDeleted;
+use crate::features::operations::domain::events::operation_updated::OperationUpdated;
+use crate::features::reports::application::queries::report_lines::{BucketTotal, KindTotal, NetFlow, TagTotal};
+use crate::features::reports::domain::bucket::Bucket;
+use crate::features::reports::domain::report_filter::ReportFilter;
+use crate::features::reports::error::ReportError;
+
+/// Read side of the reports. Totals are summed in the base currency and never include transfers.
+#[async_trait]
+#[automock]
+pub trait ReportProjectionRepository {
+    async fn find_category_totals(&self, user_id: Uuid, filter: &ReportFilter) -> Result<Vec<CategoryTotal>, ReportError>;
+
+    async fn find_tag_totals(&self, user_id: Uuid, filter: &ReportFilter) -> Result<Vec<TagTotal>, ReportError>;
+
+    async fn find_kind_totals(&self, user_id: Uuid, filter: &ReportFilter) -> Result<Vec<KindTotal>, ReportError>;
+
+    /// Totals per bucket and kind, ordered by bucket start. Buckets without operations are left out.
+    async fn find_bucket_totals(&self, user_id: Uuid, bucket: Bucket, filter: &ReportFilter) -> Result<Vec<BucketTotal>, ReportError>;
+
+    /// Signed sum of the operations created from `date_from` up to, not including, `date_to`.
+    async fn find_net_flow(&self, user_id: Uuid, date_from: DateTime<Utc>, date_to: DateTime<Utc>) -> Result<NetFlow, ReportError>;
+
+    async fn apply_operation_created(&self, event: &OperationCreated) -> Result<(), ReportError>;
+
+    /// Replaces the entries of the operation, e.g. after an edit, a recategorization or a tag merge.
+    async fn apply_operation_updated(&self, event: &OperationUpdated) -> Result<(), ReportError>;
+
+    async fn apply_operation_deleted(&self, event: &OperationDeleted) -> Result<(), ReportError>;
+}
@@ -0,0 +1,52 @@
+use async_trait::async_trait;
+use crate::features::reports::application::queries::tag_totals::query::TagTotalsQuery;
+use crate::features::reports::application::queries::report::Report;
+use crate::features::reports::application::queries::report_lines::TagTotal;
+use crate::features::reports::application::queries::report_projection_repository::ReportProjectionRepository;
+use crate::features::reports::domain::report_filter::ReportFilter;
+use crate::features::reports::error::ReportError;
+use crate::support::error::FeatureError;
+use crate::support::query_bus::QueryHandler;
+
+pub struct TagTotalsQueryHandler<R>
+    where
+        R: ReportProjectionRepository + Send + Sync,
+{
+    rep: R,
+    currency: String,
+}
+
+impl<R> TagTotalsQueryHandler<R>
+    where
+        R: ReportProjectionRepository + Send + Sync,
+{
+    pub fn new(rep: R, currency: String) -> Self {
+        Self {
+            rep,
+            currency,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> QueryHandler<TagTotalsQuery> for TagTotalsQueryHandler<R>
+    where
+        R: ReportProjectionRepository + Send + Sync,
+{
+    type Output = Report<TagTotal>;
+
+    async fn handle(&self, query: TagTotalsQuery) -> Result<Report<TagTotal>, FeatureError> {
+        let filter = ReportFilter::new(query.kind().clone(), query.date_from(), query.date_to())
+            .map_err(|e|
+                FeatureError::Report(
+                    ReportError::Domain(e)
+                )
+            )?;
+
+        let totals = self.rep.find_tag_totals(*query.user_id(), &filter)
+            .await
+            .map_err(FeatureError::Report)?;
+
+        Ok(Report::new(self.currency.clone(), totals))
+    }
+}
@@ -0,0 +1,105 @@
+use async_trait::async_trait;
+use crate::features::reports::application::queries::report::Report;
+use crate::features::reports::application::queries::report_lines::BucketTotal;
+use crate::features::reports::application::queries::report_projection_repository::ReportProjectionRepository;
+use crate::features::reports::application::queries::timeline::query::TimelineQuery;
+use crate::features::reports::domain::bucket::Bucket;
+use crate::features::reports::domain::report_filter::ReportFilter;
+use crate::features::reports::error::ReportError;
+use crate::support::error::FeatureError;
+use crate::support::query_bus::QueryHandler;
+
+pub struct TimelineQueryHandler<R>
+    where
+        R: ReportProjectionRepository + Send + Sync,
+{
+    rep: R,
+    currency: String,
+}
+
+impl<R> TimelineQueryHandler<R>
+    where
+        R: ReportProjectionRepository + Send + Sync,
+{
+    pub fn new(rep: R, currency: String) -> Self {
+        Self {
+            rep,
+            currency,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> QueryHandler<TimelineQuery> for TimelineQueryHandler<R>
+    where
+        R: ReportProjectionRepository + Send + Sync,
+{
+    type Output = Report<BucketTotal>;
+
+    async fn handle(&self, query: TimelineQuery) -> Result<Report<BucketTotal>, FeatureError> {
+        let bucket = Bucket::new(query.bucket())
+            .map_err(|e|
+                FeatureError::Report(
+                    ReportError::Domain(e)
+                )
+            )?;
+
+        let filter = ReportFilter::new(query.kind().clone(), query.date_from(), query.date_to())
+            .map_err(|e|
+                FeatureError::Report(
+                    ReportError::Domain(e)
+                )
+            )?;
+
+        let totals = self.rep.find_bucket_totals(*query.user_id(), bucket, &filter)
+            .await
+            .map_err(FeatureError::Report)?;
+
+        Ok(Report::new(self.currency.clone(), totals))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use futures_util::FutureExt;
+    use rust_decimal::Decimal;
+    use uuid::Uuid;
+    use crate::features::reports::application::queries::report_projection_repository::MockReportProjectionRepository;
+    use crate::features::reports::domain::error::DomainError;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle() {
+        let starts_on = NaiveDate::from_ymd_opt(2024, 4, 1).unwrap();
+
+        let mut rep = MockReportProjectionRepository::new();
+        rep.expect_find_bucket_totals()
+            .withf(|_, bucket, _| *bucket == Bucket::Month)
+            .times(1)
+            .returning(move |_, _, _| {
+                let totals = vec![BucketTotal::new(starts_on, "Expense".to_string(), Decimal::from(42))];
+                async move { Ok(totals) }.boxed()
+            });
+
+        let report = TimelineQueryHandler::new(rep, "USD".to_string())
+            .handle(TimelineQuery::new(Uuid::new_v4(), "Month".to_string(), None, None, None))
+            .await
+            .unwrap();
+
+        assert_eq!(report.lines()[0].starts_on(), starts_on);
+        assert_eq!(report.lines()[0].total(), Decimal::from(42));
+    }
+
+    #[tokio::test]
+    async fn test_handle_unknown_bucket() {
+        let mut rep = MockReportProjectionRepository::new();
+        rep.expect_find_bucket_totals().never();
+
+        let result = TimelineQueryHandler::new(rep, "USD".to_string())
+            .handle(TimelineQuery::new(Uuid::new_v4(), "Year".to_string(), None, None, None))
+            .await;
+
+        assert!(matches!(result, Err(FeatureError::Report(ReportError::Domain(DomainError::UnknownBucket(_))))));
+    }
+}
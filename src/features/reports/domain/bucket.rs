@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+use crate::features::reports::domain::error::DomainError;
+
+/// Length of the time slices spendings are grouped into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Bucket {
+    Day,
+    Week,
+    Month,
+}
+
+impl Bucket {
+    pub fn new(value: &str) -> Result<Self, DomainError> {
+        match value {
+            "Day" => Ok(Self::Day),
+            "Week" => Ok(Self::Week),
+            "Month" => Ok(Self::Month),
+            _ => Err(DomainError::UnknownBucket(value.to_string())),
+        }
+    }
+
+    pub fn to_str(&self) -> &str {
+        match self {
+            Self::Day => "Day",
+            Self::Week => "Week",
+            Self::Month => "Month",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert_eq!(Bucket::new("Week").unwrap(), Bucket::Week);
+        assert!(matches!(Bucket::new("Year"), Err(DomainError::UnknownBucket(_))));
+    }
+}
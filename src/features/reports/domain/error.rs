@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone)]
+pub enum DomainError {
+    #[error("Invalid report filter: {0}")]
+    InvalidFilter(String),
+
+    #[error("Unknown report bucket {0}")]
+    UnknownBucket(String),
+
+    #[error("Forecast horizon must be between 1 and {0} days")]
+    InvalidHorizon(u32),
+}
@@ -0,0 +1,126 @@
+use chrono::{Days, NaiveDate};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use crate::support::money::{DEFAULT_MINOR_UNITS, round_money};
+
+/// Money known to move on a date, e.g. a recurring operation or a loan installment.
+/// Amounts are signed and in the base currency.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScheduledFlow {
+    date: NaiveDate,
+    amount: Decimal,
+}
+
+impl ScheduledFlow {
+    pub fn new(date: NaiveDate, amount: Decimal) -> Self {
+        Self {
+            date,
+            amount,
+        }
+    }
+
+    pub fn date(&self) -> &NaiveDate {
+        &self.date
+    }
+
+    pub fn amount(&self) -> Decimal {
+        self.amount
+    }
+}
+
+/// Projected balance at the end of a day, with the scheduled and trend parts of the change of that day.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ForecastDay {
+    date: NaiveDate,
+    balance: Decimal,
+    scheduled: Decimal,
+    trend: Decimal,
+}
+
+impl ForecastDay {
+    pub fn date(&self) -> &NaiveDate {
+        &self.date
+    }
+
+    pub fn balance(&self) -> Decimal {
+        self.balance
+    }
+
+    pub fn scheduled(&self) -> Decimal {
+        self.scheduled
+    }
+
+    pub fn trend(&self) -> Decimal {
+        self.trend
+    }
+}
+
+/// Average daily change of the balance over the `days` observed, once the scheduled flows of that period are taken out
+/// of the `net` change. Those flows are forecast on their own, so counting them in the trend would count them twice.
+pub fn daily_trend(net: Decimal, scheduled: Decimal, days: i64) -> Decimal {
+    if days <= 0 {
+        return Decimal::ZERO;
+    }
+
+    round_money((net - scheduled) / Decimal::from(days), DEFAULT_MINOR_UNITS)
+}
+
+/// Balance of each of the `days` following `today`, starting from `balance`. Flows already due but not recorded yet
+/// are expected on the first day, flows past the horizon are left out.
+pub fn forecast(balance: Decimal, today: NaiveDate, days: u32, trend: Decimal, flows: &[ScheduledFlow]) -> Vec<ForecastDay> {
+    let mut balance = balance;
+
+    (1..=days as u64)
+        .filter_map(|offset| today.checked_add_days(Days::new(offset)))
+        .enumerate()
+        .map(|(index, date)| {
+            let scheduled = flows.iter()
+                .filter(|flow| flow.date == date || (index == 0 && flow.date <= today))
+                .map(ScheduledFlow::amount)
+                .sum();
+
+            balance += scheduled + trend;
+
+            ForecastDay {
+                date,
+                balance,
+                scheduled,
+                trend,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_daily_trend() {
+        assert_eq!(daily_trend(Decimal::from(-400), Decimal::from(-100), 30), Decimal::from(-10));
+        assert_eq!(daily_trend(Decimal::from(-400), Decimal::ZERO, 0), Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_forecast() {
+        let today = date(2024, 4, 10);
+        let flows = vec![
+            ScheduledFlow::new(date(2024, 4, 9), Decimal::from(-50)),
+            ScheduledFlow::new(date(2024, 4, 12), Decimal::from(1000)),
+            ScheduledFlow::new(date(2024, 4, 20), Decimal::from(-300)),
+        ];
+
+        let days = forecast(Decimal::from(500), today, 3, Decimal::from(-10), &flows);
+
+        assert_eq!(days.len(), 3);
+        assert_eq!(days[0].date(), &date(2024, 4, 11));
+        assert_eq!(days[0].scheduled(), Decimal::from(-50));
+        assert_eq!(days[0].balance(), Decimal::from(440));
+        assert_eq!(days[1].balance(), Decimal::from(1430));
+        assert_eq!(days[2].balance(), Decimal::from(1420));
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+}
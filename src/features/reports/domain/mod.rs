@@ -0,0 +1,4 @@
+pub mod bucket;
+pub mod error;
+pub mod forecast;
+pub mod report_filter;
@@ -0,0 +1,81 @@
+use chrono::{DateTime, Utc};
+use crate::features::operations::domain::kind::Kind;
+use crate::features::reports::domain::error::DomainError;
+
+/// Restricts the operations a report is built from. Every bound is optional.
+#[derive(Debug, Clone, Default)]
+pub struct ReportFilter {
+    kind: Option<Kind>,
+    date_from: Option<DateTime<Utc>>,
+    date_to: Option<DateTime<Utc>>,
+}
+
+impl ReportFilter {
+    pub fn new(kind: Option<String>, date_from: Option<DateTime<Utc>>, date_to: Option<DateTime<Utc>>) -> Result<Self, DomainError> {
+        let kind = match kind {
+            Some(kind) => Some(
+                Kind::new(&kind)
+                    .map_err(|_|
+                        DomainError::InvalidFilter(format!("Unknown operation kind {}", kind))
+                    )?
+            ),
+            None => None,
+        };
+
+        // Transfers move money between accounts of the user, they are neither incomes nor expenses
+        if kind == Some(Kind::Transfer) {
+            return Err(
+                DomainError::InvalidFilter("Transfers are not reported".to_string())
+            );
+        }
+
+        if let (Some(date_from), Some(date_to)) = (date_from, date_to) {
+            if date_from > date_to {
+                return Err(
+                    DomainError::InvalidFilter("Date range start must not be after its end".to_string())
+                );
+            }
+        }
+
+        Ok(Self {
+            kind,
+            date_from,
+            date_to,
+        })
+    }
+
+    pub fn kind(&self) -> &Option<Kind> {
+        &self.kind
+    }
+
+    pub fn date_from(&self) -> Option<DateTime<Utc>> {
+        self.date_from
+    }
+
+    pub fn date_to(&self) -> Option<DateTime<Utc>> {
+        self.date_to
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+    use super::*;
+
+    #[test]
+    fn test_new_unknown_kind() {
+        assert!(matches!(ReportFilter::new(Some("Gift".to_string()), None, None), Err(DomainError::InvalidFilter(_))));
+    }
+
+    #[test]
+    fn test_new_transfer_kind() {
+        assert!(matches!(ReportFilter::new(Some("Transfer".to_string()), None, None), Err(DomainError::InvalidFilter(_))));
+    }
+
+    #[test]
+    fn test_new_reversed_dates() {
+        let now = Utc::now();
+
+        assert!(matches!(ReportFilter::new(None, Some(now), Some(now - Duration::days(1))), Err(DomainError::InvalidFilter(_))));
+    }
+}
@@ -0,0 +1,12 @@
+use thiserror::Error;
+use crate::features::reports::domain::error::DomainError;
+use crate::features::reports::infrastructure::error::InfrastructureError;
+
+#[derive(Error, Debug, Clone)]
+pub enum ReportError {
+    #[error("Report domain error. {0}")]
+    Domain(DomainError),
+
+    #[error("Report infrastructure error. {0}")]
+    Infrastructure(InfrastructureError),
+}
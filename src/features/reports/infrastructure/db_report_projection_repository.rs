@@ -0,0 +1,338 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use sqlx::{query, query_as, query_scalar, Pool, Postgres, Transaction};
+use sqlx::postgres::PgArguments;
+use sqlx::query::QueryAs;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+use crate::db::manager::DbManager;
+use crate::features::categories::application::queries::category_total::CategoryTotal;
+use crate::features::operations::domain::amount::Amount;
+use crate::features::operations::domain::events::operation_created::OperationCreated;
+use crate::features::operations::domain::events::operation_deleted::OperationDeleted;
+use crate::features::operations::domain::events::operation_updated::OperationUpdated;
+use crate::features::operations::domain::kind::Kind;
+use crate::features::operations::domain::split_line::SplitLine;
+use crate::features::reports::application::queries::report_lines::{BucketTotal, KindTotal, NetFlow, TagTotal};
+use crate::features::reports::application::queries::report_projection_repository::ReportProjectionRepository;
+use crate::features::reports::domain::bucket::Bucket;
+use crate::features::reports::domain::report_filter::ReportFilter;
+use crate::features::reports::error::ReportError;
+use crate::features::reports::infrastructure::error::InfrastructureError;
+use crate::support::id::Id;
+
+/// Conditions shared by every report, bound by `bind_filter` as $1 to $4.
+const FILTER: &str = "
+    e.user_id = $1
+    AND e.kind <> 'Transfer'
+    AND ($2::VARCHAR IS NULL OR e.kind = $2)
+    AND ($3::TIMESTAMPTZ IS NULL OR e.created_at >= $3)
+    AND ($4::TIMESTAMPTZ IS NULL OR e.created_at <= $4)
+";
+
+#[derive(Clone)]
+pub struct DbReportProjectionRepository {
+    db_manager: Arc<Mutex<DbManager>>,
+}
+
+impl DbReportProjectionRepository {
+    pub fn new(db_manager: Arc<Mutex<DbManager>>) -> Self {
+        Self {
+            db_manager,
+        }
+    }
+
+    async fn pool(&self) -> Result<Pool<Postgres>, ReportError> {
+        let guard = self.db_manager.lock().await;
+
+        guard.pool()
+            .map_err(|e|
+                ReportError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to get pool: {}", e)
+                    )
+                )
+            )
+    }
+
+    fn bind_filter<'q, T>(
+        res_query: QueryAs<'q, Postgres, T, PgArguments>,
+        user_id: Uuid,
+        filter: &ReportFilter,
+    ) -> QueryAs<'q, Postgres, T, PgArguments> {
+        res_query
+            .bind(user_id)
+            .bind(filter.kind().as_ref().map(|kind| kind.to_str().to_string()))
+            .bind(filter.date_from())
+            .bind(filter.date_to())
+    }
+
+    /// Buckets start at midnight UTC, weeks on Monday.
+    fn unit(bucket: Bucket) -> &'static str {
+        match bucket {
+            Bucket::Day => "day",
+            Bucket::Week => "week",
+            Bucket::Month => "month",
+        }
+    }
+
+    /// Category, tags and amount of each report entry of an operation.
+    /// A split operation is reported line by line, each line with its own category and amount and with the tags of the operation.
+    fn entries(category_id: &Id, tag_ids: &[Id], amount: &Amount, lines: &[SplitLine]) -> Vec<(Uuid, Vec<Uuid>, Decimal)> {
+        let tag_ids: Vec<Uuid> = tag_ids.iter().map(|id| id.value()).collect();
+
+        if lines.is_empty() {
+            return vec![(category_id.value(), tag_ids, amount.value())];
+        }
+
+        lines.iter()
+            .map(|line| {
+                let mut line_tag_ids = tag_ids.clone();
+                for tag_id in line.tag_ids() {
+                    if !line_tag_ids.contains(&tag_id.value()) {
+                        line_tag_ids.push(tag_id.value());
+                    }
+                }
+
+                (line.category_id().value(), line_tag_ids, line.amount().value())
+            })
+            .collect()
+    }
+
+    async fn insert_entries(
+        tx: &mut Transaction<'_, Postgres>,
+        operation_id: Uuid,
+        user_id: Uuid,
+        kind: &Kind,
+        created_at: DateTime<Utc>,
+        entries: Vec<(Uuid, Vec<Uuid>, Decimal)>,
+    ) -> Result<(), ReportError> {
+        for (line, (category_id, tag_ids, amount)) in entries.into_iter().enumerate() {
+            query("INSERT INTO report_entries (operation_id, line, user_id, kind, category_id, tag_ids, amount, created_at) VALUES ($1, $2, $3, $4, $5, $6, $7, $8) ON CONFLICT DO NOTHING")
+                .bind(operation_id)
+                .bind(line as i32)
+                .bind(user_id)
+                .bind(kind.to_str())
+                .bind(category_id)
+                .bind(tag_ids)
+                .bind(amount)
+                .bind(created_at)
+                .execute(&mut **tx)
+                .await
+                .map_err(|e|
+                    ReportError::Infrastructure(
+                        InfrastructureError::Repository(
+                            format!("Failed to project report entry: {}", e)
+                        )
+                    )
+                )?;
+        }
+
+        Ok(())
+    }
+
+    async fn begin(pool: &Pool<Postgres>) -> Result<Transaction<'static, Postgres>, ReportError> {
+        pool.begin()
+            .await
+            .map_err(|e|
+                ReportError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to begin transaction: {}", e)
+                    )
+                )
+            )
+    }
+
+    async fn commit(tx: Transaction<'_, Postgres>) -> Result<(), ReportError> {
+        tx.commit()
+            .await
+            .map_err(|e|
+                ReportError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to commit transaction: {}", e)
+                    )
+                )
+            )
+    }
+}
+
+#[async_trait]
+impl ReportProjectionRepository for DbReportProjectionRepository {
+    async fn find_category_totals(&self, user_id: Uuid, filter: &ReportFilter) -> Result<Vec<CategoryTotal>, ReportError> {
+        let q = format!("SELECT e.category_id, SUM(e.amount) AS total FROM report_entries e WHERE {} GROUP BY e.category_id", FILTER);
+
+        let pool = self.pool().await?;
+
+        Self::bind_filter(query_as::<_, CategoryTotal>(&q), user_id, filter)
+            .fetch_all(&pool)
+            .await
+            .map_err(|e|
+                ReportError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to fetch category totals: {}", e)
+                    )
+                )
+            )
+    }
+
+    async fn find_tag_totals(&self, user_id: Uuid, filter: &ReportFilter) -> Result<Vec<TagTotal>, ReportError> {
+        let q = format!("
+            SELECT t.id AS tag_id, t.name, SUM(e.amount) AS total
+            FROM report_entries e
+            CROSS JOIN LATERAL unnest(e.tag_ids) AS tag_id
+            JOIN tags t ON t.id = tag_id
+            WHERE {}
+            GROUP BY t.id, t.name
+            ORDER BY t.name, t.id
+        ", FILTER);
+
+        let pool = self.pool().await?;
+
+        Self::bind_filter(query_as::<_, TagTotal>(&q), user_id, filter)
+            .fetch_all(&pool)
+            .await
+            .map_err(|e|
+                ReportError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to fetch tag totals: {}", e)
+                    )
+                )
+            )
+    }
+
+    async fn find_kind_totals(&self, user_id: Uuid, filter: &ReportFilter) -> Result<Vec<KindTotal>, ReportError> {
+        let q = format!("
+            SELECT e.kind, SUM(e.amount) AS total, COUNT(DISTINCT e.operation_id) AS count
+            FROM report_entries e
+            WHERE {}
+            GROUP BY e.kind
+            ORDER BY e.kind
+        ", FILTER);
+
+        let pool = self.pool().await?;
+
+        Self::bind_filter(query_as::<_, KindTotal>(&q), user_id, filter)
+            .fetch_all(&pool)
+            .await
+            .map_err(|e|
+                ReportError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to fetch kind totals: {}", e)
+                    )
+                )
+            )
+    }
+
+    async fn find_bucket_totals(&self, user_id: Uuid, bucket: Bucket, filter: &ReportFilter) -> Result<Vec<BucketTotal>, ReportError> {
+        let q = format!("
+            SELECT date_trunc($5, e.created_at AT TIME ZONE 'UTC')::DATE AS starts_on, e.kind, SUM(e.amount) AS total
+            FROM report_entries e
+            WHERE {}
+            GROUP BY starts_on, e.kind
+            ORDER BY starts_on, e.kind
+        ", FILTER);
+
+        let pool = self.pool().await?;
+
+        Self::bind_filter(query_as::<_, BucketTotal>(&q), user_id, filter)
+            .bind(Self::unit(bucket))
+            .fetch_all(&pool)
+            .await
+            .map_err(|e|
+                ReportError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to fetch bucket totals: {}", e)
+                    )
+                )
+            )
+    }
+
+    async fn find_net_flow(&self, user_id: Uuid, date_from: DateTime<Utc>, date_to: DateTime<Utc>) -> Result<NetFlow, ReportError> {
+        let q = "
+            SELECT COALESCE(SUM(CASE WHEN kind IN ('Income', 'Credit') THEN amount ELSE -amount END), 0) AS total,
+                   (MIN(created_at) AT TIME ZONE 'UTC')::DATE AS first_on
+            FROM report_entries
+            WHERE user_id = $1
+                AND kind <> 'Transfer'
+                AND created_at >= $2
+                AND created_at < $3
+        ";
+
+        let pool = self.pool().await?;
+
+        query_as::<_, NetFlow>(q)
+            .bind(user_id)
+            .bind(date_from)
+            .bind(date_to)
+            .fetch_one(&pool)
+            .await
+            .map_err(|e|
+                ReportError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to fetch net flow: {}", e)
+                    )
+                )
+            )
+    }
+
+    async fn apply_operation_created(&self, event: &OperationCreated) -> Result<(), ReportError> {
+        let payload = event.payload();
+        let entries = Self::entries(payload.category_id(), payload.tag_ids(), payload.amount(), payload.lines());
+
+        let pool = self.pool().await?;
+        let mut tx = Self::begin(&pool).await?;
+
+        Self::insert_entries(&mut tx, payload.id().value(), payload.user_id().value(), payload.kind(), *payload.created_at(), entries).await?;
+
+        Self::commit(tx).await
+    }
+
+    async fn apply_operation_updated(&self, event: &OperationUpdated) -> Result<(), ReportError> {
+        let payload = event.payload();
+        let entries = Self::entries(payload.category_id(), payload.tag_ids(), payload.amount(), payload.lines());
+
+        let pool = self.pool().await?;
+        let mut tx = Self::begin(&pool).await?;
+
+        // The entries of the operation are replaced as a whole, they keep the date the operation was created at
+        let created_at = query_scalar::<_, DateTime<Utc>>("DELETE FROM report_entries WHERE operation_id = $1 RETURNING created_at")
+            .bind(payload.id().value())
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e|
+                ReportError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to remove report entries: {}", e)
+                    )
+                )
+            )?
+            .into_iter()
+            .next();
+
+        if let Some(created_at) = created_at {
+            Self::insert_entries(&mut tx, payload.id().value(), payload.user_id().value(), payload.kind(), created_at, entries).await?;
+        }
+
+        Self::commit(tx).await
+    }
+
+    async fn apply_operation_deleted(&self, event: &OperationDeleted) -> Result<(), ReportError> {
+        let pool = self.pool().await?;
+
+        query("DELETE FROM report_entries WHERE operation_id = $1")
+            .bind(event.payload().id().value())
+            .execute(&pool)
+            .await
+            .map_err(|e|
+                ReportError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to remove report entries: {}", e)
+                    )
+                )
+            )?;
+
+        Ok(())
+    }
+}
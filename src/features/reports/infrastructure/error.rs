@@ -0,0 +1,7 @@
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone)]
+pub enum InfrastructureError {
+    #[error("Report repository error. {0}")]
+    Repository(String),
+}
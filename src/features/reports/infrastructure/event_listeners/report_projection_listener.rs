@@ -0,0 +1,67 @@
+use async_trait::async_trait;
+use crate::events::error::EventError;
+use crate::events::event::Event;
+use crate::events::event_listener::EventListener;
+use crate::features::operations::domain::events::operation_event::OperationEvent;
+use crate::features::reports::application::queries::report_projection_repository::ReportProjectionRepository;
+use crate::support::error::FeatureError;
+
+/// Keeps the `report_entries` read model in sync with the operation event stream, split operations line by line.
+/// One instance is registered per operation event name.
+pub struct ReportProjectionListener<R>
+    where
+        R: ReportProjectionRepository + Send + Sync + 'static,
+{
+    rep: R,
+    event_name: &'static str,
+}
+
+#[async_trait]
+impl<R> EventListener for ReportProjectionListener<R>
+    where
+        R: ReportProjectionRepository + Send + Sync + 'static,
+{
+    async fn on_event(&mut self, event: Event) -> Result<Vec<Event>, EventError> {
+        let res = match self.parse_event(event)? {
+            OperationEvent::OperationCreated(event) => self.rep.apply_operation_created(&event).await,
+            OperationEvent::OperationUpdated(event) => self.rep.apply_operation_updated(&event).await,
+            OperationEvent::OperationDeleted(event) => self.rep.apply_operation_deleted(&event).await,
+            _ => Ok(()),
+        };
+
+        res.map_err(|e|
+            EventError::Feature(
+                FeatureError::Report(e)
+            )
+        )?;
+
+        Ok(vec![])
+    }
+
+    fn event_name(&self) -> &str {
+        self.event_name
+    }
+}
+
+impl<R> ReportProjectionListener<R>
+    where
+        R: ReportProjectionRepository + Send + Sync + 'static,
+{
+    pub fn new(rep: R, event_name: &'static str) -> Self {
+        Self {
+            rep,
+            event_name,
+        }
+    }
+
+    pub fn parse_event(&self, event: Event) -> Result<OperationEvent, EventError> {
+        match event {
+            Event::OperationEvent(operation_event) => Ok(operation_event),
+            _ => Err(
+                EventError::Parsing(
+                    format!("Invalid event type. Expected OperationEvent, got {:?}", event)
+                )
+            )
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod db_report_projection_repository;
+pub mod error;
+pub mod event_listeners;
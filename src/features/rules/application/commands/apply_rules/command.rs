@@ -0,0 +1,28 @@
+use uuid::Uuid;
+use crate::support::command_bus::Command;
+
+const NAME : &str = "apply_rules";
+
+/// Re-applies the rules of the user to every operation they already have.
+#[derive(Debug, Clone)]
+pub struct ApplyRulesCommand {
+    user_id: Uuid,
+}
+
+impl ApplyRulesCommand {
+    pub fn new(user_id: Uuid) -> Self {
+        Self {
+            user_id,
+        }
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+}
+
+impl Command for ApplyRulesCommand {
+    fn name() -> &'static str {
+        NAME
+    }
+}
@@ -0,0 +1,169 @@
+use async_trait::async_trait;
+use crate::events::event::Event;
+use crate::features::rules::application::commands::apply_rules::command::ApplyRulesCommand;
+use crate::features::rules::application::queries::rule_projection_repository::RuleProjectionRepository;
+use crate::features::rules::domain::categorizer::Categorizer;
+use crate::features::rules::domain::operation_categorizer::OperationCategorizer;
+use crate::features::rules::domain::operation_source::OperationSource;
+use crate::support::command_bus::CommandHandler;
+use crate::support::error::FeatureError;
+
+/// Moves the operations of the user matching a rule to the category of the rule and adds its tags.
+/// Operations matching no rule are left as they are.
+/// Each operation publishes its events as it is categorized, so when one fails the ones before it
+/// are complete and applying the rules again carries on with the rest. No events are returned.
+pub struct ApplyRulesCommandHandler<P, S, C>
+    where
+        P: RuleProjectionRepository + Send + Sync,
+        S: OperationSource + Send + Sync,
+        C: OperationCategorizer + Send + Sync,
+{
+    rule_projection_repository: P,
+    operations: S,
+    categorizer: C,
+}
+
+impl<P, S, C> ApplyRulesCommandHandler<P, S, C>
+    where
+        P: RuleProjectionRepository + Send + Sync,
+        S: OperationSource + Send + Sync,
+        C: OperationCategorizer + Send + Sync,
+{
+    pub fn new(rule_projection_repository: P, operations: S, categorizer: C) -> Self {
+        Self {
+            rule_projection_repository,
+            operations,
+            categorizer,
+        }
+    }
+}
+
+#[async_trait]
+impl<P, S, C> CommandHandler<ApplyRulesCommand> for ApplyRulesCommandHandler<P, S, C>
+    where
+        P: RuleProjectionRepository + Send + Sync,
+        S: OperationSource + Send + Sync,
+        C: OperationCategorizer + Send + Sync,
+{
+    async fn handle(&mut self, command: ApplyRulesCommand) -> Result<Vec<Event>, FeatureError> {
+        let rules = self.rule_projection_repository.find(*command.user_id())
+            .await
+            .map_err(FeatureError::Rule)?;
+
+        let rules = Categorizer::new(
+            rules.iter()
+                .map(|rule| (rule.conditions().clone(), rule.categorization()))
+                .collect()
+        );
+
+        if rules.is_empty() {
+            return Ok(vec![]);
+        }
+
+        for operation in self.operations.operations(*command.user_id()).await? {
+            let Some(categorization) = rules.categorize(operation.target()) else {
+                continue;
+            };
+
+            if !operation.differs_from(categorization) {
+                continue;
+            }
+
+            self.categorizer.categorize(*command.user_id(), *operation.id(), categorization).await?;
+        }
+
+        Ok(vec![])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use futures_util::FutureExt;
+    use mockall::predicate::{always, eq};
+    use rust_decimal::Decimal;
+    use uuid::Uuid;
+    use crate::features::rules::application::queries::rule_projection_repository::MockRuleProjectionRepository;
+    use crate::features::rules::application::queries::rule_view::RuleView;
+    use crate::features::rules::domain::operation_categorizer::MockOperationCategorizer;
+    use crate::features::rules::domain::operation_source::{MockOperationSource, RuleOperation};
+    use crate::features::rules::domain::rule_conditions::RuleConditions;
+    use crate::features::rules::domain::rule_target::RuleTarget;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle_categorizes_matching_operations() {
+        let user_id = Uuid::new_v4();
+        let category_id = Uuid::new_v4();
+        let tag_id = Uuid::new_v4();
+
+        let unmatched = operation_fixture("Bakery", Uuid::new_v4(), vec![]);
+        let categorized = operation_fixture("Coffee bar", category_id, vec![tag_id]);
+        let uncategorized = operation_fixture("Coffee shop", Uuid::new_v4(), vec![]);
+        let uncategorized_id = *uncategorized.id();
+
+        let mut operations = MockOperationSource::new();
+        operations.expect_operations()
+            .with(eq(user_id))
+            .returning(move |_| {
+                let operations = vec![unmatched.clone(), categorized.clone(), uncategorized.clone()];
+                async move { Ok(operations) }.boxed()
+            });
+
+        let mut categorizer = MockOperationCategorizer::new();
+        categorizer.expect_categorize()
+            .with(eq(user_id), eq(uncategorized_id), always())
+            .times(1)
+            .returning(|_, _, _| async { Ok(()) }.boxed());
+
+        let mut handler = ApplyRulesCommandHandler::new(rules_fixture(user_id, category_id, tag_id), operations, categorizer);
+
+        assert!(handler.handle(ApplyRulesCommand::new(user_id)).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_handle_without_rules() {
+        let mut rules = MockRuleProjectionRepository::new();
+        rules.expect_find()
+            .returning(|_| async { Ok(vec![]) }.boxed());
+        let mut operations = MockOperationSource::new();
+        operations.expect_operations().never();
+
+        let mut handler = ApplyRulesCommandHandler::new(rules, operations, MockOperationCategorizer::new());
+
+        assert!(handler.handle(ApplyRulesCommand::new(Uuid::new_v4())).await.unwrap().is_empty());
+    }
+
+    fn rules_fixture(user_id: Uuid, category_id: Uuid, tag_id: Uuid) -> MockRuleProjectionRepository {
+        let views = vec![
+            RuleView::new(
+                Uuid::new_v4(),
+                user_id,
+                "Coffee".to_string(),
+                1,
+                RuleConditions::new(Some("coffee".to_string()), None, None, None, None, None),
+                category_id,
+                vec![tag_id],
+                Utc::now(),
+            ),
+        ];
+
+        let mut rules = MockRuleProjectionRepository::new();
+        rules.expect_find()
+            .returning(move |_| {
+                let views = views.clone();
+                async move { Ok(views) }.boxed()
+            });
+
+        rules
+    }
+
+    fn operation_fixture(label: &str, category_id: Uuid, tag_ids: Vec<Uuid>) -> RuleOperation {
+        RuleOperation::new(
+            Uuid::new_v4(),
+            RuleTarget::new("Expense".to_string(), label.to_string(), "EUR".to_string(), Decimal::from(4)),
+            category_id,
+            tag_ids,
+        )
+    }
+}
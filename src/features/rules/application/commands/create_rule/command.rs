@@ -0,0 +1,59 @@
+use uuid::Uuid;
+use crate::features::rules::domain::rule_conditions::RuleConditions;
+use crate::support::command_bus::Command;
+
+const NAME : &str = "create_rule";
+
+#[derive(Debug, Clone)]
+pub struct CreateRuleCommand {
+    user_id: Uuid,
+    name: String,
+    priority: i32,
+    conditions: RuleConditions,
+    category_id: Uuid,
+    tag_ids: Vec<Uuid>,
+}
+
+impl CreateRuleCommand {
+    pub fn new(user_id: Uuid, name: String, priority: i32, conditions: RuleConditions, category_id: Uuid, tag_ids: Vec<Uuid>) -> Self {
+        Self {
+            user_id,
+            name,
+            priority,
+            conditions,
+            category_id,
+            tag_ids,
+        }
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Rules with a lower priority are tried first.
+    pub fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    pub fn conditions(&self) -> &RuleConditions {
+        &self.conditions
+    }
+
+    pub fn category_id(&self) -> &Uuid {
+        &self.category_id
+    }
+
+    pub fn tag_ids(&self) -> &[Uuid] {
+        &self.tag_ids
+    }
+}
+
+impl Command for CreateRuleCommand {
+    fn name() -> &'static str {
+        NAME
+    }
+}
@@ -0,0 +1,93 @@
+use async_trait::async_trait;
+use crate::events::event::Event;
+use crate::features::rules::application::commands::create_rule::command::CreateRuleCommand;
+use crate::features::rules::domain::events::rule_event::RuleEvent;
+use crate::features::rules::domain::rule::Rule;
+use crate::features::rules::domain::rule_repository::RuleRepository;
+use crate::features::rules::error::RuleError;
+use crate::support::command_bus::CommandHandler;
+use crate::support::error::FeatureError;
+
+pub struct CreateRuleCommandHandler<R>
+    where
+        R: RuleRepository + Send + Sync,
+{
+    rule_repository: R,
+}
+
+impl<R> CreateRuleCommandHandler<R>
+    where
+        R: RuleRepository + Send + Sync,
+{
+    pub fn new(rule_repository: R) -> Self {
+        Self {
+            rule_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> CommandHandler<CreateRuleCommand> for CreateRuleCommandHandler<R>
+    where
+        R: RuleRepository + Send + Sync,
+{
+    async fn handle(&mut self, command: CreateRuleCommand) -> Result<Vec<Event>, FeatureError> {
+        let event = Rule::handle_creation(command)
+            .map_err(|e|
+                FeatureError::Rule(
+                    RuleError::Domain(e)
+                )
+            )?;
+
+        if let RuleEvent::RuleCreated(created) = &event {
+            self.rule_repository.append(created.payload().id().value(), 0, std::slice::from_ref(&event))
+                .await
+                .map_err(FeatureError::Rule)?;
+        }
+
+        Ok(
+            vec![Event::RuleEvent(event)]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+    use crate::features::rules::domain::error::DomainError;
+    use crate::features::rules::domain::rule_conditions::RuleConditions;
+    use crate::features::rules::domain::rule_repository::MockRuleRepository;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle_success() {
+        let events = CreateRuleCommandHandler::new(MockRuleRepository::new(false))
+            .handle(command_fixture(RuleConditions::new(Some("coffee".to_string()), None, None, None, None, None)))
+            .await
+            .unwrap();
+
+        assert!(matches!(events[0], Event::RuleEvent(RuleEvent::RuleCreated(_))));
+    }
+
+    #[tokio::test]
+    async fn test_handle_invalid_condition() {
+        let result = CreateRuleCommandHandler::new(MockRuleRepository::new(false))
+            .handle(command_fixture(RuleConditions::new(None, Some("[".to_string()), None, None, None, None)))
+            .await;
+
+        assert!(matches!(result, Err(FeatureError::Rule(RuleError::Domain(DomainError::InvalidCondition(_))))));
+    }
+
+    #[tokio::test]
+    async fn test_handle_error() {
+        let result = CreateRuleCommandHandler::new(MockRuleRepository::new(true))
+            .handle(command_fixture(RuleConditions::new(Some("coffee".to_string()), None, None, None, None, None)))
+            .await;
+
+        assert!(matches!(result, Err(FeatureError::Rule(RuleError::Infrastructure(_)))));
+    }
+
+    fn command_fixture(conditions: RuleConditions) -> CreateRuleCommand {
+        CreateRuleCommand::new(Uuid::new_v4(), "Coffee".to_string(), 1, conditions, Uuid::new_v4(), vec![])
+    }
+}
@@ -0,0 +1,33 @@
+use uuid::Uuid;
+use crate::support::command_bus::Command;
+
+const NAME : &str = "delete_rule";
+
+#[derive(Debug, Clone)]
+pub struct DeleteRuleCommand {
+    rule_id: Uuid,
+    user_id: Uuid,
+}
+
+impl DeleteRuleCommand {
+    pub fn new(rule_id: Uuid, user_id: Uuid) -> Self {
+        Self {
+            rule_id,
+            user_id,
+        }
+    }
+
+    pub fn rule_id(&self) -> &Uuid {
+        &self.rule_id
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+}
+
+impl Command for DeleteRuleCommand {
+    fn name() -> &'static str {
+        NAME
+    }
+}
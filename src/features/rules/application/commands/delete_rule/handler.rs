@@ -0,0 +1,91 @@
+use async_trait::async_trait;
+use crate::events::event::Event;
+use crate::features::rules::application::commands::delete_rule::command::DeleteRuleCommand;
+use crate::features::rules::domain::error::DomainError;
+use crate::features::rules::domain::rule_repository::RuleRepository;
+use crate::features::rules::error::RuleError;
+use crate::support::command_bus::CommandHandler;
+use crate::support::error::FeatureError;
+
+/// Deletes a rule, operations it has already categorized keep their category and tags.
+pub struct DeleteRuleCommandHandler<R>
+    where
+        R: RuleRepository + Send + Sync,
+{
+    rule_repository: R,
+}
+
+impl<R> DeleteRuleCommandHandler<R>
+    where
+        R: RuleRepository + Send + Sync,
+{
+    pub fn new(rule_repository: R) -> Self {
+        Self {
+            rule_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> CommandHandler<DeleteRuleCommand> for DeleteRuleCommandHandler<R>
+    where
+        R: RuleRepository + Send + Sync,
+{
+    async fn handle(&mut self, command: DeleteRuleCommand) -> Result<Vec<Event>, FeatureError> {
+        let rule = self.rule_repository.load(*command.rule_id())
+            .await
+            .map_err(FeatureError::Rule)?
+            .ok_or(
+                FeatureError::Rule(
+                    RuleError::Domain(
+                        DomainError::RuleNotFound(command.rule_id().to_string())
+                    )
+                )
+            )?;
+
+        let event = rule.aggregate().handle_deletion(command)
+            .map_err(|e|
+                FeatureError::Rule(
+                    RuleError::Domain(e)
+                )
+            )?;
+
+        self.rule_repository.append(rule.aggregate().id().value(), rule.version(), std::slice::from_ref(&event))
+            .await
+            .map_err(FeatureError::Rule)?;
+
+        Ok(
+            vec![Event::RuleEvent(event)]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+    use crate::features::rules::domain::events::rule_event::RuleEvent;
+    use crate::features::rules::domain::rule::tests::rule_fixture;
+    use crate::features::rules::domain::rule_repository::MockRuleRepository;
+    use crate::support::event_store::Versioned;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle_success() {
+        let rule = rule_fixture();
+        let command = DeleteRuleCommand::new(rule.id().value(), rule.user_id().value());
+
+        let rep = MockRuleRepository::with_rules(vec![Versioned::new(rule, 1)]);
+        let events = DeleteRuleCommandHandler::new(rep).handle(command).await.unwrap();
+
+        assert!(matches!(events[0], Event::RuleEvent(RuleEvent::RuleDeleted(_))));
+    }
+
+    #[tokio::test]
+    async fn test_handle_not_found() {
+        let command = DeleteRuleCommand::new(Uuid::new_v4(), Uuid::new_v4());
+
+        let result = DeleteRuleCommandHandler::new(MockRuleRepository::new(false)).handle(command).await;
+
+        assert!(matches!(result, Err(FeatureError::Rule(RuleError::Domain(DomainError::RuleNotFound(_))))));
+    }
+}
@@ -0,0 +1,3 @@
+pub mod apply_rules;
+pub mod create_rule;
+pub mod delete_rule;
\ No newline at end of file
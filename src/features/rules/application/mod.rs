@@ -0,0 +1,2 @@
+pub mod commands;
+pub mod queries;
\ No newline at end of file
@@ -0,0 +1,38 @@
+use async_trait::async_trait;
+use crate::features::rules::application::queries::list_rules::query::ListRulesQuery;
+use crate::features::rules::application::queries::rule_projection_repository::RuleProjectionRepository;
+use crate::features::rules::application::queries::rule_view::RuleView;
+use crate::support::error::FeatureError;
+use crate::support::query_bus::QueryHandler;
+
+pub struct ListRulesQueryHandler<R>
+    where
+        R: RuleProjectionRepository + Send + Sync,
+{
+    rep: R,
+}
+
+impl<R> ListRulesQueryHandler<R>
+    where
+        R: RuleProjectionRepository + Send + Sync,
+{
+    pub fn new(rep: R) -> Self {
+        Self {
+            rep,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> QueryHandler<ListRulesQuery> for ListRulesQueryHandler<R>
+    where
+        R: RuleProjectionRepository + Send + Sync,
+{
+    type Output = Vec<RuleView>;
+
+    async fn handle(&self, query: ListRulesQuery) -> Result<Vec<RuleView>, FeatureError> {
+        self.rep.find(*query.user_id())
+            .await
+            .map_err(FeatureError::Rule)
+    }
+}
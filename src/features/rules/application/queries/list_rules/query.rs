@@ -0,0 +1,27 @@
+use uuid::Uuid;
+use crate::support::query_bus::Query;
+
+const NAME: &str = "list_rules";
+
+#[derive(Debug, Clone)]
+pub struct ListRulesQuery {
+    user_id: Uuid,
+}
+
+impl ListRulesQuery {
+    pub fn new(user_id: Uuid) -> Self {
+        Self {
+            user_id,
+        }
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+}
+
+impl Query for ListRulesQuery {
+    fn name() -> &'static str {
+        NAME
+    }
+}
@@ -0,0 +1,95 @@
+use async_trait::async_trait;
+use crate::features::rules::application::queries::match_rule::query::MatchRuleQuery;
+use crate::features::rules::application::queries::rule_projection_repository::RuleProjectionRepository;
+use crate::features::rules::domain::categorizer::{Categorization, Categorizer};
+use crate::support::error::FeatureError;
+use crate::support::query_bus::QueryHandler;
+
+pub struct MatchRuleQueryHandler<R>
+    where
+        R: RuleProjectionRepository + Send + Sync,
+{
+    rep: R,
+}
+
+impl<R> MatchRuleQueryHandler<R>
+    where
+        R: RuleProjectionRepository + Send + Sync,
+{
+    pub fn new(rep: R) -> Self {
+        Self {
+            rep,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> QueryHandler<MatchRuleQuery> for MatchRuleQueryHandler<R>
+    where
+        R: RuleProjectionRepository + Send + Sync,
+{
+    type Output = Option<Categorization>;
+
+    async fn handle(&self, query: MatchRuleQuery) -> Result<Option<Categorization>, FeatureError> {
+        let rules = self.rep.find(*query.user_id())
+            .await
+            .map_err(FeatureError::Rule)?;
+
+        let categorizer = Categorizer::new(
+            rules.iter()
+                .map(|rule| (rule.conditions().clone(), rule.categorization()))
+                .collect()
+        );
+
+        Ok(categorizer.categorize(query.target()).cloned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use futures_util::FutureExt;
+    use mockall::predicate::eq;
+    use rust_decimal::Decimal;
+    use uuid::Uuid;
+    use crate::features::rules::application::queries::rule_projection_repository::MockRuleProjectionRepository;
+    use crate::features::rules::application::queries::rule_view::RuleView;
+    use crate::features::rules::domain::rule_conditions::RuleConditions;
+    use crate::features::rules::domain::rule_target::RuleTarget;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle_success() {
+        let user_id = Uuid::new_v4();
+        let category_id = Uuid::new_v4();
+        let views = vec![
+            RuleView::new(
+                Uuid::new_v4(),
+                user_id,
+                "Coffee".to_string(),
+                1,
+                RuleConditions::new(Some("coffee".to_string()), None, None, None, None, None),
+                category_id,
+                vec![],
+                Utc::now(),
+            ),
+        ];
+
+        let mut rep = MockRuleProjectionRepository::new();
+        rep.expect_find()
+            .with(eq(user_id))
+            .returning(move |_| {
+                let views = views.clone();
+                async move { Ok(views) }.boxed()
+            });
+        let handler = MatchRuleQueryHandler::new(rep);
+
+        let target = |label: &str| RuleTarget::new("Expense".to_string(), label.to_string(), "EUR".to_string(), Decimal::ONE);
+
+        let matched = handler.handle(MatchRuleQuery::new(user_id, target("Morning coffee"))).await.unwrap();
+        assert_eq!(matched.map(|categorization| *categorization.category_id()), Some(category_id));
+
+        let matched = handler.handle(MatchRuleQuery::new(user_id, target("Bakery"))).await.unwrap();
+        assert!(matched.is_none());
+    }
+}
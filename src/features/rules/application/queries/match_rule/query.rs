@@ -0,0 +1,35 @@
+use uuid::Uuid;
+use crate::features::rules::domain::rule_target::RuleTarget;
+use crate::support::query_bus::Query;
+
+const NAME: &str = "match_rule";
+
+/// Finds the rule of the user that categorizes `target`.
+#[derive(Debug, Clone)]
+pub struct MatchRuleQuery {
+    user_id: Uuid,
+    target: RuleTarget,
+}
+
+impl MatchRuleQuery {
+    pub fn new(user_id: Uuid, target: RuleTarget) -> Self {
+        Self {
+            user_id,
+            target,
+        }
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    pub fn target(&self) -> &RuleTarget {
+        &self.target
+    }
+}
+
+impl Query for MatchRuleQuery {
+    fn name() -> &'static str {
+        NAME
+    }
+}
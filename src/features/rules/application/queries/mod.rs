@@ -0,0 +1,4 @@
+pub mod list_rules;
+pub mod match_rule;
+pub mod rule_projection_repository;
+pub mod rule_view;
\ No newline at end of file
@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use mockall::automock;
+use uuid::Uuid;
+use crate::features::rules::application::queries::rule_view::RuleView;
+use crate::features::rules::domain::events::rule_created::RuleCreated;
+use crate::features::rules::domain::events::rule_deleted::RuleDeleted;
+use crate::features::rules::error::RuleError;
+
+#[async_trait]
+#[automock]
+pub trait RuleProjectionRepository {
+    /// Rules of the user in the order they are tried.
+    async fn find(&self, user_id: Uuid) -> Result<Vec<RuleView>, RuleError>;
+
+    async fn apply_rule_created(&self, event: &RuleCreated) -> Result<(), RuleError>;
+
+    async fn apply_rule_deleted(&self, event: &RuleDeleted) -> Result<(), RuleError>;
+}
@@ -0,0 +1,81 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use sqlx::types::Json;
+use uuid::Uuid;
+use crate::features::rules::domain::categorizer::Categorization;
+use crate::features::rules::domain::rule_conditions::RuleConditions;
+
+/// Read model row of the `rules` projection.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct RuleView {
+    id: Uuid,
+    user_id: Uuid,
+    name: String,
+    priority: i32,
+    conditions: Json<RuleConditions>,
+    category_id: Uuid,
+    tag_ids: Vec<Uuid>,
+    created_at: DateTime<Utc>,
+}
+
+impl RuleView {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: Uuid,
+        user_id: Uuid,
+        name: String,
+        priority: i32,
+        conditions: RuleConditions,
+        category_id: Uuid,
+        tag_ids: Vec<Uuid>,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            name,
+            priority,
+            conditions: Json(conditions),
+            category_id,
+            tag_ids,
+            created_at,
+        }
+    }
+
+    pub fn id(&self) -> &Uuid {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    pub fn conditions(&self) -> &RuleConditions {
+        &self.conditions
+    }
+
+    pub fn category_id(&self) -> &Uuid {
+        &self.category_id
+    }
+
+    pub fn tag_ids(&self) -> &[Uuid] {
+        &self.tag_ids
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    pub fn categorization(&self) -> Categorization {
+        Categorization::new(self.id, self.category_id, self.tag_ids.clone())
+    }
+}
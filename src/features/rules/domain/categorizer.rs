@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::features::rules::domain::rule_conditions::{RuleConditions, RuleMatcher};
+use crate::features::rules::domain::rule_target::RuleTarget;
+
+/// Category and tags a rule assigns to the operations it matches.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Categorization {
+    rule_id: Uuid,
+    category_id: Uuid,
+    tag_ids: Vec<Uuid>,
+}
+
+impl Categorization {
+    pub fn new(rule_id: Uuid, category_id: Uuid, tag_ids: Vec<Uuid>) -> Self {
+        Self {
+            rule_id,
+            category_id,
+            tag_ids,
+        }
+    }
+
+    pub fn rule_id(&self) -> &Uuid {
+        &self.rule_id
+    }
+
+    pub fn category_id(&self) -> &Uuid {
+        &self.category_id
+    }
+
+    pub fn tag_ids(&self) -> &[Uuid] {
+        &self.tag_ids
+    }
+}
+
+/// Rules of a user in the order they are tried, the first matching rule wins.
+pub struct Categorizer {
+    rules: Vec<(RuleMatcher, Categorization)>,
+}
+
+impl Categorizer {
+    /// Rules whose conditions no longer compile are left out.
+    pub fn new(rules: Vec<(RuleConditions, Categorization)>) -> Self {
+        Self {
+            rules: rules.into_iter()
+                .filter_map(|(conditions, categorization)|
+                    conditions.matcher()
+                        .ok()
+                        .map(|matcher| (matcher, categorization))
+                )
+                .collect(),
+        }
+    }
+
+    pub fn categorize(&self, target: &RuleTarget) -> Option<&Categorization> {
+        self.rules.iter()
+            .find(|(matcher, _)| matcher.matches(target))
+            .map(|(_, categorization)| categorization)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+    use super::*;
+
+    #[test]
+    fn test_categorize_takes_first_matching_rule() {
+        let first = Categorization::new(Uuid::new_v4(), Uuid::new_v4(), vec![]);
+        let second = Categorization::new(Uuid::new_v4(), Uuid::new_v4(), vec![Uuid::new_v4()]);
+
+        let categorizer = Categorizer::new(vec![
+            (RuleConditions::new(Some("netflix".to_string()), None, None, None, None, None), first.clone()),
+            (RuleConditions::new(None, None, None, None, Some("EUR".to_string()), None), second.clone()),
+            (RuleConditions::default(), Categorization::new(Uuid::new_v4(), Uuid::new_v4(), vec![])),
+        ]);
+        let target = |label: &str| RuleTarget::new("Expense".to_string(), label.to_string(), "EUR".to_string(), Decimal::TEN);
+
+        assert_eq!(categorizer.categorize(&target("NETFLIX.COM")), Some(&first));
+        assert_eq!(categorizer.categorize(&target("Bakery")), Some(&second));
+        assert_eq!(
+            categorizer.categorize(&RuleTarget::new("Expense".to_string(), "Bakery".to_string(), "USD".to_string(), Decimal::TEN)),
+            None
+        );
+    }
+}
@@ -0,0 +1,19 @@
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone)]
+pub enum DomainError {
+    #[error("Rule {0} not found")]
+    RuleNotFound(String),
+
+    #[error("Rule belongs to another user")]
+    AccessDenied,
+
+    #[error("Rule is already deleted")]
+    AlreadyDeleted,
+
+    #[error("Invalid rule. {0}")]
+    InvalidRule(String),
+
+    #[error("Invalid rule condition. {0}")]
+    InvalidCondition(String),
+}
@@ -0,0 +1,3 @@
+pub mod rule_created;
+pub mod rule_deleted;
+pub mod rule_event;
\ No newline at end of file
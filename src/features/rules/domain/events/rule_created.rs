@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+use crate::features::rules::domain::rule_conditions::RuleConditions;
+use crate::support::id::Id;
+
+pub const RULE_CREATED_NAME: &str = "rule_created";
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RuleCreated {
+    id: Id,
+    name: String,
+    payload: RuleCreatedPayload,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RuleCreatedPayload {
+    id: Id,
+    user_id: Id,
+    name: String,
+    priority: i32,
+    conditions: Box<RuleConditions>,
+    category_id: Id,
+    tag_ids: Vec<Id>,
+}
+
+impl RuleCreated {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: Id,
+        rule_id: Id,
+        user_id: Id,
+        name: String,
+        priority: i32,
+        conditions: RuleConditions,
+        category_id: Id,
+        tag_ids: Vec<Id>,
+    ) -> Self {
+        Self {
+            id,
+            name: RULE_CREATED_NAME.to_string(),
+            payload: RuleCreatedPayload {
+                id: rule_id,
+                user_id,
+                name,
+                priority,
+                conditions: Box::new(conditions),
+                category_id,
+                tag_ids,
+            }
+        }
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn payload(&self) -> &RuleCreatedPayload {
+        &self.payload
+    }
+}
+
+impl RuleCreatedPayload {
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Id {
+        &self.user_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    pub fn conditions(&self) -> &RuleConditions {
+        &self.conditions
+    }
+
+    pub fn category_id(&self) -> &Id {
+        &self.category_id
+    }
+
+    pub fn tag_ids(&self) -> &[Id] {
+        &self.tag_ids
+    }
+}
@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+use crate::support::id::Id;
+
+pub const RULE_DELETED_NAME: &str = "rule_deleted";
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RuleDeleted {
+    id: Id,
+    name: String,
+    payload: RuleDeletedPayload,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RuleDeletedPayload {
+    id: Id,
+    user_id: Id,
+}
+
+impl RuleDeleted {
+    pub fn new(id: Id, rule_id: Id, user_id: Id) -> Self {
+        Self {
+            id,
+            name: RULE_DELETED_NAME.to_string(),
+            payload: RuleDeletedPayload {
+                id: rule_id,
+                user_id,
+            }
+        }
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn payload(&self) -> &RuleDeletedPayload {
+        &self.payload
+    }
+}
+
+impl RuleDeletedPayload {
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Id {
+        &self.user_id
+    }
+}
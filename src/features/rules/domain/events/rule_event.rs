@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+use crate::features::rules::domain::events::rule_created::{RULE_CREATED_NAME, RuleCreated};
+use crate::features::rules::domain::events::rule_deleted::{RULE_DELETED_NAME, RuleDeleted};
+use crate::support::event_store::{decode_event, EventStoreError, NewEvent, StorableEvent, StoredEvent};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum RuleEvent {
+    RuleCreated(RuleCreated),
+    RuleDeleted(RuleDeleted),
+}
+
+impl RuleEvent {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::RuleCreated(event) => event.name(),
+            Self::RuleDeleted(event) => event.name(),
+        }
+    }
+}
+
+impl StorableEvent for RuleEvent {
+    fn from_stored(stored: &StoredEvent) -> Result<Self, EventStoreError> {
+        match stored.name() {
+            RULE_CREATED_NAME => Ok(Self::RuleCreated(decode_event(stored)?)),
+            RULE_DELETED_NAME => Ok(Self::RuleDeleted(decode_event(stored)?)),
+            name => Err(
+                EventStoreError::Serialization(format!("Unknown rule event {}", name))
+            ),
+        }
+    }
+
+    fn to_new(&self) -> Result<NewEvent, EventStoreError> {
+        match self {
+            Self::RuleCreated(event) => NewEvent::from_event(event),
+            Self::RuleDeleted(event) => NewEvent::from_event(event),
+        }
+    }
+}
@@ -0,0 +1,9 @@
+pub mod categorizer;
+pub mod error;
+pub mod events;
+pub mod operation_categorizer;
+pub mod operation_source;
+pub mod rule;
+pub mod rule_conditions;
+pub mod rule_repository;
+pub mod rule_target;
\ No newline at end of file
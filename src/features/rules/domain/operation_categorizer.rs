@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use mockall::automock;
+use uuid::Uuid;
+use crate::features::rules::domain::categorizer::Categorization;
+use crate::support::error::FeatureError;
+
+/// Re-categorizes operations of the operations bounded context.
+#[async_trait]
+#[automock]
+pub trait OperationCategorizer {
+    /// Moves the operation to the category of `categorization` and adds its tags, nothing changes when it already has them.
+    /// The events of the update are published before returning, so a later operation failing cannot lose them.
+    async fn categorize(&self, user_id: Uuid, operation_id: Uuid, categorization: &Categorization) -> Result<(), FeatureError>;
+}
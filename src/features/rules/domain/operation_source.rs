@@ -0,0 +1,48 @@
+use async_trait::async_trait;
+use mockall::automock;
+use uuid::Uuid;
+use crate::features::rules::domain::categorizer::Categorization;
+use crate::features::rules::domain::rule_target::RuleTarget;
+use crate::support::error::FeatureError;
+
+/// Operation of the history rules are re-applied to.
+#[derive(Debug, Clone)]
+pub struct RuleOperation {
+    id: Uuid,
+    target: RuleTarget,
+    category_id: Uuid,
+    tag_ids: Vec<Uuid>,
+}
+
+impl RuleOperation {
+    pub fn new(id: Uuid, target: RuleTarget, category_id: Uuid, tag_ids: Vec<Uuid>) -> Self {
+        Self {
+            id,
+            target,
+            category_id,
+            tag_ids,
+        }
+    }
+
+    pub fn id(&self) -> &Uuid {
+        &self.id
+    }
+
+    pub fn target(&self) -> &RuleTarget {
+        &self.target
+    }
+
+    /// Whether the operation is not in the category of `categorization` yet or misses one of its tags.
+    pub fn differs_from(&self, categorization: &Categorization) -> bool {
+        self.category_id != *categorization.category_id()
+            || categorization.tag_ids().iter().any(|tag_id| !self.tag_ids.contains(tag_id))
+    }
+}
+
+/// Operations of the operations bounded context.
+#[async_trait]
+#[automock]
+pub trait OperationSource {
+    /// Operations of the user that are not deleted.
+    async fn operations(&self, user_id: Uuid) -> Result<Vec<RuleOperation>, FeatureError>;
+}
@@ -0,0 +1,231 @@
+use uuid::Uuid;
+use crate::features::rules::application::commands::create_rule::command::CreateRuleCommand;
+use crate::features::rules::application::commands::delete_rule::command::DeleteRuleCommand;
+use crate::features::rules::domain::error::DomainError;
+use crate::features::rules::domain::events::rule_created::RuleCreated;
+use crate::features::rules::domain::events::rule_deleted::RuleDeleted;
+use crate::features::rules::domain::events::rule_event::RuleEvent;
+use crate::features::rules::domain::rule_conditions::RuleConditions;
+use crate::support::event_store::{Aggregate, EventStoreError};
+use crate::support::id::Id;
+
+/// User-defined rule assigning a category and tags to the operations matching its conditions.
+#[derive(Debug, Clone)]
+pub struct Rule {
+    id: Id,
+    user_id: Id,
+    name: String,
+    priority: i32,
+    conditions: RuleConditions,
+    category_id: Id,
+    tag_ids: Vec<Id>,
+    deleted: bool,
+}
+
+impl Rule {
+    pub fn handle_creation(command: CreateRuleCommand) -> Result<RuleEvent, DomainError> {
+        let name = command.name().trim();
+
+        if name.is_empty() {
+            return Err(DomainError::InvalidRule("Name is required".to_string()));
+        }
+
+        command.conditions().matcher()?;
+
+        let mut tag_ids: Vec<Id> = vec![];
+        for tag_id in command.tag_ids() {
+            let tag_id = Id::new(*tag_id);
+            if !tag_ids.contains(&tag_id) {
+                tag_ids.push(tag_id);
+            }
+        }
+
+        Ok(
+            RuleEvent::RuleCreated(
+                RuleCreated::new(
+                    Id::new(Id::generate()),
+                    Id::new(Id::generate()),
+                    Id::new(*command.user_id()),
+                    name.to_string(),
+                    command.priority(),
+                    command.conditions().clone(),
+                    Id::new(*command.category_id()),
+                    tag_ids,
+                )
+            )
+        )
+    }
+
+    pub fn handle_deletion(&self, command: DeleteRuleCommand) -> Result<RuleEvent, DomainError> {
+        self.check_access(command.user_id())?;
+
+        if self.deleted {
+            return Err(DomainError::AlreadyDeleted);
+        }
+
+        Ok(
+            RuleEvent::RuleDeleted(
+                RuleDeleted::new(
+                    Id::new(Id::generate()),
+                    self.id.clone(),
+                    self.user_id.clone(),
+                )
+            )
+        )
+    }
+
+    fn check_access(&self, user_id: &Uuid) -> Result<(), DomainError> {
+        if self.user_id.value() != *user_id {
+            return Err(DomainError::AccessDenied);
+        }
+
+        Ok(())
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Id {
+        &self.user_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    pub fn conditions(&self) -> &RuleConditions {
+        &self.conditions
+    }
+
+    pub fn category_id(&self) -> &Id {
+        &self.category_id
+    }
+
+    pub fn tag_ids(&self) -> &[Id] {
+        &self.tag_ids
+    }
+
+    pub fn is_deleted(&self) -> bool {
+        self.deleted
+    }
+}
+
+impl Aggregate for Rule {
+    type Event = RuleEvent;
+
+    fn apply(state: Option<Self>, event: &RuleEvent) -> Result<Self, EventStoreError> {
+        match (state, event) {
+            (None, RuleEvent::RuleCreated(created)) => {
+                let payload = created.payload();
+
+                Ok(
+                    Self {
+                        id: payload.id().clone(),
+                        user_id: payload.user_id().clone(),
+                        name: payload.name().to_string(),
+                        priority: payload.priority(),
+                        conditions: payload.conditions().clone(),
+                        category_id: payload.category_id().clone(),
+                        tag_ids: payload.tag_ids().to_vec(),
+                        deleted: false,
+                    }
+                )
+            }
+            (Some(rule), RuleEvent::RuleCreated(_)) => Err(
+                EventStoreError::Rehydration(
+                    format!("Rule {} is already created", rule.id().value())
+                )
+            ),
+            (Some(rule), RuleEvent::RuleDeleted(_)) => Ok(
+                Self {
+                    deleted: true,
+                    ..rule
+                }
+            ),
+            (None, event) => Err(
+                EventStoreError::Rehydration(
+                    format!("Rule stream must start with rule_created, got {}", event.name())
+                )
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn test_handle_creation() {
+        let tag_id = Uuid::new_v4();
+        let command = CreateRuleCommand::new(
+            Uuid::new_v4(),
+            " Coffee ".to_string(),
+            1,
+            conditions_fixture(),
+            Uuid::new_v4(),
+            vec![tag_id, tag_id],
+        );
+
+        let RuleEvent::RuleCreated(created) = Rule::handle_creation(command).unwrap() else {
+            panic!("Rule is not created");
+        };
+
+        assert_eq!(created.payload().name(), "Coffee");
+        assert_eq!(created.payload().tag_ids(), &[Id::new(tag_id)]);
+    }
+
+    #[test]
+    fn test_handle_creation_rejects_invalid_rule() {
+        for (name, conditions) in [
+            ("", conditions_fixture()),
+            ("Coffee", RuleConditions::default()),
+        ] {
+            let command = CreateRuleCommand::new(Uuid::new_v4(), name.to_string(), 1, conditions, Uuid::new_v4(), vec![]);
+
+            assert!(matches!(Rule::handle_creation(command), Err(DomainError::InvalidRule(_))));
+        }
+    }
+
+    #[test]
+    fn test_handle_deletion() {
+        let rule = rule_fixture();
+        let user_id = rule.user_id().value();
+
+        assert!(matches!(
+            rule.handle_deletion(DeleteRuleCommand::new(rule.id().value(), Uuid::new_v4())),
+            Err(DomainError::AccessDenied)
+        ));
+
+        let event = rule.handle_deletion(DeleteRuleCommand::new(rule.id().value(), user_id)).unwrap();
+        let rule = Rule::apply(Some(rule), &event).unwrap();
+
+        assert!(rule.is_deleted());
+        assert!(matches!(
+            rule.handle_deletion(DeleteRuleCommand::new(rule.id().value(), user_id)),
+            Err(DomainError::AlreadyDeleted)
+        ));
+    }
+
+    pub fn rule_fixture() -> Rule {
+        let command = CreateRuleCommand::new(
+            Uuid::new_v4(),
+            "Coffee".to_string(),
+            1,
+            conditions_fixture(),
+            Uuid::new_v4(),
+            vec![],
+        );
+
+        Rule::apply(None, &Rule::handle_creation(command).unwrap()).unwrap()
+    }
+
+    fn conditions_fixture() -> RuleConditions {
+        RuleConditions::new(Some("coffee".to_string()), None, None, None, None, None)
+    }
+}
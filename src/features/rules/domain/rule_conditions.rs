@@ -0,0 +1,198 @@
+use regex::{Regex, RegexBuilder};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use crate::features::operations::domain::kind::Kind;
+use crate::features::rules::domain::error::DomainError;
+use crate::features::rules::domain::rule_target::RuleTarget;
+use crate::support::currency::Currency;
+
+/// Conditions an operation has to meet for a rule to apply. Every given condition has to match,
+/// labels are compared case-insensitively and the amount range includes its bounds.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct RuleConditions {
+    #[serde(default)]
+    label_contains: Option<String>,
+    #[serde(default)]
+    label_regex: Option<String>,
+    #[serde(default)]
+    min_amount: Option<Decimal>,
+    #[serde(default)]
+    max_amount: Option<Decimal>,
+    #[serde(default)]
+    currency: Option<String>,
+    #[serde(default)]
+    kind: Option<String>,
+}
+
+impl RuleConditions {
+    pub fn new(
+        label_contains: Option<String>,
+        label_regex: Option<String>,
+        min_amount: Option<Decimal>,
+        max_amount: Option<Decimal>,
+        currency: Option<String>,
+        kind: Option<String>,
+    ) -> Self {
+        Self {
+            label_contains,
+            label_regex,
+            min_amount,
+            max_amount,
+            currency,
+            kind,
+        }
+    }
+
+    pub fn label_contains(&self) -> &Option<String> {
+        &self.label_contains
+    }
+
+    pub fn label_regex(&self) -> &Option<String> {
+        &self.label_regex
+    }
+
+    pub fn min_amount(&self) -> Option<Decimal> {
+        self.min_amount
+    }
+
+    pub fn max_amount(&self) -> Option<Decimal> {
+        self.max_amount
+    }
+
+    pub fn currency(&self) -> &Option<String> {
+        &self.currency
+    }
+
+    pub fn kind(&self) -> &Option<String> {
+        &self.kind
+    }
+
+    /// Validates the conditions and compiles them for matching.
+    pub fn matcher(&self) -> Result<RuleMatcher, DomainError> {
+        if *self == Self::default() {
+            return Err(DomainError::InvalidRule("At least one condition is required".to_string()));
+        }
+
+        let label_contains = match &self.label_contains {
+            Some(text) if text.trim().is_empty() => {
+                return Err(DomainError::InvalidCondition("Label text must not be empty".to_string()));
+            }
+            text => text.as_ref().map(|text| text.to_lowercase()),
+        };
+
+        let label_regex = self.label_regex.as_ref()
+            .map(|pattern|
+                RegexBuilder::new(pattern)
+                    .case_insensitive(true)
+                    .build()
+                    .map_err(|e| DomainError::InvalidCondition(format!("Invalid label pattern {}. {}", pattern, e)))
+            )
+            .transpose()?;
+
+        for amount in [self.min_amount, self.max_amount].into_iter().flatten() {
+            if amount.is_sign_negative() {
+                return Err(DomainError::InvalidCondition("Amounts must not be negative".to_string()));
+            }
+        }
+
+        if let (Some(min_amount), Some(max_amount)) = (self.min_amount, self.max_amount) {
+            if min_amount > max_amount {
+                return Err(DomainError::InvalidCondition("Minimum amount exceeds maximum amount".to_string()));
+            }
+        }
+
+        if let Some(currency) = &self.currency {
+            Currency::find(currency)
+                .ok_or(DomainError::InvalidCondition(format!("Unknown currency {}", currency)))?;
+        }
+
+        if let Some(kind) = &self.kind {
+            Kind::new(kind)
+                .map_err(|_| DomainError::InvalidCondition(format!("Unknown operation kind {}", kind)))?;
+        }
+
+        Ok(
+            RuleMatcher {
+                label_contains,
+                label_regex,
+                min_amount: self.min_amount,
+                max_amount: self.max_amount,
+                currency: self.currency.clone(),
+                kind: self.kind.clone(),
+            }
+        )
+    }
+}
+
+/// Compiled conditions of a rule.
+#[derive(Debug, Clone)]
+pub struct RuleMatcher {
+    label_contains: Option<String>,
+    label_regex: Option<Regex>,
+    min_amount: Option<Decimal>,
+    max_amount: Option<Decimal>,
+    currency: Option<String>,
+    kind: Option<String>,
+}
+
+impl RuleMatcher {
+    pub fn matches(&self, target: &RuleTarget) -> bool {
+        self.label_contains.as_ref().is_none_or(|text| target.label().to_lowercase().contains(text))
+            && self.label_regex.as_ref().is_none_or(|regex| regex.is_match(target.label()))
+            && self.min_amount.is_none_or(|min_amount| target.currency_amount() >= min_amount)
+            && self.max_amount.is_none_or(|max_amount| target.currency_amount() <= max_amount)
+            && self.currency.as_ref().is_none_or(|currency| target.currency() == currency)
+            && self.kind.as_ref().is_none_or(|kind| target.kind() == kind)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches() {
+        let matcher = RuleConditions::new(
+            Some("coffee".to_string()),
+            None,
+            Some(Decimal::ONE),
+            Some(Decimal::from(10)),
+            Some("EUR".to_string()),
+            Some("Expense".to_string()),
+        ).matcher().unwrap();
+
+        assert!(matcher.matches(&target_fixture("Coffee Shop", "EUR", Decimal::from(10))));
+        assert!(!matcher.matches(&target_fixture("Coffee Shop", "USD", Decimal::from(3))));
+        assert!(!matcher.matches(&target_fixture("Coffee Shop", "EUR", Decimal::from(11))));
+        assert!(!matcher.matches(&target_fixture("Bakery", "EUR", Decimal::from(3))));
+    }
+
+    #[test]
+    fn test_matches_regex() {
+        let matcher = RuleConditions::new(None, Some("^(uber|lyft)\\b".to_string()), None, None, None, None)
+            .matcher()
+            .unwrap();
+
+        assert!(matcher.matches(&target_fixture("UBER *TRIP", "EUR", Decimal::ONE)));
+        assert!(!matcher.matches(&target_fixture("Payment to Uber", "EUR", Decimal::ONE)));
+    }
+
+    #[test]
+    fn test_matcher_rejects_invalid_conditions() {
+        for conditions in [
+            RuleConditions::default(),
+            RuleConditions::new(Some(" ".to_string()), None, None, None, None, None),
+            RuleConditions::new(None, Some("(".to_string()), None, None, None, None),
+            RuleConditions::new(None, None, Some(Decimal::from(5)), Some(Decimal::ONE), None, None),
+            RuleConditions::new(None, None, Some(Decimal::NEGATIVE_ONE), None, None, None),
+            RuleConditions::new(None, None, None, None, Some("XXX".to_string()), None),
+            RuleConditions::new(None, None, None, None, None, Some("Gift".to_string())),
+        ] {
+            assert!(conditions.matcher().is_err(), "{:?} is accepted", conditions);
+        }
+    }
+
+    fn target_fixture(label: &str, currency: &str, currency_amount: Decimal) -> RuleTarget {
+        RuleTarget::new("Expense".to_string(), label.to_string(), currency.to_string(), currency_amount)
+    }
+}
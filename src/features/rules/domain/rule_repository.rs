@@ -0,0 +1,58 @@
+use std::collections::HashMap;
+use async_trait::async_trait;
+use uuid::Uuid;
+use crate::features::rules::domain::events::rule_event::RuleEvent;
+use crate::features::rules::domain::rule::Rule;
+use crate::features::rules::error::RuleError;
+use crate::features::rules::infrastructure::error::InfrastructureError;
+use crate::support::event_store::Versioned;
+
+#[async_trait]
+pub trait RuleRepository {
+    async fn load(&self, rule_id: Uuid) -> Result<Option<Versioned<Rule>>, RuleError>;
+
+    /// Fails with `InfrastructureError::Conflict` when the stream is no longer at `expected_version`.
+    async fn append(&self, rule_id: Uuid, expected_version: i32, events: &[RuleEvent]) -> Result<i32, RuleError>;
+}
+
+pub struct MockRuleRepository {
+    has_error: bool,
+    rules: HashMap<Uuid, Versioned<Rule>>,
+}
+
+impl MockRuleRepository {
+    pub fn new(has_error: bool) -> Self {
+        Self {
+            has_error,
+            rules: HashMap::new(),
+        }
+    }
+
+    pub fn with_rules(rules: Vec<Versioned<Rule>>) -> Self {
+        Self {
+            has_error: false,
+            rules: rules.into_iter()
+                .map(|rule| (rule.aggregate().id().value(), rule))
+                .collect(),
+        }
+    }
+}
+
+#[async_trait]
+impl RuleRepository for MockRuleRepository {
+    async fn load(&self, rule_id: Uuid) -> Result<Option<Versioned<Rule>>, RuleError> {
+        Ok(self.rules.get(&rule_id).cloned())
+    }
+
+    async fn append(&self, _rule_id: Uuid, expected_version: i32, events: &[RuleEvent]) -> Result<i32, RuleError> {
+        if self.has_error {
+            return Err(
+                RuleError::Infrastructure(
+                    InfrastructureError::Repository("Mock repository error".into())
+                )
+            );
+        }
+
+        Ok(expected_version + events.len() as i32)
+    }
+}
@@ -0,0 +1,38 @@
+use rust_decimal::Decimal;
+
+/// What the conditions of a rule look at in an operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuleTarget {
+    kind: String,
+    label: String,
+    currency: String,
+    currency_amount: Decimal,
+}
+
+impl RuleTarget {
+    pub fn new(kind: String, label: String, currency: String, currency_amount: Decimal) -> Self {
+        Self {
+            kind,
+            label,
+            currency,
+            currency_amount,
+        }
+    }
+
+    pub fn kind(&self) -> &str {
+        &self.kind
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    /// Amount in the currency of the operation, amounts of operations are never negative.
+    pub fn currency_amount(&self) -> Decimal {
+        self.currency_amount
+    }
+}
@@ -0,0 +1,12 @@
+use thiserror::Error;
+use crate::features::rules::domain::error::DomainError;
+use crate::features::rules::infrastructure::error::InfrastructureError;
+
+#[derive(Error, Debug, Clone)]
+pub enum RuleError {
+    #[error("Rule domain error. {0}")]
+    Domain(DomainError),
+
+    #[error("Rule infrastructure error. {0}")]
+    Infrastructure(InfrastructureError),
+}
@@ -0,0 +1,65 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use uuid::Uuid;
+use crate::di::service_container::ServiceContainer;
+use crate::events::event_bus::EventBus;
+use crate::features::operations::application::commands::categorize_operation::command::CategorizeOperationCommand;
+use crate::features::operations::application::commands::categorize_operation::handler::CategorizeOperationCommandHandler;
+use crate::features::operations::infrastructure::db_operation_repository::DbOperationRepository;
+use crate::features::rules::domain::categorizer::Categorization;
+use crate::features::rules::domain::operation_categorizer::OperationCategorizer;
+use crate::features::rules::error::RuleError;
+use crate::features::rules::infrastructure::error::InfrastructureError;
+use crate::support::error::FeatureError;
+
+/// Re-categorizes operations through `CategorizeOperationCommandHandler` and publishes their events.
+pub struct CommandOperationCategorizer {
+    service_container: Arc<ServiceContainer>,
+    event_bus: Arc<Box<dyn EventBus>>,
+}
+
+impl CommandOperationCategorizer {
+    pub fn new(service_container: Arc<ServiceContainer>, event_bus: Arc<Box<dyn EventBus>>) -> Self {
+        Self {
+            service_container,
+            event_bus,
+        }
+    }
+}
+
+#[async_trait]
+impl OperationCategorizer for CommandOperationCategorizer {
+    async fn categorize(&self, user_id: Uuid, operation_id: Uuid, categorization: &Categorization) -> Result<(), FeatureError> {
+        let rep = DbOperationRepository::new(self.service_container.db_manager(), self.service_container.serializer());
+        let command = CategorizeOperationCommand::new(
+            operation_id,
+            user_id,
+            *categorization.category_id(),
+            categorization.tag_ids().to_vec(),
+        );
+
+        let mut command_bus = self.service_container.command_bus();
+        command_bus.register(CategorizeOperationCommandHandler::new(rep));
+        let events = command_bus.dispatch(command).await?;
+
+        // Every event is published even if one fails, the update is already recorded
+        let mut failure = None;
+
+        for event in events {
+            if let Err(e) = self.event_bus.publish(event).await {
+                failure.get_or_insert(e);
+            }
+        }
+
+        match failure {
+            Some(e) => Err(
+                FeatureError::Rule(
+                    RuleError::Infrastructure(
+                        InfrastructureError::Publishing(e.to_string())
+                    )
+                )
+            ),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use sqlx::query_as;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+use crate::db::manager::DbManager;
+use crate::features::rules::domain::operation_source::{OperationSource, RuleOperation};
+use crate::features::rules::domain::rule_target::RuleTarget;
+use crate::features::rules::error::RuleError;
+use crate::features::rules::infrastructure::error::InfrastructureError;
+use crate::support::error::FeatureError;
+
+/// Takes the operations from the operations projection.
+pub struct DbOperationSource {
+    db_manager: Arc<Mutex<DbManager>>,
+}
+
+impl DbOperationSource {
+    pub fn new(db_manager: Arc<Mutex<DbManager>>) -> Self {
+        Self {
+            db_manager,
+        }
+    }
+
+    fn error(message: String) -> FeatureError {
+        FeatureError::Rule(
+            RuleError::Infrastructure(
+                InfrastructureError::Repository(message)
+            )
+        )
+    }
+}
+
+#[async_trait]
+impl OperationSource for DbOperationSource {
+    async fn operations(&self, user_id: Uuid) -> Result<Vec<RuleOperation>, FeatureError> {
+        let q = "
+            SELECT id, kind, label, currency, currency_amount, category_id, tag_ids
+            FROM operations
+            WHERE user_id = $1
+            ORDER BY created_at, id
+        ";
+
+        let pool = self.db_manager.lock().await
+            .pool()
+            .map_err(|e| Self::error(format!("Failed to get pool: {}", e)))?;
+
+        let operations = query_as::<_, (Uuid, String, String, String, Decimal, Uuid, Vec<Uuid>)>(q)
+            .bind(user_id)
+            .fetch_all(&pool)
+            .await
+            .map_err(|e| Self::error(format!("Failed to fetch operations: {}", e)))?;
+
+        Ok(
+            operations.into_iter()
+                .map(|(id, kind, label, currency, currency_amount, category_id, tag_ids)|
+                    RuleOperation::new(id, RuleTarget::new(kind, label, currency, currency_amount), category_id, tag_ids)
+                )
+                .collect()
+        )
+    }
+}
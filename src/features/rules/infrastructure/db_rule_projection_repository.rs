@@ -0,0 +1,106 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use sqlx::{query, query_as, Pool, Postgres};
+use sqlx::postgres::PgArguments;
+use sqlx::query::Query;
+use sqlx::types::Json;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+use crate::db::manager::DbManager;
+use crate::features::rules::application::queries::rule_projection_repository::RuleProjectionRepository;
+use crate::features::rules::application::queries::rule_view::RuleView;
+use crate::features::rules::domain::events::rule_created::RuleCreated;
+use crate::features::rules::domain::events::rule_deleted::RuleDeleted;
+use crate::features::rules::error::RuleError;
+use crate::features::rules::infrastructure::error::InfrastructureError;
+
+#[derive(Clone)]
+pub struct DbRuleProjectionRepository {
+    db_manager: Arc<Mutex<DbManager>>,
+}
+
+impl DbRuleProjectionRepository {
+    pub fn new(db_manager: Arc<Mutex<DbManager>>) -> Self {
+        Self {
+            db_manager,
+        }
+    }
+
+    async fn pool(&self) -> Result<Pool<Postgres>, RuleError> {
+        let guard = self.db_manager.lock().await;
+
+        guard.pool()
+            .map_err(|e|
+                RuleError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to get pool: {}", e)
+                    )
+                )
+            )
+    }
+
+    async fn execute(&self, res_query: Query<'_, Postgres, PgArguments>) -> Result<(), RuleError> {
+        let pool = self.pool().await?;
+
+        res_query.execute(&pool)
+            .await
+            .map_err(|e|
+                RuleError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to project rule: {}", e)
+                    )
+                )
+            )?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl RuleProjectionRepository for DbRuleProjectionRepository {
+    async fn find(&self, user_id: Uuid) -> Result<Vec<RuleView>, RuleError> {
+        let q = "
+            SELECT id, user_id, name, priority, conditions, category_id, tag_ids, created_at
+            FROM rules
+            WHERE user_id = $1
+            ORDER BY priority, created_at, id
+        ";
+
+        let pool = self.pool().await?;
+
+        query_as::<_, RuleView>(q)
+            .bind(user_id)
+            .fetch_all(&pool)
+            .await
+            .map_err(|e|
+                RuleError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to fetch rules: {}", e)
+                    )
+                )
+            )
+    }
+
+    async fn apply_rule_created(&self, event: &RuleCreated) -> Result<(), RuleError> {
+        let payload = event.payload();
+        let tag_ids: Vec<Uuid> = payload.tag_ids().iter().map(|tag_id| tag_id.value()).collect();
+
+        self.execute(
+            query("INSERT INTO rules (id, user_id, name, priority, conditions, category_id, tag_ids) VALUES ($1, $2, $3, $4, $5, $6, $7) ON CONFLICT DO NOTHING")
+                .bind(payload.id().value())
+                .bind(payload.user_id().value())
+                .bind(payload.name().to_string())
+                .bind(payload.priority())
+                .bind(Json(payload.conditions().clone()))
+                .bind(payload.category_id().value())
+                .bind(tag_ids)
+        ).await
+    }
+
+    async fn apply_rule_deleted(&self, event: &RuleDeleted) -> Result<(), RuleError> {
+        self.execute(
+            query("DELETE FROM rules WHERE id = $1")
+                .bind(event.payload().id().value())
+        ).await
+    }
+}
@@ -0,0 +1,64 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+use crate::db::manager::DbManager;
+use crate::features::rules::domain::rule::Rule;
+use crate::features::rules::domain::rule_repository::RuleRepository;
+use crate::features::rules::domain::events::rule_event::RuleEvent;
+use crate::features::rules::error::RuleError;
+use crate::features::rules::infrastructure::error::InfrastructureError;
+use crate::support::event_store::{Aggregate, EventStore, StorableEvent, Versioned};
+use crate::support::pg_event_store::PgEventStore;
+
+const EVENTS_TABLE: &str = "rule_events";
+
+#[derive(Clone)]
+pub struct DbRuleRepository {
+    event_store: PgEventStore,
+}
+
+impl DbRuleRepository {
+    pub fn new(db_manager: Arc<Mutex<DbManager>>) -> Self {
+        Self {
+            event_store: PgEventStore::new(db_manager, EVENTS_TABLE),
+        }
+    }
+}
+
+#[async_trait]
+impl RuleRepository for DbRuleRepository {
+    async fn load(&self, rule_id: Uuid) -> Result<Option<Versioned<Rule>>, RuleError> {
+        let stream = self.event_store.load(rule_id)
+            .await
+            .map_err(|e|
+                RuleError::Infrastructure(
+                    InfrastructureError::Repository(e.to_string())
+                )
+            )?;
+
+        Rule::rehydrate(&stream)
+            .map_err(|e|
+                RuleError::Infrastructure(
+                    InfrastructureError::Repository(e.to_string())
+                )
+            )
+    }
+
+    async fn append(&self, rule_id: Uuid, expected_version: i32, events: &[RuleEvent]) -> Result<i32, RuleError> {
+        let events = events.iter()
+            .map(|event| event.to_new())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e|
+                RuleError::Infrastructure(
+                    InfrastructureError::Repository(e.to_string())
+                )
+            )?;
+
+        self.event_store.append(rule_id, expected_version, &events)
+            .await
+            .map_err(|e|
+                RuleError::Infrastructure(e.into())
+            )
+    }
+}
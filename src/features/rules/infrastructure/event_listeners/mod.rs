@@ -0,0 +1 @@
+pub mod rule_projection_listener;
\ No newline at end of file
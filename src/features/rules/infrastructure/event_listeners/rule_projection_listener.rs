@@ -0,0 +1,65 @@
+use async_trait::async_trait;
+use crate::events::error::EventError;
+use crate::events::event::Event;
+use crate::events::event_listener::EventListener;
+use crate::features::rules::application::queries::rule_projection_repository::RuleProjectionRepository;
+use crate::features::rules::domain::events::rule_event::RuleEvent;
+use crate::support::error::FeatureError;
+
+/// Keeps the `rules` read model in sync with the rule event stream.
+/// One instance is registered per rule event name.
+pub struct RuleProjectionListener<R>
+    where
+        R: RuleProjectionRepository + Send + Sync + 'static,
+{
+    rep: R,
+    event_name: &'static str,
+}
+
+#[async_trait]
+impl<R> EventListener for RuleProjectionListener<R>
+    where
+        R: RuleProjectionRepository + Send + Sync + 'static,
+{
+    async fn on_event(&mut self, event: Event) -> Result<Vec<Event>, EventError> {
+        let res = match self.parse_event(event)? {
+            RuleEvent::RuleCreated(event) => self.rep.apply_rule_created(&event).await,
+            RuleEvent::RuleDeleted(event) => self.rep.apply_rule_deleted(&event).await,
+        };
+
+        res.map_err(|e|
+            EventError::Feature(
+                FeatureError::Rule(e)
+            )
+        )?;
+
+        Ok(vec![])
+    }
+
+    fn event_name(&self) -> &str {
+        self.event_name
+    }
+}
+
+impl<R> RuleProjectionListener<R>
+    where
+        R: RuleProjectionRepository + Send + Sync + 'static,
+{
+    pub fn new(rep: R, event_name: &'static str) -> Self {
+        Self {
+            rep,
+            event_name,
+        }
+    }
+
+    pub fn parse_event(&self, event: Event) -> Result<RuleEvent, EventError> {
+        match event {
+            Event::RuleEvent(rule_event) => Ok(rule_event),
+            _ => Err(
+                EventError::Parsing(
+                    format!("Invalid event type. Expected RuleEvent, got {:?}", event)
+                )
+            )
+        }
+    }
+}
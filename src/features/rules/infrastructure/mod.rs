@@ -0,0 +1,6 @@
+pub mod command_operation_categorizer;
+pub mod db_operation_source;
+pub mod db_rule_projection_repository;
+pub mod db_rule_repository;
+pub mod error;
+pub mod event_listeners;
\ No newline at end of file
@@ -0,0 +1 @@
+pub mod queries;
\ No newline at end of file
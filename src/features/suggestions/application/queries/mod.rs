@@ -0,0 +1,3 @@
+pub mod suggest;
+pub mod suggestion_projection_repository;
+pub mod suggestions;
\ No newline at end of file
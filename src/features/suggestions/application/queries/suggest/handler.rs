@@ -0,0 +1,207 @@
+use async_trait::async_trait;
+use crate::features::categories::application::queries::category_projection_repository::CategoryProjectionRepository;
+use crate::features::suggestions::application::queries::suggest::query::SuggestQuery;
+use crate::features::suggestions::application::queries::suggestion_projection_repository::SuggestionProjectionRepository;
+use crate::features::suggestions::application::queries::suggestions::{Suggestion, Suggestions};
+use crate::features::suggestions::domain::class_statistics::ClassStatistics;
+use crate::features::suggestions::domain::error::DomainError;
+use crate::features::suggestions::domain::naive_bayes::NaiveBayes;
+use crate::features::suggestions::domain::target::Target;
+use crate::features::suggestions::domain::tokenizer::tokenize;
+use crate::features::suggestions::error::SuggestionError;
+use crate::features::tags::application::queries::tag_projection_repository::TagProjectionRepository;
+use crate::support::error::FeatureError;
+use crate::support::query_bus::QueryHandler;
+
+const LIMIT: usize = 3;
+
+/// Tags less likely than not to be on the operation are not suggested.
+const TAG_THRESHOLD: f64 = 0.5;
+
+/// Ranks the categories and tags of the user for a draft operation, learned from the operations already recorded.
+pub struct SuggestQueryHandler<R, C, T>
+    where
+        R: SuggestionProjectionRepository + Send + Sync,
+        C: CategoryProjectionRepository + Send + Sync,
+        T: TagProjectionRepository + Send + Sync,
+{
+    rep: R,
+    category_rep: C,
+    tag_rep: T,
+}
+
+impl<R, C, T> SuggestQueryHandler<R, C, T>
+    where
+        R: SuggestionProjectionRepository + Send + Sync,
+        C: CategoryProjectionRepository + Send + Sync,
+        T: TagProjectionRepository + Send + Sync,
+{
+    pub fn new(rep: R, category_rep: C, tag_rep: T) -> Self {
+        Self {
+            rep,
+            category_rep,
+            tag_rep,
+        }
+    }
+}
+
+#[async_trait]
+impl<R, C, T> QueryHandler<SuggestQuery> for SuggestQueryHandler<R, C, T>
+    where
+        R: SuggestionProjectionRepository + Send + Sync,
+        C: CategoryProjectionRepository + Send + Sync,
+        T: TagProjectionRepository + Send + Sync,
+{
+    type Output = Suggestions;
+
+    async fn handle(&self, query: SuggestQuery) -> Result<Suggestions, FeatureError> {
+        if query.label().trim().is_empty() {
+            return Err(
+                FeatureError::Suggestion(
+                    SuggestionError::Domain(DomainError::BlankLabel)
+                )
+            );
+        }
+
+        let user_id = *query.user_id();
+        let tokens = tokenize(query.label(), query.amount());
+
+        let vocabulary = self.rep.find_vocabulary(user_id)
+            .await
+            .map_err(FeatureError::Suggestion)?;
+        let category_classes = self.rep.find_classes(user_id, Target::Category, &tokens)
+            .await
+            .map_err(FeatureError::Suggestion)?;
+        let tag_classes = self.rep.find_classes(user_id, Target::Tag, &tokens)
+            .await
+            .map_err(FeatureError::Suggestion)?;
+
+        // Archived categories and deleted tags were learned but are not offered anymore
+        let categories = self.category_rep.find(user_id, false)
+            .await
+            .map_err(FeatureError::Category)?;
+        let tags = self.tag_rep.find(user_id)
+            .await
+            .map_err(FeatureError::Tag)?;
+
+        let model = NaiveBayes::new(vocabulary);
+        let all = ClassStatistics::sum(user_id, &category_classes);
+
+        let category_suggestions = model.rank(&category_classes, &tokens)
+            .into_iter()
+            .filter_map(|(id, score)|
+                categories.iter()
+                    .find(|category| *category.id() == id)
+                    .map(|category| Suggestion::new(id, category.name().to_string(), score))
+            )
+            .take(LIMIT)
+            .collect();
+
+        let tag_suggestions = model.rank_independent(&tag_classes, &all, &tokens)
+            .into_iter()
+            .filter(|(_, score)| *score >= TAG_THRESHOLD)
+            .filter_map(|(id, score)|
+                tags.iter()
+                    .find(|tag| *tag.id() == id)
+                    .map(|tag| Suggestion::new(id, tag.name().to_string(), score))
+            )
+            .take(LIMIT)
+            .collect();
+
+        Ok(Suggestions::new(category_suggestions, tag_suggestions))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::str::FromStr;
+    use chrono::Utc;
+    use futures_util::FutureExt;
+    use rust_decimal::Decimal;
+    use uuid::Uuid;
+    use crate::features::categories::application::queries::category_projection_repository::MockCategoryProjectionRepository;
+    use crate::features::categories::application::queries::category_view::CategoryView;
+    use crate::features::suggestions::application::queries::suggestion_projection_repository::MockSuggestionProjectionRepository;
+    use crate::features::tags::application::queries::tag_projection_repository::MockTagProjectionRepository;
+    use crate::features::tags::application::queries::tag_view::TagView;
+    use super::*;
+
+    fn class_fixture(id: Uuid, operations: i64, token_counts: &[(&str, i64)]) -> ClassStatistics {
+        ClassStatistics::new(
+            id,
+            operations,
+            token_counts.iter().map(|(_, count)| count).sum(),
+            token_counts.iter().map(|(token, count)| (token.to_string(), *count)).collect::<HashMap<_, _>>(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_handle_ranks_history() {
+        let user_id = Uuid::new_v4();
+        let food = CategoryView::new(Uuid::new_v4(), user_id, "Food".to_string(), None, None, false, Utc::now());
+        let housing = CategoryView::new(Uuid::new_v4(), user_id, "Housing".to_string(), None, None, false, Utc::now());
+        let work = TagView::new(Uuid::new_v4(), user_id, "Work".to_string(), Utc::now());
+        let home = TagView::new(Uuid::new_v4(), user_id, "Home".to_string(), Utc::now());
+        let categories = vec![
+            class_fixture(*food.id(), 5, &[("coffee", 4), ("#amount:1", 5)]),
+            class_fixture(*housing.id(), 2, &[("#amount:4", 2)]),
+        ];
+        let tag_classes = vec![
+            class_fixture(*work.id(), 4, &[("coffee", 4), ("#amount:1", 4)]),
+            class_fixture(*home.id(), 2, &[("#amount:4", 2)]),
+        ];
+
+        let mut rep = MockSuggestionProjectionRepository::new();
+        rep.expect_find_vocabulary()
+            .times(1)
+            .returning(|_| async { Ok(4) }.boxed());
+        rep.expect_find_classes()
+            .times(2)
+            .returning(move |_, target, _| {
+                let classes = match target {
+                    Target::Category => categories.clone(),
+                    Target::Tag => tag_classes.clone(),
+                };
+                async move { Ok(classes) }.boxed()
+            });
+
+        let mut category_rep = MockCategoryProjectionRepository::new();
+        category_rep.expect_find()
+            .times(1)
+            .returning(move |_, _| {
+                let categories = vec![food.clone(), housing.clone()];
+                async move { Ok(categories) }.boxed()
+            });
+
+        let mut tag_rep = MockTagProjectionRepository::new();
+        tag_rep.expect_find()
+            .times(1)
+            .returning(move |_| {
+                let tags = vec![work.clone(), home.clone()];
+                async move { Ok(tags) }.boxed()
+            });
+
+        let suggestions = SuggestQueryHandler::new(rep, category_rep, tag_rep)
+            .handle(SuggestQuery::new(user_id, "Morning coffee".to_string(), Decimal::from_str("4.50").unwrap()))
+            .await
+            .unwrap();
+
+        assert_eq!(suggestions.categories()[0].name(), "Food");
+        assert_eq!(suggestions.categories().len(), 2);
+        assert_eq!(suggestions.tags().len(), 1);
+        assert_eq!(suggestions.tags()[0].name(), "Work");
+    }
+
+    #[tokio::test]
+    async fn test_handle_blank_label() {
+        let mut rep = MockSuggestionProjectionRepository::new();
+        rep.expect_find_classes().never();
+
+        let result = SuggestQueryHandler::new(rep, MockCategoryProjectionRepository::new(), MockTagProjectionRepository::new())
+            .handle(SuggestQuery::new(Uuid::new_v4(), " ".to_string(), Decimal::ONE))
+            .await;
+
+        assert!(matches!(result, Err(FeatureError::Suggestion(SuggestionError::Domain(DomainError::BlankLabel)))));
+    }
+}
@@ -0,0 +1,40 @@
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use crate::support::query_bus::Query;
+
+const NAME: &str = "suggest";
+
+#[derive(Debug, Clone)]
+pub struct SuggestQuery {
+    user_id: Uuid,
+    label: String,
+    amount: Decimal,
+}
+
+impl SuggestQuery {
+    pub fn new(user_id: Uuid, label: String, amount: Decimal) -> Self {
+        Self {
+            user_id,
+            label,
+            amount,
+        }
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub fn amount(&self) -> Decimal {
+        self.amount
+    }
+}
+
+impl Query for SuggestQuery {
+    fn name() -> &'static str {
+        NAME
+    }
+}
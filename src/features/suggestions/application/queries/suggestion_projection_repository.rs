@@ -0,0 +1,27 @@
+use async_trait::async_trait;
+use mockall::automock;
+use uuid::Uuid;
+use crate::features::operations::domain::events::operation_created::OperationCreated;
+use crate::features::operations::domain::events::operation_deleted::OperationDeleted;
+use crate::features::operations::domain::events::operation_updated::OperationUpdated;
+use crate::features::suggestions::domain::class_statistics::ClassStatistics;
+use crate::features::suggestions::domain::target::Target;
+use crate::features::suggestions::error::SuggestionError;
+
+/// What was learned from the current state of the operations of each user. Transfers are not learned from.
+#[async_trait]
+#[automock]
+pub trait SuggestionProjectionRepository {
+    /// Every category or tag learned for the user, token counts only cover `tokens`.
+    async fn find_classes(&self, user_id: Uuid, target: Target, tokens: &[String]) -> Result<Vec<ClassStatistics>, SuggestionError>;
+
+    /// Number of distinct tokens learned for the user.
+    async fn find_vocabulary(&self, user_id: Uuid) -> Result<i64, SuggestionError>;
+
+    async fn apply_operation_created(&self, event: &OperationCreated) -> Result<(), SuggestionError>;
+
+    /// Takes back what was learned from the previous state of the operation and learns the new one.
+    async fn apply_operation_updated(&self, event: &OperationUpdated) -> Result<(), SuggestionError>;
+
+    async fn apply_operation_deleted(&self, event: &OperationDeleted) -> Result<(), SuggestionError>;
+}
@@ -0,0 +1,54 @@
+use serde::Serialize;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Suggestion {
+    id: Uuid,
+    name: String,
+    score: f64,
+}
+
+impl Suggestion {
+    pub fn new(id: Uuid, name: String, score: f64) -> Self {
+        Self {
+            id,
+            name,
+            score,
+        }
+    }
+
+    pub fn id(&self) -> &Uuid {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn score(&self) -> f64 {
+        self.score
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Suggestions {
+    categories: Vec<Suggestion>,
+    tags: Vec<Suggestion>,
+}
+
+impl Suggestions {
+    pub fn new(categories: Vec<Suggestion>, tags: Vec<Suggestion>) -> Self {
+        Self {
+            categories,
+            tags,
+        }
+    }
+
+    pub fn categories(&self) -> &[Suggestion] {
+        &self.categories
+    }
+
+    pub fn tags(&self) -> &[Suggestion] {
+        &self.tags
+    }
+}
@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// What was learned about one category or tag from the operations recorded with it.
+/// `token_counts` only needs the tokens being scored.
+#[derive(Debug, Clone)]
+pub struct ClassStatistics {
+    id: Uuid,
+    operations: i64,
+    tokens: i64,
+    token_counts: HashMap<String, i64>,
+}
+
+impl ClassStatistics {
+    pub fn new(id: Uuid, operations: i64, tokens: i64, token_counts: HashMap<String, i64>) -> Self {
+        Self {
+            id,
+            operations,
+            tokens,
+            token_counts,
+        }
+    }
+
+    /// Adds up the classes. Every operation has exactly one category, so the sum of the categories describes all the operations.
+    pub fn sum(id: Uuid, classes: &[ClassStatistics]) -> Self {
+        let mut token_counts: HashMap<String, i64> = HashMap::new();
+
+        for class in classes {
+            for (token, count) in &class.token_counts {
+                *token_counts.entry(token.clone()).or_insert(0) += count;
+            }
+        }
+
+        Self {
+            id,
+            operations: classes.iter().map(|class| class.operations).sum(),
+            tokens: classes.iter().map(|class| class.tokens).sum(),
+            token_counts,
+        }
+    }
+
+    /// What is left of `self` once the operations of `class` are taken away.
+    pub fn without(&self, class: &ClassStatistics) -> Self {
+        let token_counts = self.token_counts.iter()
+            .map(|(token, count)| (token.clone(), (count - class.count(token)).max(0)))
+            .collect();
+
+        Self {
+            id: self.id,
+            operations: (self.operations - class.operations).max(0),
+            tokens: (self.tokens - class.tokens).max(0),
+            token_counts,
+        }
+    }
+
+    pub fn id(&self) -> &Uuid {
+        &self.id
+    }
+
+    pub fn operations(&self) -> i64 {
+        self.operations
+    }
+
+    pub fn tokens(&self) -> i64 {
+        self.tokens
+    }
+
+    pub fn count(&self, token: &str) -> i64 {
+        self.token_counts.get(token).copied().unwrap_or(0)
+    }
+}
@@ -0,0 +1,7 @@
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone)]
+pub enum DomainError {
+    #[error("Label must not be blank")]
+    BlankLabel,
+}
@@ -0,0 +1,5 @@
+pub mod class_statistics;
+pub mod error;
+pub mod naive_bayes;
+pub mod target;
+pub mod tokenizer;
\ No newline at end of file
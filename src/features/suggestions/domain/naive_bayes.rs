@@ -0,0 +1,124 @@
+use uuid::Uuid;
+use crate::features::suggestions::domain::class_statistics::ClassStatistics;
+
+/// Multinomial naive Bayes over the tokens of a label, with add-one smoothing.
+pub struct NaiveBayes {
+    vocabulary: i64,
+}
+
+impl NaiveBayes {
+    /// `vocabulary` is the number of distinct tokens seen for the user.
+    pub fn new(vocabulary: i64) -> Self {
+        Self {
+            vocabulary,
+        }
+    }
+
+    /// Probability of each class given the tokens, classes are mutually exclusive. Ordered from the most likely.
+    pub fn rank(&self, classes: &[ClassStatistics], tokens: &[String]) -> Vec<(Uuid, f64)> {
+        let operations: i64 = classes.iter().map(|class| class.operations()).sum();
+        if operations == 0 {
+            return vec![];
+        }
+
+        let scores: Vec<(Uuid, f64)> = classes.iter()
+            .filter(|class| class.operations() > 0)
+            .map(|class| (*class.id(), self.log_likelihood(class, operations, tokens)))
+            .collect();
+
+        let max = scores.iter().map(|(_, score)| *score).fold(f64::NEG_INFINITY, f64::max);
+        let total: f64 = scores.iter().map(|(_, score)| (score - max).exp()).sum();
+
+        let mut ranked: Vec<(Uuid, f64)> = scores.into_iter()
+            .map(|(id, score)| (id, (score - max).exp() / total))
+            .collect();
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        ranked
+    }
+
+    /// Probability that an operation with the tokens carries the class, each class is decided on its own against
+    /// the rest of `all`. Ordered from the most likely.
+    pub fn rank_independent(&self, classes: &[ClassStatistics], all: &ClassStatistics, tokens: &[String]) -> Vec<(Uuid, f64)> {
+        if all.operations() == 0 {
+            return vec![];
+        }
+
+        let mut ranked: Vec<(Uuid, f64)> = classes.iter()
+            .filter(|class| class.operations() > 0)
+            .map(|class| {
+                let with = self.log_likelihood(class, all.operations(), tokens);
+                let without = self.log_likelihood(&all.without(class), all.operations(), tokens);
+
+                (*class.id(), 1.0 / (1.0 + (without - with).exp()))
+            })
+            .collect();
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        ranked
+    }
+
+    fn log_likelihood(&self, class: &ClassStatistics, operations: i64, tokens: &[String]) -> f64 {
+        // Smoothed as well, so a class holding every operation leaves some room for the rest
+        let prior = ((class.operations() as f64 + 1.0) / (operations as f64 + 2.0)).ln();
+        let denominator = (class.tokens() + self.vocabulary.max(1)) as f64;
+
+        tokens.iter()
+            .map(|token| ((class.count(token) as f64 + 1.0) / denominator).ln())
+            .sum::<f64>() + prior
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use super::*;
+
+    fn class_fixture(operations: i64, token_counts: &[(&str, i64)]) -> ClassStatistics {
+        ClassStatistics::new(
+            Uuid::new_v4(),
+            operations,
+            token_counts.iter().map(|(_, count)| count).sum(),
+            token_counts.iter().map(|(token, count)| (token.to_string(), *count)).collect::<HashMap<_, _>>(),
+        )
+    }
+
+    fn tokens(tokens: &[&str]) -> Vec<String> {
+        tokens.iter().map(|token| token.to_string()).collect()
+    }
+
+    #[test]
+    fn test_rank() {
+        let coffee = class_fixture(4, &[("starbucks", 3), ("coffee", 4), ("#amount:1", 4)]);
+        let rent = class_fixture(2, &[("rent", 2), ("#amount:4", 2)]);
+        let classes = vec![coffee.clone(), rent.clone()];
+
+        let ranked = NaiveBayes::new(5).rank(&classes, &tokens(&["starbucks", "#amount:1"]));
+
+        assert_eq!(ranked[0].0, *coffee.id());
+        assert!(ranked[0].1 > 0.9);
+        assert!((ranked.iter().map(|(_, score)| score).sum::<f64>() - 1.0).abs() < 1e-9);
+
+        let ranked = NaiveBayes::new(5).rank(&classes, &tokens(&["rent", "#amount:4"]));
+
+        assert_eq!(ranked[0].0, *rent.id());
+        assert!(NaiveBayes::new(0).rank(&[], &tokens(&["rent"])).is_empty());
+    }
+
+    #[test]
+    fn test_rank_independent() {
+        let coffee = class_fixture(4, &[("starbucks", 3), ("coffee", 4), ("#amount:1", 4)]);
+        let rent = class_fixture(2, &[("rent", 2), ("#amount:4", 2)]);
+        let all = ClassStatistics::sum(Uuid::new_v4(), &[coffee.clone(), rent.clone()]);
+        let work = class_fixture(3, &[("coffee", 3), ("#amount:1", 3)]);
+
+        let ranked = NaiveBayes::new(5).rank_independent(&[work.clone()], &all, &tokens(&["coffee", "#amount:1"]));
+
+        assert_eq!(ranked[0].0, *work.id());
+        assert!(ranked[0].1 > 0.5);
+
+        let ranked = NaiveBayes::new(5).rank_independent(&[work], &all, &tokens(&["rent", "#amount:4"]));
+
+        assert!(ranked[0].1 < 0.5);
+    }
+}
@@ -0,0 +1,15 @@
+/// What a suggestion points to, each target is learned separately.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target {
+    Category,
+    Tag,
+}
+
+impl Target {
+    pub fn to_str(&self) -> &str {
+        match self {
+            Target::Category => "category",
+            Target::Tag => "tag",
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use rust_decimal::Decimal;
+
+const AMOUNT_TOKEN_PREFIX: &str = "#amount:";
+
+/// Splits the label into lowercase words and adds a token for the order of magnitude of the amount.
+/// Single characters and bare numbers are left out, every token is kept once.
+pub fn tokenize(label: &str, amount: Decimal) -> Vec<String> {
+    let mut tokens: Vec<String> = vec![];
+
+    for token in label.to_lowercase().split(|c: char| !c.is_alphanumeric()) {
+        if token.chars().count() < 2 || token.chars().all(|c| c.is_ascii_digit()) {
+            continue;
+        }
+
+        if !tokens.iter().any(|known| known == token) {
+            tokens.push(token.to_string());
+        }
+    }
+
+    tokens.push(amount_token(amount));
+
+    tokens
+}
+
+/// Number of digits of the integral part, so 4.50 and 7.20 share a token while 45.00 does not.
+fn amount_token(amount: Decimal) -> String {
+    format!("{}{}", AMOUNT_TOKEN_PREFIX, amount.abs().trunc().to_string().len())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+    use super::*;
+
+    #[test]
+    fn test_tokenize() {
+        let tokens = tokenize("STARBUCKS #1234, Coffee-coffee x2 é", Decimal::from_str("-4.50").unwrap());
+
+        assert_eq!(tokens, vec!["starbucks", "coffee", "x2", "#amount:1"]);
+        assert_eq!(tokenize("", Decimal::from_str("0.99").unwrap()), vec!["#amount:1"]);
+        assert_eq!(tokenize("Rent", Decimal::from_str("1200.00").unwrap()), vec!["rent", "#amount:4"]);
+    }
+}
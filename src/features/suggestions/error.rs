@@ -0,0 +1,12 @@
+use thiserror::Error;
+use crate::features::suggestions::domain::error::DomainError;
+use crate::features::suggestions::infrastructure::error::InfrastructureError;
+
+#[derive(Error, Debug, Clone)]
+pub enum SuggestionError {
+    #[error("Suggestion domain error. {0}")]
+    Domain(DomainError),
+
+    #[error("Suggestion infrastructure error. {0}")]
+    Infrastructure(InfrastructureError),
+}
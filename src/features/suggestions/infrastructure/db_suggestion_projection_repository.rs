@@ -0,0 +1,356 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use async_trait::async_trait;
+use sqlx::{query, query_as, FromRow, Pool, Postgres, Transaction};
+use tokio::sync::Mutex;
+use uuid::Uuid;
+use crate::db::manager::DbManager;
+use crate::features::operations::domain::events::operation_created::OperationCreated;
+use crate::features::operations::domain::events::operation_deleted::OperationDeleted;
+use crate::features::operations::domain::events::operation_updated::OperationUpdated;
+use crate::features::operations::domain::kind::Kind;
+use crate::features::suggestions::application::queries::suggestion_projection_repository::SuggestionProjectionRepository;
+use crate::features::suggestions::domain::class_statistics::ClassStatistics;
+use crate::features::suggestions::domain::target::Target;
+use crate::features::suggestions::domain::tokenizer::tokenize;
+use crate::features::suggestions::error::SuggestionError;
+use crate::features::suggestions::infrastructure::error::InfrastructureError;
+
+#[derive(FromRow)]
+struct ClassRow {
+    target_id: Uuid,
+    operations: i64,
+    tokens: i64,
+}
+
+#[derive(FromRow)]
+struct TokenRow {
+    target_id: Uuid,
+    token: String,
+    count: i64,
+}
+
+#[derive(FromRow)]
+struct VocabularyRow {
+    vocabulary: i64,
+}
+
+/// What was learned from one operation, kept to take it back when the operation changes.
+#[derive(FromRow)]
+struct LearnedRow {
+    user_id: Uuid,
+    category_id: Uuid,
+    tag_ids: Vec<Uuid>,
+    tokens: Vec<String>,
+}
+
+#[derive(Clone)]
+pub struct DbSuggestionProjectionRepository {
+    db_manager: Arc<Mutex<DbManager>>,
+}
+
+impl DbSuggestionProjectionRepository {
+    pub fn new(db_manager: Arc<Mutex<DbManager>>) -> Self {
+        Self {
+            db_manager,
+        }
+    }
+
+    async fn pool(&self) -> Result<Pool<Postgres>, SuggestionError> {
+        let guard = self.db_manager.lock().await;
+
+        guard.pool()
+            .map_err(|e|
+                SuggestionError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to get pool: {}", e)
+                    )
+                )
+            )
+    }
+
+    async fn begin(pool: &Pool<Postgres>) -> Result<Transaction<'static, Postgres>, SuggestionError> {
+        pool.begin()
+            .await
+            .map_err(|e|
+                SuggestionError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to begin transaction: {}", e)
+                    )
+                )
+            )
+    }
+
+    async fn commit(tx: Transaction<'_, Postgres>) -> Result<(), SuggestionError> {
+        tx.commit()
+            .await
+            .map_err(|e|
+                SuggestionError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to commit transaction: {}", e)
+                    )
+                )
+            )
+    }
+
+    /// Removes the operation from the learned ones and returns what was learned from it, if anything.
+    async fn forget(tx: &mut Transaction<'_, Postgres>, operation_id: Uuid) -> Result<Option<LearnedRow>, SuggestionError> {
+        query_as::<_, LearnedRow>("DELETE FROM suggestion_operations WHERE operation_id = $1 RETURNING user_id, category_id, tag_ids, tokens")
+            .bind(operation_id)
+            .fetch_optional(&mut **tx)
+            .await
+            .map_err(|e|
+                SuggestionError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to forget learned operation: {}", e)
+                    )
+                )
+            )
+    }
+
+    /// Adds the learned operation to the counts of its category and tags, or takes it back with a `sign` of -1.
+    /// Classes and tokens no operation counts for anymore are removed.
+    async fn count(tx: &mut Transaction<'_, Postgres>, learned: &LearnedRow, sign: i64) -> Result<(), SuggestionError> {
+        let mut targets = vec![(Target::Category, learned.category_id)];
+        targets.extend(learned.tag_ids.iter().map(|id| (Target::Tag, *id)));
+
+        for (target, target_id) in targets {
+            query("
+                INSERT INTO suggestion_classes (user_id, target, target_id, operations, tokens)
+                VALUES ($1, $2, $3, $4, $5)
+                ON CONFLICT (user_id, target, target_id) DO UPDATE
+                SET operations = suggestion_classes.operations + EXCLUDED.operations, tokens = suggestion_classes.tokens + EXCLUDED.tokens
+            ")
+                .bind(learned.user_id)
+                .bind(target.to_str())
+                .bind(target_id)
+                .bind(sign)
+                .bind(sign * learned.tokens.len() as i64)
+                .execute(&mut **tx)
+                .await
+                .map_err(|e|
+                    SuggestionError::Infrastructure(
+                        InfrastructureError::Repository(
+                            format!("Failed to project suggestion class: {}", e)
+                        )
+                    )
+                )?;
+
+            query("
+                INSERT INTO suggestion_tokens (user_id, target, target_id, token, count)
+                SELECT $1, $2, $3, token, $5 FROM unnest($4::VARCHAR[]) AS token
+                ON CONFLICT (user_id, target, target_id, token) DO UPDATE
+                SET count = suggestion_tokens.count + EXCLUDED.count
+            ")
+                .bind(learned.user_id)
+                .bind(target.to_str())
+                .bind(target_id)
+                .bind(&learned.tokens)
+                .bind(sign)
+                .execute(&mut **tx)
+                .await
+                .map_err(|e|
+                    SuggestionError::Infrastructure(
+                        InfrastructureError::Repository(
+                            format!("Failed to project suggestion tokens: {}", e)
+                        )
+                    )
+                )?;
+
+            if sign < 0 {
+                query("DELETE FROM suggestion_tokens WHERE user_id = $1 AND target = $2 AND target_id = $3 AND count <= 0")
+                    .bind(learned.user_id)
+                    .bind(target.to_str())
+                    .bind(target_id)
+                    .execute(&mut **tx)
+                    .await
+                    .map_err(|e|
+                        SuggestionError::Infrastructure(
+                            InfrastructureError::Repository(
+                                format!("Failed to remove suggestion tokens: {}", e)
+                            )
+                        )
+                    )?;
+
+                query("DELETE FROM suggestion_classes WHERE user_id = $1 AND target = $2 AND target_id = $3 AND operations <= 0")
+                    .bind(learned.user_id)
+                    .bind(target.to_str())
+                    .bind(target_id)
+                    .execute(&mut **tx)
+                    .await
+                    .map_err(|e|
+                        SuggestionError::Infrastructure(
+                            InfrastructureError::Repository(
+                                format!("Failed to remove suggestion class: {}", e)
+                            )
+                        )
+                    )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl SuggestionProjectionRepository for DbSuggestionProjectionRepository {
+    async fn find_classes(&self, user_id: Uuid, target: Target, tokens: &[String]) -> Result<Vec<ClassStatistics>, SuggestionError> {
+        let pool = self.pool().await?;
+
+        let classes = query_as::<_, ClassRow>("SELECT target_id, operations, tokens FROM suggestion_classes WHERE user_id = $1 AND target = $2")
+            .bind(user_id)
+            .bind(target.to_str())
+            .fetch_all(&pool)
+            .await
+            .map_err(|e|
+                SuggestionError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to fetch suggestion classes: {}", e)
+                    )
+                )
+            )?;
+
+        let token_rows = query_as::<_, TokenRow>("SELECT target_id, token, count FROM suggestion_tokens WHERE user_id = $1 AND target = $2 AND token = ANY($3)")
+            .bind(user_id)
+            .bind(target.to_str())
+            .bind(tokens)
+            .fetch_all(&pool)
+            .await
+            .map_err(|e|
+                SuggestionError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to fetch suggestion tokens: {}", e)
+                    )
+                )
+            )?;
+
+        let mut token_counts: HashMap<Uuid, HashMap<String, i64>> = HashMap::new();
+        for row in token_rows {
+            token_counts.entry(row.target_id)
+                .or_default()
+                .insert(row.token, row.count);
+        }
+
+        Ok(
+            classes.into_iter()
+                .map(|row| ClassStatistics::new(
+                    row.target_id,
+                    row.operations,
+                    row.tokens,
+                    token_counts.remove(&row.target_id).unwrap_or_default(),
+                ))
+                .collect()
+        )
+    }
+
+    async fn find_vocabulary(&self, user_id: Uuid) -> Result<i64, SuggestionError> {
+        let pool = self.pool().await?;
+
+        // Tokens of the tags are always tokens of a category as well
+        query_as::<_, VocabularyRow>("SELECT COUNT(DISTINCT token) AS vocabulary FROM suggestion_tokens WHERE user_id = $1 AND target = 'category'")
+            .bind(user_id)
+            .fetch_one(&pool)
+            .await
+            .map(|row| row.vocabulary)
+            .map_err(|e|
+                SuggestionError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to fetch suggestion vocabulary: {}", e)
+                    )
+                )
+            )
+    }
+
+    async fn apply_operation_created(&self, event: &OperationCreated) -> Result<(), SuggestionError> {
+        let payload = event.payload();
+        let learned = LearnedRow {
+            user_id: payload.user_id().value(),
+            category_id: payload.category_id().value(),
+            tag_ids: payload.tag_ids().iter().map(|id| id.value()).collect(),
+            tokens: tokenize(payload.label(), payload.amount_currency().value()),
+        };
+
+        let pool = self.pool().await?;
+        let mut tx = Self::begin(&pool).await?;
+
+        // Counts are added up, so an operation delivered twice must only be learned once
+        let recorded = query("INSERT INTO suggestion_operations (operation_id, user_id, category_id, tag_ids, tokens) VALUES ($1, $2, $3, $4, $5) ON CONFLICT DO NOTHING")
+            .bind(payload.id().value())
+            .bind(learned.user_id)
+            .bind(learned.category_id)
+            .bind(&learned.tag_ids)
+            .bind(&learned.tokens)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e|
+                SuggestionError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to record learned operation: {}", e)
+                    )
+                )
+            )?;
+
+        if recorded.rows_affected() == 0 {
+            return Ok(());
+        }
+
+        Self::count(&mut tx, &learned, 1).await?;
+
+        Self::commit(tx).await
+    }
+
+    async fn apply_operation_updated(&self, event: &OperationUpdated) -> Result<(), SuggestionError> {
+        let payload = event.payload();
+
+        let pool = self.pool().await?;
+        let mut tx = Self::begin(&pool).await?;
+
+        let previous = Self::forget(&mut tx, payload.id().value()).await?;
+
+        if let Some(previous) = &previous {
+            Self::count(&mut tx, previous, -1).await?;
+        }
+
+        // An operation that became a transfer is no longer learned from
+        if *payload.kind() != Kind::Transfer {
+            let learned = LearnedRow {
+                user_id: payload.user_id().value(),
+                category_id: payload.category_id().value(),
+                tag_ids: payload.tag_ids().iter().map(|id| id.value()).collect(),
+                tokens: tokenize(payload.label(), payload.amount_currency().value()),
+            };
+
+            query("INSERT INTO suggestion_operations (operation_id, user_id, category_id, tag_ids, tokens) VALUES ($1, $2, $3, $4, $5)")
+                .bind(payload.id().value())
+                .bind(learned.user_id)
+                .bind(learned.category_id)
+                .bind(&learned.tag_ids)
+                .bind(&learned.tokens)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e|
+                    SuggestionError::Infrastructure(
+                        InfrastructureError::Repository(
+                            format!("Failed to record learned operation: {}", e)
+                        )
+                    )
+                )?;
+
+            Self::count(&mut tx, &learned, 1).await?;
+        }
+
+        Self::commit(tx).await
+    }
+
+    async fn apply_operation_deleted(&self, event: &OperationDeleted) -> Result<(), SuggestionError> {
+        let pool = self.pool().await?;
+        let mut tx = Self::begin(&pool).await?;
+
+        // A deletion delivered twice finds nothing left to forget
+        if let Some(previous) = Self::forget(&mut tx, event.payload().id().value()).await? {
+            Self::count(&mut tx, &previous, -1).await?;
+        }
+
+        Self::commit(tx).await
+    }
+}
@@ -0,0 +1,7 @@
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone)]
+pub enum InfrastructureError {
+    #[error("Suggestion repository error. {0}")]
+    Repository(String),
+}
@@ -0,0 +1 @@
+pub mod suggestion_projection_listener;
\ No newline at end of file
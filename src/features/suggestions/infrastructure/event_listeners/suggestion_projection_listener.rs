@@ -0,0 +1,70 @@
+use async_trait::async_trait;
+use crate::events::error::EventError;
+use crate::events::event::Event;
+use crate::events::event_listener::EventListener;
+use crate::features::operations::domain::events::operation_event::OperationEvent;
+use crate::features::operations::domain::kind::Kind;
+use crate::features::suggestions::application::queries::suggestion_projection_repository::SuggestionProjectionRepository;
+use crate::support::error::FeatureError;
+
+/// Learns the label, amount, category and tags of every operation, transfers are skipped.
+/// Updates and deletions move what was learned from the previous state out of its category and tags.
+/// One instance is registered per operation event name.
+pub struct SuggestionProjectionListener<R>
+    where
+        R: SuggestionProjectionRepository + Send + Sync + 'static,
+{
+    rep: R,
+    event_name: &'static str,
+}
+
+#[async_trait]
+impl<R> EventListener for SuggestionProjectionListener<R>
+    where
+        R: SuggestionProjectionRepository + Send + Sync + 'static,
+{
+    async fn on_event(&mut self, event: Event) -> Result<Vec<Event>, EventError> {
+        let res = match self.parse_event(event)? {
+            OperationEvent::OperationCreated(event) if *event.payload().kind() == Kind::Transfer => Ok(()),
+            OperationEvent::OperationCreated(event) => self.rep.apply_operation_created(&event).await,
+            OperationEvent::OperationUpdated(event) => self.rep.apply_operation_updated(&event).await,
+            OperationEvent::OperationDeleted(event) => self.rep.apply_operation_deleted(&event).await,
+            _ => Ok(()),
+        };
+
+        res.map_err(|e|
+            EventError::Feature(
+                FeatureError::Suggestion(e)
+            )
+        )?;
+
+        Ok(vec![])
+    }
+
+    fn event_name(&self) -> &str {
+        self.event_name
+    }
+}
+
+impl<R> SuggestionProjectionListener<R>
+    where
+        R: SuggestionProjectionRepository + Send + Sync + 'static,
+{
+    pub fn new(rep: R, event_name: &'static str) -> Self {
+        Self {
+            rep,
+            event_name,
+        }
+    }
+
+    pub fn parse_event(&self, event: Event) -> Result<OperationEvent, EventError> {
+        match event {
+            Event::OperationEvent(operation_event) => Ok(operation_event),
+            _ => Err(
+                EventError::Parsing(
+                    format!("Invalid event type. Expected OperationEvent, got {:?}", event)
+                )
+            )
+        }
+    }
+}
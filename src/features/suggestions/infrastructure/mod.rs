@@ -0,0 +1,3 @@
+pub mod db_suggestion_projection_repository;
+pub mod error;
+pub mod event_listeners;
\ No newline at end of file
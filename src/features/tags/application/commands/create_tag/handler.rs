@@ -1,11 +1,9 @@
-use crate::features::tags::domain::events::tag_created::TAG_CREATED_NAME;
 use async_trait::async_trait;
 use crate::events::event::Event;
 use crate::features::tags::application::commands::create_tag::command::CreateTagCommand;
 use crate::features::tags::domain::error::DomainError;
 use crate::features::tags::domain::tag::Tag;
 use crate::features::tags::domain::tag_repository::TagRepository;
-use crate::features::tags::domain::events::tag_deleted::TAG_DELETED_NAME;
 use crate::features::tags::domain::events::tag_event::TagEvent;
 use crate::features::tags::error::TagError;
 use crate::support::command_bus::{Command, CommandHandler};
@@ -36,13 +34,13 @@ impl<R> CommandHandler<CreateTagCommand> for CreateTagCommandHandler<R>
         R: TagRepository + Send + Sync,
 {
     async fn handle(&mut self, command: CreateTagCommand) -> Result<Vec<Event>, FeatureError> {
-        let exists = self.tag_repository.exists(TAG_CREATED_NAME, TAG_DELETED_NAME, command.tag_name())
+        let existing_id = self.tag_repository.find_id_by_name(*command.user_id(), command.tag_name())
             .await
             .map_err(|e|
                 FeatureError::Tag(e)
             )?;
 
-        if exists {
+        if existing_id.is_some() {
             return Err(
                 FeatureError::Tag(
                     TagError::Domain(
@@ -59,15 +57,11 @@ impl<R> CommandHandler<CreateTagCommand> for CreateTagCommandHandler<R>
                 )
             )?;
 
-        match event.clone() {
-            TagEvent::TagCreated(tag_created) => {
-                self.tag_repository.persist_tag_created_event(&tag_created)
-                    .await
-                    .map_err(|e|
-                        FeatureError::Tag(e)
-                    )?;
-            }
-        };
+        if let TagEvent::TagCreated(tag_created) = &event {
+            self.tag_repository.persist_tag_created_event(tag_created)
+                .await
+                .map_err(FeatureError::Tag)?;
+        }
 
         Ok(
             vec![Event::TagEvent(event)]
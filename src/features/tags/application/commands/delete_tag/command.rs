@@ -0,0 +1,33 @@
+use uuid::Uuid;
+use crate::support::command_bus::Command;
+
+const NAME : &str = "delete_tag";
+
+#[derive(Debug, Clone)]
+pub struct DeleteTagCommand {
+    tag_id: Uuid,
+    user_id: Uuid,
+}
+
+impl DeleteTagCommand {
+    pub fn new(tag_id: Uuid, user_id: Uuid) -> Self {
+        Self {
+            tag_id,
+            user_id,
+        }
+    }
+
+    pub fn tag_id(&self) -> &Uuid {
+        &self.tag_id
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+}
+
+impl Command for DeleteTagCommand {
+    fn name() -> &'static str {
+        NAME
+    }
+}
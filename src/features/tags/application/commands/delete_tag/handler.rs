@@ -0,0 +1,100 @@
+use async_trait::async_trait;
+use crate::events::event::Event;
+use crate::features::tags::application::commands::delete_tag::command::DeleteTagCommand;
+use crate::features::tags::domain::error::DomainError;
+use crate::features::tags::domain::tag_repository::TagRepository;
+use crate::features::tags::error::TagError;
+use crate::support::command_bus::CommandHandler;
+use crate::support::error::FeatureError;
+
+pub struct DeleteTagCommandHandler<R>
+    where
+        R: TagRepository + Send + Sync,
+{
+    tag_repository: R,
+}
+
+impl<R> DeleteTagCommandHandler<R>
+    where
+        R: TagRepository + Send + Sync,
+{
+    pub fn new(tag_repository: R) -> Self {
+        Self {
+            tag_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> CommandHandler<DeleteTagCommand> for DeleteTagCommandHandler<R>
+    where
+        R: TagRepository + Send + Sync,
+{
+    async fn handle(&mut self, command: DeleteTagCommand) -> Result<Vec<Event>, FeatureError> {
+        let tag = self.tag_repository.load(*command.tag_id())
+            .await
+            .map_err(FeatureError::Tag)?
+            .ok_or(
+                FeatureError::Tag(
+                    TagError::Domain(
+                        DomainError::TagNotFound(command.tag_id().to_string())
+                    )
+                )
+            )?;
+
+        let event = tag.aggregate().handle_deletion(command)
+            .map_err(|e|
+                FeatureError::Tag(
+                    TagError::Domain(e)
+                )
+            )?;
+
+        self.tag_repository.append(tag.aggregate().id().value(), tag.version(), std::slice::from_ref(&event))
+            .await
+            .map_err(FeatureError::Tag)?;
+
+        Ok(
+            vec![Event::TagEvent(event)]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+    use crate::features::tags::application::commands::create_tag::command::CreateTagCommand;
+    use crate::features::tags::domain::events::tag_event::TagEvent;
+    use crate::features::tags::domain::tag::Tag;
+    use crate::features::tags::domain::tag_repository::MockTagRepository;
+    use crate::support::event_store::{Aggregate, Versioned};
+    use super::*;
+
+    #[tokio::test]
+    async fn test_delete_tag_command_handler_success() {
+        let tag = tag_fixture(Uuid::new_v4());
+        let command = DeleteTagCommand::new(tag.aggregate().id().value(), tag.aggregate().user_id().value());
+
+        let events = DeleteTagCommandHandler::new(MockTagRepository::with_tags(vec![tag])).handle(command).await.unwrap();
+
+        match &events[0] {
+            Event::TagEvent(TagEvent::TagDeleted(tag_deleted)) => assert!(tag_deleted.payload().merged_into().is_none()),
+            _ => panic!("Expected TagDeleted event"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_delete_tag_command_handler_foreign_tag() {
+        let tag = tag_fixture(Uuid::new_v4());
+        let command = DeleteTagCommand::new(tag.aggregate().id().value(), Uuid::new_v4());
+
+        let result = DeleteTagCommandHandler::new(MockTagRepository::with_tags(vec![tag])).handle(command).await;
+
+        assert!(matches!(result, Err(FeatureError::Tag(TagError::Domain(DomainError::AccessDenied)))));
+    }
+
+    fn tag_fixture(user_id: Uuid) -> Versioned<Tag> {
+        let event = Tag::handle_creation(CreateTagCommand::new(user_id, "food".to_string())).unwrap();
+
+        Versioned::new(Tag::apply(None, &event).unwrap(), 1)
+    }
+}
@@ -0,0 +1,39 @@
+use uuid::Uuid;
+use crate::support::command_bus::Command;
+
+const NAME : &str = "merge_tags";
+
+#[derive(Debug, Clone)]
+pub struct MergeTagsCommand {
+    user_id: Uuid,
+    source_tag_ids: Vec<Uuid>,
+    target_tag_id: Uuid,
+}
+
+impl MergeTagsCommand {
+    pub fn new(user_id: Uuid, source_tag_ids: Vec<Uuid>, target_tag_id: Uuid) -> Self {
+        Self {
+            user_id,
+            source_tag_ids,
+            target_tag_id,
+        }
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    pub fn source_tag_ids(&self) -> &[Uuid] {
+        &self.source_tag_ids
+    }
+
+    pub fn target_tag_id(&self) -> &Uuid {
+        &self.target_tag_id
+    }
+}
+
+impl Command for MergeTagsCommand {
+    fn name() -> &'static str {
+        NAME
+    }
+}
@@ -0,0 +1,142 @@
+use async_trait::async_trait;
+use crate::events::event::Event;
+use crate::features::tags::application::commands::merge_tags::command::MergeTagsCommand;
+use crate::features::tags::domain::error::DomainError;
+use crate::features::tags::domain::tag_repository::TagRepository;
+use crate::features::tags::error::TagError;
+use crate::support::command_bus::CommandHandler;
+use crate::support::error::FeatureError;
+
+/// Deletes the source tags in favour of the target one. Operations are rewritten by the listener
+/// of the emitted `TagDeleted` events.
+pub struct MergeTagsCommandHandler<R>
+    where
+        R: TagRepository + Send + Sync,
+{
+    tag_repository: R,
+}
+
+impl<R> MergeTagsCommandHandler<R>
+    where
+        R: TagRepository + Send + Sync,
+{
+    pub fn new(tag_repository: R) -> Self {
+        Self {
+            tag_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> CommandHandler<MergeTagsCommand> for MergeTagsCommandHandler<R>
+    where
+        R: TagRepository + Send + Sync,
+{
+    async fn handle(&mut self, command: MergeTagsCommand) -> Result<Vec<Event>, FeatureError> {
+        let target = self.tag_repository.load(*command.target_tag_id())
+            .await
+            .map_err(FeatureError::Tag)?
+            .ok_or(
+                FeatureError::Tag(
+                    TagError::Domain(
+                        DomainError::TagNotFound(command.target_tag_id().to_string())
+                    )
+                )
+            )?;
+
+        let mut source_tag_ids = command.source_tag_ids().to_vec();
+        source_tag_ids.sort();
+        source_tag_ids.dedup();
+
+        // Every source is checked before anything is written, so an invalid one leaves all tags untouched
+        let mut merges = vec![];
+        for source_tag_id in source_tag_ids {
+            let source = self.tag_repository.load(source_tag_id)
+                .await
+                .map_err(FeatureError::Tag)?
+                .ok_or(
+                    FeatureError::Tag(
+                        TagError::Domain(
+                            DomainError::TagNotFound(source_tag_id.to_string())
+                        )
+                    )
+                )?;
+
+            let event = source.aggregate().handle_merge_into(command.user_id(), target.aggregate())
+                .map_err(|e|
+                    FeatureError::Tag(
+                        TagError::Domain(e)
+                    )
+                )?;
+
+            merges.push((source, event));
+        }
+
+        let mut events = vec![];
+        for (source, event) in merges {
+            self.tag_repository.append(source.aggregate().id().value(), source.version(), std::slice::from_ref(&event))
+                .await
+                .map_err(FeatureError::Tag)?;
+
+            events.push(Event::TagEvent(event));
+        }
+
+        Ok(events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+    use crate::features::tags::application::commands::create_tag::command::CreateTagCommand;
+    use crate::features::tags::domain::tag::Tag;
+    use crate::features::tags::domain::tag_repository::MockTagRepository;
+    use crate::support::event_store::{Aggregate, Versioned};
+    use super::*;
+
+    #[tokio::test]
+    async fn test_merge_tags_command_handler_success() {
+        let user_id = Uuid::new_v4();
+        let first = tag_fixture(user_id);
+        let second = tag_fixture(user_id);
+        let target = tag_fixture(user_id);
+        let command = MergeTagsCommand::new(
+            user_id,
+            vec![first.aggregate().id().value(), second.aggregate().id().value(), first.aggregate().id().value()],
+            target.aggregate().id().value(),
+        );
+
+        let rep = MockTagRepository::with_tags(vec![first, second, target]);
+        let events = MergeTagsCommandHandler::new(rep).handle(command).await.unwrap();
+
+        assert_eq!(events.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_merge_tags_command_handler_into_source() {
+        let user_id = Uuid::new_v4();
+        let source = tag_fixture(user_id);
+        let command = MergeTagsCommand::new(user_id, vec![source.aggregate().id().value()], source.aggregate().id().value());
+
+        let result = MergeTagsCommandHandler::new(MockTagRepository::with_tags(vec![source])).handle(command).await;
+
+        assert!(matches!(result, Err(FeatureError::Tag(TagError::Domain(DomainError::InvalidMergeTarget(_))))));
+    }
+
+    #[tokio::test]
+    async fn test_merge_tags_command_handler_unknown_source() {
+        let user_id = Uuid::new_v4();
+        let target = tag_fixture(user_id);
+        let command = MergeTagsCommand::new(user_id, vec![Uuid::new_v4()], target.aggregate().id().value());
+
+        let result = MergeTagsCommandHandler::new(MockTagRepository::with_tags(vec![target])).handle(command).await;
+
+        assert!(matches!(result, Err(FeatureError::Tag(TagError::Domain(DomainError::TagNotFound(_))))));
+    }
+
+    fn tag_fixture(user_id: Uuid) -> Versioned<Tag> {
+        let event = Tag::handle_creation(CreateTagCommand::new(user_id, "food".to_string())).unwrap();
+
+        Versioned::new(Tag::apply(None, &event).unwrap(), 1)
+    }
+}
@@ -1 +1,5 @@
-pub mod create_tag;
\ No newline at end of file
+pub mod create_tag;
+pub mod delete_tag;
+pub mod merge_tags;
+pub mod rename_tag;
+pub mod resolve_tag;
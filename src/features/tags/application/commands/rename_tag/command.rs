@@ -0,0 +1,39 @@
+use uuid::Uuid;
+use crate::support::command_bus::Command;
+
+const NAME : &str = "rename_tag";
+
+#[derive(Debug, Clone)]
+pub struct RenameTagCommand {
+    tag_id: Uuid,
+    user_id: Uuid,
+    tag_name: String,
+}
+
+impl RenameTagCommand {
+    pub fn new(tag_id: Uuid, user_id: Uuid, tag_name: String) -> Self {
+        Self {
+            tag_id,
+            user_id,
+            tag_name,
+        }
+    }
+
+    pub fn tag_id(&self) -> &Uuid {
+        &self.tag_id
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    pub fn tag_name(&self) -> &str {
+        &self.tag_name
+    }
+}
+
+impl Command for RenameTagCommand {
+    fn name() -> &'static str {
+        NAME
+    }
+}
@@ -0,0 +1,125 @@
+use async_trait::async_trait;
+use crate::events::event::Event;
+use crate::features::tags::application::commands::rename_tag::command::RenameTagCommand;
+use crate::features::tags::domain::error::DomainError;
+use crate::features::tags::domain::tag_repository::TagRepository;
+use crate::features::tags::error::TagError;
+use crate::support::command_bus::CommandHandler;
+use crate::support::error::FeatureError;
+
+pub struct RenameTagCommandHandler<R>
+    where
+        R: TagRepository + Send + Sync,
+{
+    tag_repository: R,
+}
+
+impl<R> RenameTagCommandHandler<R>
+    where
+        R: TagRepository + Send + Sync,
+{
+    pub fn new(tag_repository: R) -> Self {
+        Self {
+            tag_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> CommandHandler<RenameTagCommand> for RenameTagCommandHandler<R>
+    where
+        R: TagRepository + Send + Sync,
+{
+    async fn handle(&mut self, command: RenameTagCommand) -> Result<Vec<Event>, FeatureError> {
+        let tag = self.tag_repository.load(*command.tag_id())
+            .await
+            .map_err(FeatureError::Tag)?
+            .ok_or(
+                FeatureError::Tag(
+                    TagError::Domain(
+                        DomainError::TagNotFound(command.tag_id().to_string())
+                    )
+                )
+            )?;
+
+        let existing_id = self.tag_repository.find_id_by_name(*command.user_id(), command.tag_name())
+            .await
+            .map_err(FeatureError::Tag)?;
+
+        // Changing only the case or spacing of the name keeps the tag itself
+        if existing_id.is_some_and(|existing_id| existing_id != tag.aggregate().id().value()) {
+            return Err(
+                FeatureError::Tag(
+                    TagError::Domain(
+                        DomainError::TagAlreadyExists(command.tag_name().to_string())
+                    )
+                )
+            );
+        }
+
+        let event = tag.aggregate().handle_rename(command)
+            .map_err(|e|
+                FeatureError::Tag(
+                    TagError::Domain(e)
+                )
+            )?;
+
+        self.tag_repository.append(tag.aggregate().id().value(), tag.version(), std::slice::from_ref(&event))
+            .await
+            .map_err(FeatureError::Tag)?;
+
+        Ok(
+            vec![Event::TagEvent(event)]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+    use crate::features::tags::application::commands::create_tag::command::CreateTagCommand;
+    use crate::features::tags::domain::events::tag_event::TagEvent;
+    use crate::features::tags::domain::tag::Tag;
+    use crate::features::tags::domain::tag_repository::MockTagRepository;
+    use crate::support::event_store::{Aggregate, Versioned};
+    use super::*;
+
+    #[tokio::test]
+    async fn test_rename_tag_command_handler_success() {
+        let tag = tag_fixture();
+        let command = RenameTagCommand::new(tag.aggregate().id().value(), tag.aggregate().user_id().value(), "groceries".to_string());
+
+        let events = RenameTagCommandHandler::new(MockTagRepository::with_tags(vec![tag])).handle(command).await.unwrap();
+
+        assert!(matches!(events[0], Event::TagEvent(TagEvent::TagRenamed(_))));
+    }
+
+    #[tokio::test]
+    async fn test_rename_tag_command_handler_unknown_tag() {
+        let command = RenameTagCommand::new(Uuid::new_v4(), Uuid::new_v4(), "groceries".to_string());
+
+        let result = RenameTagCommandHandler::new(MockTagRepository::new(false, false)).handle(command).await;
+
+        assert!(matches!(result, Err(FeatureError::Tag(TagError::Domain(DomainError::TagNotFound(_))))));
+    }
+
+    #[tokio::test]
+    async fn test_rename_tag_command_handler_existing_name() {
+        let tag = tag_fixture();
+        let other = Versioned::new(
+            Tag::apply(None, &Tag::handle_creation(CreateTagCommand::new(tag.aggregate().user_id().value(), "Groceries".to_string())).unwrap()).unwrap(),
+            1,
+        );
+        let command = RenameTagCommand::new(tag.aggregate().id().value(), tag.aggregate().user_id().value(), " groceries".to_string());
+
+        let result = RenameTagCommandHandler::new(MockTagRepository::with_tags(vec![tag, other])).handle(command).await;
+
+        assert!(matches!(result, Err(FeatureError::Tag(TagError::Domain(DomainError::TagAlreadyExists(_))))));
+    }
+
+    fn tag_fixture() -> Versioned<Tag> {
+        let event = Tag::handle_creation(CreateTagCommand::new(Uuid::new_v4(), "food".to_string())).unwrap();
+
+        Versioned::new(Tag::apply(None, &event).unwrap(), 1)
+    }
+}
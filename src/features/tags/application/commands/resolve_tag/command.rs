@@ -0,0 +1,47 @@
+use uuid::Uuid;
+use crate::support::command_bus::Command;
+
+const NAME : &str = "resolve_tag";
+
+/// Makes sure the tag an operation was created with exists, either by reusing a tag
+/// with the same name or by creating one under `tag_id`.
+#[derive(Debug, Clone)]
+pub struct ResolveTagCommand {
+    operation_id: Uuid,
+    user_id: Uuid,
+    tag_id: Uuid,
+    tag_name: String,
+}
+
+impl ResolveTagCommand {
+    pub fn new(operation_id: Uuid, user_id: Uuid, tag_id: Uuid, tag_name: String) -> Self {
+        Self {
+            operation_id,
+            user_id,
+            tag_id,
+            tag_name,
+        }
+    }
+
+    pub fn operation_id(&self) -> &Uuid {
+        &self.operation_id
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    pub fn tag_id(&self) -> &Uuid {
+        &self.tag_id
+    }
+
+    pub fn tag_name(&self) -> &str {
+        &self.tag_name
+    }
+}
+
+impl Command for ResolveTagCommand {
+    fn name() -> &'static str {
+        NAME
+    }
+}
@@ -0,0 +1,130 @@
+use async_trait::async_trait;
+use crate::events::event::Event;
+use crate::features::tags::application::commands::resolve_tag::command::ResolveTagCommand;
+use crate::features::tags::domain::error::DomainError;
+use crate::features::tags::domain::events::tag_event::TagEvent;
+use crate::features::tags::domain::tag::Tag;
+use crate::features::tags::domain::tag_repository::TagRepository;
+use crate::features::tags::error::TagError;
+use crate::support::command_bus::CommandHandler;
+use crate::support::error::FeatureError;
+
+pub struct ResolveTagCommandHandler<R>
+    where
+        R: TagRepository + Send + Sync,
+{
+    tag_repository: R,
+}
+
+impl<R> ResolveTagCommandHandler<R>
+    where
+        R: TagRepository + Send + Sync,
+{
+    pub fn new(tag_repository: R) -> Self {
+        Self {
+            tag_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> CommandHandler<ResolveTagCommand> for ResolveTagCommandHandler<R>
+    where
+        R: TagRepository + Send + Sync,
+{
+    async fn handle(&mut self, command: ResolveTagCommand) -> Result<Vec<Event>, FeatureError> {
+        let existing_id = self.tag_repository.find_id_by_name(*command.user_id(), command.tag_name())
+            .await
+            .map_err(FeatureError::Tag)?;
+
+        if let Some(existing_id) = existing_id {
+            let tag = self.tag_repository.load(existing_id)
+                .await
+                .map_err(FeatureError::Tag)?
+                .ok_or(
+                    FeatureError::Tag(
+                        TagError::Domain(
+                            DomainError::TagNotFound(existing_id.to_string())
+                        )
+                    )
+                )?;
+
+            let event = tag.aggregate().handle_reuse(command)
+                .map_err(|e|
+                    FeatureError::Tag(
+                        TagError::Domain(e)
+                    )
+                )?;
+
+            return Ok(
+                vec![Event::TagEvent(event)]
+            );
+        }
+
+        let event = Tag::handle_requested_creation(command)
+            .map_err(|e|
+                FeatureError::Tag(
+                    TagError::Domain(e)
+                )
+            )?;
+
+        if let TagEvent::TagCreated(tag_created) = &event {
+            self.tag_repository.persist_tag_created_event(tag_created)
+                .await
+                .map_err(FeatureError::Tag)?;
+        }
+
+        Ok(
+            vec![Event::TagEvent(event)]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+    use crate::features::tags::application::commands::create_tag::command::CreateTagCommand;
+    use crate::features::tags::domain::tag_repository::MockTagRepository;
+    use crate::support::event_store::{Aggregate, Versioned};
+    use super::*;
+
+    #[tokio::test]
+    async fn test_resolve_tag_command_handler_reuses_existing_tag() {
+        let tag = tag_fixture();
+        let tag_id = tag.aggregate().id().value();
+        let command = ResolveTagCommand::new(Uuid::new_v4(), tag.aggregate().user_id().value(), Uuid::new_v4(), "Groceries ".to_string());
+        let requested_id = *command.tag_id();
+
+        let events = ResolveTagCommandHandler::new(MockTagRepository::with_tags(vec![tag])).handle(command).await.unwrap();
+
+        match &events[0] {
+            Event::TagEvent(TagEvent::TagReused(tag_reused)) => {
+                assert_eq!(tag_reused.payload().id().value(), tag_id);
+                assert_eq!(tag_reused.payload().requested_tag_id().value(), requested_id);
+            }
+            event => panic!("Unexpected event {:?}", event),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resolve_tag_command_handler_ignores_tags_of_other_users() {
+        let tag = tag_fixture();
+        let command = ResolveTagCommand::new(Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4(), "groceries".to_string());
+        let requested_id = *command.tag_id();
+
+        let events = ResolveTagCommandHandler::new(MockTagRepository::with_tags(vec![tag])).handle(command).await.unwrap();
+
+        match &events[0] {
+            Event::TagEvent(TagEvent::TagCreated(tag_created)) => {
+                assert_eq!(tag_created.payload().id().value(), requested_id);
+            }
+            event => panic!("Unexpected event {:?}", event),
+        }
+    }
+
+    fn tag_fixture() -> Versioned<Tag> {
+        let event = Tag::handle_creation(CreateTagCommand::new(Uuid::new_v4(), "groceries".to_string())).unwrap();
+
+        Versioned::new(Tag::apply(None, &event).unwrap(), 1)
+    }
+}
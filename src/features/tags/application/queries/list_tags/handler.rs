@@ -0,0 +1,70 @@
+use async_trait::async_trait;
+use crate::features::tags::application::queries::list_tags::query::ListTagsQuery;
+use crate::features::tags::application::queries::tag_projection_repository::TagProjectionRepository;
+use crate::features::tags::application::queries::tag_view::TagView;
+use crate::support::error::FeatureError;
+use crate::support::query_bus::QueryHandler;
+
+pub struct ListTagsQueryHandler<R>
+    where
+        R: TagProjectionRepository + Send + Sync,
+{
+    rep: R,
+}
+
+impl<R> ListTagsQueryHandler<R>
+    where
+        R: TagProjectionRepository + Send + Sync,
+{
+    pub fn new(rep: R) -> Self {
+        Self {
+            rep,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> QueryHandler<ListTagsQuery> for ListTagsQueryHandler<R>
+    where
+        R: TagProjectionRepository + Send + Sync,
+{
+    type Output = Vec<TagView>;
+
+    async fn handle(&self, query: ListTagsQuery) -> Result<Vec<TagView>, FeatureError> {
+        self.rep.find(*query.user_id())
+            .await
+            .map_err(FeatureError::Tag)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use futures_util::FutureExt;
+    use mockall::predicate::eq;
+    use uuid::Uuid;
+    use crate::features::tags::application::queries::tag_projection_repository::MockTagProjectionRepository;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle_returns_user_tags() {
+        let user_id = Uuid::new_v4();
+
+        let mut rep = MockTagProjectionRepository::new();
+        rep.expect_find()
+            .with(eq(user_id))
+            .times(1)
+            .returning(|user_id| {
+                let view = TagView::new(Uuid::new_v4(), user_id, "food".to_string(), Utc::now());
+                async move { Ok(vec![view]) }.boxed()
+            });
+
+        let tags = ListTagsQueryHandler::new(rep)
+            .handle(ListTagsQuery::new(user_id))
+            .await
+            .unwrap();
+
+        assert_eq!(tags.len(), 1);
+        assert_eq!(tags[0].name(), "food");
+    }
+}
@@ -0,0 +1,27 @@
+use uuid::Uuid;
+use crate::support::query_bus::Query;
+
+const NAME: &str = "list_tags";
+
+#[derive(Debug, Clone)]
+pub struct ListTagsQuery {
+    user_id: Uuid,
+}
+
+impl ListTagsQuery {
+    pub fn new(user_id: Uuid) -> Self {
+        Self {
+            user_id,
+        }
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+}
+
+impl Query for ListTagsQuery {
+    fn name() -> &'static str {
+        NAME
+    }
+}
@@ -0,0 +1,3 @@
+pub mod list_tags;
+pub mod tag_projection_repository;
+pub mod tag_view;
\ No newline at end of file
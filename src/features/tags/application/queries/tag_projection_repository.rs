@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use mockall::automock;
+use uuid::Uuid;
+use crate::features::tags::application::queries::tag_view::TagView;
+use crate::features::tags::domain::events::tag_created::TagCreated;
+use crate::features::tags::domain::events::tag_deleted::TagDeleted;
+use crate::features::tags::domain::events::tag_renamed::TagRenamed;
+use crate::features::tags::error::TagError;
+
+#[async_trait]
+#[automock]
+pub trait TagProjectionRepository {
+    async fn find(&self, user_id: Uuid) -> Result<Vec<TagView>, TagError>;
+
+    async fn apply_tag_created(&self, event: &TagCreated) -> Result<(), TagError>;
+
+    async fn apply_tag_renamed(&self, event: &TagRenamed) -> Result<(), TagError>;
+
+    async fn apply_tag_deleted(&self, event: &TagDeleted) -> Result<(), TagError>;
+}
@@ -0,0 +1,40 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Read model row of the `tags` projection.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TagView {
+    id: Uuid,
+    user_id: Uuid,
+    name: String,
+    created_at: DateTime<Utc>,
+}
+
+impl TagView {
+    pub fn new(id: Uuid, user_id: Uuid, name: String, created_at: DateTime<Utc>) -> Self {
+        Self {
+            id,
+            user_id,
+            name,
+            created_at,
+        }
+    }
+
+    pub fn id(&self) -> &Uuid {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Uuid {
+        &self.user_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+}
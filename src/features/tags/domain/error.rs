@@ -4,4 +4,13 @@ use thiserror::Error;
 pub enum DomainError {
     #[error("Tag {0} already exists")]
     TagAlreadyExists(String),
+
+    #[error("Tag {0} not found")]
+    TagNotFound(String),
+
+    #[error("Tag {0} can not be merged into itself")]
+    InvalidMergeTarget(String),
+
+    #[error("Tag belongs to another user")]
+    AccessDenied,
 }
\ No newline at end of file
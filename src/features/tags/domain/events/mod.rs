@@ -1,3 +1,5 @@
 pub mod tag_event;
 pub mod tag_created;
 pub mod tag_deleted;
+pub mod tag_renamed;
+pub mod tag_reused;
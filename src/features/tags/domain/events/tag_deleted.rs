@@ -7,4 +7,53 @@ pub const TAG_DELETED_NAME: &str = "tag_deleted";
 pub struct TagDeleted {
     id: Id,
     name: String,
-}
\ No newline at end of file
+    payload: TagDeletedPayload,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TagDeletedPayload {
+    id: Id,
+    user_id: Id,
+    merged_into: Option<Id>,
+}
+
+impl TagDeleted {
+    pub fn new(id: Id, tag_id: Id, user_id: Id, merged_into: Option<Id>) -> Self {
+        Self {
+            id,
+            name: TAG_DELETED_NAME.to_string(),
+            payload: TagDeletedPayload {
+                id: tag_id,
+                user_id,
+                merged_into,
+            }
+        }
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn payload(&self) -> &TagDeletedPayload {
+        &self.payload
+    }
+}
+
+impl TagDeletedPayload {
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Id {
+        &self.user_id
+    }
+
+    /// Tag that replaces the deleted one in operations, `None` when the tag is simply removed from them.
+    pub fn merged_into(&self) -> &Option<Id> {
+        &self.merged_into
+    }
+}
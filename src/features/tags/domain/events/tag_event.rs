@@ -1,15 +1,48 @@
 use serde::{Deserialize, Serialize};
-use crate::features::tags::domain::events::tag_created::TagCreated;
+use crate::features::tags::domain::events::tag_created::{TAG_CREATED_NAME, TagCreated};
+use crate::features::tags::domain::events::tag_deleted::{TAG_DELETED_NAME, TagDeleted};
+use crate::features::tags::domain::events::tag_renamed::{TAG_RENAMED_NAME, TagRenamed};
+use crate::features::tags::domain::events::tag_reused::{TAG_REUSED_NAME, TagReused};
+use crate::support::event_store::{decode_event, EventStoreError, NewEvent, StorableEvent, StoredEvent};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum TagEvent {
     TagCreated(TagCreated),
+    TagRenamed(TagRenamed),
+    TagDeleted(TagDeleted),
+    TagReused(TagReused),
 }
 
 impl TagEvent {
     pub fn name(&self) -> &str {
         match self {
-            Self::TagCreated(event) => event.name()
+            Self::TagCreated(event) => event.name(),
+            Self::TagRenamed(event) => event.name(),
+            Self::TagDeleted(event) => event.name(),
+            Self::TagReused(event) => event.name(),
         }
     }
-}
\ No newline at end of file
+}
+
+impl StorableEvent for TagEvent {
+    fn from_stored(stored: &StoredEvent) -> Result<Self, EventStoreError> {
+        match stored.name() {
+            TAG_CREATED_NAME => Ok(Self::TagCreated(decode_event(stored)?)),
+            TAG_RENAMED_NAME => Ok(Self::TagRenamed(decode_event(stored)?)),
+            TAG_DELETED_NAME => Ok(Self::TagDeleted(decode_event(stored)?)),
+            TAG_REUSED_NAME => Ok(Self::TagReused(decode_event(stored)?)),
+            name => Err(
+                EventStoreError::Serialization(format!("Unknown tag event {}", name))
+            ),
+        }
+    }
+
+    fn to_new(&self) -> Result<NewEvent, EventStoreError> {
+        match self {
+            Self::TagCreated(event) => NewEvent::from_event(event),
+            Self::TagRenamed(event) => NewEvent::from_event(event),
+            Self::TagDeleted(event) => NewEvent::from_event(event),
+            Self::TagReused(event) => NewEvent::from_event(event),
+        }
+    }
+}
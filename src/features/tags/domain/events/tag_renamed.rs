@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+use crate::support::id::Id;
+
+pub const TAG_RENAMED_NAME: &str = "tag_renamed";
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TagRenamed {
+    id: Id,
+    name: String,
+    payload: TagRenamedPayload,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TagRenamedPayload {
+    id: Id,
+    user_id: Id,
+    name: String,
+}
+
+impl TagRenamed {
+    pub fn new(id: Id, tag_id: Id, user_id: Id, tag_name: String) -> Self {
+        Self {
+            id,
+            name: TAG_RENAMED_NAME.to_string(),
+            payload: TagRenamedPayload {
+                id: tag_id,
+                user_id,
+                name: tag_name,
+            }
+        }
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn payload(&self) -> &TagRenamedPayload {
+        &self.payload
+    }
+}
+
+impl TagRenamedPayload {
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Id {
+        &self.user_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
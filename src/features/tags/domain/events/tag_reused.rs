@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+use crate::support::id::Id;
+
+pub const TAG_REUSED_NAME: &str = "tag_reused";
+
+/// Creation of a tag requested by an operation resolved to an existing tag with the same name.
+/// It is dispatched only and never appended to the tag stream.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TagReused {
+    id: Id,
+    name: String,
+    payload: TagReusedPayload,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TagReusedPayload {
+    id: Id,
+    user_id: Id,
+    operation_id: Id,
+    requested_tag_id: Id,
+}
+
+impl TagReused {
+    pub fn new(id: Id, tag_id: Id, user_id: Id, operation_id: Id, requested_tag_id: Id) -> Self {
+        Self {
+            id,
+            name: TAG_REUSED_NAME.to_string(),
+            payload: TagReusedPayload {
+                id: tag_id,
+                user_id,
+                operation_id,
+                requested_tag_id,
+            },
+        }
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn payload(&self) -> &TagReusedPayload {
+        &self.payload
+    }
+}
+
+impl TagReusedPayload {
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> &Id {
+        &self.user_id
+    }
+
+    pub fn operation_id(&self) -> &Id {
+        &self.operation_id
+    }
+
+    pub fn requested_tag_id(&self) -> &Id {
+        &self.requested_tag_id
+    }
+}
@@ -1,13 +1,23 @@
+use uuid::Uuid;
 use crate::support::id::Id;
-use crate::features::tags::domain::events::tag_created::TagCreated;
+use crate::features::tags::domain::events::tag_created::{TAG_CREATED_NAME, TagCreated};
+use crate::features::tags::domain::events::tag_deleted::TagDeleted;
 use crate::features::tags::domain::events::tag_event::TagEvent;
+use crate::features::tags::domain::events::tag_renamed::TagRenamed;
+use crate::features::tags::domain::events::tag_reused::TagReused;
 use crate::features::tags::application::commands::create_tag::command::CreateTagCommand;
+use crate::features::tags::application::commands::delete_tag::command::DeleteTagCommand;
+use crate::features::tags::application::commands::rename_tag::command::RenameTagCommand;
+use crate::features::tags::application::commands::resolve_tag::command::ResolveTagCommand;
 use crate::features::tags::domain::error::DomainError;
+use crate::support::event_store::{Aggregate, EventStoreError};
 
+#[derive(Debug, Clone)]
 pub struct Tag {
     id: Id,
     user_id: Id,
     name: String,
+    deleted: bool,
 }
 
 impl Tag {
@@ -16,6 +26,7 @@ impl Tag {
             id: Id::new(Id::generate()),
             user_id: Id::new(command.user_id().clone()),
             name: command.tag_name().to_string(),
+            deleted: false,
         };
 
         let event = TagEvent::TagCreated(
@@ -30,6 +41,88 @@ impl Tag {
         Ok(event)
     }
 
+    /// Creates the tag an operation asked for under the id the operation already references.
+    pub fn handle_requested_creation(command: ResolveTagCommand) -> Result<TagEvent, DomainError> {
+        Ok(
+            TagEvent::TagCreated(
+                TagCreated::new(
+                    Id::new(Id::generate()),
+                    Id::new(*command.tag_id()),
+                    Id::new(*command.user_id()),
+                    command.tag_name().to_string(),
+                )
+            )
+        )
+    }
+
+    /// Hands the tag over to an operation that asked for a new one with the same name.
+    pub fn handle_reuse(&self, command: ResolveTagCommand) -> Result<TagEvent, DomainError> {
+        self.check_access(command.user_id())?;
+
+        Ok(
+            TagEvent::TagReused(
+                TagReused::new(
+                    Id::new(Id::generate()),
+                    self.id.clone(),
+                    self.user_id.clone(),
+                    Id::new(*command.operation_id()),
+                    Id::new(*command.tag_id()),
+                )
+            )
+        )
+    }
+
+    pub fn handle_rename(&self, command: RenameTagCommand) -> Result<TagEvent, DomainError> {
+        self.check_access(command.user_id())?;
+
+        Ok(
+            TagEvent::TagRenamed(
+                TagRenamed::new(
+                    Id::new(Id::generate()),
+                    self.id.clone(),
+                    self.user_id.clone(),
+                    command.tag_name().to_string(),
+                )
+            )
+        )
+    }
+
+    pub fn handle_deletion(&self, command: DeleteTagCommand) -> Result<TagEvent, DomainError> {
+        self.check_access(command.user_id())?;
+
+        Ok(
+            TagEvent::TagDeleted(
+                TagDeleted::new(
+                    Id::new(Id::generate()),
+                    self.id.clone(),
+                    self.user_id.clone(),
+                    None,
+                )
+            )
+        )
+    }
+
+    /// Deletes the tag in favour of `target`, operations tagged with it get `target` instead.
+    pub fn handle_merge_into(&self, user_id: &Uuid, target: &Tag) -> Result<TagEvent, DomainError> {
+        self.check_access(user_id)?;
+        target.check_access(user_id)?;
+
+        if target.id == self.id {
+            return Err(DomainError::InvalidMergeTarget(self.id.to_string()));
+        }
+
+        Ok(
+            TagEvent::TagDeleted(
+                TagDeleted::new(
+                    Id::new(Id::generate()),
+                    self.id.clone(),
+                    self.user_id.clone(),
+                    Some(target.id.clone()),
+                )
+            )
+        )
+    }
+
     pub fn id(&self) -> &Id {
         &self.id
     }
@@ -41,10 +134,81 @@ impl Tag {
     pub fn name(&self) -> &str {
         &self.name
     }
+
+    pub fn is_deleted(&self) -> bool {
+        self.deleted
+    }
+
+    fn check_access(&self, user_id: &Uuid) -> Result<(), DomainError> {
+        if self.deleted {
+            return Err(DomainError::TagNotFound(self.id.to_string()));
+        }
+
+        if self.user_id.value() != *user_id {
+            return Err(DomainError::AccessDenied);
+        }
+
+        Ok(())
+    }
+}
+
+impl Aggregate for Tag {
+    type Event = TagEvent;
+
+    fn apply(state: Option<Self>, event: &TagEvent) -> Result<Self, EventStoreError> {
+        match (state, event) {
+            (None, TagEvent::TagCreated(tag_created)) => {
+                let payload = tag_created.payload();
+
+                Ok(
+                    Self {
+                        id: payload.id().clone(),
+                        user_id: payload.user_id().clone(),
+                        name: payload.name().to_string(),
+                        deleted: false,
+                    }
+                )
+            }
+            (Some(tag), TagEvent::TagCreated(_)) => Err(
+                EventStoreError::Rehydration(
+                    format!("Tag {} is already created", tag.id().to_string())
+                )
+            ),
+            (Some(tag), _) if tag.deleted => Err(
+                EventStoreError::Rehydration(
+                    format!("Tag {} is changed after deletion", tag.id().to_string())
+                )
+            ),
+            (Some(tag), TagEvent::TagRenamed(tag_renamed)) => Ok(
+                Self {
+                    name: tag_renamed.payload().name().to_string(),
+                    ..tag
+                }
+            ),
+            (Some(tag), TagEvent::TagDeleted(_)) => Ok(
+                Self {
+                    deleted: true,
+                    ..tag
+                }
+            ),
+            (Some(tag), TagEvent::TagReused(_)) => Err(
+                EventStoreError::Rehydration(
+                    format!("Tag {} stream cannot contain {}", tag.id().to_string(), event.name())
+                )
+            ),
+            (None, event) => Err(
+                EventStoreError::Rehydration(
+                    format!("Tag stream must start with {}, got {}", TAG_CREATED_NAME, event.name())
+                )
+            ),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use chrono::Utc;
+    use crate::support::event_store::{StorableEvent, StoredEvent};
     use super::*;
 
     #[test]
@@ -61,6 +225,70 @@ mod tests {
                 assert_eq!(event.payload().user_id().value().to_string().len(), 36);
                 assert_eq!(event.payload().name(), tag_name);
             }
+            _ => panic!("Expected TagCreated event"),
+        }
+    }
+
+    #[test]
+    fn test_rehydrate_from_tag_created() {
+        let user_id = Id::generate();
+        let command = CreateTagCommand::new(user_id, "tag_name".to_string());
+        let event = Tag::handle_creation(command).unwrap();
+        let new = event.to_new().unwrap();
+        let stored = StoredEvent::new(*new.id(), Id::generate(), new.name().to_string(), new.payload().clone(), 1, Utc::now());
+
+        let tag = Tag::rehydrate(&[stored]).unwrap().unwrap();
+
+        assert_eq!(tag.version(), 1);
+        assert_eq!(tag.aggregate().user_id().value(), user_id);
+        assert_eq!(tag.aggregate().name(), "tag_name");
+    }
+
+    #[test]
+    fn test_rename_and_delete() {
+        let tag = tag_fixture(Id::generate());
+        let user_id = tag.user_id().value();
+
+        let renamed = tag.handle_rename(RenameTagCommand::new(tag.id().value(), user_id, "groceries".to_string())).unwrap();
+        let tag = Tag::apply(Some(tag), &renamed).unwrap();
+        assert_eq!(tag.name(), "groceries");
+
+        let deleted = tag.handle_deletion(DeleteTagCommand::new(tag.id().value(), user_id)).unwrap();
+        let tag = Tag::apply(Some(tag), &deleted).unwrap();
+        assert!(tag.is_deleted());
+
+        let res = tag.handle_rename(RenameTagCommand::new(tag.id().value(), user_id, "food".to_string()));
+        assert!(matches!(res, Err(DomainError::TagNotFound(_))));
+    }
+
+    #[test]
+    fn test_rename_foreign_tag() {
+        let tag = tag_fixture(Id::generate());
+        let command = RenameTagCommand::new(tag.id().value(), Id::generate(), "groceries".to_string());
+
+        assert!(matches!(tag.handle_rename(command), Err(DomainError::AccessDenied)));
+    }
+
+    #[test]
+    fn test_merge_into_target() {
+        let user_id = Id::generate();
+        let source = tag_fixture(user_id);
+        let target = tag_fixture(user_id);
+
+        match source.handle_merge_into(&user_id, &target).unwrap() {
+            TagEvent::TagDeleted(tag_deleted) => {
+                assert_eq!(tag_deleted.payload().merged_into(), &Some(target.id().clone()));
+            }
+            _ => panic!("Expected TagDeleted event"),
         }
+
+        assert!(matches!(source.handle_merge_into(&user_id, &source), Err(DomainError::InvalidMergeTarget(_))));
+        assert!(matches!(source.handle_merge_into(&user_id, &tag_fixture(Id::generate())), Err(DomainError::AccessDenied)));
     }
-}
\ No newline at end of file
+
+    fn tag_fixture(user_id: Uuid) -> Tag {
+        let event = Tag::handle_creation(CreateTagCommand::new(user_id, "food".to_string())).unwrap();
+
+        Tag::apply(None, &event).unwrap()
+    }
+}
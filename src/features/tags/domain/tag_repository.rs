@@ -1,11 +1,23 @@
+use std::collections::HashMap;
 use async_trait::async_trait;
+use uuid::Uuid;
 use crate::features::tags::domain::events::tag_created::TagCreated;
+use crate::features::tags::domain::events::tag_event::TagEvent;
+use crate::features::tags::domain::tag::Tag;
 use crate::features::tags::error::TagError;
 use crate::features::tags::infrastructure::error::InfrastructureError;
+use crate::support::event_store::Versioned;
+use crate::support::name::normalize_name;
 
 #[async_trait]
 pub trait TagRepository {
-    async fn exists(&self, tag_created_event_name: &str, tag_deleted_event_name: &str, tag_name: &str) -> Result<bool, TagError>;
+    /// Looks up a live tag of the user by its normalized name, see `support::name::normalize_name`.
+    async fn find_id_by_name(&self, user_id: Uuid, tag_name: &str) -> Result<Option<Uuid>, TagError>;
+
+    async fn load(&self, tag_id: Uuid) -> Result<Option<Versioned<Tag>>, TagError>;
+
+    /// Fails with `InfrastructureError::Conflict` when the stream is no longer at `expected_version`.
+    async fn append(&self, tag_id: Uuid, expected_version: i32, events: &[TagEvent]) -> Result<i32, TagError>;
 
     async fn persist_tag_created_event(&self, tag: &TagCreated) -> Result<(), TagError>;
 }
@@ -13,6 +25,7 @@ pub trait TagRepository {
 pub struct MockTagRepository {
     exists_method_has_error: bool,
     persist_tag_created_event_method_has_error: bool,
+    tags: HashMap<Uuid, Versioned<Tag>>,
 }
 
 impl MockTagRepository {
@@ -20,13 +33,24 @@ impl MockTagRepository {
         Self {
             exists_method_has_error,
             persist_tag_created_event_method_has_error,
+            tags: HashMap::new(),
+        }
+    }
+
+    pub fn with_tags(tags: Vec<Versioned<Tag>>) -> Self {
+        Self {
+            exists_method_has_error: false,
+            persist_tag_created_event_method_has_error: false,
+            tags: tags.into_iter()
+                .map(|tag| (tag.aggregate().id().value(), tag))
+                .collect(),
         }
     }
 }
 
 #[async_trait]
 impl TagRepository for MockTagRepository {
-    async fn exists(&self, _tag_created_event_name: &str, _tag_deleted_event_name: &str, _tag_name: &str) -> Result<bool, TagError> {
+    async fn find_id_by_name(&self, user_id: Uuid, tag_name: &str) -> Result<Option<Uuid>, TagError> {
         if self.exists_method_has_error {
             return Err(
                 TagError::Infrastructure(
@@ -35,7 +59,30 @@ impl TagRepository for MockTagRepository {
             );
         }
 
-        Ok(false)
+        let tag_name = normalize_name(tag_name);
+
+        Ok(
+            self.tags.values()
+                .map(|tag| tag.aggregate())
+                .find(|tag| !tag.is_deleted() && tag.user_id().value() == user_id && normalize_name(tag.name()) == tag_name)
+                .map(|tag| tag.id().value())
+        )
+    }
+
+    async fn load(&self, tag_id: Uuid) -> Result<Option<Versioned<Tag>>, TagError> {
+        Ok(self.tags.get(&tag_id).cloned())
+    }
+
+    async fn append(&self, _tag_id: Uuid, expected_version: i32, events: &[TagEvent]) -> Result<i32, TagError> {
+        if self.persist_tag_created_event_method_has_error {
+            return Err(
+                TagError::Infrastructure(
+                    InfrastructureError::Repository("Mock repository error".into())
+                )
+            );
+        }
+
+        Ok(expected_version + events.len() as i32)
     }
 
     async fn persist_tag_created_event(&self, _tag: &TagCreated) -> Result<(), TagError> {
@@ -0,0 +1,112 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use sqlx::{query, query_as, Pool, Postgres};
+use sqlx::postgres::PgArguments;
+use sqlx::query::Query;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+use crate::db::manager::DbManager;
+use crate::features::tags::application::queries::tag_projection_repository::TagProjectionRepository;
+use crate::features::tags::application::queries::tag_view::TagView;
+use crate::features::tags::domain::events::tag_created::TagCreated;
+use crate::features::tags::domain::events::tag_deleted::TagDeleted;
+use crate::features::tags::domain::events::tag_renamed::TagRenamed;
+use crate::features::tags::error::TagError;
+use crate::features::tags::infrastructure::error::InfrastructureError;
+use crate::support::name::normalize_name;
+
+#[derive(Clone)]
+pub struct DbTagProjectionRepository {
+    db_manager: Arc<Mutex<DbManager>>,
+}
+
+impl DbTagProjectionRepository {
+    pub fn new(db_manager: Arc<Mutex<DbManager>>) -> Self {
+        Self {
+            db_manager,
+        }
+    }
+
+    async fn pool(&self) -> Result<Pool<Postgres>, TagError> {
+        let guard = self.db_manager.lock().await;
+
+        guard.pool()
+            .map_err(|e|
+                TagError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to get pool: {}", e)
+                    )
+                )
+            )
+    }
+
+    async fn execute(&self, res_query: Query<'_, Postgres, PgArguments>) -> Result<(), TagError> {
+        let pool = self.pool().await?;
+
+        res_query.execute(&pool)
+            .await
+            .map_err(|e|
+                TagError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to project tag: {}", e)
+                    )
+                )
+            )?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl TagProjectionRepository for DbTagProjectionRepository {
+    async fn find(&self, user_id: Uuid) -> Result<Vec<TagView>, TagError> {
+        let q = "
+            SELECT id, user_id, name, created_at
+            FROM tags
+            WHERE user_id = $1
+            ORDER BY name, id
+        ";
+
+        let pool = self.pool().await?;
+
+        query_as::<_, TagView>(q)
+            .bind(user_id)
+            .fetch_all(&pool)
+            .await
+            .map_err(|e|
+                TagError::Infrastructure(
+                    InfrastructureError::Repository(
+                        format!("Failed to fetch tags: {}", e)
+                    )
+                )
+            )
+    }
+
+    async fn apply_tag_created(&self, event: &TagCreated) -> Result<(), TagError> {
+        let payload = event.payload();
+
+        self.execute(
+            query("INSERT INTO tags (id, user_id, name, normalized_name) VALUES ($1, $2, $3, $4) ON CONFLICT DO NOTHING")
+                .bind(payload.id().value())
+                .bind(payload.user_id().value())
+                .bind(payload.name().to_string())
+                .bind(normalize_name(payload.name()))
+        ).await
+    }
+
+    async fn apply_tag_renamed(&self, event: &TagRenamed) -> Result<(), TagError> {
+        self.execute(
+            query("UPDATE tags SET name = $2, normalized_name = $3 WHERE id = $1")
+                .bind(event.payload().id().value())
+                .bind(event.payload().name().to_string())
+                .bind(normalize_name(event.payload().name()))
+        ).await
+    }
+
+    async fn apply_tag_deleted(&self, event: &TagDeleted) -> Result<(), TagError> {
+        self.execute(
+            query("DELETE FROM tags WHERE id = $1")
+                .bind(event.payload().id().value())
+        ).await
+    }
+}
@@ -1,23 +1,33 @@
 use std::sync::Arc;
 use async_trait::async_trait;
-use sqlx::{query, Row};
+use sqlx::query_scalar;
 use tokio::sync::Mutex;
+use uuid::Uuid;
 use crate::db::manager::DbManager;
+use crate::features::tags::domain::tag::Tag;
 use crate::features::tags::domain::tag_repository::TagRepository;
 use crate::features::tags::domain::events::tag_created::TagCreated;
+use crate::features::tags::domain::events::tag_event::TagEvent;
 use crate::features::tags::error::TagError;
 use crate::features::tags::infrastructure::error::InfrastructureError;
 use crate::services::serializer::Serializer;
+use crate::support::event_store::{Aggregate, EventStore, StorableEvent, Versioned};
+use crate::support::name::normalize_name;
+use crate::support::pg_event_store::PgEventStore;
+
+const EVENTS_TABLE: &str = "tag_events";
 
 #[derive(Clone)]
 pub struct DbTagRepository {
     db_manager: Arc<Mutex<DbManager>>,
+    event_store: PgEventStore,
     serializer: Serializer,
 }
 
 impl DbTagRepository {
     pub fn new(db_manager: Arc<Mutex<DbManager>>, serializer: Serializer) -> Self {
         Self {
+            event_store: PgEventStore::new(db_manager.clone(), EVENTS_TABLE),
             db_manager,
             serializer,
         }
@@ -26,7 +36,7 @@ impl DbTagRepository {
 
 #[async_trait]
 impl TagRepository for DbTagRepository {
-    async fn exists(&self, tag_created_name: &str, tag_deleted_name: &str, name: &str) -> Result<bool, TagError> {
+    async fn find_id_by_name(&self, user_id: Uuid, name: &str) -> Result<Option<Uuid>, TagError> {
         let guard = self.db_manager.lock().await;
         let pool = guard.pool()
             .map_err(|e|
@@ -35,75 +45,57 @@ impl TagRepository for DbTagRepository {
                 )
             )?;
 
-        let q = "
-            SELECT EXISTS (
-                SELECT 1
-                FROM tag_events
-                WHERE payload->>'name' = $1
-                    AND name = $2
-            AND NOT EXISTS (
-                SELECT 1
-                FROM tag_events
-                WHERE payload->>'name' = $1
-                    AND name = $3
+        query_scalar::<_, Uuid>("SELECT id FROM tags WHERE user_id = $1 AND normalized_name = $2")
+            .bind(user_id)
+            .bind(normalize_name(name))
+            .fetch_optional(&pool)
+            .await
+            .map_err(|e|
+                TagError::Infrastructure(
+                    InfrastructureError::Repository(e.to_string())
+                )
             )
-            ) AS exists
-        ";
-
-        let query = query(q)
-            .bind(name)
-            .bind(tag_created_name)
-            .bind(tag_deleted_name);
+    }
 
-        let row = query.fetch_one(&pool).await
+    async fn load(&self, tag_id: Uuid) -> Result<Option<Versioned<Tag>>, TagError> {
+        let stream = self.event_store.load(tag_id)
+            .await
             .map_err(|e|
                 TagError::Infrastructure(
                     InfrastructureError::Repository(e.to_string())
                 )
             )?;
 
-        let exists = row.try_get::<bool, _>(0)
+        Tag::rehydrate(&stream)
             .map_err(|e|
                 TagError::Infrastructure(
                     InfrastructureError::Repository(e.to_string())
                 )
-            )?;
-
-        Ok(exists)
-    }
-
-    async fn persist_tag_created_event(&self, tag_created: &TagCreated) -> Result<(), TagError> {
-        let q = "INSERT INTO tag_events (id, name, payload) VALUES ($1, $2, $3)";
-
-        let payload = serde_json::to_value(
-            &tag_created.payload()
-        ).map_err(|e|
-            TagError::Infrastructure(
-                InfrastructureError::Repository(e.to_string())
             )
-        )?;
-
-        let query = query(q)
-            .bind(tag_created.id().value())
-            .bind(tag_created.name())
-            .bind(payload);
+    }
 
-        let guard = self.db_manager.lock().await;
-        let pool = guard.pool()
+    async fn append(&self, tag_id: Uuid, expected_version: i32, events: &[TagEvent]) -> Result<i32, TagError> {
+        let events = events.iter()
+            .map(|event| event.to_new())
+            .collect::<Result<Vec<_>, _>>()
             .map_err(|e|
                 TagError::Infrastructure(
                     InfrastructureError::Repository(e.to_string())
                 )
             )?;
 
-        query.execute(&pool)
+        self.event_store.append(tag_id, expected_version, &events)
             .await
             .map_err(|e|
-                TagError::Infrastructure(
-                    InfrastructureError::Repository(e.to_string())
-                )
-            )?;
+                TagError::Infrastructure(e.into())
+            )
+    }
+
+    async fn persist_tag_created_event(&self, tag_created: &TagCreated) -> Result<(), TagError> {
+        let tag_id = tag_created.payload().id().value();
+
+        self.append(tag_id, 0, &[TagEvent::TagCreated(tag_created.clone())]).await?;
 
         Ok(())
     }
-}
\ No newline at end of file
+}
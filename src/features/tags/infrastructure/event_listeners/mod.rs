@@ -1 +1,2 @@
-pub mod tag_creation_requested_listener;
\ No newline at end of file
+pub mod tag_creation_requested_listener;
+pub mod tag_projection_listener;
\ No newline at end of file
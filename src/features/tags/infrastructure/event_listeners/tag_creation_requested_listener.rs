@@ -4,8 +4,8 @@ use tokio::sync::Mutex;
 use crate::events::error::EventError;
 use crate::events::event::Event;
 use crate::events::event_listener::EventListener;
-use crate::features::tags::application::commands::create_tag::command::CreateTagCommand;
-use crate::features::tags::application::commands::create_tag::handler::CreateTagCommandHandler;
+use crate::features::tags::application::commands::resolve_tag::command::ResolveTagCommand;
+use crate::features::tags::application::commands::resolve_tag::handler::ResolveTagCommandHandler;
 use crate::features::tags::domain::tag_repository::TagRepository;
 use crate::features::operations::domain::events::tag_creation_requested::TagCreationRequested;
 use crate::features::operations::domain::events::operation_event::OperationEvent;
@@ -13,11 +13,12 @@ use crate::support::command_bus::{CommandBus};
 
 const EVENT_NAME: &str = "tag_creation_requested";
 
+/// Resolves the tag an operation asked for by name, reusing the user's tag with the same name if there is one.
 pub struct TagCreationRequestedListener<R>
     where
         R: TagRepository + Clone + Send + Sync + 'static,
 {
-    command_bus: Arc<Mutex<CommandBus<CreateTagCommand, CreateTagCommandHandler<R>>>>,
+    command_bus: Arc<Mutex<CommandBus<ResolveTagCommand, ResolveTagCommandHandler<R>>>>,
 }
 
 #[async_trait]
@@ -28,7 +29,12 @@ impl<R> EventListener for TagCreationRequestedListener<R>
     async fn on_event(&mut self, event: Event) -> Result<Vec<Event>, EventError> {
         let event = self.parse_event(event)?;
 
-        let command = CreateTagCommand::new(event.payload().user_id().value(), event.payload().tag_name().to_string());
+        let command = ResolveTagCommand::new(
+            event.payload().operation_id().value(),
+            event.payload().user_id().value(),
+            event.payload().tag_id().value(),
+            event.payload().tag_name().to_string(),
+        );
 
         let mut guard = self.command_bus.lock().await;
         let events = guard.dispatch(command)
@@ -50,13 +56,11 @@ impl<R> TagCreationRequestedListener<R>
         R: TagRepository + Clone + Send + Sync + 'static,
 {
     pub async fn new(
-        command_bus: Arc<Mutex<CommandBus<CreateTagCommand, CreateTagCommandHandler<R>>>>,
+        command_bus: Arc<Mutex<CommandBus<ResolveTagCommand, ResolveTagCommandHandler<R>>>>,
         rep: R,
     ) -> Self {
-        let handler = CreateTagCommandHandler::new(rep);
-
         let mut guard = command_bus.lock().await;
-        guard.register(handler.clone());
+        guard.register(ResolveTagCommandHandler::new(rep));
 
         Self {
             command_bus: command_bus.clone(),
@@ -0,0 +1,68 @@
+use async_trait::async_trait;
+use crate::events::error::EventError;
+use crate::events::event::Event;
+use crate::events::event_listener::EventListener;
+use crate::features::tags::application::queries::tag_projection_repository::TagProjectionRepository;
+use crate::features::tags::domain::events::tag_event::TagEvent;
+use crate::support::error::FeatureError;
+
+/// Keeps the `tags` read model in sync with the tag event stream.
+/// One instance is registered per tag event name.
+pub struct TagProjectionListener<R>
+    where
+        R: TagProjectionRepository + Send + Sync + 'static,
+{
+    rep: R,
+    event_name: &'static str,
+}
+
+#[async_trait]
+impl<R> EventListener for TagProjectionListener<R>
+    where
+        R: TagProjectionRepository + Send + Sync + 'static,
+{
+    async fn on_event(&mut self, event: Event) -> Result<Vec<Event>, EventError> {
+        let res = match self.parse_event(event)? {
+            TagEvent::TagCreated(event) => self.rep.apply_tag_created(&event).await,
+            TagEvent::TagRenamed(event) => self.rep.apply_tag_renamed(&event).await,
+            TagEvent::TagDeleted(event) => self.rep.apply_tag_deleted(&event).await,
+            // Dispatched only, it changes nothing in the tag itself
+            TagEvent::TagReused(_) => Ok(()),
+        };
+
+        res.map_err(|e|
+            EventError::Feature(
+                FeatureError::Tag(e)
+            )
+        )?;
+
+        Ok(vec![])
+    }
+
+    fn event_name(&self) -> &str {
+        self.event_name
+    }
+}
+
+impl<R> TagProjectionListener<R>
+    where
+        R: TagProjectionRepository + Send + Sync + 'static,
+{
+    pub fn new(rep: R, event_name: &'static str) -> Self {
+        Self {
+            rep,
+            event_name,
+        }
+    }
+
+    pub fn parse_event(&self, event: Event) -> Result<TagEvent, EventError> {
+        match event {
+            Event::TagEvent(tag_event) => Ok(tag_event),
+            _ => Err(
+                EventError::Parsing(
+                    format!("Invalid event type. Expected TagEvent, got {:?}", event)
+                )
+            )
+        }
+    }
+}
@@ -1,3 +1,4 @@
+pub mod db_tag_projection_repository;
 pub mod db_tag_repository;
 pub mod event_listeners;
 pub mod error;
\ No newline at end of file
@@ -3,11 +3,41 @@ use actix_web::{HttpResponse, ResponseError};
 
 use crate::{log_error, log_trace};
 use crate::events::error::EventError;
+use crate::features::accounts::domain::error as account_domain;
+use crate::features::accounts::error::AccountError;
+use crate::features::accounts::infrastructure::error as account_infrastructure;
 use crate::features::auth::error::AuthError;
 use crate::features::balance::error::BalanceError;
+use crate::features::banking::domain::error as banking_domain;
+use crate::features::banking::error::BankingError;
+use crate::features::banking::infrastructure::error as banking_infrastructure;
+use crate::features::budgets::domain::error as budget_domain;
+use crate::features::budgets::error::BudgetError;
+use crate::features::budgets::infrastructure::error as budget_infrastructure;
+use crate::features::categories::domain::error as category_domain;
 use crate::features::categories::error::CategoryError;
+use crate::features::categories::infrastructure::error as category_infrastructure;
+use crate::features::imports::domain::error as import_domain;
+use crate::features::imports::error::ImportError;
+use crate::features::imports::infrastructure::error as import_infrastructure;
+use crate::features::loans::domain::error as loan_domain;
+use crate::features::loans::error::LoanError;
+use crate::features::loans::infrastructure::error as loan_infrastructure;
+use crate::features::operations::domain::error as operation_domain;
 use crate::features::operations::error::OperationError;
+use crate::features::operations::infrastructure::error as operation_infrastructure;
+use crate::features::rates::error::RateError;
+use crate::features::recurrences::domain::error as recurrence_domain;
+use crate::features::recurrences::error::RecurrenceError;
+use crate::features::recurrences::infrastructure::error as recurrence_infrastructure;
+use crate::features::reports::error::ReportError;
+use crate::features::rules::domain::error as rule_domain;
+use crate::features::rules::error::RuleError;
+use crate::features::rules::infrastructure::error as rule_infrastructure;
+use crate::features::suggestions::error::SuggestionError;
+use crate::features::tags::domain::error as tag_domain;
 use crate::features::tags::error::TagError;
+use crate::features::tags::infrastructure::error as tag_infrastructure;
 use crate::support::error::FeatureError;
 
 #[derive(Clone, Debug, thiserror::Error)]
@@ -37,6 +67,14 @@ impl ResponseError for HttpError {
     fn status_code(&self, ) -> StatusCode {
         match self {
             HttpError::Feature(feature_errors) => match feature_errors {
+                FeatureError::Account(account_error) => match account_error {
+                    AccountError::Domain(account_domain::DomainError::AccountNotFound(_)) => StatusCode::NOT_FOUND,
+                    AccountError::Domain(account_domain::DomainError::AccessDenied) => StatusCode::FORBIDDEN,
+                    AccountError::Domain(_) => StatusCode::UNPROCESSABLE_ENTITY,
+                    AccountError::Infrastructure(account_infrastructure::InfrastructureError::Conflict(_)) => StatusCode::CONFLICT,
+                    AccountError::Infrastructure(_) => StatusCode::INTERNAL_SERVER_ERROR,
+                },
+
                 FeatureError::Auth(auth_error) => match auth_error {
                     AuthError::Domain(_) => StatusCode::UNPROCESSABLE_ENTITY,
                     AuthError::Infrastructure(_) => StatusCode::INTERNAL_SERVER_ERROR,
@@ -47,18 +85,92 @@ impl ResponseError for HttpError {
                     BalanceError::Infrastructure(_) => StatusCode::INTERNAL_SERVER_ERROR,
                 },
 
+                FeatureError::Banking(banking_error) => match banking_error {
+                    BankingError::Domain(banking_domain::DomainError::BankConnectionNotFound(_)) => StatusCode::NOT_FOUND,
+                    BankingError::Domain(banking_domain::DomainError::AccessDenied) => StatusCode::FORBIDDEN,
+                    BankingError::Domain(banking_domain::DomainError::AccountNotFound) => StatusCode::NOT_FOUND,
+                    BankingError::Domain(_) => StatusCode::UNPROCESSABLE_ENTITY,
+                    BankingError::Infrastructure(banking_infrastructure::InfrastructureError::Conflict(_)) => StatusCode::CONFLICT,
+                    BankingError::Infrastructure(banking_infrastructure::InfrastructureError::Provider(_)) => StatusCode::BAD_GATEWAY,
+                    BankingError::Infrastructure(_) => StatusCode::INTERNAL_SERVER_ERROR,
+                },
+
+                FeatureError::Budget(budget_error) => match budget_error {
+                    BudgetError::Domain(budget_domain::DomainError::BudgetNotFound(_)) => StatusCode::NOT_FOUND,
+                    BudgetError::Domain(budget_domain::DomainError::AccessDenied) => StatusCode::FORBIDDEN,
+                    BudgetError::Domain(_) => StatusCode::UNPROCESSABLE_ENTITY,
+                    BudgetError::Infrastructure(budget_infrastructure::InfrastructureError::Conflict(_)) => StatusCode::CONFLICT,
+                    BudgetError::Infrastructure(_) => StatusCode::INTERNAL_SERVER_ERROR,
+                },
+
                 FeatureError::Category(category_error) => match category_error {
+                    CategoryError::Domain(category_domain::DomainError::CategoryNotFound(_)) => StatusCode::NOT_FOUND,
+                    CategoryError::Domain(category_domain::DomainError::AccessDenied) => StatusCode::FORBIDDEN,
                     CategoryError::Domain(_) => StatusCode::UNPROCESSABLE_ENTITY,
+                    CategoryError::Infrastructure(category_infrastructure::InfrastructureError::Conflict(_)) => StatusCode::CONFLICT,
                     CategoryError::Infrastructure(_) => StatusCode::INTERNAL_SERVER_ERROR,
                 },
 
+                FeatureError::Import(import_error) => match import_error {
+                    ImportError::Domain(import_domain::DomainError::AccountNotFound) => StatusCode::NOT_FOUND,
+                    ImportError::Domain(_) => StatusCode::UNPROCESSABLE_ENTITY,
+                    ImportError::Infrastructure(import_infrastructure::InfrastructureError::Conflict(_)) => StatusCode::CONFLICT,
+                    ImportError::Infrastructure(_) => StatusCode::INTERNAL_SERVER_ERROR,
+                },
+
+                FeatureError::Loan(loan_error) => match loan_error {
+                    LoanError::Domain(loan_domain::DomainError::LoanNotFound(_)) => StatusCode::NOT_FOUND,
+                    LoanError::Domain(loan_domain::DomainError::AccessDenied) => StatusCode::FORBIDDEN,
+                    LoanError::Domain(_) => StatusCode::UNPROCESSABLE_ENTITY,
+                    LoanError::Infrastructure(loan_infrastructure::InfrastructureError::Conflict(_)) => StatusCode::CONFLICT,
+                    LoanError::Infrastructure(_) => StatusCode::INTERNAL_SERVER_ERROR,
+                },
+
                 FeatureError::Operation(operation_error) => match operation_error {
+                    OperationError::Domain(operation_domain::DomainError::OperationNotFound) => StatusCode::NOT_FOUND,
+                    OperationError::Domain(operation_domain::DomainError::AttachmentNotFound) => StatusCode::NOT_FOUND,
+                    OperationError::Domain(operation_domain::DomainError::AccessDenied) => StatusCode::FORBIDDEN,
                     OperationError::Domain(_) => StatusCode::UNPROCESSABLE_ENTITY,
+                    OperationError::Infrastructure(operation_infrastructure::InfrastructureError::Conflict(_)) => StatusCode::CONFLICT,
                     OperationError::Infrastructure(_) => StatusCode::INTERNAL_SERVER_ERROR,
                 },
 
+                FeatureError::Rate(rate_error) => match rate_error {
+                    RateError::Domain(_) => StatusCode::UNPROCESSABLE_ENTITY,
+                    RateError::Infrastructure(_) => StatusCode::INTERNAL_SERVER_ERROR,
+                },
+
+                FeatureError::Recurrence(recurrence_error) => match recurrence_error {
+                    RecurrenceError::Domain(recurrence_domain::DomainError::RecurringOperationNotFound(_)) => StatusCode::NOT_FOUND,
+                    RecurrenceError::Domain(recurrence_domain::DomainError::AccessDenied) => StatusCode::FORBIDDEN,
+                    RecurrenceError::Domain(_) => StatusCode::UNPROCESSABLE_ENTITY,
+                    RecurrenceError::Infrastructure(recurrence_infrastructure::InfrastructureError::Conflict(_)) => StatusCode::CONFLICT,
+                    RecurrenceError::Infrastructure(_) => StatusCode::INTERNAL_SERVER_ERROR,
+                },
+
+                FeatureError::Report(report_error) => match report_error {
+                    ReportError::Domain(_) => StatusCode::UNPROCESSABLE_ENTITY,
+                    ReportError::Infrastructure(_) => StatusCode::INTERNAL_SERVER_ERROR,
+                },
+
+                FeatureError::Rule(rule_error) => match rule_error {
+                    RuleError::Domain(rule_domain::DomainError::RuleNotFound(_)) => StatusCode::NOT_FOUND,
+                    RuleError::Domain(rule_domain::DomainError::AccessDenied) => StatusCode::FORBIDDEN,
+                    RuleError::Domain(_) => StatusCode::UNPROCESSABLE_ENTITY,
+                    RuleError::Infrastructure(rule_infrastructure::InfrastructureError::Conflict(_)) => StatusCode::CONFLICT,
+                    RuleError::Infrastructure(_) => StatusCode::INTERNAL_SERVER_ERROR,
+                },
+
+                FeatureError::Suggestion(suggestion_error) => match suggestion_error {
+                    SuggestionError::Domain(_) => StatusCode::UNPROCESSABLE_ENTITY,
+                    SuggestionError::Infrastructure(_) => StatusCode::INTERNAL_SERVER_ERROR,
+                },
+
                 FeatureError::Tag(tag_error) => match tag_error {
+                    TagError::Domain(tag_domain::DomainError::TagNotFound(_)) => StatusCode::NOT_FOUND,
+                    TagError::Domain(tag_domain::DomainError::AccessDenied) => StatusCode::FORBIDDEN,
                     TagError::Domain(_) => StatusCode::UNPROCESSABLE_ENTITY,
+                    TagError::Infrastructure(tag_infrastructure::InfrastructureError::Conflict(_)) => StatusCode::CONFLICT,
                     TagError::Infrastructure(_) => StatusCode::INTERNAL_SERVER_ERROR,
                 },
 
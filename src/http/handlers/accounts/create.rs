@@ -0,0 +1,64 @@
+use std::sync::Arc;
+use actix_web::{HttpResponse, post, Responder};
+use actix_web::web::{Data, Json};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use crate::di::service_container::ServiceContainer;
+use crate::events::event_bus::EventBus;
+use crate::features::accounts::application::commands::create_account::command::CreateAccountCommand;
+use crate::features::accounts::application::commands::create_account::handler::CreateAccountCommandHandler;
+use crate::features::accounts::infrastructure::db_account_repository::DbAccountRepository;
+use crate::http::error::HttpError;
+use crate::http::extractors::jwt::Jwt;
+use crate::services::jwt::JwtService;
+
+#[derive(Debug, Clone, Deserialize)]
+struct RequestData {
+    name: String,
+    kind: String,
+    currency: String,
+    opening_balance: Decimal,
+}
+
+#[post("/create")]
+pub async fn create_account(
+    request_data: Json<RequestData>,
+    jwt: Jwt,
+    service_container: Data<Arc<ServiceContainer>>,
+    event_bus: Data<Arc<Box<dyn EventBus>>>,
+) -> Result<impl Responder, HttpError> {
+    let jwt_service = service_container.jwt_service();
+    let claims = jwt_service.verify(jwt.0.as_str()).map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+    let user_id = claims.user_id().map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+
+    let rep = DbAccountRepository::new(service_container.db_manager());
+
+    let command = CreateAccountCommand::new(
+        user_id,
+        request_data.name.clone(),
+        request_data.kind.clone(),
+        request_data.currency.clone(),
+        request_data.opening_balance,
+    );
+    let handler = CreateAccountCommandHandler::new(rep);
+
+    let mut command_bus = service_container.command_bus();
+    command_bus.register(handler);
+    let events = command_bus.dispatch(command)
+        .await
+        .map_err(HttpError::Feature)?;
+
+    for event in events {
+        event_bus.publish(event)
+            .await
+            .map_err(HttpError::Event)?;
+    }
+
+    Ok(
+        HttpResponse::Ok()
+    )
+}
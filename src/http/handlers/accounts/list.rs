@@ -0,0 +1,37 @@
+use std::sync::Arc;
+use actix_web::{get, HttpResponse, Responder};
+use actix_web::web::Data;
+use crate::di::service_container::ServiceContainer;
+use crate::features::accounts::application::queries::list_accounts::handler::ListAccountsQueryHandler;
+use crate::features::accounts::application::queries::list_accounts::query::ListAccountsQuery;
+use crate::features::accounts::infrastructure::db_account_projection_repository::DbAccountProjectionRepository;
+use crate::http::error::HttpError;
+use crate::http::extractors::jwt::Jwt;
+use crate::services::jwt::JwtService;
+
+#[get("")]
+pub async fn list_accounts(
+    jwt: Jwt,
+    service_container: Data<Arc<ServiceContainer>>,
+) -> Result<impl Responder, HttpError> {
+    let jwt_service = service_container.jwt_service();
+    let claims = jwt_service.verify(jwt.0.as_str()).map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+    let user_id = claims.user_id().map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+
+    let rep = DbAccountProjectionRepository::new(service_container.db_manager());
+    let handler = ListAccountsQueryHandler::new(rep);
+
+    let query = ListAccountsQuery::new(user_id);
+
+    let mut query_bus = service_container.query_bus();
+    query_bus.register(handler);
+    let accounts = query_bus.dispatch(query)
+        .await
+        .map_err(HttpError::Feature)?;
+
+    Ok(HttpResponse::Ok().json(accounts))
+}
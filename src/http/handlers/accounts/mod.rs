@@ -0,0 +1,3 @@
+pub mod create;
+pub mod list;
+pub mod rename;
\ No newline at end of file
@@ -0,0 +1,56 @@
+use std::sync::Arc;
+use actix_web::{put, HttpResponse, Responder};
+use actix_web::web::{Data, Json, Path};
+use serde::Deserialize;
+use uuid::Uuid;
+use crate::di::service_container::ServiceContainer;
+use crate::events::event_bus::EventBus;
+use crate::features::accounts::application::commands::rename_account::command::RenameAccountCommand;
+use crate::features::accounts::application::commands::rename_account::handler::RenameAccountCommandHandler;
+use crate::features::accounts::infrastructure::db_account_repository::DbAccountRepository;
+use crate::http::error::HttpError;
+use crate::http::extractors::jwt::Jwt;
+use crate::services::jwt::JwtService;
+
+#[derive(Deserialize)]
+struct RequestData {
+    name: String,
+}
+
+#[put("/{id}/name")]
+pub async fn rename_account(
+    account_id: Path<Uuid>,
+    request_data: Json<RequestData>,
+    jwt: Jwt,
+    service_container: Data<Arc<ServiceContainer>>,
+    event_bus: Data<Arc<Box<dyn EventBus>>>
+) -> Result<impl Responder, HttpError> {
+    let jwt_service = service_container.jwt_service();
+    let claims = jwt_service.verify(jwt.0.as_str()).map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+    let user_id = claims.user_id().map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+
+    let rep = DbAccountRepository::new(service_container.db_manager());
+
+    let command = RenameAccountCommand::new(account_id.into_inner(), user_id, request_data.name.clone());
+    let handler = RenameAccountCommandHandler::new(rep);
+
+    let mut command_bus = service_container.command_bus();
+    command_bus.register(handler);
+    let events = command_bus.dispatch(command)
+        .await
+        .map_err(HttpError::Feature)?;
+
+    for event in events {
+        event_bus.publish(event)
+            .await
+            .map_err(HttpError::Event)?;
+    }
+
+    Ok(
+        HttpResponse::Ok()
+    )
+}
@@ -0,0 +1,55 @@
+use std::sync::Arc;
+use actix_web::{get, HttpResponse, Responder};
+use actix_web::web::Data;
+use chrono::Utc;
+use crate::di::service_container::ServiceContainer;
+use crate::features::balance::application::queries::get_balance::handler::GetBalanceQueryHandler;
+use crate::features::balance::application::queries::get_balance::query::GetBalanceQuery;
+use crate::features::balance::infrastructure::db_balance_projection_repository::DbBalanceProjectionRepository;
+use crate::features::rates::application::queries::find_rate::handler::FindRateQueryHandler;
+use crate::features::rates::infrastructure::db_rate_repository::DbRateRepository;
+use crate::features::rates::infrastructure::query_rate_source::QueryRateSource;
+use crate::http::error::HttpError;
+use crate::http::extractors::jwt::Jwt;
+use crate::services::jwt::JwtService;
+use crate::support::error::FeatureError;
+
+#[get("")]
+pub async fn get_balance(
+    jwt: Jwt,
+    service_container: Data<Arc<ServiceContainer>>,
+) -> Result<impl Responder, HttpError> {
+    let jwt_service = service_container.jwt_service();
+    let user_id = jwt_service.verify(jwt.0.as_str())
+        .map_err(|e|
+            HttpError::Service(e.to_string())
+        )?
+        .user_id()
+        .map_err(|e|
+            HttpError::Service(e.to_string())
+        )?;
+
+    let db_manager = service_container.db_manager();
+
+    let rate_provider = service_container.rate_provider()
+        .map_err(|e|
+            HttpError::Feature(FeatureError::Rate(e))
+        )?;
+    let rates = QueryRateSource::new(
+        FindRateQueryHandler::new(DbRateRepository::new(db_manager.clone()), rate_provider),
+        service_container.config().rates().base_currency().to_string(),
+    );
+
+    let rep = DbBalanceProjectionRepository::new(db_manager);
+    let handler = GetBalanceQueryHandler::new(rep, rates);
+
+    let query = GetBalanceQuery::new(user_id, Utc::now().date_naive());
+
+    let mut query_bus = service_container.query_bus();
+    query_bus.register(handler);
+    let balance = query_bus.dispatch(query)
+        .await
+        .map_err(HttpError::Feature)?;
+
+    Ok(HttpResponse::Ok().json(balance))
+}
@@ -0,0 +1,42 @@
+use std::sync::Arc;
+use actix_web::{HttpResponse, post, Responder};
+use actix_web::web::{Data, Json};
+use serde::Deserialize;
+use crate::di::service_container::ServiceContainer;
+use crate::features::banking::application::queries::list_bank_accounts::handler::ListBankAccountsQueryHandler;
+use crate::features::banking::application::queries::list_bank_accounts::query::ListBankAccountsQuery;
+use crate::features::banking::infrastructure::configured_bank_connectors::ConfiguredBankConnectors;
+use crate::http::error::HttpError;
+use crate::http::extractors::jwt::Jwt;
+use crate::services::jwt::JwtService;
+
+#[derive(Debug, Clone, Deserialize)]
+struct RequestData {
+    provider: String,
+    credentials: String,
+}
+
+/// Lists the accounts available at the bank, so the one to connect can be picked.
+#[post("/accounts")]
+pub async fn list_bank_accounts(
+    request_data: Json<RequestData>,
+    jwt: Jwt,
+    service_container: Data<Arc<ServiceContainer>>,
+) -> Result<impl Responder, HttpError> {
+    let jwt_service = service_container.jwt_service();
+    jwt_service.verify(jwt.0.as_str()).map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+
+    let connectors = ConfiguredBankConnectors::new(service_container.config().banking().providers().to_vec());
+    let handler = ListBankAccountsQueryHandler::new(connectors);
+
+    let request_data = request_data.into_inner();
+    let mut query_bus = service_container.query_bus();
+    query_bus.register(handler);
+    let accounts = query_bus.dispatch(ListBankAccountsQuery::new(request_data.provider, request_data.credentials))
+        .await
+        .map_err(HttpError::Feature)?;
+
+    Ok(HttpResponse::Ok().json(accounts))
+}
@@ -0,0 +1,73 @@
+use std::sync::Arc;
+use actix_web::{HttpResponse, post, Responder};
+use actix_web::web::{Data, Json};
+use serde::Deserialize;
+use uuid::Uuid;
+use crate::di::service_container::ServiceContainer;
+use crate::events::event_bus::EventBus;
+use crate::features::accounts::infrastructure::db_account_projection_repository::DbAccountProjectionRepository;
+use crate::features::banking::application::commands::create_bank_connection::command::CreateBankConnectionCommand;
+use crate::features::banking::application::commands::create_bank_connection::handler::CreateBankConnectionCommandHandler;
+use crate::features::banking::infrastructure::configured_bank_connectors::ConfiguredBankConnectors;
+use crate::features::banking::infrastructure::db_bank_connection_repository::DbBankConnectionRepository;
+use crate::features::operations::infrastructure::projection_account_source::ProjectionAccountSource;
+use crate::http::error::HttpError;
+use crate::http::extractors::jwt::Jwt;
+use crate::services::jwt::JwtService;
+
+#[derive(Debug, Clone, Deserialize)]
+struct RequestData {
+    account_id: Uuid,
+    provider: String,
+    external_account_id: String,
+    credentials: String,
+}
+
+#[post("/create")]
+pub async fn create_bank_connection(
+    request_data: Json<RequestData>,
+    jwt: Jwt,
+    service_container: Data<Arc<ServiceContainer>>,
+    event_bus: Data<Arc<Box<dyn EventBus>>>,
+) -> Result<impl Responder, HttpError> {
+    let jwt_service = service_container.jwt_service();
+    let claims = jwt_service.verify(jwt.0.as_str()).map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+    let user_id = claims.user_id().map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+
+    let db_manager = service_container.db_manager();
+    let handler = CreateBankConnectionCommandHandler::new(
+        DbBankConnectionRepository::new(db_manager.clone()),
+        ProjectionAccountSource::new(DbAccountProjectionRepository::new(db_manager)),
+        ConfiguredBankConnectors::new(service_container.config().banking().providers().to_vec()),
+        service_container.cipher(),
+    );
+
+    let request_data = request_data.into_inner();
+    let command = CreateBankConnectionCommand::new(
+        user_id,
+        request_data.account_id,
+        request_data.provider,
+        request_data.external_account_id,
+        request_data.credentials,
+    );
+
+    let mut command_bus = service_container.command_bus();
+    command_bus.register(handler);
+    let events = command_bus.dispatch(command)
+        .await
+        .map_err(HttpError::Feature)?;
+
+    for event in events {
+        event_bus.publish(event)
+            .await
+            .map_err(HttpError::Event)?;
+    }
+
+    Ok(
+        HttpResponse::Ok()
+    )
+}
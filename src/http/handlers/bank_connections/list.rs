@@ -0,0 +1,35 @@
+use std::sync::Arc;
+use actix_web::{get, HttpResponse, Responder};
+use actix_web::web::Data;
+use crate::di::service_container::ServiceContainer;
+use crate::features::banking::application::queries::list_bank_connections::handler::ListBankConnectionsQueryHandler;
+use crate::features::banking::application::queries::list_bank_connections::query::ListBankConnectionsQuery;
+use crate::features::banking::infrastructure::db_bank_connection_projection_repository::DbBankConnectionProjectionRepository;
+use crate::http::error::HttpError;
+use crate::http::extractors::jwt::Jwt;
+use crate::services::jwt::JwtService;
+
+#[get("")]
+pub async fn list_bank_connections(
+    jwt: Jwt,
+    service_container: Data<Arc<ServiceContainer>>,
+) -> Result<impl Responder, HttpError> {
+    let jwt_service = service_container.jwt_service();
+    let claims = jwt_service.verify(jwt.0.as_str()).map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+    let user_id = claims.user_id().map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+
+    let rep = DbBankConnectionProjectionRepository::new(service_container.db_manager());
+    let handler = ListBankConnectionsQueryHandler::new(rep);
+
+    let mut query_bus = service_container.query_bus();
+    query_bus.register(handler);
+    let bank_connections = query_bus.dispatch(ListBankConnectionsQuery::new(user_id))
+        .await
+        .map_err(HttpError::Feature)?;
+
+    Ok(HttpResponse::Ok().json(bank_connections))
+}
@@ -0,0 +1,5 @@
+pub mod accounts;
+pub mod create;
+pub mod list;
+pub mod remove;
+pub mod sync;
\ No newline at end of file
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+use actix_web::{delete, HttpResponse, Responder};
+use actix_web::web::{Data, Path};
+use uuid::Uuid;
+use crate::di::service_container::ServiceContainer;
+use crate::events::event_bus::EventBus;
+use crate::features::banking::application::commands::remove_bank_connection::command::RemoveBankConnectionCommand;
+use crate::features::banking::application::commands::remove_bank_connection::handler::RemoveBankConnectionCommandHandler;
+use crate::features::banking::infrastructure::db_bank_connection_repository::DbBankConnectionRepository;
+use crate::http::error::HttpError;
+use crate::http::extractors::jwt::Jwt;
+use crate::services::jwt::JwtService;
+
+/// Stops syncing the connection, operations already synced are kept.
+#[delete("/{id}")]
+pub async fn remove_bank_connection(
+    bank_connection_id: Path<Uuid>,
+    jwt: Jwt,
+    service_container: Data<Arc<ServiceContainer>>,
+    event_bus: Data<Arc<Box<dyn EventBus>>>
+) -> Result<impl Responder, HttpError> {
+    let jwt_service = service_container.jwt_service();
+    let claims = jwt_service.verify(jwt.0.as_str()).map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+    let user_id = claims.user_id().map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+
+    let rep = DbBankConnectionRepository::new(service_container.db_manager());
+
+    let command = RemoveBankConnectionCommand::new(bank_connection_id.into_inner(), user_id);
+    let handler = RemoveBankConnectionCommandHandler::new(rep);
+
+    let mut command_bus = service_container.command_bus();
+    command_bus.register(handler);
+    let events = command_bus.dispatch(command)
+        .await
+        .map_err(HttpError::Feature)?;
+
+    for event in events {
+        event_bus.publish(event)
+            .await
+            .map_err(HttpError::Event)?;
+    }
+
+    Ok(
+        HttpResponse::Ok()
+    )
+}
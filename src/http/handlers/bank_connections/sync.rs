@@ -0,0 +1,39 @@
+use std::sync::Arc;
+use actix_web::{HttpResponse, post, Responder};
+use actix_web::web::{Data, Path};
+use uuid::Uuid;
+use crate::di::service_container::ServiceContainer;
+use crate::events::error::EventError;
+use crate::events::event_bus::EventBus;
+use crate::features::banking::infrastructure::bank_sync_worker::BankSyncWorker;
+use crate::http::error::HttpError;
+use crate::http::extractors::jwt::Jwt;
+use crate::services::jwt::JwtService;
+
+/// Syncs the connection right away instead of waiting for the next run of the worker.
+#[post("/{id}/sync")]
+pub async fn sync_bank_connection(
+    bank_connection_id: Path<Uuid>,
+    jwt: Jwt,
+    service_container: Data<Arc<ServiceContainer>>,
+    event_bus: Data<Arc<Box<dyn EventBus>>>
+) -> Result<impl Responder, HttpError> {
+    let jwt_service = service_container.jwt_service();
+    let claims = jwt_service.verify(jwt.0.as_str()).map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+    let user_id = claims.user_id().map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+
+    BankSyncWorker::sync(service_container.get_ref(), event_bus.get_ref(), bank_connection_id.into_inner(), Some(user_id))
+        .await
+        .map_err(|e| match e {
+            EventError::Feature(e) => HttpError::Feature(e),
+            e => HttpError::Event(e),
+        })?;
+
+    Ok(
+        HttpResponse::Ok()
+    )
+}
@@ -0,0 +1,79 @@
+use std::sync::Arc;
+use actix_web::{HttpResponse, post, Responder};
+use actix_web::web::{Data, Json};
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use uuid::Uuid;
+use crate::di::service_container::ServiceContainer;
+use crate::events::event_bus::EventBus;
+use crate::features::budgets::application::commands::create_budget::command::CreateBudgetCommand;
+use crate::features::budgets::application::commands::create_budget::handler::CreateBudgetCommandHandler;
+use crate::features::budgets::infrastructure::db_budget_repository::DbBudgetRepository;
+use crate::http::error::HttpError;
+use crate::http::extractors::jwt::Jwt;
+use crate::services::jwt::JwtService;
+
+#[derive(Debug, Clone, Deserialize)]
+struct RequestData {
+    category_id: Uuid,
+    period: String,
+    currency: String,
+    limit: Decimal,
+    #[serde(default = "default_rollover")]
+    rollover: String,
+    // Percentages from the budgets config are used when omitted
+    thresholds: Option<Vec<u32>>,
+    starts_on: NaiveDate,
+}
+
+fn default_rollover() -> String {
+    "None".to_string()
+}
+
+#[post("/create")]
+pub async fn create_budget(
+    request_data: Json<RequestData>,
+    jwt: Jwt,
+    service_container: Data<Arc<ServiceContainer>>,
+    event_bus: Data<Arc<Box<dyn EventBus>>>,
+) -> Result<impl Responder, HttpError> {
+    let jwt_service = service_container.jwt_service();
+    let claims = jwt_service.verify(jwt.0.as_str()).map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+    let user_id = claims.user_id().map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+
+    let rep = DbBudgetRepository::new(service_container.db_manager());
+
+    let command = CreateBudgetCommand::new(
+        user_id,
+        request_data.category_id,
+        request_data.period.clone(),
+        request_data.currency.clone(),
+        request_data.limit,
+        request_data.rollover.clone(),
+        request_data.thresholds.clone()
+            .unwrap_or_else(|| service_container.config().budgets().thresholds().to_vec()),
+        request_data.starts_on,
+    );
+    let handler = CreateBudgetCommandHandler::new(rep);
+
+    let mut command_bus = service_container.command_bus();
+    command_bus.register(handler);
+    let events = command_bus.dispatch(command)
+        .await
+        .map_err(HttpError::Feature)?;
+
+    for event in events {
+        event_bus.publish(event)
+            .await
+            .map_err(HttpError::Event)?;
+    }
+
+    Ok(
+        HttpResponse::Ok()
+    )
+}
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+use actix_web::{get, HttpResponse, Responder};
+use actix_web::web::{Data, Query};
+use chrono::{NaiveDate, Utc};
+use serde::Deserialize;
+use crate::di::service_container::ServiceContainer;
+use crate::features::budgets::application::queries::list_budgets::handler::ListBudgetsQueryHandler;
+use crate::features::budgets::application::queries::list_budgets::query::ListBudgetsQuery;
+use crate::features::budgets::infrastructure::db_budget_projection_repository::DbBudgetProjectionRepository;
+use crate::http::error::HttpError;
+use crate::http::extractors::jwt::Jwt;
+use crate::services::jwt::JwtService;
+
+#[derive(Deserialize)]
+struct RequestData {
+    date: Option<NaiveDate>,
+}
+
+#[get("")]
+pub async fn list_budgets(
+    request_data: Query<RequestData>,
+    jwt: Jwt,
+    service_container: Data<Arc<ServiceContainer>>,
+) -> Result<impl Responder, HttpError> {
+    let jwt_service = service_container.jwt_service();
+    let claims = jwt_service.verify(jwt.0.as_str()).map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+    let user_id = claims.user_id().map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+
+    let rep = DbBudgetProjectionRepository::new(service_container.db_manager());
+    let handler = ListBudgetsQueryHandler::new(rep);
+
+    let query = ListBudgetsQuery::new(
+        user_id,
+        request_data.date.unwrap_or_else(|| Utc::now().date_naive()),
+    );
+
+    let mut query_bus = service_container.query_bus();
+    query_bus.register(handler);
+    let budgets = query_bus.dispatch(query)
+        .await
+        .map_err(HttpError::Feature)?;
+
+    Ok(HttpResponse::Ok().json(budgets))
+}
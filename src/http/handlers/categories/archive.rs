@@ -0,0 +1,49 @@
+use std::sync::Arc;
+use actix_web::{put, HttpResponse, Responder};
+use actix_web::web::{Data, Path};
+use uuid::Uuid;
+use crate::di::service_container::ServiceContainer;
+use crate::events::event_bus::EventBus;
+use crate::features::categories::application::commands::archive_category::command::ArchiveCategoryCommand;
+use crate::features::categories::application::commands::archive_category::handler::ArchiveCategoryCommandHandler;
+use crate::features::categories::infrastructure::db_category_repository::DbCategoryRepository;
+use crate::http::error::HttpError;
+use crate::http::extractors::jwt::Jwt;
+use crate::services::jwt::JwtService;
+
+#[put("/{id}/archive")]
+pub async fn archive_category(
+    category_id: Path<Uuid>,
+    jwt: Jwt,
+    service_container: Data<Arc<ServiceContainer>>,
+    event_bus: Data<Arc<Box<dyn EventBus>>>
+) -> Result<impl Responder, HttpError> {
+    let jwt_service = service_container.jwt_service();
+    let claims = jwt_service.verify(jwt.0.as_str()).map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+    let user_id = claims.user_id().map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+
+    let rep = DbCategoryRepository::new(service_container.db_manager(), service_container.serializer());
+
+    let command = ArchiveCategoryCommand::new(category_id.into_inner(), user_id);
+    let handler = ArchiveCategoryCommandHandler::new(rep);
+
+    let mut command_bus = service_container.command_bus();
+    command_bus.register(handler);
+    let events = command_bus.dispatch(command)
+        .await
+        .map_err(HttpError::Feature)?;
+
+    for event in events {
+        event_bus.publish(event)
+            .await
+            .map_err(HttpError::Event)?;
+    }
+
+    Ok(
+        HttpResponse::Ok()
+    )
+}
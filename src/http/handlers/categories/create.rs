@@ -2,6 +2,7 @@ use std::sync::Arc;
 use actix_web::{HttpResponse, post, Responder};
 use actix_web::web::{Data, Json};
 use serde::Deserialize;
+use uuid::Uuid;
 use crate::di::service_container::ServiceContainer;
 use crate::events::event_bus::EventBus;
 use crate::features::categories::application::commands::create_category::command::CreateCategoryCommand;
@@ -14,7 +15,8 @@ use crate::services::jwt::JwtService;
 #[derive(Deserialize)]
 struct RequestData {
     name: String,
-    icon: Option<String>
+    icon: Option<String>,
+    parent_id: Option<Uuid>,
 }
 
 #[post("/create")]
@@ -38,7 +40,7 @@ pub async fn create_category(
     let db_manager = service_container.db_manager();
     let rep = DbCategoryRepository::new(db_manager.clone(), service_container.serializer());
 
-    let command = CreateCategoryCommand::new(user_id, request_data.name.clone(), request_data.icon.clone());
+    let command = CreateCategoryCommand::new(user_id, request_data.name.clone(), request_data.icon.clone(), request_data.parent_id);
     let handler = CreateCategoryCommandHandler::new(rep);
 
     let mut command_bus = service_container.command_bus();
@@ -0,0 +1,59 @@
+use std::sync::Arc;
+use actix_web::{delete, HttpResponse, Responder};
+use actix_web::web::{Data, Query, Path};
+use serde::Deserialize;
+use uuid::Uuid;
+use crate::di::service_container::ServiceContainer;
+use crate::events::event_bus::EventBus;
+use crate::features::categories::application::commands::delete_category::command::DeleteCategoryCommand;
+use crate::features::categories::application::commands::delete_category::handler::DeleteCategoryCommandHandler;
+use crate::features::categories::infrastructure::db_category_projection_repository::DbCategoryProjectionRepository;
+use crate::features::categories::infrastructure::db_category_repository::DbCategoryRepository;
+use crate::http::error::HttpError;
+use crate::http::extractors::jwt::Jwt;
+use crate::services::jwt::JwtService;
+
+#[derive(Deserialize)]
+struct RequestData {
+    // Category that receives the operations of the deleted one
+    fallback_category_id: Uuid,
+}
+
+#[delete("/{id}")]
+pub async fn delete_category(
+    category_id: Path<Uuid>,
+    request_data: Query<RequestData>,
+    jwt: Jwt,
+    service_container: Data<Arc<ServiceContainer>>,
+    event_bus: Data<Arc<Box<dyn EventBus>>>
+) -> Result<impl Responder, HttpError> {
+    let jwt_service = service_container.jwt_service();
+    let claims = jwt_service.verify(jwt.0.as_str()).map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+    let user_id = claims.user_id().map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+
+    let rep = DbCategoryRepository::new(service_container.db_manager(), service_container.serializer());
+    let projection_rep = DbCategoryProjectionRepository::new(service_container.db_manager());
+
+    let command = DeleteCategoryCommand::new(category_id.into_inner(), user_id, request_data.fallback_category_id);
+    let handler = DeleteCategoryCommandHandler::new(rep, projection_rep);
+
+    let mut command_bus = service_container.command_bus();
+    command_bus.register(handler);
+    let events = command_bus.dispatch(command)
+        .await
+        .map_err(HttpError::Feature)?;
+
+    for event in events {
+        event_bus.publish(event)
+            .await
+            .map_err(HttpError::Event)?;
+    }
+
+    Ok(
+        HttpResponse::Ok()
+    )
+}
@@ -0,0 +1,44 @@
+use std::sync::Arc;
+use actix_web::{get, HttpResponse, Responder};
+use actix_web::web::{Data, Query};
+use serde::Deserialize;
+use crate::di::service_container::ServiceContainer;
+use crate::features::categories::application::queries::list_categories::handler::ListCategoriesQueryHandler;
+use crate::features::categories::application::queries::list_categories::query::ListCategoriesQuery;
+use crate::features::categories::infrastructure::db_category_projection_repository::DbCategoryProjectionRepository;
+use crate::http::error::HttpError;
+use crate::http::extractors::jwt::Jwt;
+use crate::services::jwt::JwtService;
+
+#[derive(Deserialize)]
+struct RequestData {
+    include_archived: Option<bool>,
+}
+
+#[get("")]
+pub async fn list_categories(
+    request_data: Query<RequestData>,
+    jwt: Jwt,
+    service_container: Data<Arc<ServiceContainer>>,
+) -> Result<impl Responder, HttpError> {
+    let jwt_service = service_container.jwt_service();
+    let claims = jwt_service.verify(jwt.0.as_str()).map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+    let user_id = claims.user_id().map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+
+    let rep = DbCategoryProjectionRepository::new(service_container.db_manager());
+    let handler = ListCategoriesQueryHandler::new(rep);
+
+    let query = ListCategoriesQuery::new(user_id, request_data.include_archived.unwrap_or(false));
+
+    let mut query_bus = service_container.query_bus();
+    query_bus.register(handler);
+    let categories = query_bus.dispatch(query)
+        .await
+        .map_err(HttpError::Feature)?;
+
+    Ok(HttpResponse::Ok().json(categories))
+}
@@ -1 +1,8 @@
-pub mod create;
\ No newline at end of file
+pub mod archive;
+pub mod change_icon;
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod move_category;
+pub mod rename;
+pub mod report;
\ No newline at end of file
@@ -0,0 +1,47 @@
+use std::sync::Arc;
+use actix_web::{get, HttpResponse, Responder};
+use actix_web::web::{Data, Query};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use crate::di::service_container::ServiceContainer;
+use crate::features::categories::application::queries::category_report::handler::CategoryReportQueryHandler;
+use crate::features::categories::application::queries::category_report::query::CategoryReportQuery;
+use crate::features::categories::infrastructure::db_category_projection_repository::DbCategoryProjectionRepository;
+use crate::http::error::HttpError;
+use crate::http::extractors::jwt::Jwt;
+use crate::services::jwt::JwtService;
+
+#[derive(Deserialize)]
+struct RequestData {
+    kind: Option<String>,
+    date_from: Option<DateTime<Utc>>,
+    date_to: Option<DateTime<Utc>>,
+}
+
+#[get("/report")]
+pub async fn category_report(
+    request_data: Query<RequestData>,
+    jwt: Jwt,
+    service_container: Data<Arc<ServiceContainer>>,
+) -> Result<impl Responder, HttpError> {
+    let jwt_service = service_container.jwt_service();
+    let claims = jwt_service.verify(jwt.0.as_str()).map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+    let user_id = claims.user_id().map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+
+    let rep = DbCategoryProjectionRepository::new(service_container.db_manager());
+    let handler = CategoryReportQueryHandler::new(rep);
+
+    let query = CategoryReportQuery::new(user_id, request_data.kind.clone(), request_data.date_from, request_data.date_to);
+
+    let mut query_bus = service_container.query_bus();
+    query_bus.register(handler);
+    let report = query_bus.dispatch(query)
+        .await
+        .map_err(HttpError::Feature)?;
+
+    Ok(HttpResponse::Ok().json(report))
+}
@@ -0,0 +1,35 @@
+use std::sync::Arc;
+use actix_web::{get, HttpResponse, Responder};
+use actix_web::web::Data;
+use serde::Serialize;
+use crate::di::service_container::ServiceContainer;
+use crate::http::error::HttpError;
+use crate::support::currency::Currency;
+
+#[derive(Serialize)]
+struct CurrencyView {
+    code: &'static str,
+    symbol: &'static str,
+    minor_units: u32,
+    name: &'static str,
+}
+
+#[get("")]
+pub async fn list_currencies(
+    service_container: Data<Arc<ServiceContainer>>,
+) -> Result<impl Responder, HttpError> {
+    let locale = service_container.config().general().locale();
+
+    let currencies: Vec<CurrencyView> = Currency::all()
+        .map(|currency|
+            CurrencyView {
+                code: currency.code(),
+                symbol: currency.symbol(),
+                minor_units: currency.minor_units(),
+                name: currency.name(locale),
+            }
+        )
+        .collect();
+
+    Ok(HttpResponse::Ok().json(currencies))
+}
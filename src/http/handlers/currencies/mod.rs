@@ -0,0 +1 @@
+pub mod list;
\ No newline at end of file
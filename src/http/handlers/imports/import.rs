@@ -0,0 +1,163 @@
+use std::sync::Arc;
+use actix_multipart::Multipart;
+use actix_web::{post, HttpResponse, Responder};
+use actix_web::web::Data;
+use futures_util::{StreamExt, TryStreamExt};
+use uuid::Uuid;
+use crate::di::service_container::ServiceContainer;
+use crate::events::event::Event;
+use crate::events::event_bus::EventBus;
+use crate::features::accounts::infrastructure::db_account_projection_repository::DbAccountProjectionRepository;
+use crate::features::imports::application::commands::import_statement::command::ImportStatementCommand;
+use crate::features::imports::application::commands::import_statement::handler::ImportStatementCommandHandler;
+use crate::features::imports::application::queries::preview_statement::handler::PreviewStatementQueryHandler;
+use crate::features::imports::application::queries::preview_statement::query::PreviewStatementQuery;
+use crate::features::imports::domain::column_mapping::ColumnMapping;
+use crate::features::imports::domain::events::statement_import_event::StatementImportEvent;
+use crate::features::imports::domain::import_row::ImportRow;
+use crate::features::imports::infrastructure::db_fingerprint_source::DbFingerprintSource;
+use crate::features::imports::infrastructure::db_statement_import_repository::DbStatementImportRepository;
+use crate::features::operations::infrastructure::command_operation_creator::CommandOperationCreator;
+use crate::features::operations::infrastructure::projection_account_source::ProjectionAccountSource;
+use crate::http::error::HttpError;
+use crate::http::extractors::jwt::Jwt;
+use crate::services::jwt::JwtService;
+
+/// Fields of the multipart form a statement is uploaded with.
+#[derive(Default)]
+struct RequestData {
+    file: Option<Vec<u8>>,
+    format: Option<String>,
+    account_id: Option<Uuid>,
+    mapping: Option<ColumnMapping>,
+    category: Option<String>,
+    dry_run: bool,
+}
+
+impl RequestData {
+    async fn read(mut payload: Multipart, max_file_size: usize) -> Result<Self, HttpError> {
+        let mut data = Self::default();
+
+        while let Some(mut field) = payload.try_next()
+            .await
+            .map_err(|e| HttpError::RequestValidation(e.to_string()))?
+        {
+            let name = field.name().unwrap_or_default().to_string();
+
+            let mut value = vec![];
+            while let Some(chunk) = field.next().await {
+                let chunk = chunk.map_err(|e| HttpError::RequestValidation(e.to_string()))?;
+
+                if value.len() + chunk.len() > max_file_size {
+                    return Err(
+                        HttpError::RequestValidation(format!("Field {} exceeds {} bytes", name, max_file_size))
+                    );
+                }
+
+                value.extend_from_slice(&chunk);
+            }
+
+            if name == "file" {
+                data.file = Some(value);
+                continue;
+            }
+
+            let value = String::from_utf8(value)
+                .map_err(|e| HttpError::RequestValidation(format!("Invalid field {}. {}", name, e)))?;
+
+            match name.as_str() {
+                "format" => data.format = Some(value),
+                "account_id" => data.account_id = Some(
+                    Uuid::parse_str(value.trim())
+                        .map_err(|e| HttpError::RequestValidation(format!("Invalid account id. {}", e)))?
+                ),
+                "mapping" => data.mapping = Some(
+                    serde_json::from_str(&value)
+                        .map_err(|e| HttpError::RequestValidation(format!("Invalid column mapping. {}", e)))?
+                ),
+                "category" => data.category = Some(value),
+                "dry_run" => data.dry_run = value.trim() == "true",
+                _ => {}
+            }
+        }
+
+        Ok(data)
+    }
+}
+
+#[post("/statement")]
+pub async fn import_statement(
+    payload: Multipart,
+    jwt: Jwt,
+    service_container: Data<Arc<ServiceContainer>>,
+    event_bus: Data<Arc<Box<dyn EventBus>>>,
+) -> Result<impl Responder, HttpError> {
+    let jwt_service = service_container.jwt_service();
+    let claims = jwt_service.verify(jwt.0.as_str()).map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+    let user_id = claims.user_id().map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+
+    let config = service_container.config();
+    let request_data = RequestData::read(payload, config.imports().max_file_size()).await?;
+
+    let file = request_data.file
+        .ok_or(HttpError::RequestValidation("Statement file is required".to_string()))?;
+    let format = request_data.format
+        .ok_or(HttpError::RequestValidation("Statement format is required".to_string()))?;
+    let account_id = request_data.account_id
+        .ok_or(HttpError::RequestValidation("Account id is required".to_string()))?;
+
+    let db_manager = service_container.db_manager();
+    let accounts = ProjectionAccountSource::new(DbAccountProjectionRepository::new(db_manager.clone()));
+    let fingerprints = DbFingerprintSource::new(db_manager.clone());
+
+    if request_data.dry_run {
+        let query = PreviewStatementQuery::new(user_id, account_id, format, file, request_data.mapping);
+
+        let mut query_bus = service_container.query_bus();
+        query_bus.register(PreviewStatementQueryHandler::new(accounts, fingerprints));
+        let rows = query_bus.dispatch(query)
+            .await
+            .map_err(HttpError::Feature)?;
+
+        return Ok(HttpResponse::Ok().json(rows));
+    }
+
+    let command = ImportStatementCommand::new(
+        user_id,
+        account_id,
+        format,
+        file,
+        request_data.mapping,
+        request_data.category.unwrap_or_else(|| config.imports().default_category().to_string()),
+    );
+    let handler = ImportStatementCommandHandler::new(
+        DbStatementImportRepository::new(db_manager),
+        accounts,
+        fingerprints,
+        CommandOperationCreator::new(service_container.get_ref().clone()),
+    );
+
+    let mut command_bus = service_container.command_bus();
+    command_bus.register(handler);
+    let events = command_bus.dispatch(command)
+        .await
+        .map_err(HttpError::Feature)?;
+
+    let mut rows: Vec<ImportRow> = vec![];
+
+    for event in events {
+        if let Event::StatementImportEvent(StatementImportEvent::StatementImported(imported)) = &event {
+            rows = imported.payload().rows().to_vec();
+        }
+
+        event_bus.publish(event)
+            .await
+            .map_err(HttpError::Event)?;
+    }
+
+    Ok(HttpResponse::Ok().json(rows))
+}
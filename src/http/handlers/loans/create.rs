@@ -0,0 +1,72 @@
+use std::sync::Arc;
+use actix_web::{HttpResponse, post, Responder};
+use actix_web::web::{Data, Json};
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use crate::di::service_container::ServiceContainer;
+use crate::events::event_bus::EventBus;
+use crate::features::loans::application::commands::create_loan::command::CreateLoanCommand;
+use crate::features::loans::application::commands::create_loan::handler::CreateLoanCommandHandler;
+use crate::features::loans::infrastructure::db_loan_repository::DbLoanRepository;
+use crate::http::error::HttpError;
+use crate::http::extractors::jwt::Jwt;
+use crate::services::jwt::JwtService;
+
+#[derive(Debug, Clone, Deserialize)]
+struct RequestData {
+    direction: String,
+    counterparty: String,
+    principal: Decimal,
+    currency: String,
+    #[serde(default)]
+    interest_rate: Decimal,
+    issued_on: NaiveDate,
+    term_months: u32,
+}
+
+#[post("/create")]
+pub async fn create_loan(
+    request_data: Json<RequestData>,
+    jwt: Jwt,
+    service_container: Data<Arc<ServiceContainer>>,
+    event_bus: Data<Arc<Box<dyn EventBus>>>,
+) -> Result<impl Responder, HttpError> {
+    let jwt_service = service_container.jwt_service();
+    let claims = jwt_service.verify(jwt.0.as_str()).map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+    let user_id = claims.user_id().map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+
+    let rep = DbLoanRepository::new(service_container.db_manager());
+
+    let command = CreateLoanCommand::new(
+        user_id,
+        request_data.direction.clone(),
+        request_data.counterparty.clone(),
+        request_data.principal,
+        request_data.currency.clone(),
+        request_data.interest_rate,
+        request_data.issued_on,
+        request_data.term_months,
+    );
+    let handler = CreateLoanCommandHandler::new(rep);
+
+    let mut command_bus = service_container.command_bus();
+    command_bus.register(handler);
+    let events = command_bus.dispatch(command)
+        .await
+        .map_err(HttpError::Feature)?;
+
+    for event in events {
+        event_bus.publish(event)
+            .await
+            .map_err(HttpError::Event)?;
+    }
+
+    Ok(
+        HttpResponse::Ok()
+    )
+}
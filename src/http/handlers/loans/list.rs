@@ -0,0 +1,51 @@
+use std::sync::Arc;
+use actix_web::{get, HttpResponse, Responder};
+use actix_web::web::{Data, Query};
+use chrono::{NaiveDate, Utc};
+use serde::Deserialize;
+use crate::di::service_container::ServiceContainer;
+use crate::features::loans::application::queries::list_loans::handler::ListLoansQueryHandler;
+use crate::features::loans::application::queries::list_loans::query::ListLoansQuery;
+use crate::features::loans::infrastructure::db_loan_projection_repository::DbLoanProjectionRepository;
+use crate::http::error::HttpError;
+use crate::http::extractors::jwt::Jwt;
+use crate::services::jwt::JwtService;
+
+#[derive(Deserialize)]
+struct RequestData {
+    date: Option<NaiveDate>,
+    #[serde(default)]
+    overdue: bool,
+}
+
+#[get("")]
+pub async fn list_loans(
+    request_data: Query<RequestData>,
+    jwt: Jwt,
+    service_container: Data<Arc<ServiceContainer>>,
+) -> Result<impl Responder, HttpError> {
+    let jwt_service = service_container.jwt_service();
+    let claims = jwt_service.verify(jwt.0.as_str()).map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+    let user_id = claims.user_id().map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+
+    let rep = DbLoanProjectionRepository::new(service_container.db_manager());
+    let handler = ListLoansQueryHandler::new(rep);
+
+    let query = ListLoansQuery::new(
+        user_id,
+        request_data.date.unwrap_or_else(|| Utc::now().date_naive()),
+        request_data.overdue,
+    );
+
+    let mut query_bus = service_container.query_bus();
+    query_bus.register(handler);
+    let loans = query_bus.dispatch(query)
+        .await
+        .map_err(HttpError::Feature)?;
+
+    Ok(HttpResponse::Ok().json(loans))
+}
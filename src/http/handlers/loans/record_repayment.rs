@@ -0,0 +1,61 @@
+use std::sync::Arc;
+use actix_web::{post, HttpResponse, Responder};
+use actix_web::web::{Data, Json, Path};
+use serde::Deserialize;
+use uuid::Uuid;
+use crate::di::service_container::ServiceContainer;
+use crate::events::event_bus::EventBus;
+use crate::features::loans::application::commands::record_repayment::command::RecordRepaymentCommand;
+use crate::features::loans::application::commands::record_repayment::handler::RecordRepaymentCommandHandler;
+use crate::features::loans::infrastructure::db_loan_repository::DbLoanRepository;
+use crate::features::loans::infrastructure::projection_operation_source::ProjectionOperationSource;
+use crate::features::operations::infrastructure::db_operation_projection_repository::DbOperationProjectionRepository;
+use crate::http::error::HttpError;
+use crate::http::extractors::jwt::Jwt;
+use crate::services::jwt::JwtService;
+
+#[derive(Deserialize)]
+struct RequestData {
+    operation_id: Uuid,
+}
+
+#[post("/{id}/repayments")]
+pub async fn record_repayment(
+    loan_id: Path<Uuid>,
+    request_data: Json<RequestData>,
+    jwt: Jwt,
+    service_container: Data<Arc<ServiceContainer>>,
+    event_bus: Data<Arc<Box<dyn EventBus>>>
+) -> Result<impl Responder, HttpError> {
+    let jwt_service = service_container.jwt_service();
+    let claims = jwt_service.verify(jwt.0.as_str()).map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+    let user_id = claims.user_id().map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+
+    let rep = DbLoanRepository::new(service_container.db_manager());
+    let operations = ProjectionOperationSource::new(
+        DbOperationProjectionRepository::new(service_container.db_manager())
+    );
+
+    let command = RecordRepaymentCommand::new(loan_id.into_inner(), user_id, request_data.operation_id);
+    let handler = RecordRepaymentCommandHandler::new(rep, operations);
+
+    let mut command_bus = service_container.command_bus();
+    command_bus.register(handler);
+    let events = command_bus.dispatch(command)
+        .await
+        .map_err(HttpError::Feature)?;
+
+    for event in events {
+        event_bus.publish(event)
+            .await
+            .map_err(HttpError::Event)?;
+    }
+
+    Ok(
+        HttpResponse::Ok()
+    )
+}
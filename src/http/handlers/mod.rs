@@ -1,5 +1,15 @@
 pub mod errors;
+pub mod accounts;
 pub mod auth;
+pub mod balance;
+pub mod bank_connections;
+pub mod budgets;
 pub mod categories;
+pub mod currencies;
+pub mod imports;
+pub mod loans;
 pub mod operations;
+pub mod recurring_operations;
+pub mod reports;
+pub mod rules;
 pub mod tags;
\ No newline at end of file
@@ -0,0 +1,111 @@
+use std::sync::Arc;
+use actix_multipart::Multipart;
+use actix_web::{post, HttpResponse, Responder};
+use actix_web::web::{Data, Path};
+use futures_util::{StreamExt, TryStreamExt};
+use uuid::Uuid;
+use crate::di::service_container::ServiceContainer;
+use crate::events::event::Event;
+use crate::events::event_bus::EventBus;
+use crate::features::operations::application::commands::add_attachment::command::AddAttachmentCommand;
+use crate::features::operations::application::commands::add_attachment::handler::AddAttachmentCommandHandler;
+use crate::features::operations::application::queries::operation_view::AttachmentView;
+use crate::features::operations::domain::events::operation_event::OperationEvent;
+use crate::features::operations::infrastructure::db_operation_repository::DbOperationRepository;
+use crate::http::error::HttpError;
+use crate::http::extractors::jwt::Jwt;
+use crate::services::jwt::JwtService;
+
+/// The `file` field of the multipart form with the name the client gave it.
+async fn read_file(mut payload: Multipart, max_file_size: usize) -> Result<(String, Vec<u8>), HttpError> {
+    while let Some(mut field) = payload.try_next()
+        .await
+        .map_err(|e| HttpError::RequestValidation(e.to_string()))?
+    {
+        if field.name() != Some("file") {
+            continue;
+        }
+
+        let file_name = field.content_disposition()
+            .and_then(|disposition| disposition.get_filename())
+            .unwrap_or_default()
+            .to_string();
+
+        let mut content = vec![];
+        while let Some(chunk) = field.next().await {
+            let chunk = chunk.map_err(|e| HttpError::RequestValidation(e.to_string()))?;
+
+            if content.len() + chunk.len() > max_file_size {
+                return Err(
+                    HttpError::RequestValidation(format!("File exceeds {} bytes", max_file_size))
+                );
+            }
+
+            content.extend_from_slice(&chunk);
+        }
+
+        return Ok((file_name, content));
+    }
+
+    Err(HttpError::RequestValidation("File is required".to_string()))
+}
+
+#[post("/{id}/attachments")]
+pub async fn add_attachment(
+    payload: Multipart,
+    jwt: Jwt,
+    operation_id: Path<Uuid>,
+    service_container: Data<Arc<ServiceContainer>>,
+    event_bus: Data<Arc<Box<dyn EventBus>>>,
+) -> Result<impl Responder, HttpError> {
+    let jwt_service = service_container.jwt_service();
+    let user_id = jwt_service.verify(jwt.0.as_str())
+        .map_err(|e|
+            HttpError::Service(e.to_string())
+        )?
+        .user_id()
+        .map_err(|e|
+            HttpError::Service(e.to_string())
+        )?;
+
+    let config = service_container.config().storage();
+    let (file_name, content) = read_file(payload, config.max_file_size()).await?;
+
+    let storage = service_container.storage()
+        .map_err(|e|
+            HttpError::Service(e.to_string())
+        )?;
+    let rep = DbOperationRepository::new(service_container.db_manager(), service_container.serializer());
+
+    let command = AddAttachmentCommand::new(operation_id.into_inner(), user_id, file_name, content);
+    let handler = AddAttachmentCommandHandler::new(rep, storage, config.max_file_size(), config.content_types().to_vec());
+
+    let mut command_bus = service_container.command_bus();
+    command_bus.register(handler);
+    let events = command_bus.dispatch(command)
+        .await
+        .map_err(HttpError::Feature)?;
+
+    let mut attachment: Option<AttachmentView> = None;
+
+    for event in events {
+        if let Event::OperationEvent(OperationEvent::AttachmentAdded(added)) = &event {
+            let added = added.payload().attachment();
+
+            attachment = Some(
+                AttachmentView::new(
+                    added.id().value(),
+                    added.file_name().to_string(),
+                    added.content_type().to_string(),
+                    added.size(),
+                    *added.created_at(),
+                )
+            );
+        }
+
+        event_bus.publish(event).await
+            .map_err(HttpError::Event)?;
+    }
+
+    Ok(HttpResponse::Ok().json(attachment))
+}
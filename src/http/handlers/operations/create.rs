@@ -1,27 +1,41 @@
 use std::sync::Arc;
 use actix_web::{HttpResponse, post, Responder};
 use actix_web::web::{Data, Json};
+use rust_decimal::Decimal;
 use uuid::Uuid;
 use crate::features::operations::infrastructure::db_operation_repository::DbOperationRepository;
 use crate::di::service_container::ServiceContainer;
 use crate::services::jwt::JwtService;
 use crate::events::event_bus::EventBus;
-use crate::features::operations::application::commands::create_operation::command::{CreateOperationCommand, TagData};
+use crate::features::accounts::infrastructure::db_account_projection_repository::DbAccountProjectionRepository;
+use crate::features::operations::application::commands::create_operation::command::{CreateOperationCommand, LineData, TagData, TransferData};
 use crate::features::operations::application::commands::create_operation::handler::CreateOperationCommandHandler;
+use crate::features::operations::infrastructure::projection_account_source::ProjectionAccountSource;
+use crate::features::operations::infrastructure::query_categorization_source::QueryCategorizationSource;
+use crate::features::rates::application::queries::find_rate::handler::FindRateQueryHandler;
+use crate::features::rates::infrastructure::db_rate_repository::DbRateRepository;
+use crate::features::rates::infrastructure::query_rate_source::QueryRateSource;
+use crate::features::rules::application::queries::match_rule::handler::MatchRuleQueryHandler;
+use crate::features::rules::infrastructure::db_rule_projection_repository::DbRuleProjectionRepository;
+use crate::support::error::FeatureError;
 use crate::http::error::HttpError;
 use crate::http::extractors::jwt::Jwt;
 
 #[derive(serde::Deserialize)]
 struct RequestData {
     kind: String,
+    account_id: Uuid,
     category_id: Option<Uuid>,
     category_name: String,
-    amount: f64,
+    amount: Option<Decimal>,
     currency: String,
-    currency_amount: f64,
-    rate: f64,
+    currency_amount: Decimal,
+    rate: Option<Decimal>,
     label: String,
     tags: Vec<RequestTagData>,
+    transfer: Option<RequestTransferData>,
+    #[serde(default)]
+    lines: Vec<RequestLineData>,
 }
 
 #[derive(serde::Deserialize)]
@@ -30,6 +44,22 @@ struct RequestTagData {
     name: String,
 }
 
+#[derive(serde::Deserialize)]
+struct RequestTransferData {
+    account_id: Uuid,
+    amount: Option<Decimal>,
+}
+
+#[derive(serde::Deserialize)]
+struct RequestLineData {
+    category_id: Uuid,
+    currency_amount: Decimal,
+    #[serde(default)]
+    label: String,
+    #[serde(default)]
+    tag_ids: Vec<Uuid>,
+}
+
 impl RequestData {
     fn to_command(&self, user_id: Uuid) -> CreateOperationCommand {
         let tags = self.tags.iter().map(|tag| TagData::new(
@@ -37,9 +67,10 @@ impl RequestData {
             tag.name.clone(),
         )).collect();
 
-        CreateOperationCommand::new(
+        let command = CreateOperationCommand::new(
             self.kind.clone(),
             user_id,
+            self.account_id,
             self.category_id,
             self.category_name.clone(),
             self.amount,
@@ -48,7 +79,20 @@ impl RequestData {
             self.rate,
             self.label.clone(),
             tags,
-        )
+        );
+
+        let lines = self.lines.iter().map(|line| LineData::new(
+            line.category_id,
+            line.currency_amount,
+            line.label.clone(),
+            line.tag_ids.clone(),
+        )).collect();
+        let command = command.with_lines(lines);
+
+        match &self.transfer {
+            Some(transfer) => command.with_transfer(TransferData::new(transfer.account_id, transfer.amount)),
+            None => command,
+        }
     }
 }
 
@@ -70,10 +114,22 @@ pub async fn create_operation(
         )?;
 
     let db_manager = service_container.db_manager();
-    let rep = DbOperationRepository::new(db_manager, service_container.serializer());
+    let rep = DbOperationRepository::new(db_manager.clone(), service_container.serializer());
+
+    let rate_provider = service_container.rate_provider()
+        .map_err(|e|
+            HttpError::Feature(FeatureError::Rate(e))
+        )?;
+    let rates = QueryRateSource::new(
+        FindRateQueryHandler::new(DbRateRepository::new(db_manager.clone()), rate_provider),
+        service_container.config().rates().base_currency().to_string(),
+    );
+
+    let accounts = ProjectionAccountSource::new(DbAccountProjectionRepository::new(db_manager.clone()));
+    let categorizations = QueryCategorizationSource::new(MatchRuleQueryHandler::new(DbRuleProjectionRepository::new(db_manager)));
 
     let command = request_data.to_command(user_id);
-    let handler = CreateOperationCommandHandler::new(rep);
+    let handler = CreateOperationCommandHandler::new(rep, rates, accounts, categorizations);
 
     let mut command_bus = service_container.command_bus();
     command_bus.register(handler);
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+use actix_web::{delete, HttpResponse, Responder};
+use actix_web::web::{Data, Path};
+use uuid::Uuid;
+use crate::di::service_container::ServiceContainer;
+use crate::events::event_bus::EventBus;
+use crate::features::operations::application::commands::delete_operation::command::DeleteOperationCommand;
+use crate::features::operations::application::commands::delete_operation::handler::DeleteOperationCommandHandler;
+use crate::features::operations::infrastructure::db_operation_repository::DbOperationRepository;
+use crate::http::error::HttpError;
+use crate::http::extractors::jwt::Jwt;
+use crate::services::jwt::JwtService;
+
+#[delete("/{id}")]
+pub async fn delete_operation(
+    jwt: Jwt,
+    operation_id: Path<Uuid>,
+    service_container: Data<Arc<ServiceContainer>>,
+    event_bus: Data<Arc<Box<dyn EventBus>>>,
+) -> Result<impl Responder, HttpError> {
+    let jwt_service = service_container.jwt_service();
+    let user_id = jwt_service.verify(jwt.0.as_str())
+        .map_err(|e|
+            HttpError::Service(e.to_string())
+        )?
+        .user_id()
+        .map_err(|e|
+            HttpError::Service(e.to_string())
+        )?;
+
+    let rep = DbOperationRepository::new(service_container.db_manager(), service_container.serializer());
+
+    let command = DeleteOperationCommand::new(operation_id.into_inner(), user_id);
+    let handler = DeleteOperationCommandHandler::new(rep);
+
+    let mut command_bus = service_container.command_bus();
+    command_bus.register(handler);
+    let events = command_bus.dispatch(command)
+        .await
+        .map_err(HttpError::Feature)?;
+
+    for event in events {
+        event_bus.publish(event).await
+            .map_err(HttpError::Event)?;
+    }
+
+    Ok(HttpResponse::Ok())
+}
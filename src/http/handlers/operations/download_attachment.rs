@@ -0,0 +1,53 @@
+use std::sync::Arc;
+use actix_web::{get, HttpResponse, Responder};
+use actix_web::http::header::{ContentDisposition, DispositionParam, DispositionType};
+use actix_web::web::{Data, Path};
+use uuid::Uuid;
+use crate::di::service_container::ServiceContainer;
+use crate::features::operations::application::queries::get_attachment::handler::GetAttachmentQueryHandler;
+use crate::features::operations::application::queries::get_attachment::query::GetAttachmentQuery;
+use crate::features::operations::infrastructure::db_operation_projection_repository::DbOperationProjectionRepository;
+use crate::http::error::HttpError;
+use crate::http::extractors::jwt::Jwt;
+use crate::services::jwt::JwtService;
+
+#[get("/{id}/attachments/{attachment_id}")]
+pub async fn download_attachment(
+    jwt: Jwt,
+    path: Path<(Uuid, Uuid)>,
+    service_container: Data<Arc<ServiceContainer>>,
+) -> Result<impl Responder, HttpError> {
+    let jwt_service = service_container.jwt_service();
+    let user_id = jwt_service.verify(jwt.0.as_str())
+        .map_err(|e|
+            HttpError::Service(e.to_string())
+        )?
+        .user_id()
+        .map_err(|e|
+            HttpError::Service(e.to_string())
+        )?;
+
+    let storage = service_container.storage()
+        .map_err(|e|
+            HttpError::Service(e.to_string())
+        )?;
+
+    let (operation_id, attachment_id) = path.into_inner();
+    let query = GetAttachmentQuery::new(operation_id, attachment_id, user_id);
+
+    let mut query_bus = service_container.query_bus();
+    query_bus.register(GetAttachmentQueryHandler::new(DbOperationProjectionRepository::new(service_container.db_manager()), storage));
+    let (attachment, content) = query_bus.dispatch(query)
+        .await
+        .map_err(HttpError::Feature)?;
+
+    Ok(
+        HttpResponse::Ok()
+            .content_type(attachment.content_type())
+            .insert_header(ContentDisposition {
+                disposition: DispositionType::Attachment,
+                parameters: vec![DispositionParam::Filename(attachment.file_name().to_string())],
+            })
+            .body(content)
+    )
+}
@@ -0,0 +1,100 @@
+use std::sync::Arc;
+use actix_web::{get, HttpResponse, Responder};
+use actix_web::web::{Data, Query};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use crate::di::service_container::ServiceContainer;
+use crate::features::operations::application::queries::list_operations::handler::ListOperationsQueryHandler;
+use crate::features::operations::application::queries::list_operations::query::{ListOperationsQuery, OperationFilter, Range};
+use crate::features::operations::infrastructure::db_operation_projection_repository::DbOperationProjectionRepository;
+use crate::http::error::HttpError;
+use crate::http::extractors::jwt::Jwt;
+use crate::services::jwt::JwtService;
+
+const DEFAULT_SORT: &str = "created_at";
+const DEFAULT_ORDER: &str = "desc";
+
+#[derive(serde::Deserialize)]
+struct RequestData {
+    kind: Option<String>,
+    account_id: Option<Uuid>,
+    category_id: Option<Uuid>,
+    // Comma separated list of tag ids, operations having any of them are returned
+    tag_ids: Option<String>,
+    currency: Option<String>,
+    date_from: Option<DateTime<Utc>>,
+    date_to: Option<DateTime<Utc>>,
+    amount_min: Option<Decimal>,
+    amount_max: Option<Decimal>,
+    sort: Option<String>,
+    order: Option<String>,
+    limit: Option<u32>,
+    cursor: Option<String>,
+}
+
+impl RequestData {
+    fn to_query(&self, user_id: Uuid) -> Result<ListOperationsQuery, HttpError> {
+        let tag_ids = match &self.tag_ids {
+            Some(tag_ids) => tag_ids.split(',')
+                .filter(|id| !id.trim().is_empty())
+                .map(|id| Uuid::parse_str(id.trim()))
+                .collect::<Result<Vec<Uuid>, _>>()
+                .map_err(|e|
+                    HttpError::RequestValidation(format!("Invalid tag id. {}", e))
+                )?,
+            None => vec![],
+        };
+
+        let filter = OperationFilter::new(
+            self.kind.clone(),
+            self.account_id,
+            self.category_id,
+            tag_ids,
+            self.currency.clone(),
+            Range::new(self.date_from, self.date_to),
+            Range::new(self.amount_min, self.amount_max),
+        );
+
+        Ok(
+            ListOperationsQuery::new(
+                user_id,
+                filter,
+                self.sort.clone().unwrap_or(DEFAULT_SORT.to_string()),
+                self.order.clone().unwrap_or(DEFAULT_ORDER.to_string()),
+                self.limit,
+                self.cursor.clone(),
+            )
+        )
+    }
+}
+
+#[get("")]
+pub async fn list_operations(
+    jwt: Jwt,
+    request_data: Query<RequestData>,
+    service_container: Data<Arc<ServiceContainer>>,
+) -> Result<impl Responder, HttpError> {
+    let jwt_service = service_container.jwt_service();
+    let user_id = jwt_service.verify(jwt.0.as_str())
+        .map_err(|e|
+            HttpError::Service(e.to_string())
+        )?
+        .user_id()
+        .map_err(|e|
+            HttpError::Service(e.to_string())
+        )?;
+
+    let query = request_data.to_query(user_id)?;
+
+    let rep = DbOperationProjectionRepository::new(service_container.db_manager());
+    let handler = ListOperationsQueryHandler::new(rep);
+
+    let mut query_bus = service_container.query_bus();
+    query_bus.register(handler);
+    let page = query_bus.dispatch(query)
+        .await
+        .map_err(HttpError::Feature)?;
+
+    Ok(HttpResponse::Ok().json(page))
+}
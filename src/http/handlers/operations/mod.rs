@@ -1 +1,7 @@
-pub mod create;
\ No newline at end of file
+pub mod add_attachment;
+pub mod create;
+pub mod delete;
+pub mod download_attachment;
+pub mod list;
+pub mod update;
+pub mod suggest;
\ No newline at end of file
@@ -0,0 +1,54 @@
+use std::sync::Arc;
+use actix_web::{HttpResponse, post, Responder};
+use actix_web::web::{Data, Json};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use crate::di::service_container::ServiceContainer;
+use crate::features::categories::infrastructure::db_category_projection_repository::DbCategoryProjectionRepository;
+use crate::features::suggestions::application::queries::suggest::handler::SuggestQueryHandler;
+use crate::features::suggestions::application::queries::suggest::query::SuggestQuery;
+use crate::features::suggestions::infrastructure::db_suggestion_projection_repository::DbSuggestionProjectionRepository;
+use crate::features::tags::infrastructure::db_tag_projection_repository::DbTagProjectionRepository;
+use crate::http::error::HttpError;
+use crate::http::extractors::jwt::Jwt;
+use crate::services::jwt::JwtService;
+
+#[derive(Debug, Clone, Deserialize)]
+struct RequestData {
+    label: String,
+    /// In the currency the operation is going to be recorded in.
+    amount: Decimal,
+}
+
+/// Ranked categories and tags for a draft operation, learned from the operations of the user.
+#[post("/suggest")]
+pub async fn suggest(
+    request_data: Json<RequestData>,
+    jwt: Jwt,
+    service_container: Data<Arc<ServiceContainer>>,
+) -> Result<impl Responder, HttpError> {
+    let jwt_service = service_container.jwt_service();
+    let claims = jwt_service.verify(jwt.0.as_str()).map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+    let user_id = claims.user_id().map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+
+    let db_manager = service_container.db_manager();
+    let handler = SuggestQueryHandler::new(
+        DbSuggestionProjectionRepository::new(db_manager.clone()),
+        DbCategoryProjectionRepository::new(db_manager.clone()),
+        DbTagProjectionRepository::new(db_manager),
+    );
+
+    let request_data = request_data.into_inner();
+
+    let mut query_bus = service_container.query_bus();
+    query_bus.register(handler);
+    let suggestions = query_bus.dispatch(SuggestQuery::new(user_id, request_data.label, request_data.amount))
+        .await
+        .map_err(HttpError::Feature)?;
+
+    Ok(HttpResponse::Ok().json(suggestions))
+}
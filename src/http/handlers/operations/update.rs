@@ -0,0 +1,130 @@
+use std::sync::Arc;
+use actix_web::{HttpResponse, put, Responder};
+use actix_web::web::{Data, Json, Path};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use crate::di::service_container::ServiceContainer;
+use crate::events::event_bus::EventBus;
+use crate::features::accounts::infrastructure::db_account_projection_repository::DbAccountProjectionRepository;
+use crate::features::operations::application::commands::create_operation::command::{LineData, TagData, TransferData};
+use crate::features::operations::application::commands::update_operation::command::UpdateOperationCommand;
+use crate::features::operations::application::commands::update_operation::handler::UpdateOperationCommandHandler;
+use crate::features::operations::infrastructure::db_operation_repository::DbOperationRepository;
+use crate::features::operations::infrastructure::projection_account_source::ProjectionAccountSource;
+use crate::http::error::HttpError;
+use crate::http::extractors::jwt::Jwt;
+use crate::services::jwt::JwtService;
+
+#[derive(serde::Deserialize)]
+struct RequestData {
+    kind: String,
+    account_id: Uuid,
+    category_id: Option<Uuid>,
+    category_name: String,
+    amount: Decimal,
+    currency: String,
+    currency_amount: Decimal,
+    rate: Decimal,
+    label: String,
+    tags: Vec<RequestTagData>,
+    transfer: Option<RequestTransferData>,
+    #[serde(default)]
+    lines: Vec<RequestLineData>,
+}
+
+#[derive(serde::Deserialize)]
+struct RequestTagData {
+    id: Option<Uuid>,
+    name: String,
+}
+
+#[derive(serde::Deserialize)]
+struct RequestTransferData {
+    account_id: Uuid,
+    amount: Option<Decimal>,
+}
+
+#[derive(serde::Deserialize)]
+struct RequestLineData {
+    category_id: Uuid,
+    currency_amount: Decimal,
+    #[serde(default)]
+    label: String,
+    #[serde(default)]
+    tag_ids: Vec<Uuid>,
+}
+
+impl RequestData {
+    fn to_command(&self, operation_id: Uuid, user_id: Uuid) -> UpdateOperationCommand {
+        let tags = self.tags.iter().map(|tag| TagData::new(
+            tag.id,
+            tag.name.clone(),
+        )).collect();
+
+        let command = UpdateOperationCommand::new(
+            operation_id,
+            self.kind.clone(),
+            user_id,
+            self.account_id,
+            self.category_id,
+            self.category_name.clone(),
+            self.amount,
+            self.currency.clone(),
+            self.currency_amount,
+            self.rate,
+            self.label.clone(),
+            tags,
+        );
+
+        let lines = self.lines.iter().map(|line| LineData::new(
+            line.category_id,
+            line.currency_amount,
+            line.label.clone(),
+            line.tag_ids.clone(),
+        )).collect();
+        let command = command.with_lines(lines);
+
+        match &self.transfer {
+            Some(transfer) => command.with_transfer(TransferData::new(transfer.account_id, transfer.amount)),
+            None => command,
+        }
+    }
+}
+
+#[put("/{id}")]
+pub async fn update_operation(
+    jwt: Jwt,
+    operation_id: Path<Uuid>,
+    request_data: Json<RequestData>,
+    service_container: Data<Arc<ServiceContainer>>,
+    event_bus: Data<Arc<Box<dyn EventBus>>>,
+) -> Result<impl Responder, HttpError> {
+    let jwt_service = service_container.jwt_service();
+    let user_id = jwt_service.verify(jwt.0.as_str())
+        .map_err(|e|
+            HttpError::Service(e.to_string())
+        )?
+        .user_id()
+        .map_err(|e|
+            HttpError::Service(e.to_string())
+        )?;
+
+    let rep = DbOperationRepository::new(service_container.db_manager(), service_container.serializer());
+    let accounts = ProjectionAccountSource::new(DbAccountProjectionRepository::new(service_container.db_manager()));
+
+    let command = request_data.to_command(operation_id.into_inner(), user_id);
+    let handler = UpdateOperationCommandHandler::new(rep, accounts);
+
+    let mut command_bus = service_container.command_bus();
+    command_bus.register(handler);
+    let events = command_bus.dispatch(command)
+        .await
+        .map_err(HttpError::Feature)?;
+
+    for event in events {
+        event_bus.publish(event).await
+            .map_err(HttpError::Event)?;
+    }
+
+    Ok(HttpResponse::Ok())
+}
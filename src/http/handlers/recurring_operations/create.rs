@@ -0,0 +1,112 @@
+use std::sync::Arc;
+use actix_web::{HttpResponse, post, Responder};
+use actix_web::web::{Data, Json};
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use uuid::Uuid;
+use crate::di::service_container::ServiceContainer;
+use crate::events::event_bus::EventBus;
+use crate::features::recurrences::application::commands::create_recurring_operation::command::{CreateRecurringOperationCommand, RecurrenceData, TemplateData};
+use crate::features::recurrences::application::commands::create_recurring_operation::handler::CreateRecurringOperationCommandHandler;
+use crate::features::recurrences::infrastructure::db_recurring_operation_repository::DbRecurringOperationRepository;
+use crate::http::error::HttpError;
+use crate::http::extractors::jwt::Jwt;
+use crate::services::jwt::JwtService;
+
+#[derive(Debug, Clone, Deserialize)]
+struct RequestData {
+    recurrence: RequestRecurrenceData,
+    template: RequestTemplateData,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct RequestRecurrenceData {
+    frequency: String,
+    #[serde(default = "default_interval")]
+    interval: u32,
+    starts_on: NaiveDate,
+    until: Option<NaiveDate>,
+    count: Option<u32>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct RequestTemplateData {
+    kind: String,
+    account_id: Uuid,
+    category_id: Option<Uuid>,
+    category_name: String,
+    currency: String,
+    currency_amount: Decimal,
+    label: String,
+    transfer_account_id: Option<Uuid>,
+}
+
+fn default_interval() -> u32 {
+    1
+}
+
+impl RequestData {
+    fn to_command(&self, user_id: Uuid) -> CreateRecurringOperationCommand {
+        let recurrence = &self.recurrence;
+        let template = &self.template;
+
+        CreateRecurringOperationCommand::new(
+            user_id,
+            RecurrenceData::new(
+                recurrence.frequency.clone(),
+                recurrence.interval,
+                recurrence.starts_on,
+                recurrence.until,
+                recurrence.count,
+            ),
+            TemplateData::new(
+                template.kind.clone(),
+                template.account_id,
+                template.category_id,
+                template.category_name.clone(),
+                template.currency.clone(),
+                template.currency_amount,
+                template.label.clone(),
+                template.transfer_account_id,
+            ),
+        )
+    }
+}
+
+#[post("/create")]
+pub async fn create_recurring_operation(
+    request_data: Json<RequestData>,
+    jwt: Jwt,
+    service_container: Data<Arc<ServiceContainer>>,
+    event_bus: Data<Arc<Box<dyn EventBus>>>,
+) -> Result<impl Responder, HttpError> {
+    let jwt_service = service_container.jwt_service();
+    let claims = jwt_service.verify(jwt.0.as_str()).map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+    let user_id = claims.user_id().map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+
+    let rep = DbRecurringOperationRepository::new(service_container.db_manager());
+
+    let command = request_data.to_command(user_id);
+    let handler = CreateRecurringOperationCommandHandler::new(rep);
+
+    let mut command_bus = service_container.command_bus();
+    command_bus.register(handler);
+    let events = command_bus.dispatch(command)
+        .await
+        .map_err(HttpError::Feature)?;
+
+    for event in events {
+        event_bus.publish(event)
+            .await
+            .map_err(HttpError::Event)?;
+    }
+
+    Ok(
+        HttpResponse::Ok()
+    )
+}
@@ -0,0 +1,4 @@
+pub mod cancel;
+pub mod create;
+pub mod list;
+pub mod resume;
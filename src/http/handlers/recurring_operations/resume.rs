@@ -0,0 +1,50 @@
+use std::sync::Arc;
+use actix_web::{post, HttpResponse, Responder};
+use actix_web::web::{Data, Path};
+use uuid::Uuid;
+use crate::di::service_container::ServiceContainer;
+use crate::events::event_bus::EventBus;
+use crate::features::recurrences::application::commands::resume_recurring_operation::command::ResumeRecurringOperationCommand;
+use crate::features::recurrences::application::commands::resume_recurring_operation::handler::ResumeRecurringOperationCommandHandler;
+use crate::features::recurrences::infrastructure::db_recurring_operation_repository::DbRecurringOperationRepository;
+use crate::http::error::HttpError;
+use crate::http::extractors::jwt::Jwt;
+use crate::services::jwt::JwtService;
+
+/// Resumes a recurring operation paused by an occurrence whose operation could not be created.
+#[post("/{id}/resume")]
+pub async fn resume_recurring_operation(
+    recurring_operation_id: Path<Uuid>,
+    jwt: Jwt,
+    service_container: Data<Arc<ServiceContainer>>,
+    event_bus: Data<Arc<Box<dyn EventBus>>>
+) -> Result<impl Responder, HttpError> {
+    let jwt_service = service_container.jwt_service();
+    let claims = jwt_service.verify(jwt.0.as_str()).map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+    let user_id = claims.user_id().map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+
+    let rep = DbRecurringOperationRepository::new(service_container.db_manager());
+
+    let command = ResumeRecurringOperationCommand::new(recurring_operation_id.into_inner(), user_id);
+    let handler = ResumeRecurringOperationCommandHandler::new(rep);
+
+    let mut command_bus = service_container.command_bus();
+    command_bus.register(handler);
+    let events = command_bus.dispatch(command)
+        .await
+        .map_err(HttpError::Feature)?;
+
+    for event in events {
+        event_bus.publish(event)
+            .await
+            .map_err(HttpError::Event)?;
+    }
+
+    Ok(
+        HttpResponse::Ok()
+    )
+}
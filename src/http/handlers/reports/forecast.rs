@@ -0,0 +1,79 @@
+use std::sync::Arc;
+use actix_web::{get, HttpResponse, Responder};
+use actix_web::web::{Data, Query};
+use chrono::{NaiveDate, Utc};
+use serde::Deserialize;
+use crate::di::service_container::ServiceContainer;
+use crate::features::balance::infrastructure::db_balance_projection_repository::DbBalanceProjectionRepository;
+use crate::features::loans::infrastructure::db_loan_projection_repository::DbLoanProjectionRepository;
+use crate::features::rates::application::queries::find_rate::handler::FindRateQueryHandler;
+use crate::features::rates::infrastructure::db_rate_repository::DbRateRepository;
+use crate::features::rates::infrastructure::query_rate_source::QueryRateSource;
+use crate::features::recurrences::infrastructure::db_recurring_operation_projection_repository::DbRecurringOperationProjectionRepository;
+use crate::features::reports::application::queries::forecast::handler::ForecastQueryHandler;
+use crate::features::reports::application::queries::forecast::query::ForecastQuery;
+use crate::features::reports::infrastructure::db_report_projection_repository::DbReportProjectionRepository;
+use crate::http::error::HttpError;
+use crate::http::extractors::jwt::Jwt;
+use crate::services::jwt::JwtService;
+use crate::support::error::FeatureError;
+
+const DEFAULT_DAYS: u32 = 30;
+
+#[derive(Deserialize)]
+struct RequestData {
+    days: Option<u32>,
+    date: Option<NaiveDate>,
+}
+
+#[get("/forecast")]
+pub async fn forecast_report(
+    request_data: Query<RequestData>,
+    jwt: Jwt,
+    service_container: Data<Arc<ServiceContainer>>,
+) -> Result<impl Responder, HttpError> {
+    let jwt_service = service_container.jwt_service();
+    let claims = jwt_service.verify(jwt.0.as_str()).map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+    let user_id = claims.user_id().map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+
+    let db_manager = service_container.db_manager();
+    let config = service_container.config();
+
+    let rate_provider = service_container.rate_provider()
+        .map_err(|e|
+            HttpError::Feature(FeatureError::Rate(e))
+        )?;
+    let rates = QueryRateSource::new(
+        FindRateQueryHandler::new(DbRateRepository::new(db_manager.clone()), rate_provider),
+        config.rates().base_currency().to_string(),
+    );
+
+    let handler = ForecastQueryHandler::new(
+        DbReportProjectionRepository::new(db_manager.clone()),
+        DbBalanceProjectionRepository::new(db_manager.clone()),
+        DbRecurringOperationProjectionRepository::new(db_manager.clone()),
+        DbLoanProjectionRepository::new(db_manager),
+        rates,
+        config.rates().base_currency().to_string(),
+        config.reports().forecast_lookback(),
+        config.reports().forecast_max_days(),
+    );
+
+    let query = ForecastQuery::new(
+        user_id,
+        request_data.date.unwrap_or_else(|| Utc::now().date_naive()),
+        request_data.days.unwrap_or(DEFAULT_DAYS),
+    );
+
+    let mut query_bus = service_container.query_bus();
+    query_bus.register(handler);
+    let report = query_bus.dispatch(query)
+        .await
+        .map_err(HttpError::Feature)?;
+
+    Ok(HttpResponse::Ok().json(report))
+}
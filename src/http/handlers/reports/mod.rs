@@ -0,0 +1,5 @@
+pub mod categories;
+pub mod forecast;
+pub mod kinds;
+pub mod tags;
+pub mod timeline;
\ No newline at end of file
@@ -0,0 +1,46 @@
+use std::sync::Arc;
+use actix_web::{HttpResponse, post, Responder};
+use actix_web::web::Data;
+use crate::di::service_container::ServiceContainer;
+use crate::events::event_bus::EventBus;
+use crate::features::rules::application::commands::apply_rules::command::ApplyRulesCommand;
+use crate::features::rules::application::commands::apply_rules::handler::ApplyRulesCommandHandler;
+use crate::features::rules::infrastructure::command_operation_categorizer::CommandOperationCategorizer;
+use crate::features::rules::infrastructure::db_operation_source::DbOperationSource;
+use crate::features::rules::infrastructure::db_rule_projection_repository::DbRuleProjectionRepository;
+use crate::http::error::HttpError;
+use crate::http::extractors::jwt::Jwt;
+use crate::services::jwt::JwtService;
+
+/// Re-applies the rules of the user to the operations already recorded.
+#[post("/apply")]
+pub async fn apply_rules(
+    jwt: Jwt,
+    service_container: Data<Arc<ServiceContainer>>,
+    event_bus: Data<Arc<Box<dyn EventBus>>>,
+) -> Result<impl Responder, HttpError> {
+    let jwt_service = service_container.jwt_service();
+    let claims = jwt_service.verify(jwt.0.as_str()).map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+    let user_id = claims.user_id().map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+
+    let db_manager = service_container.db_manager();
+    let handler = ApplyRulesCommandHandler::new(
+        DbRuleProjectionRepository::new(db_manager.clone()),
+        DbOperationSource::new(db_manager),
+        CommandOperationCategorizer::new(service_container.get_ref().clone(), event_bus.get_ref().clone()),
+    );
+
+    let mut command_bus = service_container.command_bus();
+    command_bus.register(handler);
+    command_bus.dispatch(ApplyRulesCommand::new(user_id))
+        .await
+        .map_err(HttpError::Feature)?;
+
+    Ok(
+        HttpResponse::Ok()
+    )
+}
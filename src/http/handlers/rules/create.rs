@@ -0,0 +1,70 @@
+use std::sync::Arc;
+use actix_web::{HttpResponse, post, Responder};
+use actix_web::web::{Data, Json};
+use serde::Deserialize;
+use uuid::Uuid;
+use crate::di::service_container::ServiceContainer;
+use crate::events::event_bus::EventBus;
+use crate::features::rules::application::commands::create_rule::command::CreateRuleCommand;
+use crate::features::rules::application::commands::create_rule::handler::CreateRuleCommandHandler;
+use crate::features::rules::domain::rule_conditions::RuleConditions;
+use crate::features::rules::infrastructure::db_rule_repository::DbRuleRepository;
+use crate::http::error::HttpError;
+use crate::http::extractors::jwt::Jwt;
+use crate::services::jwt::JwtService;
+
+#[derive(Debug, Clone, Deserialize)]
+struct RequestData {
+    name: String,
+    #[serde(default)]
+    priority: i32,
+    conditions: RuleConditions,
+    category_id: Uuid,
+    #[serde(default)]
+    tag_ids: Vec<Uuid>,
+}
+
+#[post("/create")]
+pub async fn create_rule(
+    request_data: Json<RequestData>,
+    jwt: Jwt,
+    service_container: Data<Arc<ServiceContainer>>,
+    event_bus: Data<Arc<Box<dyn EventBus>>>,
+) -> Result<impl Responder, HttpError> {
+    let jwt_service = service_container.jwt_service();
+    let claims = jwt_service.verify(jwt.0.as_str()).map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+    let user_id = claims.user_id().map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+
+    let rep = DbRuleRepository::new(service_container.db_manager());
+    let handler = CreateRuleCommandHandler::new(rep);
+
+    let request_data = request_data.into_inner();
+    let command = CreateRuleCommand::new(
+        user_id,
+        request_data.name,
+        request_data.priority,
+        request_data.conditions,
+        request_data.category_id,
+        request_data.tag_ids,
+    );
+
+    let mut command_bus = service_container.command_bus();
+    command_bus.register(handler);
+    let events = command_bus.dispatch(command)
+        .await
+        .map_err(HttpError::Feature)?;
+
+    for event in events {
+        event_bus.publish(event)
+            .await
+            .map_err(HttpError::Event)?;
+    }
+
+    Ok(
+        HttpResponse::Ok()
+    )
+}
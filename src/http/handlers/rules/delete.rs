@@ -0,0 +1,50 @@
+use std::sync::Arc;
+use actix_web::{delete, HttpResponse, Responder};
+use actix_web::web::{Data, Path};
+use uuid::Uuid;
+use crate::di::service_container::ServiceContainer;
+use crate::events::event_bus::EventBus;
+use crate::features::rules::application::commands::delete_rule::command::DeleteRuleCommand;
+use crate::features::rules::application::commands::delete_rule::handler::DeleteRuleCommandHandler;
+use crate::features::rules::infrastructure::db_rule_repository::DbRuleRepository;
+use crate::http::error::HttpError;
+use crate::http::extractors::jwt::Jwt;
+use crate::services::jwt::JwtService;
+
+/// Operations already categorized by the rule keep their category and tags.
+#[delete("/{id}")]
+pub async fn delete_rule(
+    rule_id: Path<Uuid>,
+    jwt: Jwt,
+    service_container: Data<Arc<ServiceContainer>>,
+    event_bus: Data<Arc<Box<dyn EventBus>>>
+) -> Result<impl Responder, HttpError> {
+    let jwt_service = service_container.jwt_service();
+    let claims = jwt_service.verify(jwt.0.as_str()).map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+    let user_id = claims.user_id().map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+
+    let rep = DbRuleRepository::new(service_container.db_manager());
+
+    let command = DeleteRuleCommand::new(rule_id.into_inner(), user_id);
+    let handler = DeleteRuleCommandHandler::new(rep);
+
+    let mut command_bus = service_container.command_bus();
+    command_bus.register(handler);
+    let events = command_bus.dispatch(command)
+        .await
+        .map_err(HttpError::Feature)?;
+
+    for event in events {
+        event_bus.publish(event)
+            .await
+            .map_err(HttpError::Event)?;
+    }
+
+    Ok(
+        HttpResponse::Ok()
+    )
+}
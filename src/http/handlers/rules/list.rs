@@ -0,0 +1,35 @@
+use std::sync::Arc;
+use actix_web::{get, HttpResponse, Responder};
+use actix_web::web::Data;
+use crate::di::service_container::ServiceContainer;
+use crate::features::rules::application::queries::list_rules::handler::ListRulesQueryHandler;
+use crate::features::rules::application::queries::list_rules::query::ListRulesQuery;
+use crate::features::rules::infrastructure::db_rule_projection_repository::DbRuleProjectionRepository;
+use crate::http::error::HttpError;
+use crate::http::extractors::jwt::Jwt;
+use crate::services::jwt::JwtService;
+
+#[get("")]
+pub async fn list_rules(
+    jwt: Jwt,
+    service_container: Data<Arc<ServiceContainer>>,
+) -> Result<impl Responder, HttpError> {
+    let jwt_service = service_container.jwt_service();
+    let claims = jwt_service.verify(jwt.0.as_str()).map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+    let user_id = claims.user_id().map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+
+    let rep = DbRuleProjectionRepository::new(service_container.db_manager());
+    let handler = ListRulesQueryHandler::new(rep);
+
+    let mut query_bus = service_container.query_bus();
+    query_bus.register(handler);
+    let rules = query_bus.dispatch(ListRulesQuery::new(user_id))
+        .await
+        .map_err(HttpError::Feature)?;
+
+    Ok(HttpResponse::Ok().json(rules))
+}
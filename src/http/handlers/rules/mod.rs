@@ -0,0 +1,4 @@
+pub mod apply;
+pub mod create;
+pub mod delete;
+pub mod list;
\ No newline at end of file
@@ -17,7 +17,7 @@ struct RequestData {
 }
 
 #[post("/create")]
-async fn create_tag(
+pub async fn create_tag(
     request_data: Json<RequestData>,
     jwt: Jwt,
     service_container: Data<Arc<ServiceContainer>>,
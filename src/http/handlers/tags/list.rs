@@ -0,0 +1,37 @@
+use std::sync::Arc;
+use actix_web::{get, HttpResponse, Responder};
+use actix_web::web::Data;
+use crate::di::service_container::ServiceContainer;
+use crate::features::tags::application::queries::list_tags::handler::ListTagsQueryHandler;
+use crate::features::tags::application::queries::list_tags::query::ListTagsQuery;
+use crate::features::tags::infrastructure::db_tag_projection_repository::DbTagProjectionRepository;
+use crate::http::error::HttpError;
+use crate::http::extractors::jwt::Jwt;
+use crate::services::jwt::JwtService;
+
+#[get("")]
+pub async fn list_tags(
+    jwt: Jwt,
+    service_container: Data<Arc<ServiceContainer>>,
+) -> Result<impl Responder, HttpError> {
+    let jwt_service = service_container.jwt_service();
+    let claims = jwt_service.verify(jwt.0.as_str()).map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+    let user_id = claims.user_id().map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+
+    let rep = DbTagProjectionRepository::new(service_container.db_manager());
+    let handler = ListTagsQueryHandler::new(rep);
+
+    let query = ListTagsQuery::new(user_id);
+
+    let mut query_bus = service_container.query_bus();
+    query_bus.register(handler);
+    let tags = query_bus.dispatch(query)
+        .await
+        .map_err(HttpError::Feature)?;
+
+    Ok(HttpResponse::Ok().json(tags))
+}
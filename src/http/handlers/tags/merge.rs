@@ -0,0 +1,56 @@
+use std::sync::Arc;
+use actix_web::{post, HttpResponse, Responder};
+use actix_web::web::{Data, Json};
+use serde::Deserialize;
+use uuid::Uuid;
+use crate::di::service_container::ServiceContainer;
+use crate::events::event_bus::EventBus;
+use crate::features::tags::application::commands::merge_tags::command::MergeTagsCommand;
+use crate::features::tags::application::commands::merge_tags::handler::MergeTagsCommandHandler;
+use crate::features::tags::infrastructure::db_tag_repository::DbTagRepository;
+use crate::http::error::HttpError;
+use crate::http::extractors::jwt::Jwt;
+use crate::services::jwt::JwtService;
+
+#[derive(Deserialize)]
+struct RequestData {
+    source_tag_ids: Vec<Uuid>,
+    target_tag_id: Uuid,
+}
+
+#[post("/merge")]
+pub async fn merge_tags(
+    request_data: Json<RequestData>,
+    jwt: Jwt,
+    service_container: Data<Arc<ServiceContainer>>,
+    event_bus: Data<Arc<Box<dyn EventBus>>>
+) -> Result<impl Responder, HttpError> {
+    let jwt_service = service_container.jwt_service();
+    let claims = jwt_service.verify(jwt.0.as_str()).map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+    let user_id = claims.user_id().map_err(|e|
+        HttpError::Service(e.to_string())
+    )?;
+
+    let rep = DbTagRepository::new(service_container.db_manager(), service_container.serializer());
+
+    let command = MergeTagsCommand::new(user_id, request_data.source_tag_ids.clone(), request_data.target_tag_id);
+    let handler = MergeTagsCommandHandler::new(rep);
+
+    let mut command_bus = service_container.command_bus();
+    command_bus.register(handler);
+    let events = command_bus.dispatch(command)
+        .await
+        .map_err(HttpError::Feature)?;
+
+    for event in events {
+        event_bus.publish(event)
+            .await
+            .map_err(HttpError::Event)?;
+    }
+
+    Ok(
+        HttpResponse::Ok()
+    )
+}
@@ -1 +1,5 @@
-pub mod create;
\ No newline at end of file
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod merge;
+pub mod rename;
\ No newline at end of file
@@ -1,7 +1,7 @@
 use actix_web::web;
 use actix_web::web::{scope, ServiceConfig};
 use crate::http::handlers::auth::{confirm_registration, login, registration, request_confirmation_token};
-use crate::http::handlers::{categories, operations};
+use crate::http::handlers::{accounts, balance, bank_connections, budgets, categories, currencies, imports, loans, operations, recurring_operations, reports, rules, tags};
 use crate::http::handlers::errors::not_found;
 use crate::http::middleware::check_auth::CheckAuth;
 
@@ -17,16 +17,106 @@ impl Routes {
 
         let operations = scope("/operations")
             .wrap(CheckAuth)
-            .service(operations::create::create_operation);
+            .service(operations::create::create_operation)
+            .service(operations::list::list_operations)
+            .service(operations::suggest::suggest)
+            .service(operations::update::update_operation)
+            .service(operations::delete::delete_operation)
+            .service(operations::add_attachment::add_attachment)
+            .service(operations::download_attachment::download_attachment);
+
+        let accounts = scope("/accounts")
+            .wrap(CheckAuth)
+            .service(accounts::create::create_account)
+            .service(accounts::list::list_accounts)
+            .service(accounts::rename::rename_account);
+
+        let balance = scope("/balance")
+            .wrap(CheckAuth)
+            .service(balance::get::get_balance);
+
+        let budgets = scope("/budgets")
+            .wrap(CheckAuth)
+            .service(budgets::create::create_budget)
+            .service(budgets::list::list_budgets);
+
+        let loans = scope("/loans")
+            .wrap(CheckAuth)
+            .service(loans::create::create_loan)
+            .service(loans::list::list_loans)
+            .service(loans::record_repayment::record_repayment);
+
+        let recurring_operations = scope("/recurring-operations")
+            .wrap(CheckAuth)
+            .service(recurring_operations::create::create_recurring_operation)
+            .service(recurring_operations::list::list_recurring_operations)
+            .service(recurring_operations::cancel::cancel_recurring_operation)
+            .service(recurring_operations::resume::resume_recurring_operation);
+
+        let reports = scope("/reports")
+            .wrap(CheckAuth)
+            .service(reports::categories::category_totals_report)
+            .service(reports::tags::tag_totals_report)
+            .service(reports::kinds::kind_totals_report)
+            .service(reports::timeline::timeline_report)
+            .service(reports::forecast::forecast_report);
+
+        let imports = scope("/imports")
+            .wrap(CheckAuth)
+            .service(imports::import::import_statement);
+
+        let bank_connections = scope("/bank-connections")
+            .wrap(CheckAuth)
+            .service(bank_connections::accounts::list_bank_accounts)
+            .service(bank_connections::create::create_bank_connection)
+            .service(bank_connections::list::list_bank_connections)
+            .service(bank_connections::sync::sync_bank_connection)
+            .service(bank_connections::remove::remove_bank_connection);
+
+        let rules = scope("/rules")
+            .wrap(CheckAuth)
+            .service(rules::apply::apply_rules)
+            .service(rules::create::create_rule)
+            .service(rules::list::list_rules)
+            .service(rules::delete::delete_rule);
 
         let categories = scope("/categories")
             .wrap(CheckAuth)
-            .service(categories::create::create_category);
+            .service(categories::create::create_category)
+            .service(categories::list::list_categories)
+            .service(categories::report::category_report)
+            .service(categories::rename::rename_category)
+            .service(categories::change_icon::change_category_icon)
+            .service(categories::move_category::move_category)
+            .service(categories::archive::archive_category)
+            .service(categories::delete::delete_category);
+
+        let tags = scope("/tags")
+            .wrap(CheckAuth)
+            .service(tags::create::create_tag)
+            .service(tags::list::list_tags)
+            .service(tags::merge::merge_tags)
+            .service(tags::rename::rename_tag)
+            .service(tags::delete::delete_tag);
+
+        let currencies = scope("/currencies")
+            .service(currencies::list::list_currencies);
 
 
         cfg.service(auth)
             .service(operations)
+            .service(accounts)
+            .service(balance)
+            .service(budgets)
+            .service(loans)
+            .service(recurring_operations)
+            .service(reports)
+            .service(imports)
+            .service(bank_connections)
             .service(categories)
+            .service(rules)
+            .service(tags)
+            .service(currencies)
             .default_service(web::route().to(not_found::handle));
     }
 }
\ No newline at end of file
@@ -4,6 +4,9 @@ use dotenv::dotenv;
 use crate::config::manager::ConfigManager;
 use crate::di::service_container::ServiceContainer;
 use crate::events::event_bus_factory::EventBusFactory;
+use crate::features::banking::infrastructure::bank_sync_worker::BankSyncWorker;
+use crate::features::rates::infrastructure::rate_refresher::RateRefresher;
+use crate::features::recurrences::infrastructure::recurrence_worker::RecurrenceWorker;
 use crate::http::server;
 use crate::log::logger;
 
@@ -35,8 +38,13 @@ impl App {
 
         let _guard = logger::init(service_container.config().log().clone());
 
+        RateRefresher::spawn(service_container.clone());
+
         let (event_bus, queue_receiver, mut response) = EventBusFactory::create(service_container.clone()).await.expect("Failed to create event bus");
 
+        RecurrenceWorker::spawn(service_container.clone(), event_bus.clone());
+        BankSyncWorker::spawn(service_container.clone(), event_bus.clone());
+
         let event_bus_clone = event_bus.clone();
         tokio::spawn(async move {
             if let Err(e) = event_bus_clone.start(queue_receiver).await {
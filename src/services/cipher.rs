@@ -0,0 +1,106 @@
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use mockall::automock;
+use ring::aead::{Aad, AES_256_GCM, LessSafeKey, Nonce, NONCE_LEN, UnboundKey};
+use ring::rand::{SecureRandom, SystemRandom};
+use crate::services::error::ServiceError;
+
+/// Symmetric encryption of secrets kept at rest, such as the credentials of bank connections.
+#[automock]
+pub trait Cipher {
+    fn encrypt(&self, value: &str) -> Result<String, ServiceError>;
+
+    fn decrypt(&self, value: &str) -> Result<String, ServiceError>;
+}
+
+/// AES-256-GCM with a random nonce per value, encoded in base64 as the nonce followed by the sealed value.
+pub struct AesGcmCipher {
+    key: String,
+}
+
+impl AesGcmCipher {
+    /// `key` is 32 bytes encoded in base64.
+    pub fn new(key: String) -> Self {
+        Self {
+            key,
+        }
+    }
+
+    fn key(&self) -> Result<LessSafeKey, ServiceError> {
+        let bytes = STANDARD.decode(&self.key)
+            .map_err(|e| ServiceError::Cipher(format!("Invalid key: {}", e)))?;
+
+        let key = UnboundKey::new(&AES_256_GCM, &bytes)
+            .map_err(|_| ServiceError::Cipher("Key must be 32 bytes long".to_string()))?;
+
+        Ok(LessSafeKey::new(key))
+    }
+}
+
+impl Cipher for AesGcmCipher {
+    fn encrypt(&self, value: &str) -> Result<String, ServiceError> {
+        let mut nonce = [0u8; NONCE_LEN];
+        SystemRandom::new().fill(&mut nonce)
+            .map_err(|_| ServiceError::Cipher("Failed to generate nonce".to_string()))?;
+
+        let mut sealed = value.as_bytes().to_vec();
+        self.key()?.seal_in_place_append_tag(Nonce::assume_unique_for_key(nonce), Aad::empty(), &mut sealed)
+            .map_err(|_| ServiceError::Cipher("Failed to encrypt".to_string()))?;
+
+        Ok(STANDARD.encode([nonce.as_slice(), &sealed].concat()))
+    }
+
+    fn decrypt(&self, value: &str) -> Result<String, ServiceError> {
+        let bytes = STANDARD.decode(value)
+            .map_err(|e| ServiceError::Cipher(format!("Invalid encrypted value: {}", e)))?;
+
+        if bytes.len() < NONCE_LEN {
+            return Err(ServiceError::Cipher("Encrypted value is too short".to_string()));
+        }
+
+        let (nonce, sealed) = bytes.split_at(NONCE_LEN);
+        let nonce = Nonce::try_assume_unique_for_key(nonce)
+            .map_err(|_| ServiceError::Cipher("Invalid nonce".to_string()))?;
+
+        let mut sealed = sealed.to_vec();
+        let opened = self.key()?.open_in_place(nonce, Aad::empty(), &mut sealed)
+            .map_err(|_| ServiceError::Cipher("Failed to decrypt".to_string()))?;
+
+        String::from_utf8(opened.to_vec())
+            .map_err(|e| ServiceError::Cipher(format!("Decrypted value is not text: {}", e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &str = "AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=";
+
+    #[test]
+    fn test_round_trip() {
+        let cipher = AesGcmCipher::new(KEY.to_string());
+
+        let encrypted = cipher.encrypt("token").unwrap();
+
+        assert_ne!(encrypted, "token");
+        assert_ne!(encrypted, cipher.encrypt("token").unwrap());
+        assert_eq!(cipher.decrypt(&encrypted).unwrap(), "token");
+    }
+
+    #[test]
+    fn test_decrypt_with_other_key() {
+        let encrypted = AesGcmCipher::new(KEY.to_string()).encrypt("token").unwrap();
+
+        let other = AesGcmCipher::new(STANDARD.encode([7u8; 32]));
+
+        assert!(matches!(other.decrypt(&encrypted), Err(ServiceError::Cipher(_))));
+    }
+
+    #[test]
+    fn test_invalid_key() {
+        let cipher = AesGcmCipher::new(STANDARD.encode([7u8; 16]));
+
+        assert!(matches!(cipher.encrypt("token"), Err(ServiceError::Cipher(_))));
+    }
+}
@@ -2,6 +2,9 @@ use thiserror::Error;
 
 #[derive(Error, Debug, Clone)]
 pub enum ServiceError {
+    #[error("Cipher service error. {0}")]
+    Cipher(String),
+
     #[error("Hasher service error. {0}")]
     Hasher(String),
 
@@ -17,6 +20,9 @@ pub enum ServiceError {
     #[error("Serializer service error. {0}")]
     Serializer(String),
 
+    #[error("Storage service error. {0}")]
+    Storage(String),
+
     #[error("Templater service error. {0}")]
     Templater(String),
 
@@ -4,6 +4,8 @@ use crate::services::error::ServiceError;
 #[async_trait]
 pub trait HttpClient {
     async fn get(&self, url: &str) -> Result<String, ServiceError>;
+    /// GET with an `Authorization: Bearer` header, failing on a non-success status.
+    async fn get_authorized(&self, url: &str, token: &str) -> Result<String, ServiceError>;
     async fn post(&self, url: &str, body: &str) -> Result<String, ServiceError>;
     async fn put(&self, url: &str, body: &str) -> Result<String, ServiceError>;
     async fn delete(&self, url: &str) -> Result<String, ServiceError>;
@@ -40,6 +42,30 @@ impl HttpClient for ReqwestClient {
         Ok(body)
     }
 
+    async fn get_authorized(&self, url: &str, token: &str) -> Result<String, ServiceError> {
+        let response = reqwest::Client::new()
+            .get(url)
+            .bearer_auth(token)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e|
+                ServiceError::HttpClient(
+                    format!("Failed to get request from {}. {}", url, e)
+                )
+            )?;
+
+        let body = response.text()
+            .await
+            .map_err(|e|
+                ServiceError::HttpClient(
+                    format!("Failed to get get-response body from {}. {}", url, e)
+                )
+            )?;
+
+        Ok(body)
+    }
+
     async fn post(&self, url: &str, body: &str) -> Result<String, ServiceError> {
         let response = reqwest::Client::new()
             .post(url)
@@ -5,4 +5,6 @@ pub mod tokenizer;
 pub mod hasher;
 pub mod jwt;
 pub mod http_client;
+pub mod cipher;
+pub mod storage;
 pub mod error;
\ No newline at end of file
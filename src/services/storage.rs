@@ -0,0 +1,133 @@
+use std::io::ErrorKind;
+use std::path::{Component, Path, PathBuf};
+use async_trait::async_trait;
+use mockall::automock;
+use crate::config::structs::storage::StorageConfig;
+use crate::services::error::ServiceError;
+
+/// Blob storage for user files such as receipts, addressed by slash-separated keys.
+#[async_trait]
+#[automock]
+pub trait Storage: Send + Sync {
+    /// Stores `content` under `key`, replacing what was stored there before.
+    async fn put(&self, key: &str, content: &[u8]) -> Result<(), ServiceError>;
+
+    /// Content stored under `key`, if any.
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, ServiceError>;
+}
+
+#[async_trait]
+impl Storage for Box<dyn Storage> {
+    async fn put(&self, key: &str, content: &[u8]) -> Result<(), ServiceError> {
+        self.as_ref().put(key, content).await
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, ServiceError> {
+        self.as_ref().get(key).await
+    }
+}
+
+/// Keeps every key as a file below the configured directory.
+pub struct LocalStorage {
+    dir: String,
+}
+
+impl LocalStorage {
+    pub fn new(dir: String) -> Self {
+        Self {
+            dir,
+        }
+    }
+
+    /// Keys may only name paths below the directory of the storage.
+    fn path(&self, key: &str) -> Result<PathBuf, ServiceError> {
+        let relative = Path::new(key);
+
+        if key.is_empty() || !relative.components().all(|c| matches!(c, Component::Normal(_))) {
+            return Err(ServiceError::Storage(format!("Invalid key {}", key)));
+        }
+
+        Ok(Path::new(&self.dir).join(relative))
+    }
+}
+
+#[async_trait]
+impl Storage for LocalStorage {
+    async fn put(&self, key: &str, content: &[u8]) -> Result<(), ServiceError> {
+        let path = self.path(key)?;
+
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| ServiceError::Storage(format!("Failed to create directory for {}: {}", key, e)))?;
+        }
+
+        tokio::fs::write(&path, content)
+            .await
+            .map_err(|e| ServiceError::Storage(format!("Failed to write {}: {}", key, e)))
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, ServiceError> {
+        let path = self.path(key)?;
+
+        match tokio::fs::read(&path).await {
+            Ok(content) => Ok(Some(content)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(ServiceError::Storage(format!("Failed to read {}: {}", key, e))),
+        }
+    }
+}
+
+pub struct StorageFactory;
+
+impl StorageFactory {
+    pub fn create(config: &StorageConfig) -> Result<Box<dyn Storage>, ServiceError> {
+        match config.driver() {
+            "local" => Ok(
+                Box::new(
+                    LocalStorage::new(config.dir().to_string())
+                )
+            ),
+            driver => Err(
+                ServiceError::Storage(
+                    format!("Unknown storage driver {}", driver)
+                )
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+    use super::*;
+
+    fn storage_fixture() -> LocalStorage {
+        let dir = std::env::temp_dir().join(format!("metan-storage-{}", Uuid::new_v4()));
+
+        LocalStorage::new(dir.to_string_lossy().to_string())
+    }
+
+    #[tokio::test]
+    async fn test_round_trip() {
+        let storage = storage_fixture();
+
+        storage.put("operations/1/receipt", b"first").await.unwrap();
+        storage.put("operations/1/receipt", b"second").await.unwrap();
+
+        assert_eq!(storage.get("operations/1/receipt").await.unwrap(), Some(b"second".to_vec()));
+        assert_eq!(storage.get("operations/2/receipt").await.unwrap(), None);
+
+        tokio::fs::remove_dir_all(&storage.dir).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_keys_outside_of_directory() {
+        let storage = storage_fixture();
+
+        for key in ["", "../secret", "/etc/passwd", "operations/../../secret"] {
+            assert!(matches!(storage.put(key, b"content").await, Err(ServiceError::Storage(_))));
+            assert!(matches!(storage.get(key).await, Err(ServiceError::Storage(_))));
+        }
+    }
+}
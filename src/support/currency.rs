@@ -0,0 +1,163 @@
+use std::fmt;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::Error;
+
+/// Locale used when a currency has no name in the requested one.
+pub const FALLBACK_LOCALE: &str = "en";
+
+/// Entry of the currency registry.
+#[derive(Debug, PartialEq, Eq)]
+pub struct CurrencyDefinition {
+    code: &'static str,
+    minor_units: u32,
+    symbol: &'static str,
+    names: &'static [(&'static str, &'static str)],
+}
+
+/// ISO 4217 currencies known to the application. Supporting a new currency means adding a row here.
+static CURRENCIES: &[CurrencyDefinition] = &[
+    CurrencyDefinition {
+        code: "USD",
+        minor_units: 2,
+        symbol: "$",
+        names: &[("en", "US Dollar"), ("ru", "Доллар")],
+    },
+    CurrencyDefinition {
+        code: "EUR",
+        minor_units: 2,
+        symbol: "€",
+        names: &[("en", "Euro"), ("ru", "Евро")],
+    },
+    CurrencyDefinition {
+        code: "KZT",
+        minor_units: 2,
+        symbol: "₸",
+        names: &[("en", "Tenge"), ("ru", "Тенге")],
+    },
+    CurrencyDefinition {
+        code: "RUB",
+        minor_units: 2,
+        symbol: "₽",
+        names: &[("en", "Russian Ruble"), ("ru", "Рубль")],
+    },
+    CurrencyDefinition {
+        code: "GEL",
+        minor_units: 2,
+        symbol: "₾",
+        names: &[("en", "Lari"), ("ru", "Лари")],
+    },
+];
+
+/// Currency from the registry. Serialized as its ISO 4217 code.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Currency(&'static CurrencyDefinition);
+
+impl Currency {
+    /// Looks the currency up by its ISO 4217 code.
+    pub fn find(code: &str) -> Option<Self> {
+        CURRENCIES.iter()
+            .find(|definition| definition.code == code)
+            .map(Self)
+    }
+
+    pub fn all() -> impl Iterator<Item = Self> {
+        CURRENCIES.iter().map(Self)
+    }
+
+    pub fn code(&self) -> &'static str {
+        self.0.code
+    }
+
+    /// Number of decimal places of the currency's minor unit, e.g. cents.
+    pub fn minor_units(&self) -> u32 {
+        self.0.minor_units
+    }
+
+    pub fn symbol(&self) -> &'static str {
+        self.0.symbol
+    }
+
+    /// Name in the given locale, falling back to English and then to the code.
+    pub fn name(&self, locale: &str) -> &'static str {
+        self.localized(locale)
+            .or_else(|| self.localized(FALLBACK_LOCALE))
+            .unwrap_or(self.0.code)
+    }
+
+    fn localized(&self, locale: &str) -> Option<&'static str> {
+        self.0.names.iter()
+            .find(|(name_locale, _)| *name_locale == locale)
+            .map(|(_, name)| *name)
+    }
+}
+
+impl fmt::Debug for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0.code)
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0.code)
+    }
+}
+
+impl Serialize for Currency {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.0.code)
+    }
+}
+
+impl<'de> Deserialize<'de> for Currency {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = String::deserialize(deserializer)?;
+
+        Self::find(&code)
+            .ok_or_else(|| D::Error::custom(format!("Unknown currency {}", code)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::*;
+
+    #[test]
+    fn test_find() {
+        let currency = Currency::find("GEL").unwrap();
+
+        assert_eq!(currency.code(), "GEL");
+        assert_eq!(currency.minor_units(), 2);
+        assert_eq!(currency.symbol(), "₾");
+        assert!(Currency::find("gel").is_none());
+        assert!(Currency::find("XXX").is_none());
+    }
+
+    #[test]
+    fn test_codes_are_unique() {
+        let mut codes: Vec<_> = Currency::all().map(|currency| currency.code()).collect();
+        codes.sort();
+        codes.dedup();
+
+        assert_eq!(codes.len(), CURRENCIES.len());
+    }
+
+    #[test]
+    fn test_name_falls_back_to_english() {
+        let currency = Currency::find("RUB").unwrap();
+
+        assert_eq!(currency.name("ru"), "Рубль");
+        assert_eq!(currency.name("en"), "Russian Ruble");
+        assert_eq!(currency.name("de"), "Russian Ruble");
+    }
+
+    #[test]
+    fn test_serialized_as_code() {
+        let currency = Currency::find("EUR").unwrap();
+
+        assert_eq!(serde_json::to_value(currency).unwrap(), json!("EUR"));
+        assert_eq!(serde_json::from_value::<Currency>(json!("EUR")).unwrap(), currency);
+        assert!(serde_json::from_value::<Currency>(json!("XXX")).is_err());
+    }
+}
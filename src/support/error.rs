@@ -1,28 +1,69 @@
 use thiserror::Error;
+use crate::features::accounts::error::AccountError;
 use crate::features::auth::error::AuthError;
 use crate::features::balance::error::BalanceError;
+use crate::features::banking::error::BankingError;
+use crate::features::budgets::error::BudgetError;
 use crate::features::categories::error::CategoryError;
+use crate::features::imports::error::ImportError;
+use crate::features::loans::error::LoanError;
 use crate::features::operations::error::OperationError;
+use crate::features::rates::error::RateError;
+use crate::features::recurrences::error::RecurrenceError;
+use crate::features::reports::error::ReportError;
+use crate::features::rules::error::RuleError;
+use crate::features::suggestions::error::SuggestionError;
 use crate::features::tags::error::TagError;
 use crate::support::command_bus::CommandBusError;
+use crate::support::query_bus::QueryBusError;
 
 #[derive(Debug, Clone, Error)]
 pub enum FeatureError {
     #[error("Support error. {0}")]
     Support(SupportError),
 
+    #[error("Account bounded context error. {0}")]
+    Account(AccountError),
+
     #[error("Auth bounded context error. {0}")]
     Auth(AuthError),
 
     #[error("Balance bounded context error. {0}")]
     Balance(BalanceError),
 
+    #[error("Banking bounded context error. {0}")]
+    Banking(BankingError),
+
+    #[error("Budget bounded context error. {0}")]
+    Budget(BudgetError),
+
     #[error("Category bounded context error. {0}")]
     Category(CategoryError),
 
+    #[error("Import bounded context error. {0}")]
+    Import(ImportError),
+
+    #[error("Loan bounded context error. {0}")]
+    Loan(LoanError),
+
     #[error("Operation bounded context error. {0}")]
     Operation(OperationError),
 
+    #[error("Rate bounded context error. {0}")]
+    Rate(RateError),
+
+    #[error("Recurrence bounded context error. {0}")]
+    Recurrence(RecurrenceError),
+
+    #[error("Report bounded context error. {0}")]
+    Report(ReportError),
+
+    #[error("Rule bounded context error. {0}")]
+    Rule(RuleError),
+
+    #[error("Suggestion bounded context error. {0}")]
+    Suggestion(SuggestionError),
+
     #[error("Tag bounded context error. {0}")]
     Tag(TagError),
 }
@@ -37,4 +78,7 @@ pub enum SupportError {
 
     #[error("Id error. {0}")]
     Id(String),
+
+    #[error("Query bus error. {0}")]
+    QueryBus(QueryBusError),
 }
\ No newline at end of file
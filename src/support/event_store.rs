@@ -0,0 +1,357 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Event as it is persisted in an `*_events` table. `version` is the 1-based position of the event in its aggregate stream.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct StoredEvent {
+    id: Uuid,
+    aggregate_id: Uuid,
+    name: String,
+    payload: serde_json::Value,
+    version: i32,
+    created_at: DateTime<Utc>,
+}
+
+impl StoredEvent {
+    pub fn new(id: Uuid, aggregate_id: Uuid, name: String, payload: serde_json::Value, version: i32, created_at: DateTime<Utc>) -> Self {
+        Self {
+            id,
+            aggregate_id,
+            name,
+            payload,
+            version,
+            created_at,
+        }
+    }
+
+    pub fn id(&self) -> &Uuid {
+        &self.id
+    }
+
+    pub fn aggregate_id(&self) -> &Uuid {
+        &self.aggregate_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn payload(&self) -> &serde_json::Value {
+        &self.payload
+    }
+
+    pub fn version(&self) -> i32 {
+        self.version
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+}
+
+/// Event that is about to be appended to a stream. The store assigns its version.
+#[derive(Debug, Clone)]
+pub struct NewEvent {
+    id: Uuid,
+    name: String,
+    payload: serde_json::Value,
+}
+
+impl NewEvent {
+    pub fn new(id: Uuid, name: String, payload: serde_json::Value) -> Self {
+        Self {
+            id,
+            name,
+            payload,
+        }
+    }
+
+    /// Splits a domain event shaped as `{ id, name, payload }` into the columns of the event table.
+    pub fn from_event<E: Serialize>(event: &E) -> Result<Self, EventStoreError> {
+        let value = serde_json::to_value(event)
+            .map_err(|e| EventStoreError::Serialization(e.to_string()))?;
+
+        let id = value.get("id")
+            .and_then(|id| id.as_str())
+            .and_then(|id| Uuid::parse_str(id).ok())
+            .ok_or(EventStoreError::Serialization("Event id is missing".to_string()))?;
+        let name = value.get("name")
+            .and_then(|name| name.as_str())
+            .ok_or(EventStoreError::Serialization("Event name is missing".to_string()))?
+            .to_string();
+        let payload = value.get("payload")
+            .cloned()
+            .ok_or(EventStoreError::Serialization("Event payload is missing".to_string()))?;
+
+        Ok(Self::new(id, name, payload))
+    }
+
+    pub fn id(&self) -> &Uuid {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn payload(&self) -> &serde_json::Value {
+        &self.payload
+    }
+}
+
+/// Domain event enum that can be restored from and written to an event table.
+pub trait StorableEvent: Sized {
+    fn from_stored(stored: &StoredEvent) -> Result<Self, EventStoreError>;
+
+    fn to_new(&self) -> Result<NewEvent, EventStoreError>;
+}
+
+/// Restores a domain event shaped as `{ id, name, payload }` from its stored columns.
+pub fn decode_event<E: DeserializeOwned>(stored: &StoredEvent) -> Result<E, EventStoreError> {
+    let value = serde_json::json!({
+        "id": stored.id(),
+        "name": stored.name(),
+        "payload": stored.payload(),
+    });
+
+    serde_json::from_value(value)
+        .map_err(|e|
+            EventStoreError::Serialization(
+                format!("Failed to decode event {} ({}): {}", stored.id(), stored.name(), e)
+            )
+        )
+}
+
+/// Aggregate state paired with the version of the last event folded into it.
+#[derive(Debug, Clone)]
+pub struct Versioned<A> {
+    aggregate: A,
+    version: i32,
+}
+
+impl<A> Versioned<A> {
+    pub fn new(aggregate: A, version: i32) -> Self {
+        Self {
+            aggregate,
+            version,
+        }
+    }
+
+    pub fn aggregate(&self) -> &A {
+        &self.aggregate
+    }
+
+    pub fn into_aggregate(self) -> A {
+        self.aggregate
+    }
+
+    pub fn version(&self) -> i32 {
+        self.version
+    }
+}
+
+pub trait Aggregate: Sized {
+    type Event: StorableEvent;
+
+    /// Folds a single event into the aggregate. The first event of a stream receives `None`.
+    fn apply(state: Option<Self>, event: &Self::Event) -> Result<Self, EventStoreError>;
+
+    fn rehydrate(events: &[StoredEvent]) -> Result<Option<Versioned<Self>>, EventStoreError> {
+        let mut state: Option<Self> = None;
+        let mut version = 0;
+
+        for stored in events {
+            if stored.version() != version + 1 {
+                return Err(
+                    EventStoreError::Rehydration(
+                        format!("Expected version {} of stream {}, got {}", version + 1, stored.aggregate_id(), stored.version())
+                    )
+                );
+            }
+
+            let event = Self::Event::from_stored(stored)?;
+            state = Some(Self::apply(state, &event)?);
+            version = stored.version();
+        }
+
+        Ok(state.map(|aggregate| Versioned::new(aggregate, version)))
+    }
+}
+
+#[async_trait]
+pub trait EventStore: Send + Sync {
+    async fn load(&self, aggregate_id: Uuid) -> Result<Vec<StoredEvent>, EventStoreError>;
+
+    /// Appends events after `expected_version` and returns the new stream version.
+    async fn append(&self, aggregate_id: Uuid, expected_version: i32, events: &[NewEvent]) -> Result<i32, EventStoreError>;
+}
+
+#[derive(Debug, Clone, Error)]
+pub enum EventStoreError {
+    #[error("Stream {aggregate_id} is at version {actual}, expected {expected}")]
+    Concurrency {
+        aggregate_id: Uuid,
+        expected: i32,
+        actual: i32,
+    },
+
+    #[error("Event serialization error. {0}")]
+    Serialization(String),
+
+    #[error("Aggregate rehydration error. {0}")]
+    Rehydration(String),
+
+    #[error("Event storage error. {0}")]
+    Storage(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use serde_json::json;
+    use super::*;
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct Counted {
+        id: Uuid,
+        name: String,
+        payload: CountedPayload,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct CountedPayload {
+        by: i32,
+    }
+
+    impl StorableEvent for Counted {
+        fn from_stored(stored: &StoredEvent) -> Result<Self, EventStoreError> {
+            decode_event(stored)
+        }
+
+        fn to_new(&self) -> Result<NewEvent, EventStoreError> {
+            NewEvent::from_event(self)
+        }
+    }
+
+    struct Counter(i32);
+
+    impl Aggregate for Counter {
+        type Event = Counted;
+
+        fn apply(state: Option<Self>, event: &Counted) -> Result<Self, EventStoreError> {
+            let current = state.map(|counter| counter.0).unwrap_or(0);
+
+            Ok(Counter(current + event.payload.by))
+        }
+    }
+
+    /// In-memory event store.
+    #[derive(Default)]
+    pub struct MockEventStore {
+        streams: Mutex<HashMap<Uuid, Vec<StoredEvent>>>,
+    }
+
+    impl MockEventStore {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    #[async_trait]
+    impl EventStore for MockEventStore {
+        async fn load(&self, aggregate_id: Uuid) -> Result<Vec<StoredEvent>, EventStoreError> {
+            let streams = self.streams.lock()
+                .map_err(|e| EventStoreError::Storage(e.to_string()))?;
+
+            Ok(streams.get(&aggregate_id).cloned().unwrap_or_default())
+        }
+
+        async fn append(&self, aggregate_id: Uuid, expected_version: i32, events: &[NewEvent]) -> Result<i32, EventStoreError> {
+            let mut streams = self.streams.lock()
+                .map_err(|e| EventStoreError::Storage(e.to_string()))?;
+            let stream = streams.entry(aggregate_id).or_default();
+
+            let actual = stream.last().map(|event| event.version()).unwrap_or(0);
+            if actual != expected_version {
+                return Err(
+                    EventStoreError::Concurrency {
+                        aggregate_id,
+                        expected: expected_version,
+                        actual,
+                    }
+                );
+            }
+
+            let mut version = expected_version;
+            for event in events {
+                version += 1;
+                stream.push(
+                    StoredEvent::new(*event.id(), aggregate_id, event.name().to_string(), event.payload().clone(), version, Utc::now())
+                );
+            }
+
+            Ok(version)
+        }
+    }
+
+    fn counted(by: i32) -> NewEvent {
+        Counted { id: Uuid::new_v4(), name: "counted".to_string(), payload: CountedPayload { by } }
+            .to_new()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_new_event_from_event() {
+        let event = counted(3);
+
+        assert_eq!(event.name(), "counted");
+        assert_eq!(event.payload(), &json!({ "by": 3 }));
+    }
+
+    #[tokio::test]
+    async fn test_append_and_rehydrate() {
+        let store = MockEventStore::new();
+        let aggregate_id = Uuid::new_v4();
+
+        let version = store.append(aggregate_id, 0, &[counted(1), counted(2)]).await.unwrap();
+        assert_eq!(version, 2);
+
+        let version = store.append(aggregate_id, 2, &[counted(4)]).await.unwrap();
+        assert_eq!(version, 3);
+
+        let stream = store.load(aggregate_id).await.unwrap();
+        let counter = Counter::rehydrate(&stream).unwrap().unwrap();
+
+        assert_eq!(counter.version(), 3);
+        assert_eq!(counter.aggregate().0, 7);
+    }
+
+    #[tokio::test]
+    async fn test_append_with_stale_version() {
+        let store = MockEventStore::new();
+        let aggregate_id = Uuid::new_v4();
+
+        store.append(aggregate_id, 0, &[counted(1)]).await.unwrap();
+        let res = store.append(aggregate_id, 0, &[counted(1)]).await;
+
+        assert!(matches!(res, Err(EventStoreError::Concurrency { expected: 0, actual: 1, .. })));
+    }
+
+    #[test]
+    fn test_rehydrate_empty_stream() {
+        assert!(Counter::rehydrate(&[]).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_rehydrate_with_version_gap() {
+        let stored = StoredEvent::new(Uuid::new_v4(), Uuid::new_v4(), "counted".to_string(), json!({ "by": 1 }), 2, Utc::now());
+
+        assert!(matches!(Counter::rehydrate(&[stored]), Err(EventStoreError::Rehydration(..))));
+    }
+}
@@ -1,4 +1,10 @@
 pub mod command_bus;
+pub mod currency;
 pub mod data_mapper;
 pub mod error;
+pub mod event_store;
 pub mod id;
+pub mod money;
+pub mod name;
+pub mod pg_event_store;
+pub mod query_bus;
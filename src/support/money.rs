@@ -0,0 +1,102 @@
+use rust_decimal::{Decimal, RoundingStrategy};
+
+/// Minor units of amounts that are not tied to a currency of their own, e.g. the converted amount of an operation.
+pub const DEFAULT_MINOR_UNITS: u32 = 2;
+
+/// Rounds a money value to `minor_units` decimal places, a half goes away from zero.
+pub fn round_money(value: Decimal, minor_units: u32) -> Decimal {
+    value.round_dp_with_strategy(minor_units, RoundingStrategy::MidpointAwayFromZero)
+}
+
+/// Largest error rounding to `minor_units` can introduce.
+pub fn half_minor_unit(minor_units: u32) -> Decimal {
+    Decimal::new(5, minor_units + 1)
+}
+
+/// Checks that `amount` is `currency_amount` converted at `rate`. Both amounts are rounded to their minor units,
+/// so they may be off by half a unit each, and the error of `currency_amount` grows with the rate.
+pub fn is_conversion_of(
+    amount: Decimal,
+    amount_minor_units: u32,
+    currency_amount: Decimal,
+    currency_minor_units: u32,
+    rate: Decimal,
+) -> bool {
+    let converted = currency_amount.checked_mul(rate);
+    let tolerance = half_minor_unit(currency_minor_units)
+        .checked_mul(rate)
+        .and_then(|tolerance| tolerance.checked_add(half_minor_unit(amount_minor_units)));
+
+    match (converted, tolerance) {
+        (Some(converted), Some(tolerance)) => (amount - converted).abs() <= tolerance,
+        _ => false,
+    }
+}
+
+/// Splits `total` in proportion to `weights`, rounded to `minor_units`. Every part is first rounded down, then the
+/// minor units that are left go to the parts with the largest remainders, so the parts always add up to `total`.
+pub fn allocate(total: Decimal, weights: &[Decimal], minor_units: u32) -> Vec<Decimal> {
+    let weight: Decimal = weights.iter().sum();
+    if weight.is_zero() {
+        return vec![Decimal::ZERO; weights.len()];
+    }
+
+    let shares: Vec<Decimal> = weights.iter()
+        .map(|part| part / weight * total)
+        .collect();
+    let mut parts: Vec<Decimal> = shares.iter()
+        .map(|share| share.round_dp_with_strategy(minor_units, RoundingStrategy::ToZero))
+        .collect();
+
+    let mut by_remainder: Vec<usize> = (0..parts.len()).collect();
+    by_remainder.sort_by(|a, b| (shares[*b] - parts[*b]).cmp(&(shares[*a] - parts[*a])));
+
+    let unit = Decimal::new(1, minor_units);
+    let mut left = total - parts.iter().sum::<Decimal>();
+    for i in by_remainder {
+        if left < unit {
+            break;
+        }
+        parts[i] += unit;
+        left -= unit;
+    }
+
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+    use super::*;
+
+    fn dec(value: &str) -> Decimal {
+        Decimal::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn test_round_money() {
+        assert_eq!(round_money(dec("10.005"), 2), dec("10.01"));
+        assert_eq!(round_money(dec("-10.005"), 2), dec("-10.01"));
+        assert_eq!(round_money(dec("10.004"), 2), dec("10.00"));
+        assert_eq!(round_money(dec("1234.5"), 0), dec("1235"));
+    }
+
+    #[test]
+    fn test_is_conversion_of() {
+        assert!(is_conversion_of(dec("10.00"), 2, dec("3.33"), 2, dec("3.003003")));
+        assert!(is_conversion_of(dec("4750"), 0, dec("10"), 2, dec("475")));
+        assert!(!is_conversion_of(dec("10.03"), 2, dec("3.33"), 2, dec("3.003003")));
+    }
+
+    #[test]
+    fn test_allocate() {
+        assert_eq!(allocate(dec("108"), &[dec("33.33"), dec("33.33"), dec("33.34")], 2), vec![dec("36.00"), dec("35.99"), dec("36.01")]);
+        assert_eq!(allocate(dec("10"), &[dec("1"), dec("1"), dec("1")], 2), vec![dec("3.34"), dec("3.33"), dec("3.33")]);
+        assert_eq!(allocate(dec("0.02"), &[dec("0.01"), dec("0.01"), dec("0.01")], 2), vec![dec("0.01"), dec("0.01"), dec("0")]);
+    }
+
+    #[test]
+    fn test_is_conversion_of_overflow() {
+        assert!(!is_conversion_of(Decimal::MAX, 2, Decimal::MAX, 2, dec("2")));
+    }
+}
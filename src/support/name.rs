@@ -0,0 +1,20 @@
+/// Form of a user given name that is compared when looking for duplicates:
+/// surrounding whitespace is dropped, inner runs collapse to a single space and letters are lowercased.
+pub fn normalize_name(name: &str) -> String {
+    name.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_name() {
+        assert_eq!(normalize_name("  Groceries "), "groceries");
+        assert_eq!(normalize_name("Eating\t  Out"), "eating out");
+        assert_eq!(normalize_name("ПРОДУКТЫ"), "продукты");
+    }
+}
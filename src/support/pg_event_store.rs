@@ -0,0 +1,147 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use sqlx::{query, query_as, Pool, Postgres, Row};
+use tokio::sync::Mutex;
+use uuid::Uuid;
+use crate::db::manager::DbManager;
+use crate::support::event_store::{EventStore, EventStoreError, NewEvent, StoredEvent};
+
+const UNIQUE_VIOLATION: &str = "23505";
+
+/// Event store backed by one of the `*_events` tables.
+#[derive(Clone)]
+pub struct PgEventStore {
+    db_manager: Arc<Mutex<DbManager>>,
+    table: &'static str,
+}
+
+impl PgEventStore {
+    pub fn new(db_manager: Arc<Mutex<DbManager>>, table: &'static str) -> Self {
+        Self {
+            db_manager,
+            table,
+        }
+    }
+
+    /// Unique index over `(aggregate_id, version)` that rejects a second writer of the same version.
+    fn version_index(&self) -> String {
+        format!("{}_aggregate_id_version_idx", self.table)
+    }
+
+    async fn pool(&self) -> Result<Pool<Postgres>, EventStoreError> {
+        let guard = self.db_manager.lock().await;
+
+        guard.pool()
+            .map_err(|e|
+                EventStoreError::Storage(
+                    format!("Failed to get pool: {}", e)
+                )
+            )
+    }
+}
+
+#[async_trait]
+impl EventStore for PgEventStore {
+    async fn load(&self, aggregate_id: Uuid) -> Result<Vec<StoredEvent>, EventStoreError> {
+        let q = format!(
+            "SELECT id, aggregate_id, name, payload, version, created_at FROM {} WHERE aggregate_id = $1 ORDER BY version",
+            self.table
+        );
+
+        let pool = self.pool().await?;
+
+        query_as::<_, StoredEvent>(&q)
+            .bind(aggregate_id)
+            .fetch_all(&pool)
+            .await
+            .map_err(|e|
+                EventStoreError::Storage(
+                    format!("Failed to load stream {}: {}", aggregate_id, e)
+                )
+            )
+    }
+
+    async fn append(&self, aggregate_id: Uuid, expected_version: i32, events: &[NewEvent]) -> Result<i32, EventStoreError> {
+        let pool = self.pool().await?;
+
+        let mut tx = pool.begin()
+            .await
+            .map_err(|e|
+                EventStoreError::Storage(
+                    format!("Failed to begin transaction: {}", e)
+                )
+            )?;
+
+        let q = format!("SELECT COALESCE(MAX(version), 0) FROM {} WHERE aggregate_id = $1", self.table);
+        let actual = query(&q)
+            .bind(aggregate_id)
+            .fetch_one(&mut *tx)
+            .await
+            .and_then(|row| row.try_get::<i32, _>(0))
+            .map_err(|e|
+                EventStoreError::Storage(
+                    format!("Failed to get version of stream {}: {}", aggregate_id, e)
+                )
+            )?;
+
+        if actual != expected_version {
+            return Err(
+                EventStoreError::Concurrency {
+                    aggregate_id,
+                    expected: expected_version,
+                    actual,
+                }
+            );
+        }
+
+        let q = format!(
+            "INSERT INTO {} (id, aggregate_id, name, payload, version) VALUES ($1, $2, $3, $4, $5)",
+            self.table
+        );
+
+        let mut version = expected_version;
+        for event in events {
+            version += 1;
+
+            query(&q)
+                .bind(event.id())
+                .bind(aggregate_id)
+                .bind(event.name())
+                .bind(event.payload())
+                .bind(version)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| {
+                    // A concurrent writer took the same version between our check and insert
+                    let is_conflict = e.as_database_error()
+                        .map(|db_error|
+                            db_error.code().as_deref() == Some(UNIQUE_VIOLATION)
+                                && db_error.constraint() == Some(self.version_index().as_str())
+                        )
+                        .unwrap_or(false);
+
+                    if is_conflict {
+                        EventStoreError::Concurrency {
+                            aggregate_id,
+                            expected: expected_version,
+                            actual: version,
+                        }
+                    } else {
+                        EventStoreError::Storage(
+                            format!("Failed to append event {}: {}", event.id(), e)
+                        )
+                    }
+                })?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e|
+                EventStoreError::Storage(
+                    format!("Failed to commit transaction: {}", e)
+                )
+            )?;
+
+        Ok(version)
+    }
+}
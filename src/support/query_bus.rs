@@ -0,0 +1,73 @@
+use std::collections::HashMap;
+use async_trait::async_trait;
+use thiserror::Error;
+
+use crate::support::error::{FeatureError, SupportError};
+
+pub trait Query {
+    fn name() -> &'static str;
+}
+
+#[async_trait]
+pub trait QueryHandler<Q: Query>: Send + Sync {
+    type Output;
+
+    async fn handle(&self, query: Q) -> Result<Self::Output, FeatureError>;
+}
+
+pub struct QueryBus<Q, H>
+    where
+        Q: Query,
+        H: QueryHandler<Q>,
+{
+    handlers: HashMap<String, H>,
+    _phantom: std::marker::PhantomData<Q>,
+}
+
+impl<Q, H> QueryBus<Q, H>
+    where
+        Q: Query,
+        H: QueryHandler<Q>,
+{
+    pub fn new() -> Self {
+        Self {
+            handlers: HashMap::new(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    pub fn register(&mut self, handler: H) {
+        self.handlers.insert(Q::name().to_string(), handler);
+    }
+
+    pub async fn dispatch(&self, query: Q) -> Result<H::Output, FeatureError>
+    {
+        let name = Q::name();
+
+        let handler = self.handlers.get(name).ok_or_else(||
+            FeatureError::Support(
+                SupportError::QueryBus(
+                    QueryBusError::HandlerNotFound(name.to_string())
+                )
+            )
+        )?;
+
+        handler.handle(query).await
+    }
+}
+
+impl<Q, H> Default for QueryBus<Q, H>
+    where
+        Q: Query,
+        H: QueryHandler<Q>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone, Debug, Error)]
+pub enum QueryBusError {
+    #[error("Handler for query {0} not found")]
+    HandlerNotFound(String),
+}
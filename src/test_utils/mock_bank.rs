@@ -0,0 +1,161 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use actix_web::dev::Server;
+use actix_web::web::{self, Data, Json, Path, Query};
+use actix_web::{App, HttpRequest, HttpResponse, HttpServer};
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use serde_json::json;
+use crate::features::banking::domain::bank_account::BankAccount;
+use crate::features::banking::domain::bank_connector::TransactionPage;
+use crate::features::banking::domain::bank_transaction::BankTransaction;
+
+pub const MOCK_BANK_TOKEN: &str = "mock-token";
+pub const MOCK_BANK_ACCOUNT: &str = "mock-checking";
+
+const PAGE_SIZE: usize = 2;
+
+type Accounts = BTreeMap<String, (BankAccount, Vec<BankTransaction>)>;
+
+#[derive(Deserialize)]
+struct TransactionsQuery {
+    cursor: Option<String>,
+}
+
+/// In-memory bank speaking the API `RestBankConnector` expects, for running syncs offline.
+/// Cursors are offsets in the transaction list of an account, pages are kept small
+/// so paging is exercised.
+#[derive(Clone)]
+pub struct MockBank {
+    accounts: Arc<Mutex<Accounts>>,
+}
+
+impl MockBank {
+    /// Bank with one EUR account holding a salary and two card payments.
+    pub fn new() -> Self {
+        let transactions = vec![
+            Self::transaction("tx-1", (2024, 4, 1), Decimal::new(250000, 2), "Salary"),
+            Self::transaction("tx-2", (2024, 4, 2), Decimal::new(-4590, 2), "Groceries"),
+            Self::transaction("tx-3", (2024, 4, 3), Decimal::new(-1200, 2), "Cinema"),
+        ];
+
+        let mut accounts = BTreeMap::new();
+        accounts.insert(
+            MOCK_BANK_ACCOUNT.to_string(),
+            (
+                BankAccount::new(MOCK_BANK_ACCOUNT.to_string(), "Checking".to_string(), "EUR".to_string()),
+                transactions,
+            ),
+        );
+
+        Self {
+            accounts: Arc::new(Mutex::new(accounts)),
+        }
+    }
+
+    /// Books a transaction, it is returned by the next sync.
+    pub fn add_transaction(&self, account_id: &str, transaction: BankTransaction) {
+        if let Some((_, transactions)) = self.accounts.lock().unwrap().get_mut(account_id) {
+            transactions.push(transaction);
+        }
+    }
+
+    pub fn start(&self, port: u16) -> std::io::Result<Server> {
+        let bank = self.clone();
+
+        let server = HttpServer::new(move || {
+            App::new()
+                .app_data(Data::new(bank.clone()))
+                .route("/accounts", web::get().to(Self::list_accounts))
+                .route("/accounts/{id}/transactions", web::get().to(Self::list_transactions))
+                .route("/accounts/{id}/transactions", web::post().to(Self::create_transaction))
+        })
+            .workers(1)
+            .bind(("127.0.0.1", port))?
+            .run();
+
+        Ok(server)
+    }
+
+    async fn list_accounts(request: HttpRequest, bank: Data<MockBank>) -> HttpResponse {
+        if !Self::authorized(&request) {
+            return HttpResponse::Unauthorized().finish();
+        }
+
+        let accounts: Vec<BankAccount> = bank.accounts.lock().unwrap()
+            .values()
+            .map(|(account, _)| account.clone())
+            .collect();
+
+        HttpResponse::Ok().json(json!({ "accounts": accounts }))
+    }
+
+    async fn list_transactions(
+        request: HttpRequest,
+        id: Path<String>,
+        query: Query<TransactionsQuery>,
+        bank: Data<MockBank>,
+    ) -> HttpResponse {
+        if !Self::authorized(&request) {
+            return HttpResponse::Unauthorized().finish();
+        }
+
+        let accounts = bank.accounts.lock().unwrap();
+        let Some((_, transactions)) = accounts.get(id.as_str()) else {
+            return HttpResponse::NotFound().finish();
+        };
+
+        let offset = match &query.cursor {
+            Some(cursor) => match cursor.parse::<usize>() {
+                Ok(offset) => offset.min(transactions.len()),
+                Err(_) => return HttpResponse::BadRequest().finish(),
+            },
+            None => 0,
+        };
+        let end = (offset + PAGE_SIZE).min(transactions.len());
+
+        HttpResponse::Ok().json(
+            TransactionPage::new(transactions[offset..end].to_vec(), end.to_string(), end < transactions.len())
+        )
+    }
+
+    async fn create_transaction(
+        request: HttpRequest,
+        id: Path<String>,
+        transaction: Json<BankTransaction>,
+        bank: Data<MockBank>,
+    ) -> HttpResponse {
+        if !Self::authorized(&request) {
+            return HttpResponse::Unauthorized().finish();
+        }
+
+        bank.add_transaction(id.as_str(), transaction.into_inner());
+
+        HttpResponse::Ok().finish()
+    }
+
+    fn authorized(request: &HttpRequest) -> bool {
+        request.headers()
+            .get("Authorization")
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value == format!("Bearer {}", MOCK_BANK_TOKEN))
+            .unwrap_or(false)
+    }
+
+    fn transaction(id: &str, (year, month, day): (i32, u32, u32), amount: Decimal, label: &str) -> BankTransaction {
+        BankTransaction::new(
+            id.to_string(),
+            NaiveDate::from_ymd_opt(year, month, day).unwrap(),
+            amount,
+            "EUR".to_string(),
+            label.to_string(),
+        )
+    }
+}
+
+impl Default for MockBank {
+    fn default() -> Self {
+        Self::new()
+    }
+}
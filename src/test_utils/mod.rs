@@ -1 +1,3 @@
-pub mod environment;
\ No newline at end of file
+pub mod environment;
+pub mod mock_bank;
+pub mod stub_http_client;
\ No newline at end of file
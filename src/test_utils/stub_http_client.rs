@@ -0,0 +1,57 @@
+use std::sync::Mutex;
+use async_trait::async_trait;
+use crate::services::error::ServiceError;
+use crate::services::http_client::HttpClient;
+
+/// HTTP client answering every GET with the same body, for testing the clients of remote APIs offline. Other methods fail.
+/// Requested urls are kept with the token they were authorized with, if any.
+pub struct StubHttpClient {
+    body: String,
+    requested: Mutex<Vec<(String, Option<String>)>>,
+}
+
+impl StubHttpClient {
+    pub fn new(body: &str) -> Self {
+        Self {
+            body: body.to_string(),
+            requested: Mutex::new(vec![]),
+        }
+    }
+
+    pub fn requested(&self) -> Vec<(String, Option<String>)> {
+        self.requested.lock().unwrap().clone()
+    }
+
+    fn answer(&self, url: &str, token: Option<&str>) -> Result<String, ServiceError> {
+        self.requested.lock().unwrap().push((url.to_string(), token.map(str::to_string)));
+
+        Ok(self.body.clone())
+    }
+
+    fn unsupported(&self, method: &str, url: &str) -> Result<String, ServiceError> {
+        Err(ServiceError::HttpClient(format!("Stub client does not answer {} {}", method, url)))
+    }
+}
+
+#[async_trait]
+impl HttpClient for StubHttpClient {
+    async fn get(&self, url: &str) -> Result<String, ServiceError> {
+        self.answer(url, None)
+    }
+
+    async fn get_authorized(&self, url: &str, token: &str) -> Result<String, ServiceError> {
+        self.answer(url, Some(token))
+    }
+
+    async fn post(&self, url: &str, _body: &str) -> Result<String, ServiceError> {
+        self.unsupported("POST", url)
+    }
+
+    async fn put(&self, url: &str, _body: &str) -> Result<String, ServiceError> {
+        self.unsupported("PUT", url)
+    }
+
+    async fn delete(&self, url: &str) -> Result<String, ServiceError> {
+        self.unsupported("DELETE", url)
+    }
+}
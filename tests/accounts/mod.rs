@@ -0,0 +1 @@
+pub mod list_test;
\ No newline at end of file
@@ -0,0 +1,43 @@
+use actix_web::{App, test};
+use actix_web::http::header::AUTHORIZATION;
+use actix_web::web::Data;
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+use metan::http::handlers::balance::get::get_balance;
+use metan::services::jwt::Claims;
+use metan::services::jwt::JwtService;
+use metan::test_utils::environment::Environment;
+
+#[actix_rt::test]
+async fn test_get_balance() {
+    let environment = Environment::new();
+    let (service_container, event_bus, _) = environment.setup().await;
+
+    let app = test::init_service(
+        App::new()
+            .wrap(actix_web::middleware::Logger::default())
+            .app_data(Data::new(service_container.clone()))
+            .app_data(Data::new(event_bus))
+            .service(get_balance)
+    ).await;
+
+    let jwt_service = service_container.jwt_service();
+    let claims = Claims::new(
+        Uuid::new_v4().to_string(),
+        Utc::now().timestamp() as usize + Duration::days(30).num_seconds() as usize,
+        format!("{}@example.com", Uuid::new_v4()),
+    );
+    let token = jwt_service.create(claims).expect("Failed to create token");
+
+    let req = test::TestRequest::get()
+        .uri("")
+        .insert_header((
+            AUTHORIZATION,
+            format!("Bearer {}", token)
+        ))
+        .to_request();
+
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), 200);
+}
@@ -0,0 +1,98 @@
+use actix_web::{App, test};
+use actix_web::http::header::AUTHORIZATION;
+use actix_web::web::Data;
+use chrono::{Duration, Utc};
+use serde_json::{json, Value};
+use uuid::Uuid;
+use metan::http::handlers::bank_connections::accounts::list_bank_accounts;
+use metan::http::handlers::bank_connections::create::create_bank_connection;
+use metan::http::handlers::bank_connections::list::list_bank_connections;
+use metan::services::jwt::Claims;
+use metan::services::jwt::JwtService;
+use metan::test_utils::environment::Environment;
+use metan::test_utils::mock_bank::{MockBank, MOCK_BANK_ACCOUNT, MOCK_BANK_TOKEN};
+
+#[actix_rt::test]
+async fn test_bank_connections() {
+    let environment = Environment::new();
+    let (service_container, event_bus, _) = environment.setup().await;
+
+    // Port of the mock provider in config/banking.toml
+    let mock_bank = MockBank::new().start(8090).expect("Failed to start mock bank");
+    actix_rt::spawn(mock_bank);
+
+    let app = test::init_service(
+        App::new()
+            .wrap(actix_web::middleware::Logger::default())
+            .app_data(Data::new(service_container.clone()))
+            .app_data(Data::new(event_bus))
+            .service(list_bank_accounts)
+            .service(create_bank_connection)
+            .service(list_bank_connections)
+    ).await;
+
+    let jwt_service = service_container.jwt_service();
+    let claims = Claims::new(
+        Uuid::new_v4().to_string(),
+        Utc::now().timestamp() as usize + Duration::days(30).num_seconds() as usize,
+        format!("{}@example.com", Uuid::new_v4()),
+    );
+    let token = jwt_service.create(claims).expect("Failed to create token");
+
+    for (provider, credentials, status) in [
+        ("mock", MOCK_BANK_TOKEN, 200),
+        ("mock", "wrong-token", 502),
+        ("unknown", MOCK_BANK_TOKEN, 422),
+    ] {
+        let req = test::TestRequest::post()
+            .uri("/accounts")
+            .insert_header((
+                AUTHORIZATION,
+                format!("Bearer {}", token)
+            ))
+            .set_json(json!({
+                "provider": provider,
+                "credentials": credentials,
+            }))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), status);
+
+        if status == 200 {
+            let accounts: Value = test::read_body_json(resp).await;
+            assert_eq!(accounts[0]["id"], MOCK_BANK_ACCOUNT);
+        }
+    }
+
+    let req = test::TestRequest::post()
+        .uri("/create")
+        .insert_header((
+            AUTHORIZATION,
+            format!("Bearer {}", token)
+        ))
+        .set_json(json!({
+            "account_id": Uuid::new_v4(),
+            "provider": "mock",
+            "external_account_id": MOCK_BANK_ACCOUNT,
+            "credentials": MOCK_BANK_TOKEN,
+        }))
+        .to_request();
+
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), 404);
+
+    let req = test::TestRequest::get()
+        .uri("")
+        .insert_header((
+            AUTHORIZATION,
+            format!("Bearer {}", token)
+        ))
+        .to_request();
+
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), 200);
+}
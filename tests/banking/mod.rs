@@ -0,0 +1 @@
+mod bank_connection_test;
\ No newline at end of file
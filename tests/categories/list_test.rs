@@ -0,0 +1,59 @@
+use actix_web::{App, test};
+use actix_web::http::header::AUTHORIZATION;
+use actix_web::web::Data;
+use chrono::{Duration, Utc};
+use serde_json::json;
+use uuid::Uuid;
+use metan::http::handlers::categories::list::list_categories;
+use metan::http::handlers::categories::rename::rename_category;
+use metan::services::jwt::Claims;
+use metan::services::jwt::JwtService;
+use metan::test_utils::environment::Environment;
+
+#[actix_rt::test]
+async fn test_list_categories() {
+    let environment = Environment::new();
+    let (service_container, event_bus, _) = environment.setup().await;
+
+    let app = test::init_service(
+        App::new()
+            .wrap(actix_web::middleware::Logger::default())
+            .app_data(Data::new(service_container.clone()))
+            .app_data(Data::new(event_bus))
+            .service(list_categories)
+            .service(rename_category)
+    ).await;
+
+    let jwt_service = service_container.jwt_service();
+    let claims = Claims::new(
+        Uuid::new_v4().to_string(),
+        Utc::now().timestamp() as usize + Duration::days(30).num_seconds() as usize,
+        format!("{}@example.com", Uuid::new_v4()),
+    );
+    let token = jwt_service.create(claims).expect("Failed to create token");
+
+    let req = test::TestRequest::get()
+        .uri("?include_archived=true")
+        .insert_header((
+            AUTHORIZATION,
+            format!("Bearer {}", token)
+        ))
+        .to_request();
+
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), 200);
+
+    let req = test::TestRequest::put()
+        .uri(format!("/{}/name", Uuid::new_v4()).as_str())
+        .insert_header((
+            AUTHORIZATION,
+            format!("Bearer {}", token)
+        ))
+        .set_json(&json!({ "name": "Groceries" }))
+        .to_request();
+
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), 404);
+}
@@ -1 +1,3 @@
-pub mod creation_test;
\ No newline at end of file
+pub mod creation_test;
+pub mod list_test;
+pub mod report_test;
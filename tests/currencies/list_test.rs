@@ -0,0 +1,35 @@
+use actix_web::{App, test};
+use actix_web::web::Data;
+use serde_json::Value;
+use metan::http::handlers::currencies::list::list_currencies;
+use metan::test_utils::environment::Environment;
+
+#[actix_rt::test]
+async fn test_list_currencies() {
+    let environment = Environment::new();
+    let (service_container, event_bus, _) = environment.setup().await;
+
+    let app = test::init_service(
+        App::new()
+            .wrap(actix_web::middleware::Logger::default())
+            .app_data(Data::new(service_container.clone()))
+            .app_data(Data::new(event_bus))
+            .service(list_currencies)
+    ).await;
+
+    let req = test::TestRequest::get()
+        .uri("")
+        .to_request();
+
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), 200);
+
+    let body: Value = test::read_body_json(resp).await;
+    let usd = body.as_array()
+        .and_then(|currencies| currencies.iter().find(|currency| currency["code"] == "USD"))
+        .expect("USD is missing");
+
+    assert_eq!(usd["symbol"], "$");
+    assert_eq!(usd["minor_units"], 2);
+}
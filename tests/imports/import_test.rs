@@ -0,0 +1,70 @@
+use actix_web::{App, test};
+use actix_web::http::header::{AUTHORIZATION, CONTENT_TYPE};
+use actix_web::web::Data;
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+use metan::http::handlers::imports::import::import_statement;
+use metan::services::jwt::Claims;
+use metan::services::jwt::JwtService;
+use metan::test_utils::environment::Environment;
+
+const BOUNDARY: &str = "statement-boundary";
+
+#[actix_rt::test]
+async fn test_import_statement() {
+    let environment = Environment::new();
+    let (service_container, event_bus, _) = environment.setup().await;
+
+    let app = test::init_service(
+        App::new()
+            .wrap(actix_web::middleware::Logger::default())
+            .app_data(Data::new(service_container.clone()))
+            .app_data(Data::new(event_bus))
+            .service(import_statement)
+    ).await;
+
+    let jwt_service = service_container.jwt_service();
+    let claims = Claims::new(
+        Uuid::new_v4().to_string(),
+        Utc::now().timestamp() as usize + Duration::days(30).num_seconds() as usize,
+        format!("{}@example.com", Uuid::new_v4()),
+    );
+    let token = jwt_service.create(claims).expect("Failed to create token");
+
+    let account_id = Uuid::new_v4().to_string();
+    let statement = "date,amount,label\n2024-04-01,-3.50,Coffee\n";
+
+    for (fields, status) in [
+        (vec![("format", "Csv"), ("account_id", account_id.as_str())], 400),
+        (vec![("format", "Csv"), ("account_id", account_id.as_str()), ("dry_run", "true"), ("file", statement)], 404),
+        (vec![("format", "xls"), ("account_id", account_id.as_str()), ("file", statement)], 422),
+    ] {
+        let req = test::TestRequest::post()
+            .uri("/statement")
+            .insert_header((
+                AUTHORIZATION,
+                format!("Bearer {}", token)
+            ))
+            .insert_header((
+                CONTENT_TYPE,
+                format!("multipart/form-data; boundary={}", BOUNDARY)
+            ))
+            .set_payload(multipart_body(&fields))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), status);
+    }
+}
+
+fn multipart_body(fields: &[(&str, &str)]) -> String {
+    let mut body = String::new();
+
+    for (name, value) in fields {
+        body.push_str(&format!("--{}\r\nContent-Disposition: form-data; name=\"{}\"\r\n\r\n{}\r\n", BOUNDARY, name, value));
+    }
+    body.push_str(&format!("--{}--\r\n", BOUNDARY));
+
+    body
+}
@@ -0,0 +1 @@
+mod import_test;
\ No newline at end of file
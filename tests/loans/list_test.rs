@@ -0,0 +1,82 @@
+use actix_web::{App, test};
+use actix_web::http::header::AUTHORIZATION;
+use actix_web::web::Data;
+use chrono::{Duration, Utc};
+use serde_json::json;
+use uuid::Uuid;
+use metan::http::handlers::loans::create::create_loan;
+use metan::http::handlers::loans::list::list_loans;
+use metan::http::handlers::loans::record_repayment::record_repayment;
+use metan::services::jwt::Claims;
+use metan::services::jwt::JwtService;
+use metan::test_utils::environment::Environment;
+
+#[actix_rt::test]
+async fn test_list_loans() {
+    let environment = Environment::new();
+    let (service_container, event_bus, _) = environment.setup().await;
+
+    let app = test::init_service(
+        App::new()
+            .wrap(actix_web::middleware::Logger::default())
+            .app_data(Data::new(service_container.clone()))
+            .app_data(Data::new(event_bus))
+            .service(create_loan)
+            .service(list_loans)
+            .service(record_repayment)
+    ).await;
+
+    let jwt_service = service_container.jwt_service();
+    let claims = Claims::new(
+        Uuid::new_v4().to_string(),
+        Utc::now().timestamp() as usize + Duration::days(30).num_seconds() as usize,
+        format!("{}@example.com", Uuid::new_v4()),
+    );
+    let token = jwt_service.create(claims).expect("Failed to create token");
+
+    let req = test::TestRequest::post()
+        .uri("/create")
+        .insert_header((
+            AUTHORIZATION,
+            format!("Bearer {}", token)
+        ))
+        .set_json(&json!({
+            "direction": "Taken",
+            "counterparty": "Bank",
+            "principal": "1200.00",
+            "currency": "USD",
+            "interest_rate": "12",
+            "issued_on": "2024-01-15",
+            "term_months": 12
+        }))
+        .to_request();
+
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), 200);
+
+    let req = test::TestRequest::get()
+        .uri("?overdue=true")
+        .insert_header((
+            AUTHORIZATION,
+            format!("Bearer {}", token)
+        ))
+        .to_request();
+
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), 200);
+
+    let req = test::TestRequest::post()
+        .uri(format!("/{}/repayments", Uuid::new_v4()).as_str())
+        .insert_header((
+            AUTHORIZATION,
+            format!("Bearer {}", token)
+        ))
+        .set_json(&json!({ "operation_id": Uuid::new_v4() }))
+        .to_request();
+
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), 404);
+}
@@ -1,4 +1,14 @@
+mod accounts;
 mod auth;
+mod balance;
+mod banking;
+mod budgets;
 mod categories;
+mod currencies;
+mod imports;
+mod loans;
 mod operations;
+mod recurring_operations;
+mod reports;
+mod rules;
 mod tags;
\ No newline at end of file
@@ -0,0 +1,72 @@
+use actix_web::{App, test};
+use actix_web::http::header::{AUTHORIZATION, CONTENT_TYPE};
+use actix_web::web::Data;
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+use metan::http::handlers::operations::add_attachment::add_attachment;
+use metan::http::handlers::operations::download_attachment::download_attachment;
+use metan::services::jwt::Claims;
+use metan::services::jwt::JwtService;
+use metan::test_utils::environment::Environment;
+
+const BOUNDARY: &str = "receipt-boundary";
+
+#[actix_rt::test]
+async fn attachment_http_test() {
+    let environment = Environment::new();
+    let (service_container, event_bus, _) = environment.setup().await;
+
+    let app = test::init_service(
+        App::new()
+            .wrap(actix_web::middleware::Logger::default())
+            .app_data(Data::new(service_container.clone()))
+            .app_data(Data::new(event_bus))
+            .service(add_attachment)
+            .service(download_attachment)
+    ).await;
+
+    let jwt_service = service_container.jwt_service();
+    let claims = Claims::new(
+        Uuid::new_v4().to_string(),
+        Utc::now().timestamp() as usize + Duration::days(30).num_seconds() as usize,
+        format!("{}@example.com", Uuid::new_v4()),
+    );
+    let token = jwt_service.create(claims).expect("Failed to create token");
+
+    for (field, status) in [
+        ("file", 404),
+        ("note", 400),
+    ] {
+        let body = format!(
+            "--{boundary}\r\nContent-Disposition: form-data; name=\"{field}\"; filename=\"receipt.pdf\"\r\nContent-Type: application/pdf\r\n\r\n%PDF-1.7\r\n--{boundary}--\r\n",
+            boundary = BOUNDARY,
+            field = field,
+        );
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/{}/attachments", Uuid::new_v4()))
+            .insert_header((
+                AUTHORIZATION,
+                format!("Bearer {}", token)
+            ))
+            .insert_header((CONTENT_TYPE, format!("multipart/form-data; boundary={}", BOUNDARY)))
+            .set_payload(body)
+            .to_request();
+
+        let response = test::call_service(&app, req).await;
+
+        assert_eq!(response.status(), status);
+    }
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/{}/attachments/{}", Uuid::new_v4(), Uuid::new_v4()))
+        .insert_header((
+            AUTHORIZATION,
+            format!("Bearer {}", token)
+        ))
+        .to_request();
+
+    let response = test::call_service(&app, req).await;
+
+    assert_eq!(response.status(), 404);
+}
@@ -3,6 +3,7 @@ use actix_web::http::header::AUTHORIZATION;
 use actix_web::web::Data;
 use chrono::{Duration, Utc};
 use serde_json::json;
+use sqlx::PgPool;
 use uuid::Uuid;
 use metan::http::handlers::operations::create::create_operation;
 use metan::services::jwt::Claims;
@@ -22,9 +23,23 @@ async fn test_create_operation() {
             .service(create_operation)
     ).await;
 
+    let user_id = Uuid::new_v4();
+    let account_id = Uuid::new_v4();
+
+    let pool = PgPool::connect(Environment::db_url().as_str()).await.expect("Failed to connect to database");
+    let _ = sqlx::query("INSERT INTO accounts (id, user_id, name, kind, currency) VALUES ($1, $2, $3, $4, $5)")
+        .bind(account_id)
+        .bind(user_id)
+        .bind("Cash")
+        .bind("Cash")
+        .bind("USD")
+        .execute(&pool)
+        .await
+        .expect("Failed to insert account");
+
     let jwt_service = service_container.jwt_service();
     let claims = Claims::new(
-        Uuid::new_v4().to_string(),
+        user_id.to_string(),
         Utc::now().timestamp() as usize + Duration::days(30).num_seconds() as usize,
         format!("{}@example.com", Uuid::new_v4().to_string()),
     );
@@ -39,6 +54,7 @@ async fn test_create_operation() {
         .set_json(&json!(
             {
                 "kind": "Expense",
+                "account_id": account_id,
                 "category_id": None::<Uuid>,
                 "category_name": "Food",
                 "amount": 100.0,
@@ -62,4 +78,82 @@ async fn test_create_operation() {
     let resp = test::call_service(&app, req).await;
 
     assert_eq!(resp.status(), 200);
-}
\ No newline at end of file
+}
+
+#[actix_rt::test]
+async fn test_create_split_operation() {
+    let environment = Environment::new();
+    let (service_container, event_bus, _) = environment.setup().await;
+
+    let app = test::init_service(
+        App::new()
+            .wrap(actix_web::middleware::Logger::default())
+            .app_data(Data::new(service_container.clone()))
+            .app_data(Data::new(event_bus))
+            .service(create_operation)
+    ).await;
+
+    let user_id = Uuid::new_v4();
+    let account_id = Uuid::new_v4();
+
+    let pool = PgPool::connect(Environment::db_url().as_str()).await.expect("Failed to connect to database");
+    let _ = sqlx::query("INSERT INTO accounts (id, user_id, name, kind, currency) VALUES ($1, $2, $3, $4, $5)")
+        .bind(account_id)
+        .bind(user_id)
+        .bind("Cash")
+        .bind("Cash")
+        .bind("USD")
+        .execute(&pool)
+        .await
+        .expect("Failed to insert account");
+
+    let jwt_service = service_container.jwt_service();
+    let claims = Claims::new(
+        user_id.to_string(),
+        Utc::now().timestamp() as usize + Duration::days(30).num_seconds() as usize,
+        format!("{}@example.com", Uuid::new_v4()),
+    );
+    let token = jwt_service.create(claims).expect("Failed to create token");
+
+    for (second_line_amount, status) in [
+        (40.0, 200),
+        (30.0, 422),
+    ] {
+        let req = test::TestRequest::post()
+            .uri("/create")
+            .insert_header((
+                AUTHORIZATION,
+                format!("Bearer {}", token)
+            ))
+            .set_json(&json!(
+                {
+                    "kind": "Expense",
+                    "account_id": account_id,
+                    "category_id": Uuid::new_v4(),
+                    "category_name": "Shopping",
+                    "amount": 100.0,
+                    "currency": "USD",
+                    "currency_amount": 100.0,
+                    "rate": 1.0,
+                    "label": "Supermarket",
+                    "tags": [],
+                    "lines": [
+                        {
+                            "category_id": Uuid::new_v4(),
+                            "currency_amount": 60.0,
+                            "label": "Groceries"
+                        },
+                        {
+                            "category_id": Uuid::new_v4(),
+                            "currency_amount": second_line_amount,
+                            "label": "Household"
+                        }
+                    ]
+                }
+            )).to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), status);
+    }
+}
@@ -1 +1,6 @@
-mod creation_test;
\ No newline at end of file
+mod attachment_test;
+mod creation_test;
+mod delete_test;
+mod list_test;
+mod suggest_test;
+mod update_test;
\ No newline at end of file
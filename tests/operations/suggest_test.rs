@@ -0,0 +1,60 @@
+use actix_web::{App, test};
+use actix_web::http::header::AUTHORIZATION;
+use actix_web::web::Data;
+use chrono::{Duration, Utc};
+use serde_json::{json, Value};
+use uuid::Uuid;
+use metan::http::handlers::operations::suggest::suggest;
+use metan::services::jwt::Claims;
+use metan::services::jwt::JwtService;
+use metan::test_utils::environment::Environment;
+
+#[actix_rt::test]
+async fn suggest_http_test() {
+    let environment = Environment::new();
+    let (service_container, event_bus, _) = environment.setup().await;
+
+    let app = test::init_service(
+        App::new()
+            .wrap(actix_web::middleware::Logger::default())
+            .app_data(Data::new(service_container.clone()))
+            .app_data(Data::new(event_bus))
+            .service(suggest)
+    ).await;
+
+    let jwt_service = service_container.jwt_service();
+    let claims = Claims::new(
+        Uuid::new_v4().to_string(),
+        Utc::now().timestamp() as usize + Duration::days(30).num_seconds() as usize,
+        format!("{}@example.com", Uuid::new_v4()),
+    );
+    let token = jwt_service.create(claims).expect("Failed to create token");
+
+    for (label, status) in [
+        ("Morning coffee", 200),
+        (" ", 422),
+    ] {
+        let req = test::TestRequest::post()
+            .uri("/suggest")
+            .insert_header((
+                AUTHORIZATION,
+                format!("Bearer {}", token)
+            ))
+            .set_json(json!({
+                "label": label,
+                "amount": "4.50",
+            }))
+            .to_request();
+
+        let response = test::call_service(&app, req).await;
+
+        assert_eq!(response.status(), status);
+
+        if status == 200 {
+            // Nothing was learned for a new user yet
+            let suggestions: Value = test::read_body_json(response).await;
+            assert_eq!(suggestions["categories"], json!([]));
+            assert_eq!(suggestions["tags"], json!([]));
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use actix_web::{App, test};
+use actix_web::http::header::AUTHORIZATION;
+use actix_web::web::Data;
+use chrono::{Duration, Utc};
+use serde_json::json;
+use uuid::Uuid;
+use metan::http::handlers::operations::update::update_operation;
+use metan::services::jwt::Claims;
+use metan::services::jwt::JwtService;
+use metan::test_utils::environment::Environment;
+
+#[actix_rt::test]
+async fn test_update_unknown_operation() {
+    let environment = Environment::new();
+    let (service_container, event_bus, _) = environment.setup().await;
+
+    let app = test::init_service(
+        App::new()
+            .wrap(actix_web::middleware::Logger::default())
+            .app_data(Data::new(service_container.clone()))
+            .app_data(Data::new(event_bus))
+            .service(update_operation)
+    ).await;
+
+    let jwt_service = service_container.jwt_service();
+    let claims = Claims::new(
+        Uuid::new_v4().to_string(),
+        Utc::now().timestamp() as usize + Duration::days(30).num_seconds() as usize,
+        format!("{}@example.com", Uuid::new_v4()),
+    );
+    let token = jwt_service.create(claims).expect("Failed to create token");
+
+    let req = test::TestRequest::put()
+        .uri(&format!("/{}", Uuid::new_v4()))
+        .insert_header((
+            AUTHORIZATION,
+            format!("Bearer {}", token)
+        ))
+        .set_json(&json!(
+            {
+                "kind": "Expense",
+                "account_id": Uuid::new_v4(),
+                "category_id": Uuid::new_v4(),
+                "category_name": "Food",
+                "amount": 100.0,
+                "currency": "USD",
+                "currency_amount": 100.0,
+                "rate": 1.0,
+                "label": "Lunch",
+                "tags": []
+            }
+        )).to_request();
+
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), 404);
+}
@@ -0,0 +1,89 @@
+use actix_web::{App, test};
+use actix_web::http::header::AUTHORIZATION;
+use actix_web::web::Data;
+use chrono::{Duration, Utc};
+use serde_json::json;
+use uuid::Uuid;
+use metan::http::handlers::recurring_operations::cancel::cancel_recurring_operation;
+use metan::http::handlers::recurring_operations::create::create_recurring_operation;
+use metan::http::handlers::recurring_operations::list::list_recurring_operations;
+use metan::services::jwt::Claims;
+use metan::services::jwt::JwtService;
+use metan::test_utils::environment::Environment;
+
+#[actix_rt::test]
+async fn test_list_recurring_operations() {
+    let environment = Environment::new();
+    let (service_container, event_bus, _) = environment.setup().await;
+
+    let app = test::init_service(
+        App::new()
+            .wrap(actix_web::middleware::Logger::default())
+            .app_data(Data::new(service_container.clone()))
+            .app_data(Data::new(event_bus))
+            .service(create_recurring_operation)
+            .service(list_recurring_operations)
+            .service(cancel_recurring_operation)
+    ).await;
+
+    let jwt_service = service_container.jwt_service();
+    let claims = Claims::new(
+        Uuid::new_v4().to_string(),
+        Utc::now().timestamp() as usize + Duration::days(30).num_seconds() as usize,
+        format!("{}@example.com", Uuid::new_v4()),
+    );
+    let token = jwt_service.create(claims).expect("Failed to create token");
+
+    let req = test::TestRequest::post()
+        .uri("/create")
+        .insert_header((
+            AUTHORIZATION,
+            format!("Bearer {}", token)
+        ))
+        .set_json(&json!({
+            "recurrence": {
+                "frequency": "Monthly",
+                "starts_on": "2024-01-05",
+                "count": 12
+            },
+            "template": {
+                "kind": "Expense",
+                "account_id": Uuid::new_v4(),
+                "category_id": null,
+                "category_name": "Housing",
+                "currency": "USD",
+                "currency_amount": "1200.00",
+                "label": "Rent",
+                "transfer_account_id": null
+            }
+        }))
+        .to_request();
+
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), 200);
+
+    let req = test::TestRequest::get()
+        .uri("")
+        .insert_header((
+            AUTHORIZATION,
+            format!("Bearer {}", token)
+        ))
+        .to_request();
+
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), 200);
+
+    let req = test::TestRequest::delete()
+        .uri(format!("/{}", Uuid::new_v4()).as_str())
+        .insert_header((
+            AUTHORIZATION,
+            format!("Bearer {}", token)
+        ))
+        .to_request();
+
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), 404);
+}
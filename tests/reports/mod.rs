@@ -0,0 +1 @@
+pub mod report_test;
\ No newline at end of file
@@ -0,0 +1,77 @@
+use actix_web::{App, test};
+use actix_web::http::header::AUTHORIZATION;
+use actix_web::web::Data;
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+use metan::http::handlers::reports::categories::category_totals_report;
+use metan::http::handlers::reports::forecast::forecast_report;
+use metan::http::handlers::reports::kinds::kind_totals_report;
+use metan::http::handlers::reports::tags::tag_totals_report;
+use metan::http::handlers::reports::timeline::timeline_report;
+use metan::services::jwt::Claims;
+use metan::services::jwt::JwtService;
+use metan::test_utils::environment::Environment;
+
+#[actix_rt::test]
+async fn test_reports() {
+    let environment = Environment::new();
+    let (service_container, event_bus, _) = environment.setup().await;
+
+    let app = test::init_service(
+        App::new()
+            .wrap(actix_web::middleware::Logger::default())
+            .app_data(Data::new(service_container.clone()))
+            .app_data(Data::new(event_bus))
+            .service(category_totals_report)
+            .service(tag_totals_report)
+            .service(kind_totals_report)
+            .service(timeline_report)
+            .service(forecast_report)
+    ).await;
+
+    let jwt_service = service_container.jwt_service();
+    let claims = Claims::new(
+        Uuid::new_v4().to_string(),
+        Utc::now().timestamp() as usize + Duration::days(30).num_seconds() as usize,
+        format!("{}@example.com", Uuid::new_v4()),
+    );
+    let token = jwt_service.create(claims).expect("Failed to create token");
+
+    for uri in [
+        "/categories?kind=Expense&date_from=2024-03-01T00:00:00Z&date_to=2024-03-31T23:59:59Z",
+        "/tags?kind=Expense",
+        "/kinds",
+        "/timeline?bucket=Week&kind=Income",
+        "/forecast?days=60",
+    ] {
+        let req = test::TestRequest::get()
+            .uri(uri)
+            .insert_header((
+                AUTHORIZATION,
+                format!("Bearer {}", token)
+            ))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), 200);
+    }
+
+    for uri in [
+        "/kinds?kind=Transfer",
+        "/timeline?bucket=Year",
+        "/forecast?days=0",
+    ] {
+        let req = test::TestRequest::get()
+            .uri(uri)
+            .insert_header((
+                AUTHORIZATION,
+                format!("Bearer {}", token)
+            ))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), 422);
+    }
+}
@@ -0,0 +1,57 @@
+use actix_web::{App, test};
+use actix_web::http::header::AUTHORIZATION;
+use actix_web::web::Data;
+use chrono::{Duration, Utc};
+use serde_json::json;
+use uuid::Uuid;
+use metan::http::handlers::rules::create::create_rule;
+use metan::services::jwt::Claims;
+use metan::services::jwt::JwtService;
+use metan::test_utils::environment::Environment;
+
+#[actix_rt::test]
+async fn create_rule_http_test() {
+    let environment = Environment::new();
+    let (service_container, event_bus, _) = environment.setup().await;
+
+    let app = test::init_service(
+        App::new()
+            .wrap(actix_web::middleware::Logger::default())
+            .app_data(Data::new(service_container.clone()))
+            .app_data(Data::new(event_bus))
+            .service(create_rule)
+    ).await;
+
+    let jwt_service = service_container.jwt_service();
+    let claims = Claims::new(
+        Uuid::new_v4().to_string(),
+        Utc::now().timestamp() as usize + Duration::days(30).num_seconds() as usize,
+        format!("{}@example.com", Uuid::new_v4()),
+    );
+    let token = jwt_service.create(claims).expect("Failed to create token");
+
+    for (conditions, status) in [
+        (json!({"label_contains": "coffee", "kind": "expense"}), 200),
+        (json!({"label_regex": "^(uber|bolt)\\b", "min_amount": "5"}), 200),
+        (json!({}), 422),
+        (json!({"label_regex": "(unclosed"}), 422),
+        (json!({"min_amount": "10", "max_amount": "5"}), 422),
+    ] {
+        let req = test::TestRequest::post()
+            .uri("/create")
+            .insert_header((
+                AUTHORIZATION,
+                format!("Bearer {}", token)
+            ))
+            .set_json(json!({
+                "name": "Coffee",
+                "conditions": conditions,
+                "category_id": Uuid::new_v4(),
+            }))
+            .to_request();
+
+        let response = test::call_service(&app, req).await;
+
+        assert_eq!(response.status(), status);
+    }
+}
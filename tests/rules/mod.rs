@@ -0,0 +1 @@
+pub mod creation_test;
\ No newline at end of file
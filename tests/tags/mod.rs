@@ -1 +1,2 @@
-pub mod creation_test;
\ No newline at end of file
+pub mod creation_test;
+pub mod list_test;